@@ -3,11 +3,19 @@
 use crate::polkadot_test_net::*;
 use frame_support::{assert_noop, assert_ok};
 use frame_system::RawOrigin;
-use hydradx_runtime::{Balances, CircuitBreaker, Omnipool, OmnipoolCollectionId, Tokens, Uniques};
+use hydradx_runtime::{
+	AssetRegistry, Balances, CircuitBreaker, Omnipool, OmnipoolCollectionId, Router, Stableswap, Tokens, Uniques, XYK,
+};
+use hydradx_traits::registry::Create;
+use hydradx_traits::router::{PoolType, Route, Trade};
+use hydradx_traits::stableswap::AssetAmount;
+use hydradx_traits::AssetKind;
 use orml_traits::MultiCurrency;
+use pallet_circuit_breaker::types::{AccountLimitKind, AccountLimitParameters};
 use primitives::constants::chain::CORE_ASSET_ID;
 use primitives::Balance;
 use sp_runtime::traits::Zero;
+use sp_runtime::BoundedVec;
 use sp_runtime::FixedU128;
 use sp_runtime::Permill;
 use xcm_emulator::TestExt;
@@ -667,6 +675,269 @@ fn remove_liquidity_to_omnipool_should_not_fail_when_liquidity_limit_per_block_e
 	});
 }
 
+#[test]
+fn router_sell_in_xyk_should_count_towards_account_trade_volume() {
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		create_xyk_pool_with_dai();
+		set_account_trade_volume_limit(1_000_000 * UNITS);
+
+		//Act
+		assert_ok!(Router::sell(
+			hydradx_runtime::RuntimeOrigin::signed(BOB.into()),
+			DAI,
+			HDX,
+			UNITS,
+			0,
+			xyk_route()
+		));
+
+		//Assert
+		let (used, _) = CircuitBreaker::account_limit_accumulator(AccountId::from(BOB), AccountLimitKind::TradeVolume);
+		assert!(!used.is_zero());
+	});
+}
+
+#[test]
+fn router_sell_in_xyk_should_fail_when_account_trade_volume_limit_exceeded() {
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		create_xyk_pool_with_dai();
+		set_account_trade_volume_limit(1);
+
+		//Act and assert
+		assert_noop!(
+			Router::sell(
+				hydradx_runtime::RuntimeOrigin::signed(BOB.into()),
+				DAI,
+				HDX,
+				UNITS,
+				0,
+				xyk_route()
+			),
+			pallet_circuit_breaker::Error::<hydradx_runtime::Runtime>::AccountTradeVolumeLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn xyk_sell_should_fail_when_account_trade_volume_limit_exceeded() {
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		create_xyk_pool_with_dai();
+		set_account_trade_volume_limit(1);
+
+		//Act and assert
+		assert_noop!(
+			XYK::sell(
+				hydradx_runtime::RuntimeOrigin::signed(BOB.into()),
+				DAI,
+				HDX,
+				UNITS,
+				0,
+				false
+			),
+			pallet_circuit_breaker::Error::<hydradx_runtime::Runtime>::AccountTradeVolumeLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn xyk_buy_should_count_towards_account_trade_volume() {
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		create_xyk_pool_with_dai();
+		set_account_trade_volume_limit(1_000_000 * UNITS);
+
+		//Act
+		assert_ok!(XYK::buy(
+			hydradx_runtime::RuntimeOrigin::signed(BOB.into()),
+			HDX,
+			DAI,
+			UNITS / 2,
+			UNITS,
+			false
+		));
+
+		//Assert
+		let (used, _) = CircuitBreaker::account_limit_accumulator(AccountId::from(BOB), AccountLimitKind::TradeVolume);
+		assert!(!used.is_zero());
+	});
+}
+
+#[test]
+fn stableswap_sell_should_be_limited_when_account_trade_volume_limit_is_configured() {
+	Hydra::execute_with(|| {
+		//Arrange
+		let (pool_id, asset_in, asset_out) = create_stableswap_pool_without_price();
+		set_account_trade_volume_limit(1_000_000 * UNITS);
+
+		//Act and assert
+		// The assets of the pool have no price, so the trade volume can't be counted.
+		assert_noop!(
+			Stableswap::sell(
+				hydradx_runtime::RuntimeOrigin::signed(BOB.into()),
+				pool_id,
+				asset_in,
+				asset_out,
+				UNITS,
+				0
+			),
+			pallet_circuit_breaker::Error::<hydradx_runtime::Runtime>::FailedToConvertAsset
+		);
+	});
+}
+
+#[test]
+fn stableswap_sell_of_assets_without_price_should_work_when_account_trade_volume_limit_is_not_configured() {
+	Hydra::execute_with(|| {
+		//Arrange
+		let (pool_id, asset_in, asset_out) = create_stableswap_pool_without_price();
+
+		//Act
+		assert_ok!(Stableswap::sell(
+			hydradx_runtime::RuntimeOrigin::signed(BOB.into()),
+			pool_id,
+			asset_in,
+			asset_out,
+			UNITS,
+			0
+		));
+
+		//Assert
+		let (used, _) = CircuitBreaker::account_limit_accumulator(AccountId::from(BOB), AccountLimitKind::TradeVolume);
+		assert!(used.is_zero());
+	});
+}
+
+#[test]
+fn router_sell_of_asset_without_price_should_work_when_account_is_whitelisted() {
+	Hydra::execute_with(|| {
+		//Arrange
+		let (pool_id, asset_in, asset_out) = create_stableswap_pool_without_price();
+		set_account_trade_volume_limit(1_000_000 * UNITS);
+		assert_ok!(CircuitBreaker::add_account_limit_whitelist(
+			RawOrigin::Root.into(),
+			vec![AccountId::from(BOB)].try_into().unwrap()
+		));
+
+		//Act and assert
+		assert_ok!(Router::sell(
+			hydradx_runtime::RuntimeOrigin::signed(BOB.into()),
+			asset_in,
+			asset_out,
+			UNITS,
+			0,
+			vec![Trade {
+				pool: PoolType::Stableswap(pool_id),
+				asset_in,
+				asset_out,
+			}]
+			.try_into()
+			.unwrap()
+		));
+	});
+}
+
+fn create_stableswap_pool_without_price() -> (AssetId, AssetId, AssetId) {
+	let initial_liquidity = 1_000_000 * UNITS;
+
+	let mut asset_ids = vec![];
+	let mut initial = vec![];
+	for name in [b"SSA".to_vec(), b"SSB".to_vec()] {
+		let asset_id = AssetRegistry::register_sufficient_asset(
+			None,
+			Some(name.try_into().unwrap()),
+			AssetKind::Token,
+			1_000u128,
+			None,
+			Some(12u8),
+			None,
+			None,
+		)
+		.unwrap();
+		assert_ok!(Tokens::set_balance(
+			RawOrigin::Root.into(),
+			BOB.into(),
+			asset_id,
+			initial_liquidity + UNITS,
+			0
+		));
+		asset_ids.push(asset_id);
+		initial.push(AssetAmount::new(asset_id, initial_liquidity));
+	}
+
+	let pool_id = AssetRegistry::register_sufficient_asset(
+		None,
+		Some(b"SSPOOL".to_vec().try_into().unwrap()),
+		AssetKind::StableSwap,
+		1_000u128,
+		None,
+		Some(18u8),
+		None,
+		None,
+	)
+	.unwrap();
+	assert_ok!(Stableswap::create_pool(
+		RawOrigin::Root.into(),
+		pool_id,
+		BoundedVec::truncate_from(asset_ids.clone()),
+		100,
+		Permill::from_percent(0),
+	));
+	assert_ok!(Stableswap::add_assets_liquidity(
+		hydradx_runtime::RuntimeOrigin::signed(BOB.into()),
+		pool_id,
+		BoundedVec::truncate_from(initial),
+		0,
+	));
+
+	(pool_id, asset_ids[0], asset_ids[1])
+}
+
+fn create_xyk_pool_with_dai() {
+	assert_ok!(Tokens::set_balance(
+		RawOrigin::Root.into(),
+		ALICE.into(),
+		DAI,
+		1_000 * UNITS,
+		0
+	));
+	assert_ok!(XYK::create_pool(
+		hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+		HDX,
+		1_000 * UNITS,
+		DAI,
+		1_000 * UNITS,
+	));
+	assert_ok!(Tokens::set_balance(RawOrigin::Root.into(), BOB.into(), DAI, UNITS, 0));
+}
+
+fn set_account_trade_volume_limit(limit: Balance) {
+	assert_ok!(CircuitBreaker::set_account_limit_params(
+		RawOrigin::Root.into(),
+		AccountLimitKind::TradeVolume,
+		Some(AccountLimitParameters {
+			limit,
+			window: 86_400_000,
+		}),
+	));
+}
+
+fn xyk_route() -> Route<AssetId> {
+	vec![Trade {
+		pool: PoolType::XYK,
+		asset_in: DAI,
+		asset_out: HDX,
+	}]
+	.try_into()
+	.unwrap()
+}
+
 pub fn init_omnipool() {
 	assert_ok!(hydradx_runtime::Omnipool::add_token(
 		hydradx_runtime::RuntimeOrigin::root(),
@@ -75,6 +75,52 @@ fn rococo_should_receive_asset_when_sent_from_hydra() {
 	});
 }
 
+#[test]
+fn xtoken_transfer_should_fail_when_outbound_rate_limit_is_exceeded() {
+	//Arrange
+	Hydra::execute_with(|| {
+		assert_ok!(hydradx_runtime::AssetRegistry::set_location(
+			1,
+			hydradx_runtime::AssetLocation(Location {
+				parents: 1,
+				interior: Here
+			})
+		));
+		assert_ok!(crate::deposit_limiter::update_deposit_limit(1, 5 * UNITS));
+
+		let beneficiary = Box::new(
+			Location {
+				parents: 1,
+				interior: [Junction::AccountId32 { id: BOB, network: None }].into(),
+			}
+			.into_versioned(),
+		);
+		assert_ok!(hydradx_runtime::XTokens::transfer(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			1,
+			3 * UNITS,
+			beneficiary.clone(),
+			WeightLimit::Unlimited,
+		));
+
+		//Act & Assert
+		assert_noop!(
+			hydradx_runtime::XTokens::transfer(
+				hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+				1,
+				3 * UNITS,
+				beneficiary,
+				WeightLimit::Unlimited,
+			),
+			orml_xtokens::Error::<hydradx_runtime::Runtime>::XcmExecutionFailed
+		);
+		assert_eq!(
+			hydradx_runtime::Tokens::free_balance(1, &AccountId::from(ALICE)),
+			200 * UNITS - 3 * UNITS
+		);
+	});
+}
+
 #[test]
 fn xtoken_transfer_of_erc20_should_fail() {
 	//Arrange
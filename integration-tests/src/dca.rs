@@ -366,6 +366,7 @@ mod omnipool {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				trigger: None,
 				slippage: Some(Permill::from_percent(5)),
 				order: Order::Buy {
 					asset_in: HDX,
@@ -1585,6 +1586,7 @@ mod omnipool {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				trigger: None,
 				slippage: Some(Permill::from_percent(1)),
 				order: Order::Sell {
 					asset_in: HDX,
@@ -2140,6 +2142,7 @@ mod omnipool {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				trigger: None,
 				slippage: Some(Permill::from_percent(5)),
 				order: Order::Sell {
 					asset_in: HDX,
@@ -2558,6 +2561,7 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					trigger: None,
 					slippage: Some(Permill::from_percent(10)),
 					order: Order::Sell {
 						asset_in: HDX,
@@ -2835,6 +2839,7 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					trigger: None,
 					slippage: Some(Permill::from_percent(10)),
 					order: Order::Sell {
 						asset_in: stable_asset_1,
@@ -3142,6 +3147,7 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					trigger: None,
 					slippage: Some(Permill::from_percent(10)),
 					order: Order::Buy {
 						asset_in: HDX,
@@ -3323,6 +3329,7 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					trigger: None,
 					slippage: Some(Permill::from_percent(70)),
 					order: Order::Buy {
 						asset_in: stable_asset_1,
@@ -3439,6 +3446,7 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					trigger: None,
 					slippage: Some(Permill::from_percent(100)),
 					order: Order::Buy {
 						asset_in: stable_asset_1,
@@ -3561,6 +3569,7 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					trigger: None,
 					slippage: Some(Permill::from_percent(70)),
 					order: Order::Buy {
 						asset_in: stable_asset_1,
@@ -3692,6 +3701,7 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					trigger: None,
 					slippage: Some(Permill::from_percent(70)),
 					order: Order::Buy {
 						asset_in: HDX,
@@ -4115,6 +4125,7 @@ mod all_pools {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					trigger: None,
 					slippage: Some(Permill::from_percent(15)),
 					order: Order::Sell {
 						asset_in: HDX,
@@ -4261,6 +4272,7 @@ mod with_onchain_route {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					trigger: None,
 					slippage: Some(Permill::from_percent(10)),
 					order: Order::Buy {
 						asset_in: HDX,
@@ -4375,6 +4387,7 @@ mod with_onchain_route {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					trigger: None,
 					slippage: Some(Permill::from_percent(10)),
 					order: Order::Sell {
 						asset_in: HDX,
@@ -4459,6 +4472,7 @@ mod with_onchain_route {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				trigger: None,
 				slippage: Some(Permill::from_percent(10)),
 				order: Order::Sell {
 					asset_in: DOT,
@@ -4606,6 +4620,7 @@ mod with_onchain_route {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					trigger: None,
 					slippage: Some(Permill::from_percent(10)),
 					order: Order::Sell {
 						asset_in: stable_asset_1,
@@ -4726,6 +4741,7 @@ mod with_onchain_route {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				trigger: None,
 				slippage: Some(Permill::from_percent(10)),
 				order: Order::Sell {
 					asset_in: DOT,
@@ -5110,6 +5126,7 @@ fn schedule_fake_with_buy_order_with_route(
 		total_amount: budget,
 		max_retries: None,
 		stability_threshold: None,
+		trigger: None,
 		slippage: Some(Permill::from_percent(10)),
 		order: Order::Buy {
 			asset_in,
@@ -5157,6 +5174,7 @@ fn schedule_fake_with_sell_order_with_route(
 		total_amount,
 		max_retries: None,
 		stability_threshold: None,
+		trigger: None,
 		slippage: Some(Permill::from_percent(15)),
 		order: Order::Sell {
 			asset_in,
@@ -6055,6 +6073,7 @@ mod extra_gas_erc20 {
 			total_amount,
 			max_retries,
 			stability_threshold: None,
+			trigger: None,
 			slippage: Some(Permill::from_percent(90)),
 			order: Order::Sell {
 				asset_in,
@@ -6179,6 +6198,7 @@ fn rolling_buy_dca_completes_prematurely_when_price_increases() {
 			total_amount: 0, // rolling
 			max_retries: Some(100),
 			stability_threshold: Some(Permill::from_percent(5)),
+			trigger: None,
 			slippage: Some(Permill::from_percent(50)),
 			order: Order::Buy {
 				asset_in: HDX,
@@ -5893,3 +5893,358 @@ mod weight_uses_resolved_route {
 		});
 	}
 }
+
+mod quote {
+	use super::*;
+
+	#[test]
+	fn quote_sell_should_match_executed_sell() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool();
+
+			let amount_to_sell = 10 * UNITS;
+
+			//Act
+			let quote = hydradx_runtime::router::quote_sell(HDX, DAI, amount_to_sell, BoundedVec::new()).unwrap();
+
+			//Assert
+			let amount_out = 266195070030573798;
+
+			assert_eq!(quote.amount_in, amount_to_sell);
+			assert_eq!(quote.amount_out, amount_out);
+			assert_eq!(quote.trades.len(), 1);
+			assert_eq!(
+				quote.trades[0].trade,
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: DAI,
+				}
+			);
+			assert_eq!(quote.trades[0].amount_in, amount_to_sell);
+			assert_eq!(quote.trades[0].amount_out, amount_out);
+			assert!(quote.trades[0].spot_price_with_fee.is_some());
+			assert!(!quote.trades[0].fees.is_empty());
+
+			// The dry-run must not leave anything behind
+			assert_balance!(Router::router_account(), HDX, 0);
+			assert_balance!(Router::router_account(), DAI, 0);
+
+			assert_ok!(Router::sell(
+				RuntimeOrigin::signed(BOB.into()),
+				HDX,
+				DAI,
+				amount_to_sell,
+				0,
+				BoundedVec::new()
+			));
+			assert_balance!(BOB.into(), DAI, BOB_INITIAL_DAI_BALANCE + amount_out);
+		});
+	}
+
+	#[test]
+	fn quote_buy_should_match_executed_buy() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool();
+
+			let amount_to_buy = UNITS * 100000;
+
+			//Act
+			let quote = hydradx_runtime::router::quote_buy(HDX, DAI, amount_to_buy, BoundedVec::new()).unwrap();
+
+			//Assert
+			let amount_in = 3756606010477;
+
+			assert_eq!(quote.amount_in, amount_in);
+			assert_eq!(quote.amount_out, amount_to_buy);
+			assert_eq!(quote.trades.len(), 1);
+			assert!(!quote.trades[0].fees.is_empty());
+
+			assert_ok!(Router::buy(
+				RuntimeOrigin::signed(BOB.into()),
+				HDX,
+				DAI,
+				amount_to_buy,
+				amount_in,
+				BoundedVec::new()
+			));
+			assert_balance!(BOB.into(), HDX, BOB_INITIAL_NATIVE_BALANCE - amount_in);
+		});
+	}
+
+	#[test]
+	fn quote_should_contain_every_trade_of_multi_hop_route() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool();
+			create_xyk_pool(HDX, DOT);
+
+			let amount_to_sell = UNITS;
+			let trades = vec![
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: DAI,
+					asset_out: HDX,
+				},
+				Trade {
+					pool: PoolType::XYK,
+					asset_in: HDX,
+					asset_out: DOT,
+				},
+			];
+
+			//Act
+			let quote = hydradx_runtime::router::quote_sell(
+				DAI,
+				DOT,
+				amount_to_sell,
+				BoundedVec::truncate_from(trades.clone()),
+			)
+			.unwrap();
+
+			//Assert
+			assert_eq!(quote.trades.len(), 2);
+			assert_eq!(quote.trades[0].trade, trades[0]);
+			assert_eq!(quote.trades[1].trade, trades[1]);
+			assert_eq!(quote.trades[0].amount_out, quote.trades[1].amount_in);
+			assert!(!quote.trades[0].fees.is_empty());
+			assert!(!quote.trades[1].fees.is_empty());
+			assert_eq!(
+				quote.amount_out,
+				Router::calculate_expected_amount_out(&trades, amount_to_sell).unwrap()
+			);
+		});
+	}
+
+	#[test]
+	fn quote_should_fail_when_route_is_inconsistent() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			init_omnipool();
+
+			let trades = vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DOT,
+			}];
+
+			assert_noop!(
+				hydradx_runtime::router::quote_sell(HDX, DAI, UNITS, BoundedVec::truncate_from(trades)),
+				pallet_route_executor::Error::<Runtime>::InvalidRoute
+			);
+		});
+	}
+}
+
+mod route_discovery {
+	use super::*;
+
+	fn dai_hdx_dot_route() -> Vec<Trade<AssetId>> {
+		vec![
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: DAI,
+				asset_out: HDX,
+			},
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: HDX,
+				asset_out: DOT,
+			},
+		]
+	}
+
+	#[test]
+	fn find_best_sell_route_should_find_route_across_pool_types() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool();
+			create_xyk_pool(HDX, DOT);
+
+			let amount_to_sell = UNITS;
+
+			//Act
+			let (route, amount_out) = Router::find_best_sell_route(DAI, DOT, amount_to_sell).unwrap();
+
+			//Assert
+			assert_eq!(route.to_vec(), dai_hdx_dot_route());
+			assert_eq!(
+				amount_out,
+				Router::calculate_expected_amount_out(&dai_hdx_dot_route(), amount_to_sell).unwrap()
+			);
+
+			assert_ok!(Router::sell(
+				RuntimeOrigin::signed(BOB.into()),
+				DAI,
+				DOT,
+				amount_to_sell,
+				amount_out,
+				route
+			));
+			assert_balance!(BOB.into(), DOT, amount_out);
+		});
+	}
+
+	#[test]
+	fn find_best_buy_route_should_find_route_across_pool_types() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool();
+			create_xyk_pool(HDX, DOT);
+
+			let amount_to_buy = UNITS;
+
+			//Act
+			let (route, amount_in) = Router::find_best_buy_route(DAI, DOT, amount_to_buy).unwrap();
+
+			//Assert
+			assert_eq!(route.to_vec(), dai_hdx_dot_route());
+			assert_eq!(
+				amount_in,
+				Router::calculate_expected_amount_in(&dai_hdx_dot_route(), amount_to_buy).unwrap()
+			);
+		});
+	}
+}
+
+mod split_route {
+	use super::*;
+	use hydradx_traits::router::WeightedRoute;
+
+	fn omnipool_dai_hdx_route() -> Vec<Trade<AssetId>> {
+		vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: DAI,
+			asset_out: HDX,
+		}]
+	}
+
+	fn xyk_dai_hdx_route() -> Vec<Trade<AssetId>> {
+		vec![Trade {
+			pool: PoolType::XYK,
+			asset_in: DAI,
+			asset_out: HDX,
+		}]
+	}
+
+	fn split_route() -> pallet_route_executor::SplitRoute<AssetId> {
+		BoundedVec::truncate_from(vec![
+			WeightedRoute {
+				weight: Permill::from_percent(50),
+				route: BoundedVec::truncate_from(omnipool_dai_hdx_route()),
+			},
+			WeightedRoute {
+				weight: Permill::from_percent(50),
+				route: BoundedVec::truncate_from(xyk_dai_hdx_route()),
+			},
+		])
+	}
+
+	#[test]
+	fn split_sell_should_sell_via_all_routes() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool();
+			create_xyk_pool(HDX, DAI);
+
+			let amount_to_sell = 10 * UNITS;
+			let expected_amount_out =
+				Router::calculate_expected_amount_out(&omnipool_dai_hdx_route(), amount_to_sell / 2).unwrap()
+					+ Router::calculate_expected_amount_out(&xyk_dai_hdx_route(), amount_to_sell / 2).unwrap();
+
+			let bob_hdx_balance = Currencies::free_balance(HDX, &BOB.into());
+			let bob_dai_balance = Currencies::free_balance(DAI, &BOB.into());
+
+			//Act
+			assert_ok!(Router::split_sell(
+				RuntimeOrigin::signed(BOB.into()),
+				DAI,
+				HDX,
+				amount_to_sell,
+				expected_amount_out,
+				split_route()
+			));
+
+			//Assert
+			assert_balance!(BOB.into(), HDX, bob_hdx_balance + expected_amount_out);
+			assert_balance!(BOB.into(), DAI, bob_dai_balance - amount_to_sell);
+		});
+	}
+
+	#[test]
+	fn split_buy_should_buy_via_all_routes() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool();
+			create_xyk_pool(HDX, DAI);
+
+			let amount_to_buy = 2 * UNITS;
+			let expected_amount_in =
+				Router::calculate_expected_amount_in(&omnipool_dai_hdx_route(), amount_to_buy / 2).unwrap()
+					+ Router::calculate_expected_amount_in(&xyk_dai_hdx_route(), amount_to_buy / 2).unwrap();
+
+			let bob_hdx_balance = Currencies::free_balance(HDX, &BOB.into());
+			let bob_dai_balance = Currencies::free_balance(DAI, &BOB.into());
+
+			//Act
+			assert_ok!(Router::split_buy(
+				RuntimeOrigin::signed(BOB.into()),
+				DAI,
+				HDX,
+				amount_to_buy,
+				expected_amount_in,
+				split_route()
+			));
+
+			//Assert
+			assert_balance!(BOB.into(), HDX, bob_hdx_balance + amount_to_buy);
+			assert_balance!(BOB.into(), DAI, bob_dai_balance - expected_amount_in);
+		});
+	}
+
+	#[test]
+	fn split_sell_should_revert_all_routes_when_total_amount_out_is_below_limit() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipool();
+			create_xyk_pool(HDX, DAI);
+
+			let amount_to_sell = 10 * UNITS;
+			let expected_amount_out =
+				Router::calculate_expected_amount_out(&omnipool_dai_hdx_route(), amount_to_sell / 2).unwrap()
+					+ Router::calculate_expected_amount_out(&xyk_dai_hdx_route(), amount_to_sell / 2).unwrap();
+
+			//Act and assert
+			assert_noop!(
+				Router::split_sell(
+					RuntimeOrigin::signed(BOB.into()),
+					DAI,
+					HDX,
+					amount_to_sell,
+					expected_amount_out + 1,
+					split_route()
+				),
+				pallet_route_executor::Error::<Runtime>::TradingLimitReached
+			);
+		});
+	}
+}
@@ -0,0 +1,121 @@
+use crate::concentrated_liquidity::*;
+use crate::types::Balance;
+use proptest::prelude::*;
+use sp_arithmetic::Permill;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+fn tick() -> impl Strategy<Value = i32> {
+	MIN_TICK..MAX_TICK
+}
+
+fn price_range() -> impl Strategy<Value = (i32, i32, i32)> {
+	(-100_000..100_000i32, 1..50_000i32, 1..50_000i32)
+		.prop_map(|(current, below, above)| (current, current - below, current + above))
+}
+
+fn trade_amount() -> impl Strategy<Value = Balance> {
+	1..1_000 * ONE
+}
+
+fn liquidity() -> impl Strategy<Value = Balance> {
+	ONE..1_000_000_000 * ONE
+}
+
+fn fee() -> impl Strategy<Value = Permill> {
+	(0..100_000u32).prop_map(Permill::from_parts)
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn tick_and_sqrt_price_conversion_is_consistent(tick in tick()) {
+		let sqrt_price = sqrt_price_at_tick(tick).unwrap();
+		let next_sqrt_price = sqrt_price_at_tick(tick + 1).unwrap();
+
+		assert!(sqrt_price < next_sqrt_price);
+		assert_eq!(tick_at_sqrt_price(sqrt_price), Some(tick));
+		assert_eq!(tick_at_sqrt_price(next_sqrt_price - 1), Some(tick));
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn swap_step_exact_in_does_not_exceed_amount((current, lower, upper) in price_range(),
+		liquidity in liquidity(),
+		amount in trade_amount(),
+		fee in fee(),
+		a_for_b in any::<bool>(),
+	) {
+		let sqrt_price = sqrt_price_at_tick(current).unwrap();
+		let target = sqrt_price_at_tick(if a_for_b { lower } else { upper }).unwrap();
+
+		let step = compute_swap_step(sqrt_price, target, liquidity, amount, fee, true).unwrap();
+
+		assert!(step.amount_in + step.fee_amount <= amount);
+		if a_for_b {
+			assert!(step.sqrt_price_next <= sqrt_price && step.sqrt_price_next >= target);
+		} else {
+			assert!(step.sqrt_price_next >= sqrt_price && step.sqrt_price_next <= target);
+		}
+
+		// Price moved by the step must be paid for
+		let (required_in, max_out) = if a_for_b {
+			(
+				amount_a_delta(step.sqrt_price_next, sqrt_price, liquidity, true).unwrap(),
+				amount_b_delta(step.sqrt_price_next, sqrt_price, liquidity, false).unwrap(),
+			)
+		} else {
+			(
+				amount_b_delta(sqrt_price, step.sqrt_price_next, liquidity, true).unwrap(),
+				amount_a_delta(sqrt_price, step.sqrt_price_next, liquidity, false).unwrap(),
+			)
+		};
+		assert!(step.amount_in >= required_in);
+		assert!(step.amount_out <= max_out);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn swap_step_exact_out_does_not_exceed_amount((current, lower, upper) in price_range(),
+		liquidity in liquidity(),
+		amount in trade_amount(),
+		fee in fee(),
+		a_for_b in any::<bool>(),
+	) {
+		let sqrt_price = sqrt_price_at_tick(current).unwrap();
+		let target = sqrt_price_at_tick(if a_for_b { lower } else { upper }).unwrap();
+
+		let step = compute_swap_step(sqrt_price, target, liquidity, amount, fee, false).unwrap();
+
+		assert!(step.amount_out <= amount);
+		if step.sqrt_price_next != target {
+			assert_eq!(step.amount_out, amount);
+		}
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn liquidity_round_trip_does_not_create_value((current, lower, upper) in price_range(),
+		amount_a in trade_amount(),
+		amount_b in trade_amount(),
+	) {
+		let sqrt_price = sqrt_price_at_tick(current).unwrap();
+		let sqrt_price_lower = sqrt_price_at_tick(lower).unwrap();
+		let sqrt_price_upper = sqrt_price_at_tick(upper).unwrap();
+
+		let liquidity = liquidity_for_amounts(sqrt_price, sqrt_price_lower, sqrt_price_upper, amount_a, amount_b).unwrap();
+		let (added_a, added_b) = amounts_for_liquidity(sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, true).unwrap();
+		let (removed_a, removed_b) = amounts_for_liquidity(sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, false).unwrap();
+
+		assert!(added_a <= amount_a);
+		assert!(added_b <= amount_b);
+		assert!(removed_a <= added_a);
+		assert!(removed_b <= added_b);
+	}
+}
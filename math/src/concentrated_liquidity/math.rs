@@ -0,0 +1,540 @@
+use crate::concentrated_liquidity::types::{Liquidity, SqrtPrice, SwapStep, Tick};
+use crate::support::rational::{round_to_rational, Rounding};
+use crate::types::Balance;
+use num_traits::Zero;
+use primitive_types::{U256, U512};
+use sp_arithmetic::{FixedPointNumber, FixedU128, PerThing, Permill};
+
+/// Lowest supported tick. Price at the tick is roughly `2^-64`.
+pub const MIN_TICK: Tick = -443_636;
+/// Highest supported tick. Price at the tick is roughly `2^64`.
+pub const MAX_TICK: Tick = 443_636;
+
+/// Square root of the price at `MIN_TICK`.
+pub const MIN_SQRT_PRICE: SqrtPrice = 4_295_048_017;
+/// Square root of the price at `MAX_TICK`.
+pub const MAX_SQRT_PRICE: SqrtPrice = 79_226_673_515_401_279_992_447_579_062;
+
+/// Number of fractional bits of Q64.64 numbers - square root prices and fee growth.
+const RESOLUTION: usize = 64;
+
+/// `2^128 / sqrt(1.0001)^(2^i)` for `i` in `0..19`, rounded to the nearest integer.
+/// Used to compute the square root price of a tick bit by bit.
+const TICK_RATIOS: [u128; 19] = [
+	0xfffcb933bd6fad37aa2d162d1a594001,
+	0xfff97272373d413259a46990580e213a,
+	0xfff2e50f5f656932ef12357cf3c7fdcc,
+	0xffe5caca7e10e4e61c3624eaa0941cd0,
+	0xffcb9843d60f6159c9db58835c926644,
+	0xff973b41fa98c081472e6896dfb254c0,
+	0xff2ea16466c96a3843ec78b326b52861,
+	0xfe5dee046a99a2a811c461f1969c3053,
+	0xfcbe86c7900a88aedcffc83b479aa3a4,
+	0xf987a7253ac413176f2b074cf7815e54,
+	0xf3392b0822b70005940c7a398e4b70f3,
+	0xe7159475a2c29b7443b29c7fa6e889d9,
+	0xd097f3bdfd2022b8845ad8f792aa5825,
+	0xa9f746462d870fdf8a65dc1f90e061e5,
+	0x70d869a156d2a1b890bb3df62baf32f7,
+	0x31be135f97d08fd981231505542fcfa6,
+	0x09aa508b5b7a84e1c677de54f3e99bc9,
+	0x005d6af8dedb81196699c329225ee604,
+	0x00002216e584f5fa1ea926041bedfe98,
+];
+
+fn div_round_up(numerator: U512, denominator: U512) -> Option<U512> {
+	if denominator.is_zero() {
+		return None;
+	}
+	let (quotient, remainder) = numerator.div_mod(denominator);
+	if remainder.is_zero() {
+		Some(quotient)
+	} else {
+		quotient.checked_add(U512::one())
+	}
+}
+
+fn to_u128(value: U512) -> Option<u128> {
+	u128::try_from(value).ok()
+}
+
+/// Calculate square root of the price at given tick as Q64.64 number.
+///
+/// Result is rounded up. Returns `None` if tick is outside of `MIN_TICK..=MAX_TICK`.
+pub fn sqrt_price_at_tick(tick: Tick) -> Option<SqrtPrice> {
+	if !(MIN_TICK..=MAX_TICK).contains(&tick) {
+		return None;
+	}
+
+	let abs_tick = tick.unsigned_abs();
+
+	// Q128.128 ratio of 1.0001^(-|tick|/2)
+	let mut ratio = U256::one() << (2 * RESOLUTION);
+	for (bit, factor) in TICK_RATIOS.iter().enumerate() {
+		if abs_tick & (1u32 << bit) != 0 {
+			ratio = ratio.checked_mul(U256::from(*factor))? >> (2 * RESOLUTION);
+		}
+	}
+
+	if tick > 0 {
+		ratio = U256::MAX.checked_div(ratio)?;
+	}
+
+	let (sqrt_price, remainder) = ratio.div_mod(U256::one() << RESOLUTION);
+	let sqrt_price = if remainder.is_zero() {
+		sqrt_price
+	} else {
+		sqrt_price.checked_add(U256::one())?
+	};
+
+	u128::try_from(sqrt_price).ok()
+}
+
+/// Calculate the greatest tick whose square root price is lower or equal to `sqrt_price`.
+///
+/// Returns `None` if the square root price is outside of `MIN_SQRT_PRICE..=MAX_SQRT_PRICE`.
+pub fn tick_at_sqrt_price(sqrt_price: SqrtPrice) -> Option<Tick> {
+	if !(MIN_SQRT_PRICE..=MAX_SQRT_PRICE).contains(&sqrt_price) {
+		return None;
+	}
+
+	let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+	while low < high {
+		let mid = low + (high - low + 1) / 2;
+		if sqrt_price_at_tick(mid)? <= sqrt_price {
+			low = mid;
+		} else {
+			high = mid - 1;
+		}
+	}
+
+	Some(low)
+}
+
+/// Calculate square root of the price given as a fixed point number.
+///
+/// Returns `None` if the result is outside of `MIN_SQRT_PRICE..=MAX_SQRT_PRICE`.
+pub fn sqrt_price_from_price(price: FixedU128) -> Option<SqrtPrice> {
+	let scaled = (U512::from(price.into_inner()) << (2 * RESOLUTION)).checked_div(U512::from(FixedU128::DIV))?;
+	let sqrt_price = to_u128(scaled.integer_sqrt())?;
+
+	(MIN_SQRT_PRICE..=MAX_SQRT_PRICE)
+		.contains(&sqrt_price)
+		.then_some(sqrt_price)
+}
+
+/// Calculate the price of asset A denominated in asset B as a rational number `(n, d)`.
+pub fn price_from_sqrt_price(sqrt_price: SqrtPrice) -> (Balance, Balance) {
+	// Product of two u128 numbers always fits into U256.
+	let price = U256::from(sqrt_price) * U256::from(sqrt_price);
+
+	round_to_rational((price, U256::one() << (2 * RESOLUTION)), Rounding::Nearest)
+}
+
+/// Calculate amount of asset A between two square root prices for given liquidity.
+///
+/// Formula: `L * (sqrt_upper - sqrt_lower) / (sqrt_upper * sqrt_lower)`
+pub fn amount_a_delta(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Option<Balance> {
+	let (lower, upper) = if sqrt_price_a > sqrt_price_b {
+		(sqrt_price_b, sqrt_price_a)
+	} else {
+		(sqrt_price_a, sqrt_price_b)
+	};
+	if lower.is_zero() {
+		return None;
+	}
+
+	let numerator = (U512::from(liquidity) << RESOLUTION).checked_mul(U512::from(upper - lower))?;
+	let (lower, upper) = (U512::from(lower), U512::from(upper));
+
+	let amount = if round_up {
+		div_round_up(div_round_up(numerator, upper)?, lower)?
+	} else {
+		numerator.checked_div(upper)?.checked_div(lower)?
+	};
+
+	to_u128(amount)
+}
+
+/// Calculate amount of asset B between two square root prices for given liquidity.
+///
+/// Formula: `L * (sqrt_upper - sqrt_lower)`
+pub fn amount_b_delta(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Option<Balance> {
+	let diff = sqrt_price_a.abs_diff(sqrt_price_b);
+	let numerator = U512::from(liquidity).checked_mul(U512::from(diff))?;
+	let denominator = U512::one() << RESOLUTION;
+
+	let amount = if round_up {
+		div_round_up(numerator, denominator)?
+	} else {
+		numerator >> RESOLUTION
+	};
+
+	to_u128(amount)
+}
+
+/// Calculate square root of the price after adding or removing `amount` of asset A.
+/// Result is rounded up, so the price moves less than it would with exact math.
+fn next_sqrt_price_from_amount_a(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount: Balance,
+	add: bool,
+) -> Option<SqrtPrice> {
+	if amount.is_zero() {
+		return Some(sqrt_price);
+	}
+
+	let numerator = U512::from(liquidity) << RESOLUTION;
+	let product = U512::from(amount).checked_mul(U512::from(sqrt_price))?;
+
+	let denominator = if add {
+		numerator.checked_add(product)?
+	} else {
+		numerator.checked_sub(product).filter(|d| !d.is_zero())?
+	};
+
+	let next = div_round_up(numerator.checked_mul(U512::from(sqrt_price))?, denominator)?;
+
+	to_u128(next).filter(|next| !next.is_zero())
+}
+
+/// Calculate square root of the price after adding or removing `amount` of asset B.
+/// Result is rounded down, so the price moves less than it would with exact math.
+fn next_sqrt_price_from_amount_b(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount: Balance,
+	add: bool,
+) -> Option<SqrtPrice> {
+	if liquidity.is_zero() {
+		return None;
+	}
+
+	let numerator = U512::from(amount) << RESOLUTION;
+	let liquidity = U512::from(liquidity);
+
+	if add {
+		let quotient = to_u128(numerator.checked_div(liquidity)?)?;
+		sqrt_price.checked_add(quotient)
+	} else {
+		let quotient = to_u128(div_round_up(numerator, liquidity)?)?;
+		sqrt_price.checked_sub(quotient).filter(|next| !next.is_zero())
+	}
+}
+
+/// Calculate square root of the price after `amount_in` is swapped in.
+///
+/// `a_for_b` is the direction of the swap - asset A is swapped in and the price decreases.
+pub fn next_sqrt_price_from_input(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount_in: Balance,
+	a_for_b: bool,
+) -> Option<SqrtPrice> {
+	if a_for_b {
+		next_sqrt_price_from_amount_a(sqrt_price, liquidity, amount_in, true)
+	} else {
+		next_sqrt_price_from_amount_b(sqrt_price, liquidity, amount_in, true)
+	}
+}
+
+/// Calculate square root of the price after `amount_out` is swapped out.
+///
+/// `a_for_b` is the direction of the swap - asset B is swapped out and the price decreases.
+pub fn next_sqrt_price_from_output(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount_out: Balance,
+	a_for_b: bool,
+) -> Option<SqrtPrice> {
+	if a_for_b {
+		next_sqrt_price_from_amount_b(sqrt_price, liquidity, amount_out, false)
+	} else {
+		next_sqrt_price_from_amount_a(sqrt_price, liquidity, amount_out, false)
+	}
+}
+
+/// Calculate a swap within a single tick range - between the current and the target square root price.
+///
+/// If `exact_in` is true, `amount_remaining` is the amount left to swap in including the fee,
+/// otherwise it is the amount left to swap out.
+///
+/// The swap either reaches the target price or consumes the whole remaining amount.
+/// Direction of the swap is given by the target price - asset A is swapped in if the price decreases.
+pub fn compute_swap_step(
+	sqrt_price_current: SqrtPrice,
+	sqrt_price_target: SqrtPrice,
+	liquidity: Liquidity,
+	amount_remaining: Balance,
+	fee: Permill,
+	exact_in: bool,
+) -> Option<SwapStep> {
+	let a_for_b = sqrt_price_current >= sqrt_price_target;
+	let fee_parts = U256::from(fee.deconstruct());
+	let accuracy = U256::from(Permill::ACCURACY);
+
+	let amount_in_to_target = |round_up: bool| {
+		if a_for_b {
+			amount_a_delta(sqrt_price_target, sqrt_price_current, liquidity, round_up)
+		} else {
+			amount_b_delta(sqrt_price_current, sqrt_price_target, liquidity, round_up)
+		}
+	};
+	let amount_out_to_target = || {
+		if a_for_b {
+			amount_b_delta(sqrt_price_target, sqrt_price_current, liquidity, false)
+		} else {
+			amount_a_delta(sqrt_price_current, sqrt_price_target, liquidity, false)
+		}
+	};
+
+	let (sqrt_price_next, max_amount) = if exact_in {
+		let amount_remaining_less_fee = U256::from(amount_remaining)
+			.checked_mul(accuracy.checked_sub(fee_parts)?)?
+			.checked_div(accuracy)?;
+		let amount_remaining_less_fee = u128::try_from(amount_remaining_less_fee).ok()?;
+		let amount_in = amount_in_to_target(true)?;
+
+		if amount_remaining_less_fee >= amount_in {
+			(sqrt_price_target, amount_in)
+		} else {
+			(
+				next_sqrt_price_from_input(sqrt_price_current, liquidity, amount_remaining_less_fee, a_for_b)?,
+				amount_in,
+			)
+		}
+	} else {
+		let amount_out = amount_out_to_target()?;
+
+		if amount_remaining >= amount_out {
+			(sqrt_price_target, amount_out)
+		} else {
+			(
+				next_sqrt_price_from_output(sqrt_price_current, liquidity, amount_remaining, a_for_b)?,
+				amount_out,
+			)
+		}
+	};
+
+	let target_reached = sqrt_price_next == sqrt_price_target;
+
+	let (amount_in, amount_out) = if a_for_b {
+		let amount_in = if target_reached && exact_in {
+			max_amount
+		} else {
+			amount_a_delta(sqrt_price_next, sqrt_price_current, liquidity, true)?
+		};
+		let amount_out = if target_reached && !exact_in {
+			max_amount
+		} else {
+			amount_b_delta(sqrt_price_next, sqrt_price_current, liquidity, false)?
+		};
+		(amount_in, amount_out)
+	} else {
+		let amount_in = if target_reached && exact_in {
+			max_amount
+		} else {
+			amount_b_delta(sqrt_price_current, sqrt_price_next, liquidity, true)?
+		};
+		let amount_out = if target_reached && !exact_in {
+			max_amount
+		} else {
+			amount_a_delta(sqrt_price_current, sqrt_price_next, liquidity, false)?
+		};
+		(amount_in, amount_out)
+	};
+
+	// The output can not exceed the requested amount due to rounding of the price.
+	let amount_out = if exact_in {
+		amount_out
+	} else {
+		amount_out.min(amount_remaining)
+	};
+
+	let fee_amount = if exact_in && !target_reached {
+		// The whole remaining amount is consumed, the rest of it is taken as the fee.
+		amount_remaining.checked_sub(amount_in)?
+	} else {
+		let fee_amount = div_round_up(
+			U512::from(amount_in).checked_mul(U512::from(fee_parts.low_u32()))?,
+			U512::from(accuracy.checked_sub(fee_parts)?.low_u32()),
+		)?;
+		to_u128(fee_amount)?
+	};
+
+	Some(SwapStep {
+		sqrt_price_next,
+		amount_in,
+		amount_out,
+		fee_amount,
+	})
+}
+
+/// Calculate liquidity provided by `amount_a` of asset A between two square root prices.
+///
+/// Formula: `amount_a * sqrt_upper * sqrt_lower / (sqrt_upper - sqrt_lower)`
+pub fn liquidity_for_amount_a(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	amount_a: Balance,
+) -> Option<Liquidity> {
+	let (lower, upper) = if sqrt_price_a > sqrt_price_b {
+		(sqrt_price_b, sqrt_price_a)
+	} else {
+		(sqrt_price_a, sqrt_price_b)
+	};
+
+	let numerator = U512::from(amount_a)
+		.checked_mul(U512::from(lower))?
+		.checked_mul(U512::from(upper))?;
+	let denominator = U512::from(upper.checked_sub(lower)?) << RESOLUTION;
+
+	to_u128(numerator.checked_div(denominator)?)
+}
+
+/// Calculate liquidity provided by `amount_b` of asset B between two square root prices.
+///
+/// Formula: `amount_b / (sqrt_upper - sqrt_lower)`
+pub fn liquidity_for_amount_b(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	amount_b: Balance,
+) -> Option<Liquidity> {
+	let diff = sqrt_price_a.abs_diff(sqrt_price_b);
+
+	to_u128((U512::from(amount_b) << RESOLUTION).checked_div(U512::from(diff))?)
+}
+
+/// Calculate maximum liquidity which can be provided by given amounts of asset A and B to a price range,
+/// given the current square root price of the pool.
+pub fn liquidity_for_amounts(
+	sqrt_price: SqrtPrice,
+	sqrt_price_lower: SqrtPrice,
+	sqrt_price_upper: SqrtPrice,
+	amount_a: Balance,
+	amount_b: Balance,
+) -> Option<Liquidity> {
+	if sqrt_price_lower >= sqrt_price_upper {
+		return None;
+	}
+
+	if sqrt_price <= sqrt_price_lower {
+		liquidity_for_amount_a(sqrt_price_lower, sqrt_price_upper, amount_a)
+	} else if sqrt_price < sqrt_price_upper {
+		let liquidity_a = liquidity_for_amount_a(sqrt_price, sqrt_price_upper, amount_a)?;
+		let liquidity_b = liquidity_for_amount_b(sqrt_price_lower, sqrt_price, amount_b)?;
+		Some(liquidity_a.min(liquidity_b))
+	} else {
+		liquidity_for_amount_b(sqrt_price_lower, sqrt_price_upper, amount_b)
+	}
+}
+
+/// Calculate amounts of asset A and B backing `liquidity` in a price range,
+/// given the current square root price of the pool.
+///
+/// Amounts should be rounded up when liquidity is added and rounded down when liquidity is removed.
+pub fn amounts_for_liquidity(
+	sqrt_price: SqrtPrice,
+	sqrt_price_lower: SqrtPrice,
+	sqrt_price_upper: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Option<(Balance, Balance)> {
+	if sqrt_price_lower >= sqrt_price_upper {
+		return None;
+	}
+
+	if sqrt_price <= sqrt_price_lower {
+		Some((
+			amount_a_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?,
+			0,
+		))
+	} else if sqrt_price < sqrt_price_upper {
+		Some((
+			amount_a_delta(sqrt_price, sqrt_price_upper, liquidity, round_up)?,
+			amount_b_delta(sqrt_price_lower, sqrt_price, liquidity, round_up)?,
+		))
+	} else {
+		Some((
+			0,
+			amount_b_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?,
+		))
+	}
+}
+
+/// Calculate increase of the fee growth per unit of liquidity as Q64.64 number.
+pub fn fee_growth_delta(fee_amount: Balance, liquidity: Liquidity) -> Option<u128> {
+	if liquidity.is_zero() {
+		return Some(0);
+	}
+
+	to_u128((U512::from(fee_amount) << RESOLUTION).checked_div(U512::from(liquidity))?)
+}
+
+/// Calculate fees earned by `liquidity` for given growth of fees per unit of liquidity.
+///
+/// Fee growth is tracked with wrapping arithmetic, `fee_growth_delta` is the wrapping difference
+/// of the fee growth at two points in time.
+pub fn fees_earned(fee_growth_delta: u128, liquidity: Liquidity) -> Option<Balance> {
+	to_u128(U512::from(fee_growth_delta).checked_mul(U512::from(liquidity))? >> RESOLUTION)
+}
+
+/// Position of a compressed tick in the tick bitmap - index of the word and the bit in the word.
+pub fn tick_position(compressed_tick: Tick) -> (i16, u8) {
+	((compressed_tick >> 8) as i16, (compressed_tick & 0xff) as u8)
+}
+
+/// Find the next initialized tick in the same bitmap word as the current tick.
+///
+/// If `lte` is true, the search goes to the left, to the ticks lower or equal to `tick`.
+/// Otherwise, it goes to the right, to the ticks greater than `tick`.
+///
+/// `word` provides the bitmap word at given index. Only one word is read.
+///
+/// Returns the next initialized tick and `true` or, if there is none in the word,
+/// the last tick of the word and `false`. The returned tick can be outside of `MIN_TICK..=MAX_TICK`.
+pub fn next_initialized_tick_within_one_word(
+	tick: Tick,
+	tick_spacing: u16,
+	lte: bool,
+	word: impl Fn(i16) -> U256,
+) -> (Tick, bool) {
+	let spacing = Tick::from(tick_spacing.max(1));
+	let compressed = tick.div_euclid(spacing);
+
+	if lte {
+		let (word_pos, bit_pos) = tick_position(compressed);
+		let bit = U256::one() << bit_pos;
+		let masked = word(word_pos) & (bit - U256::one() + bit);
+		let bit_pos = Tick::from(bit_pos);
+
+		if masked.is_zero() {
+			((compressed - bit_pos) * spacing, false)
+		} else {
+			let most_significant_bit = (masked.bits() - 1) as Tick;
+			((compressed - (bit_pos - most_significant_bit)) * spacing, true)
+		}
+	} else {
+		let (word_pos, bit_pos) = tick_position(compressed + 1);
+		let masked = word(word_pos) & !((U256::one() << bit_pos) - U256::one());
+		let bit_pos = Tick::from(bit_pos);
+
+		if masked.is_zero() {
+			((compressed + 1 + (255 - bit_pos)) * spacing, false)
+		} else {
+			let least_significant_bit = masked.trailing_zeros() as Tick;
+			((compressed + 1 + (least_significant_bit - bit_pos)) * spacing, true)
+		}
+	}
+}
@@ -0,0 +1,10 @@
+mod math;
+
+#[cfg(test)]
+mod invariants;
+#[cfg(test)]
+mod tests;
+
+pub mod types;
+
+pub use math::*;
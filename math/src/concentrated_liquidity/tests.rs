@@ -0,0 +1,293 @@
+use crate::concentrated_liquidity::types::SwapStep;
+use crate::concentrated_liquidity::*;
+use primitive_types::U256;
+use sp_arithmetic::{FixedU128, Permill};
+
+const ONE: u128 = 1_000_000_000_000;
+const Q64: u128 = 1 << 64;
+
+#[test]
+fn sqrt_price_at_tick_should_work() {
+	let cases = vec![
+		(0, Some(Q64), "Zero tick"),
+		(1, Some(18_447_666_387_855_959_851), "Tick 1"),
+		(-1, Some(18_445_821_805_675_392_312), "Tick -1"),
+		(100, Some(18_539_204_128_674_405_813), "Tick 100"),
+		(-100, Some(18_354_745_142_194_483_564), "Tick -100"),
+		(50_000, Some(224_699_260_982_037_790_825), "Tick 50000"),
+		(-50_000, Some(1_514_390_236_237_315_697), "Tick -50000"),
+		(MIN_TICK, Some(MIN_SQRT_PRICE), "Min tick"),
+		(MAX_TICK, Some(MAX_SQRT_PRICE), "Max tick"),
+		(MIN_TICK - 1, None, "Below min tick"),
+		(MAX_TICK + 1, None, "Above max tick"),
+	];
+
+	for (tick, expected, desc) in cases {
+		assert_eq!(sqrt_price_at_tick(tick), expected, "{}", desc);
+	}
+}
+
+#[test]
+fn tick_at_sqrt_price_should_work() {
+	let cases = vec![
+		(Q64, Some(0), "Price 1"),
+		(Q64 - 1, Some(-1), "Slightly below price 1"),
+		(Q64 + 1, Some(0), "Slightly above price 1"),
+		(MIN_SQRT_PRICE, Some(MIN_TICK), "Min price"),
+		(MAX_SQRT_PRICE, Some(MAX_TICK), "Max price"),
+		(MAX_SQRT_PRICE - 1, Some(MAX_TICK - 1), "Slightly below max price"),
+		(MIN_SQRT_PRICE - 1, None, "Below min price"),
+		(MAX_SQRT_PRICE + 1, None, "Above max price"),
+	];
+
+	for (sqrt_price, expected, desc) in cases {
+		assert_eq!(tick_at_sqrt_price(sqrt_price), expected, "{}", desc);
+	}
+}
+
+#[test]
+fn sqrt_price_from_price_should_work() {
+	assert_eq!(sqrt_price_from_price(FixedU128::from(1)), Some(Q64));
+	assert_eq!(sqrt_price_from_price(FixedU128::from(4)), Some(2 * Q64));
+	assert_eq!(sqrt_price_from_price(FixedU128::from_rational(1, 4)), Some(Q64 / 2));
+	assert_eq!(sqrt_price_from_price(FixedU128::from_inner(u128::MAX)), None);
+}
+
+#[test]
+fn price_from_sqrt_price_should_work() {
+	let (n, d) = price_from_sqrt_price(2 * Q64);
+	assert_eq!(n, 4 * d);
+
+	let (n, d) = price_from_sqrt_price(Q64);
+	assert_eq!(n, d);
+}
+
+#[test]
+fn amount_deltas_should_be_rounded_correctly() {
+	let lower = sqrt_price_at_tick(-1_000).unwrap();
+	let upper = sqrt_price_at_tick(1_000).unwrap();
+	let liquidity = 2_050_516_626_810_664;
+
+	assert_eq!(
+		amount_a_delta(lower, upper, liquidity, false),
+		Some(205_126_846_837_676)
+	);
+	assert_eq!(amount_a_delta(lower, upper, liquidity, true), Some(205_126_846_837_677));
+	assert_eq!(amount_a_delta(upper, lower, liquidity, true), Some(205_126_846_837_677));
+	assert_eq!(
+		amount_b_delta(lower, upper, liquidity, false),
+		Some(205_126_846_837_676)
+	);
+	assert_eq!(amount_b_delta(lower, upper, liquidity, true), Some(205_126_846_837_677));
+	assert_eq!(amount_a_delta(0, upper, liquidity, true), None);
+}
+
+#[test]
+fn liquidity_for_amounts_should_work() {
+	let lower = sqrt_price_at_tick(-1_000).unwrap();
+	let upper = sqrt_price_at_tick(1_000).unwrap();
+
+	// Price in range
+	assert_eq!(
+		liquidity_for_amounts(Q64, lower, upper, 100 * ONE, 100 * ONE),
+		Some(2_050_516_626_810_664)
+	);
+	// Price below range - only asset A is used
+	assert_eq!(
+		liquidity_for_amounts(lower - 1, lower, upper, 100 * ONE, 0),
+		Some(999_633_474_809_517)
+	);
+	// Price above range - only asset B is used
+	assert_eq!(
+		liquidity_for_amounts(upper, lower, upper, 0, 100 * ONE),
+		Some(999_633_474_809_517)
+	);
+	// Invalid range
+	assert_eq!(liquidity_for_amounts(Q64, upper, lower, 100 * ONE, 100 * ONE), None);
+}
+
+#[test]
+fn amounts_for_liquidity_should_work() {
+	let lower = sqrt_price_at_tick(-1_000).unwrap();
+	let upper = sqrt_price_at_tick(1_000).unwrap();
+	let liquidity = 2_050_516_626_810_664;
+
+	assert_eq!(
+		amounts_for_liquidity(Q64, lower, upper, liquidity, true),
+		Some((100 * ONE, 100 * ONE))
+	);
+	assert_eq!(
+		amounts_for_liquidity(Q64, lower, upper, liquidity, false),
+		Some((100 * ONE - 1, 100 * ONE - 1))
+	);
+	assert_eq!(
+		amounts_for_liquidity(lower, lower, upper, liquidity, false),
+		Some((205_126_846_837_676, 0))
+	);
+	assert_eq!(
+		amounts_for_liquidity(upper, lower, upper, liquidity, false),
+		Some((0, 205_126_846_837_676))
+	);
+}
+
+#[test]
+fn compute_swap_step_should_work() {
+	let lower = sqrt_price_at_tick(-1_000).unwrap();
+	let upper = sqrt_price_at_tick(1_000).unwrap();
+	let liquidity = 2_050_516_626_810_664;
+	let fee = Permill::from_rational(3u32, 1000u32);
+
+	let cases = vec![
+		(
+			lower,
+			10 * ONE,
+			true,
+			SwapStep {
+				sqrt_price_next: 18_357_486_499_298_813_205,
+				amount_in: 9_970_000_000_000,
+				amount_out: 9_921_758_531_840,
+				fee_amount: 30_000_000_000,
+			},
+			"Sell asset A within range",
+		),
+		(
+			upper,
+			10 * ONE,
+			true,
+			SwapStep {
+				sqrt_price_next: 18_536_435_635_343_431_540,
+				amount_in: 9_970_000_000_000,
+				amount_out: 9_921_758_531_840,
+				fee_amount: 30_000_000_000,
+			},
+			"Sell asset B within range",
+		),
+		(
+			lower,
+			10_000 * ONE,
+			true,
+			SwapStep {
+				sqrt_price_next: lower,
+				amount_in: 105_126_846_837_677,
+				amount_out: 99_999_999_999_999,
+				fee_amount: 316_329_529_101,
+			},
+			"Sell asset A up to the target price",
+		),
+		(
+			upper,
+			10 * ONE,
+			false,
+			SwapStep {
+				sqrt_price_next: 18_537_146_395_510_486_361,
+				amount_in: 10_049_007_196_848,
+				amount_out: 10 * ONE,
+				fee_amount: 30_237_734_795,
+			},
+			"Buy asset A within range",
+		),
+		(
+			upper,
+			10_000 * ONE,
+			false,
+			SwapStep {
+				sqrt_price_next: upper,
+				amount_in: 105_126_846_837_677,
+				amount_out: 99_999_999_999_999,
+				fee_amount: 316_329_529_101,
+			},
+			"Buy asset A up to the target price",
+		),
+	];
+
+	for (target, amount, exact_in, expected, desc) in cases {
+		assert_eq!(
+			compute_swap_step(Q64, target, liquidity, amount, fee, exact_in),
+			Some(expected),
+			"{}",
+			desc
+		);
+	}
+}
+
+#[test]
+fn compute_swap_step_should_move_to_target_when_there_is_no_liquidity() {
+	let target = sqrt_price_at_tick(-1_000).unwrap();
+
+	assert_eq!(
+		compute_swap_step(Q64, target, 0, 10 * ONE, Permill::from_percent(1), true),
+		Some(SwapStep {
+			sqrt_price_next: target,
+			amount_in: 0,
+			amount_out: 0,
+			fee_amount: 0,
+		})
+	);
+}
+
+#[test]
+fn fee_growth_should_be_distributed_by_liquidity() {
+	let growth = fee_growth_delta(3 * ONE, 2 * ONE).unwrap();
+	assert_eq!(growth, 3 * Q64 / 2);
+
+	assert_eq!(fees_earned(growth, ONE), Some(3 * ONE / 2));
+	assert_eq!(fees_earned(growth, 2 * ONE), Some(3 * ONE));
+	assert_eq!(fee_growth_delta(ONE, 0), Some(0));
+
+	// Fee growth is tracked with wrapping arithmetic
+	let before = u128::MAX - growth / 2;
+	let after = before.wrapping_add(growth);
+	assert_eq!(fees_earned(after.wrapping_sub(before), 2 * ONE), Some(3 * ONE));
+}
+
+fn bitmap(ticks: &[i32], tick_spacing: u16) -> impl Fn(i16) -> U256 {
+	let positions: Vec<(i16, u8)> = ticks
+		.iter()
+		.map(|tick| tick_position(tick.div_euclid(i32::from(tick_spacing))))
+		.collect();
+
+	move |word_pos| {
+		positions
+			.iter()
+			.filter(|(word, _)| *word == word_pos)
+			.fold(U256::zero(), |word, (_, bit)| word | (U256::one() << *bit))
+	}
+}
+
+#[test]
+fn next_initialized_tick_within_one_word_should_work() {
+	let word = bitmap(&[0, 10, -5], 1);
+
+	let cases = vec![
+		(5, true, (0, true), "Lower initialized tick"),
+		(10, true, (10, true), "Current tick is initialized"),
+		(5, false, (10, true), "Greater initialized tick"),
+		(10, false, (255, false), "No greater tick in the word"),
+		(-1, true, (-5, true), "Negative initialized tick"),
+		(-6, true, (-256, false), "No lower tick in the word"),
+		(-6, false, (-5, true), "Greater negative initialized tick"),
+	];
+
+	for (tick, lte, expected, desc) in cases {
+		assert_eq!(
+			next_initialized_tick_within_one_word(tick, 1, lte, &word),
+			expected,
+			"{}",
+			desc
+		);
+	}
+}
+
+#[test]
+fn next_initialized_tick_within_one_word_should_respect_tick_spacing() {
+	let word = bitmap(&[40, -30], 10);
+
+	assert_eq!(next_initialized_tick_within_one_word(25, 10, false, &word), (40, true));
+	assert_eq!(next_initialized_tick_within_one_word(45, 10, true, &word), (40, true));
+	assert_eq!(next_initialized_tick_within_one_word(39, 10, true, &word), (0, false));
+	assert_eq!(next_initialized_tick_within_one_word(-25, 10, true, &word), (-30, true));
+	assert_eq!(
+		next_initialized_tick_within_one_word(-35, 10, false, &word),
+		(-30, true)
+	);
+}
@@ -0,0 +1,24 @@
+use crate::types::Balance;
+use sp_core::RuntimeDebug;
+
+/// Index of a price tick. Price at tick `i` is `1.0001^i`.
+pub type Tick = i32;
+
+/// Square root of a price as an unsigned Q64.64 fixed point number.
+pub type SqrtPrice = u128;
+
+/// Amount of virtual liquidity `L = sqrt(x * y)`.
+pub type Liquidity = u128;
+
+/// Result of a swap within a single tick range.
+#[derive(Clone, Copy, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct SwapStep {
+	/// Square root of the price after the step.
+	pub sqrt_price_next: SqrtPrice,
+	/// Amount swapped in, without the fee.
+	pub amount_in: Balance,
+	/// Amount swapped out.
+	pub amount_out: Balance,
+	/// Fee paid in the asset swapped in.
+	pub fee_amount: Balance,
+}
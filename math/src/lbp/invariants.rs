@@ -52,3 +52,105 @@ proptest! {
 		assert_approx_eq!(a1*a2, b1*b2, max_delta, "The invariant does not hold")
 	}
 }
+
+fn weight() -> impl Strategy<Value = u32> {
+	2_000_000..98_000_000u32
+}
+
+fn steps() -> impl Strategy<Value = Vec<(u32, u32)>> {
+	prop::collection::vec((0..ONE_DAY_IN_BLOCKS, weight()), 0..10)
+}
+
+/// Weight is between the initial and the final weight.
+fn assert_weight_within_bounds(weight: u32, start_y_weight: u32, end_y_weight: u32) {
+	let (lower, upper) = if start_y_weight <= end_y_weight {
+		(start_y_weight, end_y_weight)
+	} else {
+		(end_y_weight, start_y_weight)
+	};
+	assert!(lower <= weight && weight <= upper, "The weight is out of bounds");
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn non_linear_weights_should_stay_within_bounds_and_move_monotonically(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		start_y_weight in weight(),
+		end_y_weight in weight()) {
+		//Arrange
+		let end_x_block = start_x_block.checked_add(lbp_length).unwrap();
+		let at_block = rand::thread_rng().gen_range(start_x_block..end_x_block);
+
+		for calculate_weights in [lbp::calculate_exponential_weights::<u32>, lbp::calculate_logarithmic_weights::<u32>] {
+			//Act
+			let weight = calculate_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, at_block).unwrap();
+			let next_weight = calculate_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, at_block + 1).unwrap();
+
+			//Assert
+			assert_weight_within_bounds(weight, start_y_weight, end_y_weight);
+			if start_y_weight <= end_y_weight {
+				assert!(weight <= next_weight, "The weight does not move towards the final weight");
+			} else {
+				assert!(weight >= next_weight, "The weight does not move towards the final weight");
+			}
+			assert_eq!(calculate_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, start_x_block), Ok(start_y_weight));
+			assert_eq!(calculate_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, end_x_block), Ok(end_y_weight));
+		}
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn non_linear_weights_should_change_faster_than_linear_weights_at_the_beginning(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		start_y_weight in initial_weight(),
+		end_y_weight in final_weight()) {
+		//Arrange
+		let end_x_block = start_x_block.checked_add(lbp_length).unwrap();
+		let at_block = rand::thread_rng().gen_range(start_x_block..end_x_block);
+
+		//Act
+		let linear = lbp::calculate_linear_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, at_block).unwrap();
+		let exponential = lbp::calculate_exponential_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, at_block).unwrap();
+		let logarithmic = lbp::calculate_logarithmic_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, at_block).unwrap();
+
+		//Assert
+		// both curves are concave, so the weights are never behind the linear weights (up to rounding)
+		assert!(exponential + 1 >= linear, "Exponential weight is behind the linear weight");
+		assert!(logarithmic + 1 >= linear, "Logarithmic weight is behind the linear weight");
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn step_weights_should_be_one_of_the_scheduled_weights(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		start_y_weight in weight(),
+		end_y_weight in weight(),
+		steps in steps()) {
+		//Arrange
+		let end_x_block = start_x_block.checked_add(lbp_length).unwrap();
+		let at_block = rand::thread_rng().gen_range(start_x_block..=end_x_block);
+
+		//Act
+		let weight = lbp::calculate_step_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, &steps, at_block).unwrap();
+
+		//Assert
+		assert!(
+			weight == start_y_weight || weight == end_y_weight || steps.iter().any(|(_, w)| *w == weight),
+			"The weight is not scheduled"
+		);
+		if at_block == end_x_block {
+			assert_eq!(weight, end_y_weight);
+		}
+		if steps.iter().all(|(offset, _)| *offset > at_block - start_x_block) && at_block != end_x_block {
+			assert_eq!(weight, start_y_weight);
+		}
+	}
+}
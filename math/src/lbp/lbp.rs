@@ -211,6 +211,158 @@ pub fn calculate_linear_weights<BlockNumber: num_traits::CheckedSub + TryInto<u3
 	to_lbp_weight!(result)
 }
 
+/// Steepness `k` of the exponential weight curve.
+pub const EXPONENTIAL_CURVE_STEEPNESS: u32 = 5;
+
+/// Scale `c` of the logarithmic weight curve.
+pub const LOGARITHMIC_CURVE_SCALE: u32 = 99;
+
+/// Calculating weight at any given block in an interval using exponential decay.
+///
+/// The distance to the final weight decays exponentially, so most of the weight change happens at the
+/// beginning of the interval.
+/// Formula: `start_y + (end_y - start_y) * (1 - e^(-k * x)) / (1 - e^(-k))`, where `x` is the elapsed
+/// fraction of the interval.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_exponential_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let x = elapsed_fraction(start_x, end_x, at)?;
+
+	let k = U32F96::from_num(EXPONENTIAL_CURVE_STEEPNESS);
+	let kx = x.checked_mul(k).ok_or(Overflow)?;
+
+	let e_kx: U32F96 = crate::transcendental::exp(kx, true).map_err(|_| Overflow)?;
+	let e_k: U32F96 = crate::transcendental::exp(k, true).map_err(|_| Overflow)?;
+
+	let progress = U32F96::one()
+		.checked_sub(e_kx)
+		.ok_or(Overflow)?
+		.checked_div(U32F96::one().checked_sub(e_k).ok_or(Overflow)?)
+		.ok_or(Overflow)?;
+
+	interpolate_weight(start_y, end_y, progress)
+}
+
+/// Calculating weight at any given block in an interval using logarithmic curve.
+///
+/// Formula: `start_y + (end_y - start_y) * ln(1 + c * x) / ln(1 + c)`, where `x` is the elapsed fraction of
+/// the interval.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_logarithmic_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let x = elapsed_fraction(start_x, end_x, at)?;
+
+	let c = U32F96::from_num(LOGARITHMIC_CURVE_SCALE);
+	let cx = x.checked_mul(c).ok_or(Overflow)?;
+
+	let (ln_cx, _) = crate::transcendental::ln::<U32F96, U32F96>(U32F96::one().checked_add(cx).ok_or(Overflow)?)
+		.map_err(|_| Overflow)?;
+	let (ln_c, _) = crate::transcendental::ln::<U32F96, U32F96>(U32F96::one().checked_add(c).ok_or(Overflow)?)
+		.map_err(|_| Overflow)?;
+
+	let progress = ln_cx.checked_div(ln_c).ok_or(Overflow)?;
+
+	interpolate_weight(start_y, end_y, progress)
+}
+
+/// Calculating weight at any given block in an interval using a step schedule.
+///
+/// Weight is `start_y` until the first step is reached. Each step `(offset, weight)` sets the weight from
+/// `start_x + offset` onwards. Weight is `end_y` at the end of the interval.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `steps` - list of steps, offset of a step is the number of blocks from `start_x`
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_step_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	steps: &[(u32, LBPWeight)],
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let (elapsed, duration) = elapsed_blocks(start_x, end_x, at)?;
+
+	if elapsed == duration {
+		return Ok(end_y);
+	}
+
+	Ok(steps
+		.iter()
+		.filter(|(offset, _)| *offset <= elapsed)
+		.max_by_key(|(offset, _)| *offset)
+		.map(|(_, weight)| *weight)
+		.unwrap_or(start_y))
+}
+
+/// Number of blocks elapsed since `start_x` and the length of the interval.
+fn elapsed_blocks<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	at: BlockNumber,
+) -> Result<(u32, u32), MathError> {
+	// at has to be within the interval
+	end_x.checked_sub(&at).ok_or(Overflow)?;
+	let elapsed = at.checked_sub(&start_x).ok_or(Overflow)?;
+	let duration = end_x.checked_sub(&start_x).ok_or(Overflow)?;
+
+	let duration: u32 = duration.try_into().map_err(|_| Overflow)?;
+	// if duration fits into u32, elapsed fits into u32
+	let elapsed: u32 = elapsed.try_into().map_err(|_| Overflow)?;
+
+	ensure!(duration != 0, ZeroDuration);
+
+	Ok((elapsed, duration))
+}
+
+/// Elapsed fraction of an interval, between 0 and 1.
+fn elapsed_fraction<BlockNumber: num_traits::CheckedSub + TryInto<u32> + TryInto<u128>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	at: BlockNumber,
+) -> Result<U32F96, MathError> {
+	let (elapsed, duration) = elapsed_blocks(start_x, end_x, at)?;
+
+	div_to_fixed(elapsed.into(), duration.into(), Rounding::Down).ok_or(Overflow)
+}
+
+/// Move the weight from `start_y` towards `end_y` by `progress`, where `progress` is between 0 and 1.
+fn interpolate_weight(start_y: LBPWeight, end_y: LBPWeight, progress: U32F96) -> Result<LBPWeight, MathError> {
+	// rounding in the transcendental functions must not move the weight beyond the final weight
+	let progress = progress.min(U32F96::one());
+
+	if start_y <= end_y {
+		let delta = mul_to_balance((end_y - start_y).into(), progress, Rounding::Down).ok_or(Overflow)?;
+		start_y.checked_add(to_lbp_weight!(delta)?).ok_or(Overflow)
+	} else {
+		let delta = mul_to_balance((start_y - end_y).into(), progress, Rounding::Down).ok_or(Overflow)?;
+		start_y.checked_sub(to_lbp_weight!(delta)?).ok_or(Overflow)
+	}
+}
+
 /// Create a fixed point number based on two `u128` values. Divides the values and rounds according to `r`.
 pub(crate) fn div_to_fixed(num: u128, denom: u128, r: Rounding) -> Option<U32F96> {
 	let bits = multiply_by_rational_with_rounding(num, U32F96::one().to_bits(), denom, r)?;
@@ -1,4 +1,5 @@
 #![allow(clippy::type_complexity)]
+use crate::assert_approx_eq;
 use crate::lbp::lbp;
 
 use crate::types::{Balance, LBPWeight, HYDRA_ONE};
@@ -219,3 +220,201 @@ fn linear_weights_should_work() {
 		);
 	}
 }
+
+#[test]
+fn exponential_weights_should_work() {
+	let cases = vec![
+		(
+			100u32,
+			200u32,
+			10_000_000u32,
+			90_000_000u32,
+			100u32,
+			10_000_000u32,
+			"Initial weight",
+		),
+		(
+			100u32,
+			200u32,
+			10_000_000u32,
+			90_000_000u32,
+			110u32,
+			41_691_080u32,
+			"Increasing weight",
+		),
+		(
+			100u32,
+			200u32,
+			10_000_000u32,
+			90_000_000u32,
+			150u32,
+			83_931_345u32,
+			"Increasing weight",
+		),
+		(
+			100u32,
+			200u32,
+			10_000_000u32,
+			90_000_000u32,
+			190u32,
+			89_647_943u32,
+			"Increasing weight",
+		),
+		(
+			100u32,
+			200u32,
+			10_000_000u32,
+			90_000_000u32,
+			200u32,
+			90_000_000u32,
+			"Final weight",
+		),
+		(
+			100u32,
+			200u32,
+			90_000_000u32,
+			10_000_000u32,
+			150u32,
+			16_068_654u32,
+			"Decreasing weight",
+		),
+		(
+			100u32,
+			200u32,
+			90_000_000u32,
+			10_000_000u32,
+			200u32,
+			10_000_000u32,
+			"Final decreasing weight",
+		),
+		(100u32, 200u32, 2_000u32, 2_000u32, 170u32, 2_000u32, "Constant weight"),
+	];
+
+	for case in cases {
+		let weight = lbp::calculate_exponential_weights(case.0, case.1, case.2, case.3, case.4).unwrap();
+		assert_approx_eq!(weight, case.5, 10, case.6);
+	}
+}
+
+#[test]
+fn logarithmic_weights_should_work() {
+	let cases = vec![
+		(
+			100u32,
+			200u32,
+			10_000_000u32,
+			90_000_000u32,
+			100u32,
+			10_000_000u32,
+			"Initial weight",
+		),
+		(
+			100u32,
+			200u32,
+			10_000_000u32,
+			90_000_000u32,
+			110u32,
+			51_497_059u32,
+			"Increasing weight",
+		),
+		(
+			100u32,
+			200u32,
+			10_000_000u32,
+			90_000_000u32,
+			150u32,
+			78_131_655u32,
+			"Increasing weight",
+		),
+		(
+			100u32,
+			200u32,
+			10_000_000u32,
+			90_000_000u32,
+			190u32,
+			88_188_991u32,
+			"Increasing weight",
+		),
+		(
+			100u32,
+			200u32,
+			10_000_000u32,
+			90_000_000u32,
+			200u32,
+			90_000_000u32,
+			"Final weight",
+		),
+		(100u32, 200u32, 2_000u32, 2_000u32, 170u32, 2_000u32, "Constant weight"),
+	];
+
+	for case in cases {
+		let weight = lbp::calculate_logarithmic_weights(case.0, case.1, case.2, case.3, case.4).unwrap();
+		assert_approx_eq!(weight, case.5, 10, case.6);
+	}
+}
+
+#[test]
+fn step_weights_should_work() {
+	let steps = [(10u32, 70_000_000u32), (50u32, 50_000_000u32), (90u32, 30_000_000u32)];
+	let cases = vec![
+		(100u32, 80_000_000, "Initial weight"),
+		(109u32, 80_000_000, "Before first step"),
+		(110u32, 70_000_000, "First step"),
+		(149u32, 70_000_000, "Before second step"),
+		(150u32, 50_000_000, "Second step"),
+		(199u32, 30_000_000, "Last step"),
+		(200u32, 20_000_000, "Final weight"),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_step_weights(100u32, 200u32, 80_000_000, 20_000_000, &steps, case.0),
+			Ok(case.1),
+			"{}",
+			case.2
+		);
+	}
+
+	assert_eq!(
+		lbp::calculate_step_weights(100u32, 200u32, 80_000_000, 20_000_000, &[], 150u32),
+		Ok(80_000_000),
+		"No steps"
+	);
+}
+
+#[test]
+fn non_linear_weights_should_fail_when_interval_is_invalid() {
+	let cases = vec![
+		(200u32, 100u32, 170u32, Err(Overflow), "Invalid interval"),
+		(100u32, 100u32, 100u32, Err(ZeroDuration), "Invalid interval"),
+		(100u32, 200u32, 10u32, Err(Overflow), "Out of bound"),
+		(100u32, 200u32, 210u32, Err(Overflow), "Out of bound"),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_exponential_weights(case.0, case.1, 1_000u32, 2_000u32, case.2),
+			case.3,
+			"{}",
+			case.4
+		);
+		assert_eq!(
+			lbp::calculate_logarithmic_weights(case.0, case.1, 1_000u32, 2_000u32, case.2),
+			case.3,
+			"{}",
+			case.4
+		);
+		assert_eq!(
+			lbp::calculate_step_weights(case.0, case.1, 1_000u32, 2_000u32, &[(5, 1_500)], case.2),
+			case.3,
+			"{}",
+			case.4
+		);
+	}
+
+	assert_eq!(
+		lbp::calculate_exponential_weights(100u64, u64::MAX, 1_000u32, 2_000u32, 200u64),
+		Err(Overflow),
+		"Interval too long"
+	);
+}
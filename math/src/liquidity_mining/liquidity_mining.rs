@@ -151,3 +151,66 @@ pub fn calculate_global_farm_rewards<Period: num_traits::CheckedSub + TryInto<u3
 
 	Ok(rewards.min(max_reward_for_periods))
 }
+
+/// This function calculates rewards [`Balance`] streamed in `elapsed_periods` from
+/// `left_to_distribute` which should be evenly distributed over `remaining_periods` or error.
+///
+/// `rewards = left_to_distribute * elapsed_periods / remaining_periods`
+pub fn calculate_streamed_rewards(
+	left_to_distribute: Balance,
+	elapsed_periods: u128,
+	remaining_periods: u128,
+) -> Result<Balance, MathError> {
+	if remaining_periods == 0 || elapsed_periods >= remaining_periods {
+		return Ok(left_to_distribute);
+	}
+
+	let rewards = U128::from(left_to_distribute)
+		.full_mul(elapsed_periods.into())
+		.checked_div(remaining_periods.into())
+		.ok_or(MathError::DivisionByZero)?;
+
+	to_balance!(rewards)
+}
+
+/// This function calculates estimated APR of the yield farm or error.
+///
+/// `global_farm_rewards = min(total_shares_z * price_adjustment * yield_per_period, max_reward_per_period)`
+/// `apr = global_farm_rewards * multiplier * periods_per_year / (total_shares_z * price_adjustment)`
+///
+/// APR is not capped by `max_reward_per_period` if there are no shares in the global farm.
+pub fn calculate_yield_farm_apr(
+	total_shares_z: Balance,
+	price_adjustment: FixedU128,
+	yield_per_period: FixedU128,
+	max_reward_per_period: Balance,
+	multiplier: FixedU128,
+	periods_per_year: u128,
+) -> Result<FixedU128, MathError> {
+	let yearly_multiplier = multiplier
+		.checked_mul(&FixedU128::from(periods_per_year))
+		.ok_or(MathError::Overflow)?;
+
+	let total_shares_z_adjusted = price_adjustment
+		.checked_mul_int(total_shares_z)
+		.ok_or(MathError::Overflow)?;
+
+	if total_shares_z_adjusted == 0 {
+		return yield_per_period
+			.checked_mul(&yearly_multiplier)
+			.ok_or(MathError::Overflow);
+	}
+
+	let global_farm_rewards = calculate_global_farm_rewards(
+		total_shares_z,
+		price_adjustment,
+		yield_per_period,
+		max_reward_per_period,
+		1_u128,
+	)?;
+
+	FixedU128::checked_from_rational(global_farm_rewards, total_shares_z_adjusted)
+		.ok_or(MathError::DivisionByZero)?
+		.checked_mul(&yearly_multiplier)
+		.ok_or(MathError::Overflow)
+}
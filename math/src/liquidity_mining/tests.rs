@@ -954,3 +954,116 @@ fn calculate_global_farm_rewards_should_work() {
 		);
 	}
 }
+
+#[test]
+fn calculate_streamed_rewards_should_work() {
+	let testing_values = vec![
+		(1_000_000_u128, 1_u128, 10_u128, 100_000_u128),
+		(1_000_000_u128, 3_u128, 10_u128, 300_000_u128),
+		(1_000_000_u128, 10_u128, 10_u128, 1_000_000_u128),
+		(1_000_000_u128, 15_u128, 10_u128, 1_000_000_u128),
+		(1_000_000_u128, 0_u128, 10_u128, 0_u128),
+		(1_000_000_u128, 1_u128, 3_u128, 333_333_u128),
+		(1_000_000_u128, 5_u128, 0_u128, 1_000_000_u128),
+		(0_u128, 5_u128, 10_u128, 0_u128),
+		(
+			340_282_366_920_938_463_463_374_607_431_768_211_455_u128,
+			1_u128,
+			2_u128,
+			170_141_183_460_469_231_731_687_303_715_884_105_727_u128,
+		),
+	];
+
+	for (left_to_distribute, elapsed_periods, remaining_periods, expected_rewards) in testing_values.iter() {
+		assert_eq!(
+			calculate_streamed_rewards(*left_to_distribute, *elapsed_periods, *remaining_periods).unwrap(),
+			*expected_rewards
+		);
+	}
+}
+
+#[test]
+fn calculate_yield_farm_apr_should_work() {
+	let testing_values = vec![
+		(
+			1_000_000_u128,
+			FixedU128::from(1),
+			FixedU128::from_rational(1, 100),
+			1_000_000_u128,
+			FixedU128::from(2),
+			100_u128,
+			FixedU128::from(2),
+		),
+		//capped by max reward per period
+		(
+			1_000_000_u128,
+			FixedU128::from(1),
+			FixedU128::from_rational(1, 100),
+			5_000_u128,
+			FixedU128::from(2),
+			100_u128,
+			FixedU128::from(1),
+		),
+		(
+			1_000_000_u128,
+			FixedU128::from_rational(1, 2),
+			FixedU128::from_rational(1, 100),
+			1_000_000_u128,
+			FixedU128::from(2),
+			100_u128,
+			FixedU128::from(2),
+		),
+		(
+			1_000_000_u128,
+			FixedU128::from_rational(1, 2),
+			FixedU128::from_rational(1, 100),
+			2_500_u128,
+			FixedU128::from(2),
+			100_u128,
+			FixedU128::from(1),
+		),
+		//no shares in the global farm
+		(
+			0_u128,
+			FixedU128::from(1),
+			FixedU128::from_rational(1, 100),
+			5_000_u128,
+			FixedU128::from(2),
+			100_u128,
+			FixedU128::from(2),
+		),
+		(
+			1_000_000_000_u128,
+			FixedU128::from(1),
+			FixedU128::from_rational(1, 100_000),
+			1_000_000_u128,
+			FixedU128::from_rational(1, 2),
+			87_600_u128,
+			FixedU128::from_rational(438, 1_000),
+		),
+	];
+
+	for (
+		total_shares_z,
+		price_adjustment,
+		yield_per_period,
+		max_reward_per_period,
+		multiplier,
+		periods_per_year,
+		expected_apr,
+	) in testing_values.iter()
+	{
+		assert_eq!(
+			calculate_yield_farm_apr(
+				*total_shares_z,
+				*price_adjustment,
+				*yield_per_period,
+				*max_reward_per_period,
+				*multiplier,
+				*periods_per_year,
+			)
+			.unwrap(),
+			*expected_apr
+		);
+	}
+}
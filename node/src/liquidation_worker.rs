@@ -13,11 +13,11 @@ pub use liquidation_worker_support::*;
 use pallet_currencies_rpc_runtime_api::CurrenciesApi;
 use pallet_ethereum::Transaction;
 use polkadot_primitives::EncodeAs;
-use primitives::{AccountId, EvmAddress};
+use primitives::{AccountId, BlockNumber, EvmAddress};
 use sc_client_api::{Backend, BlockchainEvents, StorageKey, StorageProvider};
 use sc_service::SpawnTaskHandle;
 use sc_transaction_pool_api::{InPoolTransaction, TransactionPool};
-use sp_api::{ApiError, ApiExt, ProvideRuntimeApi};
+use sp_api::{ApiError, ApiExt, Metadata as MetadataApi, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::{RuntimeDebug, H160, H256};
 use sp_offchain::OffchainWorkerApi;
@@ -27,13 +27,19 @@ use std::{
 	collections::{HashMap, HashSet},
 	marker::PhantomData,
 	ops::Deref,
+	path::PathBuf,
 	sync::{mpsc, Arc, Mutex},
 };
 use threadpool::ThreadPool;
 use xcm_runtime_apis::dry_run::{CallDryRunEffects, DryRunApi};
 
+mod borrowers_index;
+
 const LOG_TARGET: &str = "liquidation-worker";
 
+// System::events storage key
+const SYSTEM_EVENTS_KEY: [u8; 32] = hex!("26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7");
+
 // Address of the pool address provider contract.
 const PAP_CONTRACT: EvmAddress = H160(hex!("f3ba4d1b50f78301bdd7eaea9b67822a15fca691"));
 
@@ -101,9 +107,18 @@ pub struct LiquidationWorkerConfig {
 	pub target_hf: u128,
 
 	/// URL to fetch initial borrowers data from.
+	/// Borrowers are also indexed from the local chain history, but the history of a node with pruned state can't be
+	/// indexed, so the fetched borrowers are the only source of older borrowers then.
+	/// The worker keeps running with the indexed borrowers when the fetch fails.
+	/// Set to an empty string (`--omniwatch-url ""`) to use only the borrowers index, e.g. on an archive node.
 	#[clap(long, default_value = OMNIWATCH_URL)]
 	pub omniwatch_url: String,
 
+	/// Block to start indexing borrowers from if the borrowers index doesn't exist yet.
+	/// State of the blocks has to be available, indexing doesn't continue past a block with pruned state.
+	#[clap(long, default_value_t = 0)]
+	pub borrowers_index_start_block: BlockNumber,
+
 	/// Percentage of the block weight reserved for other transactions.
 	#[clap(long, default_value_t = WEIGHT_RESERVE)]
 	pub weight_reserve: u8,
@@ -233,7 +248,8 @@ where
 	C::Api: EthereumRuntimeRPCApi<B>
 		+ Erc20MappingApi<B>
 		+ DryRunApi<B, RuntimeCall, RuntimeEvent, OriginCaller>
-		+ CurrenciesApi<B, AssetId, AccountId, Balance>,
+		+ CurrenciesApi<B, AssetId, AccountId, Balance>
+		+ MetadataApi<B>,
 	C: BlockchainEvents<B> + 'static,
 	C: HeaderBackend<B> + StorageProvider<B, BE>,
 	BE: Backend<B> + 'static,
@@ -242,13 +258,16 @@ where
 {
 	/// Starting point for the liquidation worker.
 	/// Executes `on_block_imported` on every block.
-	/// The initial list of borrowers is fetched and sorted by the HF.
+	/// The initial list of borrowers is loaded from the borrowers index stored in `borrowers_index_dir`
+	/// and extended by borrowers fetched from `omniwatch_url` unless it is empty. The index is kept up to date
+	/// by a separate task that scans the local chain history.
 	pub async fn run(
 		client: Arc<C>,
 		config: LiquidationWorkerConfig,
 		transaction_pool: Arc<P>,
 		spawner: SpawnTaskHandle,
 		liquidation_task_data: Arc<LiquidationTaskData>,
+		borrowers_index_dir: PathBuf,
 	) {
 		tracing::info!("liquidation-worker: starting");
 
@@ -268,16 +287,38 @@ where
 			return;
 		};
 
-		// Fetch and sort the list of borrowers.
-		let Some(borrowers_data) = Self::fetch_borrowers_data(config.omniwatch_url.clone()).await else {
-			tracing::error!("liquidation-worker: fetch_borrowers_data failed");
-			return;
-		};
-		let Some(sorted_borrowers) = Self::process_borrowers_data(borrowers_data) else {
-			tracing::error!("liquidation-worker: process_borrowers_data failed");
-			return;
+		let borrowers_index =
+			borrowers_index::BorrowersIndex::load(&borrowers_index_dir, config.borrowers_index_start_block);
+
+		// Borrowers fetched from the external service are used only to speed up the start of the worker.
+		let mut sorted_borrowers = if config.omniwatch_url.is_empty() {
+			if borrowers_index.borrowers().is_empty() {
+				tracing::warn!(target: LOG_TARGET, "liquidation-worker: borrowers index is empty and `--omniwatch-url` is empty, borrowers are not known until they are indexed");
+			}
+			Vec::new()
+		} else {
+			Self::fetch_borrowers_data(config.omniwatch_url.clone())
+				.await
+				.and_then(Self::process_borrowers_data)
+				.unwrap_or_else(|| {
+					tracing::error!(target: LOG_TARGET, "liquidation-worker: fetching borrowers from {:?} failed, using only the {:?} indexed borrowers", config.omniwatch_url, borrowers_index.borrowers().len());
+					Vec::new()
+				})
 		};
 
+		// Add indexed borrowers. Their HF is not known yet (HF==0), so they are placed at the beginning of the list.
+		let known_borrowers: HashSet<UserAddress> = sorted_borrowers.iter().map(|b| b.user_address).collect();
+		let indexed_borrowers: Vec<Borrower> = borrowers_index
+			.borrowers()
+			.into_iter()
+			.filter(|user_address| !known_borrowers.contains(user_address))
+			.map(|user_address| Borrower {
+				user_address,
+				health_factor: U256::zero(),
+			})
+			.collect();
+		sorted_borrowers.splice(0..0, indexed_borrowers);
+
 		// Use `MoneyMarketData` to get the list of reserves.
 		let Ok(money_market) = MoneyMarketData::<B, OriginCaller, RuntimeCall, RuntimeEvent>::new::<ApiProvider<&C::Api>>(
 			ApiProvider::<&C::Api>(runtime_api.deref()),
@@ -295,6 +336,16 @@ where
 			.map(|r| (r.asset_address, r.symbol.clone()))
 			.collect();
 
+		// Channel used to communicate borrowers found by the borrowers indexer.
+		let (indexer_channel_tx, indexer_channel_rx) = mpsc::channel();
+
+		// Indexing of the chain history is blocking, don't run it on the async executor.
+		spawner.spawn_blocking(
+			"liquidation-worker-borrowers-index",
+			None,
+			borrowers_index::run::<B, C, BE>(client.clone(), borrowers_index, indexer_channel_tx),
+		);
+
 		// Channel used to communicate new blocks and transactions to the liquidation worker thread.
 		let (worker_channel_tx, worker_channel_rx) = mpsc::channel();
 
@@ -339,7 +390,8 @@ where
 			tokio::select! {
 				Some(new_block) = block_notification_stream.next() => {
 					if new_block.is_new_best {
-						let mut borrows: Vec<UserAddress> = Vec::new();
+						// Borrowers found by the borrowers indexer since the previous block.
+						let mut borrows: Vec<UserAddress> = indexer_channel_rx.try_iter().flatten().collect();
 						let mut liquidated_users_in_last_block: Vec<UserAddress> = Vec::new();
 
 						// Get events from the previous block.
@@ -912,10 +964,7 @@ where
 		client: Arc<C>,
 		block_hash: B::Hash,
 	) -> Result<Vec<frame_system::EventRecord<RuntimeEvent, hydradx_runtime::Hash>>, ()> {
-		if let Ok(Some(encoded_events)) = client.storage(
-			block_hash,
-			&StorageKey(hex!("26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7").to_vec()), // System::events storage key
-		) {
+		if let Ok(Some(encoded_events)) = client.storage(block_hash, &StorageKey(SYSTEM_EVENTS_KEY.to_vec())) {
 			if let Ok(events) = Vec::<frame_system::EventRecord<RuntimeEvent, hydradx_runtime::Hash>>::decode(
 				&mut encoded_events.0.as_slice(),
 			) {
@@ -936,10 +985,8 @@ where
 		for event in events {
 			match &event.event {
 				RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => {
-					if log.address == BORROW_CALL_ADDRESS && log.topics[0] == events::BORROW {
-						if let Some(&borrower) = log.topics.get(2) {
-							new_borrows.push(UserAddress::from(borrower));
-						}
+					if let Some(borrower) = borrower_from_log(log) {
+						new_borrows.push(borrower);
 					} else if log.address == POOL_CONFIGURATOR_ADDRESS
 						&& log.topics[0] == events::COLLATERAL_CONFIGURATION_CHANGED
 					{
@@ -1151,6 +1198,15 @@ where
 	}
 }
 
+/// Returns the borrower (`onBehalfOf`) if the log is the money market `Borrow` event.
+fn borrower_from_log(log: &pallet_evm::Log) -> Option<UserAddress> {
+	if log.address != BORROW_CALL_ADDRESS || log.topics.first() != Some(&events::BORROW) {
+		return None;
+	}
+
+	log.topics.get(2).map(|&borrower| UserAddress::from(borrower))
+}
+
 /// The data from DIA oracle update transaction.
 #[derive(Eq, PartialEq, Clone, RuntimeDebug)]
 struct OracleUpdataData {
@@ -1432,4 +1488,37 @@ mod tests {
 		];
 		assert_eq!(expected, parse_oracle_transaction(&tx).unwrap());
 	}
+
+	fn borrow_log(borrower: UserAddress) -> pallet_evm::Log {
+		pallet_evm::Log {
+			address: BORROW_CALL_ADDRESS,
+			topics: vec![events::BORROW, H256::from_low_u64_be(1), H256::from(borrower)],
+			data: vec![],
+		}
+	}
+
+	#[test]
+	fn borrower_from_log_should_return_on_behalf_of_of_borrow_log() {
+		let borrower = H160::from_low_u64_be(1);
+
+		assert_eq!(borrower_from_log(&borrow_log(borrower)), Some(borrower));
+	}
+
+	#[test]
+	fn borrower_from_log_should_return_none_when_log_is_not_borrow() {
+		// emitted by other contract
+		let mut log = borrow_log(H160::from_low_u64_be(1));
+		log.address = H160::from_low_u64_be(2);
+		assert_eq!(borrower_from_log(&log), None);
+
+		// other event
+		let mut log = borrow_log(H160::from_low_u64_be(1));
+		log.topics[0] = events::COLLATERAL_CONFIGURATION_CHANGED;
+		assert_eq!(borrower_from_log(&log), None);
+
+		// missing `onBehalfOf` topic
+		let mut log = borrow_log(H160::from_low_u64_be(1));
+		log.topics.truncate(2);
+		assert_eq!(borrower_from_log(&log), None);
+	}
 }
@@ -0,0 +1,575 @@
+//! On-disk index of money market borrowers built from the local chain history.
+//!
+//! Borrowers are collected from `Borrow` logs of the money market found in `System::Events`
+//! of finalized blocks, read from the node's own database. Only finalized blocks are indexed,
+//! so the index never has to handle reorgs. Borrows in blocks that are not finalized yet are
+//! tracked by the liquidation worker from imported best blocks.
+//!
+//! Events are decoded with the event layout read from the metadata of the runtime that executed
+//! the block, so blocks of older runtimes are decoded correctly too.
+//!
+//! A block is indexed only if its state is available and its events can be decoded completely.
+//! Indexing stops at the first block that can't be indexed, so a block is never skipped and
+//! the index is either complete or stuck.
+
+use super::{borrower_from_log, UserAddress, LOG_TARGET, SYSTEM_EVENTS_KEY};
+use crate::synthetic_logs::{
+	compat_events::{self, Source},
+	metadata_events::EventLayout,
+	storage_override,
+};
+use codec::{Decode, Encode};
+use cumulus_primitives_core::BlockT;
+use frame_support::__private::sp_tracing::tracing;
+use futures::StreamExt;
+use hydradx_runtime::RuntimeEvent;
+use primitives::BlockNumber;
+use sc_client_api::{Backend, BlockchainEvents, StorageKey, StorageProvider};
+use sp_api::{Metadata as MetadataApi, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::RuntimeDebug;
+use sp_runtime::traits::UniqueSaturatedInto;
+use std::{
+	collections::{BTreeSet, HashMap},
+	fs, io,
+	path::{Path, PathBuf},
+	sync::{mpsc, Arc},
+};
+
+const INDEX_FILE_NAME: &str = "borrowers_index";
+
+// The index is persisted at least once per this number of indexed blocks.
+const SAVE_INTERVAL: BlockNumber = 1_000;
+
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug)]
+struct IndexData {
+	/// First block that is not indexed yet.
+	next_block: BlockNumber,
+	borrowers: BTreeSet<UserAddress>,
+}
+
+/// Reason why the borrowers of a block can't be indexed.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+enum BlockError {
+	/// The state of the block is not available, e.g. it was pruned.
+	StateUnavailable,
+	/// The events of the block can't be decoded completely, so some `Borrow` logs could be missing.
+	Undecodable,
+}
+
+/// Set of all borrowers found in the indexed blocks.
+pub struct BorrowersIndex {
+	path: PathBuf,
+	data: IndexData,
+	/// Value of `next_block` when the index was persisted last time.
+	saved_at: BlockNumber,
+	/// New borrowers were added since the index was persisted last time.
+	modified: bool,
+}
+
+impl BorrowersIndex {
+	/// Loads the index from `dir`.
+	/// Returns an empty index starting at `start_block` if the index doesn't exist or can't be decoded.
+	pub fn load(dir: &Path, start_block: BlockNumber) -> Self {
+		let path = dir.join(INDEX_FILE_NAME);
+
+		let data = match fs::read(&path) {
+			Ok(encoded) => IndexData::decode(&mut encoded.as_slice()).unwrap_or_else(|_| {
+				tracing::error!(target: LOG_TARGET, "liquidation-worker: borrowers index {:?} is corrupted, rebuilding it from block {:?}", path, start_block);
+				IndexData {
+					next_block: start_block,
+					borrowers: Default::default(),
+				}
+			}),
+			Err(_) => IndexData {
+				next_block: start_block,
+				borrowers: Default::default(),
+			},
+		};
+
+		Self {
+			path,
+			saved_at: data.next_block,
+			data,
+			modified: false,
+		}
+	}
+
+	/// All indexed borrowers.
+	pub fn borrowers(&self) -> Vec<UserAddress> {
+		self.data.borrowers.iter().copied().collect()
+	}
+
+	/// First block that is not indexed yet.
+	pub fn next_block(&self) -> BlockNumber {
+		self.data.next_block
+	}
+
+	/// Adds the borrower to the index. Returns `true` if the borrower was not indexed yet.
+	fn insert(&mut self, borrower: UserAddress) -> bool {
+		let is_new = self.data.borrowers.insert(borrower);
+		self.modified |= is_new;
+		is_new
+	}
+
+	fn set_next_block(&mut self, next_block: BlockNumber) {
+		self.data.next_block = next_block;
+	}
+
+	fn should_save(&self) -> bool {
+		self.modified || self.data.next_block.saturating_sub(self.saved_at) >= SAVE_INTERVAL
+	}
+
+	/// Persists the index. The index is written to a temporary file first, so a crash can't leave
+	/// a partially written index behind.
+	pub fn save(&mut self) -> io::Result<()> {
+		if let Some(dir) = self.path.parent() {
+			fs::create_dir_all(dir)?;
+		}
+
+		let tmp_path = self.path.with_extension("tmp");
+		fs::write(&tmp_path, self.data.encode())?;
+		fs::rename(&tmp_path, &self.path)?;
+
+		self.saved_at = self.data.next_block;
+		self.modified = false;
+
+		Ok(())
+	}
+}
+
+/// Indexes all finalized blocks and keeps the index up to date with new finalized blocks.
+/// Borrowers that were not indexed yet are sent to `new_borrowers_tx`.
+/// Exits when the receiving side of `new_borrowers_tx` is dropped.
+pub async fn run<B, C, BE>(client: Arc<C>, mut index: BorrowersIndex, new_borrowers_tx: mpsc::Sender<Vec<UserAddress>>)
+where
+	B: BlockT,
+	C: HeaderBackend<B> + StorageProvider<B, BE> + BlockchainEvents<B> + ProvideRuntimeApi<B>,
+	C::Api: MetadataApi<B>,
+	BE: Backend<B>,
+{
+	tracing::info!(target: LOG_TARGET, "liquidation-worker: borrowers index starting at block {:?} with {:?} borrowers", index.next_block(), index.data.borrowers.len());
+
+	let mut finality_notification_stream = client.finality_notification_stream();
+	let mut stuck_at = None;
+	// Event layouts keyed by the spec version of the runtime, reading the metadata executes the runtime.
+	let mut layouts = HashMap::new();
+
+	loop {
+		let finalized: BlockNumber = client.info().finalized_number.unique_saturated_into();
+		let Ok(stuck) = index_blocks(
+			&mut index,
+			finalized,
+			|block_number| block_borrowers(client.as_ref(), &mut layouts, block_number),
+			&new_borrowers_tx,
+		) else {
+			tracing::info!(target: LOG_TARGET, "liquidation-worker: exiting borrowers indexer");
+			return;
+		};
+
+		// The block is retried on every finalized block, report it only once.
+		if let Some((block_number, error)) = stuck.filter(|stuck| Some(*stuck) != stuck_at) {
+			match error {
+				BlockError::StateUnavailable => tracing::error!(
+					target: LOG_TARGET,
+					"liquidation-worker: events of block {:?} are not available, borrowers are not indexed from this block on. Run an archive node, set `--borrowers-index-start-block` to a block with available state or use `--omniwatch-url` to provide the borrowers.",
+					block_number
+				),
+				BlockError::Undecodable => tracing::error!(
+					target: LOG_TARGET,
+					"liquidation-worker: events of block {:?} can't be decoded, borrowers are not indexed from this block on. Upgrade the node to a version supporting the runtime of the block or use `--omniwatch-url` to provide the borrowers.",
+					block_number
+				),
+			}
+		}
+		stuck_at = stuck;
+
+		if finality_notification_stream.next().await.is_none() {
+			return;
+		}
+	}
+}
+
+/// Indexes blocks from `index.next_block()` to `to` (inclusive).
+/// Indexing stops at the first block whose borrowers can't be read, so the block is retried
+/// next time and the index never skips a block.
+/// Returns the block at which indexing stopped together with the reason,
+/// or `Err` if the receiving side of `new_borrowers_tx` was dropped.
+fn index_blocks(
+	index: &mut BorrowersIndex,
+	to: BlockNumber,
+	mut block_borrowers: impl FnMut(BlockNumber) -> Result<Vec<UserAddress>, BlockError>,
+	new_borrowers_tx: &mpsc::Sender<Vec<UserAddress>>,
+) -> Result<Option<(BlockNumber, BlockError)>, ()> {
+	let from = index.next_block();
+	if from > to {
+		return Ok(None);
+	}
+
+	let is_catching_up = to - from >= SAVE_INTERVAL;
+	if is_catching_up {
+		tracing::info!(target: LOG_TARGET, "liquidation-worker: indexing borrowers from block {:?} to {:?}", from, to);
+	}
+
+	let mut new_borrowers = Vec::new();
+
+	for block_number in from..=to {
+		let borrowers = match block_borrowers(block_number) {
+			Ok(borrowers) => borrowers,
+			Err(error) => {
+				flush(index, &mut new_borrowers, new_borrowers_tx)?;
+				return Ok(Some((block_number, error)));
+			}
+		};
+
+		for borrower in borrowers {
+			if index.insert(borrower) {
+				new_borrowers.push(borrower);
+			}
+		}
+
+		index.set_next_block(block_number.saturating_add(1));
+
+		// Flush once per `SAVE_INTERVAL` blocks while catching up and after the last block.
+		if block_number == to || index.next_block().saturating_sub(index.saved_at) >= SAVE_INTERVAL {
+			flush(index, &mut new_borrowers, new_borrowers_tx)?;
+
+			if is_catching_up && block_number != to {
+				tracing::info!(target: LOG_TARGET, "liquidation-worker: indexed borrowers up to block {:?}/{:?}", block_number, to);
+			}
+		}
+	}
+
+	Ok(None)
+}
+
+/// Sends `new_borrowers` to the worker and persists the index if needed.
+/// Returns `Err` if the receiving side of `new_borrowers_tx` was dropped.
+fn flush(
+	index: &mut BorrowersIndex,
+	new_borrowers: &mut Vec<UserAddress>,
+	new_borrowers_tx: &mpsc::Sender<Vec<UserAddress>>,
+) -> Result<(), ()> {
+	if !new_borrowers.is_empty() {
+		new_borrowers_tx.send(std::mem::take(new_borrowers)).map_err(|_| ())?;
+	}
+
+	if index.should_save() {
+		if let Err(e) = index.save() {
+			tracing::error!(target: LOG_TARGET, "liquidation-worker: failed to save the borrowers index: {:?}", e);
+		}
+	}
+
+	Ok(())
+}
+
+/// Returns borrowers from `Borrow` logs of the block.
+/// The event layout of the runtime that executed the block is taken from `layouts` or read from its metadata.
+fn block_borrowers<B, C, BE>(
+	client: &C,
+	layouts: &mut HashMap<u32, Option<EventLayout>>,
+	block_number: BlockNumber,
+) -> Result<Vec<UserAddress>, BlockError>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + StorageProvider<B, BE> + ProvideRuntimeApi<B>,
+	C::Api: MetadataApi<B>,
+	BE: Backend<B>,
+{
+	let block_hash = client
+		.hash(block_number.into())
+		.ok()
+		.flatten()
+		.ok_or(BlockError::StateUnavailable)?;
+
+	let encoded_events = match client.storage(block_hash, &StorageKey(SYSTEM_EVENTS_KEY.to_vec())) {
+		Ok(Some(encoded_events)) => encoded_events,
+		// No events were deposited in the block.
+		Ok(None) => return Ok(Vec::new()),
+		Err(_) => return Err(BlockError::StateUnavailable),
+	};
+
+	let spec_version = storage_override::spec_version::<B, C, BE>(client, block_hash);
+	let layout = layouts
+		.entry(spec_version)
+		.or_insert_with(|| event_layout::<B, C>(client, block_hash, spec_version));
+
+	borrowers_from_events(&encoded_events.0, layout.as_ref())
+}
+
+/// Reads the event layout of runtime `spec_version` that executed the block `at` from its metadata.
+/// Returns `None` if the layout can't be read, events are decoded with the node's types then.
+fn event_layout<B, C>(client: &C, at: B::Hash, spec_version: u32) -> Option<EventLayout>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + ProvideRuntimeApi<B>,
+	C::Api: MetadataApi<B>,
+{
+	let metadata_at = storage_override::metadata_source::<B, C>(client, at);
+	let Some(metadata) = storage_override::runtime_metadata::<B, C>(client, metadata_at) else {
+		tracing::warn!(target: LOG_TARGET, "liquidation-worker: metadata of runtime {:?} is not available, decoding its events with the node's types", spec_version);
+		return None;
+	};
+
+	EventLayout::new(&metadata)
+		.map_err(|fault| {
+			tracing::warn!(target: LOG_TARGET, "liquidation-worker: event layout of runtime {:?} can't be read from its metadata ({:?}), decoding its events with the node's types", spec_version, fault);
+		})
+		.ok()
+}
+
+/// Returns borrowers from `Borrow` logs in the encoded `System::Events`.
+/// Returns `Err` if the events can't be decoded completely.
+fn borrowers_from_events(encoded_events: &[u8], layout: Option<&EventLayout>) -> Result<Vec<UserAddress>, BlockError> {
+	let (records, source) = compat_events::read_events(encoded_events, layout).ok_or(BlockError::Undecodable)?;
+
+	// Records skipped by the resync scan can't be told apart from a missing `Borrow` log,
+	// so such a block is not indexed rather than indexed incompletely.
+	if matches!(source, Source::Partial { .. }) {
+		return Err(BlockError::Undecodable);
+	}
+
+	Ok(records
+		.iter()
+		.filter_map(|record| match &record.event {
+			RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => borrower_from_log(log),
+			_ => None,
+		})
+		.collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::liquidation_worker::{events, BORROW_CALL_ADDRESS};
+	use frame_system::{EventRecord, Phase};
+	use sp_core::{H160, H256};
+
+	fn test_dir(name: &str) -> PathBuf {
+		let dir = std::env::temp_dir().join(format!("liquidation-worker-{}-{}", name, std::process::id()));
+		let _ = fs::remove_dir_all(&dir);
+		dir
+	}
+
+	#[test]
+	fn load_should_return_empty_index_when_index_does_not_exist() {
+		let dir = test_dir("empty");
+
+		let index = BorrowersIndex::load(&dir, 100);
+
+		assert_eq!(index.next_block(), 100);
+		assert!(index.borrowers().is_empty());
+	}
+
+	#[test]
+	fn save_and_load_should_work() {
+		let dir = test_dir("save");
+		let mut index = BorrowersIndex::load(&dir, 0);
+
+		assert!(index.insert(H160::from_low_u64_be(2)));
+		assert!(index.insert(H160::from_low_u64_be(1)));
+		assert!(!index.insert(H160::from_low_u64_be(2)));
+		index.set_next_block(10);
+		assert!(index.should_save());
+		assert!(index.save().is_ok());
+		assert!(!index.should_save());
+
+		let index = BorrowersIndex::load(&dir, 0);
+
+		assert_eq!(index.next_block(), 10);
+		assert_eq!(
+			index.borrowers(),
+			vec![H160::from_low_u64_be(1), H160::from_low_u64_be(2)]
+		);
+
+		let _ = fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn load_should_rebuild_index_when_index_is_corrupted() {
+		let dir = test_dir("corrupted");
+		fs::create_dir_all(&dir).unwrap();
+		fs::write(dir.join(INDEX_FILE_NAME), [1u8, 2, 3]).unwrap();
+
+		let index = BorrowersIndex::load(&dir, 5);
+
+		assert_eq!(index.next_block(), 5);
+		assert!(index.borrowers().is_empty());
+
+		let _ = fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn should_save_when_save_interval_passed() {
+		let dir = test_dir("interval");
+		let mut index = BorrowersIndex::load(&dir, 0);
+
+		index.set_next_block(SAVE_INTERVAL - 1);
+		assert!(!index.should_save());
+
+		index.set_next_block(SAVE_INTERVAL);
+		assert!(index.should_save());
+	}
+
+	fn borrow_log(borrower: UserAddress) -> pallet_evm::Log {
+		pallet_evm::Log {
+			address: BORROW_CALL_ADDRESS,
+			topics: vec![events::BORROW, H256::from_low_u64_be(1), H256::from(borrower)],
+			data: vec![],
+		}
+	}
+
+	fn encoded_events(events: Vec<RuntimeEvent>) -> Vec<u8> {
+		events
+			.into_iter()
+			.map(|event| EventRecord {
+				phase: Phase::ApplyExtrinsic(0),
+				event,
+				topics: vec![],
+			})
+			.collect::<Vec<EventRecord<RuntimeEvent, H256>>>()
+			.encode()
+	}
+
+	#[test]
+	fn borrowers_from_events_should_return_borrowers_of_borrow_logs() {
+		// Arrange
+		let mut other_log = borrow_log(H160::from_low_u64_be(3));
+		other_log.address = H160::from_low_u64_be(4);
+		let events = encoded_events(vec![
+			RuntimeEvent::EVM(pallet_evm::Event::Log {
+				log: borrow_log(H160::from_low_u64_be(1)),
+			}),
+			RuntimeEvent::EVM(pallet_evm::Event::Log { log: other_log }),
+			RuntimeEvent::EVM(pallet_evm::Event::Log {
+				log: borrow_log(H160::from_low_u64_be(2)),
+			}),
+		]);
+
+		// Act
+		let borrowers = borrowers_from_events(&events, None);
+
+		// Assert
+		assert_eq!(borrowers, Ok(vec![H160::from_low_u64_be(1), H160::from_low_u64_be(2)]));
+	}
+
+	#[test]
+	fn borrowers_from_events_should_fail_when_events_cant_be_decoded() {
+		assert_eq!(borrowers_from_events(&[1u8, 2, 3], None), Err(BlockError::Undecodable));
+	}
+
+	#[test]
+	fn borrowers_from_events_should_fail_when_events_are_decoded_partially() {
+		// Arrange
+		// The first record belongs to a pallet unknown to the node, the second one is a `Borrow` log.
+		let mut events = codec::Compact(2u32).encode();
+		events.extend(Phase::ApplyExtrinsic(0).encode());
+		events.push(u8::MAX);
+		events.extend(
+			EventRecord {
+				phase: Phase::ApplyExtrinsic(1),
+				event: RuntimeEvent::EVM(pallet_evm::Event::Log {
+					log: borrow_log(H160::from_low_u64_be(1)),
+				}),
+				topics: Vec::<H256>::new(),
+			}
+			.encode(),
+		);
+
+		// Act
+		let borrowers = borrowers_from_events(&events, None);
+
+		// Assert
+		assert_eq!(borrowers, Err(BlockError::Undecodable));
+	}
+
+	#[test]
+	fn index_blocks_should_index_borrowers_of_all_blocks() {
+		// Arrange
+		let dir = test_dir("index");
+		let mut index = BorrowersIndex::load(&dir, 1);
+		let (tx, rx) = mpsc::channel();
+
+		// Act
+		let result = index_blocks(
+			&mut index,
+			3,
+			|block_number| Ok(vec![H160::from_low_u64_be(block_number.into())]),
+			&tx,
+		);
+
+		// Assert
+		assert_eq!(result, Ok(None));
+		assert_eq!(index.next_block(), 4);
+		assert_eq!(
+			rx.try_recv(),
+			Ok(vec![
+				H160::from_low_u64_be(1),
+				H160::from_low_u64_be(2),
+				H160::from_low_u64_be(3)
+			])
+		);
+		assert_eq!(BorrowersIndex::load(&dir, 0).next_block(), 4);
+
+		let _ = fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn index_blocks_should_stop_at_block_with_unavailable_events() {
+		// Arrange
+		let dir = test_dir("unavailable");
+		let mut index = BorrowersIndex::load(&dir, 1);
+		let (tx, rx) = mpsc::channel();
+		let block_borrowers = |block_number: BlockNumber| match block_number {
+			3 => Err(BlockError::StateUnavailable),
+			_ => Ok(vec![H160::from_low_u64_be(block_number.into())]),
+		};
+
+		// Act
+		let result = index_blocks(&mut index, 5, block_borrowers, &tx);
+
+		// Assert
+		assert_eq!(result, Ok(Some((3, BlockError::StateUnavailable))));
+		assert_eq!(index.next_block(), 3);
+		assert_eq!(
+			rx.try_recv(),
+			Ok(vec![H160::from_low_u64_be(1), H160::from_low_u64_be(2)])
+		);
+
+		// The unavailable block is retried next time.
+		assert_eq!(
+			index_blocks(&mut index, 5, block_borrowers, &tx),
+			Ok(Some((3, BlockError::StateUnavailable)))
+		);
+		assert_eq!(index.next_block(), 3);
+		assert!(rx.try_recv().is_err());
+
+		let _ = fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn index_blocks_should_stop_at_block_with_undecodable_events() {
+		// Arrange
+		let dir = test_dir("undecodable");
+		let mut index = BorrowersIndex::load(&dir, 1);
+		let (tx, rx) = mpsc::channel();
+
+		// Act
+		let result = index_blocks(
+			&mut index,
+			3,
+			|block_number| match block_number {
+				2 => Err(BlockError::Undecodable),
+				_ => Ok(vec![H160::from_low_u64_be(block_number.into())]),
+			},
+			&tx,
+		);
+
+		// Assert
+		assert_eq!(result, Ok(Some((2, BlockError::Undecodable))));
+		assert_eq!(index.next_block(), 2);
+		assert_eq!(rx.try_recv(), Ok(vec![H160::from_low_u64_be(1)]));
+
+		let _ = fs::remove_dir_all(&dir);
+	}
+}
@@ -21,6 +21,11 @@
 
 use std::sync::Arc;
 
+pub mod ema_oracle;
+pub mod otc;
+pub mod router;
+pub mod stableswap;
+
 use crate::liquidation_worker::LiquidationTaskData;
 use cumulus_primitives_core::PersistedValidationData;
 use cumulus_primitives_parachain_inherent::ParachainInherentData;
@@ -29,7 +34,7 @@ use fc_db::kv::Backend as FrontierBackend;
 pub use fc_rpc::{EthBlockDataCacheTask, StorageOverride, StorageOverrideHandler};
 pub use fc_rpc_core::types::{FeeHistoryCache, FeeHistoryCacheLimit, FilterPool};
 use fp_rpc::{ConvertTransaction, ConvertTransactionRuntimeApi, EthereumRuntimeRPCApi};
-use hydradx_runtime::{opaque::Block, AccountId, Balance, Index};
+use hydradx_runtime::{opaque::Block, AccountId, AssetId, Balance, BlockNumber, EmaPrice, Index};
 use sc_client_api::{
 	backend::{Backend, StateBackend, StorageProvider},
 	client::BlockchainEvents,
@@ -116,12 +121,20 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BlockBuilderApi<Block>,
+	C::Api: router::RouterRuntimeApi<Block, AssetId, Balance>,
+	C::Api: otc::OtcRuntimeApi<Block, AccountId, AssetId, BlockNumber, Balance>,
+	C::Api: ema_oracle::EmaOracleRuntimeApi<Block, AssetId, Balance, BlockNumber, EmaPrice>,
+	C::Api: stableswap::StableswapRuntimeApi<Block, AssetId, Balance, BlockNumber>,
 	P: TransactionPool + Sync + Send + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
 	use crate::liquidation_worker::rpc::{LiquidationWorker, LiquidationWorkerApiServer};
+	use ema_oracle::{EmaOracle, EmaOracleApiServer};
+	use otc::{Otc, OtcApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use router::{Router, RouterApiServer};
+	use stableswap::{Stableswap, StableswapApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
 
@@ -136,6 +149,10 @@ where
 	module.merge(System::new(client.clone(), pool).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 	module.merge(StateMigration::new(client.clone(), backend.clone()).into_rpc())?;
+	module.merge(Router::new(client.clone()).into_rpc())?;
+	module.merge(Otc::new(client.clone()).into_rpc())?;
+	module.merge(EmaOracle::new(client.clone()).into_rpc())?;
+	module.merge(Stableswap::new(client.clone()).into_rpc())?;
 
 	module.merge(LiquidationWorker::new(liquidation_task_data).into_rpc())?;
 
@@ -0,0 +1,167 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the EMA oracle pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+pub use pallet_ema_oracle_rpc_runtime_api::EmaOracleApi as EmaOracleRuntimeApi;
+use pallet_ema_oracle_rpc_runtime_api::{AggregatedEntry, OraclePeriod, Source, Trade};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait EmaOracleApi<BlockHash, AssetId, Balance, BlockNumber, Price> {
+	/// Return the oracle entry of the asset pair for the given period and source.
+	/// The price of the entry is `asset_a/asset_b`.
+	#[method(name = "emaOracle_getEntry")]
+	fn get_entry(
+		&self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AggregatedEntry<Balance, BlockNumber, Price>>>;
+
+	/// Return the oracle entries of the asset pair for all periods of the given source.
+	#[method(name = "emaOracle_getEntries")]
+	fn get_entries(
+		&self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		source: Source,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(OraclePeriod, AggregatedEntry<Balance, BlockNumber, Price>)>>;
+
+	/// Return the asset pairs tracked for the given source.
+	#[method(name = "emaOracle_trackedPairs")]
+	fn tracked_pairs(&self, source: Source, at: Option<BlockHash>) -> RpcResult<Vec<(AssetId, AssetId)>>;
+
+	/// Return the oracle price of the route for the given period.
+	#[method(name = "emaOracle_routePrice")]
+	fn route_price(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		period: OraclePeriod,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Price>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Provides RPC methods to query EMA oracle entries.
+pub struct EmaOracle<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> EmaOracle<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query EMA oracle.",
+		Some(format!("{:?}", e)),
+	)
+}
+
+impl<C, Block, AssetId, Balance, BlockNumber, Price>
+	EmaOracleApiServer<<Block as BlockT>::Hash, AssetId, Balance, BlockNumber, Price> for EmaOracle<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: EmaOracleRuntimeApi<Block, AssetId, Balance, BlockNumber, Price>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Price: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn get_entry(
+		&self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<AggregatedEntry<Balance, BlockNumber, Price>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_entry(at, asset_a, asset_b, period, source)
+			.map_err(runtime_error)
+	}
+
+	fn get_entries(
+		&self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		source: Source,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(OraclePeriod, AggregatedEntry<Balance, BlockNumber, Price>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_entries(at, asset_a, asset_b, source).map_err(runtime_error)
+	}
+
+	fn tracked_pairs(&self, source: Source, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<(AssetId, AssetId)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.tracked_pairs(at, source).map_err(runtime_error)
+	}
+
+	fn route_price(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		period: OraclePeriod,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Price>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.route_price(at, route, period).map_err(runtime_error)
+	}
+}
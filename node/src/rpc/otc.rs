@@ -0,0 +1,128 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the OTC pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+pub use pallet_otc_rpc_runtime_api::OtcApi as OtcRuntimeApi;
+use pallet_otc_rpc_runtime_api::{OrderBookEntry, OrderId};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait OtcApi<BlockHash, AccountId, AssetId, BlockNumber, Balance> {
+	/// Return the open orders matching the given filters, best priced orders first.
+	/// Expired orders are not returned.
+	#[method(name = "otc_orders")]
+	fn orders(
+		&self,
+		asset_in: Option<AssetId>,
+		asset_out: Option<AssetId>,
+		owner: Option<AccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<OrderBookEntry<AccountId, AssetId, BlockNumber, Balance>>>;
+
+	/// Return the order with the given ID, if it exists.
+	#[method(name = "otc_order")]
+	fn order(
+		&self,
+		order_id: OrderId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<OrderBookEntry<AccountId, AssetId, BlockNumber, Balance>>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Provides RPC methods to query open OTC orders.
+pub struct Otc<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Otc<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query OTC orders.",
+		Some(format!("{:?}", e)),
+	)
+}
+
+impl<C, Block, AccountId, AssetId, BlockNumber, Balance>
+	OtcApiServer<<Block as BlockT>::Hash, AccountId, AssetId, BlockNumber, Balance> for Otc<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: OtcRuntimeApi<Block, AccountId, AssetId, BlockNumber, Balance>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn orders(
+		&self,
+		asset_in: Option<AssetId>,
+		asset_out: Option<AssetId>,
+		owner: Option<AccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<OrderBookEntry<AccountId, AssetId, BlockNumber, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.orders(at, asset_in, asset_out, owner).map_err(runtime_error)
+	}
+
+	fn order(
+		&self,
+		order_id: OrderId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<OrderBookEntry<AccountId, AssetId, BlockNumber, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.order(at, order_id).map_err(runtime_error)
+	}
+}
@@ -0,0 +1,231 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the route executor pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+pub use pallet_route_executor_rpc_runtime_api::RouterApi as RouterRuntimeApi;
+use pallet_route_executor_rpc_runtime_api::{Route, RouteQuote, Trade};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait RouterApi<BlockHash, AssetId, Balance> {
+	/// Quote a sell of `amount_in` of `asset_in` for `asset_out` with a breakdown for every trade of the route.
+	/// If no route is given, the on-chain route is used, or the default route if there is none.
+	#[method(name = "router_quoteSell")]
+	fn quote_sell(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		route: Option<Vec<Trade<AssetId>>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>>;
+
+	/// Quote a buy of `amount_out` of `asset_out` for `asset_in` with a breakdown for every trade of the route.
+	/// If no route is given, the on-chain route is used, or the default route if there is none.
+	#[method(name = "router_quoteBuy")]
+	fn quote_buy(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		route: Option<Vec<Trade<AssetId>>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>>;
+
+	/// Return the route used by the router for the asset pair.
+	#[method(name = "router_getRoute")]
+	fn get_route(&self, asset_in: AssetId, asset_out: AssetId, at: Option<BlockHash>)
+		-> RpcResult<Vec<Trade<AssetId>>>;
+
+	/// Find the route with the highest amount out for selling `amount_in` of `asset_in` for `asset_out`.
+	#[method(name = "router_findBestSellRoute")]
+	fn find_best_sell_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vec<Trade<AssetId>>>>;
+
+	/// Find the route with the lowest amount in for buying `amount_out` of `asset_out` for `asset_in`.
+	#[method(name = "router_findBestBuyRoute")]
+	fn find_best_buy_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vec<Trade<AssetId>>>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The route can't be quoted.
+	QuoteError,
+	/// The route is too long.
+	InvalidRoute,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::QuoteError => 2,
+			Error::InvalidRoute => 3,
+		}
+	}
+}
+
+/// Provides RPC methods to quote router trades.
+pub struct Router<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Router<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the router.",
+		Some(format!("{:?}", e)),
+	)
+}
+
+fn quote_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::QuoteError.into(),
+		"Unable to quote the route.",
+		Some(format!("{:?}", e)),
+	)
+}
+
+fn to_route<AssetId>(route: Option<Vec<Trade<AssetId>>>) -> RpcResult<Route<AssetId>> {
+	route.unwrap_or_default().try_into().map_err(|_| {
+		ErrorObject::owned(
+			Error::InvalidRoute.into(),
+			"The route exceeds the maximum number of trades.",
+			None::<String>,
+		)
+	})
+}
+
+impl<C, Block, AssetId, Balance> RouterApiServer<<Block as BlockT>::Hash, AssetId, Balance> for Router<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: RouterRuntimeApi<Block, AssetId, Balance>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn quote_sell(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		route: Option<Vec<Trade<AssetId>>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.quote_sell(at, asset_in, asset_out, amount_in, to_route(route)?)
+			.map_err(runtime_error)?
+			.map_err(quote_error)
+	}
+
+	fn quote_buy(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		route: Option<Vec<Trade<AssetId>>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RouteQuote<AssetId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.quote_buy(at, asset_in, asset_out, amount_out, to_route(route)?)
+			.map_err(runtime_error)?
+			.map_err(quote_error)
+	}
+
+	fn get_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Trade<AssetId>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_route(at, asset_in, asset_out)
+			.map(|route| route.into_inner())
+			.map_err(runtime_error)
+	}
+
+	fn find_best_sell_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Vec<Trade<AssetId>>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.find_best_sell_route(at, asset_in, asset_out, amount_in)
+			.map(|route| route.map(|route| route.into_inner()))
+			.map_err(runtime_error)
+	}
+
+	fn find_best_buy_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Vec<Trade<AssetId>>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.find_best_buy_route(at, asset_in, asset_out, amount_out)
+			.map(|route| route.map(|route| route.into_inner()))
+			.map_err(runtime_error)
+	}
+}
@@ -0,0 +1,196 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the stableswap pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+pub use pallet_stableswap_rpc_runtime_api::StableswapApi as StableswapRuntimeApi;
+use pallet_stableswap_rpc_runtime_api::{PoolState, RemoveLiquidityOneAssetQuote};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait StableswapApi<BlockHash, AssetId, Balance, BlockNumber> {
+	/// Return all stableswap pools with their current state.
+	#[method(name = "stableswap_pools")]
+	fn pools(&self, at: Option<BlockHash>) -> RpcResult<Vec<PoolState<AssetId, Balance, BlockNumber>>>;
+
+	/// Return the stableswap pool with its current state.
+	#[method(name = "stableswap_pool")]
+	fn pool(
+		&self,
+		pool_id: AssetId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<PoolState<AssetId, Balance, BlockNumber>>>;
+
+	/// Quote the amount of shares received for adding liquidity of the given assets.
+	#[method(name = "stableswap_quoteAddLiquidity")]
+	fn quote_add_liquidity(
+		&self,
+		pool_id: AssetId,
+		assets: Vec<(AssetId, Balance)>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
+
+	/// Quote the amounts of all pool assets received for removing `share_amount` of shares.
+	#[method(name = "stableswap_quoteRemoveLiquidity")]
+	fn quote_remove_liquidity(
+		&self,
+		pool_id: AssetId,
+		share_amount: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AssetId, Balance)>>;
+
+	/// Quote the amount of `asset_id` received for removing `share_amount` of shares as a single asset.
+	#[method(name = "stableswap_quoteRemoveLiquidityOneAsset")]
+	fn quote_remove_liquidity_one_asset(
+		&self,
+		pool_id: AssetId,
+		asset_id: AssetId,
+		share_amount: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<RemoveLiquidityOneAssetQuote<Balance>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The liquidity change can't be quoted.
+	QuoteError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::QuoteError => 2,
+		}
+	}
+}
+
+/// Provides RPC methods to query stableswap pools.
+pub struct Stableswap<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Stableswap<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query stableswap pools.",
+		Some(format!("{:?}", e)),
+	)
+}
+
+fn quote_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::QuoteError.into(),
+		"Unable to quote the liquidity change.",
+		Some(format!("{:?}", e)),
+	)
+}
+
+impl<C, Block, AssetId, Balance, BlockNumber>
+	StableswapApiServer<<Block as BlockT>::Hash, AssetId, Balance, BlockNumber> for Stableswap<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: StableswapRuntimeApi<Block, AssetId, Balance, BlockNumber>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn pools(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<PoolState<AssetId, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.pools(at).map_err(runtime_error)
+	}
+
+	fn pool(
+		&self,
+		pool_id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<PoolState<AssetId, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.pool(at, pool_id).map_err(runtime_error)
+	}
+
+	fn quote_add_liquidity(
+		&self,
+		pool_id: AssetId,
+		assets: Vec<(AssetId, Balance)>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Balance> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.quote_add_liquidity(at, pool_id, assets)
+			.map_err(runtime_error)?
+			.map_err(quote_error)
+	}
+
+	fn quote_remove_liquidity(
+		&self,
+		pool_id: AssetId,
+		share_amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AssetId, Balance)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.quote_remove_liquidity(at, pool_id, share_amount)
+			.map_err(runtime_error)?
+			.map_err(quote_error)
+	}
+
+	fn quote_remove_liquidity_one_asset(
+		&self,
+		pool_id: AssetId,
+		asset_id: AssetId,
+		share_amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RemoveLiquidityOneAssetQuote<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.quote_remove_liquidity_one_asset(at, pool_id, asset_id, share_amount)
+			.map_err(runtime_error)?
+			.map_err(quote_error)
+	}
+}
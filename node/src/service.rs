@@ -394,6 +394,7 @@ async fn start_node_impl(
 				transaction_pool.clone(),
 				task_manager.spawn_handle(),
 				liquidation_task_data.clone(),
+				evm::db_config_dir(&parachain_config).join("liquidation-worker"),
 			),
 		);
 	}
@@ -35,7 +35,7 @@ use primitives::Block;
 use sc_client_api::{backend::Backend, BlockBackend, StorageProvider};
 use sp_api::{Metadata as MetadataApi, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_core::{hashing::twox_128, H160, H256, U256};
+use sp_core::{hashing::twox_128, OpaqueMetadata, H160, H256, U256};
 use sp_runtime::traits::{BlakeTwo256, Block as BlockT, Hash as HashT, Header as HeaderT};
 use sp_storage::StorageKey;
 
@@ -81,19 +81,57 @@ fn names(missing: &[Missing]) -> String {
 ///
 /// Falls back to `at` at genesis, or when the parent is unknown — no worse than before, and
 /// the odds of the oldest block this node retains being an upgrade block are negligible.
-fn metadata_source<C>(client: &C, at: Hash) -> Hash
+pub(crate) fn metadata_source<B, C>(client: &C, at: B::Hash) -> B::Hash
 where
-	C: HeaderBackend<Block>,
+	B: BlockT,
+	C: HeaderBackend<B>,
 {
 	client
 		.header(at)
 		.ok()
 		.flatten()
 		.map(|header| *header.parent_hash())
-		.filter(|parent| *parent != Hash::default())
+		.filter(|parent| *parent != B::Hash::default())
 		.unwrap_or(at)
 }
 
+/// The spec version of the runtime that *executed* `at`, out of state rather than the
+/// runtime api: no wasm call, and `frame_executive` only bumps
+/// `System::LastRuntimeUpgrade` when a new runtime runs for the first time, so the value
+/// in a block's post-state names the runtime that produced that block's events — which is
+/// exactly the layout needed to decode them. Only the leading `Compact<u32>` of
+/// `LastRuntimeUpgradeInfo` is read, so the `spec_name` type moving between sdks cannot
+/// break it. A missing entry just shares one cache slot.
+pub(crate) fn spec_version<B, C, BE>(client: &C, at: B::Hash) -> u32
+where
+	B: BlockT,
+	C: StorageProvider<B, BE>,
+	BE: Backend<B>,
+{
+	client
+		.storage(at, &storage_key(b"System", b"LastRuntimeUpgrade"))
+		.ok()
+		.flatten()
+		.and_then(|data| codec::Compact::<u32>::decode(&mut &data.0[..]).ok())
+		.map(|v| v.0)
+		.unwrap_or_default()
+}
+
+/// The runtime metadata at `at`, in [`METADATA_VERSION`] if the runtime offers it.
+/// `None` when the runtime api can't be called there, e.g. the state was pruned.
+pub(crate) fn runtime_metadata<B, C>(client: &C, at: B::Hash) -> Option<OpaqueMetadata>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>,
+	C::Api: MetadataApi<B>,
+{
+	let api = client.runtime_api();
+	api.metadata_at_version(at, METADATA_VERSION)
+		.ok()
+		.flatten()
+		.or_else(|| api.metadata(at).ok())
+}
+
 /// Log the first occurrence, then back off by powers of two, so persistent loss stays
 /// visible without flooding one line per block.
 fn should_report(counter: &AtomicUsize) -> usize {
@@ -158,7 +196,7 @@ where
 	/// block. `None` means the chain could not be asked — an unknown metadata version, or
 	/// state this node no longer has — and the caller falls back to compiled types.
 	fn layout(&self, at: Hash) -> Option<Arc<EventLayout>> {
-		let spec = self.spec_version(at);
+		let spec = spec_version::<Block, _, BE>(self.client.as_ref(), at);
 		// The build is held under the lock rather than raced: two rpc threads reaching an
 		// unseen runtime at once would otherwise each execute the metadata call and each log
 		// the summary. Waiting costs one wasm call's worth of latency, once per runtime.
@@ -166,35 +204,13 @@ where
 		if let Some(known) = layouts.get(&spec) {
 			return known.clone();
 		}
-		let layout = self.build_layout(metadata_source(self.client.as_ref(), at), spec);
+		let layout = self.build_layout(metadata_source::<Block, _>(self.client.as_ref(), at), spec);
 		layouts.insert(spec, layout.clone());
 		layout
 	}
 
-	/// The spec version of the runtime that *executed* `at`, out of state rather than the
-	/// runtime api: no wasm call, and `frame_executive` only bumps
-	/// `System::LastRuntimeUpgrade` when a new runtime runs for the first time, so the value
-	/// in a block's post-state names the runtime that produced that block's events — which is
-	/// exactly the layout needed to decode them. Only the leading `Compact<u32>` of
-	/// `LastRuntimeUpgradeInfo` is read, so the `spec_name` type moving between sdks cannot
-	/// break it. A missing entry just shares one cache slot.
-	fn spec_version(&self, at: Hash) -> u32 {
-		self.client
-			.storage(at, &storage_key(b"System", b"LastRuntimeUpgrade"))
-			.ok()
-			.flatten()
-			.and_then(|data| codec::Compact::<u32>::decode(&mut &data.0[..]).ok())
-			.map(|v| v.0)
-			.unwrap_or_default()
-	}
-
 	fn build_layout(&self, at: Hash, spec: u32) -> Option<Arc<EventLayout>> {
-		let api = self.client.runtime_api();
-		let raw = api
-			.metadata_at_version(at, METADATA_VERSION)
-			.ok()
-			.flatten()
-			.or_else(|| api.metadata(at).ok())?;
+		let raw = runtime_metadata::<Block, _>(self.client.as_ref(), at)?;
 		let layout = match EventLayout::new(&raw) {
 			Ok(layout) => layout,
 			Err(fault) => {
@@ -529,7 +545,7 @@ mod tests {
 		let (parent, upgrade) = (Hash::repeat_byte(1), Hash::repeat_byte(2));
 		let headers = Headers(vec![(upgrade, parent)]);
 		assert_eq!(
-			metadata_source(&headers, upgrade),
+			metadata_source::<Block, _>(&headers, upgrade),
 			parent,
 			"a block's events were produced by the runtime in its PARENT's post-state"
 		);
@@ -541,11 +557,15 @@ mod tests {
 	fn genesis_and_unknown_blocks_fall_back_to_themselves() {
 		let genesis = Hash::repeat_byte(3);
 		let headers = Headers(vec![(genesis, Hash::default())]);
-		assert_eq!(metadata_source(&headers, genesis), genesis, "genesis has no parent");
+		assert_eq!(
+			metadata_source::<Block, _>(&headers, genesis),
+			genesis,
+			"genesis has no parent"
+		);
 
 		let orphan = Hash::repeat_byte(9);
 		assert_eq!(
-			metadata_source(&Headers(Vec::new()), orphan),
+			metadata_source::<Block, _>(&Headers(Vec::new()), orphan),
 			orphan,
 			"an unknown header must not send us to the zero hash"
 		);
@@ -7,6 +7,7 @@ pub type AssetId = u32;
 pub type Balance = u128;
 pub type IncrementalIdType = u32;
 pub type OtcOrderId = u32;
+pub type PoolId = u32;
 pub type ScheduleId = u32;
 
 #[derive(Encode, Decode, Clone, Copy, Debug, DecodeWithMemTracking, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
@@ -16,8 +17,10 @@ pub enum Filler {
 	XYK(AssetId),        // share token
 	LBP,
 	OTC(OtcOrderId),
-	AAVE, // ICE(solution_id/block id),      swapper: alice, filler: solver
+	AAVE,
 	HSM,
+	ICE(IncrementalIdType), // solution id, swapper: intent owner, filler: solver
+	ConcentratedLiquidity(PoolId),
 }
 
 #[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
@@ -62,6 +65,7 @@ pub enum ExecutionType {
 	Omnipool(IncrementalIdType),
 	XcmExchange(IncrementalIdType),
 	Xcm([u8; 32], IncrementalIdType),
+	ICE(IncrementalIdType),
 }
 
 #[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
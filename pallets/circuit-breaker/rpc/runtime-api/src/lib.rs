@@ -0,0 +1,54 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the circuit breaker pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+
+/// Per-account limit of an account at the current timestamp.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AccountLimitStatus<Balance> {
+	/// Limit within the rolling window.
+	pub limit: Balance,
+	/// Length of the rolling window in milliseconds.
+	pub window: u64,
+	/// Amount already used by the account within the window.
+	pub used: Balance,
+	/// Amount the account can still use before the limit is reached.
+	pub remaining: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the per-account limits of the circuit breaker.
+	pub trait CircuitBreakerApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Return the trade volume limit of the account, in hub asset units.
+		/// `None` if trade volume is not limited for the account.
+		fn account_trade_volume_limit(who: AccountId) -> Option<AccountLimitStatus<Balance>>;
+
+		/// Return the egress limit of the account, in reference currency units.
+		/// `None` if egress is not limited for the account.
+		fn account_egress_limit(who: AccountId) -> Option<AccountLimitStatus<Balance>>;
+	}
+}
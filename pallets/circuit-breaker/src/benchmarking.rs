@@ -19,7 +19,7 @@
 
 use super::*;
 
-use crate::types::BenchmarkHelper;
+use crate::types::{BenchmarkHelper, PriceDeviationAction};
 use frame_benchmarking::{account, benchmarks};
 use frame_support::{assert_ok, traits::Hooks};
 use frame_system::RawOrigin;
@@ -347,5 +347,95 @@ benchmarks! {
 		//No verify as if successfull, the extrinsic completed
 	}
 
+	set_price_deviation_limit {
+		let asset_id = T::AssetId::from(2u32);
+		let limit = Some(PriceDeviationLimit {
+			max_deviation: Permill::from_percent(10),
+			action: PriceDeviationAction::Lockdown,
+		});
+	}: _(RawOrigin::Root, asset_id, limit)
+	verify {
+		assert_eq!(crate::Pallet::<T>::price_deviation_limit_per_asset(asset_id), limit);
+	}
+
+	lift_price_deviation_lockdown {
+		let asset_id = T::AssetId::from(2u32);
+		PriceDeviationLockdown::<T>::insert(asset_id, BlockNumberFor::<T>::from(1u32));
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert!(crate::Pallet::<T>::price_deviation_lockdown(asset_id).is_none());
+	}
+
+	ensure_price_deviation_limit {
+		let asset_id = T::AssetId::from(2u32);
+		let reference_asset = T::AssetId::from(3u32);
+		crate::Pallet::<T>::set_price_deviation_limit(RawOrigin::Root.into(), asset_id, Some(PriceDeviationLimit {
+			max_deviation: Permill::from_percent(10),
+			action: PriceDeviationAction::Lockdown,
+		}))?;
+	}: {
+		crate::Pallet::<T>::ensure_price_deviation_limit(*b"omnipool", asset_id, reference_asset, EmaPrice::new(1, 1))?
+	}
+	verify {
+		assert!(crate::Pallet::<T>::price_deviation_lockdown(asset_id).is_none());
+	}
+
+	set_account_limit_params {
+		let parameters = Some(AccountLimitParameters {
+			limit: 1_000_000_000_000_000u128.into(),
+			window: 86_400_000,
+		});
+	}: _(RawOrigin::Root, AccountLimitKind::Egress, parameters.clone())
+	verify {
+		assert_eq!(crate::Pallet::<T>::account_limit_config(AccountLimitKind::Egress), parameters);
+	}
+
+	add_account_limit_whitelist {
+		let n in 0 .. MAX_ACCOUNT_LIMIT_WHITELIST_UPDATE;
+		let mut accounts: Vec<T::AccountId> = Vec::with_capacity(n as usize);
+		for i in 0..n {
+			let acc: T::AccountId = frame_benchmarking::account("whitelisted", i, 0);
+			accounts.push(acc);
+		}
+		let accounts: BoundedVec<T::AccountId, ConstU32<MAX_ACCOUNT_LIMIT_WHITELIST_UPDATE>> = accounts.try_into().unwrap();
+	}: _(RawOrigin::Root, accounts)
+	verify {
+		if n > 0 {
+			let last = frame_benchmarking::account::<T::AccountId>("whitelisted", n-1, 0);
+			assert!(AccountLimitWhitelist::<T>::contains_key(last));
+		}
+	}
+
+	remove_account_limit_whitelist {
+		let n in 0 .. MAX_ACCOUNT_LIMIT_WHITELIST_UPDATE;
+		let mut accounts: Vec<T::AccountId> = Vec::with_capacity(n as usize);
+		for i in 0..n {
+			let acc: T::AccountId = frame_benchmarking::account("whitelisted", i, 0);
+			AccountLimitWhitelist::<T>::insert(&acc, ());
+			accounts.push(acc);
+		}
+		let accounts: BoundedVec<T::AccountId, ConstU32<MAX_ACCOUNT_LIMIT_WHITELIST_UPDATE>> = accounts.try_into().unwrap();
+	}: _(RawOrigin::Root, accounts)
+	verify {
+		if n > 0 {
+			let last = frame_benchmarking::account::<T::AccountId>("whitelisted", n-1, 0);
+			assert!(!AccountLimitWhitelist::<T>::contains_key(last));
+		}
+	}
+
+	note_account_activity {
+		let user: T::AccountId = account("user", 0, 1);
+		crate::Pallet::<T>::set_account_limit_params(RawOrigin::Root.into(), AccountLimitKind::TradeVolume, Some(AccountLimitParameters {
+			limit: 1_000_000_000_000_000u128.into(),
+			window: 86_400_000,
+		}))?;
+		let amount: T::Balance = 1_000_000_000_000u128.into();
+	}: {
+		crate::Pallet::<T>::note_account_activity(&user, AccountLimitKind::TradeVolume, amount)?
+	}
+	verify {
+		assert_eq!(AccountLimitAccumulator::<T>::get(&user, AccountLimitKind::TradeVolume).0, amount);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
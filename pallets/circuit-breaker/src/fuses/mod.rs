@@ -1 +1,2 @@
 pub mod issuance;
+pub mod price_deviation;
@@ -0,0 +1,80 @@
+use crate::types::{PriceDeviationAction, PriceDeviationLimit};
+use crate::{Config, Error, Event, Pallet, PriceDeviationLimitPerAsset, PriceDeviationLockdown};
+use frame_support::ensure;
+use frame_support::traits::Get;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::oracle::{AggregatedPriceOracle, Source};
+use sp_core::U256;
+use sp_runtime::{DispatchResult, Permill};
+use sp_std::marker::PhantomData;
+
+/// Halts trading of an asset when its spot price moves too far away from the oracle price.
+///
+/// The spot price of a pool after a trade is compared against the price of the same pair from the oracle
+/// aggregated over `PriceDeviationOraclePeriod`. If the deviation exceeds the limit configured for the asset,
+/// the trade is either rejected or the asset is locked down, depending on the configured action.
+pub struct PriceDeviationFuse<T: Config>(PhantomData<T>);
+
+impl<T: Config> PriceDeviationFuse<T> {
+	/// Check the spot price of `asset_id` denominated in `reference_asset` against the oracle of `source`.
+	pub fn ensure_price_within_limit(
+		source: Source,
+		asset_id: T::AssetId,
+		reference_asset: T::AssetId,
+		spot_price: EmaPrice,
+	) -> DispatchResult {
+		if asset_id == T::OmnipoolHubAsset::get() {
+			return Ok(());
+		}
+
+		ensure!(
+			!PriceDeviationLockdown::<T>::contains_key(asset_id),
+			Error::<T>::AssetTradingLocked
+		);
+
+		let Some(PriceDeviationLimit { max_deviation, action }) = PriceDeviationLimitPerAsset::<T>::get(asset_id)
+		else {
+			return Ok(());
+		};
+
+		// The fuse cannot be evaluated until the oracle of the pair has some history.
+		let Ok((oracle_price, _)) =
+			T::PriceOracle::get_price(asset_id, reference_asset, T::PriceDeviationOraclePeriod::get(), source)
+		else {
+			return Ok(());
+		};
+
+		let deviation = Self::calculate_deviation(spot_price, oracle_price);
+		if deviation <= max_deviation {
+			return Ok(());
+		}
+
+		match action {
+			PriceDeviationAction::Reject => Err(Error::<T>::PriceDeviationLimitReached.into()),
+			PriceDeviationAction::Lockdown => {
+				PriceDeviationLockdown::<T>::insert(asset_id, <frame_system::Pallet<T>>::block_number());
+				Pallet::<T>::deposit_event(Event::PriceDeviationLockdownTriggered { asset_id, deviation });
+				Ok(())
+			}
+		}
+	}
+
+	/// Relative difference between the spot and the oracle price, capped at 100%.
+	pub fn calculate_deviation(spot_price: EmaPrice, oracle_price: EmaPrice) -> Permill {
+		if oracle_price.is_zero() {
+			return Permill::one();
+		}
+
+		let spot = U256::from(spot_price.n).saturating_mul(U256::from(oracle_price.d));
+		let oracle = U256::from(oracle_price.n).saturating_mul(U256::from(spot_price.d));
+		let diff = if spot > oracle { spot - oracle } else { oracle - spot };
+
+		if diff >= oracle {
+			return Permill::one();
+		}
+
+		// `diff < oracle`, so the result fits into the accuracy of `Permill`.
+		let parts = diff.saturating_mul(U256::from(Permill::ACCURACY)) / oracle;
+		Permill::from_parts(parts.low_u32())
+	}
+}
@@ -22,16 +22,24 @@
 use codec::{Decode, Encode};
 use frame_support::traits::{Contains, EnsureOrigin, Time};
 use frame_support::weights::Weight;
-use frame_support::{dispatch::Pays, ensure, pallet_prelude::DispatchResult, traits::Get};
+use frame_support::{
+	dispatch::Pays,
+	ensure,
+	pallet_prelude::{ConstU32, DispatchResult},
+	traits::Get,
+	BoundedVec,
+};
 use frame_system::ensure_signed_or_root;
 use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::oracle::{AggregatedPriceOracle, OraclePeriod, Source};
 use orml_traits::Handler;
 use orml_traits::{GetByKey, Happened};
 use scale_info::TypeInfo;
 use sp_core::MaxEncodedLen;
 use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Zero};
 use sp_runtime::Saturating;
-use sp_runtime::{ArithmeticError, DispatchError, RuntimeDebug};
+use sp_runtime::{ArithmeticError, DispatchError, Permill, RuntimeDebug};
 use sp_std::vec::Vec;
 pub mod weights;
 
@@ -47,6 +55,9 @@ pub mod types;
 /// Max trade volume limit multiplier of liquidity that can be traded in a block
 pub const MAX_LIMIT_VALUE: u32 = 10_000;
 
+/// Max number of accounts that can be added to or removed from the account limit whitelist in one call
+pub const MAX_ACCOUNT_LIMIT_WHITELIST_UPDATE: u32 = 100;
+
 #[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
 #[scale_info(skip_type_params(T))]
 pub struct TradeVolumeLimit<T: Config> {
@@ -129,8 +140,11 @@ where
 }
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
+use crate::fuses::price_deviation::PriceDeviationFuse;
 use crate::traits::AssetDepositLimiter;
-use crate::types::{GlobalWithdrawLimitParameters, LockdownStatus};
+use crate::types::{
+	AccountLimitKind, AccountLimitParameters, GlobalWithdrawLimitParameters, LockdownStatus, PriceDeviationLimit,
+};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -258,6 +272,13 @@ pub mod pallet {
 		type BenchmarkHelper: types::BenchmarkHelper<Self::AccountId, Self::AssetId, Self::Balance>;
 
 		type TimestampProvider: Time<Moment = primitives::Moment>;
+
+		/// Oracle providing the reference price for the price deviation fuse.
+		type PriceOracle: AggregatedPriceOracle<Self::AssetId, BlockNumberFor<Self>, EmaPrice>;
+
+		/// Oracle period the spot price is compared against by the price deviation fuse.
+		#[pallet::constant]
+		type PriceDeviationOraclePeriod: Get<OraclePeriod>;
 	}
 
 	#[pallet::pallet]
@@ -365,6 +386,48 @@ pub mod pallet {
 	/// None means buffer is inactive (not inside XCM message processing).
 	pub type XcmEgressBuffer<T: Config> = StorageValue<_, (T::Balance, T::Balance), OptionQuery>;
 
+	#[pallet::storage]
+	/// Maximum deviation of the spot price from the oracle price per asset.
+	/// If not set, the price deviation is not checked.
+	#[pallet::getter(fn price_deviation_limit_per_asset)]
+	pub type PriceDeviationLimitPerAsset<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, PriceDeviationLimit, OptionQuery>;
+
+	#[pallet::storage]
+	/// Assets locked down by the price deviation fuse with the block number of the lockdown.
+	#[pallet::getter(fn price_deviation_lockdown)]
+	pub type PriceDeviationLockdown<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn account_limit_config)]
+	/// Configured per-account limits. If not set, the activity is not limited per account.
+	pub type AccountLimitConfig<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountLimitKind,
+		AccountLimitParameters<T::Balance, primitives::Moment>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn account_limit_accumulator)]
+	/// Tuple of (current_accumulator, last_update_timestamp_ms) per account and limit kind.
+	pub type AccountLimitAccumulator<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		AccountLimitKind,
+		(T::Balance, primitives::Moment),
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_account_limit_whitelisted)]
+	/// Accounts exempt from the per-account limits.
+	pub type AccountLimitWhitelist<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -414,6 +477,24 @@ pub mod pallet {
 			asset_id: T::AssetId,
 			category: Option<GlobalAssetCategory>,
 		},
+		/// Price deviation limit of an asset was changed.
+		PriceDeviationLimitChanged {
+			asset_id: T::AssetId,
+			limit: Option<PriceDeviationLimit>,
+		},
+		/// Trading of an asset was locked down because its spot price deviated too much from the oracle price.
+		PriceDeviationLockdownTriggered { asset_id: T::AssetId, deviation: Permill },
+		/// Price deviation lockdown of an asset was lifted.
+		PriceDeviationLockdownLifted { asset_id: T::AssetId },
+		/// Per-account limit config parameters were updated.
+		AccountLimitConfigUpdated {
+			kind: AccountLimitKind,
+			parameters: Option<AccountLimitParameters<T::Balance, primitives::Moment>>,
+		},
+		/// A number of accounts added to the account limit whitelist.
+		AccountLimitWhitelistAdded { count: u32 },
+		/// A number of accounts removed from the account limit whitelist.
+		AccountLimitWhitelistRemoved { count: u32 },
 	}
 
 	#[pallet::error]
@@ -448,6 +529,16 @@ pub mod pallet {
 		GlobalWithdrawLimitExceeded,
 		/// Asset to withdraw cannot be converted to reference currency.
 		FailedToConvertAsset,
+		/// Spot price deviates from the oracle price more than allowed for the asset
+		PriceDeviationLimitReached,
+		/// Trading of the asset is locked down by the price deviation fuse
+		AssetTradingLocked,
+		/// Asset is not locked down by the price deviation fuse
+		AssetNotInPriceDeviationLockdown,
+		/// Trade volume limit of the account within the window has been reached
+		AccountTradeVolumeLimitExceeded,
+		/// Egress limit of the account within the window has been reached
+		AccountEgressLimitExceeded,
 	}
 
 	#[pallet::call]
@@ -730,6 +821,145 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set price deviation limit for an asset.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `AuthorityOrigin`
+		/// - `asset_id`: The identifier of an asset
+		/// - `limit`: Maximum deviation of the spot price from the oracle price and the action taken when it is
+		///   exceeded. If set to None, the price deviation is not checked.
+		///
+		/// Emits `PriceDeviationLimitChanged` event when successful.
+		///
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_price_deviation_limit())]
+		pub fn set_price_deviation_limit(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			limit: Option<PriceDeviationLimit>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_id != T::OmnipoolHubAsset::get(), Error::<T>::NotAllowed);
+
+			match limit {
+				Some(l) => {
+					ensure!(!l.max_deviation.is_zero(), Error::<T>::InvalidLimitValue);
+					<PriceDeviationLimitPerAsset<T>>::insert(asset_id, l);
+				}
+				None => <PriceDeviationLimitPerAsset<T>>::remove(asset_id),
+			}
+
+			Self::deposit_event(Event::PriceDeviationLimitChanged { asset_id, limit });
+
+			Ok(())
+		}
+
+		/// Lift the price deviation lockdown of an asset.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `AuthorityOrigin`
+		/// - `asset_id`: The identifier of an asset
+		///
+		/// Emits `PriceDeviationLockdownLifted` event when successful.
+		///
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::lift_price_deviation_lockdown())]
+		pub fn lift_price_deviation_lockdown(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				<PriceDeviationLockdown<T>>::take(asset_id).is_some(),
+				Error::<T>::AssetNotInPriceDeviationLockdown
+			);
+
+			Self::deposit_event(Event::PriceDeviationLockdownLifted { asset_id });
+
+			Ok(())
+		}
+
+		/// Set the per-account limit of an activity.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `AuthorityOrigin`
+		/// - `kind`: The limited activity
+		/// - `parameters`: Limit within the rolling window. If set to None, the activity is not limited per account.
+		///
+		/// Emits `AccountLimitConfigUpdated` event when successful.
+		///
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_account_limit_params())]
+		pub fn set_account_limit_params(
+			origin: OriginFor<T>,
+			kind: AccountLimitKind,
+			parameters: Option<AccountLimitParameters<T::Balance, primitives::Moment>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			match &parameters {
+				Some(p) => {
+					ensure!(!p.window.is_zero(), Error::<T>::InvalidLimitValue);
+					AccountLimitConfig::<T>::insert(kind, p);
+				}
+				None => AccountLimitConfig::<T>::remove(kind),
+			}
+
+			Self::deposit_event(Event::AccountLimitConfigUpdated { kind, parameters });
+			Ok(())
+		}
+
+		/// Exempt accounts from the per-account limits.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `AuthorityOrigin`
+		/// - `accounts`: Accounts to add to the whitelist. At most `MAX_ACCOUNT_LIMIT_WHITELIST_UPDATE` accounts.
+		///
+		/// Emits `AccountLimitWhitelistAdded` event when successful.
+		///
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_account_limit_whitelist(accounts.len() as u32))]
+		pub fn add_account_limit_whitelist(
+			origin: OriginFor<T>,
+			accounts: BoundedVec<T::AccountId, ConstU32<MAX_ACCOUNT_LIMIT_WHITELIST_UPDATE>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			for account in &accounts {
+				AccountLimitWhitelist::<T>::insert(account, ());
+			}
+			Self::deposit_event(Event::AccountLimitWhitelistAdded {
+				count: accounts.len() as u32,
+			});
+
+			Ok(())
+		}
+
+		/// Remove accounts from the account limit whitelist, so the per-account limits apply to them again.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `AuthorityOrigin`
+		/// - `accounts`: Accounts to remove from the whitelist. At most `MAX_ACCOUNT_LIMIT_WHITELIST_UPDATE` accounts.
+		///
+		/// Emits `AccountLimitWhitelistRemoved` event when successful.
+		///
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_account_limit_whitelist(accounts.len() as u32))]
+		pub fn remove_account_limit_whitelist(
+			origin: OriginFor<T>,
+			accounts: BoundedVec<T::AccountId, ConstU32<MAX_ACCOUNT_LIMIT_WHITELIST_UPDATE>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			for account in &accounts {
+				AccountLimitWhitelist::<T>::remove(account);
+			}
+			Self::deposit_event(Event::AccountLimitWhitelistRemoved {
+				count: accounts.len() as u32,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -827,6 +1057,74 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Returns true if the account is not subject to the per-account limits.
+	pub fn is_exempt_from_account_limits(who: &T::AccountId) -> bool {
+		T::WhitelistedAccounts::contains(who) || Self::is_account_limit_whitelisted(who).is_some()
+	}
+
+	/// Returns true if the activity of the account is limited by `kind`, i.e. the limit is configured and the account
+	/// is not exempt from it. Activity of accounts that are not limited doesn't have to be noted.
+	pub fn is_account_limited(who: &T::AccountId, kind: AccountLimitKind) -> bool {
+		Self::account_limit_config(kind).is_some_and(|parameters| parameters.window >= 1)
+			&& !Self::is_exempt_from_account_limits(who)
+	}
+
+	/// Current value of the account accumulator, decayed linearly over the configured window.
+	fn decayed_account_accumulator(
+		who: &T::AccountId,
+		kind: AccountLimitKind,
+		window: primitives::Moment,
+		now: primitives::Moment,
+	) -> T::Balance {
+		let (current, last_update) = Self::account_limit_accumulator(who, kind);
+		let capped_dt = now.saturating_sub(last_update).min(window);
+		let decay = sp_runtime::Perbill::from_rational(capped_dt, window).mul_floor(current);
+
+		current.saturating_sub(decay)
+	}
+
+	/// Apply an increment to the accumulator of the account.
+	/// Fails if the new value would exceed the configured per-account limit.
+	pub fn note_account_activity(who: &T::AccountId, kind: AccountLimitKind, amount: T::Balance) -> DispatchResult {
+		let Some(AccountLimitParameters { limit, window }) = Self::account_limit_config(kind) else {
+			return Ok(());
+		};
+		if window < 1 || Self::is_exempt_from_account_limits(who) {
+			return Ok(());
+		}
+
+		let now = Self::timestamp_now();
+		let current = Self::decayed_account_accumulator(who, kind, window, now);
+		let new_current = current.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			new_current <= limit,
+			match kind {
+				AccountLimitKind::TradeVolume => Error::<T>::AccountTradeVolumeLimitExceeded,
+				AccountLimitKind::Egress => Error::<T>::AccountEgressLimitExceeded,
+			}
+		);
+
+		AccountLimitAccumulator::<T>::insert(who, kind, (new_current, now));
+
+		Ok(())
+	}
+
+	/// Returns the configured per-account limit and the amount of it used by the account at the current timestamp.
+	/// None if the activity is not limited for the account.
+	pub fn account_limit_usage(
+		who: &T::AccountId,
+		kind: AccountLimitKind,
+	) -> Option<(AccountLimitParameters<T::Balance, primitives::Moment>, T::Balance)> {
+		let parameters = Self::account_limit_config(kind)?;
+		if parameters.window < 1 || Self::is_exempt_from_account_limits(who) {
+			return None;
+		}
+
+		let used = Self::decayed_account_accumulator(who, kind, parameters.window, Self::timestamp_now());
+		Some((parameters, used))
+	}
+
 	pub fn note_deposit(amount: T::Balance) {
 		let now = Self::timestamp_now();
 		if !Self::is_lockdown_at(now) {
@@ -995,6 +1293,21 @@ impl<T: Config> Pallet<T> {
 		Ok(T::WeightInfo::ensure_pool_state_change_limit())
 	}
 
+	/// Check the spot price of `asset_id` after a trade against the oracle price of the pair.
+	///
+	/// `spot_price` is the price of `asset_id` denominated in `reference_asset`, in the same orientation as the
+	/// oracle entry of `source`.
+	pub fn ensure_price_deviation_limit(
+		source: Source,
+		asset_id: T::AssetId,
+		reference_asset: T::AssetId,
+		spot_price: EmaPrice,
+	) -> Result<Weight, DispatchError> {
+		PriceDeviationFuse::<T>::ensure_price_within_limit(source, asset_id, reference_asset, spot_price)?;
+
+		Ok(T::WeightInfo::ensure_price_deviation_limit())
+	}
+
 	pub fn ensure_add_liquidity_limit(
 		origin: OriginFor<T>,
 		asset_id: T::AssetId,
@@ -0,0 +1,335 @@
+use crate::tests::mock::*;
+use crate::types::{AccountLimitKind, AccountLimitParameters};
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
+
+const DAY: primitives::Moment = primitives::constants::time::unix_time::DAY;
+const CHARLIE: AccountId = 3;
+
+fn set_limit(kind: AccountLimitKind, limit: Balance) {
+	assert_ok!(CircuitBreaker::set_account_limit_params(
+		RuntimeOrigin::root(),
+		kind,
+		Some(AccountLimitParameters { limit, window: DAY })
+	));
+}
+
+#[test]
+fn set_account_limit_params_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		set_limit(AccountLimitKind::TradeVolume, 1_000);
+
+		// Assert
+		let parameters = Some(AccountLimitParameters {
+			limit: 1_000,
+			window: DAY,
+		});
+		assert_eq!(
+			CircuitBreaker::account_limit_config(AccountLimitKind::TradeVolume),
+			parameters
+		);
+		assert_eq!(CircuitBreaker::account_limit_config(AccountLimitKind::Egress), None);
+		System::assert_last_event(
+			Event::AccountLimitConfigUpdated {
+				kind: AccountLimitKind::TradeVolume,
+				parameters,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_account_limit_params_should_remove_limit_when_none() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_limit(AccountLimitKind::Egress, 1_000);
+
+		// Act
+		assert_ok!(CircuitBreaker::set_account_limit_params(
+			RuntimeOrigin::root(),
+			AccountLimitKind::Egress,
+			None
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::account_limit_config(AccountLimitKind::Egress), None);
+		assert_ok!(CircuitBreaker::note_account_activity(
+			&ALICE,
+			AccountLimitKind::Egress,
+			10_000
+		));
+	});
+}
+
+#[test]
+fn set_account_limit_params_should_fail_when_window_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_account_limit_params(
+				RuntimeOrigin::root(),
+				AccountLimitKind::Egress,
+				Some(AccountLimitParameters {
+					limit: 1_000,
+					window: 0
+				})
+			),
+			Error::<Test>::InvalidLimitValue
+		);
+	});
+}
+
+#[test]
+fn set_account_limit_params_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_account_limit_params(
+				RuntimeOrigin::signed(ALICE),
+				AccountLimitKind::Egress,
+				Some(AccountLimitParameters {
+					limit: 1_000,
+					window: DAY
+				})
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn note_account_activity_should_increment_accumulator_of_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_limit(AccountLimitKind::TradeVolume, 1_000);
+
+		// Act
+		assert_ok!(CircuitBreaker::note_account_activity(
+			&ALICE,
+			AccountLimitKind::TradeVolume,
+			100
+		));
+		assert_ok!(CircuitBreaker::note_account_activity(
+			&ALICE,
+			AccountLimitKind::TradeVolume,
+			200
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::account_limit_accumulator(ALICE, AccountLimitKind::TradeVolume),
+			(300, 0)
+		);
+		assert_eq!(
+			CircuitBreaker::account_limit_accumulator(CHARLIE, AccountLimitKind::TradeVolume),
+			(0, 0)
+		);
+		assert_eq!(
+			CircuitBreaker::account_limit_accumulator(ALICE, AccountLimitKind::Egress),
+			(0, 0)
+		);
+	});
+}
+
+#[test]
+fn note_account_activity_should_fail_when_limit_of_account_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_limit(AccountLimitKind::TradeVolume, 1_000);
+		set_limit(AccountLimitKind::Egress, 1_000);
+		assert_ok!(CircuitBreaker::note_account_activity(
+			&ALICE,
+			AccountLimitKind::TradeVolume,
+			1_000
+		));
+		assert_ok!(CircuitBreaker::note_account_activity(
+			&ALICE,
+			AccountLimitKind::Egress,
+			1_000
+		));
+
+		// Act & Assert
+		assert_noop!(
+			CircuitBreaker::note_account_activity(&ALICE, AccountLimitKind::TradeVolume, 1),
+			Error::<Test>::AccountTradeVolumeLimitExceeded
+		);
+		assert_noop!(
+			CircuitBreaker::note_account_activity(&ALICE, AccountLimitKind::Egress, 1),
+			Error::<Test>::AccountEgressLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn note_account_activity_should_not_affect_other_accounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_limit(AccountLimitKind::Egress, 1_000);
+		assert_ok!(CircuitBreaker::note_account_activity(
+			&ALICE,
+			AccountLimitKind::Egress,
+			1_000
+		));
+
+		// Act & Assert
+		assert_ok!(CircuitBreaker::note_account_activity(
+			&CHARLIE,
+			AccountLimitKind::Egress,
+			1_000
+		));
+	});
+}
+
+#[test]
+fn note_account_activity_should_decay_accumulator_over_window() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_limit(AccountLimitKind::Egress, 1_000);
+		pallet_timestamp::Pallet::<Test>::set_timestamp(0);
+		assert_ok!(CircuitBreaker::note_account_activity(
+			&ALICE,
+			AccountLimitKind::Egress,
+			1_000
+		));
+
+		// Act
+		// 12h passed => 50% decay
+		pallet_timestamp::Pallet::<Test>::set_timestamp(DAY / 2);
+		assert_ok!(CircuitBreaker::note_account_activity(
+			&ALICE,
+			AccountLimitKind::Egress,
+			500
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::account_limit_accumulator(ALICE, AccountLimitKind::Egress),
+			(1_000, DAY / 2)
+		);
+		assert_noop!(
+			CircuitBreaker::note_account_activity(&ALICE, AccountLimitKind::Egress, 1),
+			Error::<Test>::AccountEgressLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn note_account_activity_should_skip_whitelisted_accounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_limit(AccountLimitKind::Egress, 1_000);
+		assert_ok!(CircuitBreaker::add_account_limit_whitelist(
+			RuntimeOrigin::root(),
+			vec![CHARLIE].try_into().unwrap()
+		));
+
+		// Act & Assert
+		assert_ok!(CircuitBreaker::note_account_activity(
+			&CHARLIE,
+			AccountLimitKind::Egress,
+			10_000
+		));
+		assert_ok!(CircuitBreaker::note_account_activity(
+			&WHITELISTED_ACCCOUNT,
+			AccountLimitKind::Egress,
+			10_000
+		));
+		assert_eq!(
+			CircuitBreaker::account_limit_accumulator(CHARLIE, AccountLimitKind::Egress),
+			(0, 0)
+		);
+	});
+}
+
+#[test]
+fn remove_account_limit_whitelist_should_limit_account_again() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_limit(AccountLimitKind::Egress, 1_000);
+		assert_ok!(CircuitBreaker::add_account_limit_whitelist(
+			RuntimeOrigin::root(),
+			vec![CHARLIE, ALICE].try_into().unwrap()
+		));
+		System::assert_last_event(Event::AccountLimitWhitelistAdded { count: 2 }.into());
+
+		// Act
+		assert_ok!(CircuitBreaker::remove_account_limit_whitelist(
+			RuntimeOrigin::root(),
+			vec![CHARLIE].try_into().unwrap()
+		));
+
+		// Assert
+		System::assert_last_event(Event::AccountLimitWhitelistRemoved { count: 1 }.into());
+		assert!(CircuitBreaker::is_account_limit_whitelisted(ALICE).is_some());
+		assert_noop!(
+			CircuitBreaker::note_account_activity(&CHARLIE, AccountLimitKind::Egress, 1_001),
+			Error::<Test>::AccountEgressLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn is_account_limited_should_be_false_when_limit_is_not_configured_or_account_is_exempt() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_limit(AccountLimitKind::TradeVolume, 1_000);
+		assert_ok!(CircuitBreaker::add_account_limit_whitelist(
+			RuntimeOrigin::root(),
+			vec![CHARLIE].try_into().unwrap()
+		));
+
+		// Act & Assert
+		assert!(CircuitBreaker::is_account_limited(
+			&ALICE,
+			AccountLimitKind::TradeVolume
+		));
+		assert!(!CircuitBreaker::is_account_limited(&ALICE, AccountLimitKind::Egress));
+		assert!(!CircuitBreaker::is_account_limited(
+			&CHARLIE,
+			AccountLimitKind::TradeVolume
+		));
+		assert!(!CircuitBreaker::is_account_limited(
+			&WHITELISTED_ACCCOUNT,
+			AccountLimitKind::TradeVolume
+		));
+	});
+}
+
+#[test]
+fn account_limit_usage_should_return_decayed_usage() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		set_limit(AccountLimitKind::TradeVolume, 1_000);
+		pallet_timestamp::Pallet::<Test>::set_timestamp(0);
+		assert_ok!(CircuitBreaker::note_account_activity(
+			&ALICE,
+			AccountLimitKind::TradeVolume,
+			800
+		));
+
+		// Act
+		pallet_timestamp::Pallet::<Test>::set_timestamp(DAY / 4);
+		let usage = CircuitBreaker::account_limit_usage(&ALICE, AccountLimitKind::TradeVolume);
+
+		// Assert
+		assert_eq!(
+			usage,
+			Some((
+				AccountLimitParameters {
+					limit: 1_000,
+					window: DAY
+				},
+				600
+			))
+		);
+		assert_eq!(
+			CircuitBreaker::account_limit_usage(&ALICE, AccountLimitKind::Egress),
+			None
+		);
+		assert_eq!(
+			CircuitBreaker::account_limit_usage(&WHITELISTED_ACCCOUNT, AccountLimitKind::TradeVolume),
+			None
+		);
+	});
+}
@@ -23,6 +23,7 @@ pub use frame_support::{assert_noop, assert_ok, parameter_types};
 use frame_support::PalletId;
 use frame_system::EnsureRoot;
 use hydra_dx_math::omnipool::types::BalanceUpdate;
+use hydradx_traits::oracle::{AggregatedPriceOracle, OraclePeriod, Source};
 #[cfg(feature = "runtime-benchmarks")]
 use orml_traits::MultiCurrency;
 use orml_traits::{parameter_type_with_key, GetByKey, Handler, Happened, NamedMultiReservableCurrency};
@@ -69,7 +70,6 @@ pub const NATIVE_AMOUNT: Balance = 10_000 * ONE;
 pub const FIVE_PERCENT: (u32, u32) = (500, 10_000);
 pub const TEN_PERCENT: (u32, u32) = (1_000, 10_000);
 
-#[cfg(feature = "runtime-benchmarks")]
 use frame_system::pallet_prelude::BlockNumberFor;
 #[cfg(feature = "runtime-benchmarks")]
 pub const DEFAULT_ASSET_DEPOSIT_PERIOD: BlockNumberFor<Test> = 10;
@@ -89,6 +89,7 @@ thread_local! {
 	pub static MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = const { RefCell::new(Some((2_000, 10_000))) }; // 20%
 	pub static ASSET_DEPOSIT_LIMIT: RefCell<HashMap<AssetId, Balance>> = RefCell::new(HashMap::default());
 	pub static ASSET_DEPOSIT_PERIOD: RefCell<u128> = RefCell::new(u128::zero());
+	pub static ORACLE_PRICES: RefCell<HashMap<(AssetId, AssetId), EmaPrice>> = RefCell::new(HashMap::default());
 }
 
 frame_support::construct_runtime!(
@@ -180,6 +181,7 @@ parameter_types! {
 	pub DefaultMaxAddLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub DefaultMaxRemoveLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub const OmnipoolHubAsset: AssetId = LRNA;
+	pub const PriceDeviationOraclePeriod: OraclePeriod = OraclePeriod::Short;
 }
 
 impl pallet_circuit_breaker::Config for Test {
@@ -195,11 +197,35 @@ impl pallet_circuit_breaker::Config for Test {
 	type WeightInfo = ();
 	type DepositLimiter = DepositLimiter;
 	type TimestampProvider = Timestamp;
+	type PriceOracle = PriceOracleMock;
+	type PriceDeviationOraclePeriod = PriceDeviationOraclePeriod;
 
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = BenchmarkHelperMock;
 }
 
+pub struct PriceOracleMock;
+
+impl AggregatedPriceOracle<AssetId, BlockNumberFor<Test>, EmaPrice> for PriceOracleMock {
+	type Error = ();
+
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+	) -> Result<(EmaPrice, BlockNumberFor<Test>), Self::Error> {
+		ORACLE_PRICES
+			.with(|v| v.borrow().get(&(asset_a, asset_b)).copied())
+			.map(|price| (price, 0))
+			.ok_or(())
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub struct BenchmarkHelperMock;
 #[cfg(feature = "runtime-benchmarks")]
@@ -574,6 +600,9 @@ impl Default for ExtBuilder {
 		ASSET_DEPOSIT_PERIOD.with(|v| {
 			*v.borrow_mut() = 0u128;
 		});
+		ORACLE_PRICES.with(|v| {
+			v.borrow_mut().clear();
+		});
 
 		Self {
 			endowed_accounts: vec![
@@ -645,6 +674,13 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_oracle_price(self, asset_id: AssetId, reference_asset: AssetId, price: EmaPrice) -> Self {
+		ORACLE_PRICES.with(|v| {
+			v.borrow_mut().insert((asset_id, reference_asset), price);
+		});
+		self
+	}
+
 	pub fn with_token(
 		mut self,
 		asset_id: AssetId,
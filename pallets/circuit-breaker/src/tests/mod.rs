@@ -1,3 +1,4 @@
+mod account_limits;
 mod add_liquidity_limit;
 mod deposit_limit;
 mod ensure_add_liquidity_limit;
@@ -6,6 +7,7 @@ mod global_withdraw_limit;
 mod lockdown_asset;
 pub(crate) mod mock;
 mod omnipool;
+mod price_deviation;
 mod release_deposit;
 mod remove_asset_lockdown;
 mod remove_liquidity_limit;
@@ -0,0 +1,335 @@
+use crate::fuses::price_deviation::PriceDeviationFuse;
+use crate::tests::mock::*;
+use crate::types::{PriceDeviationAction, PriceDeviationLimit};
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::ema::EmaPrice;
+use sp_runtime::traits::BadOrigin;
+use sp_runtime::Permill;
+use test_case::test_case;
+
+const SOURCE: Source = *b"omnipool";
+
+fn limit(percent: u32, action: PriceDeviationAction) -> Option<PriceDeviationLimit> {
+	Some(PriceDeviationLimit {
+		max_deviation: Permill::from_percent(percent),
+		action,
+	})
+}
+
+#[test]
+fn set_price_deviation_limit_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(CircuitBreaker::set_price_deviation_limit(
+			RuntimeOrigin::root(),
+			DOT,
+			limit(10, PriceDeviationAction::Reject)
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::price_deviation_limit_per_asset(DOT),
+			limit(10, PriceDeviationAction::Reject)
+		);
+		System::assert_last_event(
+			Event::PriceDeviationLimitChanged {
+				asset_id: DOT,
+				limit: limit(10, PriceDeviationAction::Reject),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_price_deviation_limit_should_remove_limit_when_none() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_price_deviation_limit(
+			RuntimeOrigin::root(),
+			DOT,
+			limit(10, PriceDeviationAction::Reject)
+		));
+
+		// Act
+		assert_ok!(CircuitBreaker::set_price_deviation_limit(
+			RuntimeOrigin::root(),
+			DOT,
+			None
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::price_deviation_limit_per_asset(DOT), None);
+		System::assert_last_event(
+			Event::PriceDeviationLimitChanged {
+				asset_id: DOT,
+				limit: None,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_price_deviation_limit_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_price_deviation_limit(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				limit(10, PriceDeviationAction::Reject)
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_price_deviation_limit_should_fail_for_hub_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_price_deviation_limit(
+				RuntimeOrigin::root(),
+				LRNA,
+				limit(10, PriceDeviationAction::Reject)
+			),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn set_price_deviation_limit_should_fail_when_deviation_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_price_deviation_limit(
+				RuntimeOrigin::root(),
+				DOT,
+				limit(0, PriceDeviationAction::Reject)
+			),
+			Error::<Test>::InvalidLimitValue
+		);
+	});
+}
+
+#[test]
+fn ensure_price_deviation_limit_should_pass_when_no_limit_is_set() {
+	ExtBuilder::default()
+		.with_oracle_price(DOT, LRNA, EmaPrice::new(1, 1))
+		.build()
+		.execute_with(|| {
+			assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+				SOURCE,
+				DOT,
+				LRNA,
+				EmaPrice::new(10, 1)
+			));
+		});
+}
+
+#[test]
+fn ensure_price_deviation_limit_should_pass_when_oracle_price_is_not_available() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_price_deviation_limit(
+			RuntimeOrigin::root(),
+			DOT,
+			limit(10, PriceDeviationAction::Reject)
+		));
+
+		// Act & Assert
+		assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+			SOURCE,
+			DOT,
+			LRNA,
+			EmaPrice::new(10, 1)
+		));
+	});
+}
+
+#[test]
+fn ensure_price_deviation_limit_should_pass_when_deviation_is_within_limit() {
+	ExtBuilder::default()
+		.with_oracle_price(DOT, LRNA, EmaPrice::new(100, 1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(CircuitBreaker::set_price_deviation_limit(
+				RuntimeOrigin::root(),
+				DOT,
+				limit(10, PriceDeviationAction::Reject)
+			));
+
+			// Act & Assert
+			assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+				SOURCE,
+				DOT,
+				LRNA,
+				EmaPrice::new(110, 1)
+			));
+			assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+				SOURCE,
+				DOT,
+				LRNA,
+				EmaPrice::new(90, 1)
+			));
+		});
+}
+
+#[test]
+fn ensure_price_deviation_limit_should_reject_when_deviation_exceeds_limit() {
+	ExtBuilder::default()
+		.with_oracle_price(DOT, LRNA, EmaPrice::new(100, 1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(CircuitBreaker::set_price_deviation_limit(
+				RuntimeOrigin::root(),
+				DOT,
+				limit(10, PriceDeviationAction::Reject)
+			));
+
+			// Act & Assert
+			assert_noop!(
+				CircuitBreaker::ensure_price_deviation_limit(SOURCE, DOT, LRNA, EmaPrice::new(111, 1)),
+				Error::<Test>::PriceDeviationLimitReached
+			);
+			assert_noop!(
+				CircuitBreaker::ensure_price_deviation_limit(SOURCE, DOT, LRNA, EmaPrice::new(89, 1)),
+				Error::<Test>::PriceDeviationLimitReached
+			);
+			assert_eq!(CircuitBreaker::price_deviation_lockdown(DOT), None);
+		});
+}
+
+#[test]
+fn ensure_price_deviation_limit_should_lock_down_asset_when_deviation_exceeds_limit() {
+	ExtBuilder::default()
+		.with_oracle_price(DOT, LRNA, EmaPrice::new(100, 1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			System::set_block_number(5);
+			assert_ok!(CircuitBreaker::set_price_deviation_limit(
+				RuntimeOrigin::root(),
+				DOT,
+				limit(10, PriceDeviationAction::Lockdown)
+			));
+
+			// Act
+			assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+				SOURCE,
+				DOT,
+				LRNA,
+				EmaPrice::new(150, 1)
+			));
+
+			// Assert
+			assert_eq!(CircuitBreaker::price_deviation_lockdown(DOT), Some(5));
+			System::assert_last_event(
+				Event::PriceDeviationLockdownTriggered {
+					asset_id: DOT,
+					deviation: Permill::from_percent(50),
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn ensure_price_deviation_limit_should_fail_when_asset_is_locked_down() {
+	ExtBuilder::default()
+		.with_oracle_price(DOT, LRNA, EmaPrice::new(100, 1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(CircuitBreaker::set_price_deviation_limit(
+				RuntimeOrigin::root(),
+				DOT,
+				limit(10, PriceDeviationAction::Lockdown)
+			));
+			assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+				SOURCE,
+				DOT,
+				LRNA,
+				EmaPrice::new(150, 1)
+			));
+
+			// Act & Assert
+			assert_noop!(
+				CircuitBreaker::ensure_price_deviation_limit(SOURCE, DOT, LRNA, EmaPrice::new(100, 1)),
+				Error::<Test>::AssetTradingLocked
+			);
+		});
+}
+
+#[test]
+fn lift_price_deviation_lockdown_should_allow_trading_again() {
+	ExtBuilder::default()
+		.with_oracle_price(DOT, LRNA, EmaPrice::new(100, 1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(CircuitBreaker::set_price_deviation_limit(
+				RuntimeOrigin::root(),
+				DOT,
+				limit(10, PriceDeviationAction::Lockdown)
+			));
+			assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+				SOURCE,
+				DOT,
+				LRNA,
+				EmaPrice::new(150, 1)
+			));
+
+			// Act
+			assert_ok!(CircuitBreaker::lift_price_deviation_lockdown(
+				RuntimeOrigin::root(),
+				DOT
+			));
+
+			// Assert
+			assert_eq!(CircuitBreaker::price_deviation_lockdown(DOT), None);
+			System::assert_last_event(Event::PriceDeviationLockdownLifted { asset_id: DOT }.into());
+			assert_ok!(CircuitBreaker::ensure_price_deviation_limit(
+				SOURCE,
+				DOT,
+				LRNA,
+				EmaPrice::new(100, 1)
+			));
+		});
+}
+
+#[test]
+fn lift_price_deviation_lockdown_should_fail_when_asset_is_not_locked_down() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::lift_price_deviation_lockdown(RuntimeOrigin::root(), DOT),
+			Error::<Test>::AssetNotInPriceDeviationLockdown
+		);
+	});
+}
+
+#[test]
+fn lift_price_deviation_lockdown_should_fail_when_called_by_non_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::lift_price_deviation_lockdown(RuntimeOrigin::signed(ALICE), DOT),
+			BadOrigin
+		);
+	});
+}
+
+#[test_case(EmaPrice::new(100, 1), EmaPrice::new(100, 1), Permill::zero() ; "same price")]
+#[test_case(EmaPrice::new(105, 1), EmaPrice::new(100, 1), Permill::from_percent(5) ; "spot price above oracle")]
+#[test_case(EmaPrice::new(95, 1), EmaPrice::new(100, 1), Permill::from_percent(5) ; "spot price below oracle")]
+#[test_case(EmaPrice::new(1, 2), EmaPrice::new(2, 5), Permill::from_percent(25) ; "different denominators")]
+#[test_case(EmaPrice::new(300, 1), EmaPrice::new(100, 1), Permill::one() ; "capped at hundred percent")]
+#[test_case(EmaPrice::new(1, 1), EmaPrice::zero(), Permill::one() ; "zero oracle price")]
+fn calculate_deviation_should_work(spot_price: EmaPrice, oracle_price: EmaPrice, expected: Permill) {
+	assert_eq!(
+		PriceDeviationFuse::<Test>::calculate_deviation(spot_price, oracle_price),
+		expected
+	);
+}
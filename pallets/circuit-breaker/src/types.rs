@@ -3,6 +3,7 @@ use frame_support::pallet_prelude::TypeInfo;
 use sp_core::RuntimeDebug;
 #[cfg(feature = "runtime-benchmarks")]
 use sp_runtime::DispatchResult;
+use sp_runtime::Permill;
 
 /// Represents if the asset is locked down or not, untill a specific block number.
 /// If unlocked, it contains the last block number and the baseline issuance for the given period
@@ -23,6 +24,38 @@ pub struct GlobalWithdrawLimitParameters<Balance, Moment> {
 	pub window: Moment,
 }
 
+/// Action taken by the price deviation fuse when the limit of an asset is exceeded.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, MaxEncodedLen, DecodeWithMemTracking, TypeInfo, Eq, PartialEq)]
+pub enum PriceDeviationAction {
+	/// The trade is rejected.
+	Reject,
+	/// The trade goes through, but the asset cannot be traded until the lockdown is lifted by governance.
+	Lockdown,
+}
+
+/// Maximum allowed deviation of the spot price of an asset from its oracle price.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, MaxEncodedLen, DecodeWithMemTracking, TypeInfo, Eq, PartialEq)]
+pub struct PriceDeviationLimit {
+	pub max_deviation: Permill,
+	pub action: PriceDeviationAction,
+}
+
+/// Activity limited per account by the account limits.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, MaxEncodedLen, DecodeWithMemTracking, TypeInfo, Eq, PartialEq)]
+pub enum AccountLimitKind {
+	/// Volume traded in the Omnipool and other pools, in hub asset units.
+	TradeVolume,
+	/// Withdrawals and transfers to egress accounts, in reference currency units.
+	Egress,
+}
+
+/// Limit of an activity per account within a rolling window.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, DecodeWithMemTracking, TypeInfo, Eq, PartialEq)]
+pub struct AccountLimitParameters<Balance, Moment> {
+	pub limit: Balance,
+	pub window: Moment,
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub trait BenchmarkHelper<AccountId, AssetId, Balance> {
 	fn deposit(who: AccountId, asset_id: AssetId, amount: Balance) -> DispatchResult;
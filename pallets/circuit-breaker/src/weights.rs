@@ -32,6 +32,13 @@ pub trait WeightInfo {
 	fn lockdown_asset() -> Weight;
 	fn force_lift_lockdown() -> Weight;
 	fn release_deposit() -> Weight;
+	fn set_price_deviation_limit() -> Weight;
+	fn lift_price_deviation_lockdown() -> Weight;
+	fn ensure_price_deviation_limit() -> Weight;
+	fn set_account_limit_params() -> Weight;
+	fn add_account_limit_whitelist(n: u32) -> Weight;
+	fn remove_account_limit_whitelist(n: u32) -> Weight;
+	fn note_account_activity() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -282,4 +289,93 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `CircuitBreaker::PriceDeviationLimitPerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::PriceDeviationLimitPerAsset` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn set_price_deviation_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::PriceDeviationLockdown` (r:1 w:1)
+	/// Proof: `CircuitBreaker::PriceDeviationLockdown` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn lift_price_deviation_lockdown() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `104`
+		//  Estimated: `3489`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::PriceDeviationLockdown` (r:1 w:1)
+	/// Proof: `CircuitBreaker::PriceDeviationLockdown` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::PriceDeviationLimitPerAsset` (r:1 w:0)
+	/// Proof: `CircuitBreaker::PriceDeviationLimitPerAsset` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	fn ensure_price_deviation_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6328`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 6328)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::AccountLimitConfig` (r:0 w:1)
+	/// Proof: `CircuitBreaker::AccountLimitConfig` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	fn set_account_limit_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::AccountLimitWhitelist` (r:0 w:100)
+	/// Proof: `CircuitBreaker::AccountLimitWhitelist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn add_account_limit_whitelist(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(8_436_021, 0)
+			// Standard Error: 40_115
+			.saturating_add(Weight::from_parts(1_302_117, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `CircuitBreaker::AccountLimitWhitelist` (r:0 w:100)
+	/// Proof: `CircuitBreaker::AccountLimitWhitelist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn remove_account_limit_whitelist(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(8_602_333, 0)
+			// Standard Error: 41_382
+			.saturating_add(Weight::from_parts(1_329_770, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `CircuitBreaker::AccountLimitConfig` (r:1 w:0)
+	/// Proof: `CircuitBreaker::AccountLimitConfig` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::AccountLimitWhitelist` (r:1 w:0)
+	/// Proof: `CircuitBreaker::AccountLimitWhitelist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::AccountLimitAccumulator` (r:1 w:1)
+	/// Proof: `CircuitBreaker::AccountLimitAccumulator` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	fn note_account_activity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3554`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3554)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,249 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use hydradx_traits::router::{PoolType, TradeExecution};
+use orml_traits::MultiCurrencyExtended;
+use sp_std::vec;
+use sp_std::vec::Vec;
+pub const ONE: Balance = 1_000_000_000_000;
+
+const ASSET_A: AssetId = 1_000;
+const ASSET_B: AssetId = 2_000;
+const FEE: Permill = Permill::from_parts(3_000);
+const TICK_SPACING: u16 = 10;
+// Trade through a pool created by `create_pool_with_positions` takes at least this number of steps.
+const MIN_SWAP_STEPS: u32 = 3;
+
+benchmarks! {
+	where_clause { where
+		<T as Config>::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+	}
+	create_pool {
+		register_assets::<T>()?;
+		let origin = T::AuthorityOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, ASSET_A, ASSET_B, FEE, TICK_SPACING, FixedU128::from(1))
+	verify {
+		assert!(PoolIds::<T>::get((ASSET_A, ASSET_B)).is_some());
+	}
+
+	mint_position {
+		let pool_id = create_pool::<T>()?;
+		let lp: T::AccountId = create_account_with_balances::<T>("lp", 1, vec![ASSET_A, ASSET_B])?;
+		let position_id = NextPositionId::<T>::get();
+	}: _(RawOrigin::Signed(lp.clone()), pool_id, -1_000, 1_000, 1_000 * ONE, 1_000 * ONE, 0)
+	verify {
+		assert!(Positions::<T>::get(position_id).is_some());
+	}
+
+	increase_liquidity {
+		let pool_id = create_pool::<T>()?;
+		let (lp, position_id) = mint_position::<T>(pool_id, -1_000, 1_000, 1_000 * ONE)?;
+		let liquidity = Positions::<T>::get(position_id).ok_or("PositionNotFound")?.liquidity;
+	}: _(RawOrigin::Signed(lp), position_id, 1_000 * ONE, 1_000 * ONE, 0)
+	verify {
+		assert!(Positions::<T>::get(position_id).ok_or("PositionNotFound")?.liquidity > liquidity);
+	}
+
+	decrease_liquidity {
+		let pool_id = create_pool::<T>()?;
+		let (lp, position_id) = mint_position::<T>(pool_id, -1_000, 1_000, 1_000 * ONE)?;
+		let liquidity = Positions::<T>::get(position_id).ok_or("PositionNotFound")?.liquidity;
+	}: _(RawOrigin::Signed(lp), position_id, liquidity, 0, 0)
+	verify {
+		assert!(Positions::<T>::get(position_id).ok_or("PositionNotFound")?.liquidity.is_zero());
+	}
+
+	collect_fees {
+		let pool_id = create_pool::<T>()?;
+		let (lp, position_id) = mint_position::<T>(pool_id, -1_000, 1_000, 1_000 * ONE)?;
+		let trader: T::AccountId = create_account_with_balances::<T>("trader", 1, vec![ASSET_A, ASSET_B])?;
+		assert_ok!(Pallet::<T>::sell(RawOrigin::Signed(trader).into(), ASSET_A, ASSET_B, 100 * ONE, 0));
+		let balance_before = T::Currency::free_balance(ASSET_A, &lp);
+	}: _(RawOrigin::Signed(lp.clone()), position_id)
+	verify {
+		assert!(T::Currency::free_balance(ASSET_A, &lp) > balance_before);
+	}
+
+	burn_position {
+		let pool_id = create_pool::<T>()?;
+		let (lp, position_id) = mint_position::<T>(pool_id, -1_000, 1_000, 1_000 * ONE)?;
+		let trader: T::AccountId = create_account_with_balances::<T>("trader", 1, vec![ASSET_A, ASSET_B])?;
+		assert_ok!(Pallet::<T>::sell(RawOrigin::Signed(trader).into(), ASSET_A, ASSET_B, 100 * ONE, 0));
+		let liquidity = Positions::<T>::get(position_id).ok_or("PositionNotFound")?.liquidity;
+		assert_ok!(Pallet::<T>::decrease_liquidity(RawOrigin::Signed(lp.clone()).into(), position_id, liquidity, 0, 0));
+	}: _(RawOrigin::Signed(lp), position_id)
+	verify {
+		assert!(Positions::<T>::get(position_id).is_none());
+	}
+
+	sell {
+		let s in MIN_SWAP_STEPS .. T::MaxSwapSteps::get();
+		let pool_id = create_pool_with_positions::<T>(s)?;
+		let trader: T::AccountId = create_account_with_balances::<T>("trader", 1, vec![ASSET_A, ASSET_B])?;
+	}: _(RawOrigin::Signed(trader.clone()), ASSET_A, ASSET_B, trade_amount(s), 0)
+	verify {
+		assert!(Pools::<T>::get(pool_id).ok_or("PoolNotFound")?.tick < lowest_crossed_tick(s));
+	}
+
+	buy {
+		let s in MIN_SWAP_STEPS .. T::MaxSwapSteps::get();
+		let pool_id = create_pool_with_positions::<T>(s)?;
+		let trader: T::AccountId = create_account_with_balances::<T>("trader", 1, vec![ASSET_A, ASSET_B])?;
+	}: _(RawOrigin::Signed(trader.clone()), ASSET_B, ASSET_A, trade_amount(s), u128::MAX)
+	verify {
+		assert!(Pools::<T>::get(pool_id).ok_or("PoolNotFound")?.tick < lowest_crossed_tick(s));
+	}
+
+	calculate_sell {
+		let s in MIN_SWAP_STEPS .. T::MaxSwapSteps::get();
+		create_pool_with_positions::<T>(s)?;
+		let mut amount_out = 0;
+	}: {
+		amount_out = <Pallet<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>>::calculate_out_given_in(
+			PoolType::ConcentratedLiquidity, ASSET_A, ASSET_B, trade_amount(s)
+		).map_err(|_| "CalculationFailed")?;
+	}
+	verify {
+		assert!(amount_out > 0);
+	}
+
+	calculate_buy {
+		let s in MIN_SWAP_STEPS .. T::MaxSwapSteps::get();
+		create_pool_with_positions::<T>(s)?;
+		let mut amount_in = 0;
+	}: {
+		amount_in = <Pallet<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>>::calculate_in_given_out(
+			PoolType::ConcentratedLiquidity, ASSET_A, ASSET_B, trade_amount(s)
+		).map_err(|_| "CalculationFailed")?;
+	}
+	verify {
+		assert!(amount_in > 0);
+	}
+
+	calculate_spot_price_with_fee {
+		create_pool_with_positions::<T>(MIN_SWAP_STEPS)?;
+		let mut spot_price = FixedU128::zero();
+	}: {
+		spot_price = <Pallet<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(
+			PoolType::ConcentratedLiquidity, ASSET_A, ASSET_B
+		).map_err(|_| "CalculationFailed")?;
+	}
+	verify {
+		assert!(spot_price > FixedU128::zero());
+	}
+}
+
+fn register_assets<T: Config>() -> DispatchResult {
+	T::BenchmarkHelper::register_asset(ASSET_A)?;
+	T::BenchmarkHelper::register_asset(ASSET_B)
+}
+
+fn create_pool<T: Config>() -> Result<PoolId, DispatchError> {
+	register_assets::<T>()?;
+
+	let origin = T::AuthorityOrigin::try_successful_origin().map_err(|_| DispatchError::BadOrigin)?;
+	Pallet::<T>::create_pool(origin, ASSET_A, ASSET_B, FEE, TICK_SPACING, FixedU128::from(1))?;
+
+	Ok(PoolIds::<T>::get((ASSET_A, ASSET_B)).ok_or(Error::<T>::PoolNotFound)?)
+}
+
+fn mint_position<T: Config>(
+	pool_id: PoolId,
+	tick_lower: Tick,
+	tick_upper: Tick,
+	amount: Balance,
+) -> Result<(T::AccountId, T::PositionItemId), DispatchError>
+where
+	<T as Config>::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let lp: T::AccountId = create_account_with_balances::<T>("lp", 0, vec![ASSET_A, ASSET_B])?;
+	let position_id = NextPositionId::<T>::get();
+
+	Pallet::<T>::mint_position(
+		RawOrigin::Signed(lp.clone()).into(),
+		pool_id,
+		tick_lower,
+		tick_upper,
+		amount,
+		amount,
+		0,
+	)?;
+
+	Ok((lp, position_id))
+}
+
+/// Pool in which trades of `trade_amount(steps)` take `steps` steps.
+///
+/// Below the current price, there is a ladder of `steps - 2` adjacent positions, one tick spacing wide each, on top
+/// of a wide position with deep liquidity. All initialized ticks of the ladder are in one word of the tick bitmap,
+/// so a trade selling asset A crosses all of them (one step each) and ends in the wide position (last step).
+fn create_pool_with_positions<T: Config>(steps: u32) -> Result<PoolId, DispatchError>
+where
+	<T as Config>::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let pool_id = create_pool::<T>()?;
+	mint_position::<T>(pool_id, -2_550, 2_550, 100_000 * ONE)?;
+
+	let spacing = Tick::from(TICK_SPACING);
+	for i in 0..steps.saturating_sub(2) {
+		let tick_upper = -(i as Tick) * spacing;
+		mint_position::<T>(pool_id, tick_upper - spacing, tick_upper, 1_000 * ONE)?;
+	}
+
+	Ok(pool_id)
+}
+
+/// Amount of asset A sold or asset B bought which moves the price of a pool created by
+/// `create_pool_with_positions(steps)` below the ladder, but not out of the wide position.
+fn trade_amount(steps: u32) -> Balance {
+	Balance::from(steps) * 2_000 * ONE + 10_000 * ONE
+}
+
+/// Lowest tick of the ladder of a pool created by `create_pool_with_positions(steps)`.
+fn lowest_crossed_tick(steps: u32) -> Tick {
+	-(steps.saturating_sub(2) as Tick) * Tick::from(TICK_SPACING)
+}
+
+fn create_account_with_balances<T: Config>(
+	name: &'static str,
+	index: u32,
+	assets: Vec<AssetId>,
+) -> Result<T::AccountId, DispatchError>
+where
+	<T as Config>::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let account_id: T::AccountId = account(name, index, index);
+
+	let token_amount: Balance = 1_000_000 * ONE;
+
+	for asset in assets.iter() {
+		<T as Config>::Currency::update_balance(*asset, &account_id, token_amount as i128)?;
+	}
+
+	Ok(account_id)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::mock::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
@@ -0,0 +1,1280 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Concentrated liquidity pallet
+//!
+//! ## Description
+//! AMM pools where liquidity providers choose the price range their liquidity is used in.
+//!
+//! Price range of a pool is split into ticks, where price at tick `i` is `1.0001^i`. Price of a pool is the price of
+//! `asset_a` denominated in `asset_b`, where `asset_a` is the asset with the lower id. A position provides liquidity
+//! between two ticks divisible by the tick spacing of the pool and is active only while the price of the pool is
+//! within its range. Within a range, the pool behaves like an XYK pool with virtual reserves given by the
+//! liquidity of the active positions.
+//!
+//! Positions are represented by NFTs, the holder of the NFT is the owner of the position.
+//!
+//! ## Fees
+//! Trade fee is paid in the asset sold to the pool and distributed to the active positions pro rata to their
+//! liquidity. Fees are accrued by the positions and have to be collected by the owners.
+//!
+//! ## Hooks
+//! Trades and liquidity changes are reported via `ConcentratedLiquidityHooks`, which is used to update the oracle
+//! and to check circuit breaker limits.
+//!
+//! Trades are also reported via `pallet_broadcast` with `Filler::ConcentratedLiquidity(pool_id)`.
+//!
+//! ## Dispatchable functions
+//! * `create_pool` - Creates a pool of two assets with given fee, tick spacing and initial price.
+//! * `mint_position` - Provides liquidity to a price range and mints a new position.
+//! * `increase_liquidity` - Adds liquidity to an existing position.
+//! * `decrease_liquidity` - Removes liquidity from a position.
+//! * `collect_fees` - Transfers fees accrued by a position to its owner.
+//! * `burn_position` - Burns an empty position.
+//! * `sell` - Sells an exact amount of an asset.
+//! * `buy` - Buys an exact amount of an asset.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+
+use frame_support::{
+	pallet_prelude::*,
+	require_transactional,
+	traits::tokens::nonfungibles::{Create, Inspect, Mutate},
+	transactional, PalletId,
+};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use hydra_dx_math::concentrated_liquidity as math;
+use hydradx_traits::{
+	circuit_breaker::AccountTradeVolumeHandler, pools::DustRemovalAccountWhitelist,
+	registry::Inspect as RegistryInspect,
+};
+use orml_traits::MultiCurrency;
+use pallet_broadcast::types::{Asset, Destination, Fee, Filler, TradeOperation};
+use sp_core::U256;
+use sp_runtime::{
+	traits::{AccountIdConversion, AtLeast32BitUnsigned, One, Zero},
+	ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill,
+};
+use sp_std::{vec, vec::Vec};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarks;
+#[cfg(test)]
+mod tests;
+
+pub mod trade_execution;
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Greatest allowed tick spacing of a pool.
+pub const MAX_TICK_SPACING: u16 = 16_384;
+
+pub type NFTCollectionIdOf<T> =
+	<<T as Config>::NFTHandler as Inspect<<T as frame_system::Config>::AccountId>>::CollectionId;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::HasCompact;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_broadcast::Config {
+		/// Multi currency mechanism.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Origin that can create pools.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Asset registry mechanism.
+		type AssetRegistry: RegistryInspect<AssetId = AssetId>;
+
+		/// Pallet id, used to derive the accounts of the pools.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Position identifier type.
+		type PositionItemId: Member + Parameter + Default + Copy + HasCompact + AtLeast32BitUnsigned + MaxEncodedLen;
+
+		/// Collection id type.
+		type CollectionId: TypeInfo + MaxEncodedLen;
+
+		/// Non fungible collection of the positions.
+		#[pallet::constant]
+		type NFTCollectionId: Get<NFTCollectionIdOf<Self>>;
+
+		/// Non fungible handling - mint, burn, check owner.
+		type NFTHandler: Mutate<Self::AccountId>
+			+ Create<Self::AccountId>
+			+ Inspect<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>;
+
+		/// Hooks are actions executed on trades and liquidity changes.
+		type Hooks: ConcentratedLiquidityHooks;
+
+		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
+		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// Counts trades towards the per-account trade volume limit of the trader.
+		type AccountTradeVolumeHandler: AccountTradeVolumeHandler<Self::AccountId, AssetId, Balance>;
+
+		/// Minimum trading limit.
+		#[pallet::constant]
+		type MinTradingLimit: Get<Balance>;
+
+		/// Maximum number of steps of a trade. A step ends at the next initialized tick or at the end of a word of
+		/// the tick bitmap, whichever comes first. Trades are charged for this number of steps.
+		#[pallet::constant]
+		type MaxSwapSteps: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<AssetId>;
+	}
+
+	/// Existing pools.
+	#[pallet::storage]
+	pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, PoolInfo>;
+
+	/// Pool of an asset pair. Assets of the pair are ordered.
+	#[pallet::storage]
+	pub type PoolIds<T: Config> = StorageMap<_, Blake2_128Concat, (AssetId, AssetId), PoolId>;
+
+	/// Pool id sequencer.
+	#[pallet::storage]
+	pub type NextPoolId<T: Config> = StorageValue<_, PoolId, ValueQuery>;
+
+	/// Initialized ticks of the pools.
+	#[pallet::storage]
+	pub type Ticks<T: Config> = StorageDoubleMap<_, Blake2_128Concat, PoolId, Blake2_128Concat, Tick, TickInfo>;
+
+	/// Bitmap of initialized ticks divided by the tick spacing. Each word holds 256 ticks.
+	#[pallet::storage]
+	pub type TickBitmap<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolId, Blake2_128Concat, i16, U256, ValueQuery>;
+
+	/// Liquidity positions.
+	#[pallet::storage]
+	pub type Positions<T: Config> = StorageMap<_, Blake2_128Concat, T::PositionItemId, Position>;
+
+	/// Position ids sequencer.
+	#[pallet::storage]
+	pub type NextPositionId<T: Config> = StorageValue<_, T::PositionItemId, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool has been created.
+		PoolCreated {
+			pool_id: PoolId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			fee: Permill,
+			tick_spacing: u16,
+			sqrt_price: SqrtPrice,
+			tick: Tick,
+		},
+		/// A position has been minted.
+		PositionMinted {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+			pool_id: PoolId,
+			tick_lower: Tick,
+			tick_upper: Tick,
+			liquidity: Liquidity,
+			amount_a: Balance,
+			amount_b: Balance,
+		},
+		/// Liquidity has been added to a position.
+		LiquidityIncreased {
+			position_id: T::PositionItemId,
+			who: T::AccountId,
+			liquidity: Liquidity,
+			amount_a: Balance,
+			amount_b: Balance,
+		},
+		/// Liquidity has been removed from a position.
+		LiquidityDecreased {
+			position_id: T::PositionItemId,
+			who: T::AccountId,
+			liquidity: Liquidity,
+			amount_a: Balance,
+			amount_b: Balance,
+		},
+		/// Fees accrued by a position have been collected.
+		FeesCollected {
+			position_id: T::PositionItemId,
+			who: T::AccountId,
+			amount_a: Balance,
+			amount_b: Balance,
+		},
+		/// A position has been burned.
+		PositionBurned {
+			position_id: T::PositionItemId,
+			who: T::AccountId,
+		},
+		/// Sell trade has been executed.
+		SellExecuted {
+			who: T::AccountId,
+			pool_id: PoolId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee: Balance,
+		},
+		/// Buy trade has been executed.
+		BuyExecuted {
+			who: T::AccountId,
+			pool_id: PoolId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Pool cannot be created with the same assets.
+		SameAssets,
+		/// Asset does not exist in registry.
+		AssetNotRegistered,
+		/// Pool of the asset pair already exists.
+		PoolAlreadyExists,
+		/// Pool does not exist.
+		PoolNotFound,
+		/// Tick spacing must be greater than zero and at most `MAX_TICK_SPACING`.
+		InvalidTickSpacing,
+		/// Fee must be lower than 100%.
+		InvalidFee,
+		/// Initial price is outside of the supported price range.
+		InvalidInitialPrice,
+		/// Ticks of the range are not divisible by the tick spacing, out of bounds or not in order.
+		InvalidTickRange,
+		/// Liquidity is zero.
+		ZeroLiquidity,
+		/// Provided liquidity is lower than the requested minimum.
+		InsufficientLiquidityProvided,
+		/// Liquidity of a tick would exceed the maximum.
+		MaxLiquidityPerTickExceeded,
+		/// Asset amount has exceeded given limit.
+		AssetAmountExceededLimit,
+		/// Asset amount has not reached given limit.
+		AssetAmountNotReachedLimit,
+		/// Position does not exist.
+		PositionNotFound,
+		/// The caller does not own the position.
+		Forbidden,
+		/// Position does not have enough liquidity.
+		InsufficientPositionLiquidity,
+		/// Position cannot be burned while it has liquidity.
+		PositionNotEmpty,
+		/// Amount is less than min trading limit.
+		InsufficientTradingAmount,
+		/// Pool does not have enough liquidity to execute the trade.
+		InsufficientLiquidity,
+		/// Trade would need more steps than `MaxSwapSteps`.
+		MaxSwapStepsExceeded,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert!(
+				!T::MaxSwapSteps::get().is_zero(),
+				"MaxSwapSteps must be greater than zero."
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a pool of two assets.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_a`: First asset of the pool
+		/// - `asset_b`: Second asset of the pool
+		/// - `fee`: Trade fee
+		/// - `tick_spacing`: Spacing of the ticks usable as position bounds
+		/// - `initial_price`: Price of `asset_a` denominated in `asset_b`
+		///
+		/// Emits `PoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+		#[transactional]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			fee: Permill,
+			tick_spacing: u16,
+			initial_price: FixedU128,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_a != asset_b, Error::<T>::SameAssets);
+			ensure!(T::AssetRegistry::exists(asset_a), Error::<T>::AssetNotRegistered);
+			ensure!(T::AssetRegistry::exists(asset_b), Error::<T>::AssetNotRegistered);
+			ensure!(
+				tick_spacing > 0 && tick_spacing <= MAX_TICK_SPACING,
+				Error::<T>::InvalidTickSpacing
+			);
+			ensure!(fee < Permill::one(), Error::<T>::InvalidFee);
+
+			let (asset_a, asset_b, price) = if asset_a < asset_b {
+				(asset_a, asset_b, initial_price)
+			} else {
+				let price = initial_price.reciprocal().ok_or(Error::<T>::InvalidInitialPrice)?;
+				(asset_b, asset_a, price)
+			};
+
+			ensure!(
+				!PoolIds::<T>::contains_key((asset_a, asset_b)),
+				Error::<T>::PoolAlreadyExists
+			);
+
+			let sqrt_price = math::sqrt_price_from_price(price).ok_or(Error::<T>::InvalidInitialPrice)?;
+			let tick = math::tick_at_sqrt_price(sqrt_price).ok_or(Error::<T>::InvalidInitialPrice)?;
+
+			let pool_id = NextPoolId::<T>::try_mutate(|next_id| -> Result<PoolId, DispatchError> {
+				let pool_id = *next_id;
+				*next_id = next_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(pool_id)
+			})?;
+
+			Pools::<T>::insert(
+				pool_id,
+				PoolInfo {
+					asset_a,
+					asset_b,
+					fee,
+					tick_spacing,
+					sqrt_price,
+					tick,
+					liquidity: Liquidity::zero(),
+					fee_growth_global_a: 0,
+					fee_growth_global_b: 0,
+				},
+			);
+			PoolIds::<T>::insert((asset_a, asset_b), pool_id);
+
+			T::NonDustableWhitelistHandler::add_account(&Self::pool_account(pool_id))?;
+
+			if T::NFTHandler::collection_owner(&T::NFTCollectionId::get()).is_none() {
+				T::NFTHandler::create_collection(
+					&T::NFTCollectionId::get(),
+					&Self::protocol_account(),
+					&Self::protocol_account(),
+				)?;
+			}
+
+			Self::deposit_event(Event::PoolCreated {
+				pool_id,
+				asset_a,
+				asset_b,
+				fee,
+				tick_spacing,
+				sqrt_price,
+				tick,
+			});
+
+			Ok(())
+		}
+
+		/// Provide liquidity to a price range of a pool.
+		///
+		/// The greatest liquidity which can be provided by `amount_a_max` and `amount_b_max` at the current price
+		/// of the pool is added. Position NFT is minted to the caller.
+		///
+		/// Parameters:
+		/// - `pool_id`: Pool to provide liquidity to
+		/// - `tick_lower`: Lower tick of the range
+		/// - `tick_upper`: Upper tick of the range
+		/// - `amount_a_max`: Maximum amount of asset A to provide
+		/// - `amount_b_max`: Maximum amount of asset B to provide
+		/// - `min_liquidity`: Minimum liquidity to be added
+		///
+		/// Emits `PositionMinted` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::mint_position()
+			.saturating_add(T::Hooks::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn mint_position(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			tick_lower: Tick,
+			tick_upper: Tick,
+			amount_a_max: Balance,
+			amount_b_max: Balance,
+			min_liquidity: Liquidity,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_valid_range(&pool, tick_lower, tick_upper)?;
+
+			let position = Position {
+				pool_id,
+				tick_lower,
+				tick_upper,
+				liquidity: Liquidity::zero(),
+				fee_growth_inside_a_last: 0,
+				fee_growth_inside_b_last: 0,
+				fees_owed_a: Balance::zero(),
+				fees_owed_b: Balance::zero(),
+			};
+
+			let (position, liquidity, amount_a, amount_b) =
+				Self::do_increase_liquidity(&who, pool_id, pool, position, amount_a_max, amount_b_max, min_liquidity)?;
+
+			let position_id = Self::create_and_mint_position_instance(&who)?;
+			Positions::<T>::insert(position_id, position);
+
+			Self::deposit_event(Event::PositionMinted {
+				position_id,
+				owner: who,
+				pool_id,
+				tick_lower,
+				tick_upper,
+				liquidity,
+				amount_a,
+				amount_b,
+			});
+
+			Ok(())
+		}
+
+		/// Add liquidity to an existing position.
+		///
+		/// Parameters:
+		/// - `position_id`: Position owned by the caller
+		/// - `amount_a_max`: Maximum amount of asset A to provide
+		/// - `amount_b_max`: Maximum amount of asset B to provide
+		/// - `min_liquidity`: Minimum liquidity to be added
+		///
+		/// Emits `LiquidityIncreased` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::increase_liquidity()
+			.saturating_add(T::Hooks::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn increase_liquidity(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			amount_a_max: Balance,
+			amount_b_max: Balance,
+			min_liquidity: Liquidity,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let position = Self::owned_position(&who, position_id)?;
+			let pool = Pools::<T>::get(position.pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			let (position, liquidity, amount_a, amount_b) = Self::do_increase_liquidity(
+				&who,
+				position.pool_id,
+				pool,
+				position,
+				amount_a_max,
+				amount_b_max,
+				min_liquidity,
+			)?;
+
+			Positions::<T>::insert(position_id, position);
+
+			Self::deposit_event(Event::LiquidityIncreased {
+				position_id,
+				who,
+				liquidity,
+				amount_a,
+				amount_b,
+			});
+
+			Ok(())
+		}
+
+		/// Remove liquidity from a position.
+		///
+		/// Removed amounts are transferred to the caller. Fees accrued by the position are kept in the position until
+		/// collected.
+		///
+		/// Parameters:
+		/// - `position_id`: Position owned by the caller
+		/// - `liquidity`: Liquidity to remove
+		/// - `amount_a_min`: Minimum amount of asset A to receive
+		/// - `amount_b_min`: Minimum amount of asset B to receive
+		///
+		/// Emits `LiquidityDecreased` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::decrease_liquidity()
+			.saturating_add(T::Hooks::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn decrease_liquidity(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			liquidity: Liquidity,
+			amount_a_min: Balance,
+			amount_b_min: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut position = Self::owned_position(&who, position_id)?;
+			let pool_id = position.pool_id;
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			ensure!(!liquidity.is_zero(), Error::<T>::ZeroLiquidity);
+			ensure!(
+				liquidity <= position.liquidity,
+				Error::<T>::InsufficientPositionLiquidity
+			);
+
+			let liquidity_delta = i128::try_from(liquidity)
+				.ok()
+				.and_then(|l| l.checked_neg())
+				.ok_or(ArithmeticError::Overflow)?;
+			let (amount_a, amount_b) = Self::modify_position(pool_id, &mut pool, &mut position, liquidity_delta)?;
+
+			ensure!(
+				amount_a >= amount_a_min && amount_b >= amount_b_min,
+				Error::<T>::AssetAmountNotReachedLimit
+			);
+
+			let pool_account = Self::pool_account(pool_id);
+			T::Currency::transfer(pool.asset_a, &pool_account, &who, amount_a)?;
+			T::Currency::transfer(pool.asset_b, &pool_account, &who, amount_b)?;
+
+			Pools::<T>::insert(pool_id, pool);
+			Positions::<T>::insert(position_id, position);
+
+			T::Hooks::on_liquidity_changed(pool_id, Self::pool_state(pool_id, &pool, amount_a, amount_b))?;
+
+			Self::deposit_event(Event::LiquidityDecreased {
+				position_id,
+				who,
+				liquidity,
+				amount_a,
+				amount_b,
+			});
+
+			Ok(())
+		}
+
+		/// Transfer fees accrued by a position to its owner.
+		///
+		/// Parameters:
+		/// - `position_id`: Position owned by the caller
+		///
+		/// Emits `FeesCollected` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::collect_fees())]
+		#[transactional]
+		pub fn collect_fees(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut position = Self::owned_position(&who, position_id)?;
+			let (amount_a, amount_b) = Self::do_collect_fees(&who, &mut position)?;
+
+			Positions::<T>::insert(position_id, position);
+
+			Self::deposit_event(Event::FeesCollected {
+				position_id,
+				who,
+				amount_a,
+				amount_b,
+			});
+
+			Ok(())
+		}
+
+		/// Burn a position without liquidity.
+		///
+		/// Uncollected fees of the position are transferred to the caller.
+		///
+		/// Parameters:
+		/// - `position_id`: Position owned by the caller
+		///
+		/// Emits `PositionBurned` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::burn_position())]
+		#[transactional]
+		pub fn burn_position(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut position = Self::owned_position(&who, position_id)?;
+			ensure!(position.liquidity.is_zero(), Error::<T>::PositionNotEmpty);
+
+			let (amount_a, amount_b) = Self::do_collect_fees(&who, &mut position)?;
+			if !amount_a.is_zero() || !amount_b.is_zero() {
+				Self::deposit_event(Event::FeesCollected {
+					position_id,
+					who: who.clone(),
+					amount_a,
+					amount_b,
+				});
+			}
+
+			Positions::<T>::remove(position_id);
+			T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
+
+			Self::deposit_event(Event::PositionBurned { position_id, who });
+
+			Ok(())
+		}
+
+		/// Sell an exact amount of an asset.
+		///
+		/// Parameters:
+		/// - `asset_in`: Asset to sell
+		/// - `asset_out`: Asset to buy
+		/// - `amount`: Amount of `asset_in` to sell
+		/// - `min_buy_amount`: Minimum amount of `asset_out` to receive
+		///
+		/// Emits `SellExecuted` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell(T::MaxSwapSteps::get())
+			.saturating_add(T::Hooks::on_trade_weight())
+			.saturating_add(T::AccountTradeVolumeHandler::note_trade_volume_weight()))]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let (pool_id, pool) = Self::pool_of(asset_in, asset_out)?;
+			let outcome = Self::simulate_swap(pool_id, pool, asset_in == pool.asset_a, amount, true)?;
+
+			ensure!(!outcome.amount_out.is_zero(), Error::<T>::InsufficientTradingAmount);
+			ensure!(
+				outcome.amount_out >= min_buy_amount,
+				Error::<T>::AssetAmountNotReachedLimit
+			);
+
+			Self::execute_swap(&who, pool_id, asset_in, asset_out, &outcome)?;
+			T::AccountTradeVolumeHandler::note_trade_volume(&who, asset_in, outcome.amount_in)?;
+
+			pallet_broadcast::Pallet::<T>::deposit_trade_event(
+				who.clone(),
+				Self::pool_account(pool_id),
+				Filler::ConcentratedLiquidity(pool_id),
+				TradeOperation::ExactIn,
+				vec![Asset::new(asset_in, outcome.amount_in)],
+				vec![Asset::new(asset_out, outcome.amount_out)],
+				vec![Fee {
+					asset: asset_in,
+					amount: outcome.fee,
+					destination: Destination::Account(Self::pool_account(pool_id)),
+				}],
+			);
+
+			Self::deposit_event(Event::SellExecuted {
+				who,
+				pool_id,
+				asset_in,
+				asset_out,
+				amount_in: outcome.amount_in,
+				amount_out: outcome.amount_out,
+				fee: outcome.fee,
+			});
+
+			Ok(())
+		}
+
+		/// Buy an exact amount of an asset.
+		///
+		/// Parameters:
+		/// - `asset_out`: Asset to buy
+		/// - `asset_in`: Asset to sell
+		/// - `amount`: Amount of `asset_out` to receive
+		/// - `max_sell_amount`: Maximum amount of `asset_in` to pay
+		///
+		/// Emits `BuyExecuted` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy(T::MaxSwapSteps::get())
+			.saturating_add(T::Hooks::on_trade_weight())
+			.saturating_add(T::AccountTradeVolumeHandler::note_trade_volume_weight()))]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			asset_out: AssetId,
+			asset_in: AssetId,
+			amount: Balance,
+			max_sell_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let (pool_id, pool) = Self::pool_of(asset_in, asset_out)?;
+			let outcome = Self::simulate_swap(pool_id, pool, asset_in == pool.asset_a, amount, false)?;
+
+			ensure!(
+				outcome.amount_in <= max_sell_amount,
+				Error::<T>::AssetAmountExceededLimit
+			);
+
+			Self::execute_swap(&who, pool_id, asset_in, asset_out, &outcome)?;
+			T::AccountTradeVolumeHandler::note_trade_volume(&who, asset_out, outcome.amount_out)?;
+
+			pallet_broadcast::Pallet::<T>::deposit_trade_event(
+				who.clone(),
+				Self::pool_account(pool_id),
+				Filler::ConcentratedLiquidity(pool_id),
+				TradeOperation::ExactOut,
+				vec![Asset::new(asset_in, outcome.amount_in)],
+				vec![Asset::new(asset_out, outcome.amount_out)],
+				vec![Fee {
+					asset: asset_in,
+					amount: outcome.fee,
+					destination: Destination::Account(Self::pool_account(pool_id)),
+				}],
+			);
+
+			Self::deposit_event(Event::BuyExecuted {
+				who,
+				pool_id,
+				asset_in,
+				asset_out,
+				amount_in: outcome.amount_in,
+				amount_out: outcome.amount_out,
+				fee: outcome.fee,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+/// Result of a trade calculated on the current state of a pool.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct SwapOutcome {
+	/// State of the pool after the trade.
+	pub pool: PoolInfo,
+	/// Amount paid to the pool, including the fee.
+	pub amount_in: Balance,
+	/// Amount received from the pool.
+	pub amount_out: Balance,
+	/// Fee paid in the asset sold to the pool.
+	pub fee: Balance,
+	/// Ticks crossed by the trade, together with the global fee growth of asset A and B at the crossing.
+	pub crossed_ticks: Vec<(Tick, u128, u128)>,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account holding the reserves of a pool.
+	pub fn pool_account(pool_id: PoolId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(pool_id)
+	}
+
+	/// Owner of the position NFT collection.
+	pub fn protocol_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Pool of an asset pair, in any order.
+	pub fn pool_of(asset_a: AssetId, asset_b: AssetId) -> Result<(PoolId, PoolInfo), DispatchError> {
+		let key = if asset_a < asset_b {
+			(asset_a, asset_b)
+		} else {
+			(asset_b, asset_a)
+		};
+		let pool_id = PoolIds::<T>::get(key).ok_or(Error::<T>::PoolNotFound)?;
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		Ok((pool_id, pool))
+	}
+
+	/// Greatest liquidity which can reference a single tick, so that the total liquidity does not overflow.
+	pub fn max_liquidity_per_tick(tick_spacing: u16) -> Liquidity {
+		let spacing = Tick::from(tick_spacing.max(1));
+		let min_tick = math::MIN_TICK / spacing * spacing;
+		let max_tick = math::MAX_TICK / spacing * spacing;
+		let number_of_ticks = ((max_tick - min_tick) / spacing) as u128 + 1;
+		Liquidity::MAX / number_of_ticks
+	}
+
+	/// Calculate a trade on the current state of a pool, without any storage changes.
+	///
+	/// If `exact_in` is true, `amount` is the amount sold to the pool, otherwise it is the amount bought.
+	/// Asset A is sold if `a_for_b` is true.
+	pub fn simulate_swap(
+		pool_id: PoolId,
+		mut pool: PoolInfo,
+		a_for_b: bool,
+		amount: Balance,
+		exact_in: bool,
+	) -> Result<SwapOutcome, DispatchError> {
+		let sqrt_price_limit = if a_for_b {
+			math::MIN_SQRT_PRICE.saturating_add(1)
+		} else {
+			math::MAX_SQRT_PRICE.saturating_sub(1)
+		};
+
+		let mut amount_remaining = amount;
+		let mut amount_calculated = Balance::zero();
+		let mut fee = Balance::zero();
+		let mut crossed_ticks = Vec::new();
+		let mut steps: u32 = 0;
+
+		while !amount_remaining.is_zero() && pool.sqrt_price != sqrt_price_limit {
+			steps = steps.saturating_add(1);
+			ensure!(steps <= T::MaxSwapSteps::get(), Error::<T>::MaxSwapStepsExceeded);
+
+			let sqrt_price_start = pool.sqrt_price;
+			let (tick_next, initialized) =
+				math::next_initialized_tick_within_one_word(pool.tick, pool.tick_spacing, a_for_b, |word| {
+					TickBitmap::<T>::get(pool_id, word)
+				});
+			let tick_next = tick_next.clamp(math::MIN_TICK, math::MAX_TICK);
+			let sqrt_price_next = math::sqrt_price_at_tick(tick_next).ok_or(ArithmeticError::Overflow)?;
+			let sqrt_price_target = if a_for_b {
+				sqrt_price_next.max(sqrt_price_limit)
+			} else {
+				sqrt_price_next.min(sqrt_price_limit)
+			};
+
+			let step = math::compute_swap_step(
+				pool.sqrt_price,
+				sqrt_price_target,
+				pool.liquidity,
+				amount_remaining,
+				pool.fee,
+				exact_in,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			let step_amount_in = step
+				.amount_in
+				.checked_add(step.fee_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			let (step_remaining, step_calculated) = if exact_in {
+				(step_amount_in, step.amount_out)
+			} else {
+				(step.amount_out, step_amount_in)
+			};
+			amount_remaining = amount_remaining
+				.checked_sub(step_remaining)
+				.ok_or(ArithmeticError::Underflow)?;
+			amount_calculated = amount_calculated
+				.checked_add(step_calculated)
+				.ok_or(ArithmeticError::Overflow)?;
+			fee = fee.checked_add(step.fee_amount).ok_or(ArithmeticError::Overflow)?;
+
+			let fee_growth =
+				math::fee_growth_delta(step.fee_amount, pool.liquidity).ok_or(ArithmeticError::Overflow)?;
+			if a_for_b {
+				pool.fee_growth_global_a = pool.fee_growth_global_a.wrapping_add(fee_growth);
+			} else {
+				pool.fee_growth_global_b = pool.fee_growth_global_b.wrapping_add(fee_growth);
+			}
+
+			pool.sqrt_price = step.sqrt_price_next;
+
+			if pool.sqrt_price == sqrt_price_next {
+				if initialized {
+					let tick_info = Ticks::<T>::get(pool_id, tick_next).ok_or(DispatchError::Corruption)?;
+					let liquidity_net = if a_for_b {
+						tick_info.liquidity_net.checked_neg().ok_or(ArithmeticError::Overflow)?
+					} else {
+						tick_info.liquidity_net
+					};
+					pool.liquidity = Self::add_liquidity_delta(pool.liquidity, liquidity_net)?;
+					crossed_ticks.push((tick_next, pool.fee_growth_global_a, pool.fee_growth_global_b));
+				}
+				pool.tick = if a_for_b { tick_next - 1 } else { tick_next };
+			} else if pool.sqrt_price != sqrt_price_start {
+				pool.tick = math::tick_at_sqrt_price(pool.sqrt_price).ok_or(ArithmeticError::Overflow)?;
+			}
+		}
+
+		ensure!(amount_remaining.is_zero(), Error::<T>::InsufficientLiquidity);
+
+		let (amount_in, amount_out) = if exact_in {
+			(amount, amount_calculated)
+		} else {
+			(amount_calculated, amount)
+		};
+
+		Ok(SwapOutcome {
+			pool,
+			amount_in,
+			amount_out,
+			fee,
+			crossed_ticks,
+		})
+	}
+
+	/// Apply a calculated trade to the pool and transfer the traded amounts.
+	#[require_transactional]
+	fn execute_swap(
+		who: &T::AccountId,
+		pool_id: PoolId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		outcome: &SwapOutcome,
+	) -> DispatchResult {
+		for (tick, fee_growth_global_a, fee_growth_global_b) in outcome.crossed_ticks.iter() {
+			Ticks::<T>::try_mutate(pool_id, tick, |maybe_tick| -> DispatchResult {
+				let tick_info = maybe_tick.as_mut().ok_or(DispatchError::Corruption)?;
+				tick_info.fee_growth_outside_a = fee_growth_global_a.wrapping_sub(tick_info.fee_growth_outside_a);
+				tick_info.fee_growth_outside_b = fee_growth_global_b.wrapping_sub(tick_info.fee_growth_outside_b);
+				Ok(())
+			})?;
+		}
+
+		Pools::<T>::insert(pool_id, outcome.pool);
+
+		let pool_account = Self::pool_account(pool_id);
+		T::Currency::transfer(asset_in, who, &pool_account, outcome.amount_in)?;
+		T::Currency::transfer(asset_out, &pool_account, who, outcome.amount_out)?;
+
+		let (delta_a, delta_b) = if asset_in == outcome.pool.asset_a {
+			(outcome.amount_in, outcome.amount_out)
+		} else {
+			(outcome.amount_out, outcome.amount_in)
+		};
+
+		T::Hooks::on_trade(
+			pool_id,
+			asset_in,
+			asset_out,
+			Self::pool_state(pool_id, &outcome.pool, delta_a, delta_b),
+		)
+	}
+
+	/// Add the greatest liquidity given by the maximum amounts to a position and transfer the amounts from `who`.
+	///
+	/// Returns the updated position, the added liquidity and the transferred amounts.
+	#[require_transactional]
+	fn do_increase_liquidity(
+		who: &T::AccountId,
+		pool_id: PoolId,
+		mut pool: PoolInfo,
+		mut position: Position,
+		amount_a_max: Balance,
+		amount_b_max: Balance,
+		min_liquidity: Liquidity,
+	) -> Result<(Position, Liquidity, Balance, Balance), DispatchError> {
+		let sqrt_price_lower = math::sqrt_price_at_tick(position.tick_lower).ok_or(Error::<T>::InvalidTickRange)?;
+		let sqrt_price_upper = math::sqrt_price_at_tick(position.tick_upper).ok_or(Error::<T>::InvalidTickRange)?;
+
+		let liquidity = math::liquidity_for_amounts(
+			pool.sqrt_price,
+			sqrt_price_lower,
+			sqrt_price_upper,
+			amount_a_max,
+			amount_b_max,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(!liquidity.is_zero(), Error::<T>::ZeroLiquidity);
+		ensure!(liquidity >= min_liquidity, Error::<T>::InsufficientLiquidityProvided);
+
+		let liquidity_delta = i128::try_from(liquidity).map_err(|_| Error::<T>::MaxLiquidityPerTickExceeded)?;
+		let (amount_a, amount_b) = Self::modify_position(pool_id, &mut pool, &mut position, liquidity_delta)?;
+
+		ensure!(
+			amount_a <= amount_a_max && amount_b <= amount_b_max,
+			Error::<T>::AssetAmountExceededLimit
+		);
+
+		let pool_account = Self::pool_account(pool_id);
+		T::Currency::transfer(pool.asset_a, who, &pool_account, amount_a)?;
+		T::Currency::transfer(pool.asset_b, who, &pool_account, amount_b)?;
+
+		Pools::<T>::insert(pool_id, pool);
+
+		T::Hooks::on_liquidity_changed(pool_id, Self::pool_state(pool_id, &pool, amount_a, amount_b))?;
+
+		Ok((position, liquidity, amount_a, amount_b))
+	}
+
+	/// Transfer uncollected fees of a position to `who`.
+	#[require_transactional]
+	fn do_collect_fees(who: &T::AccountId, position: &mut Position) -> Result<(Balance, Balance), DispatchError> {
+		let pool = Pools::<T>::get(position.pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+		if !position.liquidity.is_zero() {
+			let (fee_growth_inside_a, fee_growth_inside_b) =
+				Self::fee_growth_inside(position.pool_id, &pool, position.tick_lower, position.tick_upper);
+			Self::update_position_fees(position, fee_growth_inside_a, fee_growth_inside_b)?;
+		}
+
+		let (amount_a, amount_b) = (position.fees_owed_a, position.fees_owed_b);
+		let pool_account = Self::pool_account(position.pool_id);
+		T::Currency::transfer(pool.asset_a, &pool_account, who, amount_a)?;
+		T::Currency::transfer(pool.asset_b, &pool_account, who, amount_b)?;
+
+		position.fees_owed_a = Balance::zero();
+		position.fees_owed_b = Balance::zero();
+
+		Ok((amount_a, amount_b))
+	}
+
+	/// Apply a liquidity change to a position, its ticks and the pool.
+	///
+	/// Returns the amounts of asset A and B added to the pool if liquidity is added, or removed from the pool
+	/// if liquidity is removed. Amounts are rounded in favor of the pool.
+	fn modify_position(
+		pool_id: PoolId,
+		pool: &mut PoolInfo,
+		position: &mut Position,
+		liquidity_delta: i128,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let (tick_lower, tick_upper) = (position.tick_lower, position.tick_upper);
+
+		let flipped_lower = Self::update_tick(pool_id, pool, tick_lower, liquidity_delta, false)?;
+		let flipped_upper = Self::update_tick(pool_id, pool, tick_upper, liquidity_delta, true)?;
+
+		let (fee_growth_inside_a, fee_growth_inside_b) = Self::fee_growth_inside(pool_id, pool, tick_lower, tick_upper);
+		Self::update_position_fees(position, fee_growth_inside_a, fee_growth_inside_b)?;
+		position.liquidity = Self::add_liquidity_delta(position.liquidity, liquidity_delta)?;
+
+		// Ticks which are not referenced by any position anymore are cleared.
+		if liquidity_delta < 0 {
+			if flipped_lower {
+				Ticks::<T>::remove(pool_id, tick_lower);
+			}
+			if flipped_upper {
+				Ticks::<T>::remove(pool_id, tick_upper);
+			}
+		}
+
+		if pool.tick >= tick_lower && pool.tick < tick_upper {
+			pool.liquidity = Self::add_liquidity_delta(pool.liquidity, liquidity_delta)?;
+		}
+
+		let sqrt_price_lower = math::sqrt_price_at_tick(tick_lower).ok_or(Error::<T>::InvalidTickRange)?;
+		let sqrt_price_upper = math::sqrt_price_at_tick(tick_upper).ok_or(Error::<T>::InvalidTickRange)?;
+
+		let amounts = math::amounts_for_liquidity(
+			pool.sqrt_price,
+			sqrt_price_lower,
+			sqrt_price_upper,
+			liquidity_delta.unsigned_abs(),
+			liquidity_delta > 0,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(amounts)
+	}
+
+	/// Apply a liquidity change to a tick. Returns true if the tick has been initialized or uninitialized.
+	fn update_tick(
+		pool_id: PoolId,
+		pool: &PoolInfo,
+		tick: Tick,
+		liquidity_delta: i128,
+		upper: bool,
+	) -> Result<bool, DispatchError> {
+		let mut tick_info = Ticks::<T>::get(pool_id, tick).unwrap_or_default();
+
+		let liquidity_gross_before = tick_info.liquidity_gross;
+		let liquidity_gross_after = Self::add_liquidity_delta(liquidity_gross_before, liquidity_delta)?;
+		ensure!(
+			liquidity_gross_after <= Self::max_liquidity_per_tick(pool.tick_spacing),
+			Error::<T>::MaxLiquidityPerTickExceeded
+		);
+
+		let flipped = liquidity_gross_after.is_zero() != liquidity_gross_before.is_zero();
+
+		// By convention, all fees earned before the tick was initialized happened below the tick.
+		if liquidity_gross_before.is_zero() && tick <= pool.tick {
+			tick_info.fee_growth_outside_a = pool.fee_growth_global_a;
+			tick_info.fee_growth_outside_b = pool.fee_growth_global_b;
+		}
+
+		tick_info.liquidity_gross = liquidity_gross_after;
+		tick_info.liquidity_net = if upper {
+			tick_info.liquidity_net.checked_sub(liquidity_delta)
+		} else {
+			tick_info.liquidity_net.checked_add(liquidity_delta)
+		}
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ticks::<T>::insert(pool_id, tick, tick_info);
+
+		if flipped {
+			let (word, bit) = math::tick_position(tick / Tick::from(pool.tick_spacing));
+			TickBitmap::<T>::mutate(pool_id, word, |bitmap| *bitmap ^= U256::one() << bit);
+		}
+
+		Ok(flipped)
+	}
+
+	/// Fee growth of asset A and B inside of a price range.
+	fn fee_growth_inside(pool_id: PoolId, pool: &PoolInfo, tick_lower: Tick, tick_upper: Tick) -> (u128, u128) {
+		let lower = Ticks::<T>::get(pool_id, tick_lower).unwrap_or_default();
+		let upper = Ticks::<T>::get(pool_id, tick_upper).unwrap_or_default();
+
+		let (below_a, below_b) = if pool.tick >= tick_lower {
+			(lower.fee_growth_outside_a, lower.fee_growth_outside_b)
+		} else {
+			(
+				pool.fee_growth_global_a.wrapping_sub(lower.fee_growth_outside_a),
+				pool.fee_growth_global_b.wrapping_sub(lower.fee_growth_outside_b),
+			)
+		};
+
+		let (above_a, above_b) = if pool.tick < tick_upper {
+			(upper.fee_growth_outside_a, upper.fee_growth_outside_b)
+		} else {
+			(
+				pool.fee_growth_global_a.wrapping_sub(upper.fee_growth_outside_a),
+				pool.fee_growth_global_b.wrapping_sub(upper.fee_growth_outside_b),
+			)
+		};
+
+		(
+			pool.fee_growth_global_a.wrapping_sub(below_a).wrapping_sub(above_a),
+			pool.fee_growth_global_b.wrapping_sub(below_b).wrapping_sub(above_b),
+		)
+	}
+
+	/// Accrue fees earned by a position since its last update.
+	fn update_position_fees(
+		position: &mut Position,
+		fee_growth_inside_a: u128,
+		fee_growth_inside_b: u128,
+	) -> DispatchResult {
+		let earned_a = math::fees_earned(
+			fee_growth_inside_a.wrapping_sub(position.fee_growth_inside_a_last),
+			position.liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+		let earned_b = math::fees_earned(
+			fee_growth_inside_b.wrapping_sub(position.fee_growth_inside_b_last),
+			position.liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		position.fees_owed_a = position
+			.fees_owed_a
+			.checked_add(earned_a)
+			.ok_or(ArithmeticError::Overflow)?;
+		position.fees_owed_b = position
+			.fees_owed_b
+			.checked_add(earned_b)
+			.ok_or(ArithmeticError::Overflow)?;
+		position.fee_growth_inside_a_last = fee_growth_inside_a;
+		position.fee_growth_inside_b_last = fee_growth_inside_b;
+
+		Ok(())
+	}
+
+	fn add_liquidity_delta(liquidity: Liquidity, liquidity_delta: i128) -> Result<Liquidity, DispatchError> {
+		if liquidity_delta < 0 {
+			liquidity
+				.checked_sub(liquidity_delta.unsigned_abs())
+				.ok_or(ArithmeticError::Underflow.into())
+		} else {
+			liquidity
+				.checked_add(liquidity_delta.unsigned_abs())
+				.ok_or(ArithmeticError::Overflow.into())
+		}
+	}
+
+	fn ensure_valid_range(pool: &PoolInfo, tick_lower: Tick, tick_upper: Tick) -> DispatchResult {
+		let spacing = Tick::from(pool.tick_spacing);
+		ensure!(
+			tick_lower < tick_upper
+				&& tick_lower >= math::MIN_TICK
+				&& tick_upper <= math::MAX_TICK
+				&& tick_lower % spacing == 0
+				&& tick_upper % spacing == 0,
+			Error::<T>::InvalidTickRange
+		);
+		Ok(())
+	}
+
+	fn owned_position(who: &T::AccountId, position_id: T::PositionItemId) -> Result<Position, DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+		ensure!(
+			T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id).as_ref() == Some(who),
+			Error::<T>::Forbidden
+		);
+		Ok(position)
+	}
+
+	fn create_and_mint_position_instance(owner: &T::AccountId) -> Result<T::PositionItemId, DispatchError> {
+		NextPositionId::<T>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
+			let next_position_id = *current_value;
+
+			T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &next_position_id, owner)?;
+
+			*current_value = current_value
+				.checked_add(&T::PositionItemId::one())
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(next_position_id)
+		})
+	}
+
+	/// State of a pool passed to the hooks.
+	fn pool_state(pool_id: PoolId, pool: &PoolInfo, delta_a: Balance, delta_b: Balance) -> PoolState {
+		let pool_account = Self::pool_account(pool_id);
+		PoolState {
+			asset_a: pool.asset_a,
+			asset_b: pool.asset_b,
+			delta_a,
+			delta_b,
+			reserve_a: T::Currency::free_balance(pool.asset_a, &pool_account),
+			reserve_b: T::Currency::free_balance(pool.asset_b, &pool_account),
+			price: math::price_from_sqrt_price(pool.sqrt_price),
+			liquidity: pool.liquidity,
+		}
+	}
+
+	/// Spot price of `asset_out` denominated in `asset_in`, including the trade fee.
+	pub fn spot_price_with_fee(asset_in: AssetId, asset_out: AssetId) -> Result<FixedU128, DispatchError> {
+		let (_, pool) = Self::pool_of(asset_in, asset_out)?;
+		let (n, d) = math::price_from_sqrt_price(pool.sqrt_price);
+
+		// Pool price is the price of asset A denominated in asset B.
+		let spot_price = if asset_in == pool.asset_a {
+			FixedU128::checked_from_rational(d, n)
+		} else {
+			FixedU128::checked_from_rational(n, d)
+		}
+		.ok_or(ArithmeticError::Overflow)?;
+
+		spot_price
+			.checked_div(&FixedU128::from(Permill::one().saturating_sub(pool.fee)))
+			.ok_or(ArithmeticError::Overflow.into())
+	}
+}
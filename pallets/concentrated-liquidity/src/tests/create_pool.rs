@@ -0,0 +1,281 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::types::PoolInfo;
+use crate::{Error, Event, NextPoolId, PoolIds, Pools, MAX_TICK_SPACING};
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::concentrated_liquidity as math;
+use pretty_assertions::assert_eq;
+use sp_runtime::{DispatchError, FixedU128, Permill};
+
+#[test]
+fn create_pool_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			HDX,
+			DAI,
+			POOL_FEE,
+			TICK_SPACING,
+			FixedU128::from(1)
+		));
+
+		// Assert
+		let sqrt_price = 1u128 << 64;
+		assert_eq!(
+			Pools::<Test>::get(0),
+			Some(PoolInfo {
+				asset_a: HDX,
+				asset_b: DAI,
+				fee: POOL_FEE,
+				tick_spacing: TICK_SPACING,
+				sqrt_price,
+				tick: 0,
+				liquidity: 0,
+				fee_growth_global_a: 0,
+				fee_growth_global_b: 0,
+			})
+		);
+		assert_eq!(PoolIds::<Test>::get((HDX, DAI)), Some(0));
+		assert_eq!(NextPoolId::<Test>::get(), 1);
+
+		expect_events(vec![Event::PoolCreated {
+			pool_id: 0,
+			asset_a: HDX,
+			asset_b: DAI,
+			fee: POOL_FEE,
+			tick_spacing: TICK_SPACING,
+			sqrt_price,
+			tick: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_pool_should_order_assets_and_invert_price_when_assets_are_reversed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			DAI,
+			HDX,
+			POOL_FEE,
+			TICK_SPACING,
+			FixedU128::from(2)
+		));
+
+		// Assert
+		let expected_sqrt_price = math::sqrt_price_from_price(FixedU128::from_rational(1, 2)).unwrap();
+		let pool = Pools::<Test>::get(0).unwrap();
+		assert_eq!(pool.asset_a, HDX);
+		assert_eq!(pool.asset_b, DAI);
+		assert_eq!(pool.sqrt_price, expected_sqrt_price);
+		assert_eq!(pool.tick, math::tick_at_sqrt_price(expected_sqrt_price).unwrap());
+		assert!(pool.tick < 0);
+		assert_eq!(PoolIds::<Test>::get((HDX, DAI)), Some(0));
+		assert_eq!(PoolIds::<Test>::get((DAI, HDX)), None);
+	});
+}
+
+#[test]
+fn create_pool_should_create_nft_collection_only_once() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			HDX,
+			DAI,
+			POOL_FEE,
+			TICK_SPACING,
+			FixedU128::from(1)
+		));
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			HDX,
+			DOT,
+			POOL_FEE,
+			TICK_SPACING,
+			FixedU128::from(1)
+		));
+
+		// Assert
+		assert_eq!(PoolIds::<Test>::get((HDX, DOT)), Some(1));
+		assert!(COLLECTION_CREATED.with(|v| *v.borrow()));
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				POOL_FEE,
+				TICK_SPACING,
+				FixedU128::from(1)
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				HDX,
+				POOL_FEE,
+				TICK_SPACING,
+				FixedU128::from(1)
+			),
+			Error::<Test>::SameAssets
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_asset_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				NOT_REGISTERED,
+				POOL_FEE,
+				TICK_SPACING,
+				FixedU128::from(1)
+			),
+			Error::<Test>::AssetNotRegistered
+		);
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				NOT_REGISTERED,
+				HDX,
+				POOL_FEE,
+				TICK_SPACING,
+				FixedU128::from(1)
+			),
+			Error::<Test>::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_pool_already_exists() {
+	ExtBuilder::default()
+		.with_pool(HDX, DAI, POOL_FEE, TICK_SPACING, FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ConcentratedLiquidity::create_pool(
+					RuntimeOrigin::root(),
+					HDX,
+					DAI,
+					Permill::from_percent(1),
+					60,
+					FixedU128::from(1)
+				),
+				Error::<Test>::PoolAlreadyExists
+			);
+			assert_noop!(
+				ConcentratedLiquidity::create_pool(
+					RuntimeOrigin::root(),
+					DAI,
+					HDX,
+					POOL_FEE,
+					TICK_SPACING,
+					FixedU128::from(1)
+				),
+				Error::<Test>::PoolAlreadyExists
+			);
+		});
+}
+
+#[test]
+fn create_pool_should_fail_when_tick_spacing_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), HDX, DAI, POOL_FEE, 0, FixedU128::from(1)),
+			Error::<Test>::InvalidTickSpacing
+		);
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				POOL_FEE,
+				MAX_TICK_SPACING + 1,
+				FixedU128::from(1)
+			),
+			Error::<Test>::InvalidTickSpacing
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_fee_is_hundred_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				Permill::from_percent(100),
+				TICK_SPACING,
+				FixedU128::from(1)
+			),
+			Error::<Test>::InvalidFee
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_initial_price_is_out_of_range() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				POOL_FEE,
+				TICK_SPACING,
+				FixedU128::from(0)
+			),
+			Error::<Test>::InvalidInitialPrice
+		);
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				DAI,
+				HDX,
+				POOL_FEE,
+				TICK_SPACING,
+				FixedU128::from(0)
+			),
+			Error::<Test>::InvalidInitialPrice
+		);
+	});
+}
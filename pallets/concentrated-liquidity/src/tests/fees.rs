@@ -0,0 +1,206 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Event, Positions};
+use frame_support::assert_ok;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+const TOLERANCE: u128 = 2;
+
+fn sell_and_get_fee(who: AccountId, asset_in: AssetId, asset_out: AssetId, amount: Balance) -> Balance {
+	assert_ok!(ConcentratedLiquidity::sell(
+		RuntimeOrigin::signed(who),
+		asset_in,
+		asset_out,
+		amount,
+		0
+	));
+	match System::events().iter().rev().find_map(|r| match r.event {
+		RuntimeEvent::ConcentratedLiquidity(Event::SellExecuted { fee, .. }) => Some(fee),
+		_ => None,
+	}) {
+		Some(fee) => fee,
+		None => panic!("SellExecuted event not found"),
+	}
+}
+
+fn assert_fee_within_tolerance(collected: Balance, expected: Balance) {
+	assert!(
+		collected <= expected && expected - collected <= TOLERANCE,
+		"collected {collected}, expected {expected}"
+	);
+}
+
+#[test]
+fn collect_fees_should_transfer_fees_of_sole_position() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let fee_a = sell_and_get_fee(BOB, HDX, DAI, 10 * ONE);
+		let fee_b = sell_and_get_fee(BOB, DAI, HDX, 20 * ONE);
+		let hdx_before = Tokens::free_balance(HDX, &ALICE);
+		let dai_before = Tokens::free_balance(DAI, &ALICE);
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		let collected_a = Tokens::free_balance(HDX, &ALICE) - hdx_before;
+		let collected_b = Tokens::free_balance(DAI, &ALICE) - dai_before;
+		assert_fee_within_tolerance(collected_a, fee_a);
+		assert_fee_within_tolerance(collected_b, fee_b);
+
+		let position = Positions::<Test>::get(0).unwrap();
+		assert_eq!(position.fees_owed_a, 0);
+		assert_eq!(position.fees_owed_b, 0);
+
+		expect_events(vec![Event::FeesCollected {
+			position_id: 0,
+			who: ALICE,
+			amount_a: collected_a,
+			amount_b: collected_b,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn collect_fees_should_split_fees_pro_rata_between_positions_with_same_range() {
+	ExtBuilder::default()
+		.with_default_pool()
+		.with_position(InitialPosition {
+			owner: BOB,
+			tick_lower: -1000,
+			tick_upper: 1000,
+			amount_a_max: 3_000 * ONE,
+			amount_b_max: 3_000 * ONE,
+		})
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let fee = sell_and_get_fee(CHARLIE, HDX, DAI, 100 * ONE);
+			let alice_liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+			let bob_liquidity = Positions::<Test>::get(1).unwrap().liquidity;
+			let alice_before = Tokens::free_balance(HDX, &ALICE);
+			let bob_before = Tokens::free_balance(HDX, &BOB);
+
+			// Act
+			assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+			assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(BOB), 1));
+
+			// Assert
+			let total_liquidity = alice_liquidity + bob_liquidity;
+			let alice_fee = Tokens::free_balance(HDX, &ALICE) - alice_before;
+			let bob_fee = Tokens::free_balance(HDX, &BOB) - bob_before;
+			assert_fee_within_tolerance(alice_fee, fee * alice_liquidity / total_liquidity);
+			assert_fee_within_tolerance(bob_fee, fee * bob_liquidity / total_liquidity);
+			assert!(alice_fee + bob_fee <= fee);
+		});
+}
+
+#[test]
+fn position_out_of_range_should_not_earn_fees() {
+	ExtBuilder::default()
+		.with_default_pool()
+		.with_position(InitialPosition {
+			owner: CHARLIE,
+			tick_lower: 500,
+			tick_upper: 1000,
+			amount_a_max: 100 * ONE,
+			amount_b_max: 100 * ONE,
+		})
+		.build()
+		.execute_with(|| {
+			// Arrange
+			sell_and_get_fee(BOB, HDX, DAI, 100 * ONE);
+			let hdx_before = Tokens::free_balance(HDX, &CHARLIE);
+			let dai_before = Tokens::free_balance(DAI, &CHARLIE);
+
+			// Act
+			assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(CHARLIE), 1));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &CHARLIE), hdx_before);
+			assert_eq!(Tokens::free_balance(DAI, &CHARLIE), dai_before);
+		});
+}
+
+#[test]
+fn collect_fees_should_transfer_nothing_when_called_twice() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		sell_and_get_fee(BOB, HDX, DAI, 10 * ONE);
+		assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+		let hdx_before = Tokens::free_balance(HDX, &ALICE);
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), hdx_before);
+	});
+}
+
+#[test]
+fn decrease_liquidity_should_keep_accrued_fees_owed() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let fee = sell_and_get_fee(BOB, HDX, DAI, 10 * ONE);
+		let liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::decrease_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			liquidity,
+			0,
+			0
+		));
+
+		// Assert
+		let position = Positions::<Test>::get(0).unwrap();
+		assert_eq!(position.liquidity, 0);
+		assert_fee_within_tolerance(position.fees_owed_a, fee);
+		assert_eq!(position.fees_owed_b, 0);
+	});
+}
+
+#[test]
+fn burn_position_should_transfer_uncollected_fees() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let fee = sell_and_get_fee(BOB, HDX, DAI, 10 * ONE);
+		let liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+		assert_ok!(ConcentratedLiquidity::decrease_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			liquidity,
+			0,
+			0
+		));
+		let owed = Positions::<Test>::get(0).unwrap().fees_owed_a;
+		let hdx_before = Tokens::free_balance(HDX, &ALICE);
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::burn_position(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert_fee_within_tolerance(owed, fee);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), hdx_before + owed);
+		assert!(Positions::<Test>::get(0).is_none());
+		assert_eq!(position_owner(0), None);
+	});
+}
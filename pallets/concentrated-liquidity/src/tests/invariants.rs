@@ -0,0 +1,104 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Pools, Positions};
+use frame_support::assert_ok;
+use orml_traits::MultiCurrency;
+use proptest::prelude::*;
+use proptest::proptest;
+
+fn trade_amount() -> impl Strategy<Value = Balance> {
+	ONE..100 * ONE
+}
+
+fn trades() -> impl Strategy<Value = Vec<(bool, Balance)>> {
+	prop::collection::vec((any::<bool>(), trade_amount()), 1..5)
+}
+
+fn range() -> impl Strategy<Value = (i32, i32)> {
+	(-100..0i32, 1..100i32).prop_map(|(lower, upper)| (lower * TICK_SPACING as i32, upper * TICK_SPACING as i32))
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(100))]
+	#[test]
+	fn pool_should_stay_solvent_when_all_liquidity_is_removed_after_trades(
+		trades in trades(),
+		(tick_lower, tick_upper) in range(),
+	) {
+		ExtBuilder::default()
+			.with_default_pool()
+			.with_position(InitialPosition {
+				owner: BOB,
+				tick_lower,
+				tick_upper,
+				amount_a_max: 500 * ONE,
+				amount_b_max: 500 * ONE,
+			})
+			.build()
+			.execute_with(|| {
+				for (sell_a, amount) in trades {
+					let (asset_in, asset_out) = if sell_a { (HDX, DAI) } else { (DAI, HDX) };
+					assert_ok!(ConcentratedLiquidity::sell(
+						RuntimeOrigin::signed(CHARLIE),
+						asset_in,
+						asset_out,
+						amount,
+						0
+					));
+				}
+
+				for (owner, position_id) in [(ALICE, 0), (BOB, 1)] {
+					let liquidity = Positions::<Test>::get(position_id).unwrap().liquidity;
+					assert_ok!(ConcentratedLiquidity::decrease_liquidity(
+						RuntimeOrigin::signed(owner),
+						position_id,
+						liquidity,
+						0,
+						0
+					));
+					assert_ok!(ConcentratedLiquidity::burn_position(RuntimeOrigin::signed(owner), position_id));
+				}
+
+				assert_eq!(Pools::<Test>::get(0).unwrap().liquidity, 0);
+				// Rounding in favor of the pool may leave dust behind, but never a deficit.
+				assert!(Tokens::free_balance(HDX, &pool_account(0)) < ONE);
+				assert!(Tokens::free_balance(DAI, &pool_account(0)) < ONE);
+			});
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(100))]
+	#[test]
+	fn round_trip_trade_should_not_be_profitable(
+		amount in trade_amount(),
+		sell_a in any::<bool>(),
+	) {
+		ExtBuilder::default().with_default_pool().build().execute_with(|| {
+			let (asset_in, asset_out) = if sell_a { (HDX, DAI) } else { (DAI, HDX) };
+			let initial_in = Tokens::free_balance(asset_in, &BOB);
+			let initial_out = Tokens::free_balance(asset_out, &BOB);
+
+			assert_ok!(ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), asset_in, asset_out, amount, 0));
+			let received = Tokens::free_balance(asset_out, &BOB) - initial_out;
+			assert_ok!(ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), asset_out, asset_in, received, 0));
+
+			assert!(Tokens::free_balance(asset_in, &BOB) < initial_in);
+			assert_eq!(Tokens::free_balance(asset_out, &BOB), initial_out);
+		});
+	}
+}
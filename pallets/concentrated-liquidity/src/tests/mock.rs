@@ -0,0 +1,458 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_concentrated_liquidity;
+use crate::types::{PoolId, PoolState, Tick};
+use crate::ConcentratedLiquidityHooks;
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::weights::Weight;
+use frame_support::{
+	parameter_types,
+	traits::{Everything, Nothing},
+	PalletId,
+};
+use frame_system as system;
+use frame_system::EnsureRoot;
+use hydradx_traits::{pools::DustRemovalAccountWhitelist, registry::Inspect as InspectRegistry, AssetKind};
+use orml_tokens::AccountData;
+use orml_traits::parameter_type_with_key;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, DispatchResult, FixedU128, Permill,
+};
+use std::{cell::RefCell, collections::HashMap};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type Amount = i128;
+pub type AssetId = u32;
+pub type Balance = u128;
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2;
+pub const DOT: AssetId = 5;
+pub const NOT_REGISTERED: AssetId = 1000;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+
+pub const NFT_COLLECTION: u32 = 4242;
+
+pub const POOL_FEE: Permill = Permill::from_parts(3_000);
+pub const TICK_SPACING: u16 = 10;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	 {
+		 System: frame_system,
+		 ConcentratedLiquidity: pallet_concentrated_liquidity,
+		 Tokens: orml_tokens,
+		 Broadcast: pallet_broadcast,
+	 }
+);
+
+thread_local! {
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u32>> = RefCell::new(HashMap::default());
+	pub static POSITIONS: RefCell<HashMap<u32, u64>> = RefCell::new(HashMap::default());
+	pub static COLLECTION_CREATED: RefCell<bool> = const { RefCell::new(false) };
+	pub static MAX_SWAP_STEPS: RefCell<u32> = const { RefCell::new(64) };
+	pub static TRADE_HOOKS: RefCell<Vec<(PoolId, AssetId, AssetId, PoolState)>> = const { RefCell::new(Vec::new()) };
+	pub static LIQUIDITY_HOOKS: RefCell<Vec<(PoolId, PoolState)>> = const { RefCell::new(Vec::new()) };
+}
+
+parameter_types! {
+	pub const ConcentratedLiquidityPalletId: PalletId = PalletId(*b"py/concl");
+	pub const NFTCollectionId: u32 = NFT_COLLECTION;
+	pub const MinTradingLimit: Balance = 1_000;
+	pub MaxSwapSteps: u32 = MAX_SWAP_STEPS.with(|v| *v.borrow());
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl pallet_concentrated_liquidity::Config for Test {
+	type Currency = Tokens;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type AssetRegistry = DummyRegistry;
+	type PalletId = ConcentratedLiquidityPalletId;
+	type PositionItemId = u32;
+	type CollectionId = u32;
+	type NFTCollectionId = NFTCollectionId;
+	type NFTHandler = DummyNFT;
+	type Hooks = HooksRecorder;
+	type NonDustableWhitelistHandler = Whitelist;
+	type AccountTradeVolumeHandler = ();
+	type MinTradingLimit = MinTradingLimit;
+	type MaxSwapSteps = MaxSwapSteps;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyBenchmarkHelper;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+	type ExtensionsWeightInfo = ();
+}
+
+impl orml_tokens::Config for Test {
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = ();
+	type MaxReserves = ();
+	type CurrencyHooks = ();
+}
+
+impl pallet_broadcast::Config for Test {}
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u64>> Inspect<AccountId> for DummyNFT {
+	type ItemId = u32;
+	type CollectionId = u32;
+
+	fn owner(_class: &Self::CollectionId, instance: &Self::ItemId) -> Option<AccountId> {
+		POSITIONS.with(|v| v.borrow().get(instance).map(|o| (*o).into()))
+	}
+
+	fn collection_owner(_class: &Self::CollectionId) -> Option<AccountId> {
+		COLLECTION_CREATED
+			.with(|v| *v.borrow())
+			.then(|| ConcentratedLiquidity::protocol_account().into())
+	}
+}
+
+impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
+	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		COLLECTION_CREATED.with(|v| {
+			if *v.borrow() {
+				return Err(DispatchError::Other("CollectionInUse"));
+			}
+			*v.borrow_mut() = true;
+			Ok(())
+		})
+	}
+}
+
+impl<AccountId: From<u64> + Into<u64> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(_class: &Self::CollectionId, instance: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().insert(*instance, (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		_class: &Self::CollectionId,
+		instance: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().remove(instance);
+		});
+		Ok(())
+	}
+}
+
+pub struct DummyRegistry;
+
+impl InspectRegistry for DummyRegistry {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn decimals(_id: Self::AssetId) -> Option<u8> {
+		unimplemented!()
+	}
+
+	fn asset_type(_id: Self::AssetId) -> Option<AssetKind> {
+		unimplemented!()
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(_id: Self::AssetId) -> Option<u128> {
+		unimplemented!()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct DummyBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::types::BenchmarkHelper<AssetId> for DummyBenchmarkHelper {
+	fn register_asset(asset_id: AssetId) -> DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(asset_id, asset_id);
+		});
+		Ok(())
+	}
+}
+
+pub struct Whitelist;
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(_account: &AccountId) -> Result<(), Self::Error> {
+		Ok(())
+	}
+
+	fn remove_account(_account: &AccountId) -> Result<(), Self::Error> {
+		Ok(())
+	}
+}
+
+/// Hooks which record the reported pool states.
+pub struct HooksRecorder;
+
+impl ConcentratedLiquidityHooks for HooksRecorder {
+	fn on_liquidity_changed(pool_id: PoolId, state: PoolState) -> DispatchResult {
+		LIQUIDITY_HOOKS.with(|v| v.borrow_mut().push((pool_id, state)));
+		Ok(())
+	}
+
+	fn on_trade(pool_id: PoolId, asset_in: AssetId, asset_out: AssetId, state: PoolState) -> DispatchResult {
+		TRADE_HOOKS.with(|v| v.borrow_mut().push((pool_id, asset_in, asset_out, state)));
+		Ok(())
+	}
+
+	fn on_liquidity_changed_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Position to be minted by `ExtBuilder`.
+pub struct InitialPosition {
+	pub owner: AccountId,
+	pub tick_lower: Tick,
+	pub tick_upper: Tick,
+	pub amount_a_max: Balance,
+	pub amount_b_max: Balance,
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(u64, AssetId, Balance)>,
+	registered_assets: Vec<AssetId>,
+	pool: Option<(AssetId, AssetId, Permill, u16, FixedU128)>,
+	positions: Vec<InitialPosition>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		// or set to original default value
+		REGISTERED_ASSETS.with(|v| v.borrow_mut().clear());
+		POSITIONS.with(|v| v.borrow_mut().clear());
+		COLLECTION_CREATED.with(|v| *v.borrow_mut() = false);
+		MAX_SWAP_STEPS.with(|v| *v.borrow_mut() = 64);
+		TRADE_HOOKS.with(|v| v.borrow_mut().clear());
+		LIQUIDITY_HOOKS.with(|v| v.borrow_mut().clear());
+
+		Self {
+			endowed_accounts: vec![
+				(ALICE, HDX, 1_000_000 * ONE),
+				(ALICE, DAI, 1_000_000 * ONE),
+				(ALICE, DOT, 1_000_000 * ONE),
+				(BOB, HDX, 1_000_000 * ONE),
+				(BOB, DAI, 1_000_000 * ONE),
+				(BOB, DOT, 1_000_000 * ONE),
+				(CHARLIE, HDX, 1_000_000 * ONE),
+				(CHARLIE, DAI, 1_000_000 * ONE),
+			],
+			registered_assets: vec![HDX, DAI, DOT],
+			pool: None,
+			positions: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(u64, AssetId, Balance)>) -> Self {
+		self.endowed_accounts.extend(accounts);
+		self
+	}
+
+	pub fn with_max_swap_steps(self, steps: u32) -> Self {
+		MAX_SWAP_STEPS.with(|v| *v.borrow_mut() = steps);
+		self
+	}
+
+	pub fn with_pool(
+		mut self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		fee: Permill,
+		tick_spacing: u16,
+		initial_price: FixedU128,
+	) -> Self {
+		self.pool = Some((asset_a, asset_b, fee, tick_spacing, initial_price));
+		self
+	}
+
+	pub fn with_position(mut self, position: InitialPosition) -> Self {
+		self.positions.push(position);
+		self
+	}
+
+	/// HDX/DAI pool with price 1 and a position of ALICE in range `-1000..1000`.
+	pub fn with_default_pool(self) -> Self {
+		self.with_pool(HDX, DAI, POOL_FEE, TICK_SPACING, FixedU128::from(1))
+			.with_position(InitialPosition {
+				owner: ALICE,
+				tick_lower: -1000,
+				tick_upper: 1000,
+				amount_a_max: 1_000 * ONE,
+				amount_b_max: 1_000 * ONE,
+			})
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		REGISTERED_ASSETS.with(|v| {
+			self.registered_assets.iter().for_each(|asset| {
+				v.borrow_mut().insert(*asset, *asset);
+			});
+		});
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+
+			if let Some((asset_a, asset_b, fee, tick_spacing, initial_price)) = self.pool {
+				frame_support::assert_ok!(ConcentratedLiquidity::create_pool(
+					RuntimeOrigin::root(),
+					asset_a,
+					asset_b,
+					fee,
+					tick_spacing,
+					initial_price
+				));
+
+				for position in self.positions {
+					frame_support::assert_ok!(ConcentratedLiquidity::mint_position(
+						RuntimeOrigin::signed(position.owner),
+						0,
+						position.tick_lower,
+						position.tick_upper,
+						position.amount_a_max,
+						position.amount_b_max,
+						0
+					));
+				}
+			}
+
+			TRADE_HOOKS.with(|v| v.borrow_mut().clear());
+			LIQUIDITY_HOOKS.with(|v| v.borrow_mut().clear());
+		});
+
+		r
+	}
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	test_utils::expect_events::<RuntimeEvent, Test>(e);
+}
+
+pub fn pool_account(pool_id: PoolId) -> AccountId {
+	ConcentratedLiquidity::pool_account(pool_id)
+}
+
+pub fn position_owner(position_id: u32) -> Option<AccountId> {
+	POSITIONS.with(|v| v.borrow().get(&position_id).copied())
+}
+
+pub fn trade_hooks() -> Vec<(PoolId, AssetId, AssetId, PoolState)> {
+	TRADE_HOOKS.with(|v| v.borrow().clone())
+}
+
+pub fn liquidity_hooks() -> Vec<(PoolId, PoolState)> {
+	LIQUIDITY_HOOKS.with(|v| v.borrow().clone())
+}
@@ -0,0 +1,521 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::types::{Liquidity, Position, Tick};
+use crate::{Error, Event, Pools, Positions, TickBitmap, Ticks};
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::concentrated_liquidity as math;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_core::U256;
+use sp_runtime::FixedU128;
+
+fn liquidity_for(tick_lower: Tick, tick_upper: Tick, amount_a: Balance, amount_b: Balance) -> Liquidity {
+	let pool = Pools::<Test>::get(0).unwrap();
+	math::liquidity_for_amounts(
+		pool.sqrt_price,
+		math::sqrt_price_at_tick(tick_lower).unwrap(),
+		math::sqrt_price_at_tick(tick_upper).unwrap(),
+		amount_a,
+		amount_b,
+	)
+	.unwrap()
+}
+
+fn amounts_for(tick_lower: Tick, tick_upper: Tick, liquidity: Liquidity, round_up: bool) -> (Balance, Balance) {
+	let pool = Pools::<Test>::get(0).unwrap();
+	math::amounts_for_liquidity(
+		pool.sqrt_price,
+		math::sqrt_price_at_tick(tick_lower).unwrap(),
+		math::sqrt_price_at_tick(tick_upper).unwrap(),
+		liquidity,
+		round_up,
+	)
+	.unwrap()
+}
+
+fn is_tick_initialized(tick: Tick) -> bool {
+	let (word, bit) = math::tick_position(tick / Tick::from(TICK_SPACING));
+	TickBitmap::<Test>::get(0, word) & (U256::one() << bit) != U256::zero()
+}
+
+#[test]
+fn mint_position_should_work_when_range_contains_current_price() {
+	ExtBuilder::default()
+		.with_pool(HDX, DAI, POOL_FEE, TICK_SPACING, FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let expected_liquidity = liquidity_for(-1000, 1000, 1_000 * ONE, 500 * ONE);
+			let (amount_a, amount_b) = amounts_for(-1000, 1000, expected_liquidity, true);
+
+			// Act
+			assert_ok!(ConcentratedLiquidity::mint_position(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				-1000,
+				1000,
+				1_000 * ONE,
+				500 * ONE,
+				expected_liquidity
+			));
+
+			// Assert
+			assert!(amount_a > 0 && amount_a <= 1_000 * ONE);
+			assert!(amount_b > 0 && amount_b <= 500 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &pool_account(0)), amount_a);
+			assert_eq!(Tokens::free_balance(DAI, &pool_account(0)), amount_b);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), 1_000_000 * ONE - amount_a);
+			assert_eq!(Tokens::free_balance(DAI, &ALICE), 1_000_000 * ONE - amount_b);
+
+			assert_eq!(
+				Positions::<Test>::get(0),
+				Some(Position {
+					pool_id: 0,
+					tick_lower: -1000,
+					tick_upper: 1000,
+					liquidity: expected_liquidity,
+					fee_growth_inside_a_last: 0,
+					fee_growth_inside_b_last: 0,
+					fees_owed_a: 0,
+					fees_owed_b: 0,
+				})
+			);
+			assert_eq!(position_owner(0), Some(ALICE));
+			assert_eq!(Pools::<Test>::get(0).unwrap().liquidity, expected_liquidity);
+
+			let lower = Ticks::<Test>::get(0, -1000).unwrap();
+			let upper = Ticks::<Test>::get(0, 1000).unwrap();
+			assert_eq!(lower.liquidity_gross, expected_liquidity);
+			assert_eq!(lower.liquidity_net, expected_liquidity as i128);
+			assert_eq!(upper.liquidity_gross, expected_liquidity);
+			assert_eq!(upper.liquidity_net, -(expected_liquidity as i128));
+			assert!(is_tick_initialized(-1000));
+			assert!(is_tick_initialized(1000));
+
+			let hooks = liquidity_hooks();
+			assert_eq!(hooks.len(), 1);
+			assert_eq!(hooks[0].1.delta_a, amount_a);
+			assert_eq!(hooks[0].1.delta_b, amount_b);
+			assert_eq!(hooks[0].1.reserve_a, amount_a);
+			assert_eq!(hooks[0].1.reserve_b, amount_b);
+
+			expect_events(vec![Event::PositionMinted {
+				position_id: 0,
+				owner: ALICE,
+				pool_id: 0,
+				tick_lower: -1000,
+				tick_upper: 1000,
+				liquidity: expected_liquidity,
+				amount_a,
+				amount_b,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn mint_position_should_take_only_asset_a_when_range_is_above_current_price() {
+	ExtBuilder::default()
+		.with_pool(HDX, DAI, POOL_FEE, TICK_SPACING, FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(ConcentratedLiquidity::mint_position(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				100,
+				1000,
+				1_000 * ONE,
+				1_000 * ONE,
+				0
+			));
+
+			// Assert
+			assert!(Tokens::free_balance(HDX, &pool_account(0)) > 0);
+			assert_eq!(Tokens::free_balance(DAI, &pool_account(0)), 0);
+			assert_eq!(Pools::<Test>::get(0).unwrap().liquidity, 0);
+		});
+}
+
+#[test]
+fn mint_position_should_take_only_asset_b_when_range_is_below_current_price() {
+	ExtBuilder::default()
+		.with_pool(HDX, DAI, POOL_FEE, TICK_SPACING, FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(ConcentratedLiquidity::mint_position(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				-1000,
+				-100,
+				1_000 * ONE,
+				1_000 * ONE,
+				0
+			));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &pool_account(0)), 0);
+			assert!(Tokens::free_balance(DAI, &pool_account(0)) > 0);
+			assert_eq!(Pools::<Test>::get(0).unwrap().liquidity, 0);
+		});
+}
+
+#[test]
+fn mint_position_should_fail_when_tick_range_is_invalid() {
+	ExtBuilder::default()
+		.with_pool(HDX, DAI, POOL_FEE, TICK_SPACING, FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			for (tick_lower, tick_upper) in [
+				(-1005, 1000),
+				(-1000, 1005),
+				(1000, 1000),
+				(1000, -1000),
+				(math::MIN_TICK - 4, 1000),
+				(-1000, math::MAX_TICK + 4),
+			] {
+				assert_noop!(
+					ConcentratedLiquidity::mint_position(
+						RuntimeOrigin::signed(ALICE),
+						0,
+						tick_lower,
+						tick_upper,
+						1_000 * ONE,
+						1_000 * ONE,
+						0
+					),
+					Error::<Test>::InvalidTickRange
+				);
+			}
+		});
+}
+
+#[test]
+fn mint_position_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::mint_position(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				-1000,
+				1000,
+				1_000 * ONE,
+				1_000 * ONE,
+				0
+			),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn mint_position_should_fail_when_amounts_are_zero() {
+	ExtBuilder::default()
+		.with_pool(HDX, DAI, POOL_FEE, TICK_SPACING, FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ConcentratedLiquidity::mint_position(RuntimeOrigin::signed(ALICE), 0, -1000, 1000, 0, 1_000 * ONE, 0),
+				Error::<Test>::ZeroLiquidity
+			);
+		});
+}
+
+#[test]
+fn mint_position_should_fail_when_liquidity_is_below_minimum() {
+	ExtBuilder::default()
+		.with_pool(HDX, DAI, POOL_FEE, TICK_SPACING, FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			let liquidity = liquidity_for(-1000, 1000, 1_000 * ONE, 1_000 * ONE);
+
+			assert_noop!(
+				ConcentratedLiquidity::mint_position(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					-1000,
+					1000,
+					1_000 * ONE,
+					1_000 * ONE,
+					liquidity + 1
+				),
+				Error::<Test>::InsufficientLiquidityProvided
+			);
+		});
+}
+
+#[test]
+fn increase_liquidity_should_work() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let initial_liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+		let added_liquidity = liquidity_for(-1000, 1000, 100 * ONE, 100 * ONE);
+		let (amount_a, amount_b) = amounts_for(-1000, 1000, added_liquidity, true);
+		let pool_a = Tokens::free_balance(HDX, &pool_account(0));
+		let pool_b = Tokens::free_balance(DAI, &pool_account(0));
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::increase_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			100 * ONE,
+			100 * ONE,
+			0
+		));
+
+		// Assert
+		assert_eq!(
+			Positions::<Test>::get(0).unwrap().liquidity,
+			initial_liquidity + added_liquidity
+		);
+		assert_eq!(
+			Pools::<Test>::get(0).unwrap().liquidity,
+			initial_liquidity + added_liquidity
+		);
+		assert_eq!(Tokens::free_balance(HDX, &pool_account(0)), pool_a + amount_a);
+		assert_eq!(Tokens::free_balance(DAI, &pool_account(0)), pool_b + amount_b);
+
+		expect_events(vec![Event::LiquidityIncreased {
+			position_id: 0,
+			who: ALICE,
+			liquidity: added_liquidity,
+			amount_a,
+			amount_b,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn increase_liquidity_should_fail_when_caller_is_not_owner() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::increase_liquidity(RuntimeOrigin::signed(BOB), 0, 100 * ONE, 100 * ONE, 0),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn increase_liquidity_should_fail_when_position_does_not_exist() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::increase_liquidity(RuntimeOrigin::signed(ALICE), 1, 100 * ONE, 100 * ONE, 0),
+			Error::<Test>::PositionNotFound
+		);
+	});
+}
+
+#[test]
+fn decrease_liquidity_should_transfer_amounts_rounded_down() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+		let removed_liquidity = liquidity / 2;
+		let (amount_a, amount_b) = amounts_for(-1000, 1000, removed_liquidity, false);
+		let alice_a = Tokens::free_balance(HDX, &ALICE);
+		let alice_b = Tokens::free_balance(DAI, &ALICE);
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::decrease_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			removed_liquidity,
+			amount_a,
+			amount_b
+		));
+
+		// Assert
+		assert_eq!(
+			Positions::<Test>::get(0).unwrap().liquidity,
+			liquidity - removed_liquidity
+		);
+		assert_eq!(Pools::<Test>::get(0).unwrap().liquidity, liquidity - removed_liquidity);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), alice_a + amount_a);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), alice_b + amount_b);
+
+		expect_events(vec![Event::LiquidityDecreased {
+			position_id: 0,
+			who: ALICE,
+			liquidity: removed_liquidity,
+			amount_a,
+			amount_b,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn decrease_liquidity_should_clear_ticks_when_all_liquidity_is_removed() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::decrease_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			liquidity,
+			0,
+			0
+		));
+
+		// Assert
+		assert_eq!(Positions::<Test>::get(0).unwrap().liquidity, 0);
+		assert_eq!(Pools::<Test>::get(0).unwrap().liquidity, 0);
+		assert_eq!(Ticks::<Test>::get(0, -1000), None);
+		assert_eq!(Ticks::<Test>::get(0, 1000), None);
+		assert!(!is_tick_initialized(-1000));
+		assert!(!is_tick_initialized(1000));
+
+		// Rounding is in favor of the pool.
+		assert!(Tokens::free_balance(HDX, &pool_account(0)) <= 1);
+		assert!(Tokens::free_balance(DAI, &pool_account(0)) <= 1);
+	});
+}
+
+#[test]
+fn decrease_liquidity_should_keep_shared_ticks_initialized() {
+	ExtBuilder::default()
+		.with_default_pool()
+		.with_position(InitialPosition {
+			owner: BOB,
+			tick_lower: -1000,
+			tick_upper: 500,
+			amount_a_max: 100 * ONE,
+			amount_b_max: 100 * ONE,
+		})
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+			let bob_liquidity = Positions::<Test>::get(1).unwrap().liquidity;
+
+			// Act
+			assert_ok!(ConcentratedLiquidity::decrease_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				liquidity,
+				0,
+				0
+			));
+
+			// Assert
+			let lower = Ticks::<Test>::get(0, -1000).unwrap();
+			assert_eq!(lower.liquidity_gross, bob_liquidity);
+			assert_eq!(lower.liquidity_net, bob_liquidity as i128);
+			assert!(is_tick_initialized(-1000));
+			assert!(is_tick_initialized(500));
+			assert!(!is_tick_initialized(1000));
+			assert_eq!(Pools::<Test>::get(0).unwrap().liquidity, bob_liquidity);
+		});
+}
+
+#[test]
+fn decrease_liquidity_should_fail_when_liquidity_exceeds_position() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+
+		assert_noop!(
+			ConcentratedLiquidity::decrease_liquidity(RuntimeOrigin::signed(ALICE), 0, liquidity + 1, 0, 0),
+			Error::<Test>::InsufficientPositionLiquidity
+		);
+		assert_noop!(
+			ConcentratedLiquidity::decrease_liquidity(RuntimeOrigin::signed(ALICE), 0, 0, 0, 0),
+			Error::<Test>::ZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn decrease_liquidity_should_fail_when_amount_is_below_limit() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+		let (amount_a, amount_b) = amounts_for(-1000, 1000, liquidity, false);
+
+		assert_noop!(
+			ConcentratedLiquidity::decrease_liquidity(RuntimeOrigin::signed(ALICE), 0, liquidity, amount_a + 1, 0),
+			Error::<Test>::AssetAmountNotReachedLimit
+		);
+		assert_noop!(
+			ConcentratedLiquidity::decrease_liquidity(RuntimeOrigin::signed(ALICE), 0, liquidity, 0, amount_b + 1),
+			Error::<Test>::AssetAmountNotReachedLimit
+		);
+	});
+}
+
+#[test]
+fn decrease_liquidity_should_be_allowed_only_to_the_position_holder() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+		// Position NFT is transferred to BOB.
+		POSITIONS.with(|v| v.borrow_mut().insert(0, BOB));
+
+		// Act & Assert
+		assert_noop!(
+			ConcentratedLiquidity::decrease_liquidity(RuntimeOrigin::signed(ALICE), 0, liquidity, 0, 0),
+			Error::<Test>::Forbidden
+		);
+		assert_ok!(ConcentratedLiquidity::decrease_liquidity(
+			RuntimeOrigin::signed(BOB),
+			0,
+			liquidity,
+			0,
+			0
+		));
+	});
+}
+
+#[test]
+fn burn_position_should_work_when_position_is_empty() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+		assert_ok!(ConcentratedLiquidity::decrease_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			liquidity,
+			0,
+			0
+		));
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::burn_position(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert_eq!(Positions::<Test>::get(0), None);
+		assert_eq!(position_owner(0), None);
+
+		expect_events(vec![Event::PositionBurned {
+			position_id: 0,
+			who: ALICE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn burn_position_should_fail_when_position_has_liquidity() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::burn_position(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::PositionNotEmpty
+		);
+	});
+}
@@ -0,0 +1,164 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use frame_support::assert_ok;
+use hydradx_traits::router::{ExecutorError, PoolType, Trade, TradeExecution, TradeablePoolsProvider};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::{FixedU128, Permill};
+
+type Executor = ConcentratedLiquidity;
+
+#[test]
+fn calculate_out_given_in_should_match_executed_sell() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let amount = 10 * ONE;
+		let expected = <Executor as TradeExecution<_, _, _, _>>::calculate_out_given_in(
+			PoolType::ConcentratedLiquidity,
+			HDX,
+			DAI,
+			amount,
+		)
+		.unwrap();
+
+		// Act
+		assert_ok!(<Executor as TradeExecution<_, _, _, _>>::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::ConcentratedLiquidity,
+			HDX,
+			DAI,
+			amount,
+			expected
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_000_000 * ONE + expected);
+	});
+}
+
+#[test]
+fn calculate_in_given_out_should_match_executed_buy() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let amount = 10 * ONE;
+		let expected = <Executor as TradeExecution<_, _, _, _>>::calculate_in_given_out(
+			PoolType::ConcentratedLiquidity,
+			DAI,
+			HDX,
+			amount,
+		)
+		.unwrap();
+
+		// Act
+		assert_ok!(<Executor as TradeExecution<_, _, _, _>>::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::ConcentratedLiquidity,
+			DAI,
+			HDX,
+			amount,
+			expected
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_000_000 * ONE - expected);
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 1_000_000 * ONE + amount);
+	});
+}
+
+#[test]
+fn trade_execution_should_not_support_other_pool_types() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_eq!(
+			<Executor as TradeExecution<_, _, _, _>>::calculate_out_given_in(PoolType::XYK, HDX, DAI, ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			<Executor as TradeExecution<_, _, _, _>>::calculate_in_given_out(PoolType::Omnipool, HDX, DAI, ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			<Executor as TradeExecution<_, _, _, _>>::execute_sell(
+				RuntimeOrigin::signed(BOB),
+				PoolType::XYK,
+				HDX,
+				DAI,
+				ONE,
+				0
+			),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			<Executor as TradeExecution<_, _, _, _>>::calculate_spot_price_with_fee(PoolType::XYK, HDX, DAI),
+			Err(ExecutorError::NotSupported)
+		);
+	});
+}
+
+#[test]
+fn calculate_spot_price_with_fee_should_include_pool_fee() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let expected = FixedU128::from(1) / FixedU128::from(Permill::from_parts(997_000));
+
+		assert_eq!(
+			<Executor as TradeExecution<_, _, _, _>>::calculate_spot_price_with_fee(
+				PoolType::ConcentratedLiquidity,
+				HDX,
+				DAI
+			),
+			Ok(expected)
+		);
+		assert_eq!(
+			<Executor as TradeExecution<_, _, _, _>>::calculate_spot_price_with_fee(
+				PoolType::ConcentratedLiquidity,
+				DAI,
+				HDX
+			),
+			Ok(expected)
+		);
+	});
+}
+
+#[test]
+fn get_liquidity_depth_should_return_pool_reserve() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_eq!(
+			<Executor as TradeExecution<_, _, _, _>>::get_liquidity_depth(PoolType::ConcentratedLiquidity, DAI, HDX),
+			Ok(Tokens::free_balance(DAI, &pool_account(0)))
+		);
+	});
+}
+
+#[test]
+fn tradeable_pools_should_list_both_directions() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_eq!(
+			<Executor as TradeablePoolsProvider<AssetId>>::tradeable_pools(),
+			vec![
+				Trade {
+					pool: PoolType::ConcentratedLiquidity,
+					asset_in: HDX,
+					asset_out: DAI,
+				},
+				Trade {
+					pool: PoolType::ConcentratedLiquidity,
+					asset_in: DAI,
+					asset_out: HDX,
+				},
+			]
+		);
+	});
+}
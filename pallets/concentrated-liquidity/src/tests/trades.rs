@@ -0,0 +1,384 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::types::PoolState;
+use crate::{Error, Event, Pools, Positions, Ticks};
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::concentrated_liquidity as math;
+use orml_traits::MultiCurrency;
+use pallet_broadcast::types::{Asset, Destination, Fee, Filler, TradeOperation};
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+fn with_crossing_position(builder: ExtBuilder) -> ExtBuilder {
+	builder.with_default_pool().with_position(InitialPosition {
+		owner: BOB,
+		tick_lower: -200,
+		tick_upper: 200,
+		amount_a_max: 100 * ONE,
+		amount_b_max: 100 * ONE,
+	})
+}
+
+#[test]
+fn sell_should_work() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let pool = Pools::<Test>::get(0).unwrap();
+		let amount = 10 * ONE;
+		let step = math::compute_swap_step(
+			pool.sqrt_price,
+			math::sqrt_price_at_tick(-1000).unwrap(),
+			pool.liquidity,
+			amount,
+			POOL_FEE,
+			true,
+		)
+		.unwrap();
+		let pool_a = Tokens::free_balance(HDX, &pool_account(0));
+		let pool_b = Tokens::free_balance(DAI, &pool_account(0));
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DAI,
+			amount,
+			0
+		));
+
+		// Assert
+		let amount_out = step.amount_out;
+		assert!(amount_out > 0 && amount_out < amount);
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 1_000_000 * ONE - amount);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_000_000 * ONE + amount_out);
+		assert_eq!(Tokens::free_balance(HDX, &pool_account(0)), pool_a + amount);
+		assert_eq!(Tokens::free_balance(DAI, &pool_account(0)), pool_b - amount_out);
+
+		let pool_after = Pools::<Test>::get(0).unwrap();
+		assert_eq!(pool_after.sqrt_price, step.sqrt_price_next);
+		assert_eq!(pool_after.tick, math::tick_at_sqrt_price(step.sqrt_price_next).unwrap());
+		assert_eq!(pool_after.liquidity, pool.liquidity);
+		assert!(pool_after.sqrt_price < pool.sqrt_price);
+		assert!(pool_after.fee_growth_global_a > 0);
+		assert_eq!(pool_after.fee_growth_global_b, 0);
+
+		assert_eq!(
+			trade_hooks(),
+			vec![(
+				0,
+				HDX,
+				DAI,
+				PoolState {
+					asset_a: HDX,
+					asset_b: DAI,
+					delta_a: amount,
+					delta_b: amount_out,
+					reserve_a: pool_a + amount,
+					reserve_b: pool_b - amount_out,
+					price: math::price_from_sqrt_price(step.sqrt_price_next),
+					liquidity: pool.liquidity,
+				}
+			)]
+		);
+
+		expect_events(vec![
+			Event::SellExecuted {
+				who: BOB,
+				pool_id: 0,
+				asset_in: HDX,
+				asset_out: DAI,
+				amount_in: amount,
+				amount_out,
+				fee: step.fee_amount,
+			}
+			.into(),
+			pallet_broadcast::Event::Swapped3 {
+				swapper: BOB,
+				filler: pool_account(0),
+				filler_type: Filler::ConcentratedLiquidity(0),
+				operation: TradeOperation::ExactIn,
+				inputs: vec![Asset::new(HDX, amount)],
+				outputs: vec![Asset::new(DAI, amount_out)],
+				fees: vec![Fee::new(HDX, step.fee_amount, Destination::Account(pool_account(0)))],
+				operation_stack: vec![],
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn sell_of_asset_b_should_increase_price() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let pool = Pools::<Test>::get(0).unwrap();
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			HDX,
+			10 * ONE,
+			0
+		));
+
+		// Assert
+		let pool_after = Pools::<Test>::get(0).unwrap();
+		assert!(pool_after.sqrt_price > pool.sqrt_price);
+		assert!(pool_after.tick >= 0);
+		assert_eq!(pool_after.fee_growth_global_a, 0);
+		assert!(pool_after.fee_growth_global_b > 0);
+		assert!(Tokens::free_balance(HDX, &BOB) > 1_000_000 * ONE);
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		// Arrange
+		let pool = Pools::<Test>::get(0).unwrap();
+		let amount = 10 * ONE;
+		let step = math::compute_swap_step(
+			pool.sqrt_price,
+			math::sqrt_price_at_tick(-1000).unwrap(),
+			pool.liquidity,
+			amount,
+			POOL_FEE,
+			false,
+		)
+		.unwrap();
+		let amount_in = step.amount_in + step.fee_amount;
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::buy(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			HDX,
+			amount,
+			amount_in
+		));
+
+		// Assert
+		assert!(amount_in > amount);
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 1_000_000 * ONE - amount_in);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_000_000 * ONE + amount);
+		assert_eq!(Pools::<Test>::get(0).unwrap().sqrt_price, step.sqrt_price_next);
+
+		expect_events(vec![
+			Event::BuyExecuted {
+				who: BOB,
+				pool_id: 0,
+				asset_in: HDX,
+				asset_out: DAI,
+				amount_in,
+				amount_out: amount,
+				fee: step.fee_amount,
+			}
+			.into(),
+			pallet_broadcast::Event::Swapped3 {
+				swapper: BOB,
+				filler: pool_account(0),
+				filler_type: Filler::ConcentratedLiquidity(0),
+				operation: TradeOperation::ExactOut,
+				inputs: vec![Asset::new(HDX, amount_in)],
+				outputs: vec![Asset::new(DAI, amount)],
+				fees: vec![Fee::new(HDX, step.fee_amount, Destination::Account(pool_account(0)))],
+				operation_stack: vec![],
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn sell_should_cross_initialized_tick_and_update_liquidity() {
+	with_crossing_position(ExtBuilder::default()).build().execute_with(|| {
+		// Arrange
+		let pool = Pools::<Test>::get(0).unwrap();
+		let alice_liquidity = Positions::<Test>::get(0).unwrap().liquidity;
+		let bob_liquidity = Positions::<Test>::get(1).unwrap().liquidity;
+		assert_eq!(pool.liquidity, alice_liquidity + bob_liquidity);
+
+		let amount_to_tick = math::amount_a_delta(
+			math::sqrt_price_at_tick(-200).unwrap(),
+			pool.sqrt_price,
+			pool.liquidity,
+			true,
+		)
+		.unwrap();
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(CHARLIE),
+			HDX,
+			DAI,
+			2 * amount_to_tick,
+			0
+		));
+
+		// Assert
+		let pool_after = Pools::<Test>::get(0).unwrap();
+		assert!(pool_after.tick < -200);
+		assert_eq!(pool_after.liquidity, alice_liquidity);
+
+		let crossed = Ticks::<Test>::get(0, -200).unwrap();
+		assert!(crossed.fee_growth_outside_a > 0);
+		assert!(crossed.fee_growth_outside_a < pool_after.fee_growth_global_a);
+		assert_eq!(crossed.fee_growth_outside_b, 0);
+	});
+}
+
+#[test]
+fn liquidity_should_be_restored_when_price_crosses_tick_back() {
+	with_crossing_position(ExtBuilder::default()).build().execute_with(|| {
+		// Arrange
+		let pool = Pools::<Test>::get(0).unwrap();
+		let amount_to_tick = math::amount_a_delta(
+			math::sqrt_price_at_tick(-200).unwrap(),
+			pool.sqrt_price,
+			pool.liquidity,
+			true,
+		)
+		.unwrap();
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(CHARLIE),
+			HDX,
+			DAI,
+			2 * amount_to_tick,
+			0
+		));
+		assert!(Pools::<Test>::get(0).unwrap().tick < -200);
+
+		// Act
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(CHARLIE),
+			DAI,
+			HDX,
+			3 * amount_to_tick,
+			0
+		));
+
+		// Assert
+		let pool_after = Pools::<Test>::get(0).unwrap();
+		assert!(pool_after.tick >= -200 && pool_after.tick < 200);
+		assert_eq!(pool_after.liquidity, pool.liquidity);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_amount_is_below_trading_limit() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DAI, 999, 0),
+			Error::<Test>::InsufficientTradingAmount
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), DAI, HDX, 999, u128::MAX),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_amount_out_is_below_limit() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DAI, 10 * ONE, 10 * ONE),
+			Error::<Test>::AssetAmountNotReachedLimit
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_amount_in_exceeds_limit() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), DAI, HDX, 10 * ONE, 10 * ONE),
+			Error::<Test>::AssetAmountExceededLimit
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DOT, 10 * ONE, 0),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_pool_has_not_enough_liquidity() {
+	ExtBuilder::default()
+		.with_pool(HDX, DAI, POOL_FEE, 2000, FixedU128::from(1))
+		.with_position(InitialPosition {
+			owner: ALICE,
+			tick_lower: -2000,
+			tick_upper: 2000,
+			amount_a_max: 1_000 * ONE,
+			amount_b_max: 1_000 * ONE,
+		})
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DAI, 10_000 * ONE, 0),
+				Error::<Test>::InsufficientLiquidity
+			);
+			assert_noop!(
+				ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), DAI, HDX, 10_000 * ONE, u128::MAX),
+				Error::<Test>::InsufficientLiquidity
+			);
+		});
+}
+
+#[test]
+fn sell_should_fail_when_trade_needs_more_steps_than_allowed() {
+	with_crossing_position(ExtBuilder::default().with_max_swap_steps(1))
+		.build()
+		.execute_with(|| {
+			let pool = Pools::<Test>::get(0).unwrap();
+			let amount_to_tick = math::amount_a_delta(
+				math::sqrt_price_at_tick(-200).unwrap(),
+				pool.sqrt_price,
+				pool.liquidity,
+				true,
+			)
+			.unwrap();
+
+			assert_noop!(
+				ConcentratedLiquidity::sell(RuntimeOrigin::signed(CHARLIE), HDX, DAI, 2 * amount_to_tick, 0),
+				Error::<Test>::MaxSwapStepsExceeded
+			);
+		});
+}
+
+#[test]
+fn sell_should_fail_when_seller_has_insufficient_balance() {
+	let dave: AccountId = 4;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(dave, HDX, ONE)])
+		.with_default_pool()
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ConcentratedLiquidity::sell(RuntimeOrigin::signed(dave), HDX, DAI, 10 * ONE, 0),
+				orml_tokens::Error::<Test>::BalanceTooLow
+			);
+		});
+}
@@ -0,0 +1,133 @@
+use crate::types::{AssetId, Balance};
+use crate::{Config, Error, Pallet, PoolIds};
+use frame_support::ensure;
+use frame_support::traits::Get;
+use hydradx_traits::router::{ExecutorError, PoolType, Trade, TradeExecution, TradeablePoolsProvider};
+use orml_traits::MultiCurrency;
+use sp_runtime::{DispatchError, FixedU128};
+use sp_std::vec::Vec;
+
+impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn calculate_out_given_in(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::ConcentratedLiquidity {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		ensure!(
+			amount_in >= T::MinTradingLimit::get(),
+			ExecutorError::Error(Error::<T>::InsufficientTradingAmount.into())
+		);
+
+		let (pool_id, pool) = Self::pool_of(asset_in, asset_out).map_err(ExecutorError::Error)?;
+		let outcome = Self::simulate_swap(pool_id, pool, asset_in == pool.asset_a, amount_in, true)
+			.map_err(ExecutorError::Error)?;
+
+		Ok(outcome.amount_out)
+	}
+
+	fn calculate_in_given_out(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::ConcentratedLiquidity {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		ensure!(
+			amount_out >= T::MinTradingLimit::get(),
+			ExecutorError::Error(Error::<T>::InsufficientTradingAmount.into())
+		);
+
+		let (pool_id, pool) = Self::pool_of(asset_in, asset_out).map_err(ExecutorError::Error)?;
+		let outcome = Self::simulate_swap(pool_id, pool, asset_in == pool.asset_a, amount_out, false)
+			.map_err(ExecutorError::Error)?;
+
+		Ok(outcome.amount_in)
+	}
+
+	fn execute_sell(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		if pool_type != PoolType::ConcentratedLiquidity {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		Self::sell(who, asset_in, asset_out, amount_in, min_limit).map_err(ExecutorError::Error)
+	}
+
+	fn execute_buy(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		if pool_type != PoolType::ConcentratedLiquidity {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		Self::buy(who, asset_out, asset_in, amount_out, max_limit).map_err(ExecutorError::Error)
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<AssetId>,
+		asset_a: AssetId,
+		asset_b: AssetId,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::ConcentratedLiquidity {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let (pool_id, _) = Self::pool_of(asset_a, asset_b).map_err(ExecutorError::Error)?;
+
+		Ok(T::Currency::free_balance(asset_a, &Self::pool_account(pool_id)))
+	}
+
+	fn calculate_spot_price_with_fee(
+		pool_type: PoolType<AssetId>,
+		asset_a: AssetId,
+		asset_b: AssetId,
+	) -> Result<FixedU128, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::ConcentratedLiquidity {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		Self::spot_price_with_fee(asset_a, asset_b).map_err(ExecutorError::Error)
+	}
+}
+
+impl<T: Config> TradeablePoolsProvider<AssetId> for Pallet<T> {
+	fn tradeable_pools() -> Vec<Trade<AssetId>> {
+		PoolIds::<T>::iter_keys()
+			.flat_map(|(asset_a, asset_b)| {
+				[
+					Trade {
+						pool: PoolType::ConcentratedLiquidity,
+						asset_in: asset_a,
+						asset_out: asset_b,
+					},
+					Trade {
+						pool: PoolType::ConcentratedLiquidity,
+						asset_in: asset_b,
+						asset_out: asset_a,
+					},
+				]
+			})
+			.collect()
+	}
+}
@@ -0,0 +1,114 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::dispatch::DispatchResult;
+use frame_support::weights::Weight;
+pub use hydra_dx_math::concentrated_liquidity::types::{Liquidity, SqrtPrice, Tick};
+use scale_info::TypeInfo;
+use sp_runtime::{Permill, RuntimeDebug};
+
+pub type AssetId = u32;
+pub type Balance = u128;
+pub type PoolId = u32;
+
+/// Concentrated liquidity pool of two assets.
+///
+/// Price of the pool is the price of `asset_a` denominated in `asset_b`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolInfo {
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+	/// Trade fee, paid in the asset sold to the pool and distributed to the in-range liquidity.
+	pub fee: Permill,
+	/// Only ticks divisible by the spacing can be used as position bounds.
+	pub tick_spacing: u16,
+	/// Square root of the current price as Q64.64 number.
+	pub sqrt_price: SqrtPrice,
+	/// Current tick - the greatest tick with square root price lower or equal to `sqrt_price`.
+	pub tick: Tick,
+	/// Liquidity of the positions in range of the current tick.
+	pub liquidity: Liquidity,
+	/// Total fees of asset A earned per unit of liquidity as Q64.64 number. Can wrap around.
+	pub fee_growth_global_a: u128,
+	/// Total fees of asset B earned per unit of liquidity as Q64.64 number. Can wrap around.
+	pub fee_growth_global_b: u128,
+}
+
+/// Initialized tick - a bound of at least one position.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TickInfo {
+	/// Total liquidity of the positions using the tick as a bound.
+	pub liquidity_gross: Liquidity,
+	/// Liquidity added when the tick is crossed from left to right, removed when crossed from right to left.
+	pub liquidity_net: i128,
+	/// Fee growth of asset A on the other side of the tick, relative to the current tick.
+	pub fee_growth_outside_a: u128,
+	/// Fee growth of asset B on the other side of the tick, relative to the current tick.
+	pub fee_growth_outside_b: u128,
+}
+
+/// Liquidity provided to a pool in a price range. Owned by the holder of the position NFT.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Position {
+	pub pool_id: PoolId,
+	pub tick_lower: Tick,
+	pub tick_upper: Tick,
+	pub liquidity: Liquidity,
+	/// Fee growth of asset A inside of the range at the last update of the position.
+	pub fee_growth_inside_a_last: u128,
+	/// Fee growth of asset B inside of the range at the last update of the position.
+	pub fee_growth_inside_b_last: u128,
+	/// Uncollected fees of asset A.
+	pub fees_owed_a: Balance,
+	/// Uncollected fees of asset B.
+	pub fees_owed_b: Balance,
+}
+
+/// State of a pool after a trade or a liquidity change, passed to the hooks.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PoolState {
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+	/// Amount of asset A added to or removed from the pool.
+	pub delta_a: Balance,
+	/// Amount of asset B added to or removed from the pool.
+	pub delta_b: Balance,
+	/// Balance of asset A held by the pool after the change.
+	pub reserve_a: Balance,
+	/// Balance of asset B held by the pool after the change.
+	pub reserve_b: Balance,
+	/// Price of asset A denominated in asset B after the change, as a rational number.
+	pub price: (Balance, Balance),
+	/// Liquidity in range of the current tick after the change.
+	pub liquidity: Liquidity,
+}
+
+/// Interface for populating oracle from concentrated liquidity pools, and getting their weights
+pub trait ConcentratedLiquidityHooks {
+	fn on_liquidity_changed(pool_id: PoolId, state: PoolState) -> DispatchResult;
+	fn on_trade(pool_id: PoolId, asset_in: AssetId, asset_out: AssetId, state: PoolState) -> DispatchResult;
+
+	fn on_liquidity_changed_weight() -> Weight;
+	fn on_trade_weight() -> Weight;
+}
+
+impl ConcentratedLiquidityHooks for () {
+	fn on_liquidity_changed(_pool_id: PoolId, _state: PoolState) -> DispatchResult {
+		Ok(())
+	}
+
+	fn on_trade(_pool_id: PoolId, _asset_in: AssetId, _asset_out: AssetId, _state: PoolState) -> DispatchResult {
+		Ok(())
+	}
+
+	fn on_liquidity_changed_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	fn register_asset(asset_id: AssetId) -> DispatchResult;
+}
@@ -0,0 +1,283 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_concentrated_liquidity.
+pub trait WeightInfo {
+	fn create_pool() -> Weight;
+	fn mint_position() -> Weight;
+	fn increase_liquidity() -> Weight;
+	fn decrease_liquidity() -> Weight;
+	fn collect_fees() -> Weight;
+	fn burn_position() -> Weight;
+	fn sell(s: u32) -> Weight;
+	fn buy(s: u32) -> Weight;
+	fn calculate_sell(s: u32) -> Weight;
+	fn calculate_buy(s: u32) -> Weight;
+	fn calculate_spot_price_with_fee() -> Weight;
+}
+
+/// Weights for pallet_concentrated_liquidity using the hydraDX node and recommended hardware.
+impl WeightInfo for () {
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::PoolIds` (r:1 w:1)
+	/// Proof: `ConcentratedLiquidity::PoolIds` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::NextPoolId` (r:1 w:1)
+	/// Proof: `ConcentratedLiquidity::NextPoolId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountWhitelist` (r:1 w:1)
+	/// Proof: `Duster::AccountWhitelist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:1)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Pools` (r:0 w:1)
+	/// Proof: `ConcentratedLiquidity::Pools` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn create_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1102`
+		//  Estimated: `6190`
+		// Minimum execution time: 48_910_000 picoseconds.
+		Weight::from_parts(49_644_000, 6190)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Proof: `ConcentratedLiquidity::Pools` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::NextPositionId` (r:1 w:1)
+	/// Proof: `ConcentratedLiquidity::NextPositionId` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:1 w:1)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:1)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:0 w:1)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Ticks` (r:4 w:2)
+	/// Proof: `ConcentratedLiquidity::Ticks` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:2 w:2)
+	/// Proof: `ConcentratedLiquidity::TickBitmap` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Positions` (r:0 w:1)
+	/// Proof: `ConcentratedLiquidity::Positions` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	fn mint_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2214`
+		//  Estimated: `11666`
+		// Minimum execution time: 121_356_000 picoseconds.
+		Weight::from_parts(123_017_000, 11666)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::Positions` (r:1 w:1)
+	/// Proof: `ConcentratedLiquidity::Positions` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Proof: `ConcentratedLiquidity::Pools` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Ticks` (r:4 w:2)
+	/// Proof: `ConcentratedLiquidity::Ticks` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn increase_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2487`
+		//  Estimated: `11666`
+		// Minimum execution time: 94_502_000 picoseconds.
+		Weight::from_parts(95_871_000, 11666)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::Positions` (r:1 w:1)
+	/// Proof: `ConcentratedLiquidity::Positions` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Proof: `ConcentratedLiquidity::Pools` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Ticks` (r:4 w:2)
+	/// Proof: `ConcentratedLiquidity::Ticks` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:2 w:2)
+	/// Proof: `ConcentratedLiquidity::TickBitmap` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn decrease_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2531`
+		//  Estimated: `11666`
+		// Minimum execution time: 101_784_000 picoseconds.
+		Weight::from_parts(103_226_000, 11666)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::Positions` (r:1 w:1)
+	/// Proof: `ConcentratedLiquidity::Positions` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:0)
+	/// Proof: `ConcentratedLiquidity::Pools` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Ticks` (r:2 w:0)
+	/// Proof: `ConcentratedLiquidity::Ticks` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn collect_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2396`
+		//  Estimated: `11666`
+		// Minimum execution time: 78_233_000 picoseconds.
+		Weight::from_parts(79_408_000, 11666)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::Positions` (r:1 w:1)
+	/// Proof: `ConcentratedLiquidity::Positions` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Asset` (r:1 w:1)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:0)
+	/// Proof: `ConcentratedLiquidity::Pools` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:1)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:0 w:1)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn burn_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2352`
+		//  Estimated: `11666`
+		// Minimum execution time: 86_519_000 picoseconds.
+		Weight::from_parts(87_903_000, 11666)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `ConcentratedLiquidity::PoolIds` (r:1 w:0)
+	/// Proof: `ConcentratedLiquidity::PoolIds` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Proof: `ConcentratedLiquidity::Pools` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:64 w:0)
+	/// Proof: `ConcentratedLiquidity::TickBitmap` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Ticks` (r:64 w:64)
+	/// Proof: `ConcentratedLiquidity::Ticks` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Broadcast::IncrementalId` (r:1 w:1)
+	/// Proof: `Broadcast::IncrementalId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Broadcast::ExecutionContext` (r:1 w:0)
+	/// Proof: `Broadcast::ExecutionContext` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 64]`.
+	fn sell(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2738 + s * (312 ±0)`
+		//  Estimated: `11666 + s * (5124 ±0)`
+		// Minimum execution time: 237_612_000 picoseconds.
+		Weight::from_parts(159_371_000, 11666)
+			// Standard Error: 41_207
+			.saturating_add(Weight::from_parts(26_740_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5124).saturating_mul(s.into()))
+	}
+	/// Storage: `ConcentratedLiquidity::PoolIds` (r:1 w:0)
+	/// Proof: `ConcentratedLiquidity::PoolIds` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:1)
+	/// Proof: `ConcentratedLiquidity::Pools` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:64 w:0)
+	/// Proof: `ConcentratedLiquidity::TickBitmap` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Ticks` (r:64 w:64)
+	/// Proof: `ConcentratedLiquidity::Ticks` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Broadcast::IncrementalId` (r:1 w:1)
+	/// Proof: `Broadcast::IncrementalId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Broadcast::ExecutionContext` (r:1 w:0)
+	/// Proof: `Broadcast::ExecutionContext` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 64]`.
+	fn buy(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2738 + s * (312 ±0)`
+		//  Estimated: `11666 + s * (5124 ±0)`
+		// Minimum execution time: 241_384_000 picoseconds.
+		Weight::from_parts(162_904_000, 11666)
+			// Standard Error: 41_207
+			.saturating_add(Weight::from_parts(26_912_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5124).saturating_mul(s.into()))
+	}
+	/// Storage: `ConcentratedLiquidity::PoolIds` (r:1 w:0)
+	/// Proof: `ConcentratedLiquidity::PoolIds` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:0)
+	/// Proof: `ConcentratedLiquidity::Pools` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:64 w:0)
+	/// Proof: `ConcentratedLiquidity::TickBitmap` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Ticks` (r:64 w:0)
+	/// Proof: `ConcentratedLiquidity::Ticks` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 64]`.
+	fn calculate_sell(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1213 + s * (312 ±0)`
+		//  Estimated: `3563 + s * (5124 ±0)`
+		// Minimum execution time: 98_127_000 picoseconds.
+		Weight::from_parts(21_508_000, 3563)
+			// Standard Error: 18_530
+			.saturating_add(Weight::from_parts(26_113_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5124).saturating_mul(s.into()))
+	}
+	/// Storage: `ConcentratedLiquidity::PoolIds` (r:1 w:0)
+	/// Proof: `ConcentratedLiquidity::PoolIds` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:0)
+	/// Proof: `ConcentratedLiquidity::Pools` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::TickBitmap` (r:64 w:0)
+	/// Proof: `ConcentratedLiquidity::TickBitmap` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Ticks` (r:64 w:0)
+	/// Proof: `ConcentratedLiquidity::Ticks` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[3, 64]`.
+	fn calculate_buy(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1213 + s * (312 ±0)`
+		//  Estimated: `3563 + s * (5124 ±0)`
+		// Minimum execution time: 101_662_000 picoseconds.
+		Weight::from_parts(24_276_000, 3563)
+			// Standard Error: 18_530
+			.saturating_add(Weight::from_parts(26_398_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5124).saturating_mul(s.into()))
+	}
+	/// Storage: `ConcentratedLiquidity::PoolIds` (r:1 w:0)
+	/// Proof: `ConcentratedLiquidity::PoolIds` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ConcentratedLiquidity::Pools` (r:1 w:0)
+	/// Proof: `ConcentratedLiquidity::Pools` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn calculate_spot_price_with_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `582`
+		//  Estimated: `3563`
+		// Minimum execution time: 17_244_000 picoseconds.
+		Weight::from_parts(17_630_000, 3563)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+}
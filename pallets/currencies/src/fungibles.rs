@@ -330,7 +330,7 @@ where
 
 		if result.is_ok() {
 			<T::EgressHandler as AssetWithdrawHandler<T::AccountId, CurrencyIdOf<T>, BalanceOf<T>>>::OnWithdraw::handle(
-				&(asset, amount)
+				&(asset, amount, who.clone())
 			)?;
 		}
 
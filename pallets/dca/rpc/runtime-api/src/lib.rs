@@ -0,0 +1,89 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the DCA pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+pub type ScheduleId = u32;
+
+/// Reason why a schedule is, or is not, planned for execution.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ScheduleStatus {
+	/// The schedule is executed in the next execution block.
+	Planned,
+	/// The price trigger of the schedule is not met, it is checked again in the next execution block.
+	WaitingForTrigger,
+	/// The schedule is paused by its owner and it is not planned for execution.
+	Paused,
+}
+
+/// Estimated amounts of the next execution of a schedule, based on the current state of the pools.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ExecutionForecast<Balance> {
+	/// Transaction fee charged in `asset_in`.
+	pub fee: Balance,
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+}
+
+/// DCA schedule as seen by its owner.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ScheduleInfo<AccountId, AssetId, BlockNumber, Balance> {
+	pub schedule_id: ScheduleId,
+	pub owner: AccountId,
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	pub period: BlockNumber,
+	/// Total budget of the schedule, zero for rolling schedules.
+	pub total_amount: Balance,
+	/// Budget still reserved for the schedule.
+	pub remaining_amount: Balance,
+	/// Number of retries consumed since the last successful execution.
+	pub retries: u8,
+	/// Number of retries after which the schedule is terminated.
+	pub max_retries: u8,
+	/// Block in which the schedule is planned, `None` if the schedule is paused.
+	pub next_execution_block: Option<BlockNumber>,
+	pub status: ScheduleStatus,
+	/// Estimated next execution, `None` if the trade can't be calculated, e.g. because of missing liquidity.
+	pub forecast: Option<ExecutionForecast<Balance>>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query DCA schedules.
+	pub trait DcaApi<AccountId, AssetId, BlockNumber, Balance> where
+		AccountId: Codec,
+		AssetId: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+	{
+		/// Return the schedules of the given owner, ordered by schedule ID.
+		fn schedules_of(owner: AccountId) -> Vec<ScheduleInfo<AccountId, AssetId, BlockNumber, Balance>>;
+
+		/// Return the schedule with the given ID, if it exists.
+		fn schedule(schedule_id: ScheduleId) -> Option<ScheduleInfo<AccountId, AssetId, BlockNumber, Balance>>;
+	}
+}
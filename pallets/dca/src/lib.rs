@@ -56,6 +56,29 @@
 //!
 //! If a trade fails due to other types of errors, the order is terminated without any retry logic.
 //!
+//! ### Triggered Schedules
+//!
+//! A schedule can have a price trigger, in which case it is a one-shot order executed only once, when the trigger is met:
+//!
+//! - Limit order: executed when the spot price of the route reaches the limit price.
+//! - Stop-loss and take-profit orders: executed when the oracle price crosses the trigger price.
+//!
+//! The trigger is checked every `period` blocks. Each check that is not met is charged a fee from the budget covering
+//! the weight of the check, but no retry is consumed. The budget of the trade is never spent on the checks, the schedule
+//! is terminated with `TriggerCheckBudgetExhausted` once its remaining budget can't cover the fee together with the
+//! trade. Stop-loss and take-profit triggers use the `TriggerOraclePeriod` oracle price.
+//! Once the trigger is met, the schedule is executed with the same fee, price stability and retry logic as other schedules.
+//!
+//! ## Pausing, Resuming and Updating a Schedule
+//!
+//! Users can pause their schedules, in which case the schedule is not planned for execution until it is resumed.
+//! The reserved budget stays reserved while the schedule is paused.
+//!
+//! The period, slippage, price stability threshold and route of a schedule can be updated in place,
+//! and the budget of a schedule can be topped up, without losing the schedule id.
+//! A new route is validated the same way as the route of a new schedule, and the remaining budget must cover
+//! at least one execution with it.
+//!
 //! ## Terminating a Schedule
 //!
 //! Both users and TerminateOrigin can terminate a DCA schedule. However, users can only terminate schedules that they own themselves
@@ -94,7 +117,10 @@ use sp_std::vec::Vec;
 
 use hydradx_adapters::RelayChainBlockHashProvider;
 use hydradx_traits::fee::{InspectTransactionFeeCurrency, SwappablePaymentAssetTrader};
-use hydradx_traits::router::{inverse_route, AmmTradeWeights, AmountInAndOut, RouteProvider, RouterT, Trade};
+use hydradx_traits::router::{
+	inverse_route, is_valid_split_route, split_amount, AmmTradeWeights, AmountInAndOut, RouteProvider,
+	RouteSpotPriceProvider, RouterT, Trade, WeightedRoute,
+};
 use hydradx_traits::{NativePriceOracle, OraclePeriod, PriceOracle};
 use pallet_broadcast::types::ExecutionType;
 use pallet_evm::GasWeightMapping;
@@ -130,7 +156,7 @@ pub mod pallet {
 
 	use super::*;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -159,6 +185,7 @@ pub mod pallet {
 
 				let weight_for_single_execution = Self::get_trade_weight(&schedule.order, Some(schedule_id));
 				weight.saturating_accrue(weight_for_single_execution);
+				weight.saturating_accrue(Self::get_trigger_weight(&schedule));
 
 				if let Err(e) = Self::prepare_schedule(
 					current_blocknumber,
@@ -169,6 +196,7 @@ pub mod pallet {
 				) {
 					if e == Error::<T>::PriceUnstable.into()
 						|| e == Error::<T>::Bumped.into()
+						|| e == Error::<T>::TriggerNotMet.into()
 						|| e == T::ExtraGasSupport::out_of_gas_error()
 					{
 						continue;
@@ -253,6 +281,10 @@ pub mod pallet {
 		///Oracle price provider to get the price between two assets
 		type OraclePriceProvider: PriceOracle<Self::AssetId, Price = EmaPrice>;
 
+		///Oracle period used to check the stop-loss and take-profit triggers
+		#[pallet::constant]
+		type TriggerOraclePeriod: Get<OraclePeriod>;
+
 		///Native price provider to get the price of assets that are accepted as fees
 		type NativePriceOracle: NativePriceOracle<Self::AssetId, EmaPrice>;
 
@@ -268,6 +300,9 @@ pub mod pallet {
 		///Spot price provider to get the current price between two asset
 		type RouteProvider: RouteProvider<Self::AssetId>;
 
+		///Spot price provider to check the price triggers of limit orders
+		type SpotPriceProvider: RouteSpotPriceProvider<Self::AssetId>;
+
 		///Errors we want to explicitly retry on, in case of failing DCA
 		type RetryOnError: Contains<DispatchError>;
 
@@ -380,6 +415,18 @@ pub mod pallet {
 		},
 		///DCA reserve for the given asset have been unlocked for a user
 		ReserveUnlocked { who: T::AccountId, asset_id: T::AssetId },
+		///The DCA is paused and not planned for execution until resumed
+		Paused { id: ScheduleId, who: T::AccountId },
+		///The DCA is resumed and planned for execution
+		Resumed { id: ScheduleId, who: T::AccountId },
+		///The DCA schedule has been updated
+		ScheduleUpdated {
+			id: ScheduleId,
+			who: T::AccountId,
+			period: BlockNumberFor<T>,
+			total_amount: Balance,
+			order: Order<T::AssetId>,
+		},
 	}
 
 	#[pallet::error]
@@ -424,6 +471,24 @@ pub mod pallet {
 		HasActiveSchedules,
 		///No reserves are locked for the user for the given asset
 		NoReservesLocked,
+		///The split route is empty or the weights of the routes are not summing up to 100%
+		InvalidSplitRoute,
+		///The trigger price cannot be zero
+		InvalidTrigger,
+		///Schedules with a trigger must have a non-zero total amount
+		TriggerNotAllowedForRollingSchedule,
+		///The price trigger of the schedule is not met yet, leading to replanning
+		TriggerNotMet,
+		///The schedule is already paused
+		ScheduleAlreadyPaused,
+		///The schedule is not paused
+		ScheduleNotPaused,
+		///The route does not connect the assets of the order, or it does not match the order type
+		InvalidRoute,
+		///The budget of a rolling schedule cannot be topped up
+		TopUpNotAllowedForRollingSchedule,
+		///The remaining budget cannot cover another trigger check together with the trade of the schedule
+		TriggerCheckBudgetExhausted,
 	}
 
 	/// Id sequencer for schedules
@@ -472,6 +537,11 @@ pub mod pallet {
 	#[pallet::getter(fn schedule_extra_gas)]
 	pub type ScheduleExtraGas<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, u64, ValueQuery>;
 
+	/// Keep tracking of the paused schedules, which are not planned for execution until resumed
+	#[pallet::storage]
+	#[pallet::getter(fn paused_schedules)]
+	pub type PausedSchedules<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, (), OptionQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates a new DCA (Dollar-Cost Averaging) schedule and plans the next execution
@@ -492,6 +562,9 @@ pub mod pallet {
 		/// the schedule will be terminated permanently.
 		/// In the case of a successful trade, the retry counter is reset.
 		///
+		/// If the schedule has a `trigger`, the order is executed only once, when the trigger is met.
+		/// The trigger is checked every `period` blocks, starting from the first execution block.
+		///
 		/// Parameters:
 		/// - `origin`: schedule owner
 		/// - `schedule`: schedule details
@@ -501,7 +574,7 @@ pub mod pallet {
 		///
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::schedule()
-			+ <T as Config>::AmmTradeWeights::calculate_buy_trade_amounts_weight(&schedule.order.get_route_or_default::<T::RouteProvider>()))]
+			+ Pallet::<T>::routes_weight(&schedule.order.get_routes_or_default::<T::RouteProvider>(), <T as Config>::AmmTradeWeights::calculate_buy_trade_amounts_weight))]
 		#[transactional]
 		pub fn schedule(
 			origin: OriginFor<T>,
@@ -526,25 +599,16 @@ pub mod pallet {
 				},
 				Error::<T>::StabilityThresholdTooHigh
 			);
+			if let Order::SplitSell { routes, .. } | Order::SplitBuy { routes, .. } = &schedule.order {
+				ensure!(is_valid_split_route(routes), Error::<T>::InvalidSplitRoute);
+			}
+			if let Some(trigger) = schedule.trigger {
+				ensure!(!trigger.price().is_zero(), Error::<T>::InvalidTrigger);
+				ensure!(!schedule.is_rolling(), Error::<T>::TriggerNotAllowedForRollingSchedule);
+			}
 
 			let transaction_fee = Self::get_transaction_fee(&schedule.order, None)?;
-
-			let amount_in = match schedule.order {
-				Order::Sell { amount_in, .. } => amount_in,
-				Order::Buy { amount_out, .. } => {
-					let route = schedule.order.get_route_or_default::<T::RouteProvider>();
-					Self::get_amount_in_for_buy(&amount_out, &route)?
-				}
-			};
-			let min_trade_amount_in_from_fee = transaction_fee.saturating_mul(FEE_MULTIPLIER_FOR_MIN_TRADE_LIMIT);
-			ensure!(
-				amount_in >= min_trade_amount_in_from_fee,
-				Error::<T>::MinTradeAmountNotReached
-			);
-			ensure!(
-				amount_in >= T::MinimumTradingLimit::get(),
-				Error::<T>::MinTradeAmountNotReached
-			);
+			let amount_in = Self::get_amount_in_of_trade(&schedule.order, transaction_fee)?;
 
 			let amount_in_with_transaction_fee = amount_in.saturating_add(transaction_fee).saturating_mul(2);
 			let reserve_amount = if schedule.is_rolling() {
@@ -636,28 +700,14 @@ pub mod pallet {
 
 			Self::try_unreserve_all(schedule_id, &schedule);
 
-			let next_execution_block = next_execution_block
-				.or(Self::schedule_execution_block(schedule_id))
-				.ok_or(Error::<T>::ScheduleNotFound)?;
-
-			//Remove schedule id from next execution block
-			ScheduleIdsPerBlock::<T>::try_mutate_exists(
-				next_execution_block,
-				|maybe_schedule_ids| -> DispatchResult {
-					let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
-
-					let idx = schedule_ids
-						.iter()
-						.position(|x| *x == schedule_id)
-						.ok_or(Error::<T>::ScheduleNotFound)?;
-					schedule_ids.remove(idx);
+			//Paused schedules are not planned in any block
+			if !PausedSchedules::<T>::contains_key(schedule_id) {
+				let next_execution_block = next_execution_block
+					.or(Self::schedule_execution_block(schedule_id))
+					.ok_or(Error::<T>::ScheduleNotFound)?;
 
-					if schedule_ids.is_empty() {
-						*maybe_schedule_ids = None;
-					}
-					Ok(())
-				},
-			)?;
+				Self::remove_schedule_from_execution_block(schedule_id, next_execution_block)?;
+			}
 
 			Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
 
@@ -710,6 +760,229 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Pauses a DCA schedule, so it is not executed until it is resumed.
+		///
+		/// The schedule is removed from its planned execution block, but it is kept on chain
+		/// together with its reserved budget and retry counter.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		///
+		/// Emits `Paused` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::pause())]
+		#[transactional]
+		pub fn pause(origin: OriginFor<T>, schedule_id: ScheduleId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			ensure!(
+				!PausedSchedules::<T>::contains_key(schedule_id),
+				Error::<T>::ScheduleAlreadyPaused
+			);
+
+			let next_execution_block =
+				ScheduleExecutionBlock::<T>::take(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			Self::remove_schedule_from_execution_block(schedule_id, next_execution_block)?;
+
+			PausedSchedules::<T>::insert(schedule_id, ());
+
+			Self::deposit_event(Event::Paused { id: schedule_id, who });
+
+			Ok(())
+		}
+
+		/// Resumes a paused DCA schedule and plans the next execution for the specified block.
+		///
+		/// If the block is not specified, the execution is planned for the next block.
+		/// If the given block is full, the execution will be planned in the subsequent block.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `start_execution_block`: first possible execution block for the resumed schedule
+		///
+		/// Emits `Resumed` and `ExecutionPlanned` events when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::resume())]
+		#[transactional]
+		pub fn resume(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			start_execution_block: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			ensure!(
+				PausedSchedules::<T>::take(schedule_id).is_some(),
+				Error::<T>::ScheduleNotPaused
+			);
+
+			let next_execution_block = Self::get_first_execution_block(start_execution_block)?;
+
+			let mut randomness_generator =
+				Self::get_randomness_generator(frame_system::Pallet::<T>::current_block_number(), Some(schedule_id));
+			Self::plan_schedule_for_block(&who, next_execution_block, schedule_id, &mut randomness_generator)?;
+
+			Self::deposit_event(Event::Resumed { id: schedule_id, who });
+
+			Ok(())
+		}
+
+		/// Updates a DCA schedule in place, keeping its id, planned execution and reserved budget.
+		///
+		/// Only the fields set in `update` are changed:
+		/// - `period`, `slippage` and `stability_threshold` are validated the same way as in `schedule`.
+		/// - `budget_top_up` is added to `total_amount` and reserved. It is not allowed for rolling schedules.
+		/// - `route` replaces the route of a `Sell` or `Buy` order, `routes` replaces the routes of a split order.
+		///   The oracle price must be available for the new routes, the trade amounts are validated the same way
+		///   as in `schedule` and the remaining budget must cover at least one execution with the new routes.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `update`: the changes of the schedule
+		///
+		/// Emits `ScheduleUpdated` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_schedule() + Pallet::<T>::update_routes_weight(update))]
+		#[transactional]
+		pub fn update_schedule(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			update: ScheduleUpdate<T::AssetId, BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let schedule = Schedules::<T>::try_mutate(
+				schedule_id,
+				|maybe_schedule| -> Result<Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>, DispatchError> {
+					let schedule = maybe_schedule.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+					ensure!(who == schedule.owner, Error::<T>::Forbidden);
+
+					if let Some(period) = update.period {
+						ensure!(
+							period >= BlockNumberFor::<T>::from(T::MinimalPeriod::get()),
+							Error::<T>::PeriodTooShort
+						);
+						schedule.period = period;
+					}
+
+					if let Some(stability_threshold) = update.stability_threshold {
+						ensure!(
+							stability_threshold.is_none_or(
+								|threshold| threshold <= T::MaxConfigurablePriceDifferenceBetweenBlocks::get()
+							),
+							Error::<T>::StabilityThresholdTooHigh
+						);
+						schedule.stability_threshold = stability_threshold;
+					}
+
+					if let Some(slippage) = update.slippage {
+						schedule.slippage = slippage;
+					}
+
+					if let Some(new_route) = update.route {
+						let (route, asset_in, asset_out) = match &mut schedule.order {
+							Order::Sell {
+								asset_in,
+								asset_out,
+								route,
+								..
+							}
+							| Order::Buy {
+								asset_in,
+								asset_out,
+								route,
+								..
+							} => (route, *asset_in, *asset_out),
+							_ => return Err(Error::<T>::InvalidRoute.into()),
+						};
+						ensure!(
+							Self::is_route_connecting(&new_route, asset_in, asset_out),
+							Error::<T>::InvalidRoute
+						);
+						*route = new_route;
+					}
+
+					if let Some(new_routes) = update.routes {
+						let (routes, asset_in, asset_out) = match &mut schedule.order {
+							Order::SplitSell {
+								asset_in,
+								asset_out,
+								routes,
+								..
+							}
+							| Order::SplitBuy {
+								asset_in,
+								asset_out,
+								routes,
+								..
+							} => (routes, *asset_in, *asset_out),
+							_ => return Err(Error::<T>::InvalidRoute.into()),
+						};
+						ensure!(is_valid_split_route(&new_routes), Error::<T>::InvalidSplitRoute);
+						ensure!(
+							new_routes.iter().all(|weighted_route| Self::is_route_connecting(
+								&weighted_route.route,
+								asset_in,
+								asset_out
+							)),
+							Error::<T>::InvalidRoute
+						);
+						*routes = new_routes;
+					}
+
+					if let Some(budget_top_up) = update.budget_top_up {
+						ensure!(!schedule.is_rolling(), Error::<T>::TopUpNotAllowedForRollingSchedule);
+
+						schedule.total_amount = schedule
+							.total_amount
+							.checked_add(budget_top_up)
+							.ok_or(ArithmeticError::Overflow)?;
+
+						RemainingAmounts::<T>::try_mutate(schedule_id, |maybe_remaining_amount| -> DispatchResult {
+							let remaining_amount = maybe_remaining_amount
+								.as_mut()
+								.defensive_ok_or(Error::<T>::InvalidState)?;
+							*remaining_amount = remaining_amount
+								.checked_add(budget_top_up)
+								.ok_or(ArithmeticError::Overflow)?;
+							Ok(())
+						})?;
+
+						T::Currencies::reserve_named(
+							&T::NamedReserveId::get(),
+							schedule.order.get_asset_in(),
+							&who,
+							budget_top_up,
+						)?;
+					}
+
+					Ok(schedule.clone())
+				},
+			)?;
+
+			if update.route.is_some() || update.routes.is_some() {
+				Self::validate_updated_routes(schedule_id, &schedule)?;
+			}
+
+			Self::deposit_event(Event::ScheduleUpdated {
+				id: schedule_id,
+				who,
+				period: schedule.period,
+				total_amount: schedule.total_amount,
+				order: schedule.order,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -728,6 +1001,37 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Returns true if the route is empty (so the default route is used), or if it goes from `asset_in` to `asset_out`.
+	fn is_route_connecting(route: &[Trade<T::AssetId>], asset_in: T::AssetId, asset_out: T::AssetId) -> bool {
+		let (Some(first_trade), Some(last_trade)) = (route.first(), route.last()) else {
+			return true;
+		};
+
+		first_trade.asset_in == asset_in
+			&& last_trade.asset_out == asset_out
+			&& route.windows(2).all(|trades| trades[0].asset_out == trades[1].asset_in)
+	}
+
+	fn remove_schedule_from_execution_block(
+		schedule_id: ScheduleId,
+		execution_block: BlockNumberFor<T>,
+	) -> DispatchResult {
+		ScheduleIdsPerBlock::<T>::try_mutate_exists(execution_block, |maybe_schedule_ids| -> DispatchResult {
+			let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+
+			let idx = schedule_ids
+				.iter()
+				.position(|x| *x == schedule_id)
+				.ok_or(Error::<T>::ScheduleNotFound)?;
+			schedule_ids.remove(idx);
+
+			if schedule_ids.is_empty() {
+				*maybe_schedule_ids = None;
+			}
+			Ok(())
+		})
+	}
+
 	fn get_first_execution_block(
 		start_execution_block: Option<BlockNumberFor<T>>,
 	) -> Result<BlockNumberFor<T>, DispatchError> {
@@ -764,6 +1068,19 @@ impl<T: Config> Pallet<T> {
 			return Err(Error::<T>::Bumped.into());
 		}
 
+		if !Self::is_trigger_met(schedule) {
+			// Checking the trigger is paid from the budget, so a schedule can't occupy block slots forever.
+			// The schedule is terminated once the budget can't cover the fee together with the trade.
+			Self::take_trigger_check_fee_from_user(schedule_id, schedule)?;
+
+			let next_check_block = current_blocknumber
+				.checked_add(&schedule.period)
+				.ok_or(ArithmeticError::Overflow)?;
+			Self::plan_schedule_for_block(&schedule.owner, next_check_block, schedule_id, randomness_generator)?;
+
+			return Err(Error::<T>::TriggerNotMet.into());
+		}
+
 		if let Err(e) = Self::take_transaction_fee_from_user(schedule_id, schedule, weight_for_dca_execution) {
 			if e == T::ExtraGasSupport::out_of_gas_error() {
 				Self::increment_extra_gas(schedule_id, schedule);
@@ -811,28 +1128,35 @@ impl<T: Config> Pallet<T> {
 				amount_in,
 				min_amount_out,
 				..
+			}
+			| Order::SplitSell {
+				asset_in,
+				asset_out,
+				amount_in,
+				min_amount_out,
+				..
 			} => {
-				let route = &schedule.order.get_route_or_default::<T::RouteProvider>();
+				let routes = schedule.order.get_routes_or_default::<T::RouteProvider>();
 				let remaining_amount =
 					RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
 				let amount_to_sell = min(remaining_amount, *amount_in);
 
 				Self::unallocate_amount(schedule_id, schedule, amount_to_sell)?;
 
-				let route_for_slippage = inverse_route(route.clone());
-				let (estimated_amount_out, slippage_amount) =
-					Self::calculate_last_block_slippage(&route_for_slippage, amount_to_sell, schedule.slippage)?;
+				let (amount_out, estimated_amount_out, slippage_amount) =
+					Self::calculate_sell_amounts(&routes, amount_to_sell, schedule.slippage)?;
 				let last_block_slippage_min_limit = estimated_amount_out
 					.checked_sub(slippage_amount)
 					.ok_or(ArithmeticError::Overflow)?;
 
-				let trade_amounts = T::RouteExecutor::calculate_sell_trade_amounts(route, amount_to_sell)?;
-				let last_trade = trade_amounts.last().defensive_ok_or(Error::<T>::InvalidState)?;
-				let amount_out = last_trade.amount_out;
-
 				let effective_min_limit = if *min_amount_out > last_block_slippage_min_limit {
 					ensure!(amount_out >= *min_amount_out, Error::<T>::TradeLimitReached);
-					amount_out
+					// The routes of a split order can share pools, so the calculated amount out is only an estimate
+					if schedule.order.is_split() {
+						*min_amount_out
+					} else {
+						amount_out
+					}
 				} else {
 					ensure!(
 						amount_out >= last_block_slippage_min_limit,
@@ -842,14 +1166,28 @@ impl<T: Config> Pallet<T> {
 					last_block_slippage_min_limit
 				};
 
-				T::RouteExecutor::sell(
-					origin,
-					*asset_in,
-					*asset_out,
-					amount_to_sell,
-					effective_min_limit,
-					route.clone(),
-				)?;
+				let amount_out = if schedule.order.is_split() {
+					T::RouteExecutor::split_sell(
+						origin,
+						*asset_in,
+						*asset_out,
+						amount_to_sell,
+						effective_min_limit,
+						routes,
+					)?
+					.amount_out
+				} else {
+					let route = routes.first().defensive_ok_or(Error::<T>::InvalidState)?.route.clone();
+					T::RouteExecutor::sell(
+						origin,
+						*asset_in,
+						*asset_out,
+						amount_to_sell,
+						effective_min_limit,
+						route,
+					)?;
+					amount_out
+				};
 
 				Ok(AmountInAndOut {
 					amount_in: amount_to_sell,
@@ -862,28 +1200,54 @@ impl<T: Config> Pallet<T> {
 				amount_out,
 				max_amount_in,
 				..
+			}
+			| Order::SplitBuy {
+				asset_in,
+				asset_out,
+				amount_out,
+				max_amount_in,
+				..
 			} => {
-				let route = schedule.order.get_route_or_default::<T::RouteProvider>();
-				let amount_in = Self::get_amount_in_for_buy(amount_out, &route)?;
+				let routes = schedule.order.get_routes_or_default::<T::RouteProvider>();
+				let amount_in = Self::get_amount_in_for_buy(amount_out, &routes)?;
 
 				Self::unallocate_amount(schedule_id, schedule, amount_in)?;
 
 				let (estimated_amount_in, slippage_amount) =
-					Self::calculate_last_block_slippage(&route, *amount_out, schedule.slippage)?;
+					Self::calculate_buy_slippage(&routes, *amount_out, schedule.slippage)?;
 				let last_block_slippage_max_limit = estimated_amount_in
 					.checked_add(slippage_amount)
 					.ok_or(ArithmeticError::Overflow)?;
 
-				if *max_amount_in < last_block_slippage_max_limit {
+				let effective_max_limit = if *max_amount_in < last_block_slippage_max_limit {
 					ensure!(amount_in <= *max_amount_in, Error::<T>::TradeLimitReached);
+					*max_amount_in
 				} else {
 					ensure!(
 						amount_in <= last_block_slippage_max_limit,
 						Error::<T>::SlippageLimitReached
 					);
+					last_block_slippage_max_limit
 				};
 
-				T::RouteExecutor::buy(origin, *asset_in, *asset_out, *amount_out, amount_in, route)?;
+				let amount_in = if schedule.order.is_split() {
+					// The routes of a split order can share pools, so the calculated amount in is only an estimate
+					let spent_amount_in = T::RouteExecutor::split_buy(
+						origin,
+						*asset_in,
+						*asset_out,
+						*amount_out,
+						effective_max_limit,
+						routes,
+					)?
+					.amount_in;
+					Self::settle_unallocated_amount(schedule_id, schedule, amount_in, spent_amount_in)?;
+					spent_amount_in
+				} else {
+					let route = routes.first().defensive_ok_or(Error::<T>::InvalidState)?.route.clone();
+					T::RouteExecutor::buy(origin, *asset_in, *asset_out, *amount_out, amount_in, route)?;
+					amount_in
+				};
 
 				Ok(AmountInAndOut {
 					amount_in,
@@ -915,6 +1279,11 @@ impl<T: Config> Pallet<T> {
 
 		RetriesOnError::<T>::remove(schedule_id);
 
+		if schedule.is_one_shot() {
+			Self::complete_schedule(schedule_id, schedule);
+			return Ok(());
+		}
+
 		let remaining_amount: Balance =
 			RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
 		let transaction_fee = Self::get_transaction_fee(&schedule.order, Some(schedule_id))?;
@@ -925,10 +1294,10 @@ impl<T: Config> Pallet<T> {
 		}
 
 		//In buy we complete with returning leftover, in sell we sell the leftover in the next trade
-		if let Order::Buy { amount_out, .. } = &schedule.order {
+		if let Order::Buy { amount_out, .. } | Order::SplitBuy { amount_out, .. } = &schedule.order {
 			if !schedule.is_rolling() {
-				let route = schedule.order.get_route_or_default::<T::RouteProvider>();
-				let amount_to_unreserve: Balance = Self::get_amount_in_for_buy(amount_out, &route)?;
+				let routes = schedule.order.get_routes_or_default::<T::RouteProvider>();
+				let amount_to_unreserve: Balance = Self::get_amount_in_for_buy(amount_out, &routes)?;
 
 				let amount_for_next_trade: Balance = amount_to_unreserve
 					.checked_add(transaction_fee)
@@ -984,8 +1353,18 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn is_price_unstable(schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) -> bool {
-		let route = &schedule.order.get_route_or_default::<T::RouteProvider>();
+		let max_allowed_diff = schedule
+			.stability_threshold
+			.unwrap_or_else(T::MaxPriceDifferenceBetweenBlocks::get);
+
+		schedule
+			.order
+			.get_routes_or_default::<T::RouteProvider>()
+			.iter()
+			.any(|weighted_route| Self::is_route_price_unstable(&weighted_route.route, max_allowed_diff))
+	}
 
+	fn is_route_price_unstable(route: &[Trade<T::AssetId>], max_allowed_diff: Permill) -> bool {
 		let Ok(last_block_price) = Self::get_price_from_last_block_oracle(route) else {
 			return true;
 		};
@@ -994,10 +1373,6 @@ impl<T: Config> Pallet<T> {
 			return true;
 		};
 
-		let max_allowed_diff = schedule
-			.stability_threshold
-			.unwrap_or_else(T::MaxPriceDifferenceBetweenBlocks::get);
-
 		let max_allowed = FixedU128::from(max_allowed_diff);
 
 		let Some(price_sum) = last_block_price.checked_add(&price_from_short_oracle) else {
@@ -1021,12 +1396,263 @@ impl<T: Config> Pallet<T> {
 		diff > max_allowed_difference
 	}
 
-	fn get_amount_in_for_buy(amount_out: &Balance, route: &[Trade<T::AssetId>]) -> Result<Balance, DispatchError> {
-		let trade_amounts = T::RouteExecutor::calculate_buy_trade_amounts(route, *amount_out)?;
+	/// Returns true if the schedule has no price trigger, or its price trigger is met.
+	pub fn is_trigger_met(schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) -> bool {
+		let Some(trigger) = schedule.trigger else {
+			return true;
+		};
+
+		let routes = schedule.order.get_routes_or_default::<T::RouteProvider>();
+		let current_price = match trigger {
+			Trigger::LimitPrice(_) => Self::get_trigger_price(&routes, T::SpotPriceProvider::spot_price_with_fee),
+			Trigger::StopLoss(_) | Trigger::TakeProfit(_) => Self::get_trigger_price(&routes, |route| {
+				let price = T::OraclePriceProvider::price(route, T::TriggerOraclePeriod::get())?;
+				FixedU128::checked_from_rational(price.n, price.d)
+			}),
+		};
+
+		// The trigger is not considered to be met when the price is not available
+		current_price.is_some_and(|price| trigger.is_met(price))
+	}
+
+	/// Calculates the price of the sold asset denominated in the bought asset, weighted by the weights of the routes.
+	fn get_trigger_price(
+		routes: &[WeightedRoute<T::AssetId>],
+		route_price: impl Fn(&[Trade<T::AssetId>]) -> Option<FixedU128>,
+	) -> Option<FixedU128> {
+		routes.iter().try_fold(FixedU128::zero(), |price, weighted_route| {
+			// The price of a route is the price of the last asset, so the route is inverted to get the price of the sold asset
+			let price_of_route = route_price(&inverse_route(weighted_route.route.clone()))?;
+			price.checked_add(&price_of_route.checked_mul(&FixedU128::from(weighted_route.weight))?)
+		})
+	}
+
+	fn get_trigger_weight(schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) -> Weight {
+		let Some(trigger) = schedule.trigger else {
+			return Weight::zero();
+		};
+
+		let routes = schedule.order.get_routes_or_default::<T::RouteProvider>();
+		match trigger {
+			Trigger::LimitPrice(_) => {
+				Self::routes_weight(&routes, T::AmmTradeWeights::calculate_spot_price_with_fee_weight)
+			}
+			Trigger::StopLoss(_) | Trigger::TakeProfit(_) => {
+				Self::routes_weight(&routes, |route| T::DbWeight::get().reads(route.len() as u64))
+			}
+		}
+	}
+
+	/// Weight charged for a trigger check that is not met: loading and re-planning the schedule and checking the price.
+	fn get_trigger_check_weight(schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) -> Weight {
+		<T as Config>::WeightInfo::on_initialize_with_empty_block().saturating_add(Self::get_trigger_weight(schedule))
+	}
+
+	/// Charges the fee of a trigger check that is not met from the budget of the schedule.
+	///
+	/// The budget of the trade, including its transaction fee, is kept, so the order can still be executed
+	/// once the trigger is met.
+	fn take_trigger_check_fee_from_user(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let fee_currency = schedule.order.get_asset_in();
+		//Insufficient fee currencies are swapped to pay the fee, so the trade fee of the swap is charged as well
+		let fee_charged_from_budget = |fee: Balance| -> Result<Balance, DispatchError> {
+			if T::SwappablePaymentAssetSupport::is_transaction_fee_currency(fee_currency) {
+				Ok(fee)
+			} else {
+				let pool_trade_fee = T::SwappablePaymentAssetSupport::calculate_fee_amount(fee)?;
+				Ok(fee.checked_add(pool_trade_fee).ok_or(ArithmeticError::Overflow)?)
+			}
+		};
+
+		let check_weight = Self::get_trigger_check_weight(schedule);
+		let check_fee = fee_charged_from_budget(Self::convert_weight_to_fee(check_weight, fee_currency)?)?;
+		let transaction_fee = fee_charged_from_budget(Self::get_transaction_fee(&schedule.order, Some(schedule_id))?)?;
+
+		let amount_in = match &schedule.order {
+			Order::Sell { amount_in, .. } | Order::SplitSell { amount_in, .. } => *amount_in,
+			Order::Buy {
+				amount_out,
+				max_amount_in,
+				..
+			}
+			| Order::SplitBuy {
+				amount_out,
+				max_amount_in,
+				..
+			} => {
+				let routes = schedule.order.get_routes_or_default::<T::RouteProvider>();
+				// The trade fails when it would spend more than the max amount in, which is also kept
+				// when the amount in can't be calculated
+				Self::get_amount_in_for_buy(amount_out, &routes)
+					.map_or(*max_amount_in, |amount_in| amount_in.min(*max_amount_in))
+			}
+		};
+
+		let required_budget = amount_in
+			.checked_add(transaction_fee)
+			.and_then(|amount| amount.checked_add(check_fee))
+			.ok_or(ArithmeticError::Overflow)?;
+		let remaining_amount = RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
+		ensure!(
+			required_budget <= remaining_amount,
+			Error::<T>::TriggerCheckBudgetExhausted
+		);
+
+		Self::take_transaction_fee_from_user(schedule_id, schedule, check_weight)
+	}
+
+	fn get_amount_in_for_buy(
+		amount_out: &Balance,
+		routes: &[WeightedRoute<T::AssetId>],
+	) -> Result<Balance, DispatchError> {
+		let mut amount_in: Balance = 0;
+
+		for (weighted_route, amount_to_buy) in routes.iter().zip(split_amount(*amount_out, routes)) {
+			let trade_amounts = T::RouteExecutor::calculate_buy_trade_amounts(&weighted_route.route, amount_to_buy)?;
+			let first_trade = trade_amounts.last().defensive_ok_or(Error::<T>::InvalidState)?;
+
+			amount_in = amount_in
+				.checked_add(first_trade.amount_in)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		Ok(amount_in)
+	}
+
+	/// Calculates the amount out of a sell, together with the amount out estimated by the last block oracle price
+	/// and the allowed slippage amount.
+	fn calculate_sell_amounts(
+		routes: &[WeightedRoute<T::AssetId>],
+		amount_in: Balance,
+		slippage: Option<Permill>,
+	) -> Result<(Balance, Balance, Balance), DispatchError> {
+		let mut amount_out: Balance = 0;
+		let mut estimated_amount_out: Balance = 0;
+		let mut slippage_amount: Balance = 0;
+
+		for (weighted_route, amount_to_sell) in routes.iter().zip(split_amount(amount_in, routes)) {
+			let route_for_slippage = inverse_route(weighted_route.route.clone());
+			let (route_estimated_amount_out, route_slippage_amount) =
+				Self::calculate_last_block_slippage(&route_for_slippage, amount_to_sell, slippage)?;
+
+			let trade_amounts = T::RouteExecutor::calculate_sell_trade_amounts(&weighted_route.route, amount_to_sell)?;
+			let last_trade = trade_amounts.last().defensive_ok_or(Error::<T>::InvalidState)?;
+
+			amount_out = amount_out
+				.checked_add(last_trade.amount_out)
+				.ok_or(ArithmeticError::Overflow)?;
+			estimated_amount_out = estimated_amount_out
+				.checked_add(route_estimated_amount_out)
+				.ok_or(ArithmeticError::Overflow)?;
+			slippage_amount = slippage_amount
+				.checked_add(route_slippage_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		Ok((amount_out, estimated_amount_out, slippage_amount))
+	}
+
+	/// Calculates the amount in of a buy estimated by the last block oracle price, together with the allowed slippage amount.
+	fn calculate_buy_slippage(
+		routes: &[WeightedRoute<T::AssetId>],
+		amount_out: Balance,
+		slippage: Option<Permill>,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let mut estimated_amount_in: Balance = 0;
+		let mut slippage_amount: Balance = 0;
+
+		for (weighted_route, amount_to_buy) in routes.iter().zip(split_amount(amount_out, routes)) {
+			let (route_estimated_amount_in, route_slippage_amount) =
+				Self::calculate_last_block_slippage(&weighted_route.route, amount_to_buy, slippage)?;
+
+			estimated_amount_in = estimated_amount_in
+				.checked_add(route_estimated_amount_in)
+				.ok_or(ArithmeticError::Overflow)?;
+			slippage_amount = slippage_amount
+				.checked_add(route_slippage_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		Ok((estimated_amount_in, slippage_amount))
+	}
+
+	fn routes_weight(
+		routes: &[WeightedRoute<T::AssetId>],
+		route_weight: impl Fn(&[Trade<T::AssetId>]) -> Weight,
+	) -> Weight {
+		routes.iter().fold(Weight::zero(), |weight, weighted_route| {
+			weight.saturating_add(route_weight(&weighted_route.route))
+		})
+	}
+
+	/// Weight of validating the new routes of `update_schedule`, it grows with the number of trades in the routes.
+	fn update_routes_weight(update: &ScheduleUpdate<T::AssetId, BlockNumberFor<T>>) -> Weight {
+		let route_weight = |route: &[Trade<T::AssetId>]| {
+			T::AmmTradeWeights::calculate_buy_trade_amounts_weight(route)
+				.saturating_add(T::DbWeight::get().reads(route.len() as u64))
+		};
+
+		let single_route_weight = update
+			.route
+			.as_ref()
+			.map_or(Weight::zero(), |route| route_weight(route));
+		let split_routes_weight = update
+			.routes
+			.as_ref()
+			.map_or(Weight::zero(), |routes| Self::routes_weight(routes, route_weight));
+
+		single_route_weight.saturating_add(split_routes_weight)
+	}
 
-		let first_trade = trade_amounts.last().defensive_ok_or(Error::<T>::InvalidState)?;
+	/// Returns the amount sold by one execution of the order, ensuring it reaches the minimum trade amounts.
+	fn get_amount_in_of_trade(order: &Order<T::AssetId>, transaction_fee: Balance) -> Result<Balance, DispatchError> {
+		let amount_in = match order {
+			Order::Sell { amount_in, .. } | Order::SplitSell { amount_in, .. } => *amount_in,
+			Order::Buy { amount_out, .. } | Order::SplitBuy { amount_out, .. } => {
+				let routes = order.get_routes_or_default::<T::RouteProvider>();
+				Self::get_amount_in_for_buy(amount_out, &routes)?
+			}
+		};
+		let min_trade_amount_in_from_fee = transaction_fee.saturating_mul(FEE_MULTIPLIER_FOR_MIN_TRADE_LIMIT);
+		ensure!(
+			amount_in >= min_trade_amount_in_from_fee,
+			Error::<T>::MinTradeAmountNotReached
+		);
+		ensure!(
+			amount_in >= T::MinimumTradingLimit::get(),
+			Error::<T>::MinTradeAmountNotReached
+		);
 
-		Ok(first_trade.amount_in)
+		Ok(amount_in)
+	}
+
+	/// Validates a schedule whose routes were updated against the oracle and its remaining budget.
+	fn validate_updated_routes(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+	) -> DispatchResult {
+		// The price stability of every execution is checked with the oracle price of the routes
+		for weighted_route in schedule.order.get_routes_or_default::<T::RouteProvider>() {
+			Self::get_price_from_short_oracle(&weighted_route.route)?;
+		}
+
+		let transaction_fee = Self::get_transaction_fee(&schedule.order, Some(schedule_id))?;
+		let amount_in = Self::get_amount_in_of_trade(&schedule.order, transaction_fee)?;
+
+		let amount_in_with_transaction_fee = amount_in.saturating_add(transaction_fee);
+		// Rolling schedules keep the budget of two executions reserved
+		let required_budget = if schedule.is_rolling() {
+			amount_in_with_transaction_fee.saturating_mul(2)
+		} else {
+			amount_in_with_transaction_fee
+		};
+		let remaining_amount = RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
+		ensure!(required_budget <= remaining_amount, Error::<T>::BudgetTooLow);
+
+		Ok(())
 	}
 
 	pub fn get_transaction_fee(
@@ -1036,6 +1662,58 @@ impl<T: Config> Pallet<T> {
 		Self::convert_weight_to_fee(Self::get_trade_weight(order, schedule_id), order.get_asset_in())
 	}
 
+	/// Returns the ids of the schedules owned by `owner`, in ascending order.
+	pub fn schedule_ids_of(owner: &T::AccountId) -> Vec<ScheduleId> {
+		let mut schedule_ids: Vec<ScheduleId> = ScheduleOwnership::<T>::iter_key_prefix(owner).collect();
+		schedule_ids.sort();
+		schedule_ids
+	}
+
+	/// Estimates the next execution of a schedule based on the current state of the pools.
+	///
+	/// Returns the transaction fee charged in `asset_in`, together with the amount in and amount out of the trade.
+	/// The fee is taken from the remaining budget before the trade, so the amount sold by the last execution
+	/// of a `Sell` schedule can be lower than the `amount_in` of the order.
+	pub fn forecast_next_execution(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+	) -> Result<(Balance, AmountInAndOut<Balance>), DispatchError> {
+		let fee = Self::get_transaction_fee(&schedule.order, Some(schedule_id))?;
+		let routes = schedule.order.get_routes_or_default::<T::RouteProvider>();
+
+		let amounts = match &schedule.order {
+			Order::Sell { amount_in, .. } | Order::SplitSell { amount_in, .. } => {
+				let amount_to_sell = if schedule.is_rolling() {
+					*amount_in
+				} else {
+					let remaining_amount =
+						RemainingAmounts::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+					min(remaining_amount.saturating_sub(fee), *amount_in)
+				};
+
+				let mut amount_out: Balance = 0;
+				for (weighted_route, amount) in routes.iter().zip(split_amount(amount_to_sell, &routes)) {
+					let trade_amounts = T::RouteExecutor::calculate_sell_trade_amounts(&weighted_route.route, amount)?;
+					let last_trade = trade_amounts.last().defensive_ok_or(Error::<T>::InvalidState)?;
+					amount_out = amount_out
+						.checked_add(last_trade.amount_out)
+						.ok_or(ArithmeticError::Overflow)?;
+				}
+
+				AmountInAndOut {
+					amount_in: amount_to_sell,
+					amount_out,
+				}
+			}
+			Order::Buy { amount_out, .. } | Order::SplitBuy { amount_out, .. } => AmountInAndOut {
+				amount_in: Self::get_amount_in_for_buy(amount_out, &routes)?,
+				amount_out: *amount_out,
+			},
+		};
+
+		Ok((fee, amounts))
+	}
+
 	fn unallocate_amount(
 		schedule_id: ScheduleId,
 		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
@@ -1072,6 +1750,49 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Settles the difference between the unallocated (estimated) amount and the amount actually spent by a trade.
+	fn settle_unallocated_amount(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		unallocated_amount: Balance,
+		spent_amount: Balance,
+	) -> DispatchResult {
+		if spent_amount > unallocated_amount {
+			Self::unallocate_amount(schedule_id, schedule, spent_amount.saturating_sub(unallocated_amount))
+		} else {
+			Self::reallocate_amount(schedule_id, schedule, unallocated_amount.saturating_sub(spent_amount))
+		}
+	}
+
+	fn reallocate_amount(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		amount_to_reserve: Balance,
+	) -> DispatchResult {
+		if schedule.is_rolling() || amount_to_reserve.is_zero() {
+			return Ok(());
+		};
+
+		RemainingAmounts::<T>::try_mutate_exists(schedule_id, |maybe_remaining_amount| -> DispatchResult {
+			let remaining_amount = maybe_remaining_amount
+				.as_mut()
+				.defensive_ok_or(Error::<T>::InvalidState)?;
+
+			*remaining_amount = remaining_amount
+				.checked_add(amount_to_reserve)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(())
+		})?;
+
+		T::Currencies::reserve_named(
+			&T::NamedReserveId::get(),
+			schedule.order.get_asset_in(),
+			&schedule.owner,
+			amount_to_reserve,
+		)
+	}
+
 	#[transactional]
 	fn take_transaction_fee_from_user(
 		schedule_id: ScheduleId,
@@ -1245,9 +1966,9 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn get_trade_weight(order: &Order<T::AssetId>, schedule_id: Option<ScheduleId>) -> Weight {
-		let route = &order.get_route_or_default::<T::RouteProvider>();
+		let routes = order.get_routes_or_default::<T::RouteProvider>();
 		let base_weight = match order {
-			Order::Sell { .. } => {
+			Order::Sell { .. } | Order::SplitSell { .. } => {
 				let on_initialize_weight =
 					if T::SwappablePaymentAssetSupport::is_transaction_fee_currency(order.get_asset_in()) {
 						<T as Config>::WeightInfo::on_initialize_with_sell_trade()
@@ -1255,10 +1976,12 @@ impl<T: Config> Pallet<T> {
 						<T as Config>::WeightInfo::on_initialize_with_sell_trade_with_insufficient_fee_asset()
 					};
 
-				on_initialize_weight
-					.saturating_add(T::AmmTradeWeights::sell_and_calculate_sell_trade_amounts_weight(route))
+				on_initialize_weight.saturating_add(Self::routes_weight(
+					&routes,
+					T::AmmTradeWeights::sell_and_calculate_sell_trade_amounts_weight,
+				))
 			}
-			Order::Buy { .. } => {
+			Order::Buy { .. } | Order::SplitBuy { .. } => {
 				let on_initialize_weight =
 					if T::SwappablePaymentAssetSupport::is_transaction_fee_currency(order.get_asset_in()) {
 						<T as Config>::WeightInfo::on_initialize_with_buy_trade()
@@ -1266,8 +1989,10 @@ impl<T: Config> Pallet<T> {
 						<T as Config>::WeightInfo::on_initialize_with_buy_trade_with_insufficient_fee_asset()
 					};
 
-				on_initialize_weight
-					.saturating_add(T::AmmTradeWeights::buy_and_calculate_buy_trade_amounts_weight(route))
+				on_initialize_weight.saturating_add(Self::routes_weight(
+					&routes,
+					T::AmmTradeWeights::buy_and_calculate_buy_trade_amounts_weight,
+				))
 			}
 		};
 
@@ -1365,6 +2090,7 @@ impl<T: Config> Pallet<T> {
 		RetriesOnError::<T>::remove(schedule_id);
 		ScheduleExecutionBlock::<T>::remove(schedule_id);
 		ScheduleExtraGas::<T>::remove(schedule_id);
+		PausedSchedules::<T>::remove(schedule_id);
 	}
 }
 
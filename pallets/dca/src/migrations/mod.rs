@@ -1,7 +1,9 @@
 use crate::pallet;
-use frame_support::traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+use frame_support::traits::{Get, OnRuntimeUpgrade, StorageVersion};
 use sp_runtime::Saturating;
 
+pub mod v3;
+
 // This migration multiplies the periods of schedules by 2 to account for 2x faster block times
 //
 // The migration does not use a StorageVersion, make sure it is removed from the Runtime Executive
@@ -13,10 +15,9 @@ impl<T: pallet::Config> OnRuntimeUpgrade for MultiplySchedulesPeriodBy2<T> {
 		let mut writes = 0u64;
 
 		let on_chain_version = StorageVersion::get::<crate::Pallet<T>>();
-		let in_code_version = crate::Pallet::<T>::in_code_storage_version();
 		reads.saturating_inc();
 
-		if on_chain_version == in_code_version {
+		if on_chain_version >= StorageVersion::new(2) {
 			// Already migrated
 			return T::DbWeight::get().reads(reads);
 		}
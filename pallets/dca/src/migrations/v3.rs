@@ -0,0 +1,151 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::migrations::VersionedMigration;
+use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+const LOG_TARGET: &str = "runtime::dca";
+
+pub mod v2 {
+	use super::*;
+
+	#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+	pub struct Schedule<AccountId, AssetId, BlockNumber> {
+		pub owner: AccountId,
+		pub period: BlockNumber,
+		pub total_amount: Balance,
+		pub max_retries: Option<u8>,
+		pub stability_threshold: Option<Permill>,
+		pub slippage: Option<Permill>,
+		pub order: Order<AssetId>,
+	}
+}
+
+// Private module to hide migration
+mod unversioned {
+	pub struct InnerMigrateV2ToV3<T: crate::Config>(core::marker::PhantomData<T>);
+}
+
+impl<T: crate::Config> UncheckedOnRuntimeUpgrade for unversioned::InnerMigrateV2ToV3<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		log::info!(target: LOG_TARGET, "v2->v3 migration started");
+
+		let mut schedules: u64 = 0;
+		Schedules::<T>::translate::<v2::Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>, _>(|_, schedule| {
+			schedules.saturating_inc();
+			Some(Schedule {
+				owner: schedule.owner,
+				period: schedule.period,
+				total_amount: schedule.total_amount,
+				max_retries: schedule.max_retries,
+				stability_threshold: schedule.stability_threshold,
+				slippage: schedule.slippage,
+				order: schedule.order,
+				trigger: None,
+			})
+		});
+
+		log::info!(target: LOG_TARGET, "migration finished, migrated {schedules:?} schedules");
+		T::DbWeight::get().reads_writes(schedules, schedules)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == 2,
+			"can only upgrade from version 2"
+		);
+
+		Ok((Schedules::<T>::iter_keys().count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let pre_schedule_count =
+			u32::decode(&mut state.as_slice()).map_err(|_| "failed to decode pre-upgrade schedule count")?;
+
+		let mut schedules = 0u32;
+		for schedule in Schedules::<T>::iter_values() {
+			ensure!(schedule.trigger.is_none(), "migrated schedules must not have a trigger");
+			schedules.saturating_inc();
+		}
+		ensure!(schedules == pre_schedule_count, "schedule count must not change");
+		Ok(())
+	}
+}
+
+pub type MigrateV2ToV3<T> =
+	VersionedMigration<2, 3, unversioned::InnerMigrateV2ToV3<T>, Pallet<T>, <T as frame_system::Config>::DbWeight>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::mock::*;
+	use frame_support::storage::unhashed;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+	use hydradx_traits::router::PoolType;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn migration_should_add_empty_trigger_to_existing_schedules() {
+		ExtBuilder::default().build().execute_with(|| {
+			// Arrange
+			let order = Order::Sell {
+				asset_in: HDX,
+				asset_out: BTC,
+				amount_in: 100 * ONE,
+				min_amount_out: Balance::MIN,
+				route: BoundedVec::truncate_from(vec![Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: BTC,
+				}]),
+			};
+			let old_schedule = v2::Schedule {
+				owner: ALICE,
+				period: ONE_HUNDRED_BLOCKS,
+				total_amount: 1000 * ONE,
+				max_retries: Some(3),
+				stability_threshold: None,
+				slippage: Some(Permill::from_percent(5)),
+				order: order.clone(),
+			};
+			unhashed::put(&Schedules::<Test>::hashed_key_for(0), &old_schedule);
+			StorageVersion::new(2).put::<Pallet<Test>>();
+
+			// Act
+			MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+			// Assert
+			assert_eq!(
+				DCA::schedules(0),
+				Some(Schedule {
+					owner: ALICE,
+					period: ONE_HUNDRED_BLOCKS,
+					total_amount: 1000 * ONE,
+					max_retries: Some(3),
+					stability_threshold: None,
+					slippage: Some(Permill::from_percent(5)),
+					order,
+					trigger: None,
+				})
+			);
+			assert_eq!(StorageVersion::get::<Pallet<Test>>(), 3);
+		});
+	}
+}
@@ -0,0 +1,184 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::tests::terminate::set_block_number;
+use crate::tests::*;
+use crate::RemainingAmounts;
+use frame_support::assert_ok;
+use hydradx_traits::router::AmountInAndOut;
+use pretty_assertions::assert_eq;
+
+fn sell_order(amount_in: Balance) -> Order<AssetId> {
+	Order::Sell {
+		asset_in: HDX,
+		asset_out: BTC,
+		amount_in,
+		min_amount_out: Balance::MIN,
+		route: create_bounded_vec(vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: BTC,
+		}]),
+	}
+}
+
+#[test]
+fn schedule_ids_of_should_return_only_schedules_of_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE), (BOB, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().build(),
+				Option::None
+			));
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(BOB),
+				ScheduleBuilder::new().with_owner(BOB).build(),
+				Option::None
+			));
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().build(),
+				Option::None
+			));
+
+			//Act
+			let schedule_ids = DCA::schedule_ids_of(&ALICE);
+
+			//Assert
+			assert_eq!(schedule_ids, vec![0, 2]);
+		});
+}
+
+#[test]
+fn forecast_should_return_fee_and_calculated_amount_out_for_sell() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let amount_to_sell = 10 * ONE;
+			let schedule = ScheduleBuilder::new().with_order(sell_order(amount_to_sell)).build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+			set_sell_amount_out(3 * ONE);
+
+			//Act
+			let forecast = DCA::forecast_next_execution(0, &schedule).unwrap();
+
+			//Assert
+			assert_eq!(
+				forecast,
+				(
+					DCA::get_transaction_fee(&schedule.order, Some(0)).unwrap(),
+					AmountInAndOut {
+						amount_in: amount_to_sell,
+						amount_out: 3 * ONE,
+					}
+				)
+			);
+		});
+}
+
+#[test]
+fn forecast_should_deduct_fee_from_remaining_amount_when_budget_is_almost_spent() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let amount_to_sell = 10 * ONE;
+			let schedule = ScheduleBuilder::new().with_order(sell_order(amount_to_sell)).build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+			let fee = DCA::get_transaction_fee(&schedule.order, Some(0)).unwrap();
+			RemainingAmounts::<Test>::insert(0, 5 * ONE);
+
+			//Act
+			let (forecast_fee, amounts) = DCA::forecast_next_execution(0, &schedule).unwrap();
+
+			//Assert
+			assert_eq!(forecast_fee, fee);
+			assert_eq!(amounts.amount_in, 5 * ONE - fee);
+		});
+}
+
+#[test]
+fn forecast_should_return_calculated_amount_in_for_buy() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+
+			//Act
+			let (_, amounts) = DCA::forecast_next_execution(0, &schedule).unwrap();
+
+			//Assert
+			assert_eq!(
+				amounts,
+				AmountInAndOut {
+					amount_in: CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+					amount_out: ONE,
+				}
+			);
+		});
+}
+
+#[test]
+fn forecast_should_fail_when_trade_cannot_be_calculated() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().with_order(sell_order(ONE)).build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+			let schedule = ScheduleBuilder::new()
+				.with_order(sell_order(INVALID_CALCULATION_AMOUNT))
+				.build();
+			RemainingAmounts::<Test>::insert(0, 1000 * ONE);
+
+			//Act and assert
+			assert!(DCA::forecast_next_execution(0, &schedule).is_err());
+		});
+}
@@ -109,6 +109,9 @@ thread_local! {
 	pub static WITHDRAWAL_ADJUSTMENT: RefCell<(u32,u32, bool)> = const { RefCell::new((0u32,0u32, false)) };
 	pub static CALCULATED_AMOUNT_OUT_FOR_SELL: RefCell<Balance> = RefCell::new(*AMOUNT_OUT_FOR_OMNIPOOL_SELL);
 	pub static USE_PROD_RANDOMNESS: RefCell<bool> = const { RefCell::new(false) };
+	pub static SPOT_PRICE: RefCell<Option<FixedU128>> = const { RefCell::new(None) };
+	pub static TRIGGER_ORACLE_PRICE: RefCell<Option<Ratio>> = const { RefCell::new(None) };
+	pub static ASSETS_WITHOUT_ORACLE: RefCell<Vec<AssetId>> = const { RefCell::new(vec![]) };
 	pub static PARENT_HASH: RefCell<Option<Hash>> = RefCell::new(Some([
 			14, 87, 81, 192, 38, 229, 67, 178, 232, 171, 46, 176, 96, 153, 218, 161, 209, 229, 223, 71, 119, 143, 119,
 			135, 250, 171, 69, 205, 241, 47, 227, 168,
@@ -378,15 +381,49 @@ impl pallet_route_executor::Config for Test {
 	type ForceInsertOrigin = EnsureRoot<Self::AccountId>;
 	type OraclePriceProvider = PriceProviderMock;
 	type OraclePeriod = RouteValidationOraclePeriod;
+	type PoolsProvider = ();
+	type MaxRouteCandidates = frame_support::traits::ConstU32<10>;
+	type MaxRouteSearchSteps = frame_support::traits::ConstU32<100>;
 	type WeightInfo = ();
 }
 
+pub(crate) type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type RuntimeCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> hydradx_traits::CreateBare<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_bare(call: Self::RuntimeCall) -> Extrinsic {
+		Extrinsic::new_bare(call)
+	}
+}
+
 pub struct PriceProviderMock {}
 
 impl PriceOracle<AssetId> for crate::tests::mock::PriceProviderMock {
 	type Price = Ratio;
 
-	fn price(_: &[Trade<AssetId>], period: OraclePeriod) -> Option<Ratio> {
+	fn price(route: &[Trade<AssetId>], period: OraclePeriod) -> Option<Ratio> {
+		let has_oracle = ASSETS_WITHOUT_ORACLE.with(|v| {
+			let assets = v.borrow();
+			route
+				.iter()
+				.all(|trade| !assets.contains(&trade.asset_in) && !assets.contains(&trade.asset_out))
+		});
+		if !has_oracle {
+			return None;
+		}
+		if period == TriggerOraclePeriod::get() {
+			return TRIGGER_ORACLE_PRICE.with(|v| *v.borrow());
+		}
 		if period == OraclePeriod::Short {
 			return Some(Ratio::new(80, 100));
 		}
@@ -663,6 +700,7 @@ parameter_types! {
 	pub NamedReserveId: NamedReserveIdentifier = *b"dcaorder";
 	pub MaxNumberOfRetriesOnError: u8 = 3;
 	pub ExchangeFeeRate: (u32, u32) = (3, 1000);
+	pub const TriggerOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
 }
 
 pub struct RandomnessProviderMock {}
@@ -690,8 +728,10 @@ impl Config for Test {
 	type WeightToFee = IdentityFee<Balance>;
 	type WeightInfo = ();
 	type OraclePriceProvider = PriceProviderMock;
+	type TriggerOraclePeriod = TriggerOraclePeriod;
 	type RouteExecutor = RouteExecutor;
 	type RouteProvider = DefaultRouteProvider;
+	type SpotPriceProvider = SpotPriceProviderMock;
 	type MaxPriceDifferenceBetweenBlocks = OmnipoolMaxAllowedPriceDifference;
 	type MaxConfigurablePriceDifferenceBetweenBlocks = MaxConfigurablePriceDifference;
 	type MinimalPeriod = MinimalPeriod;
@@ -799,6 +839,32 @@ pub struct DefaultRouteProvider;
 
 impl RouteProvider<AssetId> for DefaultRouteProvider {}
 
+pub fn set_spot_price(price: Option<FixedU128>) {
+	SPOT_PRICE.with(|v| {
+		*v.borrow_mut() = price;
+	});
+}
+
+pub fn set_trigger_oracle_price(price: Option<Ratio>) {
+	TRIGGER_ORACLE_PRICE.with(|v| {
+		*v.borrow_mut() = price;
+	});
+}
+
+pub fn set_assets_without_oracle(assets: Vec<AssetId>) {
+	ASSETS_WITHOUT_ORACLE.with(|v| {
+		*v.borrow_mut() = assets;
+	});
+}
+
+pub struct SpotPriceProviderMock;
+
+impl RouteSpotPriceProvider<AssetId> for SpotPriceProviderMock {
+	fn spot_price_with_fee(_route: &[Trade<AssetId>]) -> Option<FixedU128> {
+		SPOT_PRICE.with(|v| *v.borrow())
+	}
+}
+
 pub struct ParentHashGetterMock {}
 
 impl RelayChainBlockHashProvider for ParentHashGetterMock {
@@ -815,7 +881,7 @@ use hydra_dx_math::to_u128_wrapper;
 use hydra_dx_math::types::Ratio;
 use hydradx_traits::evm::ExtraGasSupport;
 use hydradx_traits::fee::{GetDynamicFee, InspectTransactionFeeCurrency, SwappablePaymentAssetTrader};
-use hydradx_traits::router::{ExecutorError, PoolType, RouteProvider, Trade, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, RouteProvider, RouteSpotPriceProvider, Trade, TradeExecution};
 use pallet_currencies::fungibles::FungibleCurrencies;
 use pallet_omnipool::traits::ExternalPriceProvider;
 use rand::prelude::StdRng;
@@ -990,6 +1056,10 @@ impl ExtBuilder {
 			*v.borrow_mut() = self.min_trading_limit;
 		});
 
+		set_spot_price(None);
+		set_trigger_oracle_price(None);
+		set_assets_without_oracle(vec![]);
+
 		let mut initial_native_accounts: Vec<(AccountId, Balance)> = vec![(ASSET_PAIR_ACCOUNT, 10000 * ONE)];
 		let additional_accounts: Vec<(AccountId, Balance)> = self
 			.endowed_accounts
@@ -1,15 +1,20 @@
 use crate::tests::mock::*;
-use crate::{Balance, Order, Schedule, ScheduleId};
+use crate::{Balance, Order, Schedule, ScheduleId, Trigger};
 use hydradx_traits::router::PoolType;
 use hydradx_traits::router::Trade;
+use hydradx_traits::router::{SplitRoute, WeightedRoute};
 use sp_runtime::traits::ConstU32;
 use sp_runtime::{BoundedVec, Permill};
 
+pub mod forecast;
 pub mod mock;
 pub mod on_initialize;
+pub mod pause_resume;
 pub mod schedule;
 pub mod terminate;
+pub mod trigger;
 pub mod unlock_reserves;
+pub mod update_schedule;
 
 #[macro_export]
 macro_rules! assert_balance {
@@ -26,6 +31,7 @@ pub struct ScheduleBuilder {
 	pub max_retries: Option<Option<u8>>,
 	pub slippage: Option<Option<Permill>>,
 	pub stability_threshold: Option<Option<Permill>>,
+	pub trigger: Option<Option<Trigger>>,
 }
 
 impl ScheduleBuilder {
@@ -37,6 +43,7 @@ impl ScheduleBuilder {
 			slippage: Some(None),
 			total_amount: Some(1000 * ONE),
 			max_retries: Some(None),
+			trigger: Some(None),
 			order: Some(Order::Buy {
 				asset_in: HDX,
 				asset_out: BTC,
@@ -86,6 +93,11 @@ impl ScheduleBuilder {
 		self
 	}
 
+	fn with_trigger(mut self, trigger: Option<Trigger>) -> ScheduleBuilder {
+		self.trigger = Some(trigger);
+		self
+	}
+
 	pub fn build(self) -> Schedule<AccountId, AssetId, BlockNumber> {
 		Schedule {
 			owner: self.owner.unwrap(),
@@ -95,6 +107,7 @@ impl ScheduleBuilder {
 			total_amount: self.total_amount.unwrap(),
 			max_retries: self.max_retries.unwrap(),
 			order: self.order.unwrap(),
+			trigger: self.trigger.unwrap(),
 		}
 	}
 }
@@ -104,6 +117,17 @@ pub fn create_bounded_vec(trades: Vec<Trade<AssetId>>) -> BoundedVec<Trade<Asset
 	bounded_vec
 }
 
+pub fn create_split_route(routes: Vec<(Permill, Vec<Trade<AssetId>>)>) -> SplitRoute<AssetId> {
+	let routes: Vec<WeightedRoute<AssetId>> = routes
+		.into_iter()
+		.map(|(weight, trades)| WeightedRoute {
+			weight,
+			route: create_bounded_vec(trades),
+		})
+		.collect();
+	routes.try_into().unwrap()
+}
+
 pub fn create_bounded_vec_with_schedule_ids(schedule_ids: Vec<ScheduleId>) -> BoundedVec<ScheduleId, ConstU32<9>> {
 	let bounded_vec: BoundedVec<ScheduleId, sp_runtime::traits::ConstU32<9>> = schedule_ids.try_into().unwrap();
 	bounded_vec
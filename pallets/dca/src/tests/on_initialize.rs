@@ -142,6 +142,205 @@ fn successful_buy_dca_execution_should_emit_trade_executed_event() {
 		});
 }
 
+#[test]
+fn split_sell_dca_execution_should_sell_via_all_routes() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let amount_to_sell = ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(Order::SplitSell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					min_amount_out: Balance::MIN,
+					routes: create_split_route(vec![
+						(
+							Permill::from_percent(60),
+							vec![Trade {
+								pool: PoolType::Omnipool,
+								asset_in: HDX,
+								asset_out: BTC,
+							}],
+						),
+						(
+							Permill::from_percent(40),
+							vec![
+								Trade {
+									pool: PoolType::Omnipool,
+									asset_in: HDX,
+									asset_out: DAI,
+								},
+								Trade {
+									pool: PoolType::Omnipool,
+									asset_in: DAI,
+									asset_out: BTC,
+								},
+							],
+						),
+					]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_executed_sell_trades!(vec![
+				SellExecution {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: 6 * ONE / 10,
+					min_buy_amount: 0,
+				},
+				SellExecution {
+					asset_in: HDX,
+					asset_out: DAI,
+					amount_in: 4 * ONE / 10,
+					min_buy_amount: 0,
+				},
+				SellExecution {
+					asset_in: DAI,
+					asset_out: BTC,
+					amount_in: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+					min_buy_amount: 0,
+				}
+			]);
+
+			let schedule_id = 0;
+			expect_events(vec![
+				DcaEvent::TradeExecuted {
+					id: schedule_id,
+					who: ALICE,
+					amount_in: amount_to_sell,
+					amount_out: 2 * *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 602,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn split_buy_dca_execution_should_buy_via_all_routes() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 100 * ONE;
+			let amount_to_buy = 25 * ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_slippage(Some(Permill::from_percent(20)))
+				.with_order(Order::SplitBuy {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: amount_to_buy,
+					max_amount_in: 50 * ONE,
+					routes: create_split_route(vec![
+						(
+							Permill::from_percent(60),
+							vec![Trade {
+								pool: PoolType::Omnipool,
+								asset_in: HDX,
+								asset_out: BTC,
+							}],
+						),
+						(
+							Permill::from_percent(40),
+							vec![
+								Trade {
+									pool: PoolType::Omnipool,
+									asset_in: HDX,
+									asset_out: DAI,
+								},
+								Trade {
+									pool: PoolType::Omnipool,
+									asset_in: DAI,
+									asset_out: BTC,
+								},
+							],
+						),
+					]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_executed_buy_trades!(vec![
+				BuyExecution {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: 15 * ONE,
+					max_sell_amount: CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+				},
+				BuyExecution {
+					asset_in: HDX,
+					asset_out: DAI,
+					amount_out: CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+					max_sell_amount: CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+				},
+				BuyExecution {
+					asset_in: DAI,
+					asset_out: BTC,
+					amount_out: 10 * ONE,
+					max_sell_amount: CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+				}
+			]);
+
+			let schedule_id = 0;
+			expect_events(vec![
+				DcaEvent::TradeExecuted {
+					id: schedule_id,
+					who: ALICE,
+					amount_in: 2 * CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+					amount_out: amount_to_buy,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 602,
+				}
+				.into(),
+			]);
+
+			let buy_fee_in_native = DCA::get_transaction_fee(&schedule.order, None).unwrap();
+			assert_eq!(
+				total_amount - 2 * CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY - buy_fee_in_native,
+				Currencies::reserved_balance(HDX, &ALICE)
+			);
+		});
+}
+
 #[test]
 fn one_sell_dca_execution_should_unreserve_amount_in() {
 	let initial_alice_hdx_balance = 10000 * ONE;
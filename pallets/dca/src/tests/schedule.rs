@@ -18,13 +18,14 @@
 use crate::assert_scheduled_ids;
 use crate::tests::create_bounded_vec_with_schedule_ids;
 use crate::tests::mock::*;
-use crate::tests::{create_bounded_vec, ScheduleBuilder};
+use crate::tests::{create_bounded_vec, create_split_route, ScheduleBuilder};
 use crate::{Error, Event, Order};
 use frame_support::{assert_noop, assert_ok};
 use hydradx_traits::router::{PoolType, Trade};
 use orml_traits::NamedMultiReservableCurrency;
 use pretty_assertions::assert_eq;
 use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::Permill;
 use std::ops::RangeInclusive;
 
 #[test]
@@ -957,3 +958,41 @@ pub fn get_fee_for_buy_in_hdx() -> Balance {
 
 	DCA::get_transaction_fee(&order, None).unwrap()
 }
+
+#[test]
+fn schedule_should_fail_when_split_route_weights_do_not_sum_up_to_one_hundred_percent() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_order(Order::SplitSell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					min_amount_out: Balance::MIN,
+					routes: create_split_route(vec![
+						(
+							Permill::from_percent(50),
+							vec![Trade {
+								pool: PoolType::Omnipool,
+								asset_in: HDX,
+								asset_out: BTC,
+							}],
+						),
+						(Permill::from_percent(30), vec![]),
+					]),
+				})
+				.build();
+
+			set_block_number(500);
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::InvalidSplitRoute
+			);
+		});
+}
@@ -0,0 +1,560 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::on_initialize::{proceed_to_blocknumber, set_to_blocknumber};
+use crate::tests::*;
+use crate::{
+	assert_balance, assert_executed_sell_trades, assert_number_of_executed_buy_trades,
+	assert_number_of_executed_sell_trades, assert_scheduled_ids, assert_that_schedule_has_been_removed_from_storages,
+	Error, Event as DcaEvent, Order, Schedule, Trigger,
+};
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::types::Ratio;
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use pretty_assertions::assert_eq;
+use sp_runtime::{FixedPointNumber, FixedU128};
+use std::borrow::Borrow;
+
+fn sell_order(amount_in: Balance) -> Order<AssetId> {
+	Order::Sell {
+		asset_in: HDX,
+		asset_out: BTC,
+		amount_in,
+		min_amount_out: Balance::MIN,
+		route: create_bounded_vec(vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: BTC,
+		}]),
+	}
+}
+
+fn get_trigger_check_fee(schedule: &Schedule<AccountId, AssetId, BlockNumber>) -> Balance {
+	DCA::convert_weight_to_fee(DCA::get_trigger_check_weight(schedule), HDX).unwrap()
+}
+
+#[test]
+fn schedule_should_fail_when_trigger_price_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let schedule = ScheduleBuilder::new()
+				.with_order(sell_order(ONE))
+				.with_trigger(Some(Trigger::LimitPrice(FixedU128::from(0))))
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::InvalidTrigger
+			);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_rolling_schedule_has_trigger() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(0)
+				.with_order(sell_order(ONE))
+				.with_trigger(Some(Trigger::StopLoss(FixedU128::from_rational(1, 2))))
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::TriggerNotAllowedForRollingSchedule
+			);
+		});
+}
+
+#[test]
+fn limit_order_should_be_replanned_for_trigger_check_fee_when_spot_price_is_below_limit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+			let total_amount = 5 * ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(sell_order(ONE))
+				.with_trigger(Some(Trigger::LimitPrice(FixedU128::from(2))))
+				.build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+
+			set_spot_price(Some(FixedU128::from_rational(3, 2)));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let schedule_id = 0;
+			let trigger_check_fee = get_trigger_check_fee(&schedule);
+			assert!(trigger_check_fee > 0);
+			assert_number_of_executed_sell_trades!(0);
+			assert_eq!(
+				total_amount - trigger_check_fee,
+				Currencies::reserved_balance(HDX, &ALICE)
+			);
+			assert_eq!(
+				DCA::remaining_amounts(schedule_id),
+				Some(total_amount - trigger_check_fee)
+			);
+			assert_eq!(DCA::retries_on_error(schedule_id), 0);
+			assert_scheduled_ids!(602, vec![schedule_id]);
+		});
+}
+
+#[test]
+fn triggered_schedule_should_be_terminated_when_budget_does_not_cover_trigger_check_fee() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(5 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(sell_order(ONE))
+				.with_trigger(Some(Trigger::LimitPrice(FixedU128::from(2))))
+				.build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+
+			let schedule_id = 0;
+			crate::RemainingAmounts::<Test>::insert(schedule_id, get_trigger_check_fee(&schedule) - 1);
+
+			set_spot_price(Some(FixedU128::from_rational(3, 2)));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(0);
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+			expect_events(vec![DcaEvent::Terminated {
+				id: schedule_id,
+				who: ALICE,
+				error: Error::<Test>::TriggerCheckBudgetExhausted.into(),
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn triggered_sell_should_be_terminated_without_fee_when_trigger_check_fee_would_spend_budget_of_trade() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let amount_to_sell = ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(5 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(sell_order(amount_to_sell))
+				.with_trigger(Some(Trigger::LimitPrice(FixedU128::from(2))))
+				.build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+
+			let schedule_id = 0;
+			let transaction_fee = DCA::get_transaction_fee(&schedule.order, Some(schedule_id)).unwrap();
+			let remaining_amount = amount_to_sell + transaction_fee + get_trigger_check_fee(&schedule) - 1;
+			crate::RemainingAmounts::<Test>::insert(schedule_id, remaining_amount);
+			let treasury_balance_before = Currencies::free_balance(HDX, &TreasuryAccount::get());
+
+			set_spot_price(Some(FixedU128::from_rational(3, 2)));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(0);
+			assert_balance!(TreasuryAccount::get(), HDX, treasury_balance_before);
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+			expect_events(vec![DcaEvent::Terminated {
+				id: schedule_id,
+				who: ALICE,
+				error: Error::<Test>::TriggerCheckBudgetExhausted.into(),
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn triggered_sell_should_keep_budget_of_trade_when_trigger_check_fee_is_charged() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let amount_to_sell = ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(5 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(sell_order(amount_to_sell))
+				.with_trigger(Some(Trigger::LimitPrice(FixedU128::from(2))))
+				.build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+
+			let schedule_id = 0;
+			let transaction_fee = DCA::get_transaction_fee(&schedule.order, Some(schedule_id)).unwrap();
+			let remaining_amount = amount_to_sell + transaction_fee + get_trigger_check_fee(&schedule);
+			crate::RemainingAmounts::<Test>::insert(schedule_id, remaining_amount);
+
+			set_spot_price(Some(FixedU128::from_rational(3, 2)));
+			set_to_blocknumber(502);
+			assert_eq!(
+				DCA::remaining_amounts(schedule_id),
+				Some(amount_to_sell + transaction_fee)
+			);
+			set_spot_price(Some(FixedU128::from(2)));
+
+			//Act
+			set_to_blocknumber(602);
+
+			//Assert
+			assert_executed_sell_trades!(vec![SellExecution {
+				asset_in: HDX,
+				asset_out: BTC,
+				amount_in: amount_to_sell,
+				min_buy_amount: 0,
+			}]);
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+			expect_events(vec![DcaEvent::Completed {
+				id: schedule_id,
+				who: ALICE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn triggered_buy_should_be_terminated_when_trigger_check_fee_would_spend_budget_of_trade() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let amount_to_buy = 10 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_slippage(Some(Permill::from_percent(20)))
+				.with_order(Order::Buy {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: amount_to_buy,
+					max_amount_in: 50 * ONE,
+					route: create_bounded_vec(vec![Trade {
+						pool: PoolType::Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.with_trigger(Some(Trigger::LimitPrice(FixedU128::from(2))))
+				.build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+
+			let schedule_id = 0;
+			let transaction_fee = DCA::get_transaction_fee(&schedule.order, Some(schedule_id)).unwrap();
+			let trigger_check_fee = get_trigger_check_fee(&schedule);
+			let remaining_amount = CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY + transaction_fee + 2 * trigger_check_fee;
+			crate::RemainingAmounts::<Test>::insert(schedule_id, remaining_amount);
+
+			set_spot_price(Some(FixedU128::from_rational(3, 2)));
+			set_to_blocknumber(502);
+			set_to_blocknumber(602);
+
+			//Act
+			set_to_blocknumber(702);
+
+			//Assert
+			assert_number_of_executed_buy_trades!(0);
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+			expect_events(vec![DcaEvent::Terminated {
+				id: schedule_id,
+				who: ALICE,
+				error: Error::<Test>::TriggerCheckBudgetExhausted.into(),
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn triggered_buy_should_be_executed_when_trigger_check_fees_were_charged_from_budget() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let amount_to_buy = 10 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_slippage(Some(Permill::from_percent(20)))
+				.with_order(Order::Buy {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: amount_to_buy,
+					max_amount_in: 50 * ONE,
+					route: create_bounded_vec(vec![Trade {
+						pool: PoolType::Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.with_trigger(Some(Trigger::LimitPrice(FixedU128::from(2))))
+				.build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+
+			let schedule_id = 0;
+			let transaction_fee = DCA::get_transaction_fee(&schedule.order, Some(schedule_id)).unwrap();
+			let trigger_check_fee = get_trigger_check_fee(&schedule);
+			let remaining_amount = CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY + transaction_fee + 2 * trigger_check_fee;
+			crate::RemainingAmounts::<Test>::insert(schedule_id, remaining_amount);
+
+			set_spot_price(Some(FixedU128::from_rational(3, 2)));
+			set_to_blocknumber(502);
+			set_to_blocknumber(602);
+			set_spot_price(Some(FixedU128::from(2)));
+
+			//Act
+			set_to_blocknumber(702);
+
+			//Assert
+			assert_number_of_executed_buy_trades!(1);
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+			expect_events(vec![
+				DcaEvent::TradeExecuted {
+					id: schedule_id,
+					who: ALICE,
+					amount_in: CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+					amount_out: amount_to_buy,
+				}
+				.into(),
+				DcaEvent::Completed {
+					id: schedule_id,
+					who: ALICE,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn limit_order_should_be_executed_once_when_spot_price_reaches_limit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+			let total_amount = 5 * ONE;
+			let amount_to_sell = ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(sell_order(amount_to_sell))
+				.with_trigger(Some(Trigger::LimitPrice(FixedU128::from(2))))
+				.build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			set_spot_price(Some(FixedU128::from_rational(3, 2)));
+			set_to_blocknumber(502);
+			set_spot_price(Some(FixedU128::from(2)));
+
+			//Act
+			set_to_blocknumber(602);
+
+			//Assert
+			let schedule_id = 0;
+			assert_executed_sell_trades!(vec![SellExecution {
+				asset_in: HDX,
+				asset_out: BTC,
+				amount_in: amount_to_sell,
+				min_buy_amount: 0,
+			}]);
+			assert_eq!(0, Currencies::reserved_balance(HDX, &ALICE));
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+			expect_events(vec![DcaEvent::Completed {
+				id: schedule_id,
+				who: ALICE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn stop_loss_should_be_executed_when_oracle_price_falls_to_trigger_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_order(sell_order(ONE))
+				.with_trigger(Some(Trigger::StopLoss(FixedU128::from_rational(1, 2))))
+				.build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			set_trigger_oracle_price(Some(Ratio::new(1, 2)));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(1);
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, 0);
+		});
+}
+
+#[test]
+fn stop_loss_should_not_be_executed_when_oracle_price_is_above_trigger_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_order(sell_order(ONE))
+				.with_trigger(Some(Trigger::StopLoss(FixedU128::from_rational(1, 2))))
+				.build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			set_trigger_oracle_price(Some(Ratio::new(6, 10)));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(0);
+			assert_scheduled_ids!(602, vec![0]);
+		});
+}
+
+#[test]
+fn take_profit_should_be_executed_when_oracle_price_rises_to_trigger_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_order(sell_order(ONE))
+				.with_trigger(Some(Trigger::TakeProfit(FixedU128::from(3))))
+				.build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			set_trigger_oracle_price(Some(Ratio::new(2, 1)));
+			set_to_blocknumber(502);
+			assert_number_of_executed_sell_trades!(0);
+
+			set_trigger_oracle_price(Some(Ratio::new(3, 1)));
+
+			//Act
+			set_to_blocknumber(602);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(1);
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, 0);
+		});
+}
+
+#[test]
+fn trigger_should_not_be_met_when_price_is_not_available() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+			let total_amount = 1000 * ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_order(sell_order(ONE))
+				.with_trigger(Some(Trigger::StopLoss(FixedU128::from(10))))
+				.build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(0);
+			assert_eq!(
+				total_amount - get_trigger_check_fee(&schedule),
+				Currencies::reserved_balance(HDX, &ALICE)
+			);
+			assert_scheduled_ids!(602, vec![0]);
+		});
+}
@@ -0,0 +1,656 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::assert_scheduled_ids;
+use crate::tests::mock::*;
+use crate::tests::terminate::set_block_number;
+use crate::tests::*;
+use crate::types::ScheduleUpdate;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+
+fn sell_order() -> Order<AssetId> {
+	Order::Sell {
+		asset_in: HDX,
+		asset_out: BTC,
+		amount_in: ONE,
+		min_amount_out: Balance::MIN,
+		route: create_bounded_vec(vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: BTC,
+		}]),
+	}
+}
+
+fn split_sell_order() -> Order<AssetId> {
+	Order::SplitSell {
+		asset_in: HDX,
+		asset_out: BTC,
+		amount_in: ONE,
+		min_amount_out: Balance::MIN,
+		routes: create_split_route(vec![(
+			Permill::from_percent(100),
+			vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: BTC,
+			}],
+		)]),
+	}
+}
+
+#[test]
+fn update_schedule_should_change_period_slippage_and_threshold_in_place() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule_id = 0;
+			let schedule = ScheduleBuilder::new().with_order(sell_order()).build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::Some(600)
+			));
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				ScheduleUpdate {
+					period: Some(50),
+					slippage: Some(Some(Permill::from_percent(3))),
+					stability_threshold: Some(Some(Permill::from_percent(10))),
+					..Default::default()
+				}
+			));
+
+			//Assert
+			let updated_schedule = DCA::schedules(schedule_id).unwrap();
+			assert_eq!(updated_schedule.period, 50);
+			assert_eq!(updated_schedule.slippage, Some(Permill::from_percent(3)));
+			assert_eq!(updated_schedule.stability_threshold, Some(Permill::from_percent(10)));
+			assert_eq!(updated_schedule.total_amount, schedule.total_amount);
+			assert_eq!(updated_schedule.order, schedule.order);
+			assert_scheduled_ids!(600, vec![schedule_id]);
+
+			expect_events(vec![Event::ScheduleUpdated {
+				id: schedule_id,
+				who: ALICE,
+				period: 50,
+				total_amount: schedule.total_amount,
+				order: schedule.order,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn update_schedule_should_leave_fields_untouched_when_not_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new()
+				.with_slippage(Some(Permill::from_percent(5)))
+				.with_price_stability_threshold(Some(Permill::from_percent(5)))
+				.build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::Some(600)
+			));
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ScheduleUpdate::default()
+			));
+
+			//Assert
+			assert_eq!(DCA::schedules(0), Some(schedule));
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_period_is_too_short() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().build(),
+				Option::Some(600)
+			));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						period: Some(MinimalPeriod::get() as BlockNumber - 1),
+						..Default::default()
+					}
+				),
+				Error::<Test>::PeriodTooShort
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_stability_threshold_is_too_high() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().build(),
+				Option::Some(600)
+			));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						stability_threshold: Some(Some(Permill::from_percent(21))),
+						..Default::default()
+					}
+				),
+				Error::<Test>::StabilityThresholdTooHigh
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_called_by_non_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().build(),
+				Option::Some(600)
+			));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(BOB),
+					0,
+					ScheduleUpdate {
+						period: Some(50),
+						..Default::default()
+					}
+				),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_replace_route_of_sell_order() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().with_order(sell_order()).build(),
+				Option::Some(600)
+			));
+			let new_route = create_bounded_vec(vec![
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: DAI,
+				},
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: DAI,
+					asset_out: BTC,
+				},
+			]);
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ScheduleUpdate {
+					route: Some(new_route.clone()),
+					..Default::default()
+				}
+			));
+
+			//Assert
+			assert_eq!(
+				DCA::schedules(0).unwrap().order,
+				Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					min_amount_out: Balance::MIN,
+					route: new_route,
+				}
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_route_does_not_connect_assets_of_order() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().with_order(sell_order()).build(),
+				Option::Some(600)
+			));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						route: Some(create_bounded_vec(vec![
+							Trade {
+								pool: PoolType::Omnipool,
+								asset_in: HDX,
+								asset_out: DAI,
+							},
+							Trade {
+								pool: PoolType::Omnipool,
+								asset_in: DOT,
+								asset_out: BTC,
+							},
+						])),
+						..Default::default()
+					}
+				),
+				Error::<Test>::InvalidRoute
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_oracle_price_is_not_available_for_new_route() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().with_order(sell_order()).build(),
+				Option::Some(600)
+			));
+			set_assets_without_oracle(vec![DAI]);
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						route: Some(create_bounded_vec(vec![
+							Trade {
+								pool: PoolType::Omnipool,
+								asset_in: HDX,
+								asset_out: DAI,
+							},
+							Trade {
+								pool: PoolType::Omnipool,
+								asset_in: DAI,
+								asset_out: BTC,
+							},
+						])),
+						..Default::default()
+					}
+				),
+				Error::<Test>::CalculatingPriceError
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_remaining_budget_does_not_cover_execution_with_new_route() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().with_order(sell_order()).build(),
+				Option::Some(600)
+			));
+			crate::RemainingAmounts::<Test>::insert(0, ONE);
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						route: Some(create_bounded_vec(vec![
+							Trade {
+								pool: PoolType::Omnipool,
+								asset_in: HDX,
+								asset_out: DAI,
+							},
+							Trade {
+								pool: PoolType::Omnipool,
+								asset_in: DAI,
+								asset_out: BTC,
+							},
+						])),
+						..Default::default()
+					}
+				),
+				Error::<Test>::BudgetTooLow
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_route_is_set_for_split_order() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().with_order(split_sell_order()).build(),
+				Option::Some(600)
+			));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						route: Some(create_bounded_vec(vec![Trade {
+							pool: PoolType::Omnipool,
+							asset_in: HDX,
+							asset_out: BTC,
+						}])),
+						..Default::default()
+					}
+				),
+				Error::<Test>::InvalidRoute
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_replace_routes_of_split_order() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().with_order(split_sell_order()).build(),
+				Option::Some(600)
+			));
+			let new_routes = create_split_route(vec![
+				(
+					Permill::from_percent(60),
+					vec![Trade {
+						pool: PoolType::Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}],
+				),
+				(
+					Permill::from_percent(40),
+					vec![
+						Trade {
+							pool: PoolType::Omnipool,
+							asset_in: HDX,
+							asset_out: DAI,
+						},
+						Trade {
+							pool: PoolType::Omnipool,
+							asset_in: DAI,
+							asset_out: BTC,
+						},
+					],
+				),
+			]);
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ScheduleUpdate {
+					routes: Some(new_routes.clone()),
+					..Default::default()
+				}
+			));
+
+			//Assert
+			assert_eq!(
+				DCA::schedules(0).unwrap().order,
+				Order::SplitSell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					min_amount_out: Balance::MIN,
+					routes: new_routes,
+				}
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_split_route_weights_are_invalid() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().with_order(split_sell_order()).build(),
+				Option::Some(600)
+			));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						routes: Some(create_split_route(vec![(
+							Permill::from_percent(50),
+							vec![Trade {
+								pool: PoolType::Omnipool,
+								asset_in: HDX,
+								asset_out: BTC,
+							}],
+						)])),
+						..Default::default()
+					}
+				),
+				Error::<Test>::InvalidSplitRoute
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_routes_are_set_for_non_split_order() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().with_order(sell_order()).build(),
+				Option::Some(600)
+			));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						routes: Some(create_split_route(vec![(
+							Permill::from_percent(100),
+							vec![Trade {
+								pool: PoolType::Omnipool,
+								asset_in: HDX,
+								asset_out: BTC,
+							}],
+						)])),
+						..Default::default()
+					}
+				),
+				Error::<Test>::InvalidRoute
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_reserve_budget_top_up() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let total_amount = 100 * ONE;
+			let top_up = 50 * ONE;
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().with_total_amount(total_amount).build(),
+				Option::Some(600)
+			));
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ScheduleUpdate {
+					budget_top_up: Some(top_up),
+					..Default::default()
+				}
+			));
+
+			//Assert
+			assert_eq!(DCA::schedules(0).unwrap().total_amount, total_amount + top_up);
+			assert_eq!(DCA::remaining_amounts(0), Some(total_amount + top_up));
+			assert_eq!(
+				total_amount + top_up,
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE)
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_top_up_exceeds_free_balance() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().with_total_amount(100 * ONE).build(),
+				Option::Some(600)
+			));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						budget_top_up: Some(10000 * ONE),
+						..Default::default()
+					}
+				),
+				pallet_balances::Error::<Test>::InsufficientBalance
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_topping_up_rolling_schedule() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				ScheduleBuilder::new().with_total_amount(0).build(),
+				Option::Some(600)
+			));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						budget_top_up: Some(ONE),
+						..Default::default()
+					}
+				),
+				Error::<Test>::TopUpNotAllowedForRollingSchedule
+			);
+		});
+}
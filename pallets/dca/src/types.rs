@@ -1,8 +1,9 @@
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
-use hydradx_traits::router::{AssetPair, Route, RouteProvider, Trade};
+use hydradx_traits::router::{AssetPair, Route, RouteProvider, SplitRoute, Trade, WeightedRoute};
 use scale_info::TypeInfo;
 use sp_runtime::traits::ConstU32;
-use sp_runtime::{BoundedVec, Permill};
+use sp_runtime::{BoundedVec, FixedU128, Permill};
+use sp_std::vec;
 
 pub type Balance = u128;
 pub type ScheduleId = u32;
@@ -32,12 +33,67 @@ pub struct Schedule<AccountId, AssetId, BlockNumber> {
 	pub slippage: Option<Permill>,
 	/// The order containing information to execute a specific trade by the router.
 	pub order: Order<AssetId>,
+	/// The price condition which has to be met before the order is executed.
+	/// A schedule with a trigger is executed only once, the trigger is checked every `period` blocks.
+	pub trigger: Option<Trigger>,
 }
 
 impl<AccountId, AssetId, BlockNumber> Schedule<AccountId, AssetId, BlockNumber> {
 	pub fn is_rolling(&self) -> bool {
 		self.total_amount == 0
 	}
+
+	pub fn is_one_shot(&self) -> bool {
+		self.trigger.is_some()
+	}
+}
+
+/// Changes of an existing schedule, the fields set to `None` are left unchanged.
+#[derive(Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, Clone, TypeInfo, Default)]
+pub struct ScheduleUpdate<AssetId, BlockNumber> {
+	/// The new time period (in blocks) between two schedule executions.
+	/// It is applied from the next replanning of the schedule.
+	pub period: Option<BlockNumber>,
+	/// The new slippage limit.
+	pub slippage: Option<Option<Permill>>,
+	/// The new price stability threshold.
+	pub stability_threshold: Option<Option<Permill>>,
+	/// The amount added to the budget (`total_amount`) and reserved for the schedule.
+	pub budget_top_up: Option<Balance>,
+	/// The new route of a `Sell` or `Buy` order.
+	pub route: Option<Route<AssetId>>,
+	/// The new routes of a `SplitSell` or `SplitBuy` order.
+	pub routes: Option<SplitRoute<AssetId>>,
+}
+
+/// Price condition of a one-shot schedule.
+///
+/// The trigger prices are the prices of the sold asset (`asset_in`) denominated in the bought asset (`asset_out`),
+/// i.e. the amount of `asset_out` received for one unit of `asset_in`.
+#[derive(Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen)]
+pub enum Trigger {
+	/// Limit order, executed when the spot price of the route rises to or above the limit price.
+	LimitPrice(FixedU128),
+	/// Stop-loss order, executed when the oracle price falls to or below the trigger price.
+	StopLoss(FixedU128),
+	/// Take-profit order, executed when the oracle price rises to or above the trigger price.
+	TakeProfit(FixedU128),
+}
+
+impl Trigger {
+	pub fn price(&self) -> FixedU128 {
+		match self {
+			Trigger::LimitPrice(price) | Trigger::StopLoss(price) | Trigger::TakeProfit(price) => *price,
+		}
+	}
+
+	/// Returns true if the trigger is met by the current price.
+	pub fn is_met(&self, current_price: FixedU128) -> bool {
+		match self {
+			Trigger::LimitPrice(price) | Trigger::TakeProfit(price) => current_price >= *price,
+			Trigger::StopLoss(price) => current_price <= *price,
+		}
+	}
 }
 
 #[derive(Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
@@ -56,6 +112,22 @@ pub enum Order<AssetId> {
 		max_amount_in: Balance,
 		route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
 	},
+	/// Sell executed via multiple parallel routes, `amount_in` is split between the routes based on their weights.
+	SplitSell {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		routes: SplitRoute<AssetId>,
+	},
+	/// Buy executed via multiple parallel routes, `amount_out` is split between the routes based on their weights.
+	SplitBuy {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_amount_in: Balance,
+		routes: SplitRoute<AssetId>,
+	},
 }
 
 impl<AssetId> Order<AssetId>
@@ -66,6 +138,8 @@ where
 		let asset_in = match &self {
 			Order::Sell { asset_in, .. } => asset_in,
 			Order::Buy { asset_in, .. } => asset_in,
+			Order::SplitSell { asset_in, .. } => asset_in,
+			Order::SplitBuy { asset_in, .. } => asset_in,
 		};
 		*asset_in
 	}
@@ -74,19 +148,44 @@ where
 		let asset_out = match &self {
 			Order::Sell { asset_out, .. } => asset_out,
 			Order::Buy { asset_out, .. } => asset_out,
+			Order::SplitSell { asset_out, .. } => asset_out,
+			Order::SplitBuy { asset_out, .. } => asset_out,
 		};
 		*asset_out
 	}
 
-	pub fn get_route_or_default<Provider: RouteProvider<AssetId>>(&self) -> Route<AssetId> {
-		let route = match &self {
-			Order::Sell { route, .. } => route,
-			Order::Buy { route, .. } => route,
+	pub fn is_split(&self) -> bool {
+		matches!(self, Order::SplitSell { .. } | Order::SplitBuy { .. })
+	}
+
+	/// Returns the weighted routes of the order, a single-route order has one route with 100% weight.
+	///
+	/// Empty routes are replaced by the on-chain or default route of the asset pair.
+	pub fn get_routes_or_default<Provider: RouteProvider<AssetId>>(&self) -> SplitRoute<AssetId> {
+		let routes = match &self {
+			Order::Sell { route, .. } | Order::Buy { route, .. } => vec![WeightedRoute {
+				weight: Permill::one(),
+				route: route.clone(),
+			}],
+			Order::SplitSell { routes, .. } | Order::SplitBuy { routes, .. } => routes.to_vec(),
 		};
+
+		BoundedVec::truncate_from(
+			routes
+				.into_iter()
+				.map(|weighted_route| WeightedRoute {
+					weight: weighted_route.weight,
+					route: self.route_or_default::<Provider>(weighted_route.route),
+				})
+				.collect(),
+		)
+	}
+
+	fn route_or_default<Provider: RouteProvider<AssetId>>(&self, route: Route<AssetId>) -> Route<AssetId> {
 		if route.is_empty() {
 			Provider::get_route(AssetPair::new(self.get_asset_in(), self.get_asset_out()))
 		} else {
-			route.clone()
+			route
 		}
 	}
 }
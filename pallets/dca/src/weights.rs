@@ -19,6 +19,9 @@ pub trait WeightInfo {
 	fn schedule() -> Weight;
 	fn terminate() -> Weight;
 	fn unlock_reserves() -> Weight;
+	fn pause() -> Weight;
+	fn resume() -> Weight;
+	fn update_schedule() -> Weight;
 }
 
 /// Weights for pallet_dca using the hydraDX node and recommended hardware.
@@ -214,14 +217,16 @@ impl WeightInfo for () {
 	/// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::ScheduleOwnership` (r:0 w:1)
 	/// Proof: `DCA::ScheduleOwnership` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::PausedSchedules` (r:1 w:1)
+	/// Proof: `DCA::PausedSchedules` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	fn terminate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2530`
 		//  Estimated: `4714`
 		// Minimum execution time: 86_873_000 picoseconds.
 		Weight::from_parts(88_015_000, 4714)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 
 	/// Storage: `DCA::ScheduleOwnership` (r:1 w:0)
@@ -239,4 +244,55 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `DCA::Schedules` (r:1 w:0)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::PausedSchedules` (r:1 w:1)
+	/// Proof: `DCA::PausedSchedules` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::ScheduleExecutionBlock` (r:1 w:1)
+	/// Proof: `DCA::ScheduleExecutionBlock` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::ScheduleIdsPerBlock` (r:1 w:1)
+	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2441`
+		//  Estimated: `4097`
+		// Minimum execution time: 41_262_000 picoseconds.
+		Weight::from_parts(42_114_000, 4097)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DCA::Schedules` (r:1 w:0)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::PausedSchedules` (r:1 w:1)
+	/// Proof: `DCA::PausedSchedules` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::ScheduleIdsPerBlock` (r:11 w:1)
+	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::ScheduleExecutionBlock` (r:0 w:1)
+	/// Proof: `DCA::ScheduleExecutionBlock` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn resume() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4528`
+		//  Estimated: `28710`
+		// Minimum execution time: 79_584_000 picoseconds.
+		Weight::from_parts(80_912_000, 28710)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DCA::Schedules` (r:1 w:1)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::RemainingAmounts` (r:1 w:1)
+	/// Proof: `DCA::RemainingAmounts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Reserves` (r:1 w:1)
+	/// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2652`
+		//  Estimated: `4714`
+		// Minimum execution time: 58_407_000 picoseconds.
+		Weight::from_parts(59_638_000, 4714)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
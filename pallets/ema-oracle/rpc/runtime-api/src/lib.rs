@@ -0,0 +1,58 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the EMA oracle pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use hydradx_traits::oracle::{AggregatedEntry, OraclePeriod, Source};
+pub use hydradx_traits::router::Trade;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query EMA oracle entries.
+	pub trait EmaOracleApi<AssetId, Balance, BlockNumber, Price> where
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		Price: Codec,
+	{
+		/// Return the oracle entry of the asset pair for the given period and source,
+		/// updated to the state of the parent block.
+		/// The price of the entry is `asset_a/asset_b`.
+		fn get_entry(
+			asset_a: AssetId,
+			asset_b: AssetId,
+			period: OraclePeriod,
+			source: Source,
+		) -> Option<AggregatedEntry<Balance, BlockNumber, Price>>;
+
+		/// Return the oracle entries of the asset pair for all periods of the given source.
+		/// Periods without an entry are not returned.
+		fn get_entries(
+			asset_a: AssetId,
+			asset_b: AssetId,
+			source: Source,
+		) -> Vec<(OraclePeriod, AggregatedEntry<Balance, BlockNumber, Price>)>;
+
+		/// Return the asset pairs tracked for the given source, ordered by asset ids.
+		fn tracked_pairs(source: Source) -> Vec<(AssetId, AssetId)>;
+
+		/// Return the oracle price of the route for the given period.
+		/// The price is the price of the last asset of the route denominated in the first asset.
+		fn route_price(route: Vec<Trade<AssetId>>, period: OraclePeriod) -> Option<Price>;
+	}
+}
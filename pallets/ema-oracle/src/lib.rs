@@ -80,6 +80,7 @@ use hydradx_traits::{
 use sp_arithmetic::traits::Saturating;
 use sp_runtime::traits::Convert;
 use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
 
@@ -798,6 +799,14 @@ impl<T: Config> Pallet<T> {
 		.map(|return_entry| (return_entry, init))
 	}
 
+	/// Return the asset pairs (in `ordered_pair` form) that have an oracle entry for the given source.
+	pub fn tracked_pairs(source: Source) -> Vec<(AssetId, AssetId)> {
+		let pairs: BTreeSet<(AssetId, AssetId)> = Oracles::<T>::iter_key_prefix((source,))
+			.map(|(pair, _period)| pair)
+			.collect();
+		pairs.into_iter().collect()
+	}
+
 	/// Return last stored entry for given period and block number of last updated.
 	pub fn get_last_oracle_entry(
 		source: Source,
@@ -821,6 +821,34 @@ fn get_entry_works() {
 	});
 }
 
+#[test]
+fn tracked_pairs_should_return_ordered_pairs_of_source() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let other_source: Source = *b"othersrc";
+		for (source, asset_a, asset_b) in [(SOURCE, DOT, HDX), (SOURCE, HDX, ACA), (other_source, ACA, DOT)] {
+			assert_ok!(OnActivityHandler::<Test>::on_trade(
+				source,
+				asset_a,
+				asset_b,
+				1_000,
+				500,
+				2_000,
+				1_000,
+				Price::new(2_000, 1_000),
+				None,
+			));
+		}
+		EmaOracle::on_finalize(1);
+
+		let mut expected = vec![ordered_pair(HDX, DOT), ordered_pair(HDX, ACA)];
+		expected.sort();
+		assert_eq!(EmaOracle::tracked_pairs(SOURCE), expected);
+		assert_eq!(EmaOracle::tracked_pairs(other_source), vec![ordered_pair(ACA, DOT)]);
+		assert_eq!(EmaOracle::tracked_pairs(*b"unknown_"), vec![]);
+	});
+}
+
 #[test]
 fn get_price_returns_updated_price() {
 	ExtBuilder::default()
@@ -174,6 +174,7 @@ impl pallet_stableswap::Config for Test {
 	type BlockNumberProvider = System;
 	type DustAccountHandler = Whitelist;
 	type Hooks = ();
+	type AccountTradeVolumeHandler = ();
 	type TargetPegOracle = PegOracle;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = for_benchmark_tests::MockStableswapBenchmarkHelper;
@@ -2,9 +2,10 @@ use crate::types::Balance;
 use crate::{Collaterals, Config, Error, Pallet};
 use frame_support::pallet_prelude::{Get, IsType};
 use hydra_dx_math::stableswap::types::AssetReserve;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, Trade, TradeExecution, TradeablePoolsProvider};
 use sp_core::crypto::AccountId32;
 use sp_runtime::{ArithmeticError, DispatchError, FixedU128};
+use sp_std::vec::Vec;
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T>
 where
@@ -205,3 +206,26 @@ where
 		}
 	}
 }
+
+impl<T: Config> TradeablePoolsProvider<T::AssetId> for Pallet<T> {
+	fn tradeable_pools() -> Vec<Trade<T::AssetId>> {
+		let hollar = T::HollarId::get();
+
+		Collaterals::<T>::iter_keys()
+			.flat_map(|collateral| {
+				[
+					Trade {
+						pool: PoolType::HSM,
+						asset_in: collateral,
+						asset_out: hollar,
+					},
+					Trade {
+						pool: PoolType::HSM,
+						asset_in: hollar,
+						asset_out: collateral,
+					},
+				]
+			})
+			.collect()
+	}
+}
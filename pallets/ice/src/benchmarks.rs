@@ -0,0 +1,157 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use hydradx_traits::{AssetKind, Create};
+use orml_traits::MultiCurrencyExtended;
+use sp_std::vec;
+use sp_std::vec::Vec;
+pub const ONE: Balance = 1_000_000_000_000;
+
+benchmarks! {
+	where_clause { where
+		AssetIdOf<T>: From<u32>,
+		<T as Config>::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+		T: crate::pallet::Config,
+		u32: From<AssetIdOf<T>>,
+		<T as pallet_otc::Config>::AssetRegistry: Create<Balance, Error=DispatchError, AssetId = AssetIdOf<T>>
+	}
+	submit_intent {
+		let (dot, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
+	}: _(RawOrigin::Signed(owner.clone()), dot.into(), dai.into(), 100 * ONE, 20 * ONE, 10u32.into())
+	verify {
+		assert!(crate::Pallet::<T>::intents(0).is_some());
+		assert_eq!(<T as Config>::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 100 * ONE);
+	}
+
+	remove_intent {
+		let (dot, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
+		assert_ok!(
+			crate::Pallet::<T>::submit_intent(RawOrigin::Signed(owner.clone()).into(), dot.into(), dai.into(), 100 * ONE, 20 * ONE, 10u32.into())
+		);
+	}: _(RawOrigin::Signed(owner.clone()), 0)
+	verify {
+		assert!(crate::Pallet::<T>::intents(0).is_none());
+		assert_eq!(<T as Config>::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 0);
+	}
+
+	submit_solution {
+		let n in 2 .. T::MaxResolvedIntents::get();
+
+		let (dot, dai) = seed_registry::<T>()?;
+
+		// Opposite intents are matched directly, so that the settlement does not depend on any trade.
+		let dot_sellers = n.div_ceil(2);
+		let dai_sellers = n - dot_sellers;
+		let mut resolved_intents = Vec::new();
+		for i in 0..n {
+			let owner: T::AccountId = create_account_with_balances::<T>("owner", i, vec!(dot, dai))?;
+			let (asset_in, asset_out, amount) = if i < dot_sellers {
+				(dot, dai, dai_sellers as Balance * ONE)
+			} else {
+				(dai, dot, dot_sellers as Balance * ONE)
+			};
+			assert_ok!(
+				crate::Pallet::<T>::submit_intent(RawOrigin::Signed(owner).into(), asset_in.into(), asset_out.into(), amount, amount, 10u32.into())
+			);
+			resolved_intents.push(ResolvedIntent { intent_id: i as IntentId, amount_out: amount });
+		}
+		let resolved_intents: BoundedVec<ResolvedIntent, T::MaxResolvedIntents> = resolved_intents.try_into().map_err(|_| "BoundedConvertionFailed")?;
+
+		let solver: T::AccountId = account("solver", 0, 0);
+	}: _(RawOrigin::Signed(solver), resolved_intents, BoundedVec::new())
+	verify {
+		for i in 0..n {
+			assert!(crate::Pallet::<T>::intents(i as IntentId).is_none());
+		}
+	}
+}
+
+fn seed_registry<T: Config>() -> Result<(u32, u32), DispatchError>
+where
+	u32: From<AssetIdOf<T>>,
+	<T as pallet_otc::Config>::AssetRegistry: Create<Balance, Error = DispatchError>,
+{
+	use frame_support::storage::with_transaction;
+	use sp_runtime::TransactionOutcome;
+
+	// Register new asset in asset registry
+	let name = b"DOT".to_vec().try_into().map_err(|_| "BoundedConvertionFailed")?;
+	let dot = with_transaction(|| {
+		TransactionOutcome::Commit(<T as pallet_otc::Config>::AssetRegistry::register_sufficient_asset(
+			None,
+			Some(name),
+			AssetKind::Token,
+			ONE,
+			None,
+			None,
+			None,
+			None,
+		))
+	})?;
+	let name = b"DAI".to_vec().try_into().map_err(|_| "BoundedConvertionFailed")?;
+	let dai = with_transaction(|| {
+		TransactionOutcome::Commit(<T as pallet_otc::Config>::AssetRegistry::register_sufficient_asset(
+			None,
+			Some(name),
+			AssetKind::Token,
+			ONE,
+			None,
+			None,
+			None,
+			None,
+		))
+	})?;
+
+	Ok((dot.into(), dai.into()))
+}
+
+fn create_account_with_balances<T: Config>(
+	name: &'static str,
+	index: u32,
+	assets: Vec<u32>,
+) -> Result<T::AccountId, DispatchError>
+where
+	AssetIdOf<T>: From<u32>,
+	<T as Config>::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let account_id: T::AccountId = account(name, index, index);
+
+	let token_amount: Balance = 200 * ONE;
+
+	for asset in assets.iter() {
+		<T as Config>::Currency::update_balance((*asset).into(), &account_id, token_amount as i128)?;
+	}
+	// Intent deposit
+	<T as Config>::Currency::update_balance(T::NativeAssetId::get(), &account_id, token_amount as i128)?;
+
+	Ok(account_id)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::mock::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
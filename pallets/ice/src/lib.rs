@@ -0,0 +1,574 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # ICE pallet
+//!
+//! ## Description
+//! Intent-based trading. Users submit signed swap intents and solvers settle them in batches.
+//!
+//! An intent states that its owner wants to swap `amount_in` of `asset_in` for at least `amount_out` of `asset_out`
+//! before the `deadline` block. `amount_in` is reserved until the intent is resolved or removed, together with
+//! `IntentDeposit` of the native asset for the storage of the intent.
+//!
+//! A solver resolves a batch of intents by submitting a solution, which consists of the resolved intents with the
+//! amounts of `asset_out` guaranteed to their owners and of the trades needed to source those amounts. Opposite
+//! intents can be matched directly against each other without any trade.
+//!
+//! ## Settlement
+//! The inputs of all resolved intents are moved to the holding account of the pallet, which then executes the trades
+//! of the solution through the router or by filling OTC orders. Afterwards, every intent owner receives the resolved
+//! amount. Whatever is left of an asset is surplus and is distributed among the owners receiving that asset pro rata
+//! to their resolved amounts. A solution fails if it cannot pay the resolved amounts or if it leaves a balance of an
+//! asset which is not received by any of the resolved intents.
+//!
+//! Every fill is reported via `pallet_broadcast` with `Filler::ICE(solution_id)`, where the intent owner is the
+//! swapper and the solver is the filler. Trades executed during the settlement are reported in the
+//! `ExecutionType::ICE(solution_id)` context.
+//!
+//! Intents are resolved in full. Expired intents cannot be resolved and can be removed by anyone.
+//!
+//! ## Dispatchable functions
+//! * `submit_intent` - Submits a new swap intent.
+//! * `remove_intent` - Removes an intent and unreserves its `amount_in` and deposit.
+//! * `submit_solution` - Resolves a batch of intents.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, require_transactional, traits::ExistenceRequirement, transactional, PalletId};
+use frame_system::{
+	pallet_prelude::{BlockNumberFor, OriginFor},
+	RawOrigin,
+};
+use hydradx_traits::{
+	router::{AmmTradeWeights, AmountInAndOut, RouterT, Trade},
+	Inspect,
+};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pallet_broadcast::types::{Asset, ExecutionType, Filler, IncrementalIdType, TradeOperation};
+use pallet_otc::weights::WeightInfo as OtcWeightInfo;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AccountIdConversion, Zero},
+	ArithmeticError, Rounding,
+};
+use sp_std::{vec, vec::Vec};
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
+#[cfg(test)]
+mod tests;
+
+pub mod types;
+pub mod weights;
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+pub const NAMED_RESERVE_ID: NamedReserveIdentifier = *b"iceinten";
+pub const DEPOSIT_RESERVE_ID: NamedReserveIdentifier = *b"icedepos";
+
+pub type AssetIdOf<T> = <T as pallet_otc::Config>::AssetId;
+pub type IntentOf<T> = Intent<<T as frame_system::Config>::AccountId, AssetIdOf<T>, BlockNumberFor<T>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_otc::Config {
+		/// Named reservable multi currency.
+		type Currency: NamedMultiReservableCurrency<
+			Self::AccountId,
+			ReserveIdentifier = NamedReserveIdentifier,
+			CurrencyId = AssetIdOf<Self>,
+			Balance = Balance,
+		>;
+
+		/// Router implementation.
+		type Router: RouterT<
+			Self::RuntimeOrigin,
+			AssetIdOf<Self>,
+			Balance,
+			Trade<AssetIdOf<Self>>,
+			AmountInAndOut<Balance>,
+		>;
+
+		/// Pallet id of the holding account which settles the solutions.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Native asset id in which the intent deposit is reserved.
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetIdOf<Self>>;
+
+		/// Deposit reserved for the storage of an intent until it is resolved or removed.
+		#[pallet::constant]
+		type IntentDeposit: Get<Balance>;
+
+		/// Maximum number of blocks an intent can be valid for.
+		#[pallet::constant]
+		type MaxIntentDuration: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of intents resolved by a solution.
+		#[pallet::constant]
+		type MaxResolvedIntents: Get<u32>;
+
+		/// Maximum number of trades in a solution.
+		#[pallet::constant]
+		type MaxSolutionTrades: Get<u32>;
+
+		/// Weight information for router trades.
+		type RouterWeightInfo: AmmTradeWeights<Trade<AssetIdOf<Self>>>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// ID sequencer for intents
+	#[pallet::storage]
+	#[pallet::getter(fn next_intent_id)]
+	pub type NextIntentId<T: Config> = StorageValue<_, IntentId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn intents)]
+	pub type Intents<T: Config> = StorageMap<_, Blake2_128Concat, IntentId, IntentOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An intent has been submitted
+		IntentSubmitted {
+			intent_id: IntentId,
+			who: T::AccountId,
+			asset_in: AssetIdOf<T>,
+			asset_out: AssetIdOf<T>,
+			amount_in: Balance,
+			amount_out: Balance,
+			deadline: BlockNumberFor<T>,
+		},
+		/// An intent has been removed
+		IntentRemoved { intent_id: IntentId },
+		/// An intent has been resolved by a solution
+		IntentResolved {
+			intent_id: IntentId,
+			who: T::AccountId,
+			amount_in: Balance,
+			amount_out: Balance,
+		},
+		/// A solution has been executed
+		SolutionExecuted {
+			solution_id: IncrementalIdType,
+			solver: T::AccountId,
+			resolved_intents: u32,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Asset does not exist in registry
+		AssetNotRegistered,
+		/// Asset in and asset out of an intent are the same
+		SameAssets,
+		/// Amounts of an intent must be greater than zero
+		InvalidAmount,
+		/// Deadline must be in the future and within `MaxIntentDuration`
+		InvalidDeadline,
+		/// Intent cannot be found
+		IntentNotFound,
+		/// Intent has expired
+		IntentExpired,
+		/// The caller does not have permission to complete the action
+		Forbidden,
+		/// Reserved amount not sufficient.
+		InsufficientReservedAmount,
+		/// Solution does not resolve any intent
+		EmptySolution,
+		/// Intent is resolved more than once in a solution
+		DuplicateIntent,
+		/// Resolved amount is lower than the minimum amount of the intent
+		InsufficientAmountOut,
+		/// Solution does not provide enough to pay out the resolved amounts
+		InsufficientSolutionBalance,
+		/// Solution leaves a balance of an asset which is not received by any resolved intent
+		UnsettledBalance,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Submit a swap intent.
+		///
+		/// `amount_in` of `asset_in` is reserved until the intent is resolved or removed, together with
+		/// `IntentDeposit` of the native asset.
+		///
+		/// Parameters:
+		/// - `asset_in`: Asset which is being sold
+		/// - `asset_out`: Asset which is being bought
+		/// - `amount_in`: Amount of `asset_in` to sell
+		/// - `amount_out`: Minimum amount of `asset_out` to receive
+		/// - `deadline`: Block from which the intent cannot be resolved
+		///
+		/// Emits `IntentSubmitted` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::submit_intent())]
+		pub fn submit_intent(
+			origin: OriginFor<T>,
+			asset_in: AssetIdOf<T>,
+			asset_out: AssetIdOf<T>,
+			amount_in: Balance,
+			amount_out: Balance,
+			deadline: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(asset_in != asset_out, Error::<T>::SameAssets);
+			ensure!(
+				<T as pallet_otc::Config>::AssetRegistry::exists(asset_in),
+				Error::<T>::AssetNotRegistered
+			);
+			ensure!(
+				<T as pallet_otc::Config>::AssetRegistry::exists(asset_out),
+				Error::<T>::AssetNotRegistered
+			);
+			ensure!(!amount_in.is_zero() && !amount_out.is_zero(), Error::<T>::InvalidAmount);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				deadline > now && deadline <= now.saturating_add(T::MaxIntentDuration::get()),
+				Error::<T>::InvalidDeadline
+			);
+
+			<T as Config>::Currency::reserve_named(&NAMED_RESERVE_ID, asset_in, &who, amount_in)?;
+			let deposit = T::IntentDeposit::get();
+			<T as Config>::Currency::reserve_named(&DEPOSIT_RESERVE_ID, T::NativeAssetId::get(), &who, deposit)?;
+
+			let intent_id = <NextIntentId<T>>::try_mutate(|next_id| -> Result<IntentId, DispatchError> {
+				let intent_id = *next_id;
+				*next_id = next_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(intent_id)
+			})?;
+
+			<Intents<T>>::insert(
+				intent_id,
+				Intent {
+					who: who.clone(),
+					asset_in,
+					asset_out,
+					amount_in,
+					amount_out,
+					deadline,
+					deposit,
+				},
+			);
+
+			Self::deposit_event(Event::IntentSubmitted {
+				intent_id,
+				who,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				deadline,
+			});
+
+			Ok(())
+		}
+
+		/// Remove an intent and unreserve its `amount_in` and deposit.
+		///
+		/// Parameters:
+		/// - `intent_id`: ID of the intent
+		///
+		/// Validations:
+		/// - caller is the intent owner or the intent has expired
+		///
+		/// Emits `IntentRemoved` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_intent())]
+		pub fn remove_intent(origin: OriginFor<T>, intent_id: IntentId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let intent = <Intents<T>>::get(intent_id).ok_or(Error::<T>::IntentNotFound)?;
+
+			ensure!(intent.who == who || Self::is_expired(&intent), Error::<T>::Forbidden);
+
+			let remaining_to_unreserve = <T as Config>::Currency::unreserve_named(
+				&NAMED_RESERVE_ID,
+				intent.asset_in,
+				&intent.who,
+				intent.amount_in,
+			);
+			ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+			Self::release_deposit(&intent)?;
+
+			<Intents<T>>::remove(intent_id);
+
+			Self::deposit_event(Event::IntentRemoved { intent_id });
+			Ok(())
+		}
+
+		/// Resolve a batch of intents.
+		///
+		/// Inputs of the resolved intents are collected by the holding account, which executes `trades` and pays out
+		/// the resolved amounts together with the surplus.
+		///
+		/// Parameters:
+		/// - `resolved_intents`: Intents to resolve with the amounts of `asset_out` guaranteed to their owners
+		/// - `trades`: Trades executed by the holding account, in order
+		///
+		/// Emits `IntentResolved` for each intent and `SolutionExecuted` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::submit_solution(resolved_intents.len() as u32)
+			.saturating_add(Pallet::<T>::trades_weight(trades)))]
+		#[transactional]
+		pub fn submit_solution(
+			origin: OriginFor<T>,
+			resolved_intents: BoundedVec<ResolvedIntent, T::MaxResolvedIntents>,
+			trades: BoundedVec<SolutionTrade<AssetIdOf<T>>, T::MaxSolutionTrades>,
+		) -> DispatchResult {
+			let solver = ensure_signed(origin)?;
+			ensure!(!resolved_intents.is_empty(), Error::<T>::EmptySolution);
+
+			let solution_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::ICE)?;
+
+			Self::settle_solution(&solver, solution_id, resolved_intents.into_inner(), trades.into_inner())?;
+
+			pallet_broadcast::Pallet::<T>::remove_from_context()?;
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account which collects the intent inputs and executes the trades of a solution.
+	pub fn holding_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Returns `true` if the intent cannot be resolved anymore because its deadline has been reached.
+	pub fn is_expired(intent: &IntentOf<T>) -> bool {
+		frame_system::Pallet::<T>::block_number() >= intent.deadline
+	}
+
+	/// Unreserve the storage deposit of an intent which is being resolved or removed.
+	fn release_deposit(intent: &IntentOf<T>) -> DispatchResult {
+		let remaining_to_unreserve = <T as Config>::Currency::unreserve_named(
+			&DEPOSIT_RESERVE_ID,
+			T::NativeAssetId::get(),
+			&intent.who,
+			intent.deposit,
+		);
+		ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+		Ok(())
+	}
+
+	/// Weight of the trades of a solution.
+	pub fn trades_weight(trades: &[SolutionTrade<AssetIdOf<T>>]) -> Weight {
+		trades.iter().fold(Weight::zero(), |weight, trade| {
+			let trade_weight = match trade {
+				SolutionTrade::Router { route, .. } => T::RouterWeightInfo::sell_weight(route),
+				SolutionTrade::Otc { .. } => <T as pallet_otc::Config>::WeightInfo::fill_order()
+					.max(<T as pallet_otc::Config>::WeightInfo::partial_fill_order()),
+			};
+			weight.saturating_add(trade_weight)
+		})
+	}
+
+	#[require_transactional]
+	fn settle_solution(
+		solver: &T::AccountId,
+		solution_id: IncrementalIdType,
+		resolved_intents: Vec<ResolvedIntent>,
+		trades: Vec<SolutionTrade<AssetIdOf<T>>>,
+	) -> DispatchResult {
+		let holding_account = Self::holding_account();
+
+		let mut intents: Vec<(IntentId, IntentOf<T>, Balance)> = Vec::with_capacity(resolved_intents.len());
+		for resolved in resolved_intents {
+			ensure!(
+				intents.iter().all(|(intent_id, _, _)| *intent_id != resolved.intent_id),
+				Error::<T>::DuplicateIntent
+			);
+			let intent = <Intents<T>>::take(resolved.intent_id).ok_or(Error::<T>::IntentNotFound)?;
+			ensure!(!Self::is_expired(&intent), Error::<T>::IntentExpired);
+			ensure!(
+				resolved.amount_out >= intent.amount_out,
+				Error::<T>::InsufficientAmountOut
+			);
+			intents.push((resolved.intent_id, intent, resolved.amount_out));
+		}
+
+		// Balances of the holding account before the settlement, so that only what the solution brings in is paid out.
+		let mut initial_balances: Vec<(AssetIdOf<T>, Balance)> = Vec::new();
+		let mut track_asset = |asset: AssetIdOf<T>| {
+			if !initial_balances.iter().any(|(a, _)| *a == asset) {
+				initial_balances.push((asset, <T as Config>::Currency::free_balance(asset, &holding_account)));
+			}
+		};
+		for (_, intent, _) in intents.iter() {
+			track_asset(intent.asset_in);
+			track_asset(intent.asset_out);
+		}
+		for trade in trades.iter() {
+			match trade {
+				SolutionTrade::Router {
+					asset_in, asset_out, ..
+				} => {
+					track_asset(*asset_in);
+					track_asset(*asset_out);
+				}
+				SolutionTrade::Otc { order_id, .. } => {
+					let order = pallet_otc::Orders::<T>::get(order_id).ok_or(pallet_otc::Error::<T>::OrderNotFound)?;
+					track_asset(order.asset_in);
+					track_asset(order.asset_out);
+				}
+			}
+		}
+
+		for (_, intent, _) in intents.iter() {
+			let remaining_to_unreserve = <T as Config>::Currency::unreserve_named(
+				&NAMED_RESERVE_ID,
+				intent.asset_in,
+				&intent.who,
+				intent.amount_in,
+			);
+			ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+			Self::release_deposit(intent)?;
+			<T as Config>::Currency::transfer(
+				intent.asset_in,
+				&intent.who,
+				&holding_account,
+				intent.amount_in,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+
+		for trade in trades {
+			Self::execute_trade(&holding_account, trade)?;
+		}
+
+		let amounts_out = Self::calculate_amounts_out(&holding_account, &initial_balances, &intents)?;
+
+		for ((intent_id, intent, _), amount_out) in intents.iter().zip(amounts_out) {
+			<T as Config>::Currency::transfer(
+				intent.asset_out,
+				&holding_account,
+				&intent.who,
+				amount_out,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			pallet_broadcast::Pallet::<T>::deposit_trade_event(
+				intent.who.clone(),
+				solver.clone(),
+				Filler::ICE(solution_id),
+				TradeOperation::ExactIn,
+				vec![Asset::new(intent.asset_in.into(), intent.amount_in)],
+				vec![Asset::new(intent.asset_out.into(), amount_out)],
+				vec![],
+			);
+
+			Self::deposit_event(Event::IntentResolved {
+				intent_id: *intent_id,
+				who: intent.who.clone(),
+				amount_in: intent.amount_in,
+				amount_out,
+			});
+		}
+
+		Self::deposit_event(Event::SolutionExecuted {
+			solution_id,
+			solver: solver.clone(),
+			resolved_intents: intents.len() as u32,
+		});
+
+		Ok(())
+	}
+
+	fn execute_trade(holding_account: &T::AccountId, trade: SolutionTrade<AssetIdOf<T>>) -> DispatchResult {
+		let origin: T::RuntimeOrigin = RawOrigin::Signed(holding_account.clone()).into();
+		match trade {
+			SolutionTrade::Router {
+				asset_in,
+				asset_out,
+				amount_in,
+				min_amount_out,
+				route,
+			} => T::Router::sell(origin, asset_in, asset_out, amount_in, min_amount_out, route),
+			SolutionTrade::Otc { order_id, amount_in } => {
+				let order = pallet_otc::Orders::<T>::get(order_id).ok_or(pallet_otc::Error::<T>::OrderNotFound)?;
+				if amount_in == order.amount_in {
+					pallet_otc::Pallet::<T>::fill_order(origin, order_id)
+				} else {
+					pallet_otc::Pallet::<T>::partial_fill_order(origin, order_id, amount_in)
+				}
+			}
+		}
+	}
+
+	/// Amounts of `asset_out` paid to the owners of the resolved intents, including their share of the surplus.
+	fn calculate_amounts_out(
+		holding_account: &T::AccountId,
+		initial_balances: &[(AssetIdOf<T>, Balance)],
+		intents: &[(IntentId, IntentOf<T>, Balance)],
+	) -> Result<Vec<Balance>, DispatchError> {
+		let mut amounts_out: Vec<Balance> = intents.iter().map(|(_, _, amount_out)| *amount_out).collect();
+
+		for (asset, initial_balance) in initial_balances.iter() {
+			let available = <T as Config>::Currency::free_balance(*asset, holding_account)
+				.checked_sub(*initial_balance)
+				.ok_or(Error::<T>::InsufficientSolutionBalance)?;
+
+			let recipients: Vec<usize> = intents
+				.iter()
+				.enumerate()
+				.filter(|(_, (_, intent, _))| intent.asset_out == *asset)
+				.map(|(idx, _)| idx)
+				.collect();
+
+			let required = recipients.iter().try_fold(0u128, |acc, idx| {
+				acc.checked_add(amounts_out[*idx]).ok_or(ArithmeticError::Overflow)
+			})?;
+			let surplus = available
+				.checked_sub(required)
+				.ok_or(Error::<T>::InsufficientSolutionBalance)?;
+
+			let Some((last, others)) = recipients.split_last() else {
+				ensure!(surplus.is_zero(), Error::<T>::UnsettledBalance);
+				continue;
+			};
+
+			let mut distributed: Balance = 0;
+			for idx in others {
+				let share = multiply_by_rational_with_rounding(surplus, amounts_out[*idx], required, Rounding::Down)
+					.ok_or(ArithmeticError::Overflow)?;
+				distributed = distributed.checked_add(share).ok_or(ArithmeticError::Overflow)?;
+				amounts_out[*idx] = amounts_out[*idx].checked_add(share).ok_or(ArithmeticError::Overflow)?;
+			}
+			// The last recipient receives the rounding remainder, so that nothing is left in the holding account.
+			let remainder = surplus.checked_sub(distributed).ok_or(ArithmeticError::Underflow)?;
+			amounts_out[*last] = amounts_out[*last]
+				.checked_add(remainder)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		Ok(amounts_out)
+	}
+}
@@ -0,0 +1,433 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as ice;
+use frame_support::{
+	parameter_types,
+	traits::{Everything, Nothing},
+	PalletId,
+};
+use frame_system as system;
+use frame_system::ensure_signed;
+use hydradx_traits::{
+	registry::Inspect,
+	router::{AmountInAndOut, AssetPair, Route, RouterT, SplitRoute, Trade},
+	AssetKind,
+};
+use orml_tokens::AccountData;
+use orml_traits::{parameter_type_with_key, MultiCurrency};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, DispatchResult, Permill,
+};
+use sp_std::marker::PhantomData;
+use std::{cell::RefCell, collections::HashMap};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type Amount = i128;
+pub type AssetId = u32;
+pub type Balance = u128;
+pub type NamedReserveIdentifier = [u8; 8];
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2;
+pub const DOT: AssetId = 5;
+pub const DOGE: AssetId = 333;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const SOLVER: AccountId = 4;
+pub const ROUTER_POOL: AccountId = 100;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	 {
+		 System: frame_system,
+		 ICE: ice,
+		 OTC: pallet_otc,
+		 Tokens: orml_tokens,
+		 Broadcast: pallet_broadcast,
+	 }
+);
+
+thread_local! {
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u32>> = RefCell::new(HashMap::default());
+	pub static ROUTER_PRICE: RefCell<(Balance, Balance)> = const { RefCell::new((1, 1)) };
+}
+
+parameter_types! {
+	pub ExistentialDepositMultiplier: u8 = 5;
+	pub OtcFee: Permill = Permill::from_percent(1u32);
+	pub const TreasuryPalletId: PalletId = PalletId(*b"aca/trsy");
+	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
+	pub const IcePalletId: PalletId = PalletId(*b"iceintnt");
+	pub const MaxIntentDuration: u64 = 100;
+	pub const MaxResolvedIntents: u32 = 100;
+	pub const MaxSolutionTrades: u32 = 10;
+	pub const NativeAssetId: AssetId = HDX;
+	pub const IntentDeposit: Balance = ONE;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		ONE / 10
+	};
+}
+
+impl ice::Config for Test {
+	type Currency = Tokens;
+	type Router = RouterMock;
+	type PalletId = IcePalletId;
+	type NativeAssetId = NativeAssetId;
+	type IntentDeposit = IntentDeposit;
+	type MaxIntentDuration = MaxIntentDuration;
+	type MaxResolvedIntents = MaxResolvedIntents;
+	type MaxSolutionTrades = MaxSolutionTrades;
+	type RouterWeightInfo = ();
+	type WeightInfo = ();
+}
+
+impl pallet_otc::Config for Test {
+	type AssetId = AssetId;
+	type AssetRegistry = DummyRegistry<Test>;
+	type Currency = Tokens;
+	type ExistentialDeposits = ExistentialDeposits;
+	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
+	type Fee = OtcFee;
+	type FeeReceiver = TreasuryAccount;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const MaxReserves: u32 = 50;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+	type ExtensionsWeightInfo = ();
+}
+
+impl orml_tokens::Config for Test {
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = NamedReserveIdentifier;
+	type MaxReserves = MaxReserves;
+	type CurrencyHooks = ();
+}
+
+impl pallet_broadcast::Config for Test {}
+
+/// Router which sells at the price set in `ROUTER_PRICE` against the `ROUTER_POOL` account.
+pub struct RouterMock;
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for RouterMock {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		_route: Route<AssetId>,
+	) -> DispatchResult {
+		let who = ensure_signed(origin)?;
+		let (n, d) = ROUTER_PRICE.with(|v| *v.borrow());
+		let amount_out = amount_in * n / d;
+		if amount_out < min_amount_out {
+			return Err(DispatchError::Other("TradingLimitReached"));
+		}
+		Tokens::transfer(asset_in, &who, &ROUTER_POOL, amount_in)?;
+		Tokens::transfer(asset_out, &ROUTER_POOL, &who, amount_out)?;
+		Ok(())
+	}
+
+	fn sell_all(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_min_amount_out: Balance,
+		_route: Route<AssetId>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Route<AssetId>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn split_sell(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_in: Balance,
+		_min_amount_out: Balance,
+		_routes: SplitRoute<AssetId>,
+	) -> Result<AmountInAndOut<Balance>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn split_buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_routes: SplitRoute<AssetId>,
+	) -> Result<AmountInAndOut<Balance>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Route<AssetId>,
+	) -> frame_support::dispatch::DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn force_insert_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Route<AssetId>,
+	) -> frame_support::dispatch::DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+}
+
+pub struct DummyRegistry<T>(PhantomData<T>);
+
+impl<T: pallet_otc::Config> Inspect for DummyRegistry<T> {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn asset_type(_id: Self::AssetId) -> Option<AssetKind> {
+		unimplemented!()
+	}
+
+	fn decimals(_id: Self::AssetId) -> Option<u8> {
+		unimplemented!()
+	}
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn exists(asset_id: AssetId) -> bool {
+		let asset = REGISTERED_ASSETS.with(|v| v.borrow().get(&(asset_id)).copied());
+		asset.is_some()
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(_id: Self::AssetId) -> Option<u128> {
+		unimplemented!()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+use hydradx_traits::Create as CreateRegistry;
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_otc::Config> CreateRegistry<Balance> for DummyRegistry<T>
+where
+	T::AssetId: Into<AssetId> + From<u32>,
+{
+	type Error = DispatchError;
+	type Name = sp_runtime::BoundedVec<u8, sp_core::ConstU32<100>>;
+	type Symbol = sp_runtime::BoundedVec<u8, sp_core::ConstU32<100>>;
+
+	fn register_asset(
+		_asset_id: Option<Self::AssetId>,
+		_name: Option<Self::Name>,
+		_kind: AssetKind,
+		_existential_deposit: Option<Balance>,
+		_symbol: Option<Self::Symbol>,
+		_decimals: Option<u8>,
+		_location: Option<Self::Location>,
+		_xcm_rate_limit: Option<Balance>,
+		_is_sufficient: bool,
+	) -> Result<Self::AssetId, Self::Error> {
+		let assigned = REGISTERED_ASSETS.with(|v| {
+			let l = 1_000_000 + v.borrow().len();
+			v.borrow_mut().insert(l as u32, l as u32);
+			l as u32
+		});
+		Ok(assigned)
+	}
+
+	fn get_or_register_asset(
+		_name: Self::Name,
+		_kind: AssetKind,
+		_existential_deposit: Option<Balance>,
+		_symbol: Option<Self::Symbol>,
+		_decimals: Option<u8>,
+		_location: Option<Self::Location>,
+		_xcm_rate_limit: Option<Balance>,
+		_is_sufficient: bool,
+	) -> Result<Self::AssetId, Self::Error> {
+		unimplemented!()
+	}
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(u64, AssetId, Balance)>,
+	registered_assets: Vec<AssetId>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		// or set to original default value
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		ROUTER_PRICE.with(|v| {
+			*v.borrow_mut() = (1, 1);
+		});
+
+		Self {
+			endowed_accounts: vec![
+				(ALICE, HDX, 10_000 * ONE),
+				(ALICE, DAI, 10_000 * ONE),
+				(BOB, HDX, 10_000 * ONE),
+				(BOB, DAI, 10_000 * ONE),
+				(CHARLIE, HDX, 10_000 * ONE),
+				(CHARLIE, DAI, 10_000 * ONE),
+				(ROUTER_POOL, HDX, 1_000_000 * ONE),
+				(ROUTER_POOL, DAI, 1_000_000 * ONE),
+				(ROUTER_POOL, DOT, 1_000_000 * ONE),
+				(TreasuryAccount::get(), HDX, 1_000_000 * ONE),
+			],
+			registered_assets: vec![HDX, DAI, DOT],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(u64, AssetId, Balance)>) -> Self {
+		self.endowed_accounts.extend(accounts);
+		self
+	}
+
+	pub fn with_router_price(self, numerator: Balance, denominator: Balance) -> Self {
+		ROUTER_PRICE.with(|v| {
+			*v.borrow_mut() = (numerator, denominator);
+		});
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		REGISTERED_ASSETS.with(|v| {
+			self.registered_assets.iter().for_each(|asset| {
+				v.borrow_mut().insert(*asset, *asset);
+			});
+		});
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+		});
+
+		r
+	}
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	test_utils::expect_events::<RuntimeEvent, Test>(e);
+}
+
+pub fn holding_account() -> AccountId {
+	ICE::holding_account()
+}
@@ -0,0 +1,102 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::tests::mock::*;
+use crate::{Error, Event, DEPOSIT_RESERVE_ID, NAMED_RESERVE_ID};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn remove_intent_should_work_when_called_by_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(ICE::submit_intent(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100 * ONE,
+			50 * ONE,
+			10
+		));
+
+		// Act
+		assert_ok!(ICE::remove_intent(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert!(ICE::intents(0).is_none());
+		assert_eq!(Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::reserved_balance_named(&DEPOSIT_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 10_000 * ONE);
+
+		expect_events(vec![Event::IntentRemoved { intent_id: 0 }.into()]);
+	});
+}
+
+#[test]
+fn remove_intent_should_work_when_intent_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(ICE::submit_intent(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100 * ONE,
+			50 * ONE,
+			10
+		));
+		System::set_block_number(10);
+
+		// Act
+		assert_ok!(ICE::remove_intent(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert!(ICE::intents(0).is_none());
+		assert_eq!(Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::reserved_balance_named(&DEPOSIT_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 10_000 * ONE);
+	});
+}
+
+#[test]
+fn remove_intent_should_fail_when_called_by_non_owner_before_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(ICE::submit_intent(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100 * ONE,
+			50 * ONE,
+			10
+		));
+		System::set_block_number(9);
+
+		// Act & Assert
+		assert_noop!(
+			ICE::remove_intent(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn remove_intent_should_fail_when_intent_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ICE::remove_intent(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::IntentNotFound
+		);
+	});
+}
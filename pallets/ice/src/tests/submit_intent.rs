@@ -0,0 +1,183 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::tests::mock::*;
+use crate::{Error, Event, Intent, DEPOSIT_RESERVE_ID, NAMED_RESERVE_ID};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn submit_intent_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(ICE::submit_intent(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100 * ONE,
+			50 * ONE,
+			10
+		));
+
+		// Assert
+		assert_eq!(
+			ICE::intents(0),
+			Some(Intent {
+				who: ALICE,
+				asset_in: HDX,
+				asset_out: DAI,
+				amount_in: 100 * ONE,
+				amount_out: 50 * ONE,
+				deadline: 10,
+				deposit: ONE,
+			})
+		);
+		assert_eq!(ICE::next_intent_id(), 1);
+		assert_eq!(
+			Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE),
+			100 * ONE
+		);
+		assert_eq!(Tokens::reserved_balance_named(&DEPOSIT_RESERVE_ID, HDX, &ALICE), ONE);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 9_899 * ONE);
+
+		expect_events(vec![Event::IntentSubmitted {
+			intent_id: 0,
+			who: ALICE,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 100 * ONE,
+			amount_out: 50 * ONE,
+			deadline: 10,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn submit_intent_should_increment_intent_id() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(ICE::submit_intent(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100 * ONE,
+			50 * ONE,
+			10
+		));
+
+		// Act
+		assert_ok!(ICE::submit_intent(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			HDX,
+			50 * ONE,
+			100 * ONE,
+			10
+		));
+
+		// Assert
+		assert_eq!(ICE::intents(1).map(|intent| intent.who), Some(BOB));
+		assert_eq!(ICE::next_intent_id(), 2);
+	});
+}
+
+#[test]
+fn submit_intent_should_fail_when_asset_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ICE::submit_intent(RuntimeOrigin::signed(ALICE), HDX, DOGE, 100 * ONE, 50 * ONE, 10),
+			Error::<Test>::AssetNotRegistered
+		);
+		assert_noop!(
+			ICE::submit_intent(RuntimeOrigin::signed(ALICE), DOGE, HDX, 100 * ONE, 50 * ONE, 10),
+			Error::<Test>::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn submit_intent_should_fail_when_assets_are_the_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ICE::submit_intent(RuntimeOrigin::signed(ALICE), HDX, HDX, 100 * ONE, 50 * ONE, 10),
+			Error::<Test>::SameAssets
+		);
+	});
+}
+
+#[test]
+fn submit_intent_should_fail_when_amount_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ICE::submit_intent(RuntimeOrigin::signed(ALICE), HDX, DAI, 0, 50 * ONE, 10),
+			Error::<Test>::InvalidAmount
+		);
+		assert_noop!(
+			ICE::submit_intent(RuntimeOrigin::signed(ALICE), HDX, DAI, 100 * ONE, 0, 10),
+			Error::<Test>::InvalidAmount
+		);
+	});
+}
+
+#[test]
+fn submit_intent_should_fail_when_deadline_is_not_in_the_future() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ICE::submit_intent(RuntimeOrigin::signed(ALICE), HDX, DAI, 100 * ONE, 50 * ONE, 1),
+			Error::<Test>::InvalidDeadline
+		);
+	});
+}
+
+#[test]
+fn submit_intent_should_fail_when_deadline_exceeds_max_duration() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ICE::submit_intent(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				100 * ONE,
+				50 * ONE,
+				1 + MaxIntentDuration::get() + 1
+			),
+			Error::<Test>::InvalidDeadline
+		);
+	});
+}
+
+#[test]
+fn submit_intent_should_fail_when_balance_is_insufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ICE::submit_intent(RuntimeOrigin::signed(ALICE), HDX, DAI, 10_001 * ONE, 50 * ONE, 10),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn submit_intent_should_fail_when_deposit_cannot_be_reserved() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(SOLVER, DAI, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ICE::submit_intent(RuntimeOrigin::signed(SOLVER), DAI, HDX, 100 * ONE, 50 * ONE, 10),
+				orml_tokens::Error::<Test>::BalanceTooLow
+			);
+		});
+}
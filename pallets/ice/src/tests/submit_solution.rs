@@ -0,0 +1,417 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, ResolvedIntent, SolutionTrade, DEPOSIT_RESERVE_ID, NAMED_RESERVE_ID};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use hydradx_traits::router::{PoolType, Trade};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pallet_broadcast::types::{Asset, ExecutionType, Filler, TradeOperation};
+use pretty_assertions::assert_eq;
+
+fn resolved(intents: Vec<(u64, Balance)>) -> BoundedVec<ResolvedIntent, MaxResolvedIntents> {
+	intents
+		.into_iter()
+		.map(|(intent_id, amount_out)| ResolvedIntent { intent_id, amount_out })
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+fn trades(trades: Vec<SolutionTrade<AssetId>>) -> BoundedVec<SolutionTrade<AssetId>, MaxSolutionTrades> {
+	trades.try_into().unwrap()
+}
+
+fn router_sell(asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> SolutionTrade<AssetId> {
+	SolutionTrade::Router {
+		asset_in,
+		asset_out,
+		amount_in,
+		min_amount_out: 0,
+		route: vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in,
+			asset_out,
+		}]
+		.try_into()
+		.unwrap(),
+	}
+}
+
+fn submit_intent(who: AccountId, asset_in: AssetId, asset_out: AssetId, amount_in: Balance, amount_out: Balance) {
+	assert_ok!(ICE::submit_intent(
+		RuntimeOrigin::signed(who),
+		asset_in,
+		asset_out,
+		amount_in,
+		amount_out,
+		10
+	));
+}
+
+#[test]
+fn submit_solution_should_match_opposite_intents_directly() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		submit_intent(ALICE, HDX, DAI, 100 * ONE, 50 * ONE);
+		submit_intent(BOB, DAI, HDX, 50 * ONE, 100 * ONE);
+
+		// Act
+		assert_ok!(ICE::submit_solution(
+			RuntimeOrigin::signed(SOLVER),
+			resolved(vec![(0, 50 * ONE), (1, 100 * ONE)]),
+			trades(vec![])
+		));
+
+		// Assert
+		assert!(ICE::intents(0).is_none());
+		assert!(ICE::intents(1).is_none());
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 9_900 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 10_050 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 10_100 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 9_950 * ONE);
+		assert_eq!(Tokens::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::reserved_balance_named(&NAMED_RESERVE_ID, DAI, &BOB), 0);
+		assert_eq!(Tokens::reserved_balance_named(&DEPOSIT_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::reserved_balance_named(&DEPOSIT_RESERVE_ID, HDX, &BOB), 0);
+		assert_eq!(Tokens::free_balance(HDX, &holding_account()), 0);
+		assert_eq!(Tokens::free_balance(DAI, &holding_account()), 0);
+
+		System::assert_has_event(
+			pallet_broadcast::Event::Swapped3 {
+				swapper: ALICE,
+				filler: SOLVER,
+				filler_type: Filler::ICE(0),
+				operation: TradeOperation::ExactIn,
+				inputs: vec![Asset::new(HDX, 100 * ONE)],
+				outputs: vec![Asset::new(DAI, 50 * ONE)],
+				fees: vec![],
+				operation_stack: vec![ExecutionType::ICE(0)],
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			pallet_broadcast::Event::Swapped3 {
+				swapper: BOB,
+				filler: SOLVER,
+				filler_type: Filler::ICE(0),
+				operation: TradeOperation::ExactIn,
+				inputs: vec![Asset::new(DAI, 50 * ONE)],
+				outputs: vec![Asset::new(HDX, 100 * ONE)],
+				fees: vec![],
+				operation_stack: vec![ExecutionType::ICE(0)],
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::IntentResolved {
+				intent_id: 0,
+				who: ALICE,
+				amount_in: 100 * ONE,
+				amount_out: 50 * ONE,
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			Event::SolutionExecuted {
+				solution_id: 0,
+				solver: SOLVER,
+				resolved_intents: 2,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn submit_solution_should_execute_router_trades() {
+	ExtBuilder::default().with_router_price(2, 1).build().execute_with(|| {
+		// Arrange
+		submit_intent(ALICE, HDX, DAI, 100 * ONE, 190 * ONE);
+
+		// Act
+		assert_ok!(ICE::submit_solution(
+			RuntimeOrigin::signed(SOLVER),
+			resolved(vec![(0, 195 * ONE)]),
+			trades(vec![router_sell(HDX, DAI, 100 * ONE)])
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 9_900 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 10_200 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &ROUTER_POOL), 1_000_100 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &ROUTER_POOL), 999_800 * ONE);
+
+		System::assert_has_event(
+			Event::IntentResolved {
+				intent_id: 0,
+				who: ALICE,
+				amount_in: 100 * ONE,
+				amount_out: 200 * ONE,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn submit_solution_should_fill_otc_orders() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(CHARLIE),
+			HDX,
+			DAI,
+			100 * ONE,
+			200 * ONE,
+			true
+		));
+		submit_intent(ALICE, HDX, DAI, 100 * ONE, 190 * ONE);
+
+		// Act
+		assert_ok!(ICE::submit_solution(
+			RuntimeOrigin::signed(SOLVER),
+			resolved(vec![(0, 190 * ONE)]),
+			trades(vec![SolutionTrade::Otc {
+				order_id: 0,
+				amount_in: 100 * ONE,
+			}])
+		));
+
+		// Assert
+		let fee = OTC::calculate_fee(200 * ONE);
+		assert!(OTC::orders(0).is_none());
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 10_000 * ONE + 200 * ONE - fee);
+		assert_eq!(Tokens::free_balance(HDX, &CHARLIE), 10_100 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &holding_account()), 0);
+	});
+}
+
+#[test]
+fn submit_solution_should_partially_fill_otc_orders() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(CHARLIE),
+			HDX,
+			DAI,
+			200 * ONE,
+			400 * ONE,
+			true
+		));
+		submit_intent(ALICE, HDX, DAI, 100 * ONE, 190 * ONE);
+
+		// Act
+		assert_ok!(ICE::submit_solution(
+			RuntimeOrigin::signed(SOLVER),
+			resolved(vec![(0, 190 * ONE)]),
+			trades(vec![SolutionTrade::Otc {
+				order_id: 0,
+				amount_in: 100 * ONE,
+			}])
+		));
+
+		// Assert
+		let fee = OTC::calculate_fee(200 * ONE);
+		assert_eq!(OTC::orders(0).map(|order| order.amount_in), Some(100 * ONE));
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 10_000 * ONE + 200 * ONE - fee);
+	});
+}
+
+#[test]
+fn submit_solution_should_distribute_surplus_pro_rata() {
+	ExtBuilder::default().with_router_price(2, 1).build().execute_with(|| {
+		// Arrange
+		submit_intent(ALICE, HDX, DAI, 100 * ONE, 150 * ONE);
+		submit_intent(CHARLIE, HDX, DAI, 300 * ONE, 450 * ONE);
+
+		// Act
+		assert_ok!(ICE::submit_solution(
+			RuntimeOrigin::signed(SOLVER),
+			resolved(vec![(0, 150 * ONE), (1, 450 * ONE)]),
+			trades(vec![router_sell(HDX, DAI, 400 * ONE)])
+		));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 10_200 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &CHARLIE), 10_600 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &holding_account()), 0);
+	});
+}
+
+#[test]
+fn submit_solution_should_give_rounding_remainder_to_last_recipient() {
+	ExtBuilder::default().with_router_price(7, 3).build().execute_with(|| {
+		// Arrange
+		submit_intent(ALICE, HDX, DAI, 100 * ONE, 100 * ONE);
+		submit_intent(BOB, HDX, DAI, 100 * ONE, 100 * ONE);
+		submit_intent(CHARLIE, HDX, DAI, 100 * ONE, 100 * ONE);
+
+		// Act
+		assert_ok!(ICE::submit_solution(
+			RuntimeOrigin::signed(SOLVER),
+			resolved(vec![(0, 100 * ONE), (1, 100 * ONE), (2, 100 * ONE)]),
+			trades(vec![router_sell(HDX, DAI, 300 * ONE)])
+		));
+
+		// Assert
+		let surplus = 400 * ONE;
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 10_100 * ONE + surplus / 3);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 10_100 * ONE + surplus / 3);
+		assert_eq!(
+			Tokens::free_balance(DAI, &CHARLIE),
+			10_100 * ONE + surplus - 2 * (surplus / 3)
+		);
+		assert_eq!(Tokens::free_balance(DAI, &holding_account()), 0);
+	});
+}
+
+#[test]
+fn submit_solution_should_fail_when_no_intent_is_resolved() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ICE::submit_solution(RuntimeOrigin::signed(SOLVER), resolved(vec![]), trades(vec![])),
+			Error::<Test>::EmptySolution
+		);
+	});
+}
+
+#[test]
+fn submit_solution_should_fail_when_intent_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ICE::submit_solution(
+				RuntimeOrigin::signed(SOLVER),
+				resolved(vec![(0, 50 * ONE)]),
+				trades(vec![])
+			),
+			Error::<Test>::IntentNotFound
+		);
+	});
+}
+
+#[test]
+fn submit_solution_should_fail_when_intent_is_resolved_twice() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		submit_intent(ALICE, HDX, DAI, 100 * ONE, 50 * ONE);
+
+		// Act & Assert
+		assert_noop!(
+			ICE::submit_solution(
+				RuntimeOrigin::signed(SOLVER),
+				resolved(vec![(0, 50 * ONE), (0, 50 * ONE)]),
+				trades(vec![])
+			),
+			Error::<Test>::DuplicateIntent
+		);
+	});
+}
+
+#[test]
+fn submit_solution_should_fail_when_intent_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		submit_intent(ALICE, HDX, DAI, 100 * ONE, 50 * ONE);
+		System::set_block_number(10);
+
+		// Act & Assert
+		assert_noop!(
+			ICE::submit_solution(
+				RuntimeOrigin::signed(SOLVER),
+				resolved(vec![(0, 100 * ONE)]),
+				trades(vec![router_sell(HDX, DAI, 100 * ONE)])
+			),
+			Error::<Test>::IntentExpired
+		);
+	});
+}
+
+#[test]
+fn submit_solution_should_fail_when_resolved_amount_is_below_intent_minimum() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		submit_intent(ALICE, HDX, DAI, 100 * ONE, 50 * ONE);
+		submit_intent(BOB, DAI, HDX, 50 * ONE, 100 * ONE);
+
+		// Act & Assert
+		assert_noop!(
+			ICE::submit_solution(
+				RuntimeOrigin::signed(SOLVER),
+				resolved(vec![(0, 49 * ONE), (1, 100 * ONE)]),
+				trades(vec![])
+			),
+			Error::<Test>::InsufficientAmountOut
+		);
+	});
+}
+
+#[test]
+fn submit_solution_should_fail_when_resolved_amounts_cannot_be_paid() {
+	ExtBuilder::default().with_router_price(2, 1).build().execute_with(|| {
+		// Arrange
+		submit_intent(ALICE, HDX, DAI, 100 * ONE, 150 * ONE);
+
+		// Act & Assert
+		assert_noop!(
+			ICE::submit_solution(
+				RuntimeOrigin::signed(SOLVER),
+				resolved(vec![(0, 201 * ONE)]),
+				trades(vec![router_sell(HDX, DAI, 100 * ONE)])
+			),
+			Error::<Test>::InsufficientSolutionBalance
+		);
+	});
+}
+
+#[test]
+fn submit_solution_should_fail_when_balance_is_left_unsettled() {
+	ExtBuilder::default().with_router_price(2, 1).build().execute_with(|| {
+		// Arrange
+		submit_intent(ALICE, HDX, DAI, 100 * ONE, 150 * ONE);
+
+		// Act & Assert
+		assert_noop!(
+			ICE::submit_solution(
+				RuntimeOrigin::signed(SOLVER),
+				resolved(vec![(0, 150 * ONE)]),
+				trades(vec![router_sell(HDX, DAI, 90 * ONE)])
+			),
+			Error::<Test>::UnsettledBalance
+		);
+	});
+}
+
+#[test]
+fn submit_solution_should_fail_when_trade_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		submit_intent(ALICE, HDX, DAI, 100 * ONE, 150 * ONE);
+		let mut trade = router_sell(HDX, DAI, 100 * ONE);
+		if let SolutionTrade::Router { min_amount_out, .. } = &mut trade {
+			*min_amount_out = 150 * ONE;
+		}
+
+		// Act & Assert
+		assert_noop!(
+			ICE::submit_solution(
+				RuntimeOrigin::signed(SOLVER),
+				resolved(vec![(0, 150 * ONE)]),
+				trades(vec![trade])
+			),
+			sp_runtime::DispatchError::Other("TradingLimitReached")
+		);
+	});
+}
@@ -0,0 +1,47 @@
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use hydradx_traits::router::Route;
+use pallet_otc::OrderId;
+use scale_info::TypeInfo;
+
+pub type Balance = u128;
+pub type IntentId = u64;
+pub type NamedReserveIdentifier = [u8; 8];
+
+/// Swap intent of a user. `amount_in` of `asset_in` and `deposit` of the native asset are reserved until the intent
+/// is resolved or removed.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct Intent<AccountId, AssetId, BlockNumber> {
+	pub who: AccountId,
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	pub amount_in: Balance,
+	/// Minimum amount of `asset_out` the intent has to be resolved with.
+	pub amount_out: Balance,
+	/// The intent cannot be resolved from this block on.
+	pub deadline: BlockNumber,
+	/// Storage deposit in the native asset, released when the intent is resolved or removed.
+	pub deposit: Balance,
+}
+
+/// Intent resolved by a solution.
+#[derive(Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct ResolvedIntent {
+	pub intent_id: IntentId,
+	/// Amount of `asset_out` the solver guarantees to the intent owner. Surplus is added on top of it.
+	pub amount_out: Balance,
+}
+
+/// Trade executed by the holding account while settling a solution.
+#[derive(Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub enum SolutionTrade<AssetId> {
+	/// Sell `amount_in` of `asset_in` through the router.
+	Router {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		route: Route<AssetId>,
+	},
+	/// Fill OTC order with `amount_in` of its `asset_in`. Partial fill if lower than the order's `amount_in`.
+	Otc { order_id: OrderId, amount_in: Balance },
+}
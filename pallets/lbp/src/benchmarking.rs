@@ -103,6 +103,65 @@ benchmarks! {
 		assert_eq!(T::MultiCurrency::free_balance(ASSET_B_ID, &caller), 1000000000000000);
 	}
 
+	set_sale_finalization {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), SaleFinalization::VestProceeds { duration: 10 })
+	verify {
+		assert_eq!(PoolFinalization::<T>::get(&pool_id), Some(SaleFinalization::VestProceeds { duration: 10 }));
+	}
+
+	finalize_sale {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		LBP::<T>::set_sale_finalization(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), SaleFinalization::VestProceeds { duration: 10 })?;
+
+		let start = BlockNumberFor::<T>::from(1u32);
+		let end = BlockNumberFor::<T>::from(11u32);
+
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None)?;
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(12u32));
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone())
+	verify {
+		assert!(!PoolData::<T>::contains_key(&pool_id));
+		assert!(VestedProceeds::<T>::contains_key(&pool_id));
+	}
+
+	claim_proceeds {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		LBP::<T>::set_sale_finalization(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), SaleFinalization::VestProceeds { duration: 10 })?;
+
+		let start = BlockNumberFor::<T>::from(1u32);
+		let end = BlockNumberFor::<T>::from(11u32);
+
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None)?;
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(12u32));
+
+		LBP::<T>::finalize_sale(RawOrigin::Signed(caller.clone()).into(), pool_id.clone())?;
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(16u32));
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone())
+	verify {
+		assert_eq!(VestedProceeds::<T>::get(&pool_id).unwrap().claimed, 500_000_000);
+	}
+
 	sell {
 		let caller = funded_account::<T>("caller", 0);
 		let fee_collector = funded_account::<T>("fee_collector", 0);
@@ -296,6 +355,9 @@ mod tests {
 			assert_ok!(Pallet::<Test>::test_benchmark_update_pool_data());
 			assert_ok!(Pallet::<Test>::test_benchmark_add_liquidity());
 			assert_ok!(Pallet::<Test>::test_benchmark_remove_liquidity());
+			assert_ok!(Pallet::<Test>::test_benchmark_set_sale_finalization());
+			assert_ok!(Pallet::<Test>::test_benchmark_finalize_sale());
+			assert_ok!(Pallet::<Test>::test_benchmark_claim_proceeds());
 			assert_ok!(Pallet::<Test>::test_benchmark_sell());
 			assert_ok!(Pallet::<Test>::test_benchmark_buy());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_sell());
@@ -25,19 +25,25 @@
 pub use crate::types::{Amount, AssetId, AssetPair, Balance};
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::{
-	traits::{AtLeast32BitUnsigned, BlockNumberProvider, Saturating, Zero},
-	DispatchError, RuntimeDebug,
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AccountIdConversion, AtLeast32BitUnsigned, BlockNumberProvider, Saturating, Zero},
+	DispatchError, Rounding, RuntimeDebug, SaturatedConversion,
 };
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	traits::{EnsureOrigin, ExistenceRequirement, Get, LockIdentifier},
+	storage::with_storage_layer,
+	traits::{ConstU32, EnsureOrigin, ExistenceRequirement, Get, LockIdentifier},
 	transactional,
+	weights::Weight,
+	BoundedVec, PalletId,
 };
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydra_dx_math::types::LBPWeight;
-use hydradx_traits::{AMMTransfer, AssetPairAccountIdFor, CanCreatePool, LockedBalance, AMM};
+use hydradx_traits::{
+	circuit_breaker::AccountTradeVolumeHandler, AMMTransfer, AssetPairAccountIdFor, CanCreatePool, LockedBalance, AMM,
+};
 use pallet_broadcast::types::{Asset, Destination, Fee};
 
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency};
@@ -74,13 +80,28 @@ pub use pallet::*;
 type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
 type PoolId<T> = <T as frame_system::Config>::AccountId;
 
+/// Max number of steps of a step weight curve
+pub const MAX_WEIGHT_CURVE_STEPS: u32 = 20;
+
+/// Steps of a step weight curve. Each step is a number of blocks since the start of the sale and the weight
+/// of the asset_a from that block onwards.
+pub type WeightCurveSteps = BoundedVec<(u32, LBPWeight), ConstU32<MAX_WEIGHT_CURVE_STEPS>>;
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(
-	Default, RuntimeDebug, Encode, Decode, DecodeWithMemTracking, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen,
+	Default, RuntimeDebug, Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen,
 )]
 pub enum WeightCurveType {
+	/// Weights change linearly from the initial weight to the final weight.
 	#[default]
 	Linear,
+	/// Most of the weight change happens at the beginning of the sale.
+	Exponential,
+	/// Weights follow logarithmic curve, change is faster at the beginning of the sale.
+	Logarithmic,
+	/// Weights change in steps. Weight is the initial weight until the first step and the final weight at the end
+	/// of the sale.
+	Steps(WeightCurveSteps),
 }
 
 /// Max weight corresponds to 100%
@@ -92,6 +113,89 @@ pub const MAX_SALE_DURATION: u32 = (60 * 60 * 24 / 6) * 14;
 /// Lock Identifier for the collected fees
 pub const COLLECTOR_LOCK_ID: LockIdentifier = *b"lbpcllct";
 
+/// Action taken with the pool liquidity when the sale ends.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+	Default, RuntimeDebug, Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen,
+)]
+pub enum SaleFinalization {
+	/// Liquidity has to be removed by the pool owner.
+	#[default]
+	Manual,
+	/// All the liquidity is returned to the pool owner.
+	ReturnToOwner,
+	/// Liquidity seeds a new XYK pool at the final price of the sale. The pool owner receives the shares and the
+	/// liquidity which does not fit the final price.
+	SeedXyk,
+	/// Accumulated asset is added to the Omnipool as a position of the pool owner. Sold asset is returned to the
+	/// pool owner.
+	SeedOmnipool,
+	/// Accumulated asset is vested to the pool owner linearly over `duration` blocks since the end of the sale.
+	/// Sold asset is returned to the pool owner.
+	VestProceeds { duration: u32 },
+}
+
+/// Proceeds of a finalized sale vested to the pool owner.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ProceedsVesting<AccountId, BlockNumber> {
+	/// receiver of the proceeds
+	pub owner: AccountId,
+
+	/// vested asset, the accumulated asset of the pool
+	pub asset: AssetId,
+
+	/// total amount of the vested proceeds
+	pub total: Balance,
+
+	/// amount already claimed by the owner
+	pub claimed: Balance,
+
+	/// block from which the proceeds are vested
+	pub start: BlockNumber,
+
+	/// block at which all the proceeds are vested
+	pub end: BlockNumber,
+}
+
+/// Handler used to put the liquidity of a finalized sale to other pools.
+/// Liquidity is provided by the pool owner, it is transferred to the owner before the handler is called.
+pub trait ProceedsHandler<AccountId> {
+	/// Create a new XYK pool owned by `who`.
+	fn seed_xyk(
+		who: &AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+	) -> DispatchResult;
+
+	/// Add liquidity to the Omnipool as a position owned by `who`.
+	fn seed_omnipool(who: &AccountId, asset: AssetId, amount: Balance) -> DispatchResult;
+
+	fn seed_xyk_weight() -> Weight;
+
+	fn seed_omnipool_weight() -> Weight;
+}
+
+impl<AccountId> ProceedsHandler<AccountId> for () {
+	fn seed_xyk(_: &AccountId, _: AssetId, _: Balance, _: AssetId, _: Balance) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn seed_omnipool(_: &AccountId, _: AssetId, _: Balance) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn seed_xyk_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn seed_omnipool_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct Pool<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> {
@@ -155,7 +259,7 @@ impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Pool<AccountId, BlockN
 
 pub trait LBPWeightCalculation<BlockNumber: AtLeast32BitUnsigned> {
 	fn calculate_weight(
-		weight_curve: WeightCurveType,
+		weight_curve: &WeightCurveType,
 		start: BlockNumber,
 		end: BlockNumber,
 		initial_weight: LBPWeight,
@@ -167,14 +271,28 @@ pub trait LBPWeightCalculation<BlockNumber: AtLeast32BitUnsigned> {
 pub struct LBPWeightFunction;
 impl<BlockNumber: AtLeast32BitUnsigned> LBPWeightCalculation<BlockNumber> for LBPWeightFunction {
 	fn calculate_weight(
-		_weight_curve: WeightCurveType,
+		weight_curve: &WeightCurveType,
 		start: BlockNumber,
 		end: BlockNumber,
 		initial_weight: LBPWeight,
 		final_weight: LBPWeight,
 		at: BlockNumber,
 	) -> Option<LBPWeight> {
-		hydra_dx_math::lbp::calculate_linear_weights(start, end, initial_weight, final_weight, at).ok()
+		match weight_curve {
+			WeightCurveType::Linear => {
+				hydra_dx_math::lbp::calculate_linear_weights(start, end, initial_weight, final_weight, at)
+			}
+			WeightCurveType::Exponential => {
+				hydra_dx_math::lbp::calculate_exponential_weights(start, end, initial_weight, final_weight, at)
+			}
+			WeightCurveType::Logarithmic => {
+				hydra_dx_math::lbp::calculate_logarithmic_weights(start, end, initial_weight, final_weight, at)
+			}
+			WeightCurveType::Steps(steps) => {
+				hydra_dx_math::lbp::calculate_step_weights(start, end, initial_weight, final_weight, steps, at)
+			}
+		}
+		.ok()
 	}
 }
 
@@ -226,10 +344,53 @@ pub mod pallet {
 
 		/// The block number provider
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Handler seeding XYK pools and Omnipool positions with liquidity of finalized sales
+		type ProceedsHandler: ProceedsHandler<Self::AccountId>;
+
+		/// Pallet id of the account holding vested proceeds
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Counts trades towards the per-account trade volume limit of the trader.
+		type AccountTradeVolumeHandler: AccountTradeVolumeHandler<Self::AccountId, AssetId, Balance>;
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let read_weight = T::DbWeight::get().reads(2);
+			let mut used_weight = Weight::zero();
+			let mut ended_sales = Vec::new();
+
+			for (pool_id, finalization) in <PoolFinalization<T>>::iter() {
+				let required_weight = used_weight
+					.saturating_add(read_weight)
+					.saturating_add(Self::finalize_sale_weight(&finalization));
+				if required_weight.any_gt(remaining_weight) {
+					break;
+				}
+				used_weight = used_weight.saturating_add(read_weight);
+
+				if let Some(pool_data) = <PoolData<T>>::get(&pool_id) {
+					if Self::has_sale_ended(&pool_data) {
+						used_weight = used_weight.saturating_add(Self::finalize_sale_weight(&finalization));
+						ended_sales.push((pool_id, pool_data, finalization));
+					}
+				}
+			}
+
+			for (pool_id, pool_data, finalization) in ended_sales {
+				if let Err(error) = with_storage_layer(|| Self::do_finalize_sale(&pool_id, pool_data, finalization)) {
+					// the sale is not finalized again, liquidity has to be removed by the pool owner
+					<PoolFinalization<T>>::remove(&pool_id);
+					Self::deposit_event(Event::SaleFinalizationFailed { pool: pool_id, error });
+				}
+			}
+
+			used_weight
+		}
+
 		fn integrity_test() {
 			// The exponentiation used in the math can overflow for values smaller than 3
 			assert!(T::MaxInRatio::get() >= 3, "LBP: MaxInRatio is set to invalid value.");
@@ -308,6 +469,24 @@ pub mod pallet {
 
 		/// Not more than one fee collector per asset id
 		FeeCollectorWithAssetAlreadyUsed,
+
+		/// Steps of the weight curve are empty, not ordered or out of the sale duration
+		InvalidWeightCurve,
+
+		/// Vesting duration of the sale proceeds can not be zero
+		InvalidFinalization,
+
+		/// Sale has to be finalized by the pool owner
+		AutomaticFinalizationNotSet,
+
+		/// Proceeds of the previous sale of the pool are still vesting
+		ProceedsStillVesting,
+
+		/// No proceeds are vesting for the pool
+		ProceedsVestingNotFound,
+
+		/// No vested proceeds to claim
+		NothingToClaim,
 	}
 
 	#[pallet::event]
@@ -366,6 +545,29 @@ pub mod pallet {
 			fee_asset: AssetId,
 			fee_amount: BalanceOf<T>,
 		},
+
+		/// Finalization of the sale was set by the pool owner.
+		SaleFinalizationSet {
+			pool: PoolId<T>,
+			finalization: SaleFinalization,
+		},
+
+		/// Sale ended and the pool was finalized.
+		SaleFinalized {
+			pool: PoolId<T>,
+			finalization: SaleFinalization,
+		},
+
+		/// Automatic finalization of the sale failed. Liquidity has to be removed by the pool owner.
+		SaleFinalizationFailed { pool: PoolId<T>, error: DispatchError },
+
+		/// Vested proceeds of a sale were claimed.
+		ProceedsClaimed {
+			pool: PoolId<T>,
+			who: T::AccountId,
+			asset: AssetId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	/// Details of a pool.
@@ -380,6 +582,15 @@ pub mod pallet {
 	pub type FeeCollectorWithAsset<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, AssetId, bool, ValueQuery>;
 
+	/// Automatic finalization of pools, pools without the entry are finalized by the pool owner.
+	#[pallet::storage]
+	pub type PoolFinalization<T: Config> = StorageMap<_, Blake2_128Concat, PoolId<T>, SaleFinalization, OptionQuery>;
+
+	/// Proceeds of finalized sales vested to the pool owners.
+	#[pallet::storage]
+	pub type VestedProceeds<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId<T>, ProceedsVesting<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
 	#[pallet::extra_constants]
 	impl<T: Config> Pallet<T> {
 		pub fn repay_fee() -> (u32, u32) {
@@ -409,8 +620,8 @@ pub mod pallet {
 		/// this should be higher than final weight
 		/// - `final_weight`: Final weight of the asset_a. 1_000_000 corresponding to 1% and 100_000_000 to 100%
 		/// this should be lower than initial weight
-		/// - `weight_curve`: The weight function used to update the LBP weights. Currently,
-		/// there is only one weight function implemented, the linear function.
+		/// - `weight_curve`: The weight function used to update the LBP weights. Steps of the step function are
+		/// relative to the start of the sale, have to be ordered and within the sale duration.
 		/// - `fee`: The trading fee charged on every trade distributed to `fee_collector`.
 		/// - `fee_collector`: The account to which trading fees will be transferred.
 		/// - `repay_target`: The amount of tokens to repay to separate fee_collector account. Until this amount is
@@ -695,28 +906,7 @@ pub mod pallet {
 
 			ensure!(!Self::is_pool_running(&pool_data), Error::<T>::SaleNotEnded);
 
-			let (asset_a, asset_b) = pool_data.assets;
-
-			let amount_a = T::MultiCurrency::free_balance(asset_a, &pool_id);
-			let amount_b = T::MultiCurrency::free_balance(asset_b, &pool_id);
-
-			T::MultiCurrency::transfer(asset_a, &pool_id, &who, amount_a, ExistenceRequirement::AllowDeath)?;
-			T::MultiCurrency::transfer(asset_b, &pool_id, &who, amount_b, ExistenceRequirement::AllowDeath)?;
-
-			if Self::collected_fees(&pool_data) > 0 {
-				T::MultiCurrency::remove_lock(COLLECTOR_LOCK_ID, asset_a, &pool_data.fee_collector)?;
-			}
-
-			<FeeCollectorWithAsset<T>>::remove(pool_data.fee_collector, pool_data.assets.0);
-			<PoolData<T>>::remove(&pool_id);
-
-			Self::deposit_event(Event::LiquidityRemoved {
-				who: pool_id,
-				asset_a,
-				asset_b,
-				amount_a,
-				amount_b,
-			});
+			Self::destroy_pool(&pool_id, &pool_data, &who)?;
 
 			Ok(())
 		}
@@ -737,7 +927,8 @@ pub mod pallet {
 		/// Emits `SellExecuted` when successful. Deprecated.
 		/// Emits `pallet_broadcast::Swapped` when successful.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::sell())]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			.saturating_add(<T as Config>::AccountTradeVolumeHandler::note_trade_volume_weight()))]
 		pub fn sell(
 			origin: OriginFor<T>,
 			asset_in: AssetId,
@@ -773,7 +964,8 @@ pub mod pallet {
 		/// Emits `BuyExecuted` when successful. Deprecated.
 		/// Emits `pallet_broadcast::Swapped` when successful.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+			.saturating_add(<T as Config>::AccountTradeVolumeHandler::note_trade_volume_weight()))]
 		pub fn buy(
 			origin: OriginFor<T>,
 			asset_out: AssetId,
@@ -790,6 +982,126 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Finalize an ended sale of a pool with automatic finalization.
+		///
+		/// Sales are finalized automatically in `on_idle`. This call can be used by anyone to finalize the sale
+		/// when there is not enough idle weight.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		///
+		/// Emits `SaleFinalized` when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::finalize_sale()
+			.saturating_add(T::ProceedsHandler::seed_xyk_weight().max(T::ProceedsHandler::seed_omnipool_weight())))]
+		pub fn finalize_sale(origin: OriginFor<T>, pool_id: PoolId<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let finalization = <PoolFinalization<T>>::get(&pool_id).ok_or(Error::<T>::AutomaticFinalizationNotSet)?;
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(Self::has_sale_ended(&pool_data), Error::<T>::SaleNotEnded);
+
+			Self::do_finalize_sale(&pool_id, pool_data, finalization)
+		}
+
+		/// Claim proceeds of a finalized sale vested so far.
+		///
+		/// The dispatch origin for this call must be signed by the owner of the vested proceeds.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the finalized pool.
+		///
+		/// Emits `ProceedsClaimed` when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_proceeds())]
+		pub fn claim_proceeds(origin: OriginFor<T>, pool_id: PoolId<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<VestedProceeds<T>>::try_mutate_exists(&pool_id, |maybe_vesting| -> DispatchResult {
+				let vesting = maybe_vesting.as_mut().ok_or(Error::<T>::ProceedsVestingNotFound)?;
+
+				ensure!(who == vesting.owner, Error::<T>::NotOwner);
+
+				let amount = Self::vested_amount(vesting)?.saturating_sub(vesting.claimed);
+				ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+				T::MultiCurrency::transfer(
+					vesting.asset,
+					&Self::pallet_account(),
+					&who,
+					amount,
+					ExistenceRequirement::AllowDeath,
+				)?;
+
+				vesting.claimed = vesting.claimed.saturating_add(amount);
+
+				Self::deposit_event(Event::ProceedsClaimed {
+					pool: pool_id.clone(),
+					who: who.clone(),
+					asset: vesting.asset,
+					amount,
+				});
+
+				if vesting.claimed >= vesting.total {
+					*maybe_vesting = None;
+				}
+
+				Ok(())
+			})
+		}
+
+		/// Set the action taken with the pool liquidity when the sale ends.
+		///
+		/// Unless it is `Manual`, the pool is finalized automatically and the liquidity can't be left in the pool
+		/// after the sale. Pools are created with `Manual` finalization.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// The finalization can be set only if the sale has not already started.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `finalization`: The new finalization of the sale.
+		///
+		/// Emits `SaleFinalizationSet` when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sale_finalization())]
+		pub fn set_sale_finalization(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			finalization: SaleFinalization,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+
+			ensure!(!Self::has_pool_started(&pool_data), Error::<T>::SaleStarted);
+
+			if let SaleFinalization::VestProceeds { duration } = finalization {
+				ensure!(!duration.is_zero(), Error::<T>::InvalidFinalization);
+				ensure!(
+					!<VestedProceeds<T>>::contains_key(&pool_id),
+					Error::<T>::ProceedsStillVesting
+				);
+			}
+
+			if finalization == SaleFinalization::Manual {
+				<PoolFinalization<T>>::remove(&pool_id);
+			} else {
+				<PoolFinalization<T>>::insert(&pool_id, finalization);
+			}
+
+			Self::deposit_event(Event::SaleFinalizationSet {
+				pool: pool_id,
+				finalization,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -905,6 +1217,8 @@ impl<T: Config> Pallet<T> {
 	fn execute_sell(transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>) -> DispatchResult {
 		Self::execute_trade(transfer)?;
 
+		T::AccountTradeVolumeHandler::note_trade_volume(&transfer.origin, transfer.assets.asset_in, transfer.amount)?;
+
 		// TODO: Deprecated, remove when ready
 		Self::deposit_event(Event::<T>::SellExecuted {
 			who: transfer.origin.clone(),
@@ -1055,6 +1369,12 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		Self::execute_trade(transfer)?;
 
+		T::AccountTradeVolumeHandler::note_trade_volume(
+			&transfer.origin,
+			transfer.assets.asset_out,
+			transfer.amount_b,
+		)?;
+
 		// TODO: Deprecated, remove when ready
 		Self::deposit_event(Event::<T>::BuyExecuted {
 			who: transfer.origin.clone(),
@@ -1091,7 +1411,7 @@ impl<T: Config> Pallet<T> {
 		at: BlockNumberFor<T>,
 	) -> Result<(LBPWeight, LBPWeight), DispatchError> {
 		let weight_a = T::LBPWeightFunction::calculate_weight(
-			pool_data.weight_curve,
+			&pool_data.weight_curve,
 			pool_data.start.unwrap_or_else(Zero::zero),
 			pool_data.end.unwrap_or_else(Zero::zero),
 			pool_data.initial_weight,
@@ -1144,6 +1464,33 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(!pool_data.fee.1.is_zero(), Error::<T>::FeeAmountInvalid);
 
+		if let WeightCurveType::Steps(steps) = &pool_data.weight_curve {
+			ensure!(!steps.is_empty(), Error::<T>::InvalidWeightCurve);
+
+			// steps have to be ordered by the block and set before the end of the sale
+			let duration: Option<u32> = match (pool_data.start, pool_data.end) {
+				(Some(start), Some(end)) => Some(
+					end.saturating_sub(start)
+						.try_into()
+						.map_err(|_| Error::<T>::MaxSaleDurationExceeded)?,
+				),
+				_ => None,
+			};
+			ensure!(
+				steps.windows(2).all(|w| w[0].0 < w[1].0)
+					&& duration.is_none_or(|duration| steps.iter().all(|(block, _)| *block < duration)),
+				Error::<T>::InvalidWeightCurve
+			);
+
+			// same bounds as the initial and final weights
+			ensure!(
+				steps
+					.iter()
+					.all(|(_, weight)| *weight < MAX_WEIGHT && *weight >= MAX_WEIGHT / 50),
+				Error::<T>::InvalidWeight
+			);
+		}
+
 		Ok(())
 	}
 
@@ -1183,6 +1530,152 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// return true if the sale has been scheduled and now is > pool.end
+	fn has_sale_ended(pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> bool {
+		let now = T::BlockNumberProvider::current_block_number();
+		match pool_data.end {
+			Some(end) => end < now,
+			_ => false,
+		}
+	}
+
+	/// Transfers all the liquidity out of the pool and removes the pool.
+	/// Accumulated asset is transferred to `receiver_a`, sold asset to the pool owner.
+	fn destroy_pool(
+		pool_id: &PoolId<T>,
+		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
+		receiver_a: &T::AccountId,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		let (asset_a, asset_b) = pool_data.assets;
+
+		let amount_a = T::MultiCurrency::free_balance(asset_a, pool_id);
+		let amount_b = T::MultiCurrency::free_balance(asset_b, pool_id);
+
+		T::MultiCurrency::transfer(asset_a, pool_id, receiver_a, amount_a, ExistenceRequirement::AllowDeath)?;
+		T::MultiCurrency::transfer(
+			asset_b,
+			pool_id,
+			&pool_data.owner,
+			amount_b,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		if Self::collected_fees(pool_data) > 0 {
+			T::MultiCurrency::remove_lock(COLLECTOR_LOCK_ID, asset_a, &pool_data.fee_collector)?;
+		}
+
+		<FeeCollectorWithAsset<T>>::remove(&pool_data.fee_collector, asset_a);
+		<PoolData<T>>::remove(pool_id);
+		<PoolFinalization<T>>::remove(pool_id);
+
+		Self::deposit_event(Event::LiquidityRemoved {
+			who: pool_id.clone(),
+			asset_a,
+			asset_b,
+			amount_a,
+			amount_b,
+		});
+
+		Ok((amount_a, amount_b))
+	}
+
+	fn do_finalize_sale(
+		pool_id: &PoolId<T>,
+		pool_data: Pool<T::AccountId, BlockNumberFor<T>>,
+		finalization: SaleFinalization,
+	) -> DispatchResult {
+		let end = pool_data.end.ok_or(Error::<T>::InvalidBlockRange)?;
+		let (asset_a, asset_b) = pool_data.assets;
+		let owner = &pool_data.owner;
+
+		match finalization {
+			SaleFinalization::Manual | SaleFinalization::ReturnToOwner => {
+				Self::destroy_pool(pool_id, &pool_data, owner)?;
+			}
+			SaleFinalization::SeedXyk => {
+				let (amount_a, amount_b) = Self::destroy_pool(pool_id, &pool_data, owner)?;
+				let (seed_a, seed_b) = Self::calculate_seed_amounts(&pool_data, end, amount_a, amount_b)?;
+				T::ProceedsHandler::seed_xyk(owner, asset_a, seed_a, asset_b, seed_b)?;
+			}
+			SaleFinalization::SeedOmnipool => {
+				let (amount_a, _) = Self::destroy_pool(pool_id, &pool_data, owner)?;
+				T::ProceedsHandler::seed_omnipool(owner, asset_a, amount_a)?;
+			}
+			SaleFinalization::VestProceeds { duration } => {
+				let (amount_a, _) = Self::destroy_pool(pool_id, &pool_data, &Self::pallet_account())?;
+				<VestedProceeds<T>>::insert(
+					pool_id,
+					ProceedsVesting {
+						owner: owner.clone(),
+						asset: asset_a,
+						total: amount_a,
+						claimed: Zero::zero(),
+						start: end,
+						end: end.saturating_add(duration.into()),
+					},
+				);
+			}
+		}
+
+		Self::deposit_event(Event::SaleFinalized {
+			pool: pool_id.clone(),
+			finalization,
+		});
+
+		Ok(())
+	}
+
+	/// Returns amounts of the pool liquidity matching the spot price of the pool at the end of the sale.
+	fn calculate_seed_amounts(
+		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
+		end: BlockNumberFor<T>,
+		amount_a: Balance,
+		amount_b: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let (weight_a, weight_b) = Self::calculate_weights(pool_data, end)?;
+
+		// spot price of the sold asset is (amount_a / weight_a) / (amount_b / weight_b)
+		let seed_b = multiply_by_rational_with_rounding(amount_b, weight_a.into(), weight_b.into(), Rounding::Down)
+			.ok_or(Error::<T>::Overflow)?;
+		if seed_b <= amount_b {
+			return Ok((amount_a, seed_b));
+		}
+
+		let seed_a = multiply_by_rational_with_rounding(amount_a, weight_b.into(), weight_a.into(), Rounding::Down)
+			.ok_or(Error::<T>::Overflow)?;
+		Ok((seed_a, amount_b))
+	}
+
+	/// returns amount of the proceeds vested until now
+	fn vested_amount(vesting: &ProceedsVesting<T::AccountId, BlockNumberFor<T>>) -> Result<Balance, DispatchError> {
+		let now = T::BlockNumberProvider::current_block_number();
+		if now >= vesting.end {
+			return Ok(vesting.total);
+		}
+
+		let elapsed = now.saturating_sub(vesting.start).saturated_into::<u128>();
+		let duration = vesting.end.saturating_sub(vesting.start).saturated_into::<u128>();
+
+		Ok(
+			multiply_by_rational_with_rounding(vesting.total, elapsed, duration, Rounding::Down)
+				.ok_or(Error::<T>::Overflow)?,
+		)
+	}
+
+	fn finalize_sale_weight(finalization: &SaleFinalization) -> Weight {
+		let handler_weight = match finalization {
+			SaleFinalization::SeedXyk => T::ProceedsHandler::seed_xyk_weight(),
+			SaleFinalization::SeedOmnipool => T::ProceedsHandler::seed_omnipool_weight(),
+			_ => Weight::zero(),
+		};
+		T::WeightInfo::finalize_sale().saturating_add(handler_weight)
+	}
+
+	/// account holding the vested proceeds
+	pub fn pallet_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
 	/// returns fees collected and locked in the fee collector account
 	/// note: after LBP finishes and liquidity is removed this will be 0
 	fn collected_fees(pool: &Pool<T::AccountId, BlockNumberFor<T>>) -> BalanceOf<T> {
@@ -1,17 +1,18 @@
 use crate as lbp;
 use crate::{
 	types::{AssetId, AssetPair, Balance},
-	AssetPairAccountIdFor, Config, Pool, WeightCurveType,
+	AssetPairAccountIdFor, Config, Pool, ProceedsHandler, WeightCurveType,
 };
-use frame_support::parameter_types;
 use frame_support::traits::{Everything, LockIdentifier, Nothing};
+use frame_support::{parameter_types, weights::Weight, PalletId};
 use hydradx_traits::{AMMTransfer, LockedBalance};
-use orml_traits::parameter_type_with_key;
+use orml_traits::{parameter_type_with_key, MultiCurrency};
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup, Zero},
-	BuildStorage,
+	BuildStorage, DispatchError, DispatchResult,
 };
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 
 pub type Amount = i128;
@@ -38,6 +39,9 @@ pub const SALE_END: Option<BlockNumber> = Some(40);
 pub const HDX_BSX_POOL_ID: AccountId = 3_000;
 pub const KUSD_BSX_POOL_ID: AccountId = 2_003_000;
 
+pub const XYK_POOL: AccountId = 100;
+pub const OMNIPOOL: AccountId = 200;
+
 pub const DEFAULT_FEE: (u32, u32) = (2, 1_000);
 
 pub const SAMPLE_POOL_DATA: Pool<AccountId, BlockNumber> = Pool {
@@ -171,6 +175,51 @@ impl LockedBalance<AssetId, AccountId, Balance> for MultiLockedBalance {
 	}
 }
 
+thread_local! {
+	pub static SEEDING_FAILS: RefCell<bool> = const { RefCell::new(false) };
+}
+
+pub fn set_seeding_fails(fails: bool) {
+	SEEDING_FAILS.with(|v| *v.borrow_mut() = fails);
+}
+
+pub struct ProceedsHandlerMock;
+
+impl ProceedsHandler<AccountId> for ProceedsHandlerMock {
+	fn seed_xyk(
+		who: &AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+	) -> DispatchResult {
+		if SEEDING_FAILS.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("seeding failed"));
+		}
+		<Currency as MultiCurrency<AccountId>>::transfer(asset_a, who, &XYK_POOL, amount_a)?;
+		<Currency as MultiCurrency<AccountId>>::transfer(asset_b, who, &XYK_POOL, amount_b)
+	}
+
+	fn seed_omnipool(who: &AccountId, asset: AssetId, amount: Balance) -> DispatchResult {
+		if SEEDING_FAILS.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("seeding failed"));
+		}
+		<Currency as MultiCurrency<AccountId>>::transfer(asset, who, &OMNIPOOL, amount)
+	}
+
+	fn seed_xyk_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn seed_omnipool_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+parameter_types! {
+	pub const LBPPalletId: PalletId = PalletId(*b"py/lbpsl");
+}
+
 impl pallet_broadcast::Config for Test {}
 
 impl Config for Test {
@@ -185,6 +234,9 @@ impl Config for Test {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = System;
+	type ProceedsHandler = ProceedsHandlerMock;
+	type PalletId = LBPPalletId;
+	type AccountTradeVolumeHandler = ();
 }
 
 pub struct ExtBuilder {
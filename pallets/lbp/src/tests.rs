@@ -20,15 +20,15 @@
 
 use super::*;
 use crate::mock::{
-	expect_events, generate_trades, run_to_sale_end, run_to_sale_start, AccountId, RuntimeCall as Call, DEFAULT_FEE,
-	EXISTENTIAL_DEPOSIT, HDX_BSX_POOL_ID, INITIAL_BALANCE, INITIAL_ETH_BALANCE, KUSD_BSX_POOL_ID, SALE_END, SALE_START,
-	SAMPLE_AMM_TRANSFER, SAMPLE_POOL_DATA,
+	expect_events, generate_trades, run_to_sale_end, run_to_sale_start, set_seeding_fails, AccountId,
+	RuntimeCall as Call, DEFAULT_FEE, EXISTENTIAL_DEPOSIT, HDX_BSX_POOL_ID, INITIAL_BALANCE, INITIAL_ETH_BALANCE,
+	KUSD_BSX_POOL_ID, OMNIPOOL, SALE_END, SALE_START, SAMPLE_AMM_TRANSFER, SAMPLE_POOL_DATA, XYK_POOL,
 };
 pub use crate::mock::{
 	set_block_number, Currency, ExtBuilder, LBPPallet, RuntimeOrigin as Origin, Test, ALICE, BOB, BSX, CHARLIE, ETH,
 	HDX, KUSD,
 };
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok, traits::Hooks};
 use hydradx_traits::{AMMTransfer, LockedBalance};
 use pallet_broadcast::types::Destination;
 use sp_runtime::traits::{BadOrigin, Dispatchable};
@@ -359,6 +359,161 @@ fn calculate_weights_should_work() {
 	});
 }
 
+#[test]
+fn calculate_weights_should_follow_weight_curve() {
+	new_test_ext().execute_with(|| {
+		let mut pool_data = Pool {
+			owner: ALICE,
+			start: Some(100),
+			end: Some(200),
+			assets: (KUSD, BSX),
+			initial_weight: 10_000_000,
+			final_weight: 90_000_000,
+			weight_curve: WeightCurveType::Exponential,
+			fee: DEFAULT_FEE,
+			fee_collector: CHARLIE,
+			repay_target: 0,
+		};
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 100),
+			Ok((10_000_000, 90_000_000))
+		);
+		let expected =
+			hydra_dx_math::lbp::calculate_exponential_weights(100u64, 200, 10_000_000, 90_000_000, 150).unwrap();
+		// concave curve moves faster than the linear one at the beginning of the sale
+		assert!(expected > 50_000_000);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 150),
+			Ok((expected, MAX_WEIGHT - expected))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((90_000_000, 10_000_000))
+		);
+
+		pool_data.weight_curve = WeightCurveType::Logarithmic;
+		let expected =
+			hydra_dx_math::lbp::calculate_logarithmic_weights(100u64, 200, 10_000_000, 90_000_000, 150).unwrap();
+		assert!(expected > 50_000_000);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 150),
+			Ok((expected, MAX_WEIGHT - expected))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((90_000_000, 10_000_000))
+		);
+
+		pool_data.weight_curve = WeightCurveType::Steps(vec![(20, 30_000_000), (50, 60_000_000)].try_into().unwrap());
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 110),
+			Ok((10_000_000, 90_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 120),
+			Ok((30_000_000, 70_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 199),
+			Ok((60_000_000, 40_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((90_000_000, 10_000_000))
+		);
+
+		// out of bound
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 210),
+			Err(Error::<Test>::WeightCalculationError.into())
+		);
+	});
+}
+
+#[test]
+fn validate_pool_data_should_fail_when_weight_curve_steps_are_invalid() {
+	new_test_ext().execute_with(|| {
+		let mut pool_data = Pool {
+			owner: ALICE,
+			start: SALE_START,
+			end: SALE_END,
+			assets: (KUSD, BSX),
+			initial_weight: 20_000_000,
+			final_weight: 90_000_000,
+			weight_curve: WeightCurveType::Steps(vec![(10, 40_000_000), (20, 60_000_000)].try_into().unwrap()),
+			fee: DEFAULT_FEE,
+			fee_collector: CHARLIE,
+			repay_target: 0,
+		};
+		assert_ok!(LBPPallet::validate_pool_data(&pool_data));
+
+		// empty steps
+		pool_data.weight_curve = WeightCurveType::Steps(Default::default());
+		assert_noop!(
+			LBPPallet::validate_pool_data(&pool_data),
+			Error::<Test>::InvalidWeightCurve
+		);
+
+		// steps not ordered
+		pool_data.weight_curve = WeightCurveType::Steps(vec![(20, 40_000_000), (10, 60_000_000)].try_into().unwrap());
+		assert_noop!(
+			LBPPallet::validate_pool_data(&pool_data),
+			Error::<Test>::InvalidWeightCurve
+		);
+
+		// duplicated step
+		pool_data.weight_curve = WeightCurveType::Steps(vec![(10, 40_000_000), (10, 60_000_000)].try_into().unwrap());
+		assert_noop!(
+			LBPPallet::validate_pool_data(&pool_data),
+			Error::<Test>::InvalidWeightCurve
+		);
+
+		// step after the end of the sale
+		pool_data.weight_curve = WeightCurveType::Steps(vec![(10, 40_000_000), (30, 60_000_000)].try_into().unwrap());
+		assert_noop!(
+			LBPPallet::validate_pool_data(&pool_data),
+			Error::<Test>::InvalidWeightCurve
+		);
+
+		// offsets can't be checked without the sale interval
+		pool_data.start = None;
+		pool_data.end = None;
+		assert_ok!(LBPPallet::validate_pool_data(&pool_data));
+
+		// step weight out of bounds
+		pool_data.weight_curve = WeightCurveType::Steps(vec![(10, 40_000_000), (20, 1_000_000)].try_into().unwrap());
+		assert_noop!(LBPPallet::validate_pool_data(&pool_data), Error::<Test>::InvalidWeight);
+
+		pool_data.weight_curve = WeightCurveType::Steps(vec![(10, MAX_WEIGHT)].try_into().unwrap());
+		assert_noop!(LBPPallet::validate_pool_data(&pool_data), Error::<Test>::InvalidWeight);
+	});
+}
+
+#[test]
+fn create_pool_with_steps_weight_curve_should_work() {
+	new_test_ext().execute_with(|| {
+		let steps: WeightCurveSteps = vec![(10, 40_000_000), (20, 60_000_000)].try_into().unwrap();
+
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			KUSD,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			20_000_000u32,
+			90_000_000u32,
+			WeightCurveType::Steps(steps.clone()),
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+		));
+
+		let pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+		assert_eq!(pool_data.weight_curve, WeightCurveType::Steps(steps));
+	});
+}
+
 #[test]
 fn create_pool_should_work() {
 	new_test_ext().execute_with(|| {
@@ -1684,6 +1839,498 @@ fn remove_liquidity_by_non_owner_should_not_work() {
 	});
 }
 
+fn create_pool_with_finalization(finalization: SaleFinalization) {
+	assert_ok!(LBPPallet::create_pool(
+		Origin::root(),
+		ALICE,
+		KUSD,
+		1_000_000_000,
+		BSX,
+		2_000_000_000,
+		20_000_000,
+		80_000_000,
+		WeightCurveType::Linear,
+		DEFAULT_FEE,
+		CHARLIE,
+		0,
+	));
+
+	assert_ok!(LBPPallet::set_sale_finalization(
+		Origin::signed(ALICE),
+		KUSD_BSX_POOL_ID,
+		finalization,
+	));
+
+	assert_ok!(LBPPallet::update_pool_data(
+		Origin::signed(ALICE),
+		KUSD_BSX_POOL_ID,
+		None,
+		SALE_START,
+		SALE_END,
+		None,
+		None,
+		None,
+		None,
+		None,
+	));
+}
+
+#[test]
+fn set_sale_finalization_should_store_automatic_finalization() {
+	predefined_test_ext().execute_with(|| {
+		// Arrange
+		assert_eq!(<PoolFinalization<Test>>::get(KUSD_BSX_POOL_ID), None);
+
+		// Act
+		assert_ok!(LBPPallet::set_sale_finalization(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleFinalization::ReturnToOwner,
+		));
+
+		// Assert
+		assert_eq!(
+			<PoolFinalization<Test>>::get(KUSD_BSX_POOL_ID),
+			Some(SaleFinalization::ReturnToOwner)
+		);
+
+		expect_events(vec![Event::SaleFinalizationSet {
+			pool: KUSD_BSX_POOL_ID,
+			finalization: SaleFinalization::ReturnToOwner,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_sale_finalization_should_remove_automatic_finalization_when_set_to_manual() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		create_pool_with_finalization(SaleFinalization::SeedXyk);
+
+		// Act
+		assert_ok!(LBPPallet::set_sale_finalization(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleFinalization::Manual,
+		));
+
+		// Assert
+		assert!(!<PoolFinalization<Test>>::contains_key(KUSD_BSX_POOL_ID));
+	});
+}
+
+#[test]
+fn set_sale_finalization_should_fail_when_vesting_duration_is_zero() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::set_sale_finalization(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				SaleFinalization::VestProceeds { duration: 0 },
+			),
+			Error::<Test>::InvalidFinalization
+		);
+	});
+}
+
+#[test]
+fn set_sale_finalization_should_fail_when_called_by_non_owner() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::set_sale_finalization(Origin::signed(BOB), KUSD_BSX_POOL_ID, SaleFinalization::ReturnToOwner,),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn set_sale_finalization_should_fail_when_pool_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::set_sale_finalization(Origin::signed(ALICE), KUSD_BSX_POOL_ID, SaleFinalization::ReturnToOwner,),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn set_sale_finalization_should_fail_when_sale_started() {
+	predefined_test_ext().execute_with(|| {
+		run_to_sale_start();
+
+		assert_noop!(
+			LBPPallet::set_sale_finalization(Origin::signed(ALICE), KUSD_BSX_POOL_ID, SaleFinalization::ReturnToOwner,),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn sale_should_be_finalized_on_idle_when_sale_ended() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		create_pool_with_finalization(SaleFinalization::ReturnToOwner);
+
+		let user_balance_a_before = Currency::free_balance(KUSD, &ALICE);
+		let user_balance_b_before = Currency::free_balance(BSX, &ALICE);
+
+		// Act - the last block of the sale
+		set_block_number(SALE_END.unwrap());
+		LBPPallet::on_idle(SALE_END.unwrap(), Weight::MAX);
+
+		// Assert
+		assert!(<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+
+		// Act
+		run_to_sale_end();
+		LBPPallet::on_idle(SALE_END.unwrap() + 1, Weight::MAX);
+
+		// Assert
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(!<PoolFinalization<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(!<FeeCollectorWithAsset<Test>>::contains_key(CHARLIE, KUSD));
+
+		assert_eq!(
+			Currency::free_balance(KUSD, &ALICE),
+			user_balance_a_before + 1_000_000_000
+		);
+		assert_eq!(
+			Currency::free_balance(BSX, &ALICE),
+			user_balance_b_before + 2_000_000_000
+		);
+
+		expect_events(vec![
+			Event::LiquidityRemoved {
+				who: KUSD_BSX_POOL_ID,
+				asset_a: KUSD,
+				asset_b: BSX,
+				amount_a: 1_000_000_000,
+				amount_b: 2_000_000_000,
+			}
+			.into(),
+			Event::SaleFinalized {
+				pool: KUSD_BSX_POOL_ID,
+				finalization: SaleFinalization::ReturnToOwner,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn on_idle_should_not_finalize_sale_when_weight_is_insufficient() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		create_pool_with_finalization(SaleFinalization::ReturnToOwner);
+		run_to_sale_end();
+
+		// Act
+		let used_weight = LBPPallet::on_idle(SALE_END.unwrap() + 1, Weight::zero());
+
+		// Assert
+		assert_eq!(used_weight, Weight::zero());
+		assert!(<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(<PoolFinalization<Test>>::contains_key(KUSD_BSX_POOL_ID));
+	});
+}
+
+#[test]
+fn on_idle_should_not_finalize_sale_with_manual_finalization() {
+	predefined_test_ext().execute_with(|| {
+		// Arrange
+		run_to_sale_end();
+
+		// Act
+		LBPPallet::on_idle(SALE_END.unwrap() + 1, Weight::MAX);
+
+		// Assert
+		assert!(<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+	});
+}
+
+#[test]
+fn finalize_sale_should_work_when_called_by_anyone() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		create_pool_with_finalization(SaleFinalization::ReturnToOwner);
+		run_to_sale_end();
+
+		// Act
+		assert_ok!(LBPPallet::finalize_sale(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+
+		// Assert
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(!<PoolFinalization<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert_eq!(Currency::free_balance(KUSD, &BOB), INITIAL_BALANCE);
+		assert_eq!(Currency::free_balance(BSX, &BOB), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn finalize_sale_should_not_work_when_sale_is_running() {
+	new_test_ext().execute_with(|| {
+		create_pool_with_finalization(SaleFinalization::ReturnToOwner);
+
+		assert_noop!(
+			LBPPallet::finalize_sale(Origin::signed(BOB), KUSD_BSX_POOL_ID),
+			Error::<Test>::SaleNotEnded
+		);
+
+		run_to_sale_start();
+
+		assert_noop!(
+			LBPPallet::finalize_sale(Origin::signed(BOB), KUSD_BSX_POOL_ID),
+			Error::<Test>::SaleNotEnded
+		);
+	});
+}
+
+#[test]
+fn finalize_sale_should_not_work_when_finalization_is_manual() {
+	predefined_test_ext().execute_with(|| {
+		run_to_sale_end();
+
+		assert_noop!(
+			LBPPallet::finalize_sale(Origin::signed(ALICE), KUSD_BSX_POOL_ID),
+			Error::<Test>::AutomaticFinalizationNotSet
+		);
+	});
+}
+
+#[test]
+fn finalize_sale_should_seed_xyk_pool_at_final_price() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		create_pool_with_finalization(SaleFinalization::SeedXyk);
+		run_to_sale_end();
+
+		let user_balance_a_before = Currency::free_balance(KUSD, &ALICE);
+		let user_balance_b_before = Currency::free_balance(BSX, &ALICE);
+
+		// Act
+		assert_ok!(LBPPallet::finalize_sale(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+
+		// Assert
+		// final weights are 80/20, spot price of BSX is (1_000_000_000 / 0.8) / (2_000_000_000 / 0.2) = 0.125
+		assert_eq!(Currency::free_balance(KUSD, &XYK_POOL), 250_000_000);
+		assert_eq!(Currency::free_balance(BSX, &XYK_POOL), 2_000_000_000);
+
+		assert_eq!(
+			Currency::free_balance(KUSD, &ALICE),
+			user_balance_a_before + 750_000_000
+		);
+		assert_eq!(Currency::free_balance(BSX, &ALICE), user_balance_b_before);
+
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+	});
+}
+
+#[test]
+fn finalize_sale_should_seed_omnipool_with_accumulated_asset() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		create_pool_with_finalization(SaleFinalization::SeedOmnipool);
+		run_to_sale_end();
+
+		let user_balance_a_before = Currency::free_balance(KUSD, &ALICE);
+		let user_balance_b_before = Currency::free_balance(BSX, &ALICE);
+
+		// Act
+		assert_ok!(LBPPallet::finalize_sale(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+
+		// Assert
+		assert_eq!(Currency::free_balance(KUSD, &OMNIPOOL), 1_000_000_000);
+		assert_eq!(Currency::free_balance(BSX, &OMNIPOOL), 0);
+
+		assert_eq!(Currency::free_balance(KUSD, &ALICE), user_balance_a_before);
+		assert_eq!(
+			Currency::free_balance(BSX, &ALICE),
+			user_balance_b_before + 2_000_000_000
+		);
+	});
+}
+
+#[test]
+fn failed_finalization_should_leave_pool_to_the_owner() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		create_pool_with_finalization(SaleFinalization::SeedXyk);
+		run_to_sale_end();
+		set_seeding_fails(true);
+
+		assert_noop!(
+			LBPPallet::finalize_sale(Origin::signed(BOB), KUSD_BSX_POOL_ID),
+			DispatchError::Other("seeding failed")
+		);
+
+		// Act
+		LBPPallet::on_idle(SALE_END.unwrap() + 1, Weight::MAX);
+
+		// Assert
+		assert!(<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(!<PoolFinalization<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 1_000_000_000);
+		assert_eq!(Currency::free_balance(BSX, &KUSD_BSX_POOL_ID), 2_000_000_000);
+
+		expect_events(vec![Event::SaleFinalizationFailed {
+			pool: KUSD_BSX_POOL_ID,
+			error: DispatchError::Other("seeding failed"),
+		}
+		.into()]);
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+	});
+}
+
+#[test]
+fn remove_liquidity_should_remove_automatic_finalization() {
+	new_test_ext().execute_with(|| {
+		create_pool_with_finalization(SaleFinalization::ReturnToOwner);
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert!(!<PoolFinalization<Test>>::contains_key(KUSD_BSX_POOL_ID));
+	});
+}
+
+#[test]
+fn finalize_sale_should_vest_proceeds_to_the_owner() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		create_pool_with_finalization(SaleFinalization::VestProceeds { duration: 100 });
+		run_to_sale_end();
+
+		let user_balance_a_before = Currency::free_balance(KUSD, &ALICE);
+		let user_balance_b_before = Currency::free_balance(BSX, &ALICE);
+
+		// Act
+		assert_ok!(LBPPallet::finalize_sale(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+
+		// Assert
+		assert_eq!(
+			<VestedProceeds<Test>>::get(KUSD_BSX_POOL_ID),
+			Some(ProceedsVesting {
+				owner: ALICE,
+				asset: KUSD,
+				total: 1_000_000_000,
+				claimed: 0,
+				start: 40,
+				end: 140,
+			})
+		);
+		assert_eq!(
+			Currency::free_balance(KUSD, &LBPPallet::pallet_account()),
+			1_000_000_000
+		);
+		assert_eq!(Currency::free_balance(KUSD, &ALICE), user_balance_a_before);
+		assert_eq!(
+			Currency::free_balance(BSX, &ALICE),
+			user_balance_b_before + 2_000_000_000
+		);
+	});
+}
+
+#[test]
+fn claim_proceeds_should_transfer_vested_proceeds() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		create_pool_with_finalization(SaleFinalization::VestProceeds { duration: 100 });
+		run_to_sale_end();
+		assert_ok!(LBPPallet::finalize_sale(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+
+		let user_balance_before = Currency::free_balance(KUSD, &ALICE);
+
+		// Act
+		set_block_number(90);
+		assert_ok!(LBPPallet::claim_proceeds(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		// Assert
+		assert_eq!(Currency::free_balance(KUSD, &ALICE), user_balance_before + 500_000_000);
+		assert_eq!(
+			<VestedProceeds<Test>>::get(KUSD_BSX_POOL_ID).unwrap().claimed,
+			500_000_000
+		);
+		expect_events(vec![Event::ProceedsClaimed {
+			pool: KUSD_BSX_POOL_ID,
+			who: ALICE,
+			asset: KUSD,
+			amount: 500_000_000,
+		}
+		.into()]);
+
+		assert_noop!(
+			LBPPallet::claim_proceeds(Origin::signed(ALICE), KUSD_BSX_POOL_ID),
+			Error::<Test>::NothingToClaim
+		);
+
+		// Act
+		set_block_number(200);
+		assert_ok!(LBPPallet::claim_proceeds(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		// Assert
+		assert_eq!(
+			Currency::free_balance(KUSD, &ALICE),
+			user_balance_before + 1_000_000_000
+		);
+		assert!(!<VestedProceeds<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert_eq!(Currency::free_balance(KUSD, &LBPPallet::pallet_account()), 0);
+	});
+}
+
+#[test]
+fn claim_proceeds_should_not_work_when_not_owner() {
+	new_test_ext().execute_with(|| {
+		create_pool_with_finalization(SaleFinalization::VestProceeds { duration: 100 });
+		run_to_sale_end();
+		assert_ok!(LBPPallet::finalize_sale(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+
+		set_block_number(90);
+
+		assert_noop!(
+			LBPPallet::claim_proceeds(Origin::signed(BOB), KUSD_BSX_POOL_ID),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			LBPPallet::claim_proceeds(Origin::signed(ALICE), HDX_BSX_POOL_ID),
+			Error::<Test>::ProceedsVestingNotFound
+		);
+	});
+}
+
+#[test]
+fn set_sale_finalization_should_not_work_when_proceeds_of_previous_sale_are_vesting() {
+	new_test_ext().execute_with(|| {
+		create_pool_with_finalization(SaleFinalization::VestProceeds { duration: 100 });
+		run_to_sale_end();
+		assert_ok!(LBPPallet::finalize_sale(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			KUSD,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			20_000_000,
+			80_000_000,
+			WeightCurveType::Linear,
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+		));
+
+		assert_noop!(
+			LBPPallet::set_sale_finalization(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				SaleFinalization::VestProceeds { duration: 100 },
+			),
+			Error::<Test>::ProceedsStillVesting
+		);
+	});
+}
+
 #[test]
 fn execute_trade_should_work() {
 	predefined_test_ext().execute_with(|| {
@@ -1,5 +1,5 @@
 use crate::*;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, Trade, TradeExecution, TradeablePoolsProvider};
 use hydradx_traits::AMM;
 use orml_traits::MultiCurrency;
 use sp_runtime::traits::BlockNumberProvider;
@@ -197,3 +197,26 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 		Ok(spot_price_with_fee)
 	}
 }
+
+impl<T: Config> TradeablePoolsProvider<AssetId> for Pallet<T> {
+	fn tradeable_pools() -> Vec<Trade<AssetId>> {
+		<PoolData<T>>::iter_values()
+			.filter(|pool_data| Self::is_pool_running(pool_data))
+			.flat_map(|pool_data| {
+				let (asset_a, asset_b) = pool_data.assets;
+				[
+					Trade {
+						pool: PoolType::LBP,
+						asset_in: asset_a,
+						asset_out: asset_b,
+					},
+					Trade {
+						pool: PoolType::LBP,
+						asset_in: asset_b,
+						asset_out: asset_a,
+					},
+				]
+			})
+			.collect()
+	}
+}
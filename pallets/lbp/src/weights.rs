@@ -21,6 +21,9 @@ pub trait WeightInfo {
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
 	fn calculate_in_given_out() -> Weight;
 	fn calculate_spot_price_with_fee() -> Weight;
+	fn finalize_sale() -> Weight;
+	fn claim_proceeds() -> Weight;
+	fn set_sale_finalization() -> Weight;
 }
 
 /// Weights for pallet_lbp using the hydraDX node and recommended hardware.
@@ -265,4 +268,53 @@ impl WeightInfo for () {
 		Weight::from_parts(43_263_000, 6190)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 	}
+	/// Storage: `LBP::PoolFinalization` (r:1 w:1)
+	/// Proof: `LBP::PoolFinalization` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolData` (r:1 w:1)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:1 w:0)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::VestedProceeds` (r:0 w:1)
+	/// Proof: `LBP::VestedProceeds` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::FeeCollectorWithAsset` (r:0 w:1)
+	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn finalize_sale() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1832`
+		//  Estimated: `11322`
+		// Minimum execution time: 192_417_000 picoseconds.
+		Weight::from_parts(194_310_000, 11322)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `LBP::VestedProceeds` (r:1 w:1)
+	/// Proof: `LBP::VestedProceeds` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_proceeds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1204`
+		//  Estimated: `6196`
+		// Minimum execution time: 96_318_000 picoseconds.
+		Weight::from_parts(97_102_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::VestedProceeds` (r:1 w:0)
+	/// Proof: `LBP::VestedProceeds` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolFinalization` (r:0 w:1)
+	/// Proof: `LBP::PoolFinalization` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn set_sale_finalization() -> Weight {
+		Weight::from_parts(22_000_000, 3628)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,77 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the liquidity mining pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+pub use hydradx_traits::liquidity_mining::{DepositId, GlobalFarmId, YieldFarmId};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::FixedU128;
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Liquidity mining instance the farms belong to.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum FarmKind {
+	Omnipool,
+	Xyk,
+}
+
+/// Rewards of the deposit's yield farm entry if claimed in the current period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct YieldFarmEntryRewards<AssetId, Balance> {
+	pub global_farm_id: GlobalFarmId,
+	pub yield_farm_id: YieldFarmId,
+	pub reward_currency: AssetId,
+	/// Rewards in `reward_currency` claimable in the current period.
+	pub claimable_rewards: Balance,
+	/// Rewards in `reward_currency` not yet unlocked by the loyalty multiplier.
+	pub unclaimable_rewards: Balance,
+	pub loyalty_multiplier: FixedU128,
+	/// Estimated APR of the yield farm. Zero if the yield farm is not active.
+	pub apr: FixedU128,
+	/// Claimable rewards from the global farm's reward streams.
+	pub reward_streams: Vec<(AssetId, Balance)>,
+}
+
+/// Estimated APR of an active yield farm.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct YieldFarmApr<AssetId> {
+	pub global_farm_id: GlobalFarmId,
+	pub yield_farm_id: YieldFarmId,
+	pub reward_currency: AssetId,
+	pub apr: FixedU128,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query liquidity mining rewards and yield farms' APR.
+	pub trait LiquidityMiningApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Return rewards of all deposit's yield farm entries as if they were claimed in the current period.
+		fn deposit_rewards(kind: FarmKind, deposit_id: DepositId) -> Result<Vec<YieldFarmEntryRewards<AssetId, Balance>>, DispatchError>;
+
+		/// Return estimated APR of all active yield farms.
+		fn yield_farms_apr(kind: FarmKind) -> Vec<YieldFarmApr<AssetId>>;
+	}
+}
@@ -52,6 +52,16 @@
 //! LP shares can be redeposited only to different yield farms running liquidity mining for same
 //! pair of assets.
 //!
+//! Reward Streams
+//!
+//! Global farm's owner can add additional reward currencies("reward streams") to the global farm.
+//! Each reward stream has its own budget and it distributes rewards only in the given range of
+//! periods. Rewards are distributed based on the same stakes in the global farm as global farm's
+//! `reward_currency` either by yield per period or by streaming fixed total rewards evenly over
+//! the stream's range. Deposits don't need to be redeposited to receive rewards from reward
+//! streams.
+//! Max. number of reward streams in the global farm is `MAX_REWARD_STREAMS_PER_GLOBAL_FARM`.
+//!
 //! Notes:
 //! * LP shares are returned ONLY if deposit is destroyed - withdrawing LP shares can
 //!   be used to "free slot" for re-lock LP shares to different yield farm. Withdrawing LP shares result in
@@ -96,8 +106,9 @@ mod types;
 pub use pallet::*;
 
 pub use crate::types::{
-	Balance, DefaultPriceAdjustment, DepositData, DepositId, FarmId, FarmMultiplier, FarmState, GlobalFarmData,
-	GlobalFarmId, LoyaltyCurve, YieldFarmData, YieldFarmEntry, YieldFarmId,
+	Balance, DefaultPriceAdjustment, DepositData, DepositId, EntryRewards, EntryStreamData, FarmId, FarmMultiplier,
+	FarmState, GlobalFarmData, GlobalFarmId, LoyaltyCurve, RewardSchedule, RewardStreamData, YieldFarmApr,
+	YieldFarmData, YieldFarmEntry, YieldFarmId, YieldFarmStreamData,
 };
 use codec::{Decode, DecodeWithMemTracking, Encode, FullCodec};
 use frame_support::{
@@ -106,8 +117,9 @@ use frame_support::{
 	require_transactional,
 	sp_runtime::{
 		traits::{AccountIdConversion, BlockNumberProvider, MaybeSerializeDeserialize, One, Zero},
-		RuntimeDebug,
+		RuntimeDebug, TransactionOutcome,
 	},
+	storage::with_transaction,
 	traits::{Defensive, DefensiveOption, ExistenceRequirement},
 	PalletId,
 };
@@ -138,6 +150,8 @@ type PeriodOf<T> = BlockNumberFor<T>;
 pub(crate) const MIN_DEPOSIT: Balance = 1_000;
 /// Min value farm's owner can set as yield-farm's `multiplier`
 pub(crate) const MIN_YIELD_FARM_MULTIPLIER: FixedU128 = FixedU128::from_inner(1_000_000_000_000_000);
+/// Max number of additional reward streams in the global farm.
+pub const MAX_REWARD_STREAMS_PER_GLOBAL_FARM: u32 = 3;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -314,6 +328,22 @@ pub mod pallet {
 		/// Provided `amm_pool_id` doesn't match deposit's `amm_pool_id`.
 		AmmPoolIdMismatch,
 
+		/// Reward stream with given reward currency already exists in the global farm.
+		RewardStreamAlreadyExists,
+
+		/// Max number of reward streams in the global farm was reached.
+		MaxRewardStreamsReached,
+
+		/// Reward stream's currency can't be the same as global farm's `reward_currency`.
+		InvalidRewardStreamCurrency,
+
+		/// Reward stream's range is not valid. Stream can't start in the past and it has to last at
+		/// least one period.
+		InvalidRewardStreamRange,
+
+		/// Deposit does not exist.
+		DepositNotFound,
+
 		/// Action cannot be completed because unexpected error has occurred. This should be reported
 		/// to protocol maintainers.
 		InconsistentState(InconsistentStateError),
@@ -416,6 +446,46 @@ pub mod pallet {
 	pub type Deposit<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, DepositId, DepositData<T, I>, OptionQuery>;
 
+	/// Additional reward streams of the global farm.
+	#[pallet::storage]
+	#[pallet::getter(fn reward_stream)]
+	pub type RewardStream<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		GlobalFarmId,
+		Blake2_128Concat,
+		T::AssetId,
+		RewardStreamData<T, I>,
+		OptionQuery,
+	>;
+
+	/// State of the global farm's reward streams in the yield farm.
+	#[pallet::storage]
+	#[pallet::getter(fn yield_farm_reward_stream)]
+	pub type YieldFarmRewardStream<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		YieldFarmId,
+		Blake2_128Concat,
+		T::AssetId,
+		YieldFarmStreamData,
+		ValueQuery,
+	>;
+
+	/// State of the reward streams in the deposit's yield farm entries.
+	#[pallet::storage]
+	#[pallet::getter(fn entry_reward_stream)]
+	pub type EntryRewardStream<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, DepositId>,
+			NMapKey<Blake2_128Concat, YieldFarmId>,
+			NMapKey<Blake2_128Concat, T::AssetId>,
+		),
+		EntryStreamData,
+		ValueQuery,
+	>;
+
 	/// Active(farms able to receive LP shares deposits) yield farms.
 	#[pallet::storage]
 	#[pallet::getter(fn active_yield_farm)]
@@ -442,6 +512,14 @@ pub mod pallet {
 
 		/// Global farm has no more rewards to distribute in the moment.
 		AllRewardsDistributed { global_farm_id: GlobalFarmId },
+
+		/// Reward stream's accumulated reward per share was updated.
+		RewardStreamAccRPZUpdated {
+			global_farm_id: GlobalFarmId,
+			reward_currency: T::AssetId,
+			accumulated_rpz: FixedU128,
+			total_shares_z: Balance,
+		},
 	}
 
 	#[pallet::call]
@@ -627,6 +705,124 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Add additional reward currency(reward stream) to the existing global farm.
+	///
+	/// Reward stream distributes `total_rewards` of `reward_currency` between all yield farms in
+	/// the global farm alongside global farm's `reward_currency`. Users don't have to do anything
+	/// to receive rewards from reward streams, rewards are claimable for all existing and future
+	/// deposits in the global farm.
+	///
+	/// `owner` account has to have at least `total_rewards` balance. These funds will be
+	/// transferred from `owner` to farm account.
+	///
+	/// Only farm's owner can perform this action.
+	///
+	/// Parameters:
+	/// - `who`: farm's owner.
+	/// - `global_farm_id`: global farm id.
+	/// - `reward_currency`: payoff currency of the reward stream. It has to be different from
+	/// global farm's `reward_currency` and other reward streams' currencies.
+	/// - `total_rewards`: total rewards of the reward stream.
+	/// - `start`: block number from which reward stream starts to distribute rewards.
+	/// - `end`: block number in which reward stream stops to distribute rewards.
+	/// - `schedule`: schedule used to distribute rewards.
+	#[allow(clippy::too_many_arguments)]
+	#[require_transactional]
+	fn add_reward_stream(
+		who: T::AccountId,
+		global_farm_id: GlobalFarmId,
+		reward_currency: T::AssetId,
+		total_rewards: Balance,
+		start: BlockNumberFor<T>,
+		end: BlockNumberFor<T>,
+		schedule: RewardSchedule,
+	) -> Result<(), DispatchError> {
+		ensure!(!total_rewards.is_zero(), Error::<T, I>::InvalidTotalRewards);
+		if let RewardSchedule::Yield {
+			yield_per_period,
+			price_adjustment,
+		} = schedule
+		{
+			ensure!(!yield_per_period.is_zero(), Error::<T, I>::InvalidYieldPerPeriod);
+			ensure!(!price_adjustment.is_zero(), Error::<T, I>::InvalidPriceAdjustment);
+		}
+
+		ensure!(
+			T::AssetRegistry::exists(reward_currency),
+			Error::<T, I>::RewardCurrencyNotRegistered
+		);
+
+		<GlobalFarm<T, I>>::try_mutate(global_farm_id, |maybe_global_farm| {
+			let global_farm = maybe_global_farm.as_mut().ok_or(Error::<T, I>::GlobalFarmNotFound)?;
+
+			ensure!(global_farm.state.is_active(), Error::<T, I>::GlobalFarmNotFound);
+
+			ensure!(who == global_farm.owner, Error::<T, I>::Forbidden);
+
+			ensure!(
+				reward_currency != global_farm.reward_currency,
+				Error::<T, I>::InvalidRewardStreamCurrency
+			);
+
+			ensure!(
+				!<RewardStream<T, I>>::contains_key(global_farm_id, reward_currency),
+				Error::<T, I>::RewardStreamAlreadyExists
+			);
+
+			ensure!(
+				(<RewardStream<T, I>>::iter_prefix(global_farm_id).count() as u32) < MAX_REWARD_STREAMS_PER_GLOBAL_FARM,
+				Error::<T, I>::MaxRewardStreamsReached
+			);
+
+			ensure!(
+				start >= T::BlockNumberProvider::current_block_number(),
+				Error::<T, I>::InvalidRewardStreamRange
+			);
+
+			let start_period = Self::get_period_number(start, global_farm.blocks_per_period)?;
+			let end_period = Self::get_period_number(end, global_farm.blocks_per_period)?;
+			ensure!(end_period > start_period, Error::<T, I>::InvalidRewardStreamRange);
+
+			T::MultiCurrency::ensure_can_withdraw(reward_currency, &who, total_rewards)
+				.map_err(|_| Error::<T, I>::InsufficientRewardCurrencyBalance)?;
+
+			let current_period = Self::get_current_period(global_farm.blocks_per_period)?;
+			Self::sync_global_farm(global_farm, current_period)?;
+
+			//NOTE: this can't be 0, `end_period` > `start_period` is checked above.
+			let planned_periods = TryInto::<u128>::try_into(
+				end_period
+					.checked_sub(&start_period)
+					.ok_or(Error::<T, I>::InvalidRewardStreamRange)?,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+			let max_reward_per_period = total_rewards
+				.checked_div(planned_periods)
+				.ok_or(ArithmeticError::DivisionByZero)?;
+
+			let reward_stream = RewardStreamData::new(
+				schedule,
+				start_period,
+				end_period,
+				current_period,
+				max_reward_per_period,
+				total_rewards,
+			);
+
+			<RewardStream<T, I>>::insert(global_farm_id, reward_currency, reward_stream);
+
+			T::MultiCurrency::transfer(
+				reward_currency,
+				&who,
+				&Self::farm_account_id(global_farm_id)?,
+				total_rewards,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Ok(())
+		})
+	}
+
 	/// Terminate existing liquidity mining program. Undistributed rewards are transferred to
 	/// owner(`who`).
 	///
@@ -666,6 +862,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				ExistenceRequirement::AllowDeath,
 			)?;
 
+			for (reward_currency, reward_stream) in <RewardStream<T, I>>::drain_prefix(global_farm.id) {
+				T::MultiCurrency::transfer(
+					reward_currency,
+					&global_farm_account,
+					&who,
+					reward_stream.left_to_distribute,
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
+
 			//Mark for removal from storage on last `YieldFarm` in the farm removed.
 			global_farm.state = FarmState::Terminated;
 
@@ -751,6 +957,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					<YieldFarm<T, I>>::insert((amm_pool_id, global_farm_id, yield_farm_id), yield_farm);
 					global_farm.increase_yield_farm_counts()?;
 
+					for (reward_currency, reward_stream) in Self::reward_streams(global_farm_id) {
+						<YieldFarmRewardStream<T, I>>::insert(
+							yield_farm_id,
+							reward_currency,
+							YieldFarmStreamData {
+								accumulated_rpz: reward_stream.accumulated_rpz,
+								..Default::default()
+							},
+						);
+					}
+
 					*maybe_active_yield_farm = Some(yield_farm_id);
 
 					Ok(yield_farm_id)
@@ -973,6 +1190,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					yield_farm.accumulated_rpz = global_farm.accumulated_rpz;
 					yield_farm.updated_at = current_period;
 					yield_farm.state = FarmState::Active;
+
+					for (reward_currency, reward_stream) in Self::reward_streams(global_farm_id) {
+						<YieldFarmRewardStream<T, I>>::mutate(yield_farm.id, reward_currency, |yield_farm_stream| {
+							yield_farm_stream.accumulated_rpz = reward_stream.accumulated_rpz;
+						});
+					}
+
 					yield_farm.multiplier = multiplier;
 					yield_farm.total_stopped = yield_farm
 						.total_stopped
@@ -1049,6 +1273,24 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					)?;
 
 					yield_farm.left_to_distribute = Zero::zero();
+
+					//Transfer yield-farm's unpaid rewards from reward streams back to global farm.
+					for (reward_currency, mut reward_stream) in Self::reward_streams(global_farm.id) {
+						let yield_farm_stream = <YieldFarmRewardStream<T, I>>::take(yield_farm.id, reward_currency);
+						if yield_farm_stream.left_to_distribute.is_zero() {
+							continue;
+						}
+
+						Self::return_reward_stream_rewards(
+							&mut reward_stream,
+							reward_currency,
+							yield_farm_stream.left_to_distribute,
+							&pot,
+							&global_farm_account,
+						)?;
+
+						<RewardStream<T, I>>::insert(global_farm.id, reward_currency, reward_stream);
+					}
 					//Delete yield farm.
 					yield_farm.state = FarmState::Terminated;
 					global_farm.decrease_live_yield_farm_count()?;
@@ -1102,6 +1344,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let deposit_id = Self::get_next_deposit_id()?;
 		<Deposit<T, I>>::insert(deposit_id, deposit);
 
+		Self::init_entry_reward_streams(deposit_id, global_farm_id, yield_farm_id);
+
 		Ok(deposit_id)
 	}
 
@@ -1132,6 +1376,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				.defensive_ok_or::<Error<T, I>>(InconsistentStateError::DepositNotFound.into())?;
 
 			Self::do_deposit_lp_shares(deposit, global_farm_id, yield_farm_id, get_token_value_of_lp_shares)?;
+			Self::init_entry_reward_streams(deposit_id, global_farm_id, yield_farm_id);
 
 			Ok((deposit.shares, deposit.amm_pool_id.clone()))
 		})
@@ -1204,25 +1449,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						Self::sync_global_farm(global_farm, current_period)?;
 						Self::sync_yield_farm(yield_farm, global_farm, current_period)?;
 
-						//NOTE: this should never fail yield-farm's stopped must be >= entry's
-						//stopped
-						let delta_stopped =
-							yield_farm
-								.total_stopped
-								.checked_sub(&farm_entry.stopped_at_creation)
-								.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
-
-						//NOTE: yield-farm's `updated_at` is updated to current period if it's
-						//possible so this should be ok.
-						let periods = yield_farm
-							.updated_at
-							.checked_sub(&farm_entry.entered_at)
-							.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?
-							.checked_sub(&delta_stopped)
-							.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
-
-						let loyalty_multiplier =
-							Self::get_loyalty_multiplier(periods, yield_farm.loyalty_curve.clone())?;
+						let loyalty_multiplier = Self::get_entry_loyalty_multiplier(farm_entry, yield_farm)?;
 
 						let (rewards, unclaimable_rewards) = math::calculate_user_reward(
 							farm_entry.accumulated_rpvs,
@@ -1293,6 +1520,131 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Claim rewards from global farm's reward streams for given deposit.
+	///
+	/// This function calculate user rewards from all reward streams of the global farm and
+	/// transfer rewards to `who` account. Rewards from reward streams are subject to the same
+	/// loyalty multiplier as rewards in global farm's `reward_currency`.
+	///
+	/// WARN: User have to use `withdraw_shares()` if yield farm is terminated.
+	///
+	/// Returns: `(reward currency, claimed amount)` for each reward stream with non-zero
+	/// claimed amount.
+	///
+	/// Parameters:
+	/// - `who`: destination account to receive rewards.
+	/// - `deposit_id`: id representing deposit in the yield farm.
+	/// - `yield_farm_id`: identifier of yield farm to claim from.
+	#[require_transactional]
+	fn claim_reward_streams(
+		who: T::AccountId,
+		deposit_id: DepositId,
+		yield_farm_id: YieldFarmId,
+	) -> Result<Vec<(T::AssetId, Balance)>, DispatchError> {
+		//NOTE: At this point deposit existence and owner must be checked by pallet calling this
+		//function so this should never happen.
+		let mut deposit =
+			Self::deposit(deposit_id).defensive_ok_or::<Error<T, I>>(InconsistentStateError::DepositNotFound.into())?;
+
+		let farm_entry = deposit
+			.get_yield_farm_entry(yield_farm_id)
+			.ok_or(Error::<T, I>::YieldFarmEntryNotFound)?
+			.clone();
+
+		<YieldFarm<T, I>>::try_mutate(
+			(deposit.amm_pool_id, farm_entry.global_farm_id, yield_farm_id),
+			|maybe_yield_farm| {
+				//NOTE: yield-farm must exist if yield-farm-entry exists.
+				let yield_farm = maybe_yield_farm
+					.as_mut()
+					.defensive_ok_or::<Error<T, I>>(InconsistentStateError::YieldFarmNotFound.into())?;
+
+				ensure!(
+					!yield_farm.state.is_terminated(),
+					Error::<T, I>::LiquidityMiningCanceled
+				);
+
+				<GlobalFarm<T, I>>::try_mutate(farm_entry.global_farm_id, |maybe_global_farm| {
+					//NOTE: global-farm must exist if yield-farm exists.
+					let global_farm = maybe_global_farm
+						.as_mut()
+						.defensive_ok_or::<Error<T, I>>(InconsistentStateError::GlobalFarmNotFound.into())?;
+
+					let current_period = Self::get_current_period(global_farm.blocks_per_period)?;
+					Self::sync_global_farm(global_farm, current_period)?;
+					Self::sync_yield_farm(yield_farm, global_farm, current_period)?;
+
+					let loyalty_multiplier = Self::get_entry_loyalty_multiplier(&farm_entry, yield_farm)?;
+					let pot = Self::pot_account_id().ok_or(Error::<T, I>::ErrorGetAccountId)?;
+
+					let mut claimed = Vec::new();
+					for (reward_currency, _) in Self::reward_streams(global_farm.id) {
+						let mut entry_stream =
+							<EntryRewardStream<T, I>>::get((deposit_id, yield_farm_id, reward_currency));
+						let mut yield_farm_stream = <YieldFarmRewardStream<T, I>>::get(yield_farm_id, reward_currency);
+
+						let (rewards, _) = math::calculate_user_reward(
+							entry_stream.accumulated_rpvs,
+							farm_entry.valued_shares,
+							entry_stream.accumulated_claimed_rewards,
+							yield_farm_stream.accumulated_rpvs,
+							loyalty_multiplier,
+						)
+						.map_err(|_| ArithmeticError::Overflow)?;
+
+						if rewards.is_zero() {
+							continue;
+						}
+
+						yield_farm_stream.left_to_distribute = yield_farm_stream
+							.left_to_distribute
+							.checked_sub(rewards)
+							.defensive_ok_or::<Error<T, I>>(
+								InconsistentStateError::NotEnoughRewardsInYieldFarm.into(),
+							)?;
+
+						entry_stream.accumulated_claimed_rewards = entry_stream
+							.accumulated_claimed_rewards
+							.checked_add(rewards)
+							.ok_or(ArithmeticError::Overflow)?;
+
+						//In case of low rewards and insufficient balance, we send rewards to treasury to prevent ED error
+						let ed = T::AssetRegistry::existential_deposit(reward_currency).ok_or(
+							Error::<T, I>::InconsistentState(InconsistentStateError::NoExistentialDepositForAsset),
+						)?;
+						let should_send_reward_to_treasury =
+							rewards < ed && T::MultiCurrency::free_balance(reward_currency, &who) < ed;
+
+						if should_send_reward_to_treasury {
+							T::MultiCurrency::transfer(
+								reward_currency,
+								&pot,
+								&T::TreasuryAccountId::get(),
+								rewards,
+								ExistenceRequirement::AllowDeath,
+							)?;
+						} else {
+							T::MultiCurrency::transfer(
+								reward_currency,
+								&pot,
+								&who,
+								rewards,
+								ExistenceRequirement::AllowDeath,
+							)?;
+
+							claimed.push((reward_currency, rewards));
+						}
+
+						<EntryRewardStream<T, I>>::insert((deposit_id, yield_farm_id, reward_currency), entry_stream);
+						<YieldFarmRewardStream<T, I>>::insert(yield_farm_id, reward_currency, yield_farm_stream);
+					}
+
+					Ok(claimed)
+				})
+			},
+		)
+	}
+
 	/// Withdraw LP shares from yield farm. This function can be used to free slot for yield
 	/// farm entry in the deposit or to destroy deposit and return LP shares if deposit has no more
 	/// farm entries.
@@ -1401,6 +1753,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 								)?;
 							}
 
+							Self::withdraw_entry_reward_streams(deposit_id, &farm_entry, yield_farm)?;
+
 							yield_farm.decrease_entries_count()?;
 							if yield_farm.can_be_removed() {
 								global_farm.decrease_total_yield_farm_count()?;
@@ -1519,6 +1873,116 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		)
 	}
 
+	/// This function saves the state of global farm's reward streams for a new yield farm entry,
+	/// so rewards distributed before the entry was created are not claimable by the entry.
+	fn init_entry_reward_streams(deposit_id: DepositId, global_farm_id: GlobalFarmId, yield_farm_id: YieldFarmId) {
+		for (reward_currency, _) in Self::reward_streams(global_farm_id) {
+			let accumulated_rpvs = <YieldFarmRewardStream<T, I>>::get(yield_farm_id, reward_currency).accumulated_rpvs;
+
+			if accumulated_rpvs.is_zero() {
+				continue;
+			}
+
+			<EntryRewardStream<T, I>>::insert(
+				(deposit_id, yield_farm_id, reward_currency),
+				EntryStreamData {
+					accumulated_rpvs,
+					accumulated_claimed_rewards: Zero::zero(),
+				},
+			);
+		}
+	}
+
+	/// This function transfers unpaid rewards of the yield farm entry from reward streams back to
+	/// the global farm and removes entry's reward streams state.
+	///
+	/// WARN: unpaid rewards include claimable rewards, reward streams must be claimed before
+	/// calling this function if claiming is desirable.
+	fn withdraw_entry_reward_streams(
+		deposit_id: DepositId,
+		farm_entry: &YieldFarmEntry<T, I>,
+		yield_farm: &YieldFarmData<T, I>,
+	) -> DispatchResult {
+		//NOTE: unpaid rewards of terminated yield farm were already transferred back to the
+		//global farm.
+		if !yield_farm.state.is_terminated() {
+			let global_farm_account = Self::farm_account_id(farm_entry.global_farm_id)?;
+			let pot = Self::pot_account_id().ok_or(Error::<T, I>::ErrorGetAccountId)?;
+
+			for (reward_currency, mut reward_stream) in Self::reward_streams(farm_entry.global_farm_id) {
+				let entry_stream =
+					<EntryRewardStream<T, I>>::get((deposit_id, farm_entry.yield_farm_id, reward_currency));
+				let mut yield_farm_stream = <YieldFarmRewardStream<T, I>>::get(yield_farm.id, reward_currency);
+
+				let (unpaid_rewards, _) = math::calculate_user_reward(
+					entry_stream.accumulated_rpvs,
+					farm_entry.valued_shares,
+					entry_stream.accumulated_claimed_rewards,
+					yield_farm_stream.accumulated_rpvs,
+					FixedU128::one(),
+				)
+				.map_err(|_| ArithmeticError::Overflow)?;
+
+				if unpaid_rewards.is_zero() {
+					continue;
+				}
+
+				yield_farm_stream.left_to_distribute = yield_farm_stream
+					.left_to_distribute
+					.checked_sub(unpaid_rewards)
+					.defensive_ok_or::<Error<T, I>>(InconsistentStateError::NotEnoughRewardsInYieldFarm.into())?;
+
+				Self::return_reward_stream_rewards(
+					&mut reward_stream,
+					reward_currency,
+					unpaid_rewards,
+					&pot,
+					&global_farm_account,
+				)?;
+
+				<YieldFarmRewardStream<T, I>>::insert(yield_farm.id, reward_currency, yield_farm_stream);
+				<RewardStream<T, I>>::insert(farm_entry.global_farm_id, reward_currency, reward_stream);
+			}
+		}
+
+		let _ = <EntryRewardStream<T, I>>::clear_prefix((deposit_id, farm_entry.yield_farm_id), u32::MAX, None);
+
+		Ok(())
+	}
+
+	/// This function transfers `amount` of reward stream's rewards allocated to yield farms from
+	/// the `pot` back to the global farm.
+	fn return_reward_stream_rewards(
+		reward_stream: &mut RewardStreamData<T, I>,
+		reward_currency: T::AssetId,
+		amount: Balance,
+		pot: &T::AccountId,
+		global_farm_account: &T::AccountId,
+	) -> DispatchResult {
+		reward_stream.accumulated_paid_rewards = reward_stream
+			.accumulated_paid_rewards
+			.checked_sub(amount)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPaidAccumulatedRewards.into())?;
+
+		reward_stream.left_to_distribute = reward_stream
+			.left_to_distribute
+			.checked_add(amount)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		T::MultiCurrency::transfer(
+			reward_currency,
+			pot,
+			global_farm_account,
+			amount,
+			ExistenceRequirement::AllowDeath,
+		)
+	}
+
+	/// This function returns all reward streams of the global farm.
+	fn reward_streams(global_farm_id: GlobalFarmId) -> Vec<(T::AssetId, RewardStreamData<T, I>)> {
+		<RewardStream<T, I>>::iter_prefix(global_farm_id).collect()
+	}
+
 	/// This function returns new unused `FarmId` usable for yield global farm or error.
 	fn get_next_farm_id() -> Result<FarmId, ArithmeticError> {
 		FarmSequencer::<T, I>::try_mutate(|current_id| {
@@ -1589,6 +2053,30 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(m)
 	}
 
+	/// This function returns loyalty multiplier of the yield farm entry or error.
+	fn get_entry_loyalty_multiplier(
+		farm_entry: &YieldFarmEntry<T, I>,
+		yield_farm: &YieldFarmData<T, I>,
+	) -> Result<FixedU128, DispatchError> {
+		//NOTE: this should never fail yield-farm's stopped must be >= entry's
+		//stopped
+		let delta_stopped = yield_farm
+			.total_stopped
+			.checked_sub(&farm_entry.stopped_at_creation)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
+
+		//NOTE: yield-farm's `updated_at` is updated to current period if it's
+		//possible so this should be ok.
+		let periods = yield_farm
+			.updated_at
+			.checked_sub(&farm_entry.entered_at)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?
+			.checked_sub(&delta_stopped)
+			.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?;
+
+		Self::get_loyalty_multiplier(periods, yield_farm.loyalty_curve.clone())
+	}
+
 	/// This function calculates and updates `accumulated_rpz` and all associated properties of
 	/// `global_farm` if conditions are met.
 	/// Returns the reward transferred to the pot.
@@ -1602,6 +2090,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Ok(Zero::zero());
 		}
 
+		Self::sync_reward_streams(global_farm, current_period)?;
+
 		// Farm should be updated only once in the same period.
 		if global_farm.updated_at == current_period {
 			return Ok(Zero::zero());
@@ -1678,6 +2168,106 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(reward)
 	}
 
+	/// This function calculates and updates `accumulated_rpz` and all associated properties of
+	/// global farm's reward streams if conditions are met. Rewards are distributed only in the
+	/// periods between stream's `start_period` and `end_period`.
+	#[require_transactional]
+	fn sync_reward_streams(global_farm: &GlobalFarmData<T, I>, current_period: PeriodOf<T>) -> DispatchResult {
+		for (reward_currency, mut reward_stream) in Self::reward_streams(global_farm.id) {
+			// Stream should be updated only once in the same period.
+			if reward_stream.updated_at == current_period {
+				continue;
+			}
+
+			let from = reward_stream.updated_at.max(reward_stream.start_period);
+			let to = current_period.min(reward_stream.end_period);
+
+			// Nothing to update if there is no stake in the farm or stream is not running.
+			if global_farm.total_shares_z.is_zero() || to <= from {
+				reward_stream.updated_at = current_period;
+				<RewardStream<T, I>>::insert(global_farm.id, reward_currency, reward_stream);
+				continue;
+			}
+
+			//NOTE: `to` > `from` and `end_period` >= `to` so this can't fail.
+			let elapsed_periods = TryInto::<u128>::try_into(
+				to.checked_sub(&from)
+					.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+
+			let reward = match reward_stream.schedule {
+				RewardSchedule::Yield {
+					yield_per_period,
+					price_adjustment,
+				} => math::calculate_global_farm_rewards(
+					global_farm.total_shares_z,
+					price_adjustment,
+					yield_per_period.into(),
+					reward_stream.max_reward_per_period,
+					elapsed_periods,
+				)
+				.map_err(|_| ArithmeticError::Overflow)?,
+				RewardSchedule::Streaming => {
+					let remaining_periods = TryInto::<u128>::try_into(
+						reward_stream
+							.end_period
+							.checked_sub(&from)
+							.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?,
+					)
+					.map_err(|_| ArithmeticError::Overflow)?;
+
+					math::calculate_streamed_rewards(
+						reward_stream.left_to_distribute,
+						elapsed_periods,
+						remaining_periods,
+					)
+					.map_err(|_| ArithmeticError::Overflow)?
+				}
+			}
+			.min(reward_stream.left_to_distribute);
+
+			if !reward.is_zero() {
+				let global_farm_account = Self::farm_account_id(global_farm.id)?;
+				let pot = Self::pot_account_id().ok_or(Error::<T, I>::ErrorGetAccountId)?;
+				T::MultiCurrency::transfer(
+					reward_currency,
+					&global_farm_account,
+					&pot,
+					reward,
+					ExistenceRequirement::AllowDeath,
+				)?;
+
+				reward_stream.accumulated_rpz =
+					math::calculate_accumulated_rps(reward_stream.accumulated_rpz, global_farm.total_shares_z, reward)
+						.map_err(|_| ArithmeticError::Overflow)?;
+
+				reward_stream.pending_rewards = reward_stream
+					.pending_rewards
+					.checked_add(reward)
+					.ok_or(ArithmeticError::Overflow)?;
+
+				reward_stream.left_to_distribute = reward_stream
+					.left_to_distribute
+					.checked_sub(reward)
+					.ok_or(ArithmeticError::Overflow)?;
+			}
+
+			reward_stream.updated_at = current_period;
+
+			Pallet::<T, I>::deposit_event(Event::RewardStreamAccRPZUpdated {
+				global_farm_id: global_farm.id,
+				reward_currency,
+				accumulated_rpz: reward_stream.accumulated_rpz,
+				total_shares_z: global_farm.total_shares_z,
+			});
+
+			<RewardStream<T, I>>::insert(global_farm.id, reward_currency, reward_stream);
+		}
+
+		Ok(())
+	}
+
 	/// This function calculates and updates `accumulated_rpvz` and all associated properties of
 	/// `YieldFarm` if conditions are met. It also calculates yield-farm's rewards from `GlobalFarm`.
 	/// NOTE: Yield-farm's rewards are staying in the `pot`.
@@ -1695,6 +2285,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Ok(());
 		}
 
+		Self::sync_yield_farm_reward_streams(yield_farm, global_farm.id)?;
+
 		if yield_farm.total_valued_shares.is_zero() {
 			//NOTE: This is important to prevent rewarding of the farms for emtpy periods and it
 			//also prevents the first user getting more rewards than the second user.
@@ -1746,6 +2338,230 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// This function calculates and updates yield farm's state of the global farm's reward
+	/// streams. Yield-farm's rewards from reward streams are staying in the `pot`.
+	/// NOTE: this function should be called only for active yield farm and only if yield farm
+	/// wasn't updated in the current period.
+	#[require_transactional]
+	fn sync_yield_farm_reward_streams(
+		yield_farm: &YieldFarmData<T, I>,
+		global_farm_id: GlobalFarmId,
+	) -> DispatchResult {
+		for (reward_currency, mut reward_stream) in Self::reward_streams(global_farm_id) {
+			let mut yield_farm_stream = <YieldFarmRewardStream<T, I>>::get(yield_farm.id, reward_currency);
+
+			if yield_farm.total_valued_shares.is_zero() {
+				//NOTE: same as for yield farm's rewards, this prevents rewarding of the farms for
+				//empty periods.
+				yield_farm_stream.accumulated_rpz = reward_stream.accumulated_rpz;
+				<YieldFarmRewardStream<T, I>>::insert(yield_farm.id, reward_currency, yield_farm_stream);
+
+				continue;
+			}
+
+			let (delta_rpvs, yield_farm_rewards) = math::calculate_yield_farm_rewards(
+				yield_farm_stream.accumulated_rpz,
+				reward_stream.accumulated_rpz,
+				yield_farm.multiplier,
+				yield_farm.total_valued_shares,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+
+			yield_farm_stream.accumulated_rpz = reward_stream.accumulated_rpz;
+
+			yield_farm_stream.accumulated_rpvs = yield_farm_stream
+				.accumulated_rpvs
+				.checked_add(&delta_rpvs)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			yield_farm_stream.left_to_distribute = yield_farm_stream
+				.left_to_distribute
+				.checked_add(yield_farm_rewards)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			reward_stream.accumulated_paid_rewards = reward_stream
+				.accumulated_paid_rewards
+				.checked_add(yield_farm_rewards)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			reward_stream.pending_rewards = reward_stream
+				.pending_rewards
+				.checked_sub(yield_farm_rewards)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			<YieldFarmRewardStream<T, I>>::insert(yield_farm.id, reward_currency, yield_farm_stream);
+			<RewardStream<T, I>>::insert(global_farm_id, reward_currency, reward_stream);
+		}
+
+		Ok(())
+	}
+
+	/// This function returns rewards of all deposit's yield farm entries as if they were claimed
+	/// in the current period. Farms are synced the same way as on claim but no state change is
+	/// persisted.
+	///
+	/// NOTE: this function is intended to be used by runtime APIs.
+	///
+	/// Parameters:
+	/// - `deposit_id`: id of the deposit.
+	/// - `blocks_per_year`: number of blocks in a year used to estimate APR.
+	pub fn deposit_rewards(
+		deposit_id: DepositId,
+		blocks_per_year: BlockNumberFor<T>,
+	) -> Result<Vec<EntryRewards<T::AssetId>>, DispatchError> {
+		with_transaction(|| TransactionOutcome::Rollback(Self::do_deposit_rewards(deposit_id, blocks_per_year)))
+	}
+
+	#[require_transactional]
+	fn do_deposit_rewards(
+		deposit_id: DepositId,
+		blocks_per_year: BlockNumberFor<T>,
+	) -> Result<Vec<EntryRewards<T::AssetId>>, DispatchError> {
+		let deposit = Self::deposit(deposit_id).ok_or(Error::<T, I>::DepositNotFound)?;
+
+		let mut rewards = Vec::with_capacity(deposit.yield_farm_entries.len());
+		for farm_entry in deposit.yield_farm_entries.iter() {
+			let yield_farm_key = (
+				deposit.amm_pool_id.clone(),
+				farm_entry.global_farm_id,
+				farm_entry.yield_farm_id,
+			);
+
+			//NOTE: yield-farm must exist if yield-farm-entry exists.
+			let mut yield_farm = <YieldFarm<T, I>>::get(yield_farm_key.clone())
+				.defensive_ok_or::<Error<T, I>>(InconsistentStateError::YieldFarmNotFound.into())?;
+
+			//NOTE: global-farm must exist if yield-farm exists.
+			let mut global_farm = <GlobalFarm<T, I>>::get(farm_entry.global_farm_id)
+				.defensive_ok_or::<Error<T, I>>(InconsistentStateError::GlobalFarmNotFound.into())?;
+
+			let current_period = Self::get_current_period(global_farm.blocks_per_period)?;
+			Self::sync_global_farm(&mut global_farm, current_period)?;
+			Self::sync_yield_farm(&mut yield_farm, &mut global_farm, current_period)?;
+
+			//NOTE: synced farms are saved so they are not synced again for other deposit's entries.
+			<GlobalFarm<T, I>>::insert(global_farm.id, global_farm.clone());
+			<YieldFarm<T, I>>::insert(yield_farm_key, yield_farm.clone());
+
+			let loyalty_multiplier = Self::get_entry_loyalty_multiplier(farm_entry, &yield_farm)?;
+			let apr = Self::get_yield_farm_apr(&global_farm, &yield_farm, blocks_per_year)?;
+
+			let mut entry_rewards = EntryRewards {
+				global_farm_id: global_farm.id,
+				yield_farm_id: yield_farm.id,
+				reward_currency: global_farm.reward_currency,
+				claimable_rewards: Zero::zero(),
+				unclaimable_rewards: Zero::zero(),
+				loyalty_multiplier,
+				apr,
+				reward_streams: Vec::new(),
+			};
+
+			//NOTE: rewards can't be claimed from terminated yield farm.
+			if yield_farm.state.is_terminated() {
+				rewards.push(entry_rewards);
+				continue;
+			}
+
+			(entry_rewards.claimable_rewards, entry_rewards.unclaimable_rewards) = math::calculate_user_reward(
+				farm_entry.accumulated_rpvs,
+				farm_entry.valued_shares,
+				farm_entry.accumulated_claimed_rewards,
+				yield_farm.accumulated_rpvs,
+				loyalty_multiplier,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+
+			for (reward_currency, _) in Self::reward_streams(global_farm.id) {
+				let entry_stream = <EntryRewardStream<T, I>>::get((deposit_id, yield_farm.id, reward_currency));
+				let yield_farm_stream = <YieldFarmRewardStream<T, I>>::get(yield_farm.id, reward_currency);
+
+				let (stream_rewards, _) = math::calculate_user_reward(
+					entry_stream.accumulated_rpvs,
+					farm_entry.valued_shares,
+					entry_stream.accumulated_claimed_rewards,
+					yield_farm_stream.accumulated_rpvs,
+					loyalty_multiplier,
+				)
+				.map_err(|_| ArithmeticError::Overflow)?;
+
+				if !stream_rewards.is_zero() {
+					entry_rewards.reward_streams.push((reward_currency, stream_rewards));
+				}
+			}
+
+			rewards.push(entry_rewards);
+		}
+
+		Ok(rewards)
+	}
+
+	/// This function returns estimated APR of all active yield farms.
+	///
+	/// NOTE: this function is intended to be used by runtime APIs.
+	///
+	/// Parameters:
+	/// - `blocks_per_year`: number of blocks in a year used to estimate APR.
+	pub fn yield_farms_apr(blocks_per_year: BlockNumberFor<T>) -> Vec<YieldFarmApr<T::AssetId>> {
+		<YieldFarm<T, I>>::iter()
+			.filter(|(_, yield_farm)| yield_farm.state.is_active())
+			.filter_map(|((_, global_farm_id, yield_farm_id), yield_farm)| {
+				let global_farm = <GlobalFarm<T, I>>::get(global_farm_id)?;
+				let apr = Self::get_yield_farm_apr(&global_farm, &yield_farm, blocks_per_year).ok()?;
+
+				Some(YieldFarmApr {
+					global_farm_id,
+					yield_farm_id,
+					reward_currency: global_farm.reward_currency,
+					apr,
+				})
+			})
+			.collect()
+	}
+
+	/// This function returns estimated APR of the yield farm or error. APR is 0 if yield farm or
+	/// global farm is not active or if global farm has no rewards left to distribute.
+	fn get_yield_farm_apr(
+		global_farm: &GlobalFarmData<T, I>,
+		yield_farm: &YieldFarmData<T, I>,
+		blocks_per_year: BlockNumberFor<T>,
+	) -> Result<FixedU128, DispatchError> {
+		if !global_farm.state.is_active() || !yield_farm.state.is_active() {
+			return Ok(FixedU128::zero());
+		}
+
+		let global_farm_account = Self::farm_account_id(global_farm.id)?;
+		let reward_currency_ed = T::AssetRegistry::get(&global_farm.reward_currency);
+		let left_to_distribute = T::MultiCurrency::free_balance(global_farm.reward_currency, &global_farm_account)
+			.saturating_sub(reward_currency_ed);
+
+		if left_to_distribute.is_zero() {
+			return Ok(FixedU128::zero());
+		}
+
+		let periods_per_year = TryInto::<u128>::try_into(
+			blocks_per_year
+				.checked_div(&global_farm.blocks_per_period)
+				.defensive_ok_or::<Error<T, I>>(InconsistentStateError::InvalidPeriod.into())?,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+
+		//NOTE: Fallback. Last saved value should be used if oracle is not available.
+		let price_adjustment = T::PriceAdjustment::get(global_farm).unwrap_or(global_farm.price_adjustment);
+
+		let apr = math::calculate_yield_farm_apr(
+			global_farm.total_shares_z,
+			price_adjustment,
+			global_farm.yield_per_period.into(),
+			global_farm.max_reward_per_period,
+			yield_farm.multiplier,
+			periods_per_year,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+
+		Ok(apr)
+	}
+
 	/// This function returns an error if `farm_id` is not valid.
 	fn validate_farm_id(farm_id: FarmId) -> Result<(), Error<T, I>> {
 		if farm_id.is_zero() {
@@ -1827,6 +2643,7 @@ impl<T: Config<I>, I: 'static> hydradx_traits::liquidity_mining::Mutate<T::Accou
 	type Balance = Balance;
 	type Period = PeriodOf<T>;
 	type LoyaltyCurve = LoyaltyCurve;
+	type RewardSchedule = RewardSchedule;
 
 	fn create_global_farm(
 		total_rewards: Self::Balance,
@@ -1902,6 +2719,26 @@ impl<T: Config<I>, I: 'static> hydradx_traits::liquidity_mining::Mutate<T::Accou
 		Self::terminate_global_farm(who, global_farm_id)
 	}
 
+	fn add_reward_stream(
+		who: T::AccountId,
+		global_farm_id: GlobalFarmId,
+		reward_currency: T::AssetId,
+		total_rewards: Self::Balance,
+		start: BlockNumberFor<T>,
+		end: BlockNumberFor<T>,
+		schedule: Self::RewardSchedule,
+	) -> Result<(), Self::Error> {
+		Self::add_reward_stream(
+			who,
+			global_farm_id,
+			reward_currency,
+			total_rewards,
+			start,
+			end,
+			schedule,
+		)
+	}
+
 	fn create_yield_farm(
 		who: T::AccountId,
 		global_farm_id: GlobalFarmId,
@@ -1983,6 +2820,14 @@ impl<T: Config<I>, I: 'static> hydradx_traits::liquidity_mining::Mutate<T::Accou
 		Self::claim_rewards(who, deposit_id, yield_farm_id, fail_on_doubleclaim)
 	}
 
+	fn claim_reward_streams(
+		who: T::AccountId,
+		deposit_id: DepositId,
+		yield_farm_id: YieldFarmId,
+	) -> Result<Vec<(T::AssetId, Self::Balance)>, Self::Error> {
+		Self::claim_reward_streams(who, deposit_id, yield_farm_id)
+	}
+
 	fn withdraw_lp_shares(
 		who: T::AccountId,
 		deposit_id: DepositId,
@@ -0,0 +1,220 @@
+// This file is part of galacticcouncil/warehouse.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pretty_assertions::assert_eq;
+use test_ext::*;
+
+use crate::tests::mock::LiquidityMining2;
+
+const GLOBAL_FARM: GlobalFarmId = 1;
+const YIELD_FARM_A: YieldFarmId = 2;
+
+const ALICE_DEPOSIT: DepositId = 1;
+
+const STREAM_REWARDS: Balance = 10_000 * ONE;
+
+//1 period = 10 blocks => 100 periods per "year"
+const BLOCKS_PER_YEAR: BlockNumber = 1_000;
+
+//Global farm with one yield farm(multiplier 2) created in block 100(period 10).
+fn create_farms() {
+	set_block_number(100);
+	assert_ok!(LiquidityMining2::create_global_farm(
+		200_000 * ONE,
+		20,
+		10,
+		BSX,
+		BSX,
+		GC,
+		Perquintill::from_float(0.5),
+		1_000,
+		One::one(),
+	));
+
+	assert_ok!(LiquidityMining2::create_yield_farm(
+		GC,
+		GLOBAL_FARM,
+		FixedU128::from(2_u128),
+		None,
+		BSX_TKN1_AMM,
+		vec![BSX, TKN1],
+	));
+
+	//Reward stream running from block 200 to 300(periods 20 - 30).
+	assert_ok!(LiquidityMining2::add_reward_stream(
+		GC,
+		GLOBAL_FARM,
+		TKN2,
+		STREAM_REWARDS,
+		200,
+		300,
+		RewardSchedule::Streaming,
+	));
+}
+
+fn deposit(shares: Balance) -> DepositId {
+	LiquidityMining2::deposit_lp_shares(GLOBAL_FARM, YIELD_FARM_A, BSX_TKN1_AMM, shares, |_, _, _| Ok(shares)).unwrap()
+}
+
+#[test]
+fn deposit_rewards_should_return_same_rewards_as_claim() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+
+			set_block_number(120);
+			deposit(5_000 * ONE);
+
+			set_block_number(251);
+			let global_farm = LiquidityMining2::global_farm(GLOBAL_FARM).unwrap();
+			let yield_farm = LiquidityMining2::yield_farm((BSX_TKN1_AMM, GLOBAL_FARM, YIELD_FARM_A)).unwrap();
+
+			//Act
+			let rewards = LiquidityMining2::deposit_rewards(ALICE_DEPOSIT, BLOCKS_PER_YEAR).unwrap();
+
+			//Assert - nothing was changed
+			assert_eq!(LiquidityMining2::global_farm(GLOBAL_FARM).unwrap(), global_farm);
+			assert_eq!(
+				LiquidityMining2::yield_farm((BSX_TKN1_AMM, GLOBAL_FARM, YIELD_FARM_A)).unwrap(),
+				yield_farm
+			);
+
+			let (_, _, claimed, unclaimable) =
+				LiquidityMining2::claim_rewards(ALICE, ALICE_DEPOSIT, YIELD_FARM_A, false).unwrap();
+			let claimed_streams = LiquidityMining2::claim_reward_streams(ALICE, ALICE_DEPOSIT, YIELD_FARM_A).unwrap();
+
+			assert_eq!(
+				rewards,
+				vec![EntryRewards {
+					global_farm_id: GLOBAL_FARM,
+					yield_farm_id: YIELD_FARM_A,
+					reward_currency: BSX,
+					claimable_rewards: claimed,
+					unclaimable_rewards: unclaimable,
+					loyalty_multiplier: FixedU128::one(),
+					apr: FixedU128::from(100_u128),
+					reward_streams: claimed_streams,
+				}]
+			);
+			assert!(!claimed.is_zero());
+			assert_eq!(rewards[0].reward_streams, vec![(TKN2, STREAM_REWARDS / 2)]);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn deposit_rewards_should_return_zero_rewards_when_yield_farm_is_terminated() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+
+			set_block_number(120);
+			deposit(5_000 * ONE);
+
+			set_block_number(251);
+			assert_ok!(LiquidityMining2::stop_yield_farm(GC, GLOBAL_FARM, BSX_TKN1_AMM));
+			assert_ok!(LiquidityMining2::terminate_yield_farm(
+				GC,
+				GLOBAL_FARM,
+				YIELD_FARM_A,
+				BSX_TKN1_AMM
+			));
+
+			//Act & assert
+			assert_eq!(
+				LiquidityMining2::deposit_rewards(ALICE_DEPOSIT, BLOCKS_PER_YEAR).unwrap(),
+				vec![EntryRewards {
+					global_farm_id: GLOBAL_FARM,
+					yield_farm_id: YIELD_FARM_A,
+					reward_currency: BSX,
+					claimable_rewards: 0,
+					unclaimable_rewards: 0,
+					loyalty_multiplier: FixedU128::one(),
+					apr: FixedU128::zero(),
+					reward_streams: vec![],
+				}]
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn deposit_rewards_should_not_work_when_deposit_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			create_farms();
+
+			assert_eq!(
+				LiquidityMining2::deposit_rewards(ALICE_DEPOSIT, BLOCKS_PER_YEAR),
+				Err(Error::<Test, Instance2>::DepositNotFound.into())
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn yield_farms_apr_should_return_apr_of_active_yield_farms() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+
+			//Empty farm - APR is not capped by max reward per period.
+			//apr = yield_per_period(0.5) * multiplier(2) * periods_per_year(100)
+			assert_eq!(
+				LiquidityMining2::yield_farms_apr(BLOCKS_PER_YEAR),
+				vec![YieldFarmApr {
+					global_farm_id: GLOBAL_FARM,
+					yield_farm_id: YIELD_FARM_A,
+					reward_currency: BSX,
+					apr: FixedU128::from(100_u128),
+				}]
+			);
+
+			//max_reward_per_period: 10_000 * ONE, total_shares_z: 40_000 * ONE
+			//apr = 10_000 / 40_000 * multiplier(2) * periods_per_year(100)
+			set_block_number(120);
+			deposit(20_000 * ONE);
+
+			//Act & assert
+			assert_eq!(
+				LiquidityMining2::yield_farms_apr(BLOCKS_PER_YEAR),
+				vec![YieldFarmApr {
+					global_farm_id: GLOBAL_FARM,
+					yield_farm_id: YIELD_FARM_A,
+					reward_currency: BSX,
+					apr: FixedU128::from(50_u128),
+				}]
+			);
+
+			//Stopped yield farm is not yielding.
+			assert_ok!(LiquidityMining2::stop_yield_farm(GC, GLOBAL_FARM, BSX_TKN1_AMM));
+			assert_eq!(LiquidityMining2::yield_farms_apr(BLOCKS_PER_YEAR), vec![]);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
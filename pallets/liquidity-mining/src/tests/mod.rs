@@ -251,11 +251,13 @@ pub mod claim_rewards;
 pub mod create_global_farm;
 pub mod create_yield_farm;
 pub mod deposit_lp_shares;
+pub mod deposit_rewards;
 pub mod full_run;
 pub mod invariants;
 pub mod mock;
 pub mod redeposit_lp_shares;
 pub mod resume_yield_farm;
+pub mod reward_streams;
 pub mod stop_yield_farm;
 pub mod terminate_global_farm;
 pub mod terminate_yield_farm;
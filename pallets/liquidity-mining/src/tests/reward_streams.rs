@@ -0,0 +1,562 @@
+// This file is part of galacticcouncil/warehouse.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use pretty_assertions::assert_eq;
+use test_ext::*;
+
+use crate::tests::mock::LiquidityMining2;
+
+const GLOBAL_FARM: GlobalFarmId = 1;
+const YIELD_FARM_A: YieldFarmId = 2;
+
+const ALICE_DEPOSIT: DepositId = 1;
+const BOB_DEPOSIT: DepositId = 2;
+
+const STREAM_REWARDS: Balance = 10_000 * ONE;
+
+//Global farm with one yield farm(multiplier 2) created in block 100(period 10).
+fn create_farms() {
+	set_block_number(100);
+	assert_ok!(LiquidityMining2::create_global_farm(
+		200_000 * ONE,
+		20,
+		10,
+		BSX,
+		BSX,
+		GC,
+		Perquintill::from_float(0.5),
+		1_000,
+		One::one(),
+	));
+
+	assert_ok!(LiquidityMining2::create_yield_farm(
+		GC,
+		GLOBAL_FARM,
+		FixedU128::from(2_u128),
+		None,
+		BSX_TKN1_AMM,
+		vec![BSX, TKN1],
+	));
+}
+
+//Reward stream running from block 200 to 300(periods 20 - 30).
+fn add_stream(schedule: RewardSchedule) {
+	assert_ok!(LiquidityMining2::add_reward_stream(
+		GC,
+		GLOBAL_FARM,
+		TKN2,
+		STREAM_REWARDS,
+		200,
+		300,
+		schedule,
+	));
+}
+
+fn deposit(shares: Balance) -> DepositId {
+	LiquidityMining2::deposit_lp_shares(GLOBAL_FARM, YIELD_FARM_A, BSX_TKN1_AMM, shares, |_, _, _| Ok(shares)).unwrap()
+}
+
+#[test]
+fn add_reward_stream_should_work() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+			let global_farm_account = LiquidityMining2::farm_account_id(GLOBAL_FARM).unwrap();
+
+			//Act
+			add_stream(RewardSchedule::Streaming);
+
+			//Assert
+			assert_eq!(
+				LiquidityMining2::reward_stream(GLOBAL_FARM, TKN2).unwrap(),
+				RewardStreamData::new(RewardSchedule::Streaming, 20, 30, 10, 1_000 * ONE, STREAM_REWARDS)
+			);
+			assert_eq!(Tokens::free_balance(TKN2, &global_farm_account), STREAM_REWARDS);
+			assert_eq!(Tokens::free_balance(TKN2, &GC), INITIAL_BALANCE * ONE - STREAM_REWARDS);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn add_reward_stream_should_not_work_when_params_are_invalid() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			create_farms();
+
+			assert_noop!(
+				LiquidityMining2::add_reward_stream(
+					ALICE,
+					GLOBAL_FARM,
+					TKN2,
+					STREAM_REWARDS,
+					200,
+					300,
+					RewardSchedule::Streaming
+				),
+				Error::<Test, Instance2>::Forbidden
+			);
+
+			assert_noop!(
+				LiquidityMining2::add_reward_stream(
+					GC,
+					GLOBAL_FARM,
+					BSX,
+					STREAM_REWARDS,
+					200,
+					300,
+					RewardSchedule::Streaming
+				),
+				Error::<Test, Instance2>::InvalidRewardStreamCurrency
+			);
+
+			assert_noop!(
+				LiquidityMining2::add_reward_stream(GC, GLOBAL_FARM, TKN2, 0, 200, 300, RewardSchedule::Streaming),
+				Error::<Test, Instance2>::InvalidTotalRewards
+			);
+
+			//Stream can't start in the past.
+			assert_noop!(
+				LiquidityMining2::add_reward_stream(
+					GC,
+					GLOBAL_FARM,
+					TKN2,
+					STREAM_REWARDS,
+					99,
+					300,
+					RewardSchedule::Streaming
+				),
+				Error::<Test, Instance2>::InvalidRewardStreamRange
+			);
+
+			//Stream has to last at least one period.
+			assert_noop!(
+				LiquidityMining2::add_reward_stream(
+					GC,
+					GLOBAL_FARM,
+					TKN2,
+					STREAM_REWARDS,
+					200,
+					209,
+					RewardSchedule::Streaming
+				),
+				Error::<Test, Instance2>::InvalidRewardStreamRange
+			);
+
+			assert_noop!(
+				LiquidityMining2::add_reward_stream(
+					GC,
+					GLOBAL_FARM,
+					TKN2,
+					STREAM_REWARDS,
+					200,
+					300,
+					RewardSchedule::Yield {
+						yield_per_period: Perquintill::zero(),
+						price_adjustment: One::one(),
+					}
+				),
+				Error::<Test, Instance2>::InvalidYieldPerPeriod
+			);
+
+			assert_noop!(
+				LiquidityMining2::add_reward_stream(
+					GC,
+					GLOBAL_FARM,
+					UNKNOWN_ASSET,
+					STREAM_REWARDS,
+					200,
+					300,
+					RewardSchedule::Streaming
+				),
+				Error::<Test, Instance2>::RewardCurrencyNotRegistered
+			);
+
+			assert_noop!(
+				LiquidityMining2::add_reward_stream(
+					GC,
+					GLOBAL_FARM,
+					KSM,
+					STREAM_REWARDS,
+					200,
+					300,
+					RewardSchedule::Streaming
+				),
+				Error::<Test, Instance2>::InsufficientRewardCurrencyBalance
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn add_reward_stream_should_not_work_when_stream_exists_or_farm_is_full() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+			add_stream(RewardSchedule::Streaming);
+
+			//Act & assert
+			assert_noop!(
+				LiquidityMining2::add_reward_stream(
+					GC,
+					GLOBAL_FARM,
+					TKN2,
+					STREAM_REWARDS,
+					200,
+					300,
+					RewardSchedule::Streaming
+				),
+				Error::<Test, Instance2>::RewardStreamAlreadyExists
+			);
+
+			for asset in [TKN1, KSM, ACA] {
+				assert_ok!(<Tokens as MultiCurrency<AccountId>>::deposit(
+					asset,
+					&GC,
+					STREAM_REWARDS
+				));
+			}
+
+			assert_ok!(LiquidityMining2::add_reward_stream(
+				GC,
+				GLOBAL_FARM,
+				TKN1,
+				STREAM_REWARDS,
+				200,
+				300,
+				RewardSchedule::Streaming
+			));
+			assert_ok!(LiquidityMining2::add_reward_stream(
+				GC,
+				GLOBAL_FARM,
+				KSM,
+				STREAM_REWARDS,
+				200,
+				300,
+				RewardSchedule::Streaming
+			));
+
+			assert_noop!(
+				LiquidityMining2::add_reward_stream(
+					GC,
+					GLOBAL_FARM,
+					ACA,
+					STREAM_REWARDS,
+					200,
+					300,
+					RewardSchedule::Streaming
+				),
+				Error::<Test, Instance2>::MaxRewardStreamsReached
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn streaming_should_distribute_total_rewards_evenly_over_stream_range() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+			add_stream(RewardSchedule::Streaming);
+
+			set_block_number(120);
+			deposit(5_000 * ONE);
+
+			//Act - half of the stream's range
+			set_block_number(251);
+			let claimed = LiquidityMining2::claim_reward_streams(ALICE, ALICE_DEPOSIT, YIELD_FARM_A).unwrap();
+
+			//Assert
+			assert_eq!(claimed, vec![(TKN2, STREAM_REWARDS / 2)]);
+			assert_eq!(Tokens::free_balance(TKN2, &ALICE), STREAM_REWARDS / 2);
+
+			//Act - after stream's end
+			set_block_number(401);
+			let claimed = LiquidityMining2::claim_reward_streams(ALICE, ALICE_DEPOSIT, YIELD_FARM_A).unwrap();
+
+			//Assert
+			assert_eq!(claimed, vec![(TKN2, STREAM_REWARDS / 2)]);
+			assert_eq!(Tokens::free_balance(TKN2, &ALICE), STREAM_REWARDS);
+			assert_eq!(
+				LiquidityMining2::reward_stream(GLOBAL_FARM, TKN2)
+					.unwrap()
+					.left_to_distribute,
+				0
+			);
+
+			//Nothing left to claim.
+			assert_eq!(
+				LiquidityMining2::claim_reward_streams(ALICE, ALICE_DEPOSIT, YIELD_FARM_A).unwrap(),
+				vec![]
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn streaming_should_redistribute_rewards_from_empty_periods_over_remaining_periods() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+			add_stream(RewardSchedule::Streaming);
+
+			//Farm is empty for first half of the stream's range.
+			set_block_number(251);
+			deposit(5_000 * ONE);
+
+			//Act
+			set_block_number(301);
+			let claimed = LiquidityMining2::claim_reward_streams(ALICE, ALICE_DEPOSIT, YIELD_FARM_A).unwrap();
+
+			//Assert
+			assert_eq!(claimed, vec![(TKN2, STREAM_REWARDS)]);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn yield_schedule_should_distribute_rewards_based_on_yield_per_period() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+			//max reward per period: 1_000 * ONE, stake 10_000 * ONE * 1% = 100 * ONE per period
+			add_stream(RewardSchedule::Yield {
+				yield_per_period: Perquintill::from_percent(1),
+				price_adjustment: One::one(),
+			});
+
+			set_block_number(120);
+			deposit(5_000 * ONE);
+
+			//Act
+			set_block_number(251);
+			let claimed = LiquidityMining2::claim_reward_streams(ALICE, ALICE_DEPOSIT, YIELD_FARM_A).unwrap();
+
+			//Assert
+			assert_eq!(claimed, vec![(TKN2, 500 * ONE)]);
+
+			//Act - rewards are capped by max reward per period
+			assert_ok!(LiquidityMining2::update_yield_farm_multiplier(
+				GC,
+				GLOBAL_FARM,
+				BSX_TKN1_AMM,
+				FixedU128::from(100_u128)
+			));
+
+			set_block_number(281);
+			let claimed = LiquidityMining2::claim_reward_streams(ALICE, ALICE_DEPOSIT, YIELD_FARM_A).unwrap();
+
+			//Assert
+			assert_eq!(claimed, vec![(TKN2, 3_000 * ONE)]);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn new_deposit_should_not_receive_rewards_streamed_before_its_creation() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+			add_stream(RewardSchedule::Streaming);
+
+			set_block_number(120);
+			deposit(5_000 * ONE);
+
+			set_block_number(251);
+			deposit(5_000 * ONE);
+
+			//Act
+			set_block_number(301);
+			let alice_claimed = LiquidityMining2::claim_reward_streams(ALICE, ALICE_DEPOSIT, YIELD_FARM_A).unwrap();
+			let bob_claimed = LiquidityMining2::claim_reward_streams(BOB, BOB_DEPOSIT, YIELD_FARM_A).unwrap();
+
+			//Assert
+			assert_eq!(alice_claimed, vec![(TKN2, 7_500 * ONE)]);
+			assert_eq!(bob_claimed, vec![(TKN2, 2_500 * ONE)]);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn reward_stream_should_be_added_to_existing_deposits() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+
+			set_block_number(120);
+			deposit(5_000 * ONE);
+
+			//Act
+			set_block_number(150);
+			add_stream(RewardSchedule::Streaming);
+
+			//Assert
+			set_block_number(301);
+			let claimed = LiquidityMining2::claim_reward_streams(ALICE, ALICE_DEPOSIT, YIELD_FARM_A).unwrap();
+
+			assert_eq!(claimed, vec![(TKN2, STREAM_REWARDS)]);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn withdraw_lp_shares_should_return_unclaimed_stream_rewards_to_global_farm() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+			add_stream(RewardSchedule::Streaming);
+			let global_farm_account = LiquidityMining2::farm_account_id(GLOBAL_FARM).unwrap();
+			let pot = LiquidityMining2::pot_account_id().unwrap();
+
+			set_block_number(120);
+			deposit(5_000 * ONE);
+
+			set_block_number(251);
+			let (_, _, _, unclaimable) =
+				LiquidityMining2::claim_rewards(ALICE, ALICE_DEPOSIT, YIELD_FARM_A, false).unwrap();
+
+			//Act
+			assert_ok!(LiquidityMining2::withdraw_lp_shares(
+				ALICE_DEPOSIT,
+				YIELD_FARM_A,
+				unclaimable,
+				BSX_TKN1_AMM
+			));
+
+			//Assert
+			assert_eq!(Tokens::free_balance(TKN2, &ALICE), 0);
+			assert_eq!(Tokens::free_balance(TKN2, &global_farm_account), STREAM_REWARDS);
+			assert_eq!(Tokens::free_balance(TKN2, &pot), 0);
+
+			let reward_stream = LiquidityMining2::reward_stream(GLOBAL_FARM, TKN2).unwrap();
+			assert_eq!(reward_stream.left_to_distribute, STREAM_REWARDS);
+			assert_eq!(reward_stream.accumulated_paid_rewards, 0);
+			assert_eq!(
+				LiquidityMining2::yield_farm_reward_stream(YIELD_FARM_A, TKN2).left_to_distribute,
+				0
+			);
+			assert_eq!(
+				EntryRewardStream::<Test, Instance2>::iter_prefix((ALICE_DEPOSIT,)).count(),
+				0
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn terminate_yield_farm_should_return_stream_rewards_to_global_farm() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+			add_stream(RewardSchedule::Streaming);
+			let global_farm_account = LiquidityMining2::farm_account_id(GLOBAL_FARM).unwrap();
+
+			set_block_number(120);
+			deposit(5_000 * ONE);
+
+			set_block_number(251);
+			assert_ok!(LiquidityMining2::stop_yield_farm(GC, GLOBAL_FARM, BSX_TKN1_AMM));
+			assert_eq!(
+				LiquidityMining2::yield_farm_reward_stream(YIELD_FARM_A, TKN2).left_to_distribute,
+				STREAM_REWARDS / 2
+			);
+
+			//Act
+			assert_ok!(LiquidityMining2::terminate_yield_farm(
+				GC,
+				GLOBAL_FARM,
+				YIELD_FARM_A,
+				BSX_TKN1_AMM
+			));
+
+			//Assert
+			assert_eq!(Tokens::free_balance(TKN2, &global_farm_account), STREAM_REWARDS);
+			assert_eq!(
+				LiquidityMining2::reward_stream(GLOBAL_FARM, TKN2)
+					.unwrap()
+					.left_to_distribute,
+				STREAM_REWARDS
+			);
+			assert!(!YieldFarmRewardStream::<Test, Instance2>::contains_key(
+				YIELD_FARM_A,
+				TKN2
+			));
+
+			assert_noop!(
+				LiquidityMining2::claim_reward_streams(ALICE, ALICE_DEPOSIT, YIELD_FARM_A),
+				Error::<Test, Instance2>::LiquidityMiningCanceled
+			);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn terminate_global_farm_should_return_undistributed_stream_rewards_to_owner() {
+	new_test_ext().execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			create_farms();
+			add_stream(RewardSchedule::Streaming);
+
+			assert_ok!(LiquidityMining2::stop_yield_farm(GC, GLOBAL_FARM, BSX_TKN1_AMM));
+			assert_ok!(LiquidityMining2::terminate_yield_farm(
+				GC,
+				GLOBAL_FARM,
+				YIELD_FARM_A,
+				BSX_TKN1_AMM
+			));
+
+			//Act
+			assert_ok!(LiquidityMining2::terminate_global_farm(GC, GLOBAL_FARM));
+
+			//Assert
+			assert_eq!(Tokens::free_balance(TKN2, &GC), INITIAL_BALANCE * ONE);
+			assert!(LiquidityMining2::reward_stream(GLOBAL_FARM, TKN2).is_none());
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
@@ -287,6 +287,81 @@ impl Default for LoyaltyCurve {
 	}
 }
 
+/// Schedule used by global farm's reward stream to distribute its rewards.
+/// - `Yield` - rewards are distributed the same way as global farm's primary rewards, based on
+///   `yield_per_period` of the global farm's total stake. `price_adjustment` is price adjustment
+///   between global farm's `incentivized_asset` and stream's reward currency.
+/// - `Streaming` - fixed total rewards are streamed evenly over the stream's range. Rewards which
+///   were not distributed because global farm was empty are streamed in the remaining periods.
+#[derive(Clone, Copy, Encode, Decode, DecodeWithMemTracking, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RewardSchedule {
+	Yield {
+		yield_per_period: Perquintill,
+		price_adjustment: FixedU128,
+	},
+	Streaming,
+}
+
+/// This struct represents the state of an additional reward currency of the global farm("reward
+/// stream"). Reward stream distributes its rewards between yield farms alongside global farm's
+/// `reward_currency` based on the same stakes in the global farm(`total_shares_z`) but it has its
+/// own budget and it's active only between `start_period` and `end_period`.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[codec(mel_bound())]
+#[scale_info(skip_type_params(T, I))]
+pub struct RewardStreamData<T: Config<I>, I: 'static = ()> {
+	pub schedule: RewardSchedule,
+	pub start_period: PeriodOf<T>,
+	pub end_period: PeriodOf<T>,
+	pub(super) updated_at: PeriodOf<T>,
+	pub(super) accumulated_rpz: FixedU128,
+	pub(super) max_reward_per_period: Balance,
+	// Rewards in the global farm's account which were not distributed yet.
+	pub(super) left_to_distribute: Balance,
+	pub(super) pending_rewards: Balance,
+	pub(super) accumulated_paid_rewards: Balance,
+	pub(super) _phantom: PhantomData<I>,
+}
+
+impl<T: Config<I>, I: 'static> RewardStreamData<T, I> {
+	pub fn new(
+		schedule: RewardSchedule,
+		start_period: PeriodOf<T>,
+		end_period: PeriodOf<T>,
+		updated_at: PeriodOf<T>,
+		max_reward_per_period: Balance,
+		total_rewards: Balance,
+	) -> Self {
+		Self {
+			schedule,
+			start_period,
+			end_period,
+			updated_at,
+			max_reward_per_period,
+			accumulated_rpz: Zero::zero(),
+			left_to_distribute: total_rewards,
+			pending_rewards: Zero::zero(),
+			accumulated_paid_rewards: Zero::zero(),
+			_phantom: PhantomData,
+		}
+	}
+}
+
+/// State of the global farm's reward stream in the yield farm.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct YieldFarmStreamData {
+	pub(super) accumulated_rpz: FixedU128,
+	pub(super) accumulated_rpvs: FixedU128,
+	pub(super) left_to_distribute: Balance,
+}
+
+/// State of the global farm's reward stream in the yield farm entry.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EntryStreamData {
+	pub(super) accumulated_rpvs: FixedU128,
+	pub(super) accumulated_claimed_rewards: Balance,
+}
+
 /// Deposit represents a group of locked LP shares in the liquidity mining program("Position").
 /// LP shares in the deposit can be locked in one or more yield farms based on pallet's
 /// configuration(`MaxEntriesPerDeposit`).
@@ -427,3 +502,29 @@ impl FarmState {
 		*self == FarmState::Terminated
 	}
 }
+
+/// Rewards of the deposit's yield farm entry as if they were claimed in the current period.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct EntryRewards<AssetId> {
+	pub global_farm_id: GlobalFarmId,
+	pub yield_farm_id: YieldFarmId,
+	pub reward_currency: AssetId,
+	/// Rewards user would receive by claiming. This is 0 if yield farm is terminated.
+	pub claimable_rewards: Balance,
+	/// Rewards user would lose by withdrawing because of the loyalty multiplier.
+	pub unclaimable_rewards: Balance,
+	pub loyalty_multiplier: FixedU128,
+	/// Estimated APR of the yield farm.
+	pub apr: FixedU128,
+	/// Claimable rewards from global farm's reward streams `(reward currency, claimable amount)`.
+	pub reward_streams: Vec<(AssetId, Balance)>,
+}
+
+/// Estimated APR of the yield farm.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct YieldFarmApr<AssetId> {
+	pub global_farm_id: GlobalFarmId,
+	pub yield_farm_id: YieldFarmId,
+	pub reward_currency: AssetId,
+	pub apr: FixedU128,
+}
@@ -69,12 +69,12 @@ use hydradx_traits::{
 use orml_traits::MultiCurrency;
 pub use pallet::*;
 use pallet_ema_oracle::OracleError;
-use pallet_liquidity_mining::{FarmMultiplier, LoyaltyCurve};
+use pallet_liquidity_mining::{FarmMultiplier, LoyaltyCurve, RewardSchedule};
 use pallet_omnipool::{types::Position as OmniPosition, NFTCollectionIdOf};
 use primitive_types::U256;
 use primitives::{Balance, ItemId as DepositId};
 use sp_runtime::{ArithmeticError, FixedU128, Perquintill};
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 pub use weights::WeightInfo;
 
 pub const MAX_ASSETS_IN_POOL: u32 = pallet_stableswap::MAX_ASSETS_IN_POOL;
@@ -143,6 +143,7 @@ pub mod pallet {
 			Balance = Balance,
 			LoyaltyCurve = LoyaltyCurve,
 			Period = PeriodOf<Self>,
+			RewardSchedule = RewardSchedule,
 		>;
 
 		type Stableswap: StableswapLiquidityMutation<Self::AccountId, Self::AssetId, Balance>;
@@ -203,6 +204,17 @@ pub mod pallet {
 			undistributed_rewards: Balance,
 		},
 
+		/// New reward stream was added to the global farm.
+		RewardStreamAdded {
+			global_farm_id: GlobalFarmId,
+			who: T::AccountId,
+			reward_currency: T::AssetId,
+			total_rewards: Balance,
+			start: BlockNumberFor<T>,
+			end: BlockNumberFor<T>,
+			schedule: RewardSchedule,
+		},
+
 		/// New yield farm was added to the farm.
 		YieldFarmCreated {
 			global_farm_id: GlobalFarmId,
@@ -763,14 +775,15 @@ pub mod pallet {
 		/// Claim rewards from liquidity mining program for deposit represented by the `deposit_id`.
 		///
 		/// This function calculate user rewards from liquidity mining and transfer rewards to `origin`
-		/// account. Claiming multiple time the same period is not allowed.
+		/// account. Claiming multiple time the same period is not allowed. Rewards from global farm's
+		/// reward streams are claimed too.
 		///
 		/// Parameters:
 		/// - `origin`: owner of deposit.
 		/// - `deposit_id`: id of the deposit to claim rewards for.
 		/// - `yield_farm_id`: id of the yield farm to claim rewards from.
 		///
-		/// Emits `RewardClaimed` event when successful.
+		/// Emits `RewardClaimed` event for each reward currency with claimed rewards > 0 when successful.
 		///
 		#[pallet::call_index(10)]
 		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards()
@@ -785,16 +798,32 @@ pub mod pallet {
 			let (global_farm_id, reward_currency, claimed, _) =
 				T::LiquidityMiningHandler::claim_rewards(owner.clone(), deposit_id, yield_farm_id)?;
 
-			ensure!(!claimed.is_zero(), Error::<T>::ZeroClaimedRewards);
+			let claimed_streams =
+				T::LiquidityMiningHandler::claim_reward_streams(owner.clone(), deposit_id, yield_farm_id)?;
 
-			Self::deposit_event(Event::RewardClaimed {
+			ensure!(
+				!claimed.is_zero() || !claimed_streams.is_empty(),
+				Error::<T>::ZeroClaimedRewards
+			);
+
+			if !claimed.is_zero() {
+				Self::deposit_event(Event::RewardClaimed {
+					global_farm_id,
+					yield_farm_id,
+					who: owner.clone(),
+					claimed,
+					reward_currency,
+					deposit_id,
+				});
+			}
+
+			Self::deposit_reward_stream_claimed_events(
 				global_farm_id,
 				yield_farm_id,
-				who: owner,
-				claimed,
-				reward_currency,
+				&owner,
 				deposit_id,
-			});
+				claimed_streams,
+			);
 
 			Ok(())
 		}
@@ -803,6 +832,7 @@ pub mod pallet {
 		/// is transferred to origin only if this is last withdraw in the deposit and deposit is
 		/// destroyed. This function claim rewards only if yield farm is not terminated and user
 		/// didn't already claim rewards in current period.
+		/// Rewards from global farm's reward streams are claimed only if yield farm is not terminated.
 		///
 		/// Unclaimable rewards represents rewards which user won't be able to claim because of
 		/// exiting early and these rewards will be transferred back to global farm for future
@@ -838,6 +868,20 @@ pub mod pallet {
 			let global_farm_id = T::LiquidityMiningHandler::get_global_farm_id(deposit_id, yield_farm_id)
 				.defensive_ok_or::<Error<T>>(InconsistentStateError::DepositDataNotFound.into())?;
 
+			//NOTE: rewards from reward streams are not claimed by `withdraw_lp_shares()`
+			if T::LiquidityMiningHandler::is_yield_farm_claimable(global_farm_id, yield_farm_id, lp_position.asset_id) {
+				let claimed_streams =
+					T::LiquidityMiningHandler::claim_reward_streams(owner.clone(), deposit_id, yield_farm_id)?;
+
+				Self::deposit_reward_stream_claimed_events(
+					global_farm_id,
+					yield_farm_id,
+					&owner,
+					deposit_id,
+					claimed_streams,
+				);
+			}
+
 			let (withdrawn_amount, claim_data, is_destroyed) = T::LiquidityMiningHandler::withdraw_lp_shares(
 				owner.clone(),
 				deposit_id,
@@ -1085,6 +1129,66 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Add additional reward currency(reward stream) to the existing global farm.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Reward stream distributes rewards between all yield farms in the global farm based on
+		/// the same stakes as global farm's `reward_currency`. Max. 3 reward streams can be added
+		/// to the global farm and each reward stream must have different `reward_currency`.
+		///
+		/// `origin` account has to have at least `total_rewards` balance. These funds will be
+		/// transferred from `origin` to farm account.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: id of the global farm to add reward stream to.
+		/// - `reward_currency`: payoff currency of the reward stream's rewards. This must be
+		/// different from global farm's `reward_currency`.
+		/// - `total_rewards`: total rewards to distribute by reward stream.
+		/// - `start`: block number from which reward stream starts distributing rewards. This can't
+		/// be in the past.
+		/// - `end`: block number when reward stream stops distributing rewards.
+		/// - `schedule`: distribution mode of the reward stream.
+		///
+		/// Emits `RewardStreamAdded` event when successful.
+		///
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_reward_stream())]
+		pub fn add_reward_stream(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			reward_currency: T::AssetId,
+			total_rewards: Balance,
+			start: BlockNumberFor<T>,
+			end: BlockNumberFor<T>,
+			schedule: RewardSchedule,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			T::LiquidityMiningHandler::add_reward_stream(
+				who.clone(),
+				global_farm_id,
+				reward_currency,
+				total_rewards,
+				start,
+				end,
+				schedule,
+			)?;
+
+			Self::deposit_event(Event::RewardStreamAdded {
+				global_farm_id,
+				who,
+				reward_currency,
+				total_rewards,
+				start,
+				end,
+				schedule,
+			});
+
+			Ok(())
+		}
+
 		/// Remove liquidity from stableswap and omnipool, optionally exiting associated yield farms.
 		///
 		/// This extrinsic reverses the operation performed by `add_liquidity_stableswap_omnipool_and_join_farms`,
@@ -1252,6 +1356,26 @@ impl<T: Config> Pallet<T> {
 		Ok(position_value)
 	}
 
+	/// This function emits `RewardClaimed` event for each claimed reward stream.
+	fn deposit_reward_stream_claimed_events(
+		global_farm_id: GlobalFarmId,
+		yield_farm_id: YieldFarmId,
+		who: &T::AccountId,
+		deposit_id: DepositId,
+		claimed_streams: Vec<(T::AssetId, Balance)>,
+	) {
+		for (reward_currency, claimed) in claimed_streams {
+			Self::deposit_event(Event::RewardClaimed {
+				global_farm_id,
+				yield_farm_id,
+				who: who.clone(),
+				claimed,
+				reward_currency,
+				deposit_id,
+			});
+		}
+	}
+
 	/// This function check if origin is signed and returns account if account is owner of the
 	/// deposit.
 	fn ensure_nft_owner(origin: OriginFor<T>, deposit_id: DepositId) -> Result<T::AccountId, DispatchError> {
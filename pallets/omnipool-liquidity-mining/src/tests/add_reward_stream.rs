@@ -0,0 +1,226 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use orml_traits::MultiCurrency;
+use pallet_liquidity_mining::{Instance1, RewardSchedule};
+
+const STREAM_REWARDS: Balance = 1_000 * ONE;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, KSM, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(GC, DOT, 10_000 * ONE),
+		])
+		.with_registered_asset(KSM)
+		.with_registered_asset(DOT)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_global_farm(
+			//id: 1
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, KSM, FixedU128::one(), None) //id: 2
+		.build()
+}
+
+#[test]
+fn add_reward_stream_should_work() {
+	ext().execute_with(|| {
+		let global_farm_id = 1;
+
+		//Act
+		assert_ok!(OmnipoolMining::add_reward_stream(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			DOT,
+			STREAM_REWARDS,
+			10,
+			110,
+			RewardSchedule::Streaming
+		));
+
+		//Assert
+		assert_last_event!(crate::Event::RewardStreamAdded {
+			global_farm_id,
+			who: GC,
+			reward_currency: DOT,
+			total_rewards: STREAM_REWARDS,
+			start: 10,
+			end: 110,
+			schedule: RewardSchedule::Streaming,
+		}
+		.into());
+
+		assert_eq!(Tokens::free_balance(DOT, &GC), 10_000 * ONE - STREAM_REWARDS);
+	});
+}
+
+#[test]
+fn add_reward_stream_should_fail_when_origin_is_not_global_farm_owner() {
+	ext().execute_with(|| {
+		assert_noop!(
+			OmnipoolMining::add_reward_stream(
+				RuntimeOrigin::signed(LP1),
+				1,
+				DOT,
+				STREAM_REWARDS,
+				10,
+				110,
+				RewardSchedule::Streaming
+			),
+			pallet_liquidity_mining::Error::<Test, Instance1>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn add_reward_stream_should_fail_when_origin_is_none() {
+	ext().execute_with(|| {
+		assert_noop!(
+			OmnipoolMining::add_reward_stream(
+				RuntimeOrigin::none(),
+				1,
+				DOT,
+				STREAM_REWARDS,
+				10,
+				110,
+				RewardSchedule::Streaming
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn claim_rewards_should_claim_reward_streams_when_reward_stream_exists() {
+	ext().execute_with(|| {
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+		let omnipool_position_id = 2;
+		let deposit_id = 1;
+
+		//Arrange
+		assert_ok!(OmnipoolMining::add_reward_stream(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			DOT,
+			STREAM_REWARDS,
+			10,
+			110,
+			RewardSchedule::Streaming
+		));
+
+		assert_ok!(OmnipoolMining::deposit_shares(
+			RuntimeOrigin::signed(LP1),
+			global_farm_id,
+			yield_farm_id,
+			omnipool_position_id
+		));
+
+		//Half of the stream's range
+		set_block_number(60);
+
+		//Act
+		assert_ok!(OmnipoolMining::claim_rewards(
+			RuntimeOrigin::signed(LP1),
+			deposit_id,
+			yield_farm_id
+		));
+
+		//Assert
+		let claimed = Tokens::free_balance(DOT, &LP1);
+		//NOTE: rounding errors are expected
+		assert!(claimed.abs_diff(STREAM_REWARDS / 2) < 10);
+
+		assert_last_event!(crate::Event::RewardClaimed {
+			global_farm_id,
+			yield_farm_id,
+			who: LP1,
+			claimed,
+			reward_currency: DOT,
+			deposit_id,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn withdraw_shares_should_claim_reward_streams_when_yield_farm_is_claimable() {
+	ext().execute_with(|| {
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+		let omnipool_position_id = 2;
+		let deposit_id = 1;
+
+		//Arrange
+		assert_ok!(OmnipoolMining::add_reward_stream(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			DOT,
+			STREAM_REWARDS,
+			10,
+			110,
+			RewardSchedule::Streaming
+		));
+
+		assert_ok!(OmnipoolMining::deposit_shares(
+			RuntimeOrigin::signed(LP1),
+			global_farm_id,
+			yield_farm_id,
+			omnipool_position_id
+		));
+
+		set_block_number(1_000);
+
+		//Act
+		assert_ok!(OmnipoolMining::withdraw_shares(
+			RuntimeOrigin::signed(LP1),
+			deposit_id,
+			yield_farm_id
+		));
+
+		//Assert
+		let claimed = Tokens::free_balance(DOT, &LP1);
+		//NOTE: rounding errors are expected
+		assert!(claimed.abs_diff(STREAM_REWARDS) < 10);
+
+		assert!(has_event(
+			crate::Event::RewardClaimed {
+				global_farm_id,
+				yield_farm_id,
+				who: LP1,
+				claimed,
+				reward_currency: DOT,
+				deposit_id,
+			}
+			.into()
+		));
+
+		assert_last_event!(crate::Event::DepositDestroyed { who: LP1, deposit_id }.into());
+	});
+}
@@ -32,6 +32,7 @@ pub fn has_event(event: mock::RuntimeEvent) -> bool {
 pub mod add_liquidity_and_join_farms;
 pub mod add_liquidity_stableswap_omnipool_and_join_farms;
 pub mod add_liquidity_with_limit_and_join_farms;
+pub mod add_reward_stream;
 pub mod create_global_farm;
 pub mod create_yield_farm;
 pub mod deposit_shares;
@@ -14,6 +14,7 @@ pub trait WeightInfo {
 	fn create_global_farm() -> Weight;
 	fn update_global_farm() -> Weight;
 	fn terminate_global_farm() -> Weight;
+	fn add_reward_stream() -> Weight;
 	fn create_yield_farm() -> Weight;
 	fn update_yield_farm() -> Weight;
 	fn stop_yield_farm() -> Weight;
@@ -81,6 +82,25 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
+	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:4 w:1)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn add_reward_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1388`
+		//  Estimated: `11410`
+		// Minimum execution time: 101_270_000 picoseconds.
+		Weight::from_parts(102_488_000, 11410)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
 	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
@@ -220,14 +240,22 @@ impl WeightInfo for () {
 	/// Proof: `OmnipoolLiquidityMining::OmniPositionId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `OmnipoolWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:6 w:6)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:0 w:3)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn deposit_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4276`
-		//  Estimated: `11598`
-		// Minimum execution time: 239_939_000 picoseconds.
-		Weight::from_parts(241_587_000, 11598)
-			.saturating_add(RocksDbWeight::get().reads(17_u64))
-			.saturating_add(RocksDbWeight::get().writes(14_u64))
+		//  Estimated: `50313`
+		// Minimum execution time: 350_939_000 picoseconds.
+		Weight::from_parts(352_587_000, 50313)
+			.saturating_add(RocksDbWeight::get().reads(29_u64))
+			.saturating_add(RocksDbWeight::get().writes(29_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:2 w:0)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -249,14 +277,22 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:4 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:6 w:6)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:0 w:3)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn redeposit_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4513`
-		//  Estimated: `11598`
-		// Minimum execution time: 204_467_000 picoseconds.
-		Weight::from_parts(207_022_000, 11598)
-			.saturating_add(RocksDbWeight::get().reads(15_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		//  Estimated: `50313`
+		// Minimum execution time: 315_467_000 picoseconds.
+		Weight::from_parts(318_022_000, 50313)
+			.saturating_add(RocksDbWeight::get().reads(27_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:1 w:0)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -266,20 +302,28 @@ impl WeightInfo for () {
 	/// Proof: `OmnipoolWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
 	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:4 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:3)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:9 w:9)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3012`
-		//  Estimated: `8799`
-		// Minimum execution time: 192_783_000 picoseconds.
-		Weight::from_parts(196_174_000, 8799)
-			.saturating_add(RocksDbWeight::get().reads(10_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		//  Estimated: `63063`
+		// Minimum execution time: 381_783_000 picoseconds.
+		Weight::from_parts(385_174_000, 63063)
+			.saturating_add(RocksDbWeight::get().reads(31_u64))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:2 w:2)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -293,7 +337,7 @@ impl WeightInfo for () {
 	/// Proof: `OmnipoolWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
 	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:4 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:3)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
@@ -305,14 +349,22 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:2)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:9 w:9)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:3 w:6)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn withdraw_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4105`
-		//  Estimated: `8799`
-		// Minimum execution time: 285_929_000 picoseconds.
-		Weight::from_parts(289_072_000, 8799)
-			.saturating_add(RocksDbWeight::get().reads(15_u64))
-			.saturating_add(RocksDbWeight::get().writes(15_u64))
+		//  Estimated: `63063`
+		// Minimum execution time: 489_929_000 picoseconds.
+		Weight::from_parts(493_072_000, 63063)
+			.saturating_add(RocksDbWeight::get().reads(36_u64))
+			.saturating_add(RocksDbWeight::get().writes(36_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:2 w:2)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -344,24 +396,32 @@ impl WeightInfo for () {
 	/// Proof: `OmnipoolLiquidityMining::OmniPositionId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `OmnipoolWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:30 w:30)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:0 w:15)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn join_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4248 + c * (474 ±0)`
-		//  Estimated: `11598 + c * (2680 ±0)`
-		// Minimum execution time: 244_981_000 picoseconds.
+		//  Estimated: `11598 + c * (41395 ±0)`
+		// Minimum execution time: 355_981_000 picoseconds.
 		Weight::from_parts(147_330_577, 11598)
 			// Standard Error: 69_233
-			.saturating_add(Weight::from_parts(102_226_732, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(213_226_732, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(14_u64))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((15_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes(11_u64))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2680).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().writes((18_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 41395).saturating_mul(c.into()))
 	}
 	/// Storage: `AssetRegistry::Assets` (r:3 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:3 w:3)
+	/// Storage: `Tokens::Accounts` (r:33 w:33)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
 	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
@@ -415,20 +475,26 @@ impl WeightInfo for () {
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `OmnipoolWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:0 w:15)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn add_liquidity_and_join_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `5748 + c * (474 ±0)`
-		//  Estimated: `14250 + c * (2680 ±0)`
-		// Minimum execution time: 453_628_000 picoseconds.
+		//  Estimated: `14250 + c * (41395 ±0)`
+		// Minimum execution time: 564_628_000 picoseconds.
 		Weight::from_parts(351_105_889, 14250)
 			// Standard Error: 118_417
-			.saturating_add(Weight::from_parts(106_095_381, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(217_095_381, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(33_u64))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((15_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes(24_u64))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2680).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().writes((18_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 41395).saturating_mul(c.into()))
 	}
 	/// Storage: `Uniques::Asset` (r:2 w:2)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -442,7 +508,7 @@ impl WeightInfo for () {
 	/// Proof: `OmnipoolWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:5 w:5)
 	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:16 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:8 w:7)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
@@ -454,20 +520,28 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:2)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:45 w:45)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:15 w:30)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn exit_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3755 + c * (486 ±0)`
-		//  Estimated: `8799 + c * (2680 ±0)`
-		// Minimum execution time: 247_754_000 picoseconds.
+		//  Estimated: `8799 + c * (56944 ±0)`
+		// Minimum execution time: 451_754_000 picoseconds.
 		Weight::from_parts(90_316_650, 8799)
 			// Standard Error: 240_623
-			.saturating_add(Weight::from_parts(159_106_526, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(363_106_526, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((24_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2680).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().writes((24_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 56944).saturating_mul(c.into()))
 	}
 
 	/// Storage: `Stableswap::Pools` (r:1 w:0)
@@ -476,7 +550,7 @@ impl WeightInfo for () {
 	/// Proof: `Stableswap::AssetTradability` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:8 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:13 w:13)
+	/// Storage: `Tokens::Accounts` (r:43 w:43)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::TotalIssuance` (r:2 w:2)
 	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
@@ -532,20 +606,26 @@ impl WeightInfo for () {
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `OmnipoolWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:0 w:15)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn add_liquidity_stableswap_omnipool_and_join_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `22068 + c * (507 ±0)`
-		//  Estimated: `34569 + c * (2680 ±0)`
-		// Minimum execution time: 1_247_561_000 picoseconds.
+		//  Estimated: `34569 + c * (41395 ±0)`
+		// Minimum execution time: 1_358_561_000 picoseconds.
 		Weight::from_parts(1_200_125_260, 34569)
 			// Standard Error: 863_778
-			.saturating_add(Weight::from_parts(91_357_644, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(202_357_644, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(62_u64))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((15_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes(35_u64))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2680).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().writes((18_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 41395).saturating_mul(c.into()))
 	}
 
 	/// Storage: `Uniques::Asset` (r:2 w:2)
@@ -560,7 +640,7 @@ impl WeightInfo for () {
 	/// Proof: `OmnipoolWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:5 w:5)
 	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:8 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:11 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:0)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
@@ -568,7 +648,7 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
 	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:13 w:13)
+	/// Storage: `Tokens::Accounts` (r:22 w:22)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `DynamicFees::AssetFeeConfiguration` (r:1 w:0)
 	/// Proof: `DynamicFees::AssetFeeConfiguration` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
@@ -604,20 +684,26 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:2)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:3 w:6)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[0, 1]`.
 	fn remove_liquidity_stableswap_omnipool_and_exit_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `25250 + c * (5724 ±0)`
-		//  Estimated: `34569 + c * (7195 ±0)`
-		// Minimum execution time: 571_000_000 picoseconds.
+		//  Estimated: `34569 + c * (61459 ±0)`
+		// Minimum execution time: 775_000_000 picoseconds.
 		Weight::from_parts(626_842_857, 34569)
 			// Standard Error: 2_082_005
-			.saturating_add(Weight::from_parts(261_557_142, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(465_557_142, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(53_u64))
-			.saturating_add(RocksDbWeight::get().reads((16_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((37_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes(22_u64))
-			.saturating_add(RocksDbWeight::get().writes((17_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 7195).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().writes((38_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 61459).saturating_mul(c.into()))
 	}
 	fn price_adjustment_get() -> Weight {
 		// Proof Size summary in bytes:
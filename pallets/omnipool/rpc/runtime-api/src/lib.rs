@@ -0,0 +1,102 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the Omnipool pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::{FixedU128, Permill};
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// State of an asset in the Omnipool.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AssetState<AssetId, Balance> {
+	pub asset_id: AssetId,
+	/// Quantity of asset in the Omnipool.
+	pub reserve: Balance,
+	/// Quantity of hub asset matching the asset.
+	pub hub_reserve: Balance,
+	/// Quantity of LP shares of the asset.
+	pub shares: Balance,
+	/// Quantity of LP shares of the asset owned by protocol.
+	pub protocol_shares: Balance,
+	/// Weight cap of the asset.
+	pub cap: FixedU128,
+	/// Tradability bit flags: 1 sell, 2 buy, 4 add liquidity, 8 remove liquidity.
+	pub tradable: u8,
+	/// Current dynamic asset fee.
+	pub asset_fee: Permill,
+	/// Current dynamic protocol fee.
+	pub protocol_fee: Permill,
+}
+
+/// State of the Omnipool.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OmnipoolState<AssetId, Balance> {
+	/// Tradability bit flags of hub asset, same as of the assets.
+	pub hub_asset_tradability: u8,
+	/// Maximum slip fee rate per side. `None` if slip fees are disabled.
+	pub max_slip_fee: Option<Permill>,
+	pub assets: Vec<AssetState<AssetId, Balance>>,
+}
+
+/// Value of an LP position if all its liquidity was removed at the current asset state.
+/// Values are in units of the position asset, hub asset is valued at the current spot price.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PositionValuation<AssetId, Balance> {
+	pub asset_id: AssetId,
+	/// Amount of asset provided by the position.
+	pub amount: Balance,
+	/// Quantity of LP shares of the position.
+	pub shares: Balance,
+	/// Amount of asset received on removal, after the withdrawal fee.
+	pub asset_amount: Balance,
+	/// Amount of hub asset received on removal, after the withdrawal fee.
+	pub hub_amount: Balance,
+	/// Current value of the position, after the withdrawal fee.
+	pub value: Balance,
+	/// Current withdrawal fee rate.
+	pub withdrawal_fee: FixedU128,
+	/// Value paid as the withdrawal fee.
+	pub withdrawal_fee_amount: Balance,
+	/// Value lost compared to holding the provided amount, before the withdrawal fee.
+	pub impermanent_loss: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the Omnipool state and LP positions.
+	pub trait OmnipoolApi<AssetId, PositionId, Balance> where
+		AssetId: Codec,
+		PositionId: Codec,
+		Balance: Codec,
+	{
+		/// Return the state of the Omnipool and all its assets.
+		fn state() -> OmnipoolState<AssetId, Balance>;
+
+		/// Return the state of the asset, if it is in the Omnipool.
+		fn asset_state(asset_id: AssetId) -> Option<AssetState<AssetId, Balance>>;
+
+		/// Return the value of the position if all its liquidity was removed now.
+		fn position_valuation(position_id: PositionId) -> Result<PositionValuation<AssetId, Balance>, DispatchError>;
+	}
+}
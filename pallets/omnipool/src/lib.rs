@@ -113,7 +113,7 @@ pub mod types;
 pub mod weights;
 
 use crate::traits::{AssetInfo, OmnipoolHooks};
-use crate::types::{AssetReserveState, AssetState, Balance, Position, SlipFeeConfig, Tradability};
+use crate::types::{AssetReserveState, AssetState, Balance, Position, PositionValue, SlipFeeConfig, Tradability};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -2291,6 +2291,65 @@ impl<T: Config> Pallet<T> {
 		Assets::<T>::contains_key(asset)
 	}
 
+	/// Returns tradable state of hub asset.
+	pub fn hub_asset_tradability() -> Tradability {
+		HubAssetTradability::<T>::get()
+	}
+
+	/// Calculate value of a position if all its liquidity was removed at the current asset state.
+	///
+	/// Uses the same withdrawal fee and remove liquidity math as `remove_liquidity`.
+	/// Price barrier and tradability of the asset are not checked.
+	pub fn position_value(position_id: T::PositionItemId) -> Result<PositionValue<Balance>, DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+		let asset_state = Self::load_asset_state(position.asset_id)?;
+
+		let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), position.asset_id)?;
+		if ext_asset_price.is_zero() {
+			return Err(Error::<T>::InvalidOraclePrice.into());
+		}
+		let withdrawal_fee = hydra_dx_math::omnipool::calculate_withdrawal_fee(
+			asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
+			FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
+				.ok_or(Error::<T>::InvalidOraclePrice)?,
+			T::MinWithdrawalFee::get(),
+		);
+
+		let remove_all = |fee: FixedU128| -> Result<(Balance, Balance, Balance), DispatchError> {
+			let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+				&(&asset_state).into(),
+				position.shares,
+				&(&position).into(),
+				fee,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			let asset_amount = *state_changes.asset.delta_reserve;
+			let hub_amount = state_changes.lp_hub_amount;
+			let hub_value: Balance = U256::from(hub_amount)
+				.checked_mul(asset_state.reserve.into())
+				.and_then(|v| v.checked_div(asset_state.hub_reserve.into()))
+				.ok_or(ArithmeticError::DivisionByZero)?
+				.try_into()
+				.map_err(|_| ArithmeticError::Overflow)?;
+			let value = asset_amount.checked_add(hub_value).ok_or(ArithmeticError::Overflow)?;
+
+			Ok((asset_amount, hub_amount, value))
+		};
+
+		let (_, _, value_without_fee) = remove_all(FixedU128::zero())?;
+		let (asset_amount, hub_amount, value) = remove_all(withdrawal_fee)?;
+
+		Ok(PositionValue {
+			asset_amount,
+			hub_amount,
+			value,
+			withdrawal_fee,
+			withdrawal_fee_amount: value_without_fee.saturating_sub(value),
+			impermanent_loss: position.amount.saturating_sub(value_without_fee),
+		})
+	}
+
 	/// Calls `on_trade_fee` hook and ensures that no more than the fee amount is transferred.
 	#[allow(clippy::type_complexity)]
 	fn process_trade_fee(
@@ -1,12 +1,13 @@
-use crate::types::Balance;
-use crate::{Config, Error, Pallet};
+use crate::types::{Balance, Tradability};
+use crate::{Assets, Config, Error, Pallet};
 use frame_system::pallet_prelude::OriginFor;
 
 use hydradx_traits::fee::GetDynamicFee;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, Trade, TradeExecution, TradeablePoolsProvider};
 use sp_runtime::traits::Get;
 use sp_runtime::DispatchError::Corruption;
 use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::vec::Vec;
 
 // dev note: The code is calculate sell and buy is copied from the corresponding functions.
 // This is not ideal and should be refactored to avoid code duplication.
@@ -211,3 +212,30 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 		Ok(spot_price)
 	}
 }
+
+impl<T: Config> TradeablePoolsProvider<T::AssetId> for Pallet<T> {
+	fn tradeable_pools() -> Vec<Trade<T::AssetId>> {
+		let assets: Vec<(T::AssetId, Tradability)> = Assets::<T>::iter()
+			.map(|(asset_id, state)| (asset_id, state.tradable))
+			.collect();
+
+		let mut trades = Vec::new();
+		for (asset_in, tradable_in) in assets.iter() {
+			if !tradable_in.contains(Tradability::SELL) {
+				continue;
+			}
+
+			for (asset_out, tradable_out) in assets.iter() {
+				if asset_in != asset_out && tradable_out.contains(Tradability::BUY) {
+					trades.push(Trade {
+						pool: PoolType::Omnipool,
+						asset_in: *asset_in,
+						asset_out: *asset_out,
+					});
+				}
+			}
+		}
+
+		trades
+	}
+}
@@ -14,6 +14,7 @@ mod add_all_liquidity;
 mod add_liquidity_with_limit;
 mod barrier;
 pub(crate) mod mock;
+mod position_value;
 mod positions;
 mod protocol_fee;
 mod refund;
@@ -0,0 +1,112 @@
+use super::*;
+use frame_support::assert_noop;
+
+#[test]
+fn position_value_should_equal_provided_amount_when_price_has_not_changed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+
+			// Act
+			let value = Omnipool::position_value(position_id).unwrap();
+
+			// Assert
+			assert_eq!(
+				value,
+				PositionValue {
+					asset_amount: 400 * ONE,
+					hub_amount: 0,
+					value: 400 * ONE,
+					withdrawal_fee: FixedU128::zero(),
+					withdrawal_fee_amount: 0,
+					impermanent_loss: 0,
+				}
+			);
+		});
+}
+
+#[test]
+fn position_value_should_match_amounts_received_on_removal_when_price_has_changed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP3, 1_000, 100 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+			(LP2, DAI, 50000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP3, 100 * ONE)
+		.with_min_withdrawal_fee(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let liq_added = 400 * ONE;
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, liq_added));
+			assert_ok!(Omnipool::buy(
+				RuntimeOrigin::signed(LP2),
+				1_000,
+				DAI,
+				200 * ONE,
+				500000 * ONE
+			));
+			let asset_state = Omnipool::load_asset_state(1_000).unwrap();
+
+			// Act
+			let value = Omnipool::position_value(position_id).unwrap();
+
+			// Assert
+			assert_eq!(value.withdrawal_fee, FixedU128::from_rational(1, 100));
+			assert!(value.hub_amount > 0);
+			assert_eq!(
+				value.value,
+				value.asset_amount + value.hub_amount * asset_state.reserve / asset_state.hub_reserve
+			);
+			assert_eq_approx!(
+				value.withdrawal_fee_amount,
+				(value.value + value.withdrawal_fee_amount) / 100,
+				1,
+				"withdrawal fee amount"
+			);
+			assert_eq!(
+				value.impermanent_loss,
+				liq_added - value.value - value.withdrawal_fee_amount
+			);
+
+			let asset_balance = Tokens::free_balance(1_000, &LP1);
+			let hub_balance = Tokens::free_balance(LRNA, &LP1);
+			assert_ok!(Omnipool::remove_liquidity(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				liq_added
+			));
+			assert_balance!(LP1, 1_000, asset_balance + value.asset_amount);
+			assert_balance!(LP1, LRNA, hub_balance + value.hub_amount);
+		});
+}
+
+#[test]
+fn position_value_should_fail_when_position_does_not_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(Omnipool::position_value(1_000), Error::<Test>::PositionNotFound);
+		});
+}
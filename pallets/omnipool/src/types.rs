@@ -259,3 +259,22 @@ where
 		})
 	}
 }
+
+/// Value of a position if all its liquidity was removed at the current asset state.
+///
+/// Values are in units of the position asset, hub asset is valued at the current spot price.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PositionValue<Balance> {
+	/// Amount of asset received, after the withdrawal fee.
+	pub asset_amount: Balance,
+	/// Amount of hub asset received, after the withdrawal fee.
+	pub hub_amount: Balance,
+	/// Value of received asset and hub asset, after the withdrawal fee.
+	pub value: Balance,
+	/// Withdrawal fee rate.
+	pub withdrawal_fee: FixedU128,
+	/// Value paid as the withdrawal fee.
+	pub withdrawal_fee_amount: Balance,
+	/// Value lost compared to holding the provided amount, before the withdrawal fee.
+	pub impermanent_loss: Balance,
+}
@@ -27,6 +27,7 @@
 //! aligns the OTC and the Omnipool prices. Executing this trade needs to be profitable, but we are not trying to maximize
 //! the profit. If the pallet couldn't find the amount that closes the arb, the amount that reduces the size of the arb is used.
 //! In the case of not partially fillable OTC orders, the pallet tries to maximize the profit.
+//! Expired OTC orders are skipped, and partial fills are never lower than the minimum fill amount of the order.
 //!
 //! ## Dispatachable functions
 //! * `settle_otc_order` -  Executes a trade between an OTC order and some route.
@@ -87,7 +88,7 @@ pub use weights::WeightInfo;
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
-use pallet_otc::Order;
+use pallet_otc::OrderOf;
 
 pub type Balance = u128;
 pub type NamedReserveIdentifier = [u8; 8];
@@ -462,6 +463,13 @@ impl<T: Config> Pallet<T> {
 
 				let mut list = vec![];
 				for (otc_id, otc) in <pallet_otc::Orders<T>>::iter() {
+					if pallet_otc::Pallet::<T>::is_expired(&otc) {
+						log::debug!(
+							target: "offchain_worker::sort_otcs",
+							"expired, skipping OTC: {otc_id:?}");
+						continue;
+					}
+
 					let otc_price = Self::otc_price(&otc).ok();
 
 					let route = T::Router::get_route(AssetPair {
@@ -542,15 +550,17 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Try to find the correct amount to close the arbitrage opportunity.
-	fn try_find_trade_amount(
-		otc_id: OrderId,
-		otc: &Order<T::AccountId, T::AssetId>,
-		route: &Route<AssetIdOf<T>>,
-	) -> Option<Balance> {
+	fn try_find_trade_amount(otc_id: OrderId, otc: &OrderOf<T>, route: &Route<AssetIdOf<T>>) -> Option<Balance> {
 		// use binary search to determine the correct sell amount
 		let mut sell_amt = otc.amount_in; // start by trying to fill the whole order
 		let mut sell_amt_up = sell_amt;
-		let mut sell_amt_down = T::MinTradingLimit::get();
+		// partial fills of the order cannot be lower than its minimum fill amount
+		let min_sell_amt = otc
+			.min_fill_amount
+			.map_or(T::MinTradingLimit::get(), |min_fill_amount| {
+				min_fill_amount.max(T::MinTradingLimit::get())
+			});
+		let mut sell_amt_down = min_sell_amt;
 
 		let iters = if otc.partially_fillable {
 			T::MaxIterations::get()
@@ -600,7 +610,7 @@ impl<T: Config> Pallet<T> {
 			sell_amt = (sell_amt_up.checked_add(sell_amt_down)).and_then(|value| value.checked_div(2))?;
 		}
 		// execute with the latest min value
-		if sell_amt_down != T::MinTradingLimit::get() {
+		if sell_amt_down != min_sell_amt {
 			match Self::settle_otc(otc_id, sell_amt_down, route.clone(), true) {
 				Ok(_) => Some(sell_amt_down),
 				Err(_) => None,
@@ -611,7 +621,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Calculates the price (asset_out/asset_in) after subtracting the OTC fee from the amount_out.
-	fn otc_price(otc: &Order<T::AccountId, T::AssetId>) -> Result<FixedU128, DispatchError> {
+	fn otc_price(otc: &OrderOf<T>) -> Result<FixedU128, DispatchError> {
 		let fee = pallet_otc::Pallet::<T>::calculate_fee(otc.amount_out);
 		Ok(FixedU128::checked_from_rational(
 			otc.amount_out.checked_sub(fee).ok_or(ArithmeticError::Overflow)?,
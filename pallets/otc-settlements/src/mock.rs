@@ -142,6 +142,9 @@ impl pallet_route_executor::Config for Test {
 	type OraclePeriod = RouteValidationOraclePeriod;
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type ForceInsertOrigin = EnsureRoot<Self::AccountId>;
+	type PoolsProvider = Omnipool;
+	type MaxRouteCandidates = frame_support::traits::ConstU32<10>;
+	type MaxRouteSearchSteps = frame_support::traits::ConstU32<100>;
 	type WeightInfo = ();
 }
 
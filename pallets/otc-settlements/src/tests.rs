@@ -35,7 +35,7 @@ pub fn expect_last_events(e: Vec<RuntimeEvent>) {
 	test_utils::expect_events::<RuntimeEvent, Test>(e);
 }
 
-pub fn calculate_otc_price(otc: &pallet_otc::Order<AccountId, AssetId>) -> FixedU128 {
+pub fn calculate_otc_price(otc: &pallet_otc::OrderOf<Test>) -> FixedU128 {
 	FixedU128::checked_from_rational(otc.amount_out, otc.amount_in).unwrap()
 }
 
@@ -385,7 +385,9 @@ fn existing_arb_opportunity_should_trigger_trade_when_otc_is_not_partially_filla
 			DAI, // otc asset_out
 			100_000 * ONE,
 			300_000 * ONE,
-			false, // not partially fillable
+			false, // not partially fillable,
+			None,
+			None
 		));
 
 		// get otc price
@@ -636,6 +638,50 @@ fn trade_should_not_be_triggered_when_there_is_no_arb_opportunity() {
 	});
 }
 
+#[test]
+fn trade_should_not_be_triggered_when_otc_is_expired() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		assert_ok!(OTC::place_order_with_constraints(
+			RuntimeOrigin::signed(ALICE),
+			HDX, // otc asset_in
+			DAI, // otc asset_out
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+			Some(System::block_number() + 1),
+			None,
+		));
+
+		// get otc price
+		let otc_id = 0;
+		let otc = <pallet_otc::Orders<Test>>::get(otc_id).unwrap();
+		let otc_price = calculate_otc_price(&otc);
+
+		// get trade price
+		let route = Router::get_route(AssetPair {
+			asset_in: otc.asset_out,
+			asset_out: otc.asset_in,
+		});
+		let router_price = Router::spot_price_with_fee(&route).unwrap();
+
+		// verify that there's an arb opportunity
+		assert!(otc_price > router_price);
+
+		System::set_block_number(System::block_number() + 1);
+
+		assert_storage_noop!(<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(
+			System::block_number()
+		));
+
+		let sorted_otcs = StorageValueRef::persistent(OFFCHAIN_WORKER_DATA)
+			.get::<Vec<SortedOtcsStorageType>>()
+			.unwrap_or_default()
+			.unwrap_or_default();
+		assert!(sorted_otcs.is_empty());
+	});
+}
+
 #[test]
 fn trade_should_be_triggered_when_optimal_amount_not_found_but_arb_can_be_reduced() {
 	let (mut ext, _) = ExtBuilder::default().build();
@@ -0,0 +1,70 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the OTC pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::FixedU128;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+pub type OrderId = u32;
+
+/// Open OTC order as seen by a taker.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OrderBookEntry<AccountId, AssetId, BlockNumber, Balance> {
+	pub order_id: OrderId,
+	pub owner: AccountId,
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	/// Remaining amount of `asset_in` the order is seeking to buy.
+	pub amount_in: Balance,
+	/// Remaining amount of `asset_out` the order is selling, including the fee.
+	pub amount_out: Balance,
+	/// Fee deducted from `amount_out` when the remaining order is filled.
+	pub fee: Balance,
+	/// Price of the order (asset_out/asset_in) after subtracting the fee.
+	pub price: FixedU128,
+	pub partially_fillable: bool,
+	pub expires_at: Option<BlockNumber>,
+	pub min_fill_amount: Option<Balance>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query open OTC orders.
+	pub trait OtcApi<AccountId, AssetId, BlockNumber, Balance> where
+		AccountId: Codec,
+		AssetId: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+	{
+		/// Return the open orders matching the given filters, best priced orders first.
+		/// Expired orders are not returned.
+		fn orders(
+			asset_in: Option<AssetId>,
+			asset_out: Option<AssetId>,
+			owner: Option<AccountId>,
+		) -> Vec<OrderBookEntry<AccountId, AssetId, BlockNumber, Balance>>;
+
+		/// Return the order with the given ID, if it exists.
+		fn order(order_id: OrderId) -> Option<OrderBookEntry<AccountId, AssetId, BlockNumber, Balance>>;
+	}
+}
@@ -40,6 +40,15 @@ benchmarks! {
 		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 100 * ONE);
 	}
 
+  place_order_with_constraints {
+		let (dot, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
+  }:  _(RawOrigin::Signed(owner.clone()), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, Some(10u32.into()), Some(ONE))
+	verify {
+		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 100 * ONE);
+	}
+
 	partial_fill_order {
 		let (dot, dai) = seed_registry::<T>()?;
 
@@ -47,7 +56,7 @@ benchmarks! {
 		let filler: T::AccountId = create_account_with_balances::<T>("filler", 2, vec!(dot, dai))?;
 
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order_with_constraints(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, Some(10u32.into()), None)
 		);
   }:  _(RawOrigin::Signed(filler.clone()), 0u32, 10 * ONE)
 	verify {
@@ -73,12 +82,27 @@ benchmarks! {
 
 		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order_with_constraints(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, Some(10u32.into()), None)
 		);
   }:  _(RawOrigin::Signed(owner.clone()), 0u32)
 	verify {
 		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 0);
 	}
+
+	expire_order {
+		let (dot, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
+		assert_ok!(
+			crate::Pallet::<T>::place_order_with_constraints(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, Some(10u32.into()), None)
+		);
+  }: {
+		crate::Pallet::<T>::expire_order(10u32.into(), 0u32)
+	}
+	verify {
+		assert!(crate::Pallet::<T>::orders(0u32).is_none());
+		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 0);
+	}
 }
 
 fn seed_registry<T: Config>() -> Result<(u32, u32), DispatchError>
@@ -20,6 +20,10 @@
 // whether the order is partially fillable. Fee is applied to all trades and is deducted from the `amount_out`.
 // Because of the fee, the order price is static and calculated as `(amount_out - fee) / amount_in`.
 //
+// An order placed by `place_order_with_constraints` can expire at a given block and can require a minimum `amount_in`
+// for partial fills. Expired orders cannot be filled. They are removed in `on_idle` and the reserved `amount_out` is
+// returned to the owner.
+//
 // ## Notes
 // The pallet implements a minimum order size as an alternative to storage fees. The amounts of an open order cannot
 // be lower than the existential deposit for the respective asset, multiplied by `ExistentialDepositMultiplier`.
@@ -28,6 +32,7 @@
 //
 // ## Dispatachable functions
 // * `place_order` -  create a new OTC order.
+// * `place_order_with_constraints` -  create a new OTC order with an expiry and a minimum partial fill amount.
 // * `partial_fill_order` - fill an OTC order (partially).
 // * `fill_order` - fill an OTC order (completely).
 // * `cancel_order` - cancel an open OTC order.
@@ -37,15 +42,19 @@
 
 use codec::MaxEncodedLen;
 use frame_support::{pallet_prelude::*, require_transactional, traits::ExistenceRequirement};
-use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use frame_system::{
+	ensure_signed,
+	pallet_prelude::{BlockNumberFor, OriginFor},
+};
 use hydradx_traits::Inspect;
 use orml_traits::{GetByKey, MultiCurrency, NamedMultiReservableCurrency};
 use pallet_broadcast::types::Destination;
 use pallet_broadcast::types::Fee;
 use sp_core::U256;
 use sp_runtime::traits::{One, Zero};
-use sp_runtime::Permill;
+use sp_runtime::{FixedPointNumber, FixedU128, Permill};
 use sp_std::vec;
+use sp_std::vec::Vec;
 
 #[cfg(test)]
 mod tests;
@@ -53,6 +62,7 @@ mod tests;
 #[cfg(any(feature = "runtime-benchmarks", test))]
 mod benchmarks;
 
+pub mod migrations;
 pub mod weights;
 
 pub use weights::WeightInfo;
@@ -67,21 +77,28 @@ pub type NamedReserveIdentifier = [u8; 8];
 pub const NAMED_RESERVE_ID: NamedReserveIdentifier = *b"otcorder";
 
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
-pub struct Order<AccountId, AssetId> {
+pub struct Order<AccountId, AssetId, BlockNumber> {
 	pub owner: AccountId,
 	pub asset_in: AssetId,
 	pub asset_out: AssetId,
 	pub amount_in: Balance,
 	pub amount_out: Balance,
 	pub partially_fillable: bool,
+	/// The order cannot be filled from this block on.
+	pub expires_at: Option<BlockNumber>,
+	/// Minimum `amount_in` of a partial fill.
+	pub min_fill_amount: Option<Balance>,
 }
 
+pub type OrderOf<T> = Order<<T as frame_system::Config>::AccountId, <T as Config>::AssetId, BlockNumberFor<T>>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use codec::HasCompact;
 
 	#[pallet::pallet]
+	#[pallet::storage_version(migrations::STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -151,6 +168,8 @@ pub mod pallet {
 			amount_out: Balance,
 			partially_fillable: bool,
 		},
+		/// An expired Order has been removed
+		Expired { order_id: OrderId },
 	}
 
 	#[pallet::error]
@@ -173,6 +192,14 @@ pub mod pallet {
 		Forbidden,
 		/// Reserved amount not sufficient.
 		InsufficientReservedAmount,
+		/// Order has expired
+		OrderExpired,
+		/// Expiry block must be in the future
+		InvalidExpiry,
+		/// Minimum fill amount cannot be higher than the order amount_in
+		InvalidMinFillAmount,
+		/// Fill amount is lower than the minimum fill amount of the order
+		FillAmountTooSmall,
 	}
 
 	/// ID sequencer for Orders
@@ -182,7 +209,23 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn orders)]
-	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, Order<T::AccountId, T::AssetId>, OptionQuery>;
+	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, OrderOf<T>, OptionQuery>;
+
+	/// Orders with an expiry, indexed by the expiry block.
+	#[pallet::storage]
+	pub type OrderExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, OrderId, (), OptionQuery>;
+
+	/// First block whose expired orders have not been removed yet.
+	#[pallet::storage]
+	pub type NextExpiryBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::remove_expired_orders(n, remaining_weight)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -215,43 +258,16 @@ pub mod pallet {
 			partially_fillable: bool,
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
-			let order = Order {
+
+			Self::do_place_order(Order {
 				owner,
 				asset_in,
 				asset_out,
 				amount_in,
 				amount_out,
 				partially_fillable,
-			};
-
-			ensure!(T::AssetRegistry::exists(order.asset_in), Error::<T>::AssetNotRegistered);
-
-			let fee = Self::calculate_fee(order.amount_out);
-
-			Self::ensure_min_order_amount(order.asset_in, order.amount_in)?;
-			// the fee is applied to amount_out
-			Self::ensure_min_order_amount(
-				order.asset_out,
-				order.amount_out.checked_sub(fee).ok_or(Error::<T>::MathError)?,
-			)?;
-
-			<NextOrderId<T>>::try_mutate(|next_id| -> DispatchResult {
-				let order_id = *next_id;
-
-				T::Currency::reserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out)?;
-				<Orders<T>>::insert(order_id, &order);
-
-				Self::deposit_event(Event::Placed {
-					order_id,
-					asset_in: order.asset_in,
-					asset_out: order.asset_out,
-					amount_in: order.amount_in,
-					amount_out,
-					partially_fillable: order.partially_fillable,
-				});
-
-				*next_id = next_id.checked_add(One::one()).ok_or(Error::<T>::OrderIdOutOfBound)?;
-				Ok(())
+				expires_at: None,
+				min_fill_amount: None,
 			})
 		}
 
@@ -263,6 +279,8 @@ pub mod pallet {
 		///
 		/// Validations:
 		/// - order must be partially_fillable
+		/// - order must not be expired
+		/// - amount_in cannot be lower than the min_fill_amount of the order
 		/// - after the partial_fill, the remaining order.amount_in must be higher than the existential deposit
 		///   of asset_in multiplied by ExistentialDepositMultiplier
 		/// - after the partial_fill, the remaining order.amount_out must be higher than the existential deposit
@@ -279,6 +297,8 @@ pub mod pallet {
 				let order = maybe_order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
 
 				ensure!(order.partially_fillable, Error::<T>::OrderNotPartiallyFillable);
+				Self::ensure_not_expired(order)?;
+				Self::ensure_min_fill_amount(order, amount_in)?;
 
 				let amount_out = Self::partial_amount_out(order.amount_out, amount_in, order.amount_in)?;
 				let fee = Self::calculate_fee(amount_out);
@@ -313,6 +333,9 @@ pub mod pallet {
 		/// Parameters:
 		/// - `order_id`: ID of the order
 		///
+		/// Validations:
+		/// - order must not be expired
+		///
 		/// Events:
 		/// `Filled` event when successful. Deprecated.
 		/// `pallet_broadcast::Swapped` event when successful.
@@ -321,11 +344,12 @@ pub mod pallet {
 		pub fn fill_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			Self::ensure_not_expired(&order)?;
 
 			let fee = Self::calculate_fee(order.amount_out);
 
 			Self::execute_order(&order, &who, order.amount_in, order.amount_out, fee)?;
-			<Orders<T>>::remove(order_id);
+			Self::remove_order(order_id, &order);
 
 			Self::deposit_fill_events(
 				order_id,
@@ -365,12 +389,68 @@ pub mod pallet {
 				let remaining_to_unreserve =
 					T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
 				ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+				if let Some(expires_at) = order.expires_at {
+					<OrderExpiries<T>>::remove(expires_at, order_id);
+				}
 				*maybe_order = None;
 
 				Self::deposit_event(Event::Cancelled { order_id });
 				Ok(())
 			})
 		}
+		/// Create a new OTC order with an optional expiry and minimum partial fill amount
+		///
+		/// Parameters:
+		/// - `asset_in`: Asset which is being bought
+		/// - `asset_out`: Asset which is being sold
+		/// - `amount_in`: Amount that the order is seeking to buy
+		/// - `amount_out`: Amount that the order is selling
+		/// - `partially_fillable`: Flag indicating whether users can fill the order partially
+		/// - `expires_at`: Optional block from which the order cannot be filled and is removed
+		/// - `min_fill_amount`: Optional minimum amount_in of a partial fill
+		///
+		/// Validations:
+		/// - same as `place_order`
+		/// - expires_at must be higher than the current block number
+		/// - min_fill_amount cannot be higher than amount_in
+		///
+		/// Events:
+		/// - `Placed` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_order_with_constraints())]
+		pub fn place_order_with_constraints(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			partially_fillable: bool,
+			expires_at: Option<BlockNumberFor<T>>,
+			min_fill_amount: Option<Balance>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			if let Some(expires_at) = expires_at {
+				ensure!(
+					expires_at > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidExpiry
+				);
+			}
+			if let Some(min_fill_amount) = min_fill_amount {
+				ensure!(min_fill_amount <= amount_in, Error::<T>::InvalidMinFillAmount);
+			}
+
+			Self::do_place_order(Order {
+				owner,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				partially_fillable,
+				expires_at,
+				min_fill_amount,
+			})
+		}
 	}
 }
 
@@ -385,9 +465,139 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn do_place_order(order: OrderOf<T>) -> DispatchResult {
+		ensure!(T::AssetRegistry::exists(order.asset_in), Error::<T>::AssetNotRegistered);
+
+		let fee = Self::calculate_fee(order.amount_out);
+
+		Self::ensure_min_order_amount(order.asset_in, order.amount_in)?;
+		// the fee is applied to amount_out
+		Self::ensure_min_order_amount(
+			order.asset_out,
+			order.amount_out.checked_sub(fee).ok_or(Error::<T>::MathError)?,
+		)?;
+
+		<NextOrderId<T>>::try_mutate(|next_id| -> DispatchResult {
+			let order_id = *next_id;
+
+			T::Currency::reserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out)?;
+			<Orders<T>>::insert(order_id, &order);
+			if let Some(expires_at) = order.expires_at {
+				<OrderExpiries<T>>::insert(expires_at, order_id, ());
+				// expired orders are removed from `NextExpiryBlock` on, which is never higher than `expires_at`
+				<NextExpiryBlock<T>>::mutate(|next_expiry_block| {
+					*next_expiry_block = Some(next_expiry_block.map_or(expires_at, |block| block.min(expires_at)));
+				});
+			}
+
+			Self::deposit_event(Event::Placed {
+				order_id,
+				asset_in: order.asset_in,
+				asset_out: order.asset_out,
+				amount_in: order.amount_in,
+				amount_out: order.amount_out,
+				partially_fillable: order.partially_fillable,
+			});
+
+			*next_id = next_id.checked_add(One::one()).ok_or(Error::<T>::OrderIdOutOfBound)?;
+			Ok(())
+		})
+	}
+
+	fn ensure_not_expired(order: &OrderOf<T>) -> DispatchResult {
+		ensure!(!Self::is_expired(order), Error::<T>::OrderExpired);
+		Ok(())
+	}
+
+	fn ensure_min_fill_amount(order: &OrderOf<T>, amount_in: Balance) -> DispatchResult {
+		if let Some(min_fill_amount) = order.min_fill_amount {
+			ensure!(amount_in >= min_fill_amount, Error::<T>::FillAmountTooSmall);
+		}
+		Ok(())
+	}
+
+	/// Returns `true` if the order cannot be filled anymore because its expiry block has been reached.
+	pub fn is_expired(order: &OrderOf<T>) -> bool {
+		order
+			.expires_at
+			.is_some_and(|expires_at| frame_system::Pallet::<T>::block_number() >= expires_at)
+	}
+
+	fn remove_order(order_id: OrderId, order: &OrderOf<T>) {
+		<Orders<T>>::remove(order_id);
+		if let Some(expires_at) = order.expires_at {
+			<OrderExpiries<T>>::remove(expires_at, order_id);
+		}
+	}
+
+	/// Removes the orders which expired up to block `now` and unreserves their `amount_out`.
+	///
+	/// Blocks are processed in order, starting at `NextExpiryBlock`. If the remaining weight is not enough
+	/// to process all the expired orders, the rest is removed in the next blocks.
+	/// `NextExpiryBlock` is lowered whenever an order expiring before it is placed.
+	fn remove_expired_orders(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		// reading and writing `NextExpiryBlock`
+		let mut used_weight = T::DbWeight::get().reads_writes(1, 1);
+		if remaining_weight.any_lt(used_weight) {
+			return Weight::zero();
+		}
+
+		let Some(next_expiry_block) = <NextExpiryBlock<T>>::get() else {
+			// no order with an expiry has been placed yet
+			return T::DbWeight::get().reads(1);
+		};
+		let mut block = next_expiry_block;
+		let expire_weight = T::WeightInfo::expire_order();
+
+		'blocks: while block <= now {
+			// reading the first key of the block prefix
+			let block_weight = used_weight.saturating_add(T::DbWeight::get().reads(1));
+			if remaining_weight.any_lt(block_weight) {
+				break;
+			}
+			used_weight = block_weight;
+
+			for order_id in <OrderExpiries<T>>::iter_key_prefix(block) {
+				let order_weight = used_weight.saturating_add(expire_weight);
+				if remaining_weight.any_lt(order_weight) {
+					break 'blocks;
+				}
+				used_weight = order_weight;
+
+				Self::expire_order(block, order_id);
+			}
+
+			block = block.saturating_add(One::one());
+		}
+
+		if block != next_expiry_block {
+			<NextExpiryBlock<T>>::put(block);
+		}
+
+		used_weight
+	}
+
+	pub(crate) fn expire_order(expires_at: BlockNumberFor<T>, order_id: OrderId) {
+		<OrderExpiries<T>>::remove(expires_at, order_id);
+
+		let Some(order) = <Orders<T>>::take(order_id) else {
+			return;
+		};
+
+		let remaining_to_unreserve =
+			T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
+		if !remaining_to_unreserve.is_zero() {
+			log::warn!(
+				target: "runtime::otc",
+				"expired order {order_id:?} had insufficient reserved amount, remaining: {remaining_to_unreserve:?}");
+		}
+
+		Self::deposit_event(Event::Expired { order_id });
+	}
+
 	#[require_transactional]
 	fn execute_order(
-		order: &Order<T::AccountId, T::AssetId>,
+		order: &OrderOf<T>,
 		who: &T::AccountId,
 		amount_in: Balance,
 		amount_out: Balance,
@@ -530,6 +740,8 @@ impl<T: Config> Pallet<T> {
 	/// `filler` to the maker to complete the fill. The final transfer fails if `deliver` did not
 	/// leave `filler` holding at least `amount_in` of `asset_in`.
 	///
+	/// Expired orders cannot be filled, and a partial fill cannot be lower than the order's `min_fill_amount`.
+	///
 	/// Must run inside a transaction: a failure in `deliver` or in any transfer rolls the whole fill
 	/// back, so the order and the maker's reserve are never left half-settled.
 	#[require_transactional]
@@ -543,8 +755,12 @@ impl<T: Config> Pallet<T> {
 		F: FnOnce(Balance) -> DispatchResult,
 	{
 		<Orders<T>>::try_mutate_exists(order_id, |maybe_order| -> DispatchResult {
-			let (owner, asset_in, asset_out, order_amount_in, order_amount_out, partially_fillable) = {
+			let (owner, asset_in, asset_out, order_amount_in, order_amount_out, partially_fillable, expires_at) = {
 				let order = maybe_order.as_ref().ok_or(Error::<T>::OrderNotFound)?;
+				Self::ensure_not_expired(order)?;
+				if amount_in != order.amount_in {
+					Self::ensure_min_fill_amount(order, amount_in)?;
+				}
 				(
 					order.owner.clone(),
 					order.asset_in,
@@ -552,6 +768,7 @@ impl<T: Config> Pallet<T> {
 					order.amount_in,
 					order.amount_out,
 					order.partially_fillable,
+					order.expires_at,
 				)
 			};
 
@@ -595,6 +812,9 @@ impl<T: Config> Pallet<T> {
 
 			match remaining {
 				None => {
+					if let Some(expires_at) = expires_at {
+						<OrderExpiries<T>>::remove(expires_at, order_id);
+					}
 					*maybe_order = None;
 				}
 				Some((new_amount_in, new_amount_out)) => {
@@ -623,4 +843,39 @@ impl<T: Config> Pallet<T> {
 	pub fn calculate_fee(amount: Balance) -> Balance {
 		T::Fee::get().mul_ceil(amount)
 	}
+
+	/// Price of the order (asset_out/asset_in) after subtracting the fee from the amount_out.
+	pub fn order_price(order: &OrderOf<T>) -> Option<FixedU128> {
+		let amount_out = order.amount_out.checked_sub(Self::calculate_fee(order.amount_out))?;
+		FixedU128::checked_from_rational(amount_out, order.amount_in)
+	}
+
+	/// Returns the open orders matching the given filters, best priced orders first.
+	///
+	/// Expired orders are not returned. Orders with the same price are sorted by the order ID.
+	pub fn open_orders(
+		asset_in: Option<T::AssetId>,
+		asset_out: Option<T::AssetId>,
+		owner: Option<T::AccountId>,
+	) -> Vec<(OrderId, OrderOf<T>)> {
+		let mut orders: Vec<(OrderId, OrderOf<T>, FixedU128)> = <Orders<T>>::iter()
+			.filter(|(_, order)| {
+				asset_in.is_none_or(|asset_in| order.asset_in == asset_in)
+					&& asset_out.is_none_or(|asset_out| order.asset_out == asset_out)
+					&& owner.as_ref().is_none_or(|owner| order.owner == *owner)
+					&& !Self::is_expired(order)
+			})
+			.map(|(order_id, order)| {
+				let price = Self::order_price(&order).unwrap_or_default();
+				(order_id, order, price)
+			})
+			.collect();
+
+		orders.sort_by(|(id_a, _, price_a), (id_b, _, price_b)| price_b.cmp(price_a).then(id_a.cmp(id_b)));
+
+		orders
+			.into_iter()
+			.map(|(order_id, order, _)| (order_id, order))
+			.collect()
+	}
 }
@@ -0,0 +1,138 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::migrations::VersionedMigration;
+use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+const LOG_TARGET: &str = "runtime::otc";
+
+pub mod v0 {
+	use super::*;
+
+	#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+	pub struct Order<AccountId, AssetId> {
+		pub owner: AccountId,
+		pub asset_in: AssetId,
+		pub asset_out: AssetId,
+		pub amount_in: Balance,
+		pub amount_out: Balance,
+		pub partially_fillable: bool,
+	}
+}
+
+// Private module to hide migration
+mod unversioned {
+	pub struct InnerMigrateV0ToV1<T: crate::Config>(core::marker::PhantomData<T>);
+}
+
+impl<T: crate::Config> UncheckedOnRuntimeUpgrade for unversioned::InnerMigrateV0ToV1<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		log::info!(target: LOG_TARGET, "v0->v1 migration started");
+
+		let mut orders: u64 = 0;
+		Orders::<T>::translate::<v0::Order<T::AccountId, T::AssetId>, _>(|_, order| {
+			orders += 1;
+			Some(Order {
+				owner: order.owner,
+				asset_in: order.asset_in,
+				asset_out: order.asset_out,
+				amount_in: order.amount_in,
+				amount_out: order.amount_out,
+				partially_fillable: order.partially_fillable,
+				expires_at: None,
+				min_fill_amount: None,
+			})
+		});
+
+		log::info!(target: LOG_TARGET, "migration finished, migrated {orders:?} orders");
+		T::DbWeight::get().reads_writes(orders, orders)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == 0,
+			"can only upgrade from version 0"
+		);
+
+		Ok((Orders::<T>::iter_keys().count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let pre_order_count =
+			u32::decode(&mut state.as_slice()).map_err(|_| "failed to decode pre-upgrade order count")?;
+
+		let mut orders = 0u32;
+		for order in Orders::<T>::iter_values() {
+			ensure!(
+				order.expires_at.is_none() && order.min_fill_amount.is_none(),
+				"migrated orders must not have limits"
+			);
+			orders += 1;
+		}
+		ensure!(orders == pre_order_count, "order count must not change");
+		Ok(())
+	}
+}
+
+pub type MigrateV0ToV1<T> =
+	VersionedMigration<0, 1, unversioned::InnerMigrateV0ToV1<T>, Pallet<T>, <T as frame_system::Config>::DbWeight>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::mock::*;
+	use frame_support::storage::unhashed;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn migration_should_add_empty_limits_to_existing_orders() {
+		ExtBuilder::default().build().execute_with(|| {
+			// Arrange
+			let old_order = v0::Order {
+				owner: ALICE,
+				asset_in: DAI,
+				asset_out: HDX,
+				amount_in: 20 * ONE,
+				amount_out: 100 * ONE,
+				partially_fillable: true,
+			};
+			unhashed::put(&Orders::<Test>::hashed_key_for(0), &old_order);
+			StorageVersion::new(0).put::<Pallet<Test>>();
+
+			// Act
+			MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+			// Assert
+			assert_eq!(
+				OTC::orders(0),
+				Some(Order {
+					owner: ALICE,
+					asset_in: DAI,
+					asset_out: HDX,
+					amount_in: 20 * ONE,
+					amount_out: 100 * ONE,
+					partially_fillable: true,
+					expires_at: None,
+					min_fill_amount: None,
+				})
+			);
+			assert_eq!(StorageVersion::get::<Pallet<Test>>(), 1);
+		});
+	}
+}
@@ -86,3 +86,26 @@ fn cancel_order_should_throw_error_when_called_by_non_owner() {
 		);
 	});
 }
+
+#[test]
+fn cancel_order_should_remove_order_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order_with_constraints(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None
+		));
+
+		// Act
+		assert_ok!(OTC::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert!(!otc::OrderExpiries::<Test>::contains_key(10, 0));
+	});
+}
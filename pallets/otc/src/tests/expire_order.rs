@@ -0,0 +1,152 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as otc;
+use crate::tests::mock::*;
+use crate::weights::WeightInfo;
+use crate::Event;
+use frame_support::assert_ok;
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+
+fn place_order_expiring_at(expires_at: Option<u64>) {
+	assert_ok!(OTC::place_order_with_constraints(
+		RuntimeOrigin::signed(ALICE),
+		DAI,
+		HDX,
+		20 * ONE,
+		100 * ONE,
+		true,
+		expires_at,
+		None
+	));
+}
+
+#[test]
+fn on_idle_should_remove_expired_orders() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_expiring_at(Some(5));
+		place_order_expiring_at(Some(10));
+		place_order_expiring_at(Some(11));
+		place_order_expiring_at(None);
+		System::set_block_number(10);
+
+		// Act
+		OTC::on_idle(10, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::orders(1).is_none());
+		assert!(OTC::orders(2).is_some());
+		assert!(OTC::orders(3).is_some());
+
+		assert!(!otc::OrderExpiries::<Test>::contains_key(5, 0));
+		assert!(!otc::OrderExpiries::<Test>::contains_key(10, 1));
+		assert!(otc::OrderExpiries::<Test>::contains_key(11, 2));
+		assert_eq!(otc::NextExpiryBlock::<Test>::get(), Some(11));
+
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			200 * ONE
+		);
+
+		expect_events(vec![
+			Event::Expired { order_id: 0 }.into(),
+			Event::Expired { order_id: 1 }.into(),
+		]);
+	});
+}
+
+#[test]
+fn on_idle_should_not_remove_orders_before_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_expiring_at(Some(10));
+		System::set_block_number(9);
+
+		// Act
+		OTC::on_idle(9, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_some());
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			100 * ONE
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_continue_removing_expired_orders_in_next_block_when_weight_is_not_sufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_expiring_at(Some(5));
+		place_order_expiring_at(Some(5));
+		System::set_block_number(10);
+
+		// Act
+		let used_weight = OTC::on_idle(10, <() as WeightInfo>::expire_order());
+
+		// Assert
+		assert_eq!(used_weight, <() as WeightInfo>::expire_order());
+		assert_eq!(otc::NextExpiryBlock::<Test>::get(), Some(5));
+		assert_eq!(otc::OrderExpiries::<Test>::iter_prefix(5).count(), 1);
+
+		// Act
+		System::set_block_number(11);
+		OTC::on_idle(11, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::orders(1).is_none());
+		assert_eq!(otc::NextExpiryBlock::<Test>::get(), Some(12));
+		assert_eq!(Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE), 0);
+	});
+}
+
+#[test]
+fn on_idle_should_remove_order_placed_after_later_expiring_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_expiring_at(Some(20));
+		place_order_expiring_at(Some(10));
+		assert_eq!(otc::NextExpiryBlock::<Test>::get(), Some(10));
+
+		// Act
+		System::set_block_number(10);
+		OTC::on_idle(10, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_some());
+		assert!(OTC::orders(1).is_none());
+
+		// Act
+		System::set_block_number(20);
+		OTC::on_idle(20, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert_eq!(otc::NextExpiryBlock::<Test>::get(), Some(21));
+		assert_eq!(Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE), 0);
+
+		expect_events(vec![
+			Event::Expired { order_id: 1 }.into(),
+			Event::Expired { order_id: 0 }.into(),
+		]);
+	});
+}
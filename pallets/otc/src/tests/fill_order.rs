@@ -14,7 +14,7 @@
 // limitations under the License.
 use crate as otc;
 use crate::tests::mock::*;
-use crate::Event;
+use crate::{Error, Event};
 use frame_support::{assert_noop, assert_ok};
 use orml_tokens::Error::BalanceTooLow;
 use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
@@ -312,3 +312,51 @@ fn fill_order_should_throw_error_when_insufficient_balance() {
 		assert_eq!(bob_dai_balance_after, bob_dai_balance_before);
 	});
 }
+
+#[test]
+fn fill_order_should_throw_error_when_order_is_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order_with_constraints(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None
+		));
+		System::set_block_number(10);
+
+		// Act
+		assert_noop!(
+			OTC::fill_order(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_remove_order_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order_with_constraints(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			Some(10 * ONE)
+		));
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(!otc::OrderExpiries::<Test>::contains_key(10, 0));
+	});
+}
@@ -13,7 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use crate::tests::mock::*;
-use frame_support::{assert_ok, storage::with_storage_layer};
+use crate::Error;
+use frame_support::{assert_noop, assert_ok, storage::with_storage_layer};
 use orml_traits::MultiCurrency;
 use pretty_assertions::assert_eq;
 
@@ -85,3 +86,53 @@ fn fill_order_with_deferred_delivery_should_apply_same_remainder_dust_check_as_p
 		assert_eq!(order.amount_out, 600_000_000_000); // 20 - 19.4 = 0.6 HDX
 	});
 }
+
+#[test]
+fn fill_order_with_deferred_delivery_should_fail_when_order_is_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(OTC::place_order_with_constraints(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None
+		));
+		System::set_block_number(10);
+
+		assert_noop!(
+			with_storage_layer(|| OTC::fill_order_with_deferred_delivery(0, &BOB, 20 * ONE, |_| Ok(()))),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
+
+// A full fill is always allowed, only partial fills are checked against the minimum fill amount.
+#[test]
+fn fill_order_with_deferred_delivery_should_fail_when_partial_fill_is_lower_than_min_fill_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(OTC::place_order_with_constraints(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None,
+			Some(10 * ONE)
+		));
+
+		assert_noop!(
+			with_storage_layer(|| OTC::fill_order_with_deferred_delivery(0, &BOB, 5 * ONE, |_| Ok(()))),
+			Error::<Test>::FillAmountTooSmall
+		);
+
+		let res = with_storage_layer(|| {
+			OTC::fill_order_with_deferred_delivery(0, &BOB, 20 * ONE, |_| Tokens::deposit(DAI, &BOB, 20 * ONE))
+		});
+		assert_ok!(res);
+		assert!(OTC::orders(0).is_none());
+	});
+}
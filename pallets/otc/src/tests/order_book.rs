@@ -0,0 +1,109 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::OrderId;
+use frame_support::assert_ok;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+fn place_order(owner: AccountId, asset_in: AssetId, asset_out: AssetId, amount_in: Balance, amount_out: Balance) {
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(owner),
+		asset_in,
+		asset_out,
+		amount_in,
+		amount_out,
+		true
+	));
+}
+
+fn order_ids(orders: Vec<(OrderId, crate::OrderOf<Test>)>) -> Vec<OrderId> {
+	orders.into_iter().map(|(order_id, _)| order_id).collect()
+}
+
+#[test]
+fn open_orders_should_return_best_priced_orders_first() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order(ALICE, DAI, HDX, 20 * ONE, 100 * ONE);
+		place_order(ALICE, DAI, HDX, 20 * ONE, 120 * ONE);
+		place_order(BOB, DAI, HDX, 10 * ONE, 50 * ONE);
+		place_order(BOB, DAI, HDX, 25 * ONE, 100 * ONE);
+
+		// Act
+		let orders = OTC::open_orders(Some(DAI), Some(HDX), None);
+
+		// Assert
+		assert_eq!(order_ids(orders), vec![1, 0, 2, 3]);
+	});
+}
+
+#[test]
+fn open_orders_should_filter_by_assets_and_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order(ALICE, DAI, HDX, 20 * ONE, 100 * ONE);
+		place_order(ALICE, HDX, DAI, 100 * ONE, 20 * ONE);
+		place_order(BOB, DAI, HDX, 20 * ONE, 100 * ONE);
+
+		// Act and assert
+		assert_eq!(order_ids(OTC::open_orders(Some(DAI), None, None)), vec![0, 2]);
+		assert_eq!(order_ids(OTC::open_orders(None, Some(DAI), None)), vec![1]);
+		assert_eq!(order_ids(OTC::open_orders(None, None, Some(ALICE))), vec![0, 1]);
+		assert_eq!(order_ids(OTC::open_orders(Some(DAI), Some(HDX), Some(BOB))), vec![2]);
+		assert!(OTC::open_orders(Some(HDX), Some(HDX), None).is_empty());
+	});
+}
+
+#[test]
+fn open_orders_should_not_return_expired_orders() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order(ALICE, DAI, HDX, 20 * ONE, 100 * ONE);
+		assert_ok!(OTC::place_order_with_constraints(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None
+		));
+		System::set_block_number(10);
+
+		// Act
+		let orders = OTC::open_orders(Some(DAI), Some(HDX), None);
+
+		// Assert
+		assert_eq!(order_ids(orders), vec![0]);
+	});
+}
+
+#[test]
+fn order_price_should_not_include_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order(ALICE, DAI, HDX, 20 * ONE, 100 * ONE);
+		let order = OTC::orders(0).unwrap();
+
+		// Act
+		let price = OTC::order_price(&order);
+
+		// Assert
+		assert_eq!(price, Some(FixedU128::from_rational(99, 20)));
+	});
+}
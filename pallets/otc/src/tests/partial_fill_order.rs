@@ -377,3 +377,53 @@ fn partial_fill_order_should_throw_error_when_amount_is_larger_than_order() {
 		assert_eq!(bob_dai_balance_after, bob_dai_balance_before);
 	});
 }
+
+#[test]
+fn partial_fill_order_should_throw_error_when_amount_is_lower_than_min_fill_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order_with_constraints(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None,
+			Some(5 * ONE)
+		));
+
+		// Act
+		assert_noop!(
+			OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 5 * ONE - 1),
+			Error::<Test>::FillAmountTooSmall
+		);
+
+		// Assert
+		assert_ok!(OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 5 * ONE));
+	});
+}
+
+#[test]
+fn partial_fill_order_should_throw_error_when_order_is_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order_with_constraints(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None
+		));
+		System::set_block_number(10);
+
+		// Act
+		assert_noop!(
+			OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 5 * ONE),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
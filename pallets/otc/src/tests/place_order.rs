@@ -148,3 +148,69 @@ fn place_order_should_throw_error_when_amount_out_is_too_low() {
 		);
 	});
 }
+
+#[test]
+fn place_order_with_constraints_should_store_expiry_and_min_fill_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(OTC::place_order_with_constraints(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			Some(5 * ONE)
+		));
+
+		// Assert
+		let order = OTC::orders(0).unwrap();
+		assert_eq!(order.expires_at, Some(10));
+		assert_eq!(order.min_fill_amount, Some(5 * ONE));
+		assert!(otc::OrderExpiries::<Test>::contains_key(10, 0));
+	});
+}
+
+#[test]
+fn place_order_with_constraints_should_throw_error_when_expiry_is_not_in_future() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		System::set_block_number(10);
+
+		// Act
+		assert_noop!(
+			OTC::place_order_with_constraints(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				Some(10),
+				None
+			),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn place_order_with_constraints_should_throw_error_when_min_fill_amount_is_higher_than_amount_in() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_noop!(
+			OTC::place_order_with_constraints(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				None,
+				Some(20 * ONE + 1)
+			),
+			Error::<Test>::InvalidMinFillAmount
+		);
+	});
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the route executor pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+pub use hydradx_traits::router::{PoolType, Route, Trade};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::FixedU128;
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Fee charged by the AMM while executing a single trade of a route.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TradeFee<AssetId, Balance> {
+	pub asset: AssetId,
+	pub amount: Balance,
+}
+
+/// Quote of a single trade (hop) of a route.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TradeQuote<AssetId, Balance> {
+	/// The trade the quote belongs to.
+	pub trade: Trade<AssetId>,
+	/// Amount of `trade.asset_in` going into the pool.
+	pub amount_in: Balance,
+	/// Amount of `trade.asset_out` coming out of the pool.
+	pub amount_out: Balance,
+	/// Spot price of the trade including the pool fees, if the pool supports it.
+	pub spot_price_with_fee: Option<FixedU128>,
+	/// Fees charged by the pool for the trade.
+	///
+	/// Fees are collected by dry-running the route, so the list is empty if the dry-run is not possible,
+	/// e.g. for assets which can't be minted.
+	pub fees: Vec<TradeFee<AssetId, Balance>>,
+}
+
+/// Quote of a full route with a breakdown for every trade.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RouteQuote<AssetId, Balance> {
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	/// Total amount of `asset_in` spent.
+	pub amount_in: Balance,
+	/// Total amount of `asset_out` received.
+	pub amount_out: Balance,
+	/// Quotes of the trades, in the order of execution.
+	pub trades: Vec<TradeQuote<AssetId, Balance>>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to quote trades executed by the router.
+	pub trait RouterApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Quote a sell of `amount_in` of `asset_in` for `asset_out`.
+		/// If `route` is empty, the on-chain route is used, or the default route if there is none.
+		fn quote_sell(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			route: Route<AssetId>,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError>;
+
+		/// Quote a buy of `amount_out` of `asset_out` for `asset_in`.
+		/// If `route` is empty, the on-chain route is used, or the default route if there is none.
+		fn quote_buy(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+			route: Route<AssetId>,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError>;
+
+		/// Return the on-chain route for the asset pair, or the default route if there is none.
+		fn get_route(asset_in: AssetId, asset_out: AssetId) -> Route<AssetId>;
+
+		/// Find the route with the highest amount out for selling `amount_in` of `asset_in` for `asset_out`.
+		/// The candidates are discovered across all pools, the on-chain (or default) route is a candidate as well.
+		fn find_best_sell_route(asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> Option<Route<AssetId>>;
+
+		/// Find the route with the lowest amount in for buying `amount_out` of `asset_out` for `asset_in`.
+		/// The candidates are discovered across all pools, the on-chain (or default) route is a candidate as well.
+		fn find_best_buy_route(asset_in: AssetId, asset_out: AssetId, amount_out: Balance) -> Option<Route<AssetId>>;
+	}
+}
@@ -0,0 +1,255 @@
+// This file is part of pallet-route-executor.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Route discovery
+//!
+//! Builds a graph from the trades provided by `T::PoolsProvider` and enumerates the candidate routes
+//! between two assets, shortest routes first. The candidates are ranked by the amounts calculated by the AMMs.
+//!
+//! The search is bounded by `MAX_NUMBER_OF_TRADES`, `T::MaxRouteCandidates` and `T::MaxRouteSearchSteps`,
+//! so it is suitable for runtime API calls and the offchain worker, but it is not meant to be used in extrinsics.
+
+use crate::*;
+use sp_runtime::SaturatedConversion;
+use sp_std::collections::{btree_map::BTreeMap, vec_deque::VecDeque};
+
+type TradeGraph<AssetId> = BTreeMap<AssetId, Vec<Trade<AssetId>>>;
+
+impl<T: Config> Pallet<T> {
+	/// Enumerates the candidate routes from `asset_in` to `asset_out`, shortest routes first.
+	///
+	/// An asset is visited at most once in a route.
+	pub fn discover_routes(asset_in: T::AssetId, asset_out: T::AssetId) -> Vec<Route<T::AssetId>> {
+		if asset_in == asset_out {
+			return vec![];
+		}
+
+		Self::search_routes(&Self::trade_graph(), asset_in, asset_out)
+	}
+
+	/// Returns the route with the highest amount out for selling `amount_in`, together with the amount out.
+	///
+	/// The current on-chain (or default) route is a candidate as well, so the result is never worse than it.
+	pub fn find_best_sell_route(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+	) -> Option<(Route<T::AssetId>, T::Balance)> {
+		let candidates = Self::route_candidates(&Self::trade_graph(), asset_in, asset_out);
+
+		Self::best_sell_route(candidates, amount_in)
+	}
+
+	/// Returns the route with the lowest amount in for buying `amount_out`, together with the amount in.
+	///
+	/// The current on-chain (or default) route is a candidate as well, so the result is never worse than it.
+	pub fn find_best_buy_route(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+	) -> Option<(Route<T::AssetId>, T::Balance)> {
+		Self::route_candidates(&Self::trade_graph(), asset_in, asset_out)
+			.into_iter()
+			.filter_map(|route| {
+				let amount_in = Self::calculate_expected_amount_in(&route, amount_out).ok()?;
+				Some((route, amount_in))
+			})
+			.fold(None, |best, (route, amount_in)| match best {
+				Some((_, best_amount_in)) if best_amount_in <= amount_in => best,
+				_ => Some((route, amount_in)),
+			})
+	}
+
+	/// Returns the best sell route for the asset pair, ranked with the reference amount used by `set_route`.
+	pub fn find_best_route(asset_pair: AssetPair<T::AssetId>) -> Option<Route<T::AssetId>> {
+		Self::best_route_in(&Self::trade_graph(), asset_pair)
+	}
+
+	/// Looks for better routes of some asset pairs and submits them as unsigned `propose_route` transactions.
+	///
+	/// Asset pairs are checked in a round-robin manner, `OFFCHAIN_WORKER_PAIRS_PER_BLOCK` pairs in every block.
+	pub(crate) fn propose_routes(block_number: BlockNumberFor<T>) {
+		let graph = Self::trade_graph();
+		let assets: Vec<T::AssetId> = graph.keys().copied().collect();
+
+		let mut asset_pairs = Vec::new();
+		for (i, asset_a) in assets.iter().enumerate() {
+			for asset_b in assets.iter().skip(i.saturating_add(1)) {
+				asset_pairs.push(AssetPair::new(*asset_a, *asset_b));
+			}
+		}
+
+		if asset_pairs.is_empty() {
+			return;
+		}
+
+		let start = block_number
+			.saturated_into::<u64>()
+			.saturating_mul(OFFCHAIN_WORKER_PAIRS_PER_BLOCK as u64)
+			% asset_pairs.len() as u64;
+
+		for asset_pair in asset_pairs
+			.iter()
+			.cycle()
+			.skip(start as usize)
+			.take((OFFCHAIN_WORKER_PAIRS_PER_BLOCK as usize).min(asset_pairs.len()))
+		{
+			let Some(new_route) = Self::best_route_in(&graph, *asset_pair) else {
+				continue;
+			};
+
+			if new_route == <Self as RouteProvider<T::AssetId>>::get_route(*asset_pair)
+				|| Self::validate_route_update(*asset_pair, new_route.clone()).is_err()
+			{
+				continue;
+			}
+
+			log::debug!(
+				target: "offchain_worker::propose_routes",
+				"Proposing route for asset pair {asset_pair:?}: {new_route:?}");
+
+			let call = Call::propose_route {
+				asset_pair: *asset_pair,
+				new_route,
+			};
+
+			// Create an unsigned extrinsic
+			let xt = T::create_bare(call.into());
+			let _ = SubmitTransaction::<T, Call<T>>::submit_transaction(xt);
+		}
+	}
+
+	fn best_route_in(graph: &TradeGraph<T::AssetId>, asset_pair: AssetPair<T::AssetId>) -> Option<Route<T::AssetId>> {
+		let candidates = Self::route_candidates(graph, asset_pair.asset_in, asset_pair.asset_out);
+
+		// The first candidate is the current route, so its reference amount is used whenever it is available
+		let reference_amount_in = candidates
+			.iter()
+			.find_map(|route| Self::calculate_reference_amount_in(route).ok())?;
+
+		Self::best_sell_route(candidates, reference_amount_in).map(|(route, _)| route)
+	}
+
+	fn best_sell_route(
+		candidates: Vec<Route<T::AssetId>>,
+		amount_in: T::Balance,
+	) -> Option<(Route<T::AssetId>, T::Balance)> {
+		candidates
+			.into_iter()
+			.filter_map(|route| {
+				let amount_out = Self::calculate_expected_amount_out(&route, amount_in).ok()?;
+				Some((route, amount_out))
+			})
+			.fold(None, |best, (route, amount_out)| match best {
+				Some((_, best_amount_out)) if best_amount_out >= amount_out => best,
+				_ => Some((route, amount_out)),
+			})
+	}
+
+	/// The current on-chain (or default) route followed by the discovered routes.
+	fn route_candidates(
+		graph: &TradeGraph<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+	) -> Vec<Route<T::AssetId>> {
+		let mut candidates = vec![<Self as RouteProvider<T::AssetId>>::get_route(AssetPair::new(
+			asset_in, asset_out,
+		))];
+
+		if asset_in == asset_out {
+			return candidates;
+		}
+
+		for route in Self::search_routes(graph, asset_in, asset_out) {
+			if !candidates.contains(&route) {
+				candidates.push(route);
+			}
+		}
+
+		candidates
+	}
+
+	fn trade_graph() -> TradeGraph<T::AssetId> {
+		let mut graph = TradeGraph::<T::AssetId>::new();
+
+		for trade in T::PoolsProvider::tradeable_pools() {
+			if trade.asset_in != trade.asset_out {
+				graph.entry(trade.asset_in).or_default().push(trade);
+			}
+		}
+
+		graph
+	}
+
+	/// Breadth-first search of the routes, so the shorter routes are found first.
+	fn search_routes(
+		graph: &TradeGraph<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+	) -> Vec<Route<T::AssetId>> {
+		let max_candidates = T::MaxRouteCandidates::get() as usize;
+		let mut remaining_steps = T::MaxRouteSearchSteps::get();
+
+		let mut candidates = Vec::new();
+		let mut queue = VecDeque::<Vec<Trade<T::AssetId>>>::new();
+		queue.push_back(Vec::new());
+
+		while let Some(path) = queue.pop_front() {
+			if remaining_steps == 0 || candidates.len() >= max_candidates {
+				break;
+			}
+			remaining_steps = remaining_steps.saturating_sub(1);
+
+			let last_asset = path.last().map(|trade| trade.asset_out).unwrap_or(asset_in);
+			let Some(trades) = graph.get(&last_asset) else {
+				continue;
+			};
+
+			for trade in trades {
+				let is_visited =
+					trade.asset_out == asset_in || path.iter().any(|visited| visited.asset_out == trade.asset_out);
+				if is_visited
+					|| path
+						.last()
+						.is_some_and(|previous| Self::is_redundant_hop(previous, trade))
+				{
+					continue;
+				}
+
+				let mut next_path = path.clone();
+				next_path.push(*trade);
+
+				if trade.asset_out == asset_out {
+					candidates.push(Route::truncate_from(next_path));
+					if candidates.len() >= max_candidates {
+						break;
+					}
+				} else if (next_path.len() as u32) < MAX_NUMBER_OF_TRADES {
+					queue.push_back(next_path);
+				}
+			}
+		}
+
+		candidates
+	}
+
+	/// Two consecutive trades in the same Omnipool or Stableswap pool can always be replaced by a single trade
+	/// with a better price, as the fee is paid only once.
+	fn is_redundant_hop(previous: &Trade<T::AssetId>, next: &Trade<T::AssetId>) -> bool {
+		previous.pool == next.pool && matches!(previous.pool, PoolType::Omnipool | PoolType::Stableswap(_))
+	}
+}
@@ -23,7 +23,8 @@ use codec::MaxEncodedLen;
 use frame_support::traits::fungibles::Mutate;
 use frame_support::traits::tokens::{Fortitude, Preservation};
 use frame_support::PalletId;
-use frame_system::pallet_prelude::OriginFor;
+use frame_system::offchain::SubmitTransaction;
+use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use frame_system::Origin;
 
 use frame_support::{
@@ -35,11 +36,15 @@ use frame_support::{
 use hydra_dx_math::support::rational::{round_u512_to_rational, Rounding};
 use sp_runtime::traits::Zero;
 
-use frame_system::ensure_signed;
-use hydradx_traits::router::{inverse_route, AssetPair, Route, RouteProvider, RouteSpotPriceProvider};
+use frame_system::{ensure_none, ensure_signed};
+use hydradx_traits::router::{
+	inverse_route, is_valid_split_route, split_amount, AssetPair, Route, RouteProvider, RouteSpotPriceProvider,
+};
 pub use hydradx_traits::router::{
-	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouterT, Trade, TradeExecution,
+	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouterT, SplitRoute, Trade, TradeExecution,
+	TradeablePoolsProvider, WeightedRoute,
 };
+use hydradx_traits::CreateBare;
 
 use orml_traits::arithmetic::{CheckedAdd, CheckedSub};
 use pallet_broadcast::types::IncrementalIdType;
@@ -53,6 +58,7 @@ use sp_std::{vec, vec::Vec};
 mod tests;
 pub mod weights;
 
+mod discovery;
 mod types;
 
 pub use weights::WeightInfo;
@@ -62,6 +68,11 @@ pub use pallet::*;
 
 pub const MAX_NUMBER_OF_TRADES: u32 = 9;
 
+/// Number of asset pairs the offchain worker looks for a better route in a single block.
+pub const OFFCHAIN_WORKER_PAIRS_PER_BLOCK: u32 = 5;
+
+pub const UNSIGNED_TXS_PRIORITY: u64 = 100;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -77,7 +88,7 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config + pallet_broadcast::Config {
+	pub trait Config: frame_system::Config + pallet_broadcast::Config + CreateBare<Call<Self>> {
 		/// Asset id type
 		type AssetId: Parameter + Member + Copy + MaybeSerializeDeserialize + MaxEncodedLen + AtLeast32BitUnsigned;
 
@@ -88,6 +99,7 @@ pub mod pallet {
 			+ PartialOrd
 			+ MaybeSerializeDeserialize
 			+ From<u128>
+			+ Into<u128>
 			+ Default
 			+ CheckedSub
 			+ CheckedAdd
@@ -125,6 +137,17 @@ pub mod pallet {
 		/// Origin able to set route without validation
 		type ForceInsertOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Provider of the pools the route discovery can build routes from
+		type PoolsProvider: TradeablePoolsProvider<Self::AssetId>;
+
+		/// Max number of candidate routes evaluated by the route discovery
+		#[pallet::constant]
+		type MaxRouteCandidates: Get<u32>;
+
+		/// Max number of partial routes extended by the route discovery
+		#[pallet::constant]
+		type MaxRouteSearchSteps: Get<u32>;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: AmmTradeWeights<Trade<Self::AssetId>>;
 	}
@@ -142,6 +165,13 @@ pub mod pallet {
 		},
 		///The route with trades has been successfully executed
 		RouteUpdated { asset_ids: Vec<T::AssetId> },
+		///The split route has been successfully executed, the amounts are the totals of all the routes
+		SplitExecuted {
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			amount_out: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -166,6 +196,8 @@ pub mod pallet {
 		InvalidRouteExecution,
 		/// Trading same assets is not allowed.
 		NotAllowed,
+		///The split route is empty or the weights of the routes are not summing up to 100%
+		InvalidSplitRoute,
 	}
 
 	/// Storing routes for asset pairs
@@ -173,6 +205,48 @@ pub mod pallet {
 	#[pallet::getter(fn route)]
 	pub type Routes<T: Config> = StorageMap<_, Blake2_128Concat, AssetPair<T::AssetId>, Route<T::AssetId>>;
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn offchain_worker(block_number: BlockNumberFor<T>) {
+			// limit the cases when the offchain worker run
+			if sp_io::offchain::is_validator() {
+				Self::propose_routes(block_number);
+			}
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match source {
+				TransactionSource::External => {
+					// receiving unsigned transaction from network - disallow
+					return InvalidTransaction::Call.into();
+				}
+				TransactionSource::Local => {}   // produced by off-chain worker
+				TransactionSource::InBlock => {} // some other node included it in a block
+			};
+
+			match call {
+				Call::propose_route { asset_pair, new_route } => {
+					if Self::validate_route_update(*asset_pair, new_route.clone()).is_err() {
+						return InvalidTransaction::Call.into();
+					}
+
+					ValidTransaction::with_tag_prefix("router-propose-route")
+						.priority(UNSIGNED_TXS_PRIORITY)
+						.and_provides(asset_pair.ordered_pair())
+						.longevity(3)
+						.propagate(false)
+						.build()
+				}
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Executes a sell with a series of trades specified in the route.
@@ -199,7 +273,7 @@ pub mod pallet {
 			min_amount_out: T::Balance,
 			route: Route<T::AssetId>,
 		) -> DispatchResult {
-			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)
+			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route).map(|_| ())
 		}
 
 		/// Executes a buy with a series of trades specified in the route.
@@ -226,67 +300,7 @@ pub mod pallet {
 			max_amount_in: T::Balance,
 			route: Route<T::AssetId>,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-
-			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
-			Self::ensure_route_size(route.len())?;
-
-			let asset_pair = AssetPair::new(asset_in, asset_out);
-			let route = Self::get_route_or_default(asset_in, asset_out, &route);
-			Self::ensure_route_arguments(&asset_pair, &route)?;
-
-			let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
-			let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
-			ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
-
-			let trader_account = Self::router_account();
-			pallet_broadcast::Pallet::<T>::set_swapper(who.clone());
-
-			T::Currency::transfer(
-				asset_in,
-				&who,
-				&trader_account.clone(),
-				first_trade.amount_in,
-				Preservation::Expendable,
-			)?;
-
-			let next_event_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::Router)?;
-
-			for (trade_amount, trade) in trade_amounts.iter().rev().zip(route) {
-				let origin: OriginFor<T> = Origin::<T>::Signed(trader_account.clone()).into();
-				let execution_result = T::AMM::execute_buy(
-					origin.clone(),
-					trade.pool,
-					trade.asset_in,
-					trade.asset_out,
-					trade_amount.amount_out,
-					trade_amount.amount_in,
-				);
-
-				handle_execution_error!(execution_result);
-			}
-
-			let amount_out = T::Currency::reducible_balance(
-				asset_out,
-				&trader_account.clone(),
-				Preservation::Expendable,
-				Fortitude::Polite,
-			);
-
-			T::Currency::transfer(asset_out, &trader_account, &who, amount_out, Preservation::Expendable)?;
-
-			Self::deposit_event(Event::Executed {
-				asset_in,
-				asset_out,
-				amount_in: first_trade.amount_in,
-				amount_out,
-				event_id: next_event_id,
-			});
-
-			pallet_broadcast::Pallet::<T>::remove_from_context()?;
-			pallet_broadcast::Pallet::<T>::remove_swapper();
-
-			Ok(())
+			Self::do_buy(origin, asset_in, asset_out, amount_out, max_amount_in, route).map(|_| ())
 		}
 
 		/// Sets the on-chain route for a given asset pair.
@@ -315,65 +329,14 @@ pub mod pallet {
 		#[transactional]
 		pub fn set_route(
 			origin: OriginFor<T>,
-			mut asset_pair: AssetPair<T::AssetId>,
-			mut new_route: Route<T::AssetId>,
+			asset_pair: AssetPair<T::AssetId>,
+			new_route: Route<T::AssetId>,
 		) -> DispatchResultWithPostInfo {
-			let _ = ensure_signed(origin.clone())?;
-			Self::ensure_route_size(new_route.len())?;
-			Self::ensure_route_arguments(&asset_pair, &new_route)?;
-			T::OraclePriceProvider::price(&new_route, T::OraclePeriod::get()).ok_or(Error::<T>::RouteHasNoOracle)?;
+			ensure_signed(origin)?;
 
-			if !asset_pair.is_ordered() {
-				asset_pair = asset_pair.ordered_pair();
-				new_route = inverse_route(new_route)
-			}
-
-			let existing_route = Self::get_route(asset_pair);
-
-			match Self::validate_route(&existing_route.clone()) {
-				Ok((reference_amount_in, reference_amount_in_for_inverse)) => {
-					let new_route_validation = Self::validate_sell(new_route.clone(), reference_amount_in);
-
-					let inverse_new_route = inverse_route(new_route.clone());
-					let inverse_new_route_validation =
-						Self::validate_sell(inverse_new_route.clone(), reference_amount_in_for_inverse);
-
-					match (new_route_validation, inverse_new_route_validation) {
-						(Ok(_), Ok(_)) => (),
-						(Err(_), Ok(amount_out)) => {
-							Self::validate_sell(new_route.clone(), amount_out).map(|_| ())?;
-						}
-						(Ok(amount_out), Err(_)) => {
-							Self::validate_sell(inverse_new_route.clone(), amount_out).map(|_| ())?;
-						}
-						(Err(err), Err(_)) => return Err(err.into()),
-					}
-
-					let amount_out_for_existing_route =
-						Self::calculate_expected_amount_out(&existing_route, reference_amount_in)?;
-					let amount_out_for_new_route =
-						Self::calculate_expected_amount_out(&new_route, reference_amount_in)?;
-
-					let inverse_existing_route = inverse_route(existing_route);
-					let amount_out_for_existing_inversed_route =
-						Self::calculate_expected_amount_out(&inverse_existing_route, reference_amount_in_for_inverse)?;
-					let amount_out_for_new_inversed_route =
-						Self::calculate_expected_amount_out(&inverse_new_route, reference_amount_in_for_inverse)?;
-
-					if amount_out_for_new_route > amount_out_for_existing_route
-						&& amount_out_for_new_inversed_route > amount_out_for_existing_inversed_route
-					{
-						return Self::insert_route(asset_pair, new_route);
-					}
-				}
-				Err(_) => {
-					Self::validate_route(&new_route.clone())?;
-
-					return Self::insert_route(asset_pair, new_route);
-				}
-			}
+			let (asset_pair, new_route) = Self::validate_route_update(asset_pair, new_route)?;
 
-			Err(Error::<T>::RouteUpdateIsNotSuccessful.into())
+			Self::insert_route(asset_pair, new_route)
 		}
 
 		/// Force inserts the on-chain route for a given asset pair, so there is no any validation for the route
@@ -435,7 +398,96 @@ pub mod pallet {
 			let who = ensure_signed(origin.clone())?;
 			let amount_in = T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
 
-			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)
+			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route).map(|_| ())
+		}
+
+		/// Sets the on-chain route for a given asset pair, proposed by the offchain worker.
+		///
+		/// Unsigned version of `set_route`, the new route is validated and compared to the existing route the same way.
+		///
+		/// The offchain worker looks for better routes with the route discovery and submits them via this extrinsic.
+		///
+		/// - `origin`: Unsigned origin, the transaction can be submitted only by the offchain worker of a collator
+		/// - `asset_pair`: The identifier of the asset-pair for which the route is set
+		/// - `new_route`: Series of [`Trade<AssetId>`] to be executed. A [`Trade<AssetId>`] specifies the asset pair (`asset_in`, `asset_out`) and the AMM (`pool`) in which the trade is executed.
+		///
+		/// Emits `RouteUpdated` when successful.
+		///
+		/// Fails with `RouteUpdateIsNotSuccessful` error when the new route is not better than the existing one
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_route_weight(new_route))]
+		#[transactional]
+		pub fn propose_route(
+			origin: OriginFor<T>,
+			asset_pair: AssetPair<T::AssetId>,
+			new_route: Route<T::AssetId>,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+
+			let (asset_pair, new_route) = Self::validate_route_update(asset_pair, new_route)?;
+
+			Self::insert_route(asset_pair, new_route)
+		}
+
+		/// Executes a sell split across multiple parallel routes of the same asset pair.
+		///
+		/// `amount_in` is split between the routes based on their weights, the last route gets the rounding remainder.
+		/// The routes are executed one after the other, and the trading limit is checked on the total amount out,
+		/// so either all the routes are executed, or none of them.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `amount_in`: The total amount of `asset_in` to sell
+		/// - `min_amount_out`: The minimum total amount of `asset_out` to receive.
+		/// - `routes`: Series of [`WeightedRoute<AssetId>`]. The weights must be non-zero and sum up to 100%.
+		/// 		   If the route of a [`WeightedRoute<AssetId>`] is empty, then the on-chain or default route is used.
+		///
+		/// Emits `Executed` for every route and `SplitExecuted` when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(Pallet::<T>::split_sell_weight(*asset_in, *asset_out, routes))]
+		#[transactional]
+		pub fn split_sell(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			min_amount_out: T::Balance,
+			routes: SplitRoute<T::AssetId>,
+		) -> DispatchResult {
+			Self::do_split_sell(origin, asset_in, asset_out, amount_in, min_amount_out, routes).map(|_| ())
+		}
+
+		/// Executes a buy split across multiple parallel routes of the same asset pair.
+		///
+		/// `amount_out` is split between the routes based on their weights, the last route gets the rounding remainder.
+		/// The routes are executed one after the other, and the trading limit is checked on the total amount in,
+		/// so either all the routes are executed, or none of them.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `amount_out`: The total amount of `asset_out` to buy
+		/// - `max_amount_in`: The max total amount of `asset_in` to spend on the buy.
+		/// - `routes`: Series of [`WeightedRoute<AssetId>`]. The weights must be non-zero and sum up to 100%.
+		/// 		   If the route of a [`WeightedRoute<AssetId>`] is empty, then the on-chain or default route is used.
+		///
+		/// Emits `Executed` for every route and `SplitExecuted` when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(Pallet::<T>::split_buy_weight(*asset_in, *asset_out, routes))]
+		#[transactional]
+		pub fn split_buy(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_out: T::Balance,
+			max_amount_in: T::Balance,
+			routes: SplitRoute<T::AssetId>,
+		) -> DispatchResult {
+			Self::do_split_buy(origin, asset_in, asset_out, amount_out, max_amount_in, routes).map(|_| ())
 		}
 	}
 }
@@ -453,7 +505,7 @@ impl<T: Config> Pallet<T> {
 		amount_in: T::Balance,
 		min_amount_out: T::Balance,
 		route: Route<T::AssetId>,
-	) -> Result<(), DispatchError> {
+	) -> Result<AmountInAndOut<T::Balance>, DispatchError> {
 		let who = ensure_signed(origin.clone())?;
 
 		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
@@ -523,7 +575,200 @@ impl<T: Config> Pallet<T> {
 		pallet_broadcast::Pallet::<T>::remove_from_context()?;
 		pallet_broadcast::Pallet::<T>::remove_swapper();
 
-		Ok(())
+		Ok(AmountInAndOut { amount_in, amount_out })
+	}
+
+	fn do_buy(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		max_amount_in: T::Balance,
+		route: Route<T::AssetId>,
+	) -> Result<AmountInAndOut<T::Balance>, DispatchError> {
+		let who = ensure_signed(origin.clone())?;
+
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+		Self::ensure_route_size(route.len())?;
+
+		let asset_pair = AssetPair::new(asset_in, asset_out);
+		let route = Self::get_route_or_default(asset_in, asset_out, &route);
+		Self::ensure_route_arguments(&asset_pair, &route)?;
+
+		let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
+		let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+		ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
+
+		let trader_account = Self::router_account();
+		pallet_broadcast::Pallet::<T>::set_swapper(who.clone());
+
+		T::Currency::transfer(
+			asset_in,
+			&who,
+			&trader_account.clone(),
+			first_trade.amount_in,
+			Preservation::Expendable,
+		)?;
+
+		let next_event_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::Router)?;
+
+		for (trade_amount, trade) in trade_amounts.iter().rev().zip(route) {
+			let origin: OriginFor<T> = Origin::<T>::Signed(trader_account.clone()).into();
+			let execution_result = T::AMM::execute_buy(
+				origin.clone(),
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				trade_amount.amount_out,
+				trade_amount.amount_in,
+			);
+
+			handle_execution_error!(execution_result);
+		}
+
+		let amount_out = T::Currency::reducible_balance(
+			asset_out,
+			&trader_account.clone(),
+			Preservation::Expendable,
+			Fortitude::Polite,
+		);
+
+		T::Currency::transfer(asset_out, &trader_account, &who, amount_out, Preservation::Expendable)?;
+
+		Self::deposit_event(Event::Executed {
+			asset_in,
+			asset_out,
+			amount_in: first_trade.amount_in,
+			amount_out,
+			event_id: next_event_id,
+		});
+
+		pallet_broadcast::Pallet::<T>::remove_from_context()?;
+		pallet_broadcast::Pallet::<T>::remove_swapper();
+
+		Ok(AmountInAndOut {
+			amount_in: first_trade.amount_in,
+			amount_out,
+		})
+	}
+
+	#[transactional]
+	fn do_split_sell(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+		min_amount_out: T::Balance,
+		routes: SplitRoute<T::AssetId>,
+	) -> Result<AmountInAndOut<T::Balance>, DispatchError> {
+		ensure!(is_valid_split_route(&routes), Error::<T>::InvalidSplitRoute);
+
+		let mut total_amount_out = T::Balance::zero();
+		for (weighted_route, amount_to_sell) in routes.iter().zip(split_amount(amount_in, &routes)) {
+			if amount_to_sell.is_zero() {
+				continue;
+			}
+
+			let amounts = Self::do_sell(
+				origin.clone(),
+				asset_in,
+				asset_out,
+				amount_to_sell,
+				T::Balance::zero(),
+				weighted_route.route.clone(),
+			)?;
+			total_amount_out = total_amount_out
+				.checked_add(&amounts.amount_out)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		ensure!(total_amount_out >= min_amount_out, Error::<T>::TradingLimitReached);
+
+		Self::deposit_event(Event::SplitExecuted {
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out: total_amount_out,
+		});
+
+		Ok(AmountInAndOut {
+			amount_in,
+			amount_out: total_amount_out,
+		})
+	}
+
+	#[transactional]
+	fn do_split_buy(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		max_amount_in: T::Balance,
+		routes: SplitRoute<T::AssetId>,
+	) -> Result<AmountInAndOut<T::Balance>, DispatchError> {
+		ensure!(is_valid_split_route(&routes), Error::<T>::InvalidSplitRoute);
+
+		let mut total_amount_in = T::Balance::zero();
+		let mut total_amount_out = T::Balance::zero();
+		for (weighted_route, amount_to_buy) in routes.iter().zip(split_amount(amount_out, &routes)) {
+			if amount_to_buy.is_zero() {
+				continue;
+			}
+
+			// The remaining budget is the limit of every route, so the total amount in never exceeds `max_amount_in`
+			let remaining_amount_in = max_amount_in
+				.checked_sub(&total_amount_in)
+				.ok_or(Error::<T>::TradingLimitReached)?;
+
+			let amounts = Self::do_buy(
+				origin.clone(),
+				asset_in,
+				asset_out,
+				amount_to_buy,
+				remaining_amount_in,
+				weighted_route.route.clone(),
+			)?;
+			total_amount_in = total_amount_in
+				.checked_add(&amounts.amount_in)
+				.ok_or(ArithmeticError::Overflow)?;
+			total_amount_out = total_amount_out
+				.checked_add(&amounts.amount_out)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		Self::deposit_event(Event::SplitExecuted {
+			asset_in,
+			asset_out,
+			amount_in: total_amount_in,
+			amount_out: total_amount_out,
+		});
+
+		Ok(AmountInAndOut {
+			amount_in: total_amount_in,
+			amount_out: total_amount_out,
+		})
+	}
+
+	/// Sum of the sell weights of the routes of a split sell.
+	pub fn split_sell_weight(asset_in: T::AssetId, asset_out: T::AssetId, routes: &SplitRoute<T::AssetId>) -> Weight {
+		routes.iter().fold(Weight::zero(), |weight, weighted_route| {
+			weight.saturating_add(T::WeightInfo::sell_weight(&Self::get_route_or_default(
+				asset_in,
+				asset_out,
+				&weighted_route.route,
+			)))
+		})
+	}
+
+	/// Sum of the buy weights of the routes of a split buy.
+	pub fn split_buy_weight(asset_in: T::AssetId, asset_out: T::AssetId, routes: &SplitRoute<T::AssetId>) -> Weight {
+		routes.iter().fold(Weight::zero(), |weight, weighted_route| {
+			weight.saturating_add(T::WeightInfo::buy_weight(&Self::get_route_or_default(
+				asset_in,
+				asset_out,
+				&weighted_route.route,
+			)))
+		})
 	}
 
 	fn ensure_route_size(route_length: usize) -> Result<(), DispatchError> {
@@ -570,6 +815,112 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Calculates the amounts of every trade of a sell, in the order of execution.
+	///
+	/// If `route` is empty, the on-chain route is used, or the default route if there is none.
+	/// Returns the route used for the calculation together with the trade amounts.
+	pub fn quote_sell(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+		route: &Route<T::AssetId>,
+	) -> Result<(Route<T::AssetId>, Vec<AmountInAndOut<T::Balance>>), DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+		let route = Self::get_route_or_default(asset_in, asset_out, route);
+		Self::ensure_route_arguments(&AssetPair::new(asset_in, asset_out), &route)?;
+
+		let trade_amounts = Self::calculate_sell_trade_amounts(&route, amount_in)?;
+
+		Ok((route, trade_amounts))
+	}
+
+	/// Calculates the amounts of every trade of a buy, in the order of execution.
+	///
+	/// If `route` is empty, the on-chain route is used, or the default route if there is none.
+	/// Returns the route used for the calculation together with the trade amounts.
+	pub fn quote_buy(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		route: &Route<T::AssetId>,
+	) -> Result<(Route<T::AssetId>, Vec<AmountInAndOut<T::Balance>>), DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+		let route = Self::get_route_or_default(asset_in, asset_out, route);
+		Self::ensure_route_arguments(&AssetPair::new(asset_in, asset_out), &route)?;
+
+		// Buy amounts are calculated backwards, from the last trade to the first one
+		let mut trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
+		trade_amounts.reverse();
+
+		Ok((route, trade_amounts))
+	}
+
+	/// Validates that `new_route` can replace the on-chain route of `asset_pair`.
+	///
+	/// The new route has to be better than the existing (or default) route, both in the original and in the inverse direction.
+	/// Returns the ordered asset pair together with the route in the order it is stored.
+	fn validate_route_update(
+		mut asset_pair: AssetPair<T::AssetId>,
+		mut new_route: Route<T::AssetId>,
+	) -> Result<(AssetPair<T::AssetId>, Route<T::AssetId>), DispatchError> {
+		Self::ensure_route_size(new_route.len())?;
+		Self::ensure_route_arguments(&asset_pair, &new_route)?;
+		T::OraclePriceProvider::price(&new_route, T::OraclePeriod::get()).ok_or(Error::<T>::RouteHasNoOracle)?;
+
+		if !asset_pair.is_ordered() {
+			asset_pair = asset_pair.ordered_pair();
+			new_route = inverse_route(new_route)
+		}
+
+		let existing_route = Self::get_route(asset_pair);
+
+		match Self::validate_route(&existing_route.clone()) {
+			Ok((reference_amount_in, reference_amount_in_for_inverse)) => {
+				let new_route_validation = Self::validate_sell(new_route.clone(), reference_amount_in);
+
+				let inverse_new_route = inverse_route(new_route.clone());
+				let inverse_new_route_validation =
+					Self::validate_sell(inverse_new_route.clone(), reference_amount_in_for_inverse);
+
+				match (new_route_validation, inverse_new_route_validation) {
+					(Ok(_), Ok(_)) => (),
+					(Err(_), Ok(amount_out)) => {
+						Self::validate_sell(new_route.clone(), amount_out).map(|_| ())?;
+					}
+					(Ok(amount_out), Err(_)) => {
+						Self::validate_sell(inverse_new_route.clone(), amount_out).map(|_| ())?;
+					}
+					(Err(err), Err(_)) => return Err(err),
+				}
+
+				let amount_out_for_existing_route =
+					Self::calculate_expected_amount_out(&existing_route, reference_amount_in)?;
+				let amount_out_for_new_route = Self::calculate_expected_amount_out(&new_route, reference_amount_in)?;
+
+				let inverse_existing_route = inverse_route(existing_route);
+				let amount_out_for_existing_inversed_route =
+					Self::calculate_expected_amount_out(&inverse_existing_route, reference_amount_in_for_inverse)?;
+				let amount_out_for_new_inversed_route =
+					Self::calculate_expected_amount_out(&inverse_new_route, reference_amount_in_for_inverse)?;
+
+				if amount_out_for_new_route > amount_out_for_existing_route
+					&& amount_out_for_new_inversed_route > amount_out_for_existing_inversed_route
+				{
+					return Ok((asset_pair, new_route));
+				}
+			}
+			Err(_) => {
+				Self::validate_route(&new_route.clone())?;
+
+				return Ok((asset_pair, new_route));
+			}
+		}
+
+		Err(Error::<T>::RouteUpdateIsNotSuccessful.into())
+	}
+
 	fn validate_route(route: &Route<T::AssetId>) -> Result<(T::Balance, T::Balance), DispatchError> {
 		let reference_amount_in = Self::calculate_reference_amount_in(route)?;
 		let route_validation = Self::validate_sell(route.clone(), reference_amount_in);
@@ -738,6 +1089,28 @@ impl<T: Config> RouterT<T::RuntimeOrigin, T::AssetId, T::Balance, Trade<T::Asset
 		Pallet::<T>::buy(origin, asset_in, asset_out, amount_out, max_amount_in, route)
 	}
 
+	fn split_sell(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+		min_amount_out: T::Balance,
+		routes: SplitRoute<T::AssetId>,
+	) -> Result<AmountInAndOut<T::Balance>, DispatchError> {
+		Pallet::<T>::do_split_sell(origin, asset_in, asset_out, amount_in, min_amount_out, routes)
+	}
+
+	fn split_buy(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		max_amount_in: T::Balance,
+		routes: SplitRoute<T::AssetId>,
+	) -> Result<AmountInAndOut<T::Balance>, DispatchError> {
+		Pallet::<T>::do_split_buy(origin, asset_in, asset_out, amount_out, max_amount_in, routes)
+	}
+
 	fn calculate_sell_trade_amounts(
 		route: &[Trade<T::AssetId>],
 		amount_in: T::Balance,
@@ -805,6 +1178,34 @@ impl<T: Config> RouterT<T::RuntimeOrigin, T::AssetId, T::Balance, Trade<T::Asset
 		Ok(())
 	}
 
+	fn split_sell(
+		_origin: T::RuntimeOrigin,
+		_asset_in: T::AssetId,
+		_asset_out: T::AssetId,
+		amount_in: T::Balance,
+		_min_amount_out: T::Balance,
+		_routes: SplitRoute<T::AssetId>,
+	) -> Result<AmountInAndOut<T::Balance>, DispatchError> {
+		Ok(AmountInAndOut::<T::Balance> {
+			amount_in,
+			amount_out: amount_in,
+		})
+	}
+
+	fn split_buy(
+		_origin: T::RuntimeOrigin,
+		_asset_in: T::AssetId,
+		_asset_out: T::AssetId,
+		amount_out: T::Balance,
+		_max_amount_in: T::Balance,
+		_routes: SplitRoute<T::AssetId>,
+	) -> Result<AmountInAndOut<T::Balance>, DispatchError> {
+		Ok(AmountInAndOut::<T::Balance> {
+			amount_in: amount_out,
+			amount_out,
+		})
+	}
+
 	fn calculate_sell_trade_amounts(
 		_route: &[Trade<T::AssetId>],
 		amount_in: T::Balance,
@@ -0,0 +1,374 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade};
+use codec::Decode;
+use frame_support::pallet_prelude::*;
+use frame_support::{assert_noop, assert_ok};
+use frame_system::pallet_prelude::BlockNumberFor;
+use hydradx_traits::router::RouteProvider;
+use hydradx_traits::router::{AssetPair, PoolType};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+const HDX_AUSD_TRADE_IN_OMNIPOOL: Trade<AssetId> = Trade {
+	pool: PoolType::Omnipool,
+	asset_in: HDX,
+	asset_out: AUSD,
+};
+
+#[test]
+fn discover_routes_should_return_shorter_routes_first() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::Omnipool, HDX, DOT),
+			(PoolType::LBP, DOT, AUSD),
+			(PoolType::XYK, HDX, AUSD),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let routes = Router::discover_routes(HDX, AUSD);
+
+			//Assert
+			assert_eq!(
+				routes,
+				vec![
+					BoundedVec::truncate_from(vec![HDX_AUSD_TRADE_IN_XYK]),
+					BoundedVec::truncate_from(vec![
+						Trade {
+							pool: PoolType::Omnipool,
+							asset_in: HDX,
+							asset_out: DOT,
+						},
+						Trade {
+							pool: PoolType::LBP,
+							asset_in: DOT,
+							asset_out: AUSD,
+						}
+					]),
+				]
+			);
+		});
+}
+
+#[test]
+fn discover_routes_should_not_visit_asset_twice() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::XYK, HDX, KSM),
+			(PoolType::XYK, KSM, DOT),
+			(PoolType::XYK, DOT, HDX),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let routes = Router::discover_routes(HDX, DOT);
+
+			//Assert
+			assert_eq!(
+				routes,
+				vec![
+					BoundedVec::truncate_from(vec![Trade {
+						pool: PoolType::XYK,
+						asset_in: HDX,
+						asset_out: DOT,
+					}]),
+					BoundedVec::truncate_from(vec![
+						Trade {
+							pool: PoolType::XYK,
+							asset_in: HDX,
+							asset_out: KSM,
+						},
+						Trade {
+							pool: PoolType::XYK,
+							asset_in: KSM,
+							asset_out: DOT,
+						}
+					]),
+				]
+			);
+		});
+}
+
+#[test]
+fn discover_routes_should_skip_consecutive_trades_in_same_omnipool() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::Omnipool, HDX, DOT),
+			(PoolType::Omnipool, DOT, AUSD),
+			(PoolType::Omnipool, HDX, AUSD),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let routes = Router::discover_routes(HDX, AUSD);
+
+			//Assert
+			assert_eq!(
+				routes,
+				vec![BoundedVec::truncate_from(vec![HDX_AUSD_TRADE_IN_OMNIPOOL])]
+			);
+		});
+}
+
+#[test]
+fn discover_routes_should_return_empty_list_when_assets_are_not_connected() {
+	ExtBuilder::default()
+		.with_pools(vec![(PoolType::XYK, HDX, DOT), (PoolType::XYK, KSM, AUSD)])
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert!(Router::discover_routes(HDX, AUSD).is_empty());
+			assert!(Router::discover_routes(HDX, HDX).is_empty());
+		});
+}
+
+#[test]
+fn discover_routes_should_be_limited_by_max_route_candidates() {
+	let intermediate_assets: Vec<AssetId> = (100..115).collect();
+	let pools = intermediate_assets
+		.iter()
+		.flat_map(|asset| vec![(PoolType::XYK, HDX, *asset), (PoolType::XYK, *asset, AUSD)])
+		.collect();
+
+	ExtBuilder::default().with_pools(pools).build().execute_with(|| {
+		//Act
+		let routes = Router::discover_routes(HDX, AUSD);
+
+		//Assert
+		assert_eq!(routes.len(), MaxRouteCandidates::get() as usize);
+	});
+}
+
+#[test]
+fn discover_routes_should_not_return_routes_longer_than_max_number_of_trades() {
+	let assets: Vec<AssetId> = (100..=110).collect();
+	let pools = assets
+		.windows(2)
+		.map(|pair| (PoolType::XYK, pair[0], pair[1]))
+		.collect();
+
+	ExtBuilder::default().with_pools(pools).build().execute_with(|| {
+		//Act and assert
+		assert_eq!(Router::discover_routes(100, 109).len(), 1);
+		assert_eq!(Router::discover_routes(100, 109)[0].len(), 9);
+		assert!(Router::discover_routes(100, 110).is_empty());
+	});
+}
+
+#[test]
+fn find_best_sell_route_should_return_route_with_highest_amount_out() {
+	ExtBuilder::default()
+		.with_pools(vec![
+			(PoolType::LBP, HDX, AUSD),
+			(PoolType::XYK, HDX, AUSD),
+			(PoolType::Stableswap(STABLE_SHARE_ASSET), HDX, AUSD),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let best_route = Router::find_best_sell_route(HDX, AUSD, 100);
+
+			//Assert
+			assert_eq!(
+				best_route,
+				Some((
+					BoundedVec::truncate_from(vec![HDX_AUSD_TRADE_IN_XYK]),
+					XYK_SELL_CALCULATION_RESULT
+				))
+			);
+		});
+}
+
+#[test]
+fn find_best_buy_route_should_return_route_with_lowest_amount_in() {
+	ExtBuilder::default()
+		.with_pools(vec![(PoolType::LBP, HDX, AUSD), (PoolType::XYK, HDX, AUSD)])
+		.build()
+		.execute_with(|| {
+			//Act
+			let best_route = Router::find_best_buy_route(HDX, AUSD, 100);
+
+			//Assert
+			assert_eq!(
+				best_route,
+				Some((
+					BoundedVec::truncate_from(vec![HDX_AUSD_TRADE_IN_XYK]),
+					XYK_BUY_CALCULATION_RESULT
+				))
+			);
+		});
+}
+
+#[test]
+fn find_best_sell_route_should_return_current_route_when_no_route_is_discovered() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let best_route = Router::find_best_sell_route(HDX, AUSD, 100);
+
+		//Assert
+		assert_eq!(
+			best_route,
+			Some((
+				BoundedVec::truncate_from(default_omnipool_route()),
+				OMNIPOOL_SELL_CALCULATION_RESULT
+			))
+		);
+	});
+}
+
+#[test]
+fn find_best_sell_route_should_skip_routes_which_cannot_be_calculated() {
+	ExtBuilder::default()
+		.with_pools(vec![(PoolType::XYK, HDX, AUSD)])
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_eq!(
+				Router::find_best_sell_route(HDX, AUSD, INVALID_CALCULATION_AMOUNT),
+				None
+			);
+		});
+}
+
+#[test]
+fn offchain_worker_should_propose_better_route() {
+	let (mut ext, pool_state) = ExtBuilder::default()
+		.with_pools(vec![(PoolType::LBP, HDX, AUSD), (PoolType::XYK, HDX, AUSD)])
+		.build_with_offchain();
+
+	ext.execute_with(|| {
+		//Act
+		<Router as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		//Assert
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert!(matches!(tx.preamble, sp_runtime::generic::Preamble::Bare(_)));
+		assert_eq!(
+			tx.function,
+			RuntimeCall::Router(crate::Call::propose_route {
+				asset_pair: AssetPair::new(HDX, AUSD),
+				new_route: BoundedVec::truncate_from(vec![HDX_AUSD_TRADE_IN_XYK]),
+			})
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_should_not_propose_route_when_current_route_is_the_best() {
+	let (mut ext, pool_state) = ExtBuilder::default()
+		.with_pools(vec![(PoolType::LBP, HDX, AUSD), (PoolType::XYK, HDX, AUSD)])
+		.build_with_offchain();
+
+	ext.execute_with(|| {
+		//Arrange
+		assert_ok!(Router::force_insert_route(
+			RuntimeOrigin::root(),
+			AssetPair::new(HDX, AUSD),
+			BoundedVec::truncate_from(vec![HDX_AUSD_TRADE_IN_XYK]),
+		));
+
+		//Act
+		<Router as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		//Assert
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_should_not_propose_route_without_oracle() {
+	let (mut ext, pool_state) = ExtBuilder::default()
+		.with_pools(vec![(PoolType::XYK, HDX, INSUFFICIENT_ASSET)])
+		.build_with_offchain();
+
+	ext.execute_with(|| {
+		//Act
+		<Router as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
+
+		//Assert
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn propose_route_should_store_better_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let asset_pair = AssetPair::new(AUSD, HDX);
+		let route = vec![Trade {
+			pool: PoolType::XYK,
+			asset_in: AUSD,
+			asset_out: HDX,
+		}];
+
+		//Act
+		assert_ok!(
+			Router::propose_route(RuntimeOrigin::none(), asset_pair, BoundedVec::truncate_from(route)),
+			Pays::No.into()
+		);
+
+		//Assert
+		assert_eq!(
+			Router::get_route(AssetPair::new(HDX, AUSD)),
+			vec![HDX_AUSD_TRADE_IN_XYK]
+		);
+
+		expect_events(vec![Event::RouteUpdated {
+			asset_ids: vec![HDX, AUSD],
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn propose_route_should_fail_when_called_by_signed_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::propose_route(
+				RuntimeOrigin::signed(ALICE),
+				AssetPair::new(HDX, AUSD),
+				BoundedVec::truncate_from(vec![HDX_AUSD_TRADE_IN_XYK])
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn propose_route_should_fail_when_route_is_not_better() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::propose_route(
+				RuntimeOrigin::none(),
+				AssetPair::new(HDX, AUSD),
+				BoundedVec::truncate_from(vec![Trade {
+					pool: PoolType::Stableswap(STABLE_SHARE_ASSET),
+					asset_in: HDX,
+					asset_out: AUSD,
+				}])
+			),
+			Error::<Test>::RouteUpdateIsNotSuccessful
+		);
+	});
+}
@@ -25,10 +25,14 @@ use frame_support::{
 use frame_system::EnsureRoot;
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use hydra_dx_math::ratio::Ratio;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution, TradeablePoolsProvider};
 use orml_traits::parameter_type_with_key;
 use pallet_currencies::{fungibles::FungibleCurrencies, BasicCurrencyAdapter, MockBoundErc20, MockErc20Currency};
 use pretty_assertions::assert_eq;
+use sp_core::offchain::{
+	testing::PoolState, testing::TestOffchainExt, testing::TestTransactionPoolExt, OffchainDbExt, OffchainWorkerExt,
+	TransactionPoolExt,
+};
 use sp_core::H256;
 use sp_runtime::FixedU128;
 use sp_runtime::{
@@ -37,6 +41,7 @@ use sp_runtime::{
 };
 use std::cell::RefCell;
 use std::ops::Deref;
+use std::sync::Arc;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -159,6 +164,8 @@ parameter_types! {
 	pub const RouteValidationOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
 
 	pub const RouterPalletId: PalletId = PalletId(*b"routerac");
+	pub const MaxRouteCandidates: u32 = 10;
+	pub const MaxRouteSearchSteps: u32 = 100;
 }
 
 impl Config for Test {
@@ -171,8 +178,42 @@ impl Config for Test {
 	type OraclePeriod = RouteValidationOraclePeriod;
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type ForceInsertOrigin = EnsureRoot<Self::AccountId>;
+	type PoolsProvider = PoolsProviderMock;
+	type MaxRouteCandidates = MaxRouteCandidates;
+	type MaxRouteSearchSteps = MaxRouteSearchSteps;
 	type WeightInfo = ();
 }
+
+pub(crate) type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type RuntimeCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> hydradx_traits::CreateBare<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_bare(call: Self::RuntimeCall) -> Extrinsic {
+		Extrinsic::new_bare(call)
+	}
+}
+
+thread_local! {
+	pub static TRADEABLE_POOLS: RefCell<Vec<Trade<AssetId>>> = RefCell::new(Vec::default());
+}
+
+pub struct PoolsProviderMock;
+
+impl TradeablePoolsProvider<AssetId> for PoolsProviderMock {
+	fn tradeable_pools() -> Vec<Trade<AssetId>> {
+		TRADEABLE_POOLS.with(|v| v.borrow().clone())
+	}
+}
 pub struct PriceProviderMock {}
 
 impl PriceOracle<AssetId> for PriceProviderMock {
@@ -272,6 +313,7 @@ pub const HDX_AUSD_TRADE_IN_XYK: Trade<AssetId> = Trade {
 
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	tradeable_pools: Vec<Trade<AssetId>>,
 }
 
 // Returns default values for genesis config
@@ -279,6 +321,7 @@ impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
 			endowed_accounts: vec![(ALICE, HDX, 1000u128)],
+			tradeable_pools: vec![],
 		}
 	}
 }
@@ -289,6 +332,37 @@ impl ExtBuilder {
 		self
 	}
 
+	/// Pools are added in both directions
+	pub fn with_pools(mut self, pools: Vec<(PoolType<AssetId>, AssetId, AssetId)>) -> Self {
+		for (pool, asset_a, asset_b) in pools {
+			self.tradeable_pools.push(Trade {
+				pool,
+				asset_in: asset_a,
+				asset_out: asset_b,
+			});
+			self.tradeable_pools.push(Trade {
+				pool,
+				asset_in: asset_b,
+				asset_out: asset_a,
+			});
+		}
+		self
+	}
+
+	pub fn build_with_offchain(self) -> (sp_io::TestExternalities, Arc<parking_lot::RwLock<PoolState>>) {
+		let mut ext = self.build();
+
+		let (offchain, _offchain_state) = TestOffchainExt::with_offchain_db(ext.offchain_db());
+		ext.register_extension(OffchainDbExt::new(offchain.clone()));
+		ext.register_extension(OffchainWorkerExt::new(offchain));
+		let (pool, pool_state) = TestTransactionPoolExt::new();
+		ext.register_extension(TransactionPoolExt::new(pool));
+
+		ext.persist_offchain_overlay();
+
+		(ext, pool_state)
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
@@ -315,6 +389,8 @@ impl ExtBuilder {
 
 		initial_accounts.extend(self.endowed_accounts);
 
+		TRADEABLE_POOLS.with(|v| *v.borrow_mut() = self.tradeable_pools);
+
 		orml_tokens::GenesisConfig::<Test> {
 			balances: initial_accounts,
 		}
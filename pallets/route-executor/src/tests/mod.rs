@@ -1,7 +1,10 @@
 pub mod buy;
+pub mod discovery;
 pub mod force_insert_route;
 pub mod mock;
+pub mod quote;
 pub mod sell;
 pub mod sell_all;
 pub mod set_route;
+pub mod split;
 pub mod spot_price;
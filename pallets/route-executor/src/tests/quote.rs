@@ -0,0 +1,161 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{AmountInAndOut, Error, Trade};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use hydradx_traits::router::{AssetPair, PoolType};
+use pretty_assertions::assert_eq;
+
+fn multi_hop_route() -> Vec<Trade<AssetId>> {
+	vec![
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: HDX,
+			asset_out: MOVR,
+		},
+		Trade {
+			pool: PoolType::Stableswap(AUSD),
+			asset_in: MOVR,
+			asset_out: AUSD,
+		},
+		Trade {
+			pool: PoolType::Omnipool,
+			asset_in: AUSD,
+			asset_out: KSM,
+		},
+	]
+}
+
+#[test]
+fn quote_sell_should_return_amounts_for_every_trade() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+		let route = BoundedVec::truncate_from(multi_hop_route());
+
+		//Act
+		let (quoted_route, amounts) = Router::quote_sell(HDX, KSM, amount_to_sell, &route).unwrap();
+
+		//Assert
+		assert_eq!(quoted_route, route);
+		assert_eq!(
+			amounts,
+			vec![
+				AmountInAndOut {
+					amount_in: amount_to_sell,
+					amount_out: XYK_SELL_CALCULATION_RESULT,
+				},
+				AmountInAndOut {
+					amount_in: XYK_SELL_CALCULATION_RESULT,
+					amount_out: STABLESWAP_SELL_CALCULATION_RESULT,
+				},
+				AmountInAndOut {
+					amount_in: STABLESWAP_SELL_CALCULATION_RESULT,
+					amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn quote_buy_should_return_amounts_in_order_of_execution() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+		let route = BoundedVec::truncate_from(multi_hop_route());
+
+		//Act
+		let (_, amounts) = Router::quote_buy(HDX, KSM, amount_to_buy, &route).unwrap();
+
+		//Assert
+		assert_eq!(
+			amounts,
+			vec![
+				AmountInAndOut {
+					amount_in: XYK_BUY_CALCULATION_RESULT,
+					amount_out: STABLESWAP_BUY_CALCULATION_RESULT,
+				},
+				AmountInAndOut {
+					amount_in: STABLESWAP_BUY_CALCULATION_RESULT,
+					amount_out: OMNIPOOL_BUY_CALCULATION_RESULT,
+				},
+				AmountInAndOut {
+					amount_in: OMNIPOOL_BUY_CALCULATION_RESULT,
+					amount_out: amount_to_buy,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn quote_sell_should_use_onchain_route_when_no_route_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let route = BoundedVec::truncate_from(vec![HDX_AUSD_TRADE_IN_XYK]);
+		assert_ok!(Router::force_insert_route(
+			RuntimeOrigin::root(),
+			AssetPair::new(HDX, AUSD),
+			route.clone()
+		));
+
+		//Act
+		let (quoted_route, amounts) = Router::quote_sell(HDX, AUSD, 10, &BoundedVec::new()).unwrap();
+
+		//Assert
+		assert_eq!(quoted_route, route);
+		assert_eq!(
+			amounts,
+			vec![AmountInAndOut {
+				amount_in: 10,
+				amount_out: XYK_SELL_CALCULATION_RESULT,
+			}]
+		);
+	});
+}
+
+#[test]
+fn quote_sell_should_use_default_route_when_no_onchain_route_exists() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let (quoted_route, _) = Router::quote_sell(HDX, AUSD, 10, &BoundedVec::new()).unwrap();
+
+		//Assert
+		assert_eq!(quoted_route.into_inner(), default_omnipool_route());
+	});
+}
+
+#[test]
+fn quote_sell_should_fail_when_route_does_not_match_asset_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		let route = BoundedVec::truncate_from(multi_hop_route());
+
+		assert_noop!(Router::quote_sell(HDX, AUSD, 10, &route), Error::<Test>::InvalidRoute);
+	});
+}
+
+#[test]
+fn quote_buy_should_fail_when_assets_are_the_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::quote_buy(HDX, HDX, 10, &BoundedVec::new()),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
@@ -0,0 +1,320 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade, WeightedRoute};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::{BoundedVec, Permill};
+
+const HDX_AUSD_TRADE_IN_LBP: Trade<AssetId> = Trade {
+	pool: PoolType::LBP,
+	asset_in: HDX,
+	asset_out: AUSD,
+};
+
+fn weighted_route(percent: u32, trades: Vec<Trade<AssetId>>) -> WeightedRoute<AssetId> {
+	WeightedRoute {
+		weight: Permill::from_percent(percent),
+		route: BoundedVec::truncate_from(trades),
+	}
+}
+
+#[test]
+fn split_sell_should_execute_all_routes_with_weighted_amounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 100;
+		let routes = vec![
+			weighted_route(60, vec![HDX_AUSD_TRADE_IN_XYK]),
+			weighted_route(40, vec![]),
+		];
+
+		//Act
+		assert_ok!(Router::split_sell(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT,
+			BoundedVec::truncate_from(routes)
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![
+			(PoolType::XYK, 60, HDX, AUSD),
+			(PoolType::Omnipool, 40, HDX, AUSD),
+		]);
+		assert_eq!(
+			Currencies::free_balance(AUSD, &ALICE),
+			XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT
+		);
+		expect_events(vec![Event::SplitExecuted {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: amount_to_sell,
+			amount_out: XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn split_sell_should_give_rounding_remainder_to_last_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+		let routes = vec![
+			weighted_route(33, vec![HDX_AUSD_TRADE_IN_XYK]),
+			weighted_route(33, vec![HDX_AUSD_TRADE_IN_LBP]),
+			weighted_route(34, vec![]),
+		];
+
+		//Act
+		assert_ok!(Router::split_sell(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			0,
+			BoundedVec::truncate_from(routes)
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![
+			(PoolType::XYK, 3, HDX, AUSD),
+			(PoolType::LBP, 3, HDX, AUSD),
+			(PoolType::Omnipool, 4, HDX, AUSD),
+		]);
+	});
+}
+
+#[test]
+fn split_sell_should_fail_when_total_amount_out_is_below_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			weighted_route(50, vec![HDX_AUSD_TRADE_IN_XYK]),
+			weighted_route(50, vec![HDX_AUSD_TRADE_IN_LBP]),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::split_sell(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				100,
+				XYK_SELL_CALCULATION_RESULT + LBP_SELL_CALCULATION_RESULT + 1,
+				BoundedVec::truncate_from(routes)
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn split_sell_should_fail_when_weights_do_not_sum_up_to_one_hundred_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			weighted_route(50, vec![HDX_AUSD_TRADE_IN_XYK]),
+			weighted_route(40, vec![HDX_AUSD_TRADE_IN_LBP]),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::split_sell(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				100,
+				0,
+				BoundedVec::truncate_from(routes)
+			),
+			Error::<Test>::InvalidSplitRoute
+		);
+	});
+}
+
+#[test]
+fn split_sell_should_fail_when_route_has_zero_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			weighted_route(100, vec![HDX_AUSD_TRADE_IN_XYK]),
+			weighted_route(0, vec![HDX_AUSD_TRADE_IN_LBP]),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::split_sell(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				100,
+				0,
+				BoundedVec::truncate_from(routes)
+			),
+			Error::<Test>::InvalidSplitRoute
+		);
+	});
+}
+
+#[test]
+fn split_sell_should_fail_when_no_route_is_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::split_sell(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 0, BoundedVec::new()),
+			Error::<Test>::InvalidSplitRoute
+		);
+	});
+}
+
+#[test]
+fn split_sell_should_fail_when_route_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			weighted_route(50, vec![HDX_AUSD_TRADE_IN_XYK]),
+			weighted_route(
+				50,
+				vec![Trade {
+					pool: PoolType::XYK,
+					asset_in: HDX,
+					asset_out: KSM,
+				}],
+			),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::split_sell(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				100,
+				0,
+				BoundedVec::truncate_from(routes)
+			),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn split_sell_should_fail_when_called_by_unsigned() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::split_sell(
+				RuntimeOrigin::none(),
+				HDX,
+				AUSD,
+				100,
+				0,
+				BoundedVec::truncate_from(vec![weighted_route(100, vec![HDX_AUSD_TRADE_IN_XYK])])
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn split_buy_should_execute_all_routes_with_weighted_amounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+		let routes = vec![
+			weighted_route(50, vec![HDX_AUSD_TRADE_IN_XYK]),
+			weighted_route(50, vec![HDX_AUSD_TRADE_IN_LBP]),
+		];
+
+		//Act
+		assert_ok!(Router::split_buy(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_buy,
+			XYK_BUY_CALCULATION_RESULT + LBP_BUY_CALCULATION_RESULT,
+			BoundedVec::truncate_from(routes)
+		));
+
+		//Assert
+		assert_executed_buy_trades(vec![(PoolType::XYK, 5, HDX, AUSD), (PoolType::LBP, 5, HDX, AUSD)]);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), amount_to_buy);
+		expect_events(vec![Event::SplitExecuted {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: XYK_BUY_CALCULATION_RESULT + LBP_BUY_CALCULATION_RESULT,
+			amount_out: amount_to_buy,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn split_buy_should_fail_when_total_amount_in_is_above_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			weighted_route(50, vec![HDX_AUSD_TRADE_IN_XYK]),
+			weighted_route(50, vec![HDX_AUSD_TRADE_IN_LBP]),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::split_buy(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				XYK_BUY_CALCULATION_RESULT + LBP_BUY_CALCULATION_RESULT - 1,
+				BoundedVec::truncate_from(routes)
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn split_buy_should_fail_when_weights_do_not_sum_up_to_one_hundred_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			weighted_route(60, vec![HDX_AUSD_TRADE_IN_XYK]),
+			weighted_route(60, vec![HDX_AUSD_TRADE_IN_LBP]),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::split_buy(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				100,
+				BoundedVec::truncate_from(routes)
+			),
+			Error::<Test>::InvalidSplitRoute
+		);
+	});
+}
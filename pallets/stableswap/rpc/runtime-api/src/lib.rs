@@ -0,0 +1,131 @@
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the stableswap pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::{FixedU128, Perbill, Permill};
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Peg of an asset as (numerator, denominator).
+pub type PegType = (u128, u128);
+
+/// Reserve of an asset in the pool.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AssetReserve<AssetId, Balance> {
+	pub asset_id: AssetId,
+	pub reserve: Balance,
+	pub decimals: u8,
+}
+
+/// State of the pool used by the pool math in the current block.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PoolSnapshot<AssetId, Balance> {
+	/// Pool assets in pool order.
+	pub assets: Vec<AssetReserve<AssetId, Balance>>,
+	/// Current amplification.
+	pub amplification: u128,
+	/// Base trade fee of the pool.
+	pub fee: Permill,
+	/// Trade fee in the current block, adjusted by peg changes.
+	pub block_fee: Permill,
+	/// Current pegs of the assets, moved towards the target pegs.
+	pub pegs: Vec<PegType>,
+	pub share_issuance: Balance,
+}
+
+/// Amplification ramp of the pool.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AmplificationRamp<BlockNumber> {
+	pub initial_amplification: u16,
+	pub final_amplification: u16,
+	pub initial_block: BlockNumber,
+	pub final_block: BlockNumber,
+	/// Amplification is changing in the current block.
+	pub in_progress: bool,
+}
+
+/// Pegs of a pool with peg sources.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PegState<BlockNumber> {
+	/// Pegs stored at `updated_at`.
+	pub stored: Vec<PegType>,
+	pub updated_at: BlockNumber,
+	/// Target pegs provided by the peg sources. `None` if a peg source is not available.
+	pub target: Option<Vec<PegType>>,
+	/// Maximum relative peg change per block.
+	pub max_peg_update: Perbill,
+}
+
+/// Stableswap pool with its current state.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PoolState<AssetId, Balance, BlockNumber> {
+	pub pool_id: AssetId,
+	pub snapshot: PoolSnapshot<AssetId, Balance>,
+	pub amplification: AmplificationRamp<BlockNumber>,
+	/// `None` if the pool has no peg sources, assets are pegged 1:1.
+	pub pegs: Option<PegState<BlockNumber>>,
+	/// Value of one share in terms of the pool invariant, normalized to 18 decimals.
+	/// `None` if the pool has no liquidity.
+	pub virtual_price: Option<FixedU128>,
+}
+
+/// Quote for removing liquidity as a single asset.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RemoveLiquidityOneAssetQuote<Balance> {
+	/// Amount received, after the fee.
+	pub amount: Balance,
+	pub fee: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query stableswap pools and quote liquidity changes.
+	pub trait StableswapApi<AssetId, Balance, BlockNumber> where
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Return all pools with their current state.
+		fn pools() -> Vec<PoolState<AssetId, Balance, BlockNumber>>;
+
+		/// Return the pool with its current state, if it exists.
+		fn pool(pool_id: AssetId) -> Option<PoolState<AssetId, Balance, BlockNumber>>;
+
+		/// Quote the amount of shares received for adding liquidity of the given assets.
+		fn quote_add_liquidity(pool_id: AssetId, assets: Vec<(AssetId, Balance)>) -> Result<Balance, DispatchError>;
+
+		/// Quote the amounts of all pool assets received for removing the given amount of shares.
+		fn quote_remove_liquidity(pool_id: AssetId, share_amount: Balance) -> Result<Vec<(AssetId, Balance)>, DispatchError>;
+
+		/// Quote the amount of the asset received for removing the given amount of shares as a single asset.
+		fn quote_remove_liquidity_one_asset(
+			pool_id: AssetId,
+			asset_id: AssetId,
+			share_amount: Balance,
+		) -> Result<RemoveLiquidityOneAssetQuote<Balance>, DispatchError>;
+	}
+}
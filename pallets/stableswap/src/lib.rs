@@ -74,7 +74,7 @@ use sp_std::num::NonZeroU16;
 use sp_std::prelude::*;
 use sp_std::vec;
 
-use sp_runtime::FixedU128;
+use sp_runtime::{FixedPointNumber, FixedU128};
 
 mod trade_execution;
 pub mod traits;
@@ -88,6 +88,7 @@ use crate::types::{
 };
 
 use hydra_dx_math::stableswap::types::AssetReserve;
+use hydradx_traits::circuit_breaker::AccountTradeVolumeHandler;
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use hydradx_traits::stableswap::AssetAmount;
 use orml_traits::MultiCurrency;
@@ -170,6 +171,9 @@ pub mod pallet {
 		/// Hooks are actions executed on add_liquidity, sell or buy.
 		type Hooks: StableswapHooks<Self::AssetId>;
 
+		/// Counts trades towards the per-account trade volume limit of the trader.
+		type AccountTradeVolumeHandler: AccountTradeVolumeHandler<Self::AccountId, Self::AssetId, Balance>;
+
 		/// Minimum pool liquidity
 		#[pallet::constant]
 		type MinPoolLiquidity: Get<Balance>;
@@ -733,7 +737,8 @@ pub mod pallet {
 		///
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as Config>::WeightInfo::sell()
-							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(T::AccountTradeVolumeHandler::note_trade_volume_weight()))]
 		#[transactional]
 		pub fn sell(
 			origin: OriginFor<T>,
@@ -789,6 +794,8 @@ pub mod pallet {
 				ExistenceRequirement::AllowDeath,
 			)?;
 
+			T::AccountTradeVolumeHandler::note_trade_volume(&who, asset_in, amount_in)?;
+
 			//All done and updated. Let's call on_trade hook.
 			Self::call_on_trade_hook(pool_id, asset_in, asset_out, &initial_reserves)?;
 
@@ -836,7 +843,8 @@ pub mod pallet {
 		///
 		#[pallet::call_index(8)]
 		#[pallet::weight(<T as Config>::WeightInfo::buy()
-							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(T::AccountTradeVolumeHandler::note_trade_volume_weight()))]
 		#[transactional]
 		pub fn buy(
 			origin: OriginFor<T>,
@@ -895,6 +903,8 @@ pub mod pallet {
 				ExistenceRequirement::AllowDeath,
 			)?;
 
+			T::AccountTradeVolumeHandler::note_trade_volume(&who, asset_out, amount_out)?;
+
 			//All done and updated. Let's call on_trade_hook.
 			Self::call_on_trade_hook(pool_id, asset_in, asset_out, &initial_reserves)?;
 
@@ -2353,4 +2363,112 @@ impl<T: Config> Pallet<T> {
 		);
 		Ok((amount_in, updated_pool_state))
 	}
+
+	/// Calculates amount of shares received for adding liquidity of given assets to the pool.
+	pub fn simulate_add_liquidity(
+		pool_id: T::AssetId,
+		assets: &[AssetAmount<T::AssetId>],
+	) -> Result<Balance, DispatchError> {
+		let snapshot = Self::create_snapshot(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		ensure!(!snapshot.share_issuance.is_zero(), Error::<T>::InsufficientLiquidity);
+
+		let mut updated_reserves = snapshot.reserves.to_vec();
+		for asset in assets.iter() {
+			let idx = snapshot.asset_idx(asset.asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+			updated_reserves[idx].amount = updated_reserves[idx]
+				.amount
+				.checked_add(asset.amount)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		let (share_amount, _) = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
+			&snapshot.reserves,
+			&updated_reserves,
+			snapshot.amplification,
+			snapshot.share_issuance,
+			snapshot.block_fee,
+			&snapshot.pegs,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(share_amount)
+	}
+
+	/// Calculates amounts of all pool assets received for removing given amount of shares.
+	pub fn simulate_remove_liquidity(
+		pool_id: T::AssetId,
+		share_amount: Balance,
+	) -> Result<Vec<AssetAmount<T::AssetId>>, DispatchError> {
+		let snapshot = Self::create_snapshot(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		ensure!(
+			share_amount <= snapshot.share_issuance,
+			Error::<T>::InsufficientLiquidityRemaining
+		);
+
+		snapshot
+			.assets
+			.iter()
+			.zip(snapshot.reserves.iter())
+			.map(|(asset_id, reserve)| {
+				hydra_dx_math::stableswap::calculate_liquidity_out(
+					reserve.amount,
+					share_amount,
+					snapshot.share_issuance,
+				)
+				.map(|amount| AssetAmount::new(*asset_id, amount))
+				.ok_or_else(|| ArithmeticError::Overflow.into())
+			})
+			.collect()
+	}
+
+	/// Calculates amount of asset received for removing given amount of shares as single asset.
+	/// Returns (amount, fee amount). Fee amount is already subtracted from the amount.
+	pub fn simulate_remove_liquidity_one_asset(
+		pool_id: T::AssetId,
+		asset_id: T::AssetId,
+		share_amount: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let snapshot = Self::create_snapshot(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let asset_idx = snapshot.asset_idx(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+
+		hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+			&snapshot.reserves,
+			share_amount,
+			asset_idx,
+			snapshot.share_issuance,
+			snapshot.amplification,
+			snapshot.block_fee,
+			&snapshot.pegs,
+		)
+		.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// Returns value of one share in terms of the pool invariant D, normalized to 18 decimals.
+	/// `None` if pool has no liquidity.
+	pub fn virtual_price(snapshot: &PoolSnapshot<T::AssetId>) -> Option<FixedU128> {
+		if snapshot.share_issuance.is_zero() {
+			return None;
+		}
+		let d = hydra_dx_math::stableswap::calculate_d::<D_ITERATIONS>(
+			&snapshot.reserves,
+			snapshot.amplification,
+			&snapshot.pegs,
+		)?;
+
+		FixedU128::checked_from_rational(d, snapshot.share_issuance)
+	}
+
+	/// Returns target pegs of the pool, as provided by the peg sources now.
+	/// Pools without peg sources are pegged 1:1.
+	pub fn target_pegs(pool_id: T::AssetId) -> Result<Vec<PegType>, DispatchError> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let Some(peg_info) = PoolPegs::<T>::get(pool_id) else {
+			return Ok(vec![(1, 1); pool.assets.len()]);
+		};
+
+		Ok(Self::get_target_pegs(&pool.assets, &peg_info.source)?
+			.into_iter()
+			.map(|(peg, _)| peg)
+			.collect())
+	}
 }
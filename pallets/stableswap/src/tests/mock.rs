@@ -213,6 +213,7 @@ impl Config for Test {
 	type BlockNumberProvider = System;
 	type DustAccountHandler = Whitelist;
 	type Hooks = DummyHookAdapter;
+	type AccountTradeVolumeHandler = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = DummyRegistry;
 	type TargetPegOracle = DummyPegOracle;
@@ -649,6 +650,7 @@ parameter_types! {
 	pub DefaultMaxAddLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub DefaultMaxRemoveLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub const OmnipoolHubAsset: AssetId = 111;
+	pub const PriceDeviationOraclePeriod: hydradx_traits::oracle::OraclePeriod = hydradx_traits::oracle::OraclePeriod::Short;
 }
 
 pub struct DepositLimiter;
@@ -753,4 +755,6 @@ impl pallet_circuit_breaker::Config for Test {
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type TimestampProvider = Timestamp;
+	type PriceOracle = ();
+	type PriceDeviationOraclePeriod = PriceDeviationOraclePeriod;
 }
@@ -17,6 +17,7 @@ mod pegs_with_different_decimals;
 mod price;
 mod remove_liquidity;
 mod share_issuance;
+mod simulation;
 mod trades;
 mod update_max_peg_update;
 mod update_peg_source;
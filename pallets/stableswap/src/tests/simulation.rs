@@ -0,0 +1,166 @@
+use crate::tests::mock::*;
+use crate::types::PoolInfo;
+use crate::{assert_balance, Error};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use hydradx_traits::stableswap::AssetAmount;
+use sp_runtime::{FixedU128, Permill};
+use std::num::NonZeroU16;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const ASSET_C: AssetId = 3;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 200 * ONE),
+			(BOB, ASSET_B, 200 * ONE),
+			(ALICE, ASSET_A, 100 * ONE),
+			(ALICE, ASSET_B, 200 * ONE),
+			(ALICE, ASSET_C, 300 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), ASSET_C, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B, ASSET_C].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(1),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, 100 * ONE),
+					AssetAmount::new(ASSET_B, 200 * ONE),
+					AssetAmount::new(ASSET_C, 300 * ONE),
+				],
+			},
+		)
+		.build()
+}
+
+#[test]
+fn simulate_add_liquidity_should_return_shares_received_on_add_liquidity() {
+	ext().execute_with(|| {
+		// Arrange
+		let pool_id = get_pool_id_at(0);
+		let assets = vec![AssetAmount::new(ASSET_A, 50 * ONE), AssetAmount::new(ASSET_B, 10 * ONE)];
+
+		// Act
+		let shares = Stableswap::simulate_add_liquidity(pool_id, &assets).unwrap();
+
+		// Assert
+		assert_ok!(Stableswap::add_assets_liquidity(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			BoundedVec::truncate_from(assets),
+			0,
+		));
+		assert_balance!(BOB, pool_id, shares);
+	});
+}
+
+#[test]
+fn simulate_add_liquidity_should_fail_when_asset_is_not_in_pool() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::simulate_add_liquidity(pool_id, &[AssetAmount::new(pool_id, ONE)]),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn simulate_remove_liquidity_one_asset_should_return_amount_received_on_removal() {
+	ext().execute_with(|| {
+		// Arrange
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::free_balance(pool_id, &ALICE) / 10;
+
+		// Act
+		let (amount, fee) = Stableswap::simulate_remove_liquidity_one_asset(pool_id, ASSET_C, shares).unwrap();
+
+		// Assert
+		assert!(fee > 0);
+		assert_ok!(Stableswap::remove_liquidity_one_asset(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			ASSET_C,
+			shares,
+			0,
+		));
+		assert_balance!(ALICE, ASSET_C, amount);
+	});
+}
+
+#[test]
+fn simulate_remove_liquidity_should_return_amounts_received_on_removal() {
+	ext().execute_with(|| {
+		// Arrange
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::free_balance(pool_id, &ALICE) / 4;
+
+		// Act
+		let amounts = Stableswap::simulate_remove_liquidity(pool_id, shares).unwrap();
+
+		// Assert
+		assert_eq!(
+			amounts.iter().map(|a| a.asset_id).collect::<Vec<_>>(),
+			vec![ASSET_A, ASSET_B, ASSET_C]
+		);
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			BoundedVec::truncate_from(vec![
+				AssetAmount::new(ASSET_A, 0),
+				AssetAmount::new(ASSET_B, 0),
+				AssetAmount::new(ASSET_C, 0),
+			]),
+		));
+		for asset in amounts {
+			assert_balance!(ALICE, asset.asset_id, asset.amount);
+		}
+	});
+}
+
+#[test]
+fn virtual_price_should_increase_when_fees_are_collected() {
+	ext().execute_with(|| {
+		// Arrange
+		let pool_id = get_pool_id_at(0);
+		let initial_price = Stableswap::virtual_price(&Stableswap::create_snapshot(pool_id).unwrap()).unwrap();
+
+		// Act
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_B,
+			50 * ONE,
+			0,
+		));
+
+		// Assert
+		let price = Stableswap::virtual_price(&Stableswap::create_snapshot(pool_id).unwrap()).unwrap();
+		assert!(initial_price >= FixedU128::from(1));
+		assert!(initial_price < FixedU128::from_rational(1_000_001, 1_000_000));
+		assert!(price > initial_price);
+	});
+}
+
+#[test]
+fn target_pegs_should_be_one_when_pool_has_no_peg_sources() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_eq!(Stableswap::target_pegs(pool_id), Ok(vec![(1, 1), (1, 1), (1, 1)]));
+	});
+}
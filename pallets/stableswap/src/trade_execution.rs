@@ -1,8 +1,10 @@
 use crate::types::Tradability;
-use crate::{Balance, Config, Error, Pallet, Pools, ShareIssuance, D_ITERATIONS, Y_ITERATIONS};
+use crate::{AssetTradability, Balance, Config, Error, Pallet, Pools, ShareIssuance, D_ITERATIONS, Y_ITERATIONS};
 use frame_support::{ensure, BoundedVec};
+use frame_system::ensure_signed;
 use hydra_dx_math::stableswap::types::AssetReserve;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::circuit_breaker::AccountTradeVolumeHandler;
+use hydradx_traits::router::{ExecutorError, PoolType, Trade, TradeExecution, TradeablePoolsProvider};
 use hydradx_traits::stableswap::AssetAmount;
 use orml_traits::MultiCurrency;
 use sp_core::Get;
@@ -227,11 +229,12 @@ where
 		match pool_type {
 			PoolType::Stableswap(pool_id) => {
 				if asset_in == pool_id {
-					Self::remove_liquidity_one_asset(who, pool_id, asset_out, amount_in, min_limit)
-						.map_err(ExecutorError::Error)
+					Self::remove_liquidity_one_asset(who.clone(), pool_id, asset_out, amount_in, min_limit)
+						.map_err(ExecutorError::Error)?;
+					Self::note_share_trade_volume(who, asset_in, amount_in)
 				} else if asset_out == pool_id {
 					Self::add_assets_liquidity(
-						who,
+						who.clone(),
 						pool_id,
 						BoundedVec::truncate_from(vec![AssetAmount {
 							asset_id: asset_in,
@@ -239,7 +242,8 @@ where
 						}]),
 						min_limit,
 					)
-					.map_err(ExecutorError::Error)
+					.map_err(ExecutorError::Error)?;
+					Self::note_share_trade_volume(who, asset_in, amount_in)
 				} else {
 					Self::sell(who, pool_id, asset_in, asset_out, amount_in, min_limit).map_err(ExecutorError::Error)
 				}
@@ -259,11 +263,13 @@ where
 		match pool_type {
 			PoolType::Stableswap(pool_id) => {
 				if asset_out == pool_id {
-					Self::add_liquidity_shares(who, pool_id, amount_out, asset_in, max_limit)
-						.map_err(ExecutorError::Error)
+					Self::add_liquidity_shares(who.clone(), pool_id, amount_out, asset_in, max_limit)
+						.map_err(ExecutorError::Error)?;
+					Self::note_share_trade_volume(who, asset_out, amount_out)
 				} else if asset_in == pool_id {
-					Self::withdraw_asset_amount(who, pool_id, asset_out, amount_out, max_limit)
-						.map_err(ExecutorError::Error)
+					Self::withdraw_asset_amount(who.clone(), pool_id, asset_out, amount_out, max_limit)
+						.map_err(ExecutorError::Error)?;
+					Self::note_share_trade_volume(who, asset_out, amount_out)
 				} else {
 					Self::buy(who, pool_id, asset_out, asset_in, amount_out, max_limit).map_err(ExecutorError::Error)
 				}
@@ -330,3 +336,56 @@ where
 		}
 	}
 }
+
+impl<T: Config> Pallet<T> {
+	/// Trades of pool shares are executed as liquidity operations, which are not counted as trades by themselves.
+	fn note_share_trade_volume(
+		who: T::RuntimeOrigin,
+		asset_id: T::AssetId,
+		amount: Balance,
+	) -> Result<(), ExecutorError<DispatchError>> {
+		let who = ensure_signed(who).map_err(|e| ExecutorError::Error(e.into()))?;
+		T::AccountTradeVolumeHandler::note_trade_volume(&who, asset_id, amount).map_err(ExecutorError::Error)
+	}
+}
+
+impl<T: Config> TradeablePoolsProvider<T::AssetId> for Pallet<T> {
+	fn tradeable_pools() -> Vec<Trade<T::AssetId>> {
+		let mut trades = Vec::new();
+
+		for (pool_id, pool) in Pools::<T>::iter() {
+			let pool_type = PoolType::Stableswap(pool_id);
+			let trade = |asset_in, asset_out| Trade {
+				pool: pool_type,
+				asset_in,
+				asset_out,
+			};
+
+			for asset_in in pool.assets.iter() {
+				let tradability_in = AssetTradability::<T>::get(pool_id, asset_in);
+
+				// Trading the pool asset for shares is adding liquidity, and vice versa
+				if tradability_in.contains(Tradability::ADD_LIQUIDITY) {
+					trades.push(trade(*asset_in, pool_id));
+				}
+				if tradability_in.contains(Tradability::REMOVE_LIQUIDITY) {
+					trades.push(trade(pool_id, *asset_in));
+				}
+
+				if !tradability_in.contains(Tradability::SELL) {
+					continue;
+				}
+
+				for asset_out in pool.assets.iter() {
+					if asset_in != asset_out
+						&& AssetTradability::<T>::get(pool_id, asset_out).contains(Tradability::BUY)
+					{
+						trades.push(trade(*asset_in, *asset_out));
+					}
+				}
+			}
+		}
+
+		trades
+	}
+}
@@ -1203,6 +1203,41 @@ impl<T: Config> Pallet<T> {
 	pub fn get_position(position_id: T::PositionItemId) -> Option<Position<BlockNumberFor<T>>> {
 		Positions::<T>::get(position_id)
 	}
+
+	/// Returns amount of rewards which would be paid to the owner of `position_id` if the
+	/// position was claimed now. Rewards in the `pot` which were not distributed yet are included.
+	///
+	/// Action points for votes in finished referendums are not included until the votes are
+	/// processed by `claim` or `increase_stake`.
+	///
+	/// Returns `None` if position doesn't exist or staking is not initialized.
+	pub fn pending_rewards(position_id: T::PositionItemId) -> Option<Balance> {
+		if !Self::is_initialized() {
+			return None;
+		}
+
+		let position = Positions::<T>::get(position_id)?;
+		let staking = Staking::<T>::get();
+
+		let accumulated_reward_per_stake = if staking.total_stake.is_zero() {
+			staking.accumulated_reward_per_stake
+		} else {
+			let pending_rewards = T::Currency::free_balance(T::NativeAssetId::get(), &Self::pot_account_id())
+				.checked_sub(staking.pot_reserved_balance)?;
+
+			math::calculate_accumulated_rps(
+				staking.accumulated_reward_per_stake,
+				pending_rewards,
+				staking.total_stake,
+			)?
+		};
+
+		let current_period = Self::get_current_period()?;
+		let created_at = Self::get_period_number(position.created_at)?;
+
+		Self::calculate_rewards(&position, accumulated_reward_per_stake, current_period, created_at)
+			.map(|(claimable_rewards, _, _)| claimable_rewards)
+	}
 }
 
 pub struct SigmoidPercentage<T, B>(sp_std::marker::PhantomData<(T, B)>);
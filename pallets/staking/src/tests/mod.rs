@@ -9,6 +9,7 @@ mod claim;
 mod force_unstake;
 mod increase_stake;
 pub(crate) mod mock;
+mod pending_rewards;
 mod stake;
 #[allow(clippy::module_inception)]
 mod tests;
@@ -0,0 +1,76 @@
+use super::*;
+
+use mock::Staking;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn pending_rewards_should_be_equal_to_claimed_rewards() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+		])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			let bob_balance_before = Tokens::free_balance(HDX, &BOB);
+
+			//Act
+			let pending_rewards = Staking::pending_rewards(bob_position_id).unwrap();
+
+			//Assert
+			assert!(!pending_rewards.is_zero());
+			assert_ok!(Staking::claim(RuntimeOrigin::signed(BOB), bob_position_id));
+			assert_eq!(Tokens::free_balance(HDX, &BOB) - bob_balance_before, pending_rewards);
+		});
+}
+
+#[test]
+fn pending_rewards_should_be_zero_when_position_is_in_unclaimable_periods() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.start_at_block(1_452_987)
+		.with_initialized_staking()
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act & assert
+			assert_eq!(Staking::pending_rewards(bob_position_id), Some(0));
+		});
+}
+
+#[test]
+fn pending_rewards_should_return_none_when_position_does_not_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.build()
+		.execute_with(|| {
+			assert_eq!(Staking::pending_rewards(0), None);
+		});
+}
+
+#[test]
+fn pending_rewards_should_return_none_when_staking_is_not_initialized() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Staking::pending_rewards(0), None);
+	});
+}
@@ -59,6 +59,20 @@ impl<BlockNumber> Position<BlockNumber> {
 	pub fn get_action_points(&self) -> Point {
 		self.action_points
 	}
+
+	pub fn get_stake(&self) -> Balance {
+		self.stake
+	}
+
+	pub fn get_accumulated_locked_rewards(&self) -> Balance {
+		self.accumulated_locked_rewards
+	}
+}
+
+impl<BlockNumber: Copy> Position<BlockNumber> {
+	pub fn get_created_at(&self) -> BlockNumber {
+		self.created_at
+	}
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
@@ -0,0 +1,274 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # XCM Outbound Limiter Pallet
+//!
+//! ## Overview
+//!
+//! This pallet provides `OutboundRateLimiter`, a `SendXcm` wrapper that tracks tokens leaving the chain via XCM and
+//! rejects outgoing messages which would exceed the rate limit configured in `RateLimitFor`.
+//!
+//! ### Integration
+//!
+//! The `RateLimitFor` associated type is supposed to be provided by the `AssetRegistry`, but could work with any other
+//! implementation of `GetByKey<AssetId, Option<u128>>`.
+//!
+//! This pallet does not provide any extrinsics of its own. To limit outgoing tokens, the `XcmRouter` of the runtime has
+//! to be wrapped in `OutboundRateLimiter`, which covers reserve transfers and teleports sent by `pallet_xcm` as well as
+//! `orml_xtokens`.
+//!
+//! ### Implementation
+//!
+//! The accumulated amount of each asset decays linearly, by the rate limit over `LimitDuration` relay chain blocks.
+//! As outgoing messages cannot be deferred, a message is rejected with `SendError::Transport` once the accumulated
+//! amount of any of its assets would exceed the rate limit. The amounts are only recorded when the message is
+//! delivered, so validating a message (e.g. for fee estimation) does not count towards the limit.
+//!
+//! All `ReserveAssetDeposited`, `ReceiveTeleportedAsset`, `WithdrawAsset`, `TransferAsset` and `TransferReserveAsset`
+//! instructions of an outgoing message are counted, including the ones nested in `SetAppendix`, `SetErrorHandler` and
+//! `ExecuteWithOrigin`. Their asset locations are reanchored to the local point of view and mapped to the canonical
+//! location of the local asset id via `CurrencyIdConvert`, so the same asset is always tracked under the same key no
+//! matter how it is encoded.
+//!
+//! ### Limitations
+//!
+//! - Tokens that don't have a defined local id or don't have a configured rate limit are neither tracked nor limited.
+//! - Messages forwarded by the destination (e.g. the `xcm` of `DepositReserveAsset`) are not inspected, as the assets
+//!   they carry are already counted.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::ensure;
+use frame_support::traits::Get;
+use hydra_dx_math::rate_limiter::calculate_new_accumulated_amount;
+use orml_traits::GetByKey;
+use polkadot_parachain::primitives::RelayChainBlockNumber;
+use scale_info::TypeInfo;
+use sp_runtime::traits::{BlockNumberProvider, Convert};
+use sp_runtime::{RuntimeDebug, SaturatedConversion};
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::marker::PhantomData;
+use sp_std::vec::Vec;
+use xcm::v5::prelude::*;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// Error reported by the router when an outgoing message exceeds the rate limit of one of its assets.
+pub const RATE_LIMIT_EXCEEDED: &str = "XcmRateLimitExceeded";
+
+#[derive(Clone, Default, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
+pub struct AccumulatedAmount {
+	pub amount: u128,
+	pub last_updated: RelayChainBlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use xcm::v5::{InteriorLocation, Location};
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Identifier for the class of asset.
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// Number of relay chain blocks over which the accumulated amount of an asset decays by its rate limit.
+		#[pallet::constant]
+		type LimitDuration: Get<RelayChainBlockNumber>;
+
+		/// Relay chain block number provider
+		type RelayBlockNumberProvider: BlockNumberProvider<BlockNumber = RelayChainBlockNumber>;
+
+		/// Convert between `Location` and local `AssetId`
+		type CurrencyIdConvert: Convert<Location, Option<Self::AssetId>> + Convert<Self::AssetId, Option<Location>>;
+
+		/// Universal location of this chain, used to reanchor locations of outgoing assets
+		type UniversalLocation: Get<InteriorLocation>;
+
+		/// Xcm rate limit getter for each asset
+		type RateLimitFor: GetByKey<Self::AssetId, Option<u128>>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	/// Accumulated amounts of outgoing tokens for each asset, keyed by its canonical location
+	pub type AccumulatedAmounts<T: Config> = StorageMap<_, Blake2_128Concat, Location, AccumulatedAmount, ValueQuery>;
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {}
+}
+
+/// `SendXcm` wrapper which tracks tokens leaving the chain and rejects messages exceeding the rate limit
+/// configured in `RateLimitFor`.
+pub struct OutboundRateLimiter<T, Router>(PhantomData<(T, Router)>);
+
+impl<T: Config, Router: SendXcm> SendXcm for OutboundRateLimiter<T, Router> {
+	type Ticket = (Router::Ticket, Vec<(Location, AccumulatedAmount)>);
+
+	fn validate(destination: &mut Option<Location>, message: &mut Option<Xcm<()>>) -> SendResult<Self::Ticket> {
+		// The inner router takes the destination and message, so the outgoing assets have to be inspected first.
+		let accumulated_amounts = match (destination.as_ref(), message.as_ref()) {
+			(Some(dest), Some(msg)) => Pallet::<T>::new_accumulated_amounts(dest, msg)?,
+			_ => Vec::new(),
+		};
+
+		let (ticket, price) = Router::validate(destination, message)?;
+		Ok(((ticket, accumulated_amounts), price))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		let (inner_ticket, accumulated_amounts) = ticket;
+		let hash = Router::deliver(inner_ticket)?;
+		for (location, accumulated_amount) in accumulated_amounts {
+			AccumulatedAmounts::<T>::insert(location, accumulated_amount);
+		}
+		Ok(hash)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful_delivery(location: Option<Location>) {
+		Router::ensure_successful_delivery(location);
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Calculate the accumulated amounts of all rate limited assets transferred by `message` to `dest`.
+	///
+	/// Fails if any of the new accumulated amounts exceeds the rate limit of its asset.
+	pub fn new_accumulated_amounts(
+		dest: &Location,
+		message: &Xcm<()>,
+	) -> Result<Vec<(Location, AccumulatedAmount)>, SendError> {
+		// The same asset can be included more than once, possibly with different location encodings.
+		let mut outgoing: BTreeMap<Location, (T::AssetId, u128)> = BTreeMap::new();
+		for (location, amount) in message.inner().iter().flat_map(get_outgoing_locations_and_amounts) {
+			// We assume that it's fine to not track assets whose id cannot be determined.
+			let Some((asset_id, canonical_location)) = Self::canonicalize_outgoing_location(dest, location) else {
+				continue;
+			};
+			let entry = outgoing.entry(canonical_location).or_insert((asset_id, 0));
+			entry.1 = entry.1.saturating_add(amount);
+		}
+
+		let limit_duration = T::LimitDuration::get();
+		let current_time = T::RelayBlockNumberProvider::current_block_number();
+
+		let mut accumulated_amounts = Vec::with_capacity(outgoing.len());
+		for (location, (asset_id, amount)) in outgoing {
+			// Assets without a rate limit are not tracked either.
+			let Some(limit_per_duration) = T::RateLimitFor::get(&asset_id) else {
+				continue;
+			};
+			let accumulated_liquidity = AccumulatedAmounts::<T>::get(&location);
+			let time_difference = current_time.saturating_sub(accumulated_liquidity.last_updated);
+
+			let new_accumulated_amount = calculate_new_accumulated_amount(
+				limit_duration,
+				limit_per_duration,
+				amount,
+				accumulated_liquidity.amount,
+				time_difference.saturated_into(),
+			);
+
+			ensure!(
+				new_accumulated_amount <= limit_per_duration,
+				SendError::Transport(RATE_LIMIT_EXCEEDED)
+			);
+
+			accumulated_amounts.push((
+				location,
+				AccumulatedAmount {
+					amount: new_accumulated_amount,
+					last_updated: current_time,
+				},
+			));
+		}
+
+		Ok(accumulated_amounts)
+	}
+
+	/// Reanchor `location` from the point of view of `dest` to the local one and map it to the canonical location
+	/// of its asset, so that the same asset is always tracked under the same key.
+	fn canonicalize_outgoing_location(dest: &Location, location: Location) -> Option<(T::AssetId, Location)> {
+		let universal_location = T::UniversalLocation::get();
+		let dest_context = Location::from(universal_location.clone())
+			.appended_with(dest.clone())
+			.ok()?
+			.interior;
+		let here_from_dest = Location::here().reanchored(dest, &universal_location).ok()?;
+		let mut local_location = location.reanchored(&here_from_dest, &dest_context).ok()?;
+		// Global locations pointing back into this chain are only simplified against the local context.
+		local_location.simplify(&universal_location);
+
+		let asset_id =
+			<T::CurrencyIdConvert as Convert<Location, Option<T::AssetId>>>::convert(local_location.clone())?;
+		let canonical_location = <T::CurrencyIdConvert as Convert<T::AssetId, Option<Location>>>::convert(asset_id)
+			.unwrap_or(local_location);
+
+		Some((asset_id, canonical_location))
+	}
+}
+
+/// Collect the assets arriving at or moved on the destination of an outgoing message.
+///
+/// All top-level instructions are inspected rather than only the first one, because a single message can carry
+/// assets of different transfer types (e.g. fees as a reserve transfer and the rest as a teleport). Instructions
+/// executed later on the destination (`SetAppendix`, `SetErrorHandler` and `ExecuteWithOrigin`) are inspected too.
+fn get_outgoing_locations_and_amounts(instruction: &Instruction<()>) -> Vec<(Location, u128)> {
+	let mut locations_and_amounts = Vec::new();
+	collect_outgoing_locations_and_amounts(instruction, &mut locations_and_amounts);
+	locations_and_amounts
+}
+
+fn collect_outgoing_locations_and_amounts(instruction: &Instruction<()>, outgoing: &mut Vec<(Location, u128)>) {
+	match instruction {
+		// `WithdrawAsset` is sent to the reserve when transferring non-local assets via a remote reserve.
+		// `TransferAsset` and `TransferReserveAsset` move assets held by this chain's sovereign account.
+		ReserveAssetDeposited(assets)
+		| ReceiveTeleportedAsset(assets)
+		| WithdrawAsset(assets)
+		| TransferAsset { assets, .. }
+		| TransferReserveAsset { assets, .. } => outgoing.extend(assets.inner().iter().filter_map(|asset| match asset {
+			Asset {
+				id: AssetId(location),
+				fun: Fungible(amount),
+			} => Some((location.clone(), *amount)),
+			_ => None,
+		})),
+		SetAppendix(xcm) | SetErrorHandler(xcm) | ExecuteWithOrigin { xcm, .. } => xcm
+			.inner()
+			.iter()
+			.for_each(|nested_instruction| collect_outgoing_locations_and_amounts(nested_instruction, outgoing)),
+		_ => {}
+	}
+}
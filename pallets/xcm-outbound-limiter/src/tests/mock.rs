@@ -0,0 +1,205 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub use crate as pallet_xcm_outbound_limiter;
+
+pub use frame_support::traits::Everything;
+pub use frame_support::{assert_ok, parameter_types};
+use orml_traits::parameter_type_with_key;
+use polkadot_parachain::primitives::RelayChainBlockNumber;
+use sp_core::H256;
+use sp_runtime::traits::BlockNumberProvider;
+use sp_runtime::traits::ConstU32;
+use sp_runtime::traits::Convert;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use std::cell::RefCell;
+
+use xcm::v5::prelude::*;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AssetId = u32;
+pub type Balance = u128;
+
+pub const HDX: AssetId = 100;
+pub const DOT: AssetId = 200;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const UNLIMITED_ASSET: AssetId = 1000;
+
+pub const HYDRA_PARA_ID: u32 = 2034;
+
+thread_local! {
+	pub static SENT_XCM: RefCell<Vec<(Location, Xcm<()>)>> = RefCell::new(Vec::default());
+}
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		XcmOutboundLimiter: pallet_xcm_outbound_limiter,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+pub struct ConvertIdMock;
+impl Convert<Location, Option<AssetId>> for ConvertIdMock {
+	fn convert(location: Location) -> Option<AssetId> {
+		use sp_runtime::SaturatedConversion;
+		match location {
+			loc if loc == Location::here() => Some(HDX),
+			Location {
+				parents: _,
+				interior: Junctions::X1(ref junctions),
+			} => {
+				if let Some(GeneralIndex(i)) = junctions.first() {
+					Some((*i).saturated_into())
+				} else {
+					None
+				}
+			}
+			_ => None,
+		}
+	}
+}
+
+impl Convert<AssetId, Option<Location>> for ConvertIdMock {
+	fn convert(asset_id: AssetId) -> Option<Location> {
+		match asset_id {
+			HDX => Some(Location::here()),
+			id => Some(Location::new(1, [GeneralIndex(id.into())])),
+		}
+	}
+}
+
+parameter_types! {
+	pub UniversalLocation: InteriorLocation = [
+		GlobalConsensus(Polkadot),
+		Parachain(HYDRA_PARA_ID),
+	].into();
+}
+
+parameter_type_with_key! {
+	pub XcmRateLimitFor: |asset_id: AssetId| -> Option<Balance> {
+		match *asset_id {
+			HDX => Some(1000 * ONE),
+			DOT => Some(1000 * ONE),
+			_ => None,
+		}
+	};
+}
+
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+	type Ticket = (Location, Xcm<()>);
+
+	fn validate(destination: &mut Option<Location>, message: &mut Option<Xcm<()>>) -> SendResult<Self::Ticket> {
+		let destination = destination.take().ok_or(SendError::MissingArgument)?;
+		let message = message.take().ok_or(SendError::MissingArgument)?;
+		Ok(((destination, message), Assets::new()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		SENT_XCM.with(|v| v.borrow_mut().push(ticket));
+		Ok([0; 32])
+	}
+}
+
+pub type RateLimitedRouter = pallet_xcm_outbound_limiter::OutboundRateLimiter<Test, TestSendXcm>;
+
+pub fn sent_xcm() -> Vec<(Location, Xcm<()>)> {
+	SENT_XCM.with(|v| v.borrow().clone())
+}
+
+pub struct TreatSystemAsRelayBlockNumberProvider;
+impl BlockNumberProvider for TreatSystemAsRelayBlockNumberProvider {
+	type BlockNumber = RelayChainBlockNumber;
+
+	fn current_block_number() -> Self::BlockNumber {
+		use sp_runtime::SaturatedConversion;
+		System::current_block_number().saturated_into()
+	}
+}
+
+impl pallet_xcm_outbound_limiter::Config for Test {
+	type AssetId = AssetId;
+	type LimitDuration = ConstU32<10>;
+	// dirty trick: we use System as a relay block number provider here
+	type RelayBlockNumberProvider = TreatSystemAsRelayBlockNumberProvider;
+	type CurrencyIdConvert = ConvertIdMock;
+	type UniversalLocation = UniversalLocation;
+	type RateLimitFor = XcmRateLimitFor;
+}
+
+#[derive(Default)]
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		SENT_XCM.with(|v| {
+			v.borrow_mut().clear();
+		});
+
+		let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+		r.execute_with(|| {
+			System::set_block_number(1);
+		});
+		r
+	}
+}
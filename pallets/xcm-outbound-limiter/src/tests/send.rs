@@ -0,0 +1,309 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+use xcm::v5::{send_xcm, validate_send};
+
+pub use pretty_assertions::assert_eq;
+
+fn sibling() -> Location {
+	Location::new(1, [Parachain(1000)])
+}
+
+// HDX as seen from the sibling chain
+fn hdx_from_sibling() -> Location {
+	Location::new(1, [Parachain(HYDRA_PARA_ID)])
+}
+
+fn outgoing_assets(locs_and_amounts: Vec<(Location, u128)>) -> Assets {
+	locs_and_amounts
+		.into_iter()
+		.map(|(loc, amount)| (loc, amount).into())
+		.collect::<Vec<Asset>>()
+		.into()
+}
+
+fn reserve_transfer(loc: Location, amount: u128) -> Xcm<()> {
+	Xcm(vec![
+		ReserveAssetDeposited(outgoing_assets(vec![(loc, amount)])),
+		ClearOrigin,
+	])
+}
+
+#[test]
+fn send_should_track_outgoing_reserve_transfer() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = reserve_transfer(hdx_from_sibling(), 600 * ONE);
+
+		//Act
+		assert_ok!(send_xcm::<RateLimitedRouter>(sibling(), message));
+
+		//Assert
+		let accumulated_amount = AccumulatedAmounts::<Test>::get(Location::here());
+		assert_eq!(accumulated_amount.amount, 600 * ONE);
+		assert_eq!(accumulated_amount.last_updated, 1);
+		assert_eq!(sent_xcm().len(), 1);
+	});
+}
+
+#[test]
+fn send_should_fail_when_outbound_limit_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = reserve_transfer(hdx_from_sibling(), 1001 * ONE);
+
+		//Act
+		let result = send_xcm::<RateLimitedRouter>(sibling(), message);
+
+		//Assert
+		assert_eq!(result, Err(SendError::Transport(RATE_LIMIT_EXCEEDED)));
+		assert_eq!(
+			AccumulatedAmounts::<Test>::get(Location::here()),
+			AccumulatedAmount::default()
+		);
+		assert!(sent_xcm().is_empty());
+	});
+}
+
+#[test]
+fn send_should_fail_when_successive_transfers_exceed_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(send_xcm::<RateLimitedRouter>(
+			sibling(),
+			reserve_transfer(hdx_from_sibling(), 600 * ONE)
+		));
+
+		//Act
+		let result = send_xcm::<RateLimitedRouter>(sibling(), reserve_transfer(hdx_from_sibling(), 600 * ONE));
+
+		//Assert
+		assert_eq!(result, Err(SendError::Transport(RATE_LIMIT_EXCEEDED)));
+		assert_eq!(AccumulatedAmounts::<Test>::get(Location::here()).amount, 600 * ONE);
+		assert_eq!(sent_xcm().len(), 1);
+	});
+}
+
+#[test]
+fn send_should_succeed_when_accumulated_amount_decayed() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(send_xcm::<RateLimitedRouter>(
+			sibling(),
+			reserve_transfer(hdx_from_sibling(), 1000 * ONE)
+		));
+		System::set_block_number(6);
+
+		//Act
+		assert_ok!(send_xcm::<RateLimitedRouter>(
+			sibling(),
+			reserve_transfer(hdx_from_sibling(), 500 * ONE)
+		));
+
+		//Assert
+		let accumulated_amount = AccumulatedAmounts::<Test>::get(Location::here());
+		assert_eq!(accumulated_amount.amount, 1000 * ONE);
+		assert_eq!(accumulated_amount.last_updated, 6);
+	});
+}
+
+#[test]
+fn validate_should_not_record_accumulated_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = reserve_transfer(hdx_from_sibling(), 600 * ONE);
+
+		//Act
+		assert_ok!(validate_send::<RateLimitedRouter>(sibling(), message));
+
+		//Assert
+		assert_eq!(
+			AccumulatedAmounts::<Test>::get(Location::here()),
+			AccumulatedAmount::default()
+		);
+	});
+}
+
+#[test]
+fn send_should_track_outgoing_teleports_and_remote_reserve_withdrawals() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let dot_location = Location::new(1, [GeneralIndex(DOT.into())]);
+		let teleport = Xcm(vec![ReceiveTeleportedAsset(outgoing_assets(vec![(
+			hdx_from_sibling(),
+			300 * ONE,
+		)]))]);
+		let remote_reserve_withdrawal = Xcm(vec![WithdrawAsset(outgoing_assets(vec![(
+			dot_location.clone(),
+			200 * ONE,
+		)]))]);
+
+		//Act
+		assert_ok!(send_xcm::<RateLimitedRouter>(sibling(), teleport));
+		assert_ok!(send_xcm::<RateLimitedRouter>(sibling(), remote_reserve_withdrawal));
+
+		//Assert
+		assert_eq!(AccumulatedAmounts::<Test>::get(Location::here()).amount, 300 * ONE);
+		assert_eq!(AccumulatedAmounts::<Test>::get(dot_location).amount, 200 * ONE);
+	});
+}
+
+#[test]
+fn send_should_sum_assets_of_all_transfer_instructions() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = Xcm(vec![
+			ReserveAssetDeposited(outgoing_assets(vec![(hdx_from_sibling(), 100 * ONE)])),
+			ReceiveTeleportedAsset(outgoing_assets(vec![(hdx_from_sibling(), 950 * ONE)])),
+			ClearOrigin,
+		]);
+
+		//Act
+		let result = send_xcm::<RateLimitedRouter>(sibling(), message);
+
+		//Assert
+		assert_eq!(result, Err(SendError::Transport(RATE_LIMIT_EXCEEDED)));
+	});
+}
+
+#[test]
+fn send_should_track_same_asset_under_canonical_location_when_encoded_differently() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let global_hdx = Location::new(2, [GlobalConsensus(Polkadot), Parachain(HYDRA_PARA_ID)]);
+		let general_index_hdx = Location::new(1, [Parachain(HYDRA_PARA_ID), GeneralIndex(HDX.into())]);
+		assert_ok!(send_xcm::<RateLimitedRouter>(
+			sibling(),
+			reserve_transfer(hdx_from_sibling(), 400 * ONE)
+		));
+		assert_ok!(send_xcm::<RateLimitedRouter>(
+			sibling(),
+			reserve_transfer(global_hdx, 400 * ONE)
+		));
+
+		//Act
+		let result = send_xcm::<RateLimitedRouter>(sibling(), reserve_transfer(general_index_hdx, 400 * ONE));
+
+		//Assert
+		assert_eq!(result, Err(SendError::Transport(RATE_LIMIT_EXCEEDED)));
+		assert_eq!(AccumulatedAmounts::<Test>::get(Location::here()).amount, 800 * ONE);
+	});
+}
+
+#[test]
+fn send_should_reanchor_asset_locations_for_relay_destination() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let hdx_from_relay = Location::new(0, [Parachain(HYDRA_PARA_ID)]);
+
+		//Act
+		assert_ok!(send_xcm::<RateLimitedRouter>(
+			Location::parent(),
+			reserve_transfer(hdx_from_relay, 600 * ONE)
+		));
+
+		//Assert
+		assert_eq!(AccumulatedAmounts::<Test>::get(Location::here()).amount, 600 * ONE);
+	});
+}
+
+#[test]
+fn send_should_not_track_or_limit_assets_without_rate_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let unlimited_asset = Location::new(1, [GeneralIndex(UNLIMITED_ASSET.into())]);
+
+		//Act
+		assert_ok!(send_xcm::<RateLimitedRouter>(
+			sibling(),
+			reserve_transfer(unlimited_asset.clone(), 20_000 * ONE)
+		));
+
+		//Assert
+		assert_eq!(
+			AccumulatedAmounts::<Test>::get(unlimited_asset),
+			AccumulatedAmount::default()
+		);
+		assert_eq!(sent_xcm().len(), 1);
+	});
+}
+
+#[test]
+fn send_should_track_assets_transferred_from_sovereign_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = Xcm(vec![TransferAsset {
+			assets: outgoing_assets(vec![(hdx_from_sibling(), 700 * ONE)]),
+			beneficiary: Location::new(
+				0,
+				[AccountId32 {
+					network: None,
+					id: [1; 32],
+				}],
+			),
+		}]);
+
+		//Act
+		assert_ok!(send_xcm::<RateLimitedRouter>(sibling(), message));
+
+		//Assert
+		assert_eq!(AccumulatedAmounts::<Test>::get(Location::here()).amount, 700 * ONE);
+	});
+}
+
+#[test]
+fn send_should_track_assets_of_nested_instructions() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = Xcm(vec![
+			SetErrorHandler(Xcm(vec![ReceiveTeleportedAsset(outgoing_assets(vec![(
+				hdx_from_sibling(),
+				300 * ONE,
+			)]))])),
+			SetAppendix(Xcm(vec![SetErrorHandler(reserve_transfer(
+				hdx_from_sibling(),
+				200 * ONE,
+			))])),
+		]);
+
+		//Act
+		assert_ok!(send_xcm::<RateLimitedRouter>(sibling(), message));
+
+		//Assert
+		assert_eq!(AccumulatedAmounts::<Test>::get(Location::here()).amount, 500 * ONE);
+	});
+}
+
+#[test]
+fn send_should_fail_when_nested_instructions_exceed_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let message = Xcm(vec![
+			ReserveAssetDeposited(outgoing_assets(vec![(hdx_from_sibling(), 600 * ONE)])),
+			SetAppendix(reserve_transfer(hdx_from_sibling(), 600 * ONE)),
+		]);
+
+		//Act
+		let result = send_xcm::<RateLimitedRouter>(sibling(), message);
+
+		//Assert
+		assert_eq!(result, Err(SendError::Transport(RATE_LIMIT_EXCEEDED)));
+		assert!(sent_xcm().is_empty());
+	});
+}
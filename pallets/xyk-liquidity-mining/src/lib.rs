@@ -53,7 +53,7 @@ use hydradx_traits::liquidity_mining::{
 	GlobalFarmId, Inspect as LiquidityMiningInspect, Mutate as LiquidityMiningMutate, YieldFarmId,
 };
 use hydradx_traits::oracle::{AggregatedOracle, OraclePeriod};
-use pallet_liquidity_mining::{FarmMultiplier, LoyaltyCurve};
+use pallet_liquidity_mining::{FarmMultiplier, LoyaltyCurve, RewardSchedule};
 use pallet_xyk::types::{AssetId, AssetPair, Balance};
 
 use frame_support::{pallet_prelude::*, sp_runtime::traits::AccountIdConversion};
@@ -66,6 +66,7 @@ use sp_arithmetic::{FixedU128, Perquintill};
 use sp_std::{
 	convert::{From, Into, TryInto},
 	vec,
+	vec::Vec,
 };
 
 type PeriodOf<T> = BlockNumberFor<T>;
@@ -141,6 +142,7 @@ pub mod pallet {
 				Balance = Balance,
 				LoyaltyCurve = LoyaltyCurve,
 				Period = PeriodOf<Self>,
+				RewardSchedule = RewardSchedule,
 			> + LiquidityMiningInspect<Self::AccountId>;
 
 		/// Oracle source identifier for this pallet.
@@ -252,6 +254,17 @@ pub mod pallet {
 			undistributed_rewards: Balance,
 		},
 
+		/// New reward stream was added to the global farm.
+		RewardStreamAdded {
+			global_farm_id: GlobalFarmId,
+			who: T::AccountId,
+			reward_currency: AssetId,
+			total_rewards: Balance,
+			start: BlockNumberFor<T>,
+			end: BlockNumberFor<T>,
+			schedule: RewardSchedule,
+		},
+
 		/// New LP tokens was deposited.
 		SharesDeposited {
 			global_farm_id: GlobalFarmId,
@@ -887,14 +900,15 @@ pub mod pallet {
 		/// Claim rewards from liq. mining for deposit represented by `nft_id`.
 		///
 		/// This function calculate user rewards from liq. mining and transfer rewards to `origin`
-		/// account. Claiming in the same period is allowed only once.
+		/// account. Claiming in the same period is allowed only once. Rewards from global farm's
+		/// reward streams are claimed too.
 		///
 		/// Parameters:
 		/// - `origin`: account owner of deposit(nft).
 		/// - `deposit_id`: nft id representing deposit in the yield farm.
 		/// - `yield_farm_id`: yield farm identifier to claim rewards from.
 		///
-		/// Emits `RewardClaimed` event when successful.
+		/// Emits `RewardClaimed` event for each reward currency with claimed rewards > 0 when successful.
 		#[pallet::call_index(10)]
 		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards()
 			.saturating_add(<T as Config>::WeightInfo::price_adjustment_get()))]
@@ -908,16 +922,32 @@ pub mod pallet {
 			let (global_farm_id, reward_currency, claimed, _) =
 				T::LiquidityMiningHandler::claim_rewards(owner.clone(), deposit_id, yield_farm_id)?;
 
-			ensure!(!claimed.is_zero(), Error::<T>::ZeroClaimedRewards);
+			let claimed_streams =
+				T::LiquidityMiningHandler::claim_reward_streams(owner.clone(), deposit_id, yield_farm_id)?;
 
-			Self::deposit_event(Event::RewardClaimed {
+			ensure!(
+				!claimed.is_zero() || !claimed_streams.is_empty(),
+				Error::<T>::ZeroClaimedRewards
+			);
+
+			if !claimed.is_zero() {
+				Self::deposit_event(Event::RewardClaimed {
+					global_farm_id,
+					yield_farm_id,
+					who: owner.clone(),
+					claimed,
+					reward_currency,
+					deposit_id,
+				});
+			}
+
+			Self::deposit_reward_stream_claimed_events(
 				global_farm_id,
 				yield_farm_id,
-				who: owner,
-				claimed,
-				reward_currency,
+				&owner,
 				deposit_id,
-			});
+				claimed_streams,
+			);
 
 			Ok(())
 		}
@@ -933,6 +963,8 @@ pub mod pallet {
 		/// * yield farm was terminated - only LP shares will be transferred.
 		/// * farm was terminated - only LP shares will be transferred.
 		///
+		/// Rewards from global farm's reward streams are claimed only if yield farm is not terminated.
+		///
 		/// User's unclaimable rewards will be transferred back to global farm's account.
 		///
 		/// Parameters:
@@ -959,6 +991,20 @@ pub mod pallet {
 			let global_farm_id = T::LiquidityMiningHandler::get_global_farm_id(deposit_id, yield_farm_id)
 				.ok_or(Error::<T>::DepositDataNotFound)?;
 
+			//NOTE: rewards from reward streams are not claimed by `withdraw_lp_shares()`
+			if T::LiquidityMiningHandler::is_yield_farm_claimable(global_farm_id, yield_farm_id, amm_pool_id.clone()) {
+				let claimed_streams =
+					T::LiquidityMiningHandler::claim_reward_streams(owner.clone(), deposit_id, yield_farm_id)?;
+
+				Self::deposit_reward_stream_claimed_events(
+					global_farm_id,
+					yield_farm_id,
+					&owner,
+					deposit_id,
+					claimed_streams,
+				);
+			}
+
 			let (withdrawn_amount, claim_data, is_destroyed) = T::LiquidityMiningHandler::withdraw_lp_shares(
 				owner.clone(),
 				deposit_id,
@@ -1033,6 +1079,65 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Add additional reward currency(reward stream) to the existing global farm.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Reward stream distributes rewards between all yield farms in the global farm based on
+		/// the same stakes as global farm's `reward_currency`. Max. 3 reward streams can be added
+		/// to the global farm and each reward stream must have different `reward_currency`.
+		///
+		/// `origin` account has to have at least `total_rewards` balance. These funds will be
+		/// transferred from `origin` to farm account.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: id of the global farm to add reward stream to.
+		/// - `reward_currency`: payoff currency of the reward stream's rewards. This must be
+		/// different from global farm's `reward_currency`.
+		/// - `total_rewards`: total rewards to distribute by reward stream.
+		/// - `start`: block number from which reward stream starts distributing rewards. This can't
+		/// be in the past.
+		/// - `end`: block number when reward stream stops distributing rewards.
+		/// - `schedule`: distribution mode of the reward stream.
+		///
+		/// Emits `RewardStreamAdded` event when successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_reward_stream())]
+		pub fn add_reward_stream(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			reward_currency: AssetId,
+			total_rewards: Balance,
+			start: BlockNumberFor<T>,
+			end: BlockNumberFor<T>,
+			schedule: RewardSchedule,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			T::LiquidityMiningHandler::add_reward_stream(
+				who.clone(),
+				global_farm_id,
+				reward_currency,
+				total_rewards,
+				start,
+				end,
+				schedule,
+			)?;
+
+			Self::deposit_event(Event::RewardStreamAdded {
+				global_farm_id,
+				who,
+				reward_currency,
+				total_rewards,
+				start,
+				end,
+				schedule,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -1112,6 +1217,26 @@ impl<T: Config> Pallet<T> {
 		Ok(T::AMM::get_pair_id(asset_pair))
 	}
 
+	/// This function emits `RewardClaimed` event for each claimed reward stream.
+	fn deposit_reward_stream_claimed_events(
+		global_farm_id: GlobalFarmId,
+		yield_farm_id: YieldFarmId,
+		who: &T::AccountId,
+		deposit_id: DepositId,
+		claimed_streams: Vec<(AssetId, Balance)>,
+	) {
+		for (reward_currency, claimed) in claimed_streams {
+			Self::deposit_event(Event::RewardClaimed {
+				global_farm_id,
+				yield_farm_id,
+				who: who.clone(),
+				claimed,
+				reward_currency,
+				deposit_id,
+			});
+		}
+	}
+
 	fn ensure_nft_owner(origin: OriginFor<T>, deposit_id: DepositId) -> Result<T::AccountId, DispatchError> {
 		let who = ensure_signed(origin)?;
 
@@ -0,0 +1,90 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn add_reward_stream_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE), (BOB, KSM, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_ok!(LiquidityMining::add_reward_stream(
+				Origin::signed(BOB),
+				1,
+				KSM,
+				100_000 * ONE,
+				100,
+				1_000,
+				RewardSchedule::Streaming,
+			));
+
+			assert_last_event!(crate::Event::RewardStreamAdded {
+				global_farm_id: 1,
+				who: BOB,
+				reward_currency: KSM,
+				total_rewards: 100_000 * ONE,
+				start: 100,
+				end: 1_000,
+				schedule: RewardSchedule::Streaming,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn add_reward_stream_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE), (BOB, KSM, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::add_reward_stream(
+					Origin::none(),
+					BOB_FARM,
+					KSM,
+					100_000 * ONE,
+					100,
+					1_000,
+					RewardSchedule::Streaming,
+				),
+				BadOrigin
+			);
+		});
+}
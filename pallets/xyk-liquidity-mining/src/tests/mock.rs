@@ -502,6 +502,7 @@ impl hydradx_traits::liquidity_mining::Mutate<AccountId, AssetId, BlockNumber> f
 	type Balance = Balance;
 	type Period = PeriodOf<Test>;
 	type LoyaltyCurve = LoyaltyCurve;
+	type RewardSchedule = RewardSchedule;
 
 	fn create_global_farm(
 		total_rewards: Self::Balance,
@@ -565,6 +566,18 @@ impl hydradx_traits::liquidity_mining::Mutate<AccountId, AssetId, BlockNumber> f
 		})
 	}
 
+	fn add_reward_stream(
+		_who: AccountId,
+		_global_farm_id: u32,
+		_reward_currency: AssetId,
+		_total_rewards: Self::Balance,
+		_start: BlockNumber,
+		_end: BlockNumber,
+		_schedule: Self::RewardSchedule,
+	) -> Result<(), Self::Error> {
+		Ok(())
+	}
+
 	fn create_yield_farm(
 		_who: AccountId,
 		global_farm_id: u32,
@@ -764,6 +777,14 @@ impl hydradx_traits::liquidity_mining::Mutate<AccountId, AssetId, BlockNumber> f
 		Self::claim_rewards(who, deposit_id, yield_farm_id, fail_on_double_claim)
 	}
 
+	fn claim_reward_streams(
+		_who: AccountId,
+		_deposit_id: u128,
+		_yield_farm_id: u32,
+	) -> Result<Vec<(AssetId, Self::Balance)>, Self::Error> {
+		Ok(vec![])
+	}
+
 	fn withdraw_lp_shares(
 		who: AccountId,
 		deposit_id: u128,
@@ -26,7 +26,7 @@ use sp_runtime::traits::BadOrigin;
 const ALICE_FARM: u32 = BSX_FARM;
 const BOB_FARM: u32 = KSM_FARM;
 
-use pallet_liquidity_mining::LoyaltyCurve;
+use pallet_liquidity_mining::{LoyaltyCurve, RewardSchedule};
 
 pub type Origin = RuntimeOrigin;
 
@@ -45,6 +45,7 @@ pub fn expect_events(e: Vec<RuntimeEvent>) {
 }
 
 pub mod add_liquidity_and_join_farms;
+pub mod add_reward_stream;
 pub mod claim_rewards;
 pub mod create_global_farm;
 pub mod create_yield_farm;
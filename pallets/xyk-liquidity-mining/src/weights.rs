@@ -14,6 +14,7 @@ pub trait WeightInfo {
 	fn create_global_farm() -> Weight;
 	fn update_global_farm() -> Weight;
 	fn terminate_global_farm() -> Weight;
+	fn add_reward_stream() -> Weight;
 	fn create_yield_farm() -> Weight;
 	fn update_yield_farm() -> Weight;
 	fn stop_yield_farm() -> Weight;
@@ -122,6 +123,25 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(13_u64))
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:1 w:1)
+	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:4 w:1)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn add_reward_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2901`
+		//  Estimated: `11410`
+		// Minimum execution time: 118_940_000 picoseconds.
+		Weight::from_parts(119_852_000, 11410)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 	/// Storage: `XYK::ShareToken` (r:1 w:0)
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:1 w:1)
@@ -232,7 +252,7 @@ impl WeightInfo for () {
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:3 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:6 w:4)
+	/// Storage: `Tokens::Accounts` (r:12 w:10)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::YieldFarm` (r:1 w:1)
 	/// Proof: `XYKWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
@@ -264,14 +284,20 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `XYKWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:0 w:3)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn deposit_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6357`
-		//  Estimated: `16488`
-		// Minimum execution time: 283_636_000 picoseconds.
-		Weight::from_parts(286_604_000, 16488)
-			.saturating_add(RocksDbWeight::get().reads(26_u64))
-			.saturating_add(RocksDbWeight::get().writes(11_u64))
+		//  Estimated: `55203`
+		// Minimum execution time: 394_636_000 picoseconds.
+		Weight::from_parts(397_604_000, 55203)
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().writes(26_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:1 w:0)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -285,7 +311,7 @@ impl WeightInfo for () {
 	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:4 w:2)
+	/// Storage: `Tokens::Accounts` (r:10 w:8)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:1 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -301,14 +327,20 @@ impl WeightInfo for () {
 	/// Proof: `XYK::PoolAssets` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `XYK::TotalLiquidity` (r:1 w:0)
 	/// Proof: `XYK::TotalLiquidity` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:0 w:3)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn redeposit_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7286`
-		//  Estimated: `11322`
-		// Minimum execution time: 223_433_000 picoseconds.
-		Weight::from_parts(224_674_000, 11322)
-			.saturating_add(RocksDbWeight::get().reads(18_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		//  Estimated: `50037`
+		// Minimum execution time: 334_433_000 picoseconds.
+		Weight::from_parts(335_674_000, 50037)
+			.saturating_add(RocksDbWeight::get().reads(30_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:1 w:0)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -318,9 +350,9 @@ impl WeightInfo for () {
 	/// Proof: `XYKWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:1 w:1)
 	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:4 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:3 w:3)
+	/// Storage: `Tokens::Accounts` (r:12 w:12)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:1 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -332,14 +364,20 @@ impl WeightInfo for () {
 	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:0)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7110`
-		//  Estimated: `8739`
-		// Minimum execution time: 224_682_000 picoseconds.
-		Weight::from_parts(226_222_000, 8739)
-			.saturating_add(RocksDbWeight::get().reads(15_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		//  Estimated: `63003`
+		// Minimum execution time: 413_682_000 picoseconds.
+		Weight::from_parts(415_222_000, 63003)
+			.saturating_add(RocksDbWeight::get().reads(36_u64))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:1 w:1)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -351,9 +389,9 @@ impl WeightInfo for () {
 	/// Proof: `XYKWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:1 w:1)
 	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:5 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:5 w:5)
+	/// Storage: `Tokens::Accounts` (r:14 w:14)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:1 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -381,14 +419,20 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:1)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:3 w:6)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn withdraw_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6783`
-		//  Estimated: `13905`
-		// Minimum execution time: 441_189_000 picoseconds.
-		Weight::from_parts(444_785_000, 13905)
-			.saturating_add(RocksDbWeight::get().reads(29_u64))
-			.saturating_add(RocksDbWeight::get().writes(16_u64))
+		//  Estimated: `68169`
+		// Minimum execution time: 645_189_000 picoseconds.
+		Weight::from_parts(648_785_000, 68169)
+			.saturating_add(RocksDbWeight::get().reads(50_u64))
+			.saturating_add(RocksDbWeight::get().writes(37_u64))
 	}
 	/// Storage: `XYK::ShareToken` (r:1 w:0)
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
@@ -425,7 +469,7 @@ impl WeightInfo for () {
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:3 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:10 w:8)
+	/// Storage: `Tokens::Accounts` (r:40 w:38)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::YieldFarm` (r:5 w:5)
 	/// Proof: `XYKWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
@@ -457,26 +501,32 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `XYKWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:0 w:15)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn join_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6878 + c * (672 ±0)`
-		//  Estimated: `13905 + c * (2701 ±0)`
-		// Minimum execution time: 293_151_000 picoseconds.
+		//  Estimated: `13905 + c * (41416 ±0)`
+		// Minimum execution time: 404_151_000 picoseconds.
 		Weight::from_parts(202_764_318, 13905)
 			// Standard Error: 71_458
-			.saturating_add(Weight::from_parts(94_309_018, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(205_309_018, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(21_u64))
-			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((17_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2701).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().writes((18_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 41416).saturating_mul(c.into()))
 	}
 	/// Storage: `XYK::ShareToken` (r:1 w:0)
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:3 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:12 w:12)
+	/// Storage: `Tokens::Accounts` (r:42 w:42)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `XYK::TotalLiquidity` (r:1 w:1)
 	/// Proof: `XYK::TotalLiquidity` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
@@ -512,20 +562,26 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `XYKWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:0 w:15)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn add_liquidity_and_join_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7086 + c * (672 ±0)`
-		//  Estimated: `19071 + c * (2701 ±0)`
-		// Minimum execution time: 430_597_000 picoseconds.
+		//  Estimated: `19071 + c * (41416 ±0)`
+		// Minimum execution time: 541_597_000 picoseconds.
 		Weight::from_parts(340_717_139, 19071)
 			// Standard Error: 102_863
-			.saturating_add(Weight::from_parts(94_542_857, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(205_542_857, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(26_u64))
-			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((17_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes(14_u64))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2701).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().writes((18_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 41416).saturating_mul(c.into()))
 	}
 	/// Storage: `Uniques::Asset` (r:1 w:1)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -537,9 +593,9 @@ impl WeightInfo for () {
 	/// Proof: `XYKWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:5 w:5)
 	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:17 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:9 w:9)
+	/// Storage: `Tokens::Accounts` (r:54 w:54)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:1 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -567,20 +623,26 @@ impl WeightInfo for () {
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:1)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:15 w:30)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn exit_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6479 + c * (692 ±0)`
-		//  Estimated: `5788 + c * (2792 ±0)`
-		// Minimum execution time: 296_635_000 picoseconds.
+		//  Estimated: `5788 + c * (57056 ±0)`
+		// Minimum execution time: 500_635_000 picoseconds.
 		Weight::from_parts(113_520_180, 5788)
 			// Standard Error: 894_486
-			.saturating_add(Weight::from_parts(180_465_455, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(384_465_455, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((26_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2792).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().writes((24_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 57056).saturating_mul(c.into()))
 	}
 	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
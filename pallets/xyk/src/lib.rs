@@ -49,6 +49,7 @@ use frame_support::require_transactional;
 use hydra_dx_math::ratio::Ratio;
 use hydradx_traits::AMMAddLiquidity;
 use hydradx_traits::{
+	circuit_breaker::AccountTradeVolumeHandler,
 	pools::DustRemovalAccountWhitelist,
 	registry::{AssetKind, Create},
 	Source,
@@ -59,6 +60,7 @@ use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 mod tests;
 
 mod impls;
+pub mod migrations;
 mod trade_execution;
 pub mod types;
 pub mod weights;
@@ -77,6 +79,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::OriginFor;
 
 	#[pallet::pallet]
+	#[pallet::storage_version(migrations::STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -134,6 +137,9 @@ pub mod pallet {
 
 		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
 		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// Counts trades towards the per-account trade volume limit of the trader.
+		type AccountTradeVolumeHandler: AccountTradeVolumeHandler<Self::AccountId, AssetId, Balance>;
 	}
 
 	#[pallet::error]
@@ -279,6 +285,10 @@ pub mod pallet {
 	#[pallet::getter(fn share_token)]
 	pub(crate) type ShareToken<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, AssetId, ValueQuery>;
 
+	/// Pool account of a share token.
+	#[pallet::storage]
+	pub(crate) type PoolByShareToken<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, T::AccountId, OptionQuery>;
+
 	/// Total liquidity in a pool.
 	#[pallet::storage]
 	#[pallet::getter(fn total_liquidity)]
@@ -362,6 +372,7 @@ pub mod pallet {
 			T::NonDustableWhitelistHandler::add_account(&pair_account)?;
 
 			<ShareToken<T>>::insert(&pair_account, share_token);
+			<PoolByShareToken<T>>::insert(share_token, &pair_account);
 			<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
 
 			Self::deposit_event(Event::PoolCreated {
@@ -474,7 +485,9 @@ pub mod pallet {
 		/// Emits `SellExecuted` when successful. Deprecated.
 		/// Emits `pallet_broadcast::Swapped` when successful.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::sell() + <T as Config>::AMMHandler::on_trade_weight())]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			.saturating_add(<T as Config>::AMMHandler::on_trade_weight())
+			.saturating_add(<T as Config>::AccountTradeVolumeHandler::note_trade_volume_weight()))]
 		pub fn sell(
 			origin: OriginFor<T>,
 			asset_in: AssetId,
@@ -503,7 +516,9 @@ pub mod pallet {
 		/// Emits `BuyExecuted` when successful. Deprecated.
 		/// Emits `pallet_broadcast::Swapped` when successful.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::buy() + <T as Config>::AMMHandler::on_trade_weight())]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+			.saturating_add(<T as Config>::AMMHandler::on_trade_weight())
+			.saturating_add(<T as Config>::AccountTradeVolumeHandler::note_trade_volume_weight()))]
 		pub fn buy(
 			origin: OriginFor<T>,
 			asset_out: AssetId,
@@ -730,6 +745,7 @@ impl<T: Config> Pallet<T> {
 		});
 
 		if liquidity_left == 0 {
+			<PoolByShareToken<T>>::remove(share_token);
 			<ShareToken<T>>::remove(&pair_account);
 			<PoolAssets<T>>::remove(&pair_account);
 			<TotalLiquidity<T>>::remove(&pair_account);
@@ -860,6 +876,8 @@ impl<T: Config> Pallet<T> {
 			ExistenceRequirement::AllowDeath,
 		)?;
 
+		T::AccountTradeVolumeHandler::note_trade_volume(&transfer.origin, transfer.assets.asset_in, transfer.amount)?;
+
 		let liquidity_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
 		let liquidity_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
 		T::AMMHandler::on_trade(
@@ -996,6 +1014,8 @@ impl<T: Config> Pallet<T> {
 			ExistenceRequirement::AllowDeath,
 		)?;
 
+		T::AccountTradeVolumeHandler::note_trade_volume(&transfer.origin, transfer.assets.asset_out, transfer.amount)?;
+
 		let liquidity_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
 		let liquidity_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
 		T::AMMHandler::on_trade(
@@ -1063,6 +1083,14 @@ impl<T: Config> Pallet<T> {
 	pub fn pair_account_from_assets(asset_a: AssetId, asset_b: AssetId) -> T::AccountId {
 		T::AssetPairAccountId::from_assets(asset_a, asset_b, "xyk")
 	}
+
+	/// Return pool account and assets ordered by asset id of the pool with `share_token`.
+	pub fn pool_by_share_token(share_token: AssetId) -> Option<(T::AccountId, (AssetId, AssetId))> {
+		let pair_account = <PoolByShareToken<T>>::get(share_token)?;
+		let (asset_a, asset_b) = Self::pool_assets(&pair_account)?;
+
+		Some((pair_account, AssetPair::new(asset_a, asset_b).ordered_pair()))
+	}
 }
 
 // Implementation of AMM API which makes possible to plug the AMM pool into the exchange pallet.
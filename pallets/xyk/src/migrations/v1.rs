@@ -0,0 +1,111 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::migrations::VersionedMigration;
+#[cfg(feature = "try-runtime")]
+use frame_support::pallet_prelude::*;
+use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+const LOG_TARGET: &str = "runtime::xyk";
+
+// Private module to hide migration
+mod unversioned {
+	pub struct InnerMigrateV0ToV1<T: crate::Config>(core::marker::PhantomData<T>);
+}
+
+impl<T: crate::Config> UncheckedOnRuntimeUpgrade for unversioned::InnerMigrateV0ToV1<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		log::info!(target: LOG_TARGET, "v0->v1 migration started");
+
+		let mut pools: u64 = 0;
+		for (pair_account, share_token) in ShareToken::<T>::iter() {
+			PoolByShareToken::<T>::insert(share_token, pair_account);
+			pools += 1;
+		}
+
+		log::info!(target: LOG_TARGET, "migration finished, indexed {pools:?} pools by share token");
+		T::DbWeight::get().reads_writes(pools, pools)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == 0,
+			"can only upgrade from version 0"
+		);
+
+		Ok((ShareToken::<T>::iter_keys().count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let pre_pool_count =
+			u32::decode(&mut state.as_slice()).map_err(|_| "failed to decode pre-upgrade pool count")?;
+
+		let mut pools = 0u32;
+		for (pair_account, share_token) in ShareToken::<T>::iter() {
+			ensure!(
+				PoolByShareToken::<T>::get(share_token) == Some(pair_account),
+				"pool must be indexed by its share token"
+			);
+			pools += 1;
+		}
+		ensure!(pools == pre_pool_count, "pool count must not change");
+		Ok(())
+	}
+}
+
+pub type MigrateV0ToV1<T> =
+	VersionedMigration<0, 1, unversioned::InnerMigrateV0ToV1<T>, Pallet<T>, <T as frame_system::Config>::DbWeight>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::mock::*;
+	use frame_support::assert_ok;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	#[test]
+	fn migration_should_index_existing_pools_by_share_token() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				100_000_000_000_000,
+				ACA,
+				100_000_000_000_000,
+			));
+			let pair_account = XYK::get_pair_id(AssetPair {
+				asset_in: HDX,
+				asset_out: ACA,
+			});
+			let share_token = XYK::share_token(pair_account);
+			PoolByShareToken::<Test>::remove(share_token);
+			StorageVersion::new(0).put::<Pallet<Test>>();
+
+			// Act
+			MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+			// Assert
+			assert_eq!(PoolByShareToken::<Test>::get(share_token), Some(pair_account));
+			assert_eq!(XYK::pool_by_share_token(share_token), Some((pair_account, (HDX, ACA))));
+			assert_eq!(StorageVersion::get::<Pallet<Test>>(), 1);
+		});
+	}
+}
@@ -439,3 +439,106 @@ fn share_asset_id_should_be_offset() {
 		);
 	});
 }
+
+#[test]
+fn pool_by_share_token_should_return_pool_with_ordered_assets() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			100_000_000_000_000,
+			HDX,
+			100_000_000_000_000,
+		));
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: HDX,
+			asset_out: ACA,
+		});
+		let share_token = XYK::share_token(pair_account);
+
+		// Act & Assert
+		assert_eq!(XYK::pool_by_share_token(share_token), Some((pair_account, (HDX, ACA))));
+	});
+}
+
+#[test]
+fn pool_by_share_token_should_return_none_when_asset_is_not_share_token() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100_000_000_000_000,
+			ACA,
+			100_000_000_000_000,
+		));
+
+		// Act & Assert
+		assert_eq!(XYK::pool_by_share_token(HDX), None);
+		assert_eq!(XYK::pool_by_share_token(1_234_567), None);
+	});
+}
+
+#[test]
+fn pool_by_share_token_should_return_none_when_pool_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		let asset_pair = AssetPair {
+			asset_in: HDX,
+			asset_out: ACA,
+		};
+		assert_ok!(AssetRegistry::register(
+			RuntimeOrigin::signed(ALICE),
+			None,
+			Some(asset_pair.name().try_into().unwrap()),
+			AssetType::XYK,
+			Some(<Test as crate::Config>::MinPoolLiquidity::get()),
+			None,
+			None,
+			None,
+			None,
+			false
+		));
+		let share_token = AssetRegistry::asset_ids(
+			BoundedVec::<u8, <Test as pallet_asset_registry::Config>::StringLimit>::try_from(asset_pair.name())
+				.unwrap(),
+		)
+		.unwrap();
+
+		// Act & Assert
+		assert_eq!(XYK::pool_by_share_token(share_token), None);
+	});
+}
+
+#[test]
+fn pool_by_share_token_should_return_none_when_pool_was_destroyed() {
+	new_test_ext().execute_with(|| {
+		// Arrange
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100_000_000,
+			DOT,
+			1_000_000_000_000,
+		));
+		let pair_account = XYK::get_pair_id(AssetPair {
+			asset_in: HDX,
+			asset_out: DOT,
+		});
+		let share_token = XYK::share_token(pair_account);
+
+		// Act
+		assert_ok!(XYK::remove_liquidity_with_limits(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DOT,
+			100_000_000,
+			0,
+			0
+		));
+
+		// Assert
+		assert_eq!(XYK::pool_by_share_token(share_token), None);
+	});
+}
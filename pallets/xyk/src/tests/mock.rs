@@ -208,6 +208,7 @@ impl xyk::Config for Test {
 	type CanCreatePool = Disallow10_10Pool;
 	type AMMHandler = ();
 	type NonDustableWhitelistHandler = Whitelist;
+	type AccountTradeVolumeHandler = ();
 	type OracleSource = OracleSourceIdentifier;
 }
 
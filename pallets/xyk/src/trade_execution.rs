@@ -1,12 +1,13 @@
 use crate::types::{AssetId, AssetPair, Balance};
-use crate::{Config, Error, Pallet};
+use crate::{Config, Error, Pallet, PoolAssets};
 use frame_support::ensure;
 use frame_support::traits::Get;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, Trade, TradeExecution, TradeablePoolsProvider};
 use hydradx_traits::AMM;
 use orml_traits::MultiCurrency;
 use sp_runtime::DispatchError::Corruption;
 use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::vec::Vec;
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
@@ -171,3 +172,24 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 		Ok(spot_price_with_fee)
 	}
 }
+
+impl<T: Config> TradeablePoolsProvider<AssetId> for Pallet<T> {
+	fn tradeable_pools() -> Vec<Trade<AssetId>> {
+		PoolAssets::<T>::iter_values()
+			.flat_map(|(asset_a, asset_b)| {
+				[
+					Trade {
+						pool: PoolType::XYK,
+						asset_in: asset_a,
+						asset_out: asset_b,
+					},
+					Trade {
+						pool: PoolType::XYK,
+						asset_in: asset_b,
+						asset_out: asset_a,
+					},
+				]
+			})
+			.collect()
+	}
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0
+pragma solidity >=0.8.3;
+
+/// @dev The Router contract's address.
+address constant ROUTER_ADDRESS = 0x0000000000000000000000000000000000000807;
+
+/// @dev The Router contract's instance.
+Router constant ROUTER_CONTRACT = Router(ROUTER_ADDRESS);
+
+/// @title Router Interface
+/// @dev Executes trades via the route executor on behalf of the caller.
+/// Assets are identified by their asset ids and amounts are in the smallest unit of the asset.
+/// An empty route means that the on-chain route of the asset pair is used, or the default omnipool route.
+/// @custom:address 0x0000000000000000000000000000000000000807
+interface Router {
+    /// @dev Pool types, the values correspond to the `pool` field of a trade.
+    /// XYK = 0, LBP = 1, Stableswap = 2, Omnipool = 3, Aave = 4, HSM = 5, ConcentratedLiquidity = 6
+    struct Trade {
+        /// Pool type of the trade.
+        uint8 pool;
+        /// Id of the stableswap pool, ignored for other pool types.
+        uint32 poolId;
+        /// Asset sold in the trade.
+        uint32 assetIn;
+        /// Asset bought in the trade.
+        uint32 assetOut;
+    }
+
+    /// @dev Sells `amountIn` of `assetIn` for at least `minAmountOut` of `assetOut`.
+    /// @param assetIn Asset to sell.
+    /// @param assetOut Asset to receive.
+    /// @param amountIn Amount of `assetIn` to sell.
+    /// @param minAmountOut Minimum amount of `assetOut` to receive.
+    /// @param route Trades to execute.
+    /// @return amountOut Received amount of `assetOut`.
+    /// @custom:selector a66fdb52
+    function sell(
+        uint32 assetIn,
+        uint32 assetOut,
+        uint256 amountIn,
+        uint256 minAmountOut,
+        Trade[] memory route
+    ) external returns (uint256 amountOut);
+
+    /// @dev Buys `amountOut` of `assetOut` for at most `maxAmountIn` of `assetIn`.
+    /// @param assetIn Asset to spend.
+    /// @param assetOut Asset to buy.
+    /// @param amountOut Amount of `assetOut` to buy.
+    /// @param maxAmountIn Maximum amount of `assetIn` to spend.
+    /// @param route Trades to execute.
+    /// @return amountIn Spent amount of `assetIn`.
+    /// @custom:selector 8742a336
+    function buy(
+        uint32 assetIn,
+        uint32 assetOut,
+        uint256 amountOut,
+        uint256 maxAmountIn,
+        Trade[] memory route
+    ) external returns (uint256 amountIn);
+
+    /// @dev Sells the whole transferable balance of `assetIn` for at least `minAmountOut` of `assetOut`.
+    /// @param assetIn Asset to sell.
+    /// @param assetOut Asset to receive.
+    /// @param minAmountOut Minimum amount of `assetOut` to receive.
+    /// @param route Trades to execute.
+    /// @return amountOut Received amount of `assetOut`.
+    /// @custom:selector af0e4ff9
+    function sellAll(
+        uint32 assetIn,
+        uint32 assetOut,
+        uint256 minAmountOut,
+        Trade[] memory route
+    ) external returns (uint256 amountOut);
+
+    /// @dev Calculates the amount of `assetOut` received when selling `amountIn` of `assetIn`.
+    /// @param assetIn Asset to sell.
+    /// @param assetOut Asset to receive.
+    /// @param amountIn Amount of `assetIn` to sell.
+    /// @param route Trades to calculate.
+    /// @return amountOut Expected amount of `assetOut`.
+    /// @custom:selector a6ca360d
+    function quoteSell(
+        uint32 assetIn,
+        uint32 assetOut,
+        uint256 amountIn,
+        Trade[] memory route
+    ) external view returns (uint256 amountOut);
+
+    /// @dev Calculates the amount of `assetIn` needed to buy `amountOut` of `assetOut`.
+    /// @param assetIn Asset to spend.
+    /// @param assetOut Asset to buy.
+    /// @param amountOut Amount of `assetOut` to buy.
+    /// @param route Trades to calculate.
+    /// @return amountIn Expected amount of `assetIn`.
+    /// @custom:selector 08968a1c
+    function quoteBuy(
+        uint32 assetIn,
+        uint32 assetOut,
+        uint256 amountOut,
+        Trade[] memory route
+    ) external view returns (uint256 amountIn);
+}
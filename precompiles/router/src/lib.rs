@@ -0,0 +1,279 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2025  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use core::marker::PhantomData;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::fungibles::Inspect;
+use frame_support::traits::ConstU32;
+use hydradx_traits::router::{AmmTradeWeights, PoolType, Route, Trade, MAX_NUMBER_OF_TRADES};
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_runtime::traits::Dispatchable;
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity representation of a single trade of a route: `(pool, poolId, assetIn, assetOut)`.
+///
+/// `pool` is the index of the [`PoolType`] variant, `poolId` is only used by stableswap trades.
+pub type SolidityTrade = (u8, u32, u32, u32);
+
+pub type SolidityRoute = BoundedVec<SolidityTrade, ConstU32<MAX_NUMBER_OF_TRADES>>;
+
+/// Precompile at address 0x0807.
+///
+/// Exposes `pallet_route_executor` to EVM contracts. Trades are executed on behalf of the caller
+/// of the precompile, so contracts trade with their own balances, the same way as a substrate account
+/// calling the router extrinsics.
+///
+/// An empty route means that the on-chain route of the asset pair is used, or the default omnipool
+/// route if there is none.
+pub struct RouterPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> RouterPrecompile<Runtime>
+where
+	Runtime: pallet_route_executor::Config + pallet_evm::Config,
+	Runtime::AddressMapping: AddressMapping<Runtime::AccountId>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_route_executor::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as pallet_route_executor::Config>::AssetId: From<u32>,
+	<Runtime as pallet_route_executor::Config>::Balance: Into<U256> + TryFrom<U256>,
+{
+	/// Sells `amount_in` of `asset_in` for at least `min_amount_out` of `asset_out`.
+	/// Returns the received amount of `asset_out`.
+	#[precompile::public("sell(uint32,uint32,uint256,uint256,(uint8,uint32,uint32,uint32)[])")]
+	fn sell(
+		handle: &mut impl PrecompileHandle,
+		asset_in: u32,
+		asset_out: u32,
+		amount_in: U256,
+		min_amount_out: U256,
+		route: SolidityRoute,
+	) -> EvmResult<U256> {
+		let amount_in = Self::to_balance(amount_in, "amountIn")?;
+		let min_amount_out = Self::to_balance(min_amount_out, "minAmountOut")?;
+		let route = Self::to_route(route)?;
+
+		let call = pallet_route_executor::Call::<Runtime>::sell {
+			asset_in: asset_in.into(),
+			asset_out: asset_out.into(),
+			amount_in,
+			min_amount_out,
+			route,
+		};
+
+		let (received, _) = Self::dispatch_and_track_balances(handle, call, asset_out, asset_in)?;
+
+		Ok(received)
+	}
+
+	/// Buys `amount_out` of `asset_out` for at most `max_amount_in` of `asset_in`.
+	/// Returns the spent amount of `asset_in`.
+	#[precompile::public("buy(uint32,uint32,uint256,uint256,(uint8,uint32,uint32,uint32)[])")]
+	fn buy(
+		handle: &mut impl PrecompileHandle,
+		asset_in: u32,
+		asset_out: u32,
+		amount_out: U256,
+		max_amount_in: U256,
+		route: SolidityRoute,
+	) -> EvmResult<U256> {
+		let amount_out = Self::to_balance(amount_out, "amountOut")?;
+		let max_amount_in = Self::to_balance(max_amount_in, "maxAmountIn")?;
+		let route = Self::to_route(route)?;
+
+		let call = pallet_route_executor::Call::<Runtime>::buy {
+			asset_in: asset_in.into(),
+			asset_out: asset_out.into(),
+			amount_out,
+			max_amount_in,
+			route,
+		};
+
+		let (_, spent) = Self::dispatch_and_track_balances(handle, call, asset_out, asset_in)?;
+
+		Ok(spent)
+	}
+
+	/// Sells the whole reducible balance of `asset_in` of the caller for at least `min_amount_out` of `asset_out`.
+	/// Returns the received amount of `asset_out`.
+	#[precompile::public("sellAll(uint32,uint32,uint256,(uint8,uint32,uint32,uint32)[])")]
+	fn sell_all(
+		handle: &mut impl PrecompileHandle,
+		asset_in: u32,
+		asset_out: u32,
+		min_amount_out: U256,
+		route: SolidityRoute,
+	) -> EvmResult<U256> {
+		let min_amount_out = Self::to_balance(min_amount_out, "minAmountOut")?;
+		let route = Self::to_route(route)?;
+
+		let call = pallet_route_executor::Call::<Runtime>::sell_all {
+			asset_in: asset_in.into(),
+			asset_out: asset_out.into(),
+			min_amount_out,
+			route,
+		};
+
+		let (received, _) = Self::dispatch_and_track_balances(handle, call, asset_out, asset_in)?;
+
+		Ok(received)
+	}
+
+	/// Returns the amount of `asset_out` received when selling `amount_in` of `asset_in` via `route`.
+	#[precompile::public("quoteSell(uint32,uint32,uint256,(uint8,uint32,uint32,uint32)[])")]
+	#[precompile::view]
+	fn quote_sell(
+		handle: &mut impl PrecompileHandle,
+		asset_in: u32,
+		asset_out: u32,
+		amount_in: U256,
+		route: SolidityRoute,
+	) -> EvmResult<U256> {
+		let amount_in = Self::to_balance(amount_in, "amountIn")?;
+		let route = Self::to_route(route)?;
+
+		// There is no dedicated weight for calculating sell amounts, the weight of the sell is used as an upper bound.
+		let weight = <Runtime as pallet_route_executor::Config>::WeightInfo::get_route_weight().saturating_add(
+			<Runtime as pallet_route_executor::Config>::WeightInfo::sell_weight(&route),
+		);
+		handle.record_cost(Runtime::GasWeightMapping::weight_to_gas(weight))?;
+
+		let (_, trade_amounts) =
+			pallet_route_executor::Pallet::<Runtime>::quote_sell(asset_in.into(), asset_out.into(), amount_in, &route)
+				.map_err(|e| revert(alloc::format!("Quote failed: {e:?}")))?;
+
+		let amount_out = trade_amounts
+			.last()
+			.map(|amounts| amounts.amount_out)
+			.ok_or_else(|| revert("Quote failed: empty route"))?;
+
+		Ok(amount_out.into())
+	}
+
+	/// Returns the amount of `asset_in` needed to buy `amount_out` of `asset_out` via `route`.
+	#[precompile::public("quoteBuy(uint32,uint32,uint256,(uint8,uint32,uint32,uint32)[])")]
+	#[precompile::view]
+	fn quote_buy(
+		handle: &mut impl PrecompileHandle,
+		asset_in: u32,
+		asset_out: u32,
+		amount_out: U256,
+		route: SolidityRoute,
+	) -> EvmResult<U256> {
+		let amount_out = Self::to_balance(amount_out, "amountOut")?;
+		let route = Self::to_route(route)?;
+
+		let weight = <Runtime as pallet_route_executor::Config>::WeightInfo::get_route_weight().saturating_add(
+			<Runtime as pallet_route_executor::Config>::WeightInfo::calculate_buy_trade_amounts_weight(&route),
+		);
+		handle.record_cost(Runtime::GasWeightMapping::weight_to_gas(weight))?;
+
+		let (_, trade_amounts) =
+			pallet_route_executor::Pallet::<Runtime>::quote_buy(asset_in.into(), asset_out.into(), amount_out, &route)
+				.map_err(|e| revert(alloc::format!("Quote failed: {e:?}")))?;
+
+		let amount_in = trade_amounts
+			.first()
+			.map(|amounts| amounts.amount_in)
+			.ok_or_else(|| revert("Quote failed: empty route"))?;
+
+		Ok(amount_in.into())
+	}
+
+	/// Dispatches the router call with the caller as signed origin.
+	/// Returns the amount of `asset_out` received and the amount of `asset_in` spent by the caller.
+	fn dispatch_and_track_balances(
+		handle: &mut impl PrecompileHandle,
+		call: pallet_route_executor::Call<Runtime>,
+		asset_out: u32,
+		asset_in: u32,
+	) -> EvmResult<(U256, U256)> {
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		// Balances of both assets are read before and after the trade
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(4))?;
+
+		let balance_of = |asset: u32| -> U256 {
+			<Runtime as pallet_route_executor::Config>::Currency::balance(asset.into(), &who).into()
+		};
+
+		let asset_out_before = balance_of(asset_out);
+		let asset_in_before = balance_of(asset_in);
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(who.clone()).into(), call, 0)?;
+
+		let received = balance_of(asset_out).saturating_sub(asset_out_before);
+		let spent = asset_in_before.saturating_sub(balance_of(asset_in));
+
+		Ok((received, spent))
+	}
+
+	fn to_balance(value: U256, field: &'static str) -> EvmResult<<Runtime as pallet_route_executor::Config>::Balance> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field(field).into())
+	}
+
+	fn to_route(route: SolidityRoute) -> EvmResult<Route<<Runtime as pallet_route_executor::Config>::AssetId>> {
+		let trades: Vec<SolidityTrade> = route.into();
+
+		let trades = trades
+			.into_iter()
+			.enumerate()
+			.map(|(index, (pool, pool_id, asset_in, asset_out))| -> EvmResult<Trade<_>> {
+				let pool = match pool {
+					0 => PoolType::XYK,
+					1 => PoolType::LBP,
+					2 => PoolType::Stableswap(pool_id.into()),
+					3 => PoolType::Omnipool,
+					4 => PoolType::Aave,
+					5 => PoolType::HSM,
+					6 => PoolType::ConcentratedLiquidity,
+					_ => {
+						return Err(RevertReason::custom("Unknown pool type")
+							.in_array(index)
+							.in_field("route")
+							.into())
+					}
+				};
+
+				Ok(Trade {
+					pool,
+					asset_in: asset_in.into(),
+					asset_out: asset_out.into(),
+				})
+			})
+			.collect::<EvmResult<Vec<_>>>()?;
+
+		Route::try_from(trades).map_err(|_| RevertReason::value_is_too_large("route").into())
+	}
+}
@@ -0,0 +1,401 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2025  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+//! Test utilities
+use super::*;
+
+use frame_support::traits::{Everything, Nothing};
+use frame_support::{construct_runtime, pallet_prelude::*, parameter_types};
+use frame_system::pallet_prelude::OriginFor;
+use frame_system::EnsureRoot;
+use hydradx_traits::router::{ExecutorError, TradeExecution, TradeablePoolsProvider};
+use hydradx_traits::{OraclePeriod, PriceOracle};
+use orml_traits::{parameter_type_with_key, MultiCurrency};
+use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, FrameSystemAccountProvider};
+use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::{BuildStorage, DispatchError, FixedU128};
+
+pub type AccountId = MockAccount;
+pub type AssetId = u32;
+pub type Balance = u128;
+pub type Amount = i128;
+
+type Block = frame_system::mocking::MockBlockU32<Runtime>;
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2;
+pub const DOT: AssetId = 5;
+
+/// Every mocked trade receives two units of `asset_out` for one unit of `asset_in`.
+pub const PRICE: Balance = 2;
+
+construct_runtime!(
+	pub enum Runtime	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		Evm: pallet_evm,
+		Timestamp: pallet_timestamp,
+		Broadcast: pallet_broadcast,
+		RouteExecutor: pallet_route_executor,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+	type ExtensionsWeightInfo = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 0;
+}
+
+impl pallet_balances::Config for Runtime {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 4];
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeFreezeReason = ();
+	type DoneSlashHandler = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = ();
+	type MaxReserves = ();
+	type CurrencyHooks = ();
+}
+
+mock_account!(Router, |_| MockAccount::from_u64(1));
+mock_account!(Pool, |_| MockAccount::from_u64(2));
+
+pub type Precompiles<R> = PrecompileSetBuilder<R, (PrecompileAt<AddressU64<1>, RouterPrecompile<R>>,)>;
+
+pub type PCall = RouterPrecompileCall<Runtime>;
+
+parameter_types! {
+	pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
+	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+}
+
+impl pallet_evm::Config for Runtime {
+	type AccountProvider = FrameSystemAccountProvider<Runtime>;
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = AccountId;
+	type Currency = Balances;
+	type PrecompilesType = Precompiles<Runtime>;
+	type PrecompilesValue = PrecompilesValue;
+	type ChainId = ();
+	type BlockGasLimit = ();
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type OnChargeTransaction = ();
+	type OnCreate = ();
+	type FindAuthor = ();
+	type GasLimitPovSizeRatio = ();
+	type GasLimitStorageGrowthRatio = ();
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+	type CreateOriginFilter = ();
+	type CreateInnerOriginFilter = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+impl pallet_broadcast::Config for Runtime {}
+
+parameter_types! {
+	pub NativeCurrencyId: AssetId = HDX;
+	pub DefaultRoutePoolType: PoolType<AssetId> = PoolType::Omnipool;
+	pub const RouteValidationOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
+	pub const MaxRouteCandidates: u32 = 10;
+	pub const MaxRouteSearchSteps: u32 = 100;
+}
+
+impl pallet_route_executor::Config for Runtime {
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type NativeAssetId = NativeCurrencyId;
+	type Currency = Tokens;
+	type AMM = MockAmm;
+	type OraclePriceProvider = PriceProviderMock;
+	type OraclePeriod = RouteValidationOraclePeriod;
+	type DefaultRoutePoolType = DefaultRoutePoolType;
+	type ForceInsertOrigin = EnsureRoot<Self::AccountId>;
+	type PoolsProvider = PoolsProviderMock;
+	type MaxRouteCandidates = MaxRouteCandidates;
+	type MaxRouteSearchSteps = MaxRouteSearchSteps;
+	type WeightInfo = ();
+}
+
+pub(crate) type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type RuntimeCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> hydradx_traits::CreateBare<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_bare(call: Self::RuntimeCall) -> Extrinsic {
+		Extrinsic::new_bare(call)
+	}
+}
+
+pub struct PoolsProviderMock;
+
+impl TradeablePoolsProvider<AssetId> for PoolsProviderMock {
+	fn tradeable_pools() -> Vec<Trade<AssetId>> {
+		Vec::new()
+	}
+}
+
+pub struct PriceProviderMock;
+
+impl PriceOracle<AssetId> for PriceProviderMock {
+	type Price = hydra_dx_math::ratio::Ratio;
+
+	fn price(_route: &[Trade<AssetId>], _: OraclePeriod) -> Option<Self::Price> {
+		None
+	}
+}
+
+/// Executes XYK and Omnipool trades against the `Pool` account at a fixed [`PRICE`].
+pub struct MockAmm;
+
+impl MockAmm {
+	fn ensure_supported(pool_type: PoolType<AssetId>) -> Result<(), ExecutorError<DispatchError>> {
+		match pool_type {
+			PoolType::XYK | PoolType::Omnipool => Ok(()),
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn swap(
+		who: OriginFor<Runtime>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		amount_out: Balance,
+	) -> Result<(), ExecutorError<DispatchError>> {
+		let who = frame_system::ensure_signed(who).map_err(|e| ExecutorError::Error(e.into()))?;
+
+		<Tokens as MultiCurrency<AccountId>>::transfer(asset_in, &who, &Pool.into(), amount_in)
+			.map_err(ExecutorError::Error)?;
+		<Tokens as MultiCurrency<AccountId>>::transfer(asset_out, &Pool.into(), &who, amount_out)
+			.map_err(ExecutorError::Error)?;
+
+		Ok(())
+	}
+}
+
+impl TradeExecution<OriginFor<Runtime>, AccountId, AssetId, Balance> for MockAmm {
+	type Error = DispatchError;
+
+	fn calculate_out_given_in(
+		pool_type: PoolType<AssetId>,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		Self::ensure_supported(pool_type)?;
+		Ok(amount_in * PRICE)
+	}
+
+	fn calculate_in_given_out(
+		pool_type: PoolType<AssetId>,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		Self::ensure_supported(pool_type)?;
+		Ok(amount_out.div_ceil(PRICE))
+	}
+
+	fn execute_sell(
+		who: OriginFor<Runtime>,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		let amount_out = Self::calculate_out_given_in(pool_type, asset_in, asset_out, amount_in)?;
+		if amount_out < min_limit {
+			return Err(ExecutorError::Error(DispatchError::Other("Trade limit reached")));
+		}
+
+		Self::swap(who, asset_in, asset_out, amount_in, amount_out)
+	}
+
+	fn execute_buy(
+		who: OriginFor<Runtime>,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		let amount_in = Self::calculate_in_given_out(pool_type, asset_in, asset_out, amount_out)?;
+		if amount_in > max_limit {
+			return Err(ExecutorError::Error(DispatchError::Other("Trade limit reached")));
+		}
+
+		Self::swap(who, asset_in, asset_out, amount_in, amount_out)
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<AssetId>,
+		_asset_a: AssetId,
+		_asset_b: AssetId,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		Self::ensure_supported(pool_type)?;
+		Ok(POOL_BALANCE)
+	}
+
+	fn calculate_spot_price_with_fee(
+		pool_type: PoolType<AssetId>,
+		_asset_a: AssetId,
+		_asset_b: AssetId,
+	) -> Result<FixedU128, ExecutorError<Self::Error>> {
+		Self::ensure_supported(pool_type)?;
+		Ok(FixedU128::from_rational(1, PRICE))
+	}
+}
+
+pub const POOL_BALANCE: Balance = 1_000_000;
+
+pub(crate) struct ExtBuilder {
+	// endowed accounts with balances
+	balances: Vec<(AccountId, AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> ExtBuilder {
+		ExtBuilder {
+			balances: vec![
+				(Pool.into(), HDX, POOL_BALANCE),
+				(Pool.into(), DAI, POOL_BALANCE),
+				(Pool.into(), DOT, POOL_BALANCE),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.balances.extend(balances);
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.expect("Frame system builds valid default genesis config");
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.expect("Tokens storage can be assimilated");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub fn balance(who: impl Into<AccountId>, asset: AssetId) -> Balance {
+	<Tokens as MultiCurrency<AccountId>>::free_balance(asset, &who.into())
+}
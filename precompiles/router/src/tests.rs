@@ -0,0 +1,331 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2025  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::mock::*;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::U256;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn omnipool_trade(asset_in: AssetId, asset_out: AssetId) -> (u8, u32, u32, u32) {
+	(3, 0, asset_in, asset_out)
+}
+
+fn xyk_trade(asset_in: AssetId, asset_out: AssetId) -> (u8, u32, u32, u32) {
+	(0, 0, asset_in, asset_out)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::sell_selectors().contains(&0xa66fdb52));
+	assert!(PCall::buy_selectors().contains(&0x8742a336));
+	assert!(PCall::sell_all_selectors().contains(&0xaf0e4ff9));
+	assert!(PCall::quote_sell_selectors().contains(&0xa6ca360d));
+	assert!(PCall::quote_buy_selectors().contains(&0x08968a1c));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Router);
+
+		tester.test_default_modifier(PCall::sell_selectors());
+		tester.test_default_modifier(PCall::buy_selectors());
+		tester.test_default_modifier(PCall::sell_all_selectors());
+		tester.test_view_modifier(PCall::quote_sell_selectors());
+		tester.test_view_modifier(PCall::quote_buy_selectors());
+	});
+}
+
+#[test]
+fn sell_should_work() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), HDX, 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Router,
+					PCall::sell {
+						asset_in: HDX,
+						asset_out: DAI,
+						amount_in: 100.into(),
+						min_amount_out: 200.into(),
+						route: vec![omnipool_trade(HDX, DAI)].into(),
+					},
+				)
+				.execute_returns(U256::from(200));
+
+			assert_eq!(balance(Alice, HDX), 900);
+			assert_eq!(balance(Alice, DAI), 200);
+		});
+}
+
+#[test]
+fn sell_should_work_with_multiple_trades() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), HDX, 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Router,
+					PCall::sell {
+						asset_in: HDX,
+						asset_out: DAI,
+						amount_in: 100.into(),
+						min_amount_out: 400.into(),
+						route: vec![xyk_trade(HDX, DOT), omnipool_trade(DOT, DAI)].into(),
+					},
+				)
+				.execute_returns(U256::from(400));
+
+			assert_eq!(balance(Alice, HDX), 900);
+			assert_eq!(balance(Alice, DOT), 0);
+			assert_eq!(balance(Alice, DAI), 400);
+		});
+}
+
+#[test]
+fn sell_should_use_default_route_when_route_is_empty() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), HDX, 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Router,
+					PCall::sell {
+						asset_in: HDX,
+						asset_out: DAI,
+						amount_in: 100.into(),
+						min_amount_out: 0.into(),
+						route: vec![].into(),
+					},
+				)
+				.execute_returns(U256::from(200));
+
+			assert_eq!(balance(Alice, DAI), 200);
+		});
+}
+
+#[test]
+fn sell_should_revert_when_min_amount_out_is_not_reached() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), HDX, 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Router,
+					PCall::sell {
+						asset_in: HDX,
+						asset_out: DAI,
+						amount_in: 100.into(),
+						min_amount_out: 201.into(),
+						route: vec![omnipool_trade(HDX, DAI)].into(),
+					},
+				)
+				.execute_reverts(|output| output.starts_with(b"Dispatched call failed with error: "));
+
+			assert_eq!(balance(Alice, HDX), 1_000);
+			assert_eq!(balance(Alice, DAI), 0);
+		});
+}
+
+#[test]
+fn sell_should_revert_when_pool_type_is_unknown() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), HDX, 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Router,
+					PCall::sell {
+						asset_in: HDX,
+						asset_out: DAI,
+						amount_in: 100.into(),
+						min_amount_out: 0.into(),
+						route: vec![(6, 0, HDX, DAI)].into(),
+					},
+				)
+				.execute_reverts(|output| output == b"route[0]: Unknown pool type");
+		});
+}
+
+#[test]
+fn sell_should_revert_when_amount_does_not_fit_into_balance() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), HDX, 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Router,
+					PCall::sell {
+						asset_in: HDX,
+						asset_out: DAI,
+						amount_in: U256::MAX,
+						min_amount_out: 0.into(),
+						route: vec![omnipool_trade(HDX, DAI)].into(),
+					},
+				)
+				.execute_reverts(|output| output == b"amountIn: Value is too large for balance type");
+		});
+}
+
+#[test]
+fn buy_should_work() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), HDX, 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Router,
+					PCall::buy {
+						asset_in: HDX,
+						asset_out: DAI,
+						amount_out: 200.into(),
+						max_amount_in: 100.into(),
+						route: vec![omnipool_trade(HDX, DAI)].into(),
+					},
+				)
+				.execute_returns(U256::from(100));
+
+			assert_eq!(balance(Alice, HDX), 900);
+			assert_eq!(balance(Alice, DAI), 200);
+		});
+}
+
+#[test]
+fn buy_should_revert_when_max_amount_in_is_exceeded() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), HDX, 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Router,
+					PCall::buy {
+						asset_in: HDX,
+						asset_out: DAI,
+						amount_out: 200.into(),
+						max_amount_in: 99.into(),
+						route: vec![omnipool_trade(HDX, DAI)].into(),
+					},
+				)
+				.execute_reverts(|output| output.starts_with(b"Dispatched call failed with error: "));
+
+			assert_eq!(balance(Alice, HDX), 1_000);
+		});
+}
+
+#[test]
+fn sell_all_should_sell_whole_balance() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), HDX, 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Router,
+					PCall::sell_all {
+						asset_in: HDX,
+						asset_out: DAI,
+						min_amount_out: 2_000.into(),
+						route: vec![omnipool_trade(HDX, DAI)].into(),
+					},
+				)
+				.execute_returns(U256::from(2_000));
+
+			assert_eq!(balance(Alice, HDX), 0);
+			assert_eq!(balance(Alice, DAI), 2_000);
+		});
+}
+
+#[test]
+fn quote_sell_should_return_amount_out_of_last_trade() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Router,
+				PCall::quote_sell {
+					asset_in: HDX,
+					asset_out: DAI,
+					amount_in: 100.into(),
+					route: vec![xyk_trade(HDX, DOT), omnipool_trade(DOT, DAI)].into(),
+				},
+			)
+			.with_static_call(true)
+			.execute_returns(U256::from(400));
+	});
+}
+
+#[test]
+fn quote_buy_should_return_amount_in_of_first_trade() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Router,
+				PCall::quote_buy {
+					asset_in: HDX,
+					asset_out: DAI,
+					amount_out: 400.into(),
+					route: vec![xyk_trade(HDX, DOT), omnipool_trade(DOT, DAI)].into(),
+				},
+			)
+			.with_static_call(true)
+			.execute_returns(U256::from(100));
+	});
+}
+
+#[test]
+fn quote_sell_should_revert_when_route_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Router,
+				PCall::quote_sell {
+					asset_in: HDX,
+					asset_out: DAI,
+					amount_in: 100.into(),
+					route: vec![omnipool_trade(HDX, DOT)].into(),
+				},
+			)
+			.execute_reverts(|output| output.starts_with(b"Quote failed: "));
+	});
+}
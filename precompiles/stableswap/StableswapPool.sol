@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: Apache-2.0
+pragma solidity >=0.8.3;
+
+/// @dev Address prefix of the stableswap pool precompiles. The last 4 bytes of the address are the pool id.
+/// E.g. the pool with id 100 is located at 0x0000020000000000000000000000000000000064.
+address constant STABLESWAP_POOL_ADDRESS_PREFIX = 0x0000020000000000000000000000000000000000;
+
+/// @title Stableswap Pool Interface
+/// @dev Curve-compatible interface of a single stableswap pool.
+/// Coin indices correspond to the order of the assets in the pool.
+/// Amounts are in the smallest unit of the asset. Pool shares are the pool id asset.
+/// Trades and liquidity changes are executed on behalf of the caller, no approvals are needed.
+/// @custom:address 0x0000020000000000000000000000000000000000 + pool id
+interface StableswapPool {
+    /// @dev Returns the ERC20 address of the coin at index `i`.
+    /// @custom:selector c6610657
+    function coins(uint256 i) external view returns (address);
+
+    /// @dev Returns the pool reserve of the coin at index `i`.
+    /// @custom:selector 4903b0d1
+    function balances(uint256 i) external view returns (uint256);
+
+    /// @dev Returns the number of coins in the pool.
+    /// @custom:selector 29357750
+    function N_COINS() external view returns (uint256);
+
+    /// @dev Returns the current amplification of the pool.
+    /// @custom:selector f446c1d0
+    function A() external view returns (uint256);
+
+    /// @dev Returns the current trade fee of the pool with 1e10 precision.
+    /// @custom:selector ddca3f43
+    function fee() external view returns (uint256);
+
+    /// @dev Returns the value of one pool share with 1e18 precision.
+    /// @custom:selector bb7b8b80
+    function get_virtual_price() external view returns (uint256);
+
+    /// @dev Returns the amount of coin `j` received for selling `dx` of coin `i`.
+    /// @custom:selector 5e0d443f
+    function get_dy(int128 i, int128 j, uint256 dx) external view returns (uint256);
+
+    /// @dev Returns the amount of coin `i` needed to buy `dy` of coin `j`.
+    /// @custom:selector 67df02ca
+    function get_dx(int128 i, int128 j, uint256 dy) external view returns (uint256);
+
+    /// @dev Sells `dx` of coin `i` for at least `min_dy` of coin `j`.
+    /// @return Received amount of coin `j`.
+    /// @custom:selector 3df02124
+    function exchange(int128 i, int128 j, uint256 dx, uint256 min_dy) external returns (uint256);
+
+    /// @dev Adds liquidity and mints at least `min_mint_amount` of pool shares.
+    /// @param amounts Amount of every coin of the pool, zero amounts are skipped.
+    /// @return Minted amount of pool shares.
+    /// @custom:selector b72df5de
+    function add_liquidity(uint256[] memory amounts, uint256 min_mint_amount) external returns (uint256);
+
+    /// @dev Burns `token_amount` of pool shares for at least `min_amount` of coin `i`.
+    /// @return Received amount of coin `i`.
+    /// @custom:selector 1a4d01d2
+    function remove_liquidity_one_coin(uint256 token_amount, int128 i, uint256 min_amount) external returns (uint256);
+}
@@ -0,0 +1,334 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2025  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use core::marker::PhantomData;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use hydradx_traits::evm::Erc20Mapping;
+use hydradx_traits::stableswap::AssetAmount;
+use orml_traits::MultiCurrency;
+use pallet_evm::AddressMapping;
+use pallet_stableswap::types::PoolSnapshot;
+use pallet_stableswap::MAX_ASSETS_IN_POOL;
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_runtime::traits::Dispatchable;
+use sp_runtime::FixedPointNumber;
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Stableswap pool precompiles are located at `0x0000020000000000000000000000000000000000 + pool_id`.
+pub const POOL_ADDRESS_PREFIX: [u8; 16] = [0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// Precision of the fee returned by `fee()`, same as in Curve pools.
+pub const FEE_PRECISION: u128 = 10_000_000_000;
+
+/// Precision of the virtual price returned by `get_virtual_price()`.
+pub const VIRTUAL_PRICE_PRECISION: u128 = <sp_runtime::FixedU128 as FixedPointNumber>::DIV;
+
+/// Returns the address of the precompile of stableswap pool `pool_id`.
+pub fn pool_address(pool_id: u32) -> H160 {
+	let mut address = [0u8; 20];
+	address[..16].copy_from_slice(&POOL_ADDRESS_PREFIX);
+	address[16..].copy_from_slice(&pool_id.to_be_bytes());
+	H160::from(address)
+}
+
+/// Returns the stableswap pool id encoded in `address`, if it is a pool precompile address.
+pub fn pool_id_from_address(address: H160) -> Option<u32> {
+	let bytes = address.to_fixed_bytes();
+	if bytes[..16] != POOL_ADDRESS_PREFIX {
+		return None;
+	}
+
+	let mut pool_id = [0u8; 4];
+	pool_id.copy_from_slice(&bytes[16..]);
+	Some(u32::from_be_bytes(pool_id))
+}
+
+pub fn is_pool_address(address: H160) -> bool {
+	pool_id_from_address(address).is_some()
+}
+
+/// Precompile of a single stableswap pool, exposing a Curve-compatible ABI.
+///
+/// Every pool has its own precompile address derived from the pool id (see [`pool_address`]),
+/// coin indices correspond to the order of the assets in the pool.
+///
+/// Unlike Curve pools, the precompile does not pull tokens with `transferFrom`. Trades and liquidity
+/// changes are executed on behalf of the caller directly via the stableswap extrinsics, so no approval is needed.
+///
+/// `AssetAddress` maps the pool assets to the ERC20 addresses returned by `coins`.
+pub struct StableswapPrecompile<Runtime, AssetAddress>(PhantomData<(Runtime, AssetAddress)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, AssetAddress> StableswapPrecompile<Runtime, AssetAddress>
+where
+	Runtime: pallet_stableswap::Config + pallet_evm::Config,
+	Runtime::AddressMapping: AddressMapping<Runtime::AccountId>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_stableswap::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as pallet_stableswap::Config>::AssetId: From<u32>,
+	AssetAddress: Erc20Mapping<<Runtime as pallet_stableswap::Config>::AssetId>,
+{
+	/// Returns the ERC20 address of the coin at index `i`.
+	#[precompile::public("coins(uint256)")]
+	#[precompile::view]
+	fn coins(handle: &mut impl PrecompileHandle, i: U256) -> EvmResult<Address> {
+		let (_, snapshot) = Self::snapshot(handle)?;
+		let index = Self::to_index(i, snapshot.assets.len(), "i")?;
+
+		Ok(Address(AssetAddress::asset_address(snapshot.assets[index])))
+	}
+
+	/// Returns the pool reserve of the coin at index `i`.
+	#[precompile::public("balances(uint256)")]
+	#[precompile::view]
+	fn balances(handle: &mut impl PrecompileHandle, i: U256) -> EvmResult<U256> {
+		let (_, snapshot) = Self::snapshot(handle)?;
+		let index = Self::to_index(i, snapshot.assets.len(), "i")?;
+
+		Ok(snapshot.reserves[index].amount.into())
+	}
+
+	#[precompile::public("N_COINS()")]
+	#[precompile::view]
+	fn n_coins(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		let (_, snapshot) = Self::snapshot(handle)?;
+
+		Ok(snapshot.assets.len().into())
+	}
+
+	/// Returns the current amplification of the pool.
+	#[precompile::public("A()")]
+	#[precompile::view]
+	fn a(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		let (_, snapshot) = Self::snapshot(handle)?;
+
+		Ok(snapshot.amplification.into())
+	}
+
+	/// Returns the current trade fee of the pool with [`FEE_PRECISION`].
+	#[precompile::public("fee()")]
+	#[precompile::view]
+	fn fee(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		let (_, snapshot) = Self::snapshot(handle)?;
+
+		Ok(U256::from(snapshot.block_fee.deconstruct()) * U256::from(FEE_PRECISION / 1_000_000))
+	}
+
+	/// Returns the value of one share in terms of the pool invariant, with [`VIRTUAL_PRICE_PRECISION`].
+	///
+	/// The invariant is normalized to 18 decimals and adjusted by the asset pegs of the pool.
+	#[precompile::public("get_virtual_price()")]
+	#[precompile::view]
+	fn get_virtual_price(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		let (_, snapshot) = Self::snapshot(handle)?;
+
+		if snapshot.share_issuance == 0 {
+			return Err(revert("Pool has no liquidity"));
+		}
+
+		let virtual_price = pallet_stableswap::Pallet::<Runtime>::virtual_price(&snapshot)
+			.ok_or_else(|| revert("Failed to calculate virtual price"))?;
+
+		Ok(virtual_price.into_inner().into())
+	}
+
+	/// Returns the amount of coin `j` received for selling `dx` of coin `i`.
+	#[precompile::public("get_dy(int128,int128,uint256)")]
+	#[precompile::view]
+	fn get_dy(handle: &mut impl PrecompileHandle, i: i128, j: i128, dx: U256) -> EvmResult<U256> {
+		let (pool_id, snapshot) = Self::snapshot(handle)?;
+		let asset_in = snapshot.assets[Self::to_index(i, snapshot.assets.len(), "i")?];
+		let asset_out = snapshot.assets[Self::to_index(j, snapshot.assets.len(), "j")?];
+		let dx = Self::to_balance(dx, "dx")?;
+
+		let (dy, _) =
+			pallet_stableswap::Pallet::<Runtime>::simulate_sell(pool_id, asset_in, asset_out, dx, 0, Some(snapshot))
+				.map_err(|e| revert(alloc::format!("Simulation failed: {e:?}")))?;
+
+		Ok(dy.into())
+	}
+
+	/// Returns the amount of coin `i` needed to buy `dy` of coin `j`.
+	#[precompile::public("get_dx(int128,int128,uint256)")]
+	#[precompile::view]
+	fn get_dx(handle: &mut impl PrecompileHandle, i: i128, j: i128, dy: U256) -> EvmResult<U256> {
+		let (pool_id, snapshot) = Self::snapshot(handle)?;
+		let asset_in = snapshot.assets[Self::to_index(i, snapshot.assets.len(), "i")?];
+		let asset_out = snapshot.assets[Self::to_index(j, snapshot.assets.len(), "j")?];
+		let dy = Self::to_balance(dy, "dy")?;
+
+		let (dx, _) = pallet_stableswap::Pallet::<Runtime>::simulate_buy(
+			pool_id,
+			asset_in,
+			asset_out,
+			dy,
+			u128::MAX,
+			Some(snapshot),
+		)
+		.map_err(|e| revert(alloc::format!("Simulation failed: {e:?}")))?;
+
+		Ok(dx.into())
+	}
+
+	/// Sells `dx` of coin `i` for at least `min_dy` of coin `j`. Returns the received amount of coin `j`.
+	#[precompile::public("exchange(int128,int128,uint256,uint256)")]
+	fn exchange(handle: &mut impl PrecompileHandle, i: i128, j: i128, dx: U256, min_dy: U256) -> EvmResult<U256> {
+		let (pool_id, snapshot) = Self::snapshot(handle)?;
+		let asset_in = snapshot.assets[Self::to_index(i, snapshot.assets.len(), "i")?];
+		let asset_out = snapshot.assets[Self::to_index(j, snapshot.assets.len(), "j")?];
+
+		let call = pallet_stableswap::Call::<Runtime>::sell {
+			pool_id,
+			asset_in,
+			asset_out,
+			amount_in: Self::to_balance(dx, "dx")?,
+			min_buy_amount: Self::to_balance(min_dy, "min_dy")?,
+		};
+
+		Self::dispatch_and_track_balance(handle, call, asset_out)
+	}
+
+	/// Adds liquidity of `amounts` of every coin of the pool and mints at least `min_mint_amount` of shares.
+	/// Returns the amount of minted shares.
+	#[precompile::public("add_liquidity(uint256[],uint256)")]
+	fn add_liquidity(
+		handle: &mut impl PrecompileHandle,
+		amounts: BoundedVec<U256, frame_support::traits::ConstU32<MAX_ASSETS_IN_POOL>>,
+		min_mint_amount: U256,
+	) -> EvmResult<U256> {
+		let (pool_id, snapshot) = Self::snapshot(handle)?;
+		let amounts: Vec<U256> = amounts.into();
+
+		if amounts.len() != snapshot.assets.len() {
+			return Err(RevertReason::custom("Amounts do not match pool coins")
+				.in_field("amounts")
+				.into());
+		}
+
+		let mut assets = Vec::with_capacity(amounts.len());
+		for (index, (asset_id, amount)) in snapshot.assets.iter().zip(amounts).enumerate() {
+			let amount: u128 = amount.try_into().map_err(|_| {
+				RevertReason::value_is_too_large("balance type")
+					.in_array(index)
+					.in_field("amounts")
+			})?;
+			if amount > 0 {
+				assets.push(AssetAmount::new(*asset_id, amount));
+			}
+		}
+
+		let call = pallet_stableswap::Call::<Runtime>::add_assets_liquidity {
+			pool_id,
+			assets: frame_support::BoundedVec::truncate_from(assets),
+			min_shares: Self::to_balance(min_mint_amount, "min_mint_amount")?,
+		};
+
+		Self::dispatch_and_track_balance(handle, call, pool_id)
+	}
+
+	/// Burns `token_amount` of shares for at least `min_amount` of coin `i`. Returns the received amount of coin `i`.
+	#[precompile::public("remove_liquidity_one_coin(uint256,int128,uint256)")]
+	fn remove_liquidity_one_coin(
+		handle: &mut impl PrecompileHandle,
+		token_amount: U256,
+		i: i128,
+		min_amount: U256,
+	) -> EvmResult<U256> {
+		let (pool_id, snapshot) = Self::snapshot(handle)?;
+		let asset_id = snapshot.assets[Self::to_index(i, snapshot.assets.len(), "i")?];
+
+		let call = pallet_stableswap::Call::<Runtime>::remove_liquidity_one_asset {
+			pool_id,
+			asset_id,
+			share_amount: Self::to_balance(token_amount, "token_amount")?,
+			min_amount_out: Self::to_balance(min_amount, "min_amount")?,
+		};
+
+		Self::dispatch_and_track_balance(handle, call, asset_id)
+	}
+
+	/// Returns the pool id of the called precompile together with the current state of the pool.
+	fn snapshot(
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<(
+		<Runtime as pallet_stableswap::Config>::AssetId,
+		PoolSnapshot<<Runtime as pallet_stableswap::Config>::AssetId>,
+	)> {
+		let pool_id = pool_id_from_address(handle.code_address()).ok_or_else(|| revert("Pool not found"))?;
+
+		// Pool info, share issuance, pegs and reserves of the pool assets are read
+		handle.record_cost(
+			RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(3u64.saturating_add(MAX_ASSETS_IN_POOL.into())),
+		)?;
+
+		let pool_id = pool_id.into();
+		let snapshot =
+			pallet_stableswap::Pallet::<Runtime>::create_snapshot(pool_id).ok_or_else(|| revert("Pool not found"))?;
+
+		Ok((pool_id, snapshot))
+	}
+
+	/// Dispatches the stableswap call with the caller as signed origin.
+	/// Returns the amount of `asset` received by the caller.
+	fn dispatch_and_track_balance(
+		handle: &mut impl PrecompileHandle,
+		call: pallet_stableswap::Call<Runtime>,
+		asset: <Runtime as pallet_stableswap::Config>::AssetId,
+	) -> EvmResult<U256> {
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		// Balance of the received asset is read before and after the call
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+
+		let balance_before = <Runtime as pallet_stableswap::Config>::Currency::free_balance(asset, &who);
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(who.clone()).into(), call, 0)?;
+
+		let balance_after = <Runtime as pallet_stableswap::Config>::Currency::free_balance(asset, &who);
+
+		Ok(balance_after.saturating_sub(balance_before).into())
+	}
+
+	fn to_index<I: TryInto<usize>>(index: I, n_coins: usize, field: &'static str) -> EvmResult<usize> {
+		index
+			.try_into()
+			.ok()
+			.filter(|index| *index < n_coins)
+			.ok_or_else(|| RevertReason::custom("Coin index out of bounds").in_field(field).into())
+	}
+
+	fn to_balance(value: U256, field: &'static str) -> EvmResult<u128> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field(field).into())
+	}
+}
@@ -0,0 +1,440 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2025  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+//! Test utilities
+use super::*;
+
+use frame_support::traits::{Everything, Nothing};
+use frame_support::{assert_ok, construct_runtime, parameter_types, weights::Weight};
+use frame_system::EnsureRoot;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{AccountIdFor, AssetKind, Inspect, RawEntry};
+use orml_traits::parameter_type_with_key;
+use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, FrameSystemAccountProvider, IsPrecompileResult, Precompile};
+use pallet_stableswap::traits::PegRawOracle;
+use pallet_stableswap::types::{BoundedPegSources, PegSource};
+use precompile_utils::{mock_account, testing::MockAccount};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::{BuildStorage, DispatchError, Perbill, Permill};
+use sp_std::num::NonZeroU16;
+use sp_std::ops::RangeInclusive;
+
+pub type AccountId = MockAccount;
+pub type AssetId = u32;
+pub type Balance = u128;
+pub type Amount = i128;
+
+type Block = frame_system::mocking::MockBlockU32<Runtime>;
+
+pub const DAI: AssetId = 2;
+pub const USDC: AssetId = 3;
+pub const USDT: AssetId = 4;
+pub const POOL_ID: AssetId = 100;
+pub const PEGGED_POOL_ID: AssetId = 101;
+
+pub const ONE: Balance = 1_000_000_000_000_000_000;
+pub const POOL_LIQUIDITY: Balance = 1_000_000 * ONE;
+
+pub const AMPLIFICATION: u16 = 100;
+pub const FEE: Permill = Permill::from_percent(1);
+
+/// Peg of `USDT` to `DAI` in the pegged pool.
+pub const USDT_PEG: (Balance, Balance) = (2, 1);
+
+construct_runtime!(
+	pub enum Runtime	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		Evm: pallet_evm,
+		Timestamp: pallet_timestamp,
+		Broadcast: pallet_broadcast,
+		Stableswap: pallet_stableswap,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+	type ExtensionsWeightInfo = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 0;
+}
+
+impl pallet_balances::Config for Runtime {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 4];
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeFreezeReason = ();
+	type DoneSlashHandler = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = ();
+	type MaxReserves = ();
+	type CurrencyHooks = ();
+}
+
+mock_account!(Provider, |_| MockAccount::from_u64(1));
+
+/// Executes the stableswap precompile at the addresses of stableswap pools, same as the runtime.
+pub struct Precompiles<R>(PhantomData<R>);
+
+impl<R> pallet_evm::PrecompileSet for Precompiles<R>
+where
+	StableswapPrecompile<R, AssetAddressMock>: Precompile,
+{
+	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<pallet_evm::PrecompileResult> {
+		is_pool_address(handle.code_address()).then(|| StableswapPrecompile::<R, AssetAddressMock>::execute(handle))
+	}
+
+	fn is_precompile(&self, address: H160, _remaining_gas: u64) -> IsPrecompileResult {
+		IsPrecompileResult::Answer {
+			is_precompile: is_pool_address(address),
+			extra_cost: 0,
+		}
+	}
+}
+
+pub type PCall = StableswapPrecompileCall<Runtime, AssetAddressMock>;
+
+parameter_types! {
+	pub PrecompilesValue: Precompiles<Runtime> = Precompiles(PhantomData);
+	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+}
+
+impl pallet_evm::Config for Runtime {
+	type AccountProvider = FrameSystemAccountProvider<Runtime>;
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = AccountId;
+	type Currency = Balances;
+	type PrecompilesType = Precompiles<Runtime>;
+	type PrecompilesValue = PrecompilesValue;
+	type ChainId = ();
+	type BlockGasLimit = ();
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type OnChargeTransaction = ();
+	type OnCreate = ();
+	type FindAuthor = ();
+	type GasLimitPovSizeRatio = ();
+	type GasLimitStorageGrowthRatio = ();
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+	type CreateOriginFilter = ();
+	type CreateInnerOriginFilter = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+impl pallet_broadcast::Config for Runtime {}
+
+parameter_types! {
+	pub const MinimumLiquidity: Balance = 1_000_000;
+	pub const MinimumTradingLimit: Balance = 1_000;
+	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+}
+
+impl pallet_stableswap::Config for Runtime {
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type ShareAccountId = AccountIdConstructor;
+	type AssetInspection = RegistryMock;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type UpdateTradabilityOrigin = EnsureRoot<AccountId>;
+	type MinPoolLiquidity = MinimumLiquidity;
+	type AmplificationRange = AmplificationRange;
+	type MinTradingLimit = MinimumTradingLimit;
+	type WeightInfo = ();
+	type BlockNumberProvider = System;
+	type DustAccountHandler = DustAccountHandlerMock;
+	type Hooks = ();
+	type AccountTradeVolumeHandler = ();
+	type TargetPegOracle = PegOracleMock;
+}
+
+/// Maps asset ids to ERC20 addresses the same way as the runtime does for assets without a contract.
+pub struct AssetAddressMock;
+
+impl Erc20Mapping<AssetId> for AssetAddressMock {
+	fn asset_address(asset_id: AssetId) -> H160 {
+		let mut address = [0u8; 20];
+		address[15] = 1;
+		address[16..].copy_from_slice(&asset_id.to_be_bytes());
+		H160::from(address)
+	}
+
+	fn address_to_asset(address: H160) -> Option<AssetId> {
+		let bytes = address.to_fixed_bytes();
+		if bytes[..16] != Self::asset_address(0).to_fixed_bytes()[..16] {
+			return None;
+		}
+
+		let mut asset_id = [0u8; 4];
+		asset_id.copy_from_slice(&bytes[16..]);
+		Some(u32::from_be_bytes(asset_id))
+	}
+}
+
+/// Every asset is registered with 18 decimals.
+pub struct RegistryMock;
+
+impl Inspect for RegistryMock {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn exists(_asset_id: AssetId) -> bool {
+		true
+	}
+
+	fn decimals(_asset_id: AssetId) -> Option<u8> {
+		Some(18)
+	}
+
+	fn is_sufficient(_asset_id: AssetId) -> bool {
+		true
+	}
+
+	fn asset_type(_asset_id: AssetId) -> Option<AssetKind> {
+		Some(AssetKind::Token)
+	}
+
+	fn is_banned(_asset_id: AssetId) -> bool {
+		false
+	}
+
+	fn asset_name(_asset_id: AssetId) -> Option<Vec<u8>> {
+		None
+	}
+
+	fn asset_symbol(_asset_id: AssetId) -> Option<Vec<u8>> {
+		None
+	}
+
+	fn existential_deposit(_asset_id: AssetId) -> Option<u128> {
+		Some(0)
+	}
+}
+
+pub struct AccountIdConstructor;
+
+impl AccountIdFor<AssetId> for AccountIdConstructor {
+	type AccountId = AccountId;
+
+	fn from_assets(asset: &AssetId, _identifier: Option<&[u8]>) -> Self::AccountId {
+		MockAccount::from_u64(*asset as u64 * 1_000)
+	}
+
+	fn name(asset: &AssetId, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf = identifier.map(|ident| ident.to_vec()).unwrap_or_default();
+		buf.extend_from_slice(&asset.to_le_bytes());
+		buf
+	}
+}
+
+pub struct DustAccountHandlerMock;
+
+impl DustRemovalAccountWhitelist<AccountId> for DustAccountHandlerMock {
+	type Error = DispatchError;
+
+	fn add_account(_account: &AccountId) -> Result<(), Self::Error> {
+		Ok(())
+	}
+
+	fn remove_account(_account: &AccountId) -> Result<(), Self::Error> {
+		Ok(())
+	}
+}
+
+/// Supports fixed peg values only.
+pub struct PegOracleMock;
+
+impl PegRawOracle<AssetId, Balance, u32> for PegOracleMock {
+	type Error = ();
+
+	fn get_raw_entry(_peg_asset: AssetId, source: PegSource<AssetId>) -> Result<RawEntry<Balance, u32>, Self::Error> {
+		match source {
+			PegSource::Value(peg) => Ok(RawEntry {
+				price: peg,
+				volume: Default::default(),
+				liquidity: Default::default(),
+				shares_issuance: Default::default(),
+				updated_at: System::block_number(),
+			}),
+			_ => Err(()),
+		}
+	}
+}
+
+pub(crate) struct ExtBuilder {
+	// endowed accounts with balances
+	balances: Vec<(AccountId, AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> ExtBuilder {
+		ExtBuilder {
+			balances: vec![
+				(Provider.into(), DAI, 3 * POOL_LIQUIDITY),
+				(Provider.into(), USDC, 3 * POOL_LIQUIDITY),
+				(Provider.into(), USDT, 3 * POOL_LIQUIDITY),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.balances.extend(balances);
+		self
+	}
+
+	/// Creates a plain pool of `DAI`, `USDC` and `USDT` and a pool of `DAI` and `USDT` with `USDT` pegged
+	/// to [`USDT_PEG`]. Both pools are provided with liquidity by `Provider`.
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.expect("Frame system builds valid default genesis config");
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.expect("Tokens storage can be assimilated");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				POOL_ID,
+				frame_support::BoundedVec::truncate_from(vec![DAI, USDC, USDT]),
+				AMPLIFICATION,
+				FEE,
+			));
+			assert_ok!(Stableswap::add_assets_liquidity(
+				RuntimeOrigin::signed(Provider.into()),
+				POOL_ID,
+				frame_support::BoundedVec::truncate_from(vec![
+					AssetAmount::new(DAI, POOL_LIQUIDITY),
+					AssetAmount::new(USDC, POOL_LIQUIDITY),
+					AssetAmount::new(USDT, POOL_LIQUIDITY),
+				]),
+				0,
+			));
+
+			assert_ok!(Stableswap::create_pool_with_pegs(
+				RuntimeOrigin::root(),
+				PEGGED_POOL_ID,
+				frame_support::BoundedVec::truncate_from(vec![DAI, USDT]),
+				AMPLIFICATION,
+				FEE,
+				BoundedPegSources::truncate_from(vec![PegSource::Value((1, 1)), PegSource::Value(USDT_PEG)]),
+				Perbill::from_percent(100),
+			));
+			assert_ok!(Stableswap::add_assets_liquidity(
+				RuntimeOrigin::signed(Provider.into()),
+				PEGGED_POOL_ID,
+				frame_support::BoundedVec::truncate_from(vec![
+					AssetAmount::new(DAI, POOL_LIQUIDITY),
+					AssetAmount::new(USDT, POOL_LIQUIDITY / 2),
+				]),
+				0,
+			));
+		});
+		ext
+	}
+}
+
+pub fn balance(who: impl Into<AccountId>, asset: AssetId) -> Balance {
+	<Tokens as MultiCurrency<AccountId>>::free_balance(asset, &who.into())
+}
@@ -0,0 +1,403 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2025  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::mock::*;
+use crate::{pool_address, pool_id_from_address, FEE_PRECISION};
+use frame_support::assert_ok;
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use hydradx_traits::evm::Erc20Mapping;
+use hydradx_traits::stableswap::AssetAmount;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, U256};
+use sp_runtime::{DispatchError, FixedPointNumber};
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn pool() -> H160 {
+	pool_address(POOL_ID)
+}
+
+fn pegged_pool() -> H160 {
+	pool_address(PEGGED_POOL_ID)
+}
+
+fn simulate_sell(pool_id: AssetId, asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> Balance {
+	pallet_stableswap::Pallet::<Runtime>::simulate_sell(pool_id, asset_in, asset_out, amount_in, 0, None)
+		.expect("Simulation succeeds")
+		.0
+}
+
+/// Executes `f` and reverts all storage changes made by it.
+fn dry_run<T>(f: impl FnOnce() -> T) -> T {
+	with_transaction(|| TransactionOutcome::Rollback(Ok::<_, DispatchError>(f()))).expect("Dry run succeeds")
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::coins_selectors().contains(&0xc6610657));
+	assert!(PCall::balances_selectors().contains(&0x4903b0d1));
+	assert!(PCall::n_coins_selectors().contains(&0x29357750));
+	assert!(PCall::a_selectors().contains(&0xf446c1d0));
+	assert!(PCall::fee_selectors().contains(&0xddca3f43));
+	assert!(PCall::get_virtual_price_selectors().contains(&0xbb7b8b80));
+	assert!(PCall::get_dy_selectors().contains(&0x5e0d443f));
+	assert!(PCall::get_dx_selectors().contains(&0x67df02ca));
+	assert!(PCall::exchange_selectors().contains(&0x3df02124));
+	assert!(PCall::add_liquidity_selectors().contains(&0xb72df5de));
+	assert!(PCall::remove_liquidity_one_coin_selectors().contains(&0x1a4d01d2));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, pool());
+
+		tester.test_view_modifier(PCall::coins_selectors());
+		tester.test_view_modifier(PCall::balances_selectors());
+		tester.test_view_modifier(PCall::n_coins_selectors());
+		tester.test_view_modifier(PCall::a_selectors());
+		tester.test_view_modifier(PCall::fee_selectors());
+		tester.test_view_modifier(PCall::get_virtual_price_selectors());
+		tester.test_view_modifier(PCall::get_dy_selectors());
+		tester.test_view_modifier(PCall::get_dx_selectors());
+		tester.test_default_modifier(PCall::exchange_selectors());
+		tester.test_default_modifier(PCall::add_liquidity_selectors());
+		tester.test_default_modifier(PCall::remove_liquidity_one_coin_selectors());
+	});
+}
+
+#[test]
+fn pool_address_should_encode_pool_id() {
+	assert_eq!(
+		pool_address(POOL_ID),
+		H160([0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100])
+	);
+	assert_eq!(pool_id_from_address(pool_address(POOL_ID)), Some(POOL_ID));
+	assert_eq!(pool_id_from_address(AssetAddressMock::asset_address(POOL_ID)), None);
+}
+
+#[test]
+fn pool_info_should_be_returned() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, pool(), PCall::coins { i: 2.into() })
+			.execute_returns(Address(AssetAddressMock::asset_address(USDT)));
+
+		precompiles()
+			.prepare_test(Alice, pool(), PCall::balances { i: 1.into() })
+			.execute_returns(U256::from(POOL_LIQUIDITY));
+
+		precompiles()
+			.prepare_test(Alice, pool(), PCall::n_coins {})
+			.execute_returns(U256::from(3));
+
+		precompiles()
+			.prepare_test(Alice, pool(), PCall::a {})
+			.execute_returns(U256::from(AMPLIFICATION));
+
+		precompiles()
+			.prepare_test(Alice, pool(), PCall::fee {})
+			.execute_returns(U256::from(FEE_PRECISION / 100));
+	});
+}
+
+#[test]
+fn coins_should_revert_when_index_is_out_of_bounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, pool(), PCall::coins { i: 3.into() })
+			.execute_reverts(|output| output == b"i: Coin index out of bounds");
+	});
+}
+
+#[test]
+fn call_should_revert_when_pool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, pool_address(POOL_ID + 10), PCall::n_coins {})
+			.execute_reverts(|output| output == b"Pool not found");
+	});
+}
+
+#[test]
+fn get_virtual_price_should_be_one_for_balanced_pool() {
+	ExtBuilder::default().build().execute_with(|| {
+		let snapshot = pallet_stableswap::Pallet::<Runtime>::create_snapshot(POOL_ID).unwrap();
+		let virtual_price = pallet_stableswap::Pallet::<Runtime>::virtual_price(&snapshot).unwrap();
+
+		precompiles()
+			.prepare_test(Alice, pool(), PCall::get_virtual_price {})
+			.execute_returns(U256::from(virtual_price.into_inner()));
+	});
+}
+
+#[test]
+fn get_dy_should_return_simulated_sell() {
+	ExtBuilder::default().build().execute_with(|| {
+		let expected = simulate_sell(POOL_ID, DAI, USDT, 1_000 * ONE);
+		assert!(expected > 0);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				pool(),
+				PCall::get_dy {
+					i: 0,
+					j: 2,
+					dx: (1_000 * ONE).into(),
+				},
+			)
+			.execute_returns(U256::from(expected));
+	});
+}
+
+#[test]
+fn get_dy_should_respect_pegs() {
+	ExtBuilder::default().build().execute_with(|| {
+		let expected = simulate_sell(PEGGED_POOL_ID, USDT, DAI, 1_000 * ONE);
+		// USDT is pegged to two DAI
+		assert!(expected > 1_900 * ONE && expected < 2_000 * ONE);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				pegged_pool(),
+				PCall::get_dy {
+					i: 1,
+					j: 0,
+					dx: (1_000 * ONE).into(),
+				},
+			)
+			.execute_returns(U256::from(expected));
+	});
+}
+
+#[test]
+fn get_dy_should_revert_when_index_is_negative() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				pool(),
+				PCall::get_dy {
+					i: 0,
+					j: -1,
+					dx: ONE.into(),
+				},
+			)
+			.execute_reverts(|output| output == b"j: Coin index out of bounds");
+	});
+}
+
+#[test]
+fn get_dx_should_return_simulated_buy() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (expected, _) =
+			pallet_stableswap::Pallet::<Runtime>::simulate_buy(POOL_ID, DAI, USDC, 1_000 * ONE, u128::MAX, None)
+				.unwrap();
+		assert!(expected > 1_000 * ONE);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				pool(),
+				PCall::get_dx {
+					i: 0,
+					j: 1,
+					dy: (1_000 * ONE).into(),
+				},
+			)
+			.execute_returns(U256::from(expected));
+	});
+}
+
+#[test]
+fn exchange_should_work() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), DAI, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			let expected = simulate_sell(POOL_ID, DAI, USDT, 1_000 * ONE);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					pool(),
+					PCall::exchange {
+						i: 0,
+						j: 2,
+						dx: (1_000 * ONE).into(),
+						min_dy: expected.into(),
+					},
+				)
+				.execute_returns(U256::from(expected));
+
+			assert_eq!(balance(Alice, DAI), 0);
+			assert_eq!(balance(Alice, USDT), expected);
+		});
+}
+
+#[test]
+fn exchange_should_work_with_pegged_pool() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), USDT, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			let expected = simulate_sell(PEGGED_POOL_ID, USDT, DAI, 1_000 * ONE);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					pegged_pool(),
+					PCall::exchange {
+						i: 1,
+						j: 0,
+						dx: (1_000 * ONE).into(),
+						min_dy: 0.into(),
+					},
+				)
+				.execute_returns(U256::from(expected));
+
+			assert_eq!(balance(Alice, USDT), 0);
+			assert_eq!(balance(Alice, DAI), expected);
+		});
+}
+
+#[test]
+fn exchange_should_revert_when_min_dy_is_not_reached() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), DAI, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			let expected = simulate_sell(POOL_ID, DAI, USDT, 1_000 * ONE);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					pool(),
+					PCall::exchange {
+						i: 0,
+						j: 2,
+						dx: (1_000 * ONE).into(),
+						min_dy: (expected + 1).into(),
+					},
+				)
+				.execute_reverts(|output| output.starts_with(b"Dispatched call failed with error: "));
+
+			assert_eq!(balance(Alice, DAI), 1_000 * ONE);
+		});
+}
+
+#[test]
+fn add_liquidity_should_work() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(Alice.into(), DAI, 1_000 * ONE),
+			(Alice.into(), USDT, 1_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			let expected = dry_run(|| {
+				assert_ok!(Stableswap::add_assets_liquidity(
+					RuntimeOrigin::signed(Alice.into()),
+					POOL_ID,
+					frame_support::BoundedVec::truncate_from(vec![
+						AssetAmount::new(DAI, 1_000 * ONE),
+						AssetAmount::new(USDT, 1_000 * ONE),
+					]),
+					0,
+				));
+				balance(Alice, POOL_ID)
+			});
+			assert!(expected > 0);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					pool(),
+					PCall::add_liquidity {
+						amounts: vec![U256::from(1_000 * ONE), U256::zero(), U256::from(1_000 * ONE)].into(),
+						min_mint_amount: expected.into(),
+					},
+				)
+				.execute_returns(U256::from(expected));
+
+			assert_eq!(balance(Alice, POOL_ID), expected);
+			assert_eq!(balance(Alice, DAI), 0);
+			assert_eq!(balance(Alice, USDC), 0);
+			assert_eq!(balance(Alice, USDT), 0);
+		});
+}
+
+#[test]
+fn add_liquidity_should_revert_when_amounts_do_not_match_coins() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), DAI, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					pool(),
+					PCall::add_liquidity {
+						amounts: vec![U256::from(1_000 * ONE)].into(),
+						min_mint_amount: 0.into(),
+					},
+				)
+				.execute_reverts(|output| output == b"amounts: Amounts do not match pool coins");
+		});
+}
+
+#[test]
+fn remove_liquidity_one_coin_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let shares = balance(Provider, POOL_ID) / 10;
+		let usdc_before = balance(Provider, USDC);
+		let expected = dry_run(|| {
+			assert_ok!(Stableswap::remove_liquidity_one_asset(
+				RuntimeOrigin::signed(Provider.into()),
+				POOL_ID,
+				USDC,
+				shares,
+				0,
+			));
+			balance(Provider, USDC) - usdc_before
+		});
+		assert!(expected > 0);
+
+		precompiles()
+			.prepare_test(
+				Provider,
+				pool(),
+				PCall::remove_liquidity_one_coin {
+					token_amount: shares.into(),
+					i: 1,
+					min_amount: expected.into(),
+				},
+			)
+			.execute_returns(U256::from(expected));
+
+		assert_eq!(balance(Provider, USDC), usdc_before + expected);
+		assert_eq!(balance(Provider, POOL_ID), 9 * shares);
+	});
+}
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: Apache-2.0
+pragma solidity >=0.8.3;
+
+/// @dev The Staking contract's address.
+address constant STAKING_ADDRESS = 0x0000000000000000000000000000000000000808;
+
+/// @dev The Staking contract's instance.
+Staking constant STAKING_CONTRACT = Staking(STAKING_ADDRESS);
+
+/// @title Staking Interface
+/// @dev Stakes HDX in the staking pallet and HDX into GIGAHDX, and votes in referenda on behalf of the caller.
+/// Amounts are in the smallest unit of HDX or GIGAHDX.
+/// @custom:address 0x0000000000000000000000000000000000000808
+interface Staking {
+    /// @dev Emitted when a new staking position is created.
+    event Staked(address indexed who, uint256 positionId, uint256 amount);
+
+    /// @dev Emitted when stake of a position is increased.
+    event StakeIncreased(address indexed who, uint256 positionId, uint256 amount);
+
+    /// @dev Emitted when rewards of a position are paid to the caller.
+    event RewardsClaimed(address indexed who, uint256 positionId, uint256 amount);
+
+    /// @dev Emitted when a position is destroyed and its stake unlocked.
+    event Unstaked(address indexed who, uint256 positionId, uint256 unlockedStake);
+
+    /// @dev Emitted when HDX is staked into GIGAHDX.
+    event GigaStaked(address indexed who, uint256 amount, uint256 gigahdx);
+
+    /// @dev Emitted when GIGAHDX is unstaked.
+    event GigaUnstaked(address indexed who, uint256 gigahdxAmount, uint256 payout);
+
+    /// @dev Emitted when the caller votes aye on a poll.
+    event VotedYes(address indexed who, uint256 pollIndex, uint256 amount);
+
+    /// @dev Emitted when the caller votes nay on a poll.
+    event VotedNo(address indexed who, uint256 pollIndex, uint256 amount);
+
+    /// @dev Emitted when a vote of the caller is removed.
+    event VoteRemoved(address indexed who, uint256 pollIndex);
+
+    /// @dev Stakes `amount` of HDX into a new staking position.
+    /// @param amount Amount of HDX to stake.
+    /// @return positionId Id of the created position.
+    /// @custom:selector a694fc3a
+    function stake(uint256 amount) external returns (uint256 positionId);
+
+    /// @dev Adds `amount` of HDX to the stake of a position owned by the caller.
+    /// @param positionId Id of the position.
+    /// @param amount Amount of HDX to add.
+    /// @custom:selector bec10cde
+    function increaseStake(uint256 positionId, uint256 amount) external;
+
+    /// @dev Claims rewards of a position owned by the caller.
+    /// @param positionId Id of the position.
+    /// @return paidRewards Amount of HDX paid to the caller.
+    /// @custom:selector 379607f5
+    function claim(uint256 positionId) external returns (uint256 paidRewards);
+
+    /// @dev Pays rewards and unlocks the whole stake of a position owned by the caller.
+    /// The position is destroyed.
+    /// @param positionId Id of the position.
+    /// @return paidRewards Amount of HDX paid to the caller.
+    /// @custom:selector 2e17de78
+    function unstake(uint256 positionId) external returns (uint256 paidRewards);
+
+    /// @dev Stakes `amount` of HDX into GIGAHDX.
+    /// @param amount Amount of HDX to stake.
+    /// @return gigahdx Amount of received GIGAHDX.
+    /// @custom:selector e3493038
+    function gigaStake(uint256 amount) external returns (uint256 gigahdx);
+
+    /// @dev Unstakes `gigahdxAmount` of GIGAHDX. The payout stays locked until the cooldown period elapses.
+    /// @param gigahdxAmount Amount of GIGAHDX to unstake.
+    /// @return payout Amount of HDX paid out for the unstaked GIGAHDX.
+    /// @custom:selector aab46a46
+    function gigaUnstake(uint256 gigahdxAmount) external returns (uint256 payout);
+
+    /// @dev Votes aye on a poll. Votes of accounts with a staking position are rewarded by the staking pallet.
+    /// @param pollIndex Index of the poll (referendum).
+    /// @param amount Amount of HDX to vote with.
+    /// @param conviction Conviction of the vote, 0 (0.1x, no lock) to 6 (6x).
+    /// @custom:selector da9df518
+    function voteYes(uint32 pollIndex, uint256 amount, uint8 conviction) external;
+
+    /// @dev Votes nay on a poll. Votes of accounts with a staking position are rewarded by the staking pallet.
+    /// @param pollIndex Index of the poll (referendum).
+    /// @param amount Amount of HDX to vote with.
+    /// @param conviction Conviction of the vote, 0 (0.1x, no lock) to 6 (6x).
+    /// @custom:selector cc600eba
+    function voteNo(uint32 pollIndex, uint256 amount, uint8 conviction) external;
+
+    /// @dev Removes the vote of the caller on a poll. The voted HDX stays locked until `unlock` is called.
+    /// @param pollIndex Index of the poll (referendum).
+    /// @custom:selector 79cae220
+    function removeVote(uint32 pollIndex) external;
+
+    /// @dev Removes expired vote locks of the caller on a track.
+    /// @param trackId Track of the polls the caller voted on.
+    /// @custom:selector 265a1d16
+    function unlock(uint16 trackId) external;
+
+    /// @dev Returns the staking position of `who`. Reverts if `who` has no position.
+    /// @param who Owner of the position.
+    /// @return positionId Id of the position.
+    /// @return stake Staked amount of HDX.
+    /// @return lockedRewards Rewards locked in the position.
+    /// @return createdAt Block number the position was created at.
+    /// @custom:selector fd2d39c5
+    function positionOf(address who)
+        external
+        view
+        returns (uint256 positionId, uint256 stake, uint256 lockedRewards, uint256 createdAt);
+
+    /// @dev Returns the amount of rewards `claim` would pay for a position now.
+    /// @param positionId Id of the position.
+    /// @return rewards Claimable rewards.
+    /// @custom:selector 7dcb2abf
+    function pendingRewards(uint256 positionId) external view returns (uint256 rewards);
+
+    /// @dev Returns the GIGAHDX stake of `who`.
+    /// @param who Staker.
+    /// @return hdx HDX locked as active stake.
+    /// @return gigahdx GIGAHDX backed by the stake.
+    /// @return unstaking HDX in pending unstakes.
+    /// @custom:selector 02e2275f
+    function gigaStakeOf(address who) external view returns (uint256 hdx, uint256 gigahdx, uint256 unstaking);
+
+    /// @dev Returns the amount of HDX one GIGAHDX is worth, with 18 decimals of precision.
+    /// @custom:selector 3ba0b9a9
+    function exchangeRate() external view returns (uint256 rate);
+}
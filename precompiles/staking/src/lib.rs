@@ -0,0 +1,450 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2025  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::MaxEncodedLen;
+use core::marker::PhantomData;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::{Currency, Get, Polling};
+use orml_traits::MultiCurrency;
+use pallet_conviction_voting::{AccountVote, Conviction, TallyOf, Vote};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_runtime::SaturatedConversion;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Precision of the GIGAHDX exchange rate returned by `exchangeRate()`.
+pub const EXCHANGE_RATE_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+/// Solidity selector of the Staked log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_STAKED: [u8; 32] = keccak256!("Staked(address,uint256,uint256)");
+
+/// Solidity selector of the StakeIncreased log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_STAKE_INCREASED: [u8; 32] = keccak256!("StakeIncreased(address,uint256,uint256)");
+
+/// Solidity selector of the RewardsClaimed log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_REWARDS_CLAIMED: [u8; 32] = keccak256!("RewardsClaimed(address,uint256,uint256)");
+
+/// Solidity selector of the Unstaked log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_UNSTAKED: [u8; 32] = keccak256!("Unstaked(address,uint256,uint256)");
+
+/// Solidity selector of the GigaStaked log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_GIGA_STAKED: [u8; 32] = keccak256!("GigaStaked(address,uint256,uint256)");
+
+/// Solidity selector of the GigaUnstaked log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_GIGA_UNSTAKED: [u8; 32] = keccak256!("GigaUnstaked(address,uint256,uint256)");
+
+/// Solidity selector of the VotedYes log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_VOTED_YES: [u8; 32] = keccak256!("VotedYes(address,uint256,uint256)");
+
+/// Solidity selector of the VotedNo log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_VOTED_NO: [u8; 32] = keccak256!("VotedNo(address,uint256,uint256)");
+
+/// Solidity selector of the VoteRemoved log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_VOTE_REMOVED: [u8; 32] = keccak256!("VoteRemoved(address,uint256)");
+
+/// Every log emitted by the precompile has the caller as indexed topic and one or two `uint256` values as data.
+const LOG_TOPICS: usize = 2;
+const LOG_DATA_LEN: usize = 64;
+const LOG_SINGLE_VALUE_DATA_LEN: usize = 32;
+
+/// Precompile exposing HDX staking (`pallet-staking`), GIGAHDX staking (`pallet-gigahdx`)
+/// and conviction voting (`pallet-conviction-voting`) to EVM accounts.
+///
+/// Calls are dispatched on behalf of the caller, so EVM-only accounts can stake and vote without
+/// a substrate signature. Pallet events are mirrored as EVM logs of the precompile.
+///
+/// Votes of accounts with a staking position are tracked by the staking pallet and rewarded
+/// with action points, the same way as votes submitted via the extrinsic.
+pub struct StakingPrecompile<Runtime>(PhantomData<Runtime>);
+
+type PositionIdOf<Runtime> = <Runtime as pallet_staking::Config>::PositionItemId;
+
+type VotingBalanceOf<Runtime> = <<Runtime as pallet_conviction_voting::Config>::Currency as Currency<
+	<Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+type PollIndexOf<Runtime> = <<Runtime as pallet_conviction_voting::Config>::Polls as Polling<TallyOf<Runtime>>>::Index;
+type ClassOf<Runtime> = <<Runtime as pallet_conviction_voting::Config>::Polls as Polling<TallyOf<Runtime>>>::Class;
+
+#[precompile_utils::precompile]
+impl<Runtime> StakingPrecompile<Runtime>
+where
+	Runtime: pallet_staking::Config + pallet_gigahdx::Config + pallet_conviction_voting::Config + pallet_evm::Config,
+	Runtime::AddressMapping: AddressMapping<Runtime::AccountId>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_staking::Call<Runtime>>
+		+ From<pallet_gigahdx::Call<Runtime>>
+		+ From<pallet_conviction_voting::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	VotingBalanceOf<Runtime>: From<u128>,
+	PollIndexOf<Runtime>: From<u32>,
+	ClassOf<Runtime>: From<u16>,
+{
+	/// Stakes `amount` of HDX into a new staking position. Returns the id of the created position.
+	#[precompile::public("stake(uint256)")]
+	fn stake(handle: &mut impl PrecompileHandle, amount: U256) -> EvmResult<U256> {
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let amount = Self::to_balance(amount, "amount")?;
+
+		handle.record_log_costs_manual(LOG_TOPICS, LOG_DATA_LEN)?;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who.clone()).into(),
+			pallet_staking::Call::<Runtime>::stake { amount },
+			0,
+		)?;
+
+		// Position id of the caller is looked up in the NFT collection
+		handle.record_db_read::<Runtime>(64)?;
+		let position_id: u128 = pallet_staking::Pallet::<Runtime>::get_user_position_id(&who)
+			.ok()
+			.flatten()
+			.ok_or_else(|| revert("Position not found"))?
+			.saturated_into();
+
+		Self::log(handle, SELECTOR_LOG_STAKED, position_id.into(), amount.into())?;
+
+		Ok(position_id.into())
+	}
+
+	/// Adds `amount` of HDX to the stake of position `positionId` owned by the caller.
+	#[precompile::public("increaseStake(uint256,uint256)")]
+	fn increase_stake(handle: &mut impl PrecompileHandle, position_id: U256, amount: U256) -> EvmResult {
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let amount = Self::to_balance(amount, "amount")?;
+
+		handle.record_log_costs_manual(LOG_TOPICS, LOG_DATA_LEN)?;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who).into(),
+			pallet_staking::Call::<Runtime>::increase_stake {
+				position_id: Self::to_position_id(position_id)?,
+				amount,
+			},
+			0,
+		)?;
+
+		Self::log(handle, SELECTOR_LOG_STAKE_INCREASED, position_id, amount.into())
+	}
+
+	/// Claims rewards of position `positionId` owned by the caller. Returns the amount of paid rewards.
+	#[precompile::public("claim(uint256)")]
+	fn claim(handle: &mut impl PrecompileHandle, position_id: U256) -> EvmResult<U256> {
+		handle.record_log_costs_manual(LOG_TOPICS, LOG_DATA_LEN)?;
+
+		let call = pallet_staking::Call::<Runtime>::claim {
+			position_id: Self::to_position_id(position_id)?,
+		};
+		let paid_rewards = Self::dispatch_and_track_native_balance(handle, call)?;
+
+		Self::log(handle, SELECTOR_LOG_REWARDS_CLAIMED, position_id, paid_rewards)?;
+
+		Ok(paid_rewards)
+	}
+
+	/// Pays rewards and unlocks the whole stake of position `positionId` owned by the caller.
+	/// The position is destroyed. Returns the amount of paid rewards.
+	#[precompile::public("unstake(uint256)")]
+	fn unstake(handle: &mut impl PrecompileHandle, position_id: U256) -> EvmResult<U256> {
+		handle.record_log_costs_manual(LOG_TOPICS, LOG_DATA_LEN)?;
+		handle.record_log_costs_manual(LOG_TOPICS, LOG_DATA_LEN)?;
+
+		let id = Self::to_position_id(position_id)?;
+		handle.record_db_read::<Runtime>(pallet_staking::types::Position::<u128>::max_encoded_len())?;
+		let unlocked_stake = pallet_staking::Pallet::<Runtime>::get_position(id)
+			.map(|position| position.get_stake())
+			.unwrap_or_default();
+
+		let call = pallet_staking::Call::<Runtime>::unstake { position_id: id };
+		let paid_rewards = Self::dispatch_and_track_native_balance(handle, call)?;
+
+		Self::log(handle, SELECTOR_LOG_REWARDS_CLAIMED, position_id, paid_rewards)?;
+		Self::log(handle, SELECTOR_LOG_UNSTAKED, position_id, unlocked_stake.into())?;
+
+		Ok(paid_rewards)
+	}
+
+	/// Stakes `amount` of HDX into GIGAHDX. Returns the amount of received GIGAHDX.
+	#[precompile::public("gigaStake(uint256)")]
+	fn giga_stake(handle: &mut impl PrecompileHandle, amount: U256) -> EvmResult<U256> {
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let amount = Self::to_balance(amount, "amount")?;
+
+		handle.record_log_costs_manual(LOG_TOPICS, LOG_DATA_LEN)?;
+
+		// Stake record of the caller is read before and after the call
+		handle.record_db_read::<Runtime>(2 * pallet_gigahdx::StakeRecord::max_encoded_len())?;
+		let gigahdx_before = pallet_gigahdx::Pallet::<Runtime>::locked_gigahdx(&who);
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who.clone()).into(),
+			pallet_gigahdx::Call::<Runtime>::giga_stake { amount },
+			0,
+		)?;
+
+		let gigahdx = pallet_gigahdx::Pallet::<Runtime>::locked_gigahdx(&who).saturating_sub(gigahdx_before);
+
+		Self::log(handle, SELECTOR_LOG_GIGA_STAKED, amount.into(), gigahdx.into())?;
+
+		Ok(gigahdx.into())
+	}
+
+	/// Unstakes `gigahdxAmount` of GIGAHDX. The HDX payout stays locked until the cooldown period elapses.
+	/// Returns the amount of HDX paid out for the unstaked GIGAHDX.
+	#[precompile::public("gigaUnstake(uint256)")]
+	fn giga_unstake(handle: &mut impl PrecompileHandle, gigahdx_amount: U256) -> EvmResult<U256> {
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let gigahdx_amount = Self::to_balance(gigahdx_amount, "gigahdxAmount")?;
+
+		handle.record_log_costs_manual(LOG_TOPICS, LOG_DATA_LEN)?;
+
+		// Stake record of the caller is read before and after the call
+		handle.record_db_read::<Runtime>(2 * pallet_gigahdx::StakeRecord::max_encoded_len())?;
+		let unstaking_before = Self::giga_stake_record(&who).unstaking;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who.clone()).into(),
+			pallet_gigahdx::Call::<Runtime>::giga_unstake { gigahdx_amount },
+			0,
+		)?;
+
+		let payout = Self::giga_stake_record(&who).unstaking.saturating_sub(unstaking_before);
+
+		Self::log(handle, SELECTOR_LOG_GIGA_UNSTAKED, gigahdx_amount.into(), payout.into())?;
+
+		Ok(payout.into())
+	}
+
+	/// Votes aye on poll `pollIndex` with `amount` of HDX and `conviction` (0 - 6).
+	#[precompile::public("voteYes(uint32,uint256,uint8)")]
+	fn vote_yes(handle: &mut impl PrecompileHandle, poll_index: u32, amount: U256, conviction: u8) -> EvmResult {
+		Self::vote(handle, poll_index, true, amount, conviction)
+	}
+
+	/// Votes nay on poll `pollIndex` with `amount` of HDX and `conviction` (0 - 6).
+	#[precompile::public("voteNo(uint32,uint256,uint8)")]
+	fn vote_no(handle: &mut impl PrecompileHandle, poll_index: u32, amount: U256, conviction: u8) -> EvmResult {
+		Self::vote(handle, poll_index, false, amount, conviction)
+	}
+
+	/// Removes the vote of the caller on poll `pollIndex`.
+	/// The voted balance stays locked until it is unlocked by `unlock`.
+	#[precompile::public("removeVote(uint32)")]
+	fn remove_vote(handle: &mut impl PrecompileHandle, poll_index: u32) -> EvmResult {
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		handle.record_log_costs_manual(LOG_TOPICS, LOG_SINGLE_VALUE_DATA_LEN)?;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who).into(),
+			pallet_conviction_voting::Call::<Runtime>::remove_vote {
+				class: None,
+				index: poll_index.into(),
+			},
+			0,
+		)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_VOTE_REMOVED,
+			handle.context().caller,
+			solidity::encode_event_data(U256::from(poll_index)),
+		)
+		.record(handle)
+	}
+
+	/// Removes the expired vote locks of the caller on track `trackId`.
+	#[precompile::public("unlock(uint16)")]
+	fn unlock(handle: &mut impl PrecompileHandle, track_id: u16) -> EvmResult {
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who.clone()).into(),
+			pallet_conviction_voting::Call::<Runtime>::unlock {
+				class: track_id.into(),
+				target: <Runtime as frame_system::Config>::Lookup::unlookup(who),
+			},
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	/// Returns the staking position of `who`: position id, staked amount, locked rewards and
+	/// the block number the position was created at.
+	#[precompile::public("positionOf(address)")]
+	#[precompile::view]
+	fn position_of(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<(U256, U256, U256, U256)> {
+		let who = Runtime::AddressMapping::into_account_id(who.into());
+
+		// Position id of the account is looked up in the NFT collection, then the position is read
+		handle.record_db_read::<Runtime>(64 + pallet_staking::types::Position::<u128>::max_encoded_len())?;
+
+		let position_id = pallet_staking::Pallet::<Runtime>::get_user_position_id(&who)
+			.ok()
+			.flatten()
+			.ok_or_else(|| revert("Position not found"))?;
+		let position =
+			pallet_staking::Pallet::<Runtime>::get_position(position_id).ok_or_else(|| revert("Position not found"))?;
+
+		Ok((
+			position_id.saturated_into::<u128>().into(),
+			position.get_stake().into(),
+			position.get_accumulated_locked_rewards().into(),
+			position.get_created_at().saturated_into::<u128>().into(),
+		))
+	}
+
+	/// Returns the amount of rewards which would be paid by `claim` of position `positionId` now.
+	#[precompile::public("pendingRewards(uint256)")]
+	#[precompile::view]
+	fn pending_rewards(handle: &mut impl PrecompileHandle, position_id: U256) -> EvmResult<U256> {
+		// Staking state, the position and the pot balance are read
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(3))?;
+
+		let rewards = pallet_staking::Pallet::<Runtime>::pending_rewards(Self::to_position_id(position_id)?)
+			.ok_or_else(|| revert("Position not found"))?;
+
+		Ok(rewards.into())
+	}
+
+	/// Returns the GIGAHDX stake of `who`: HDX locked as active stake, GIGAHDX backed by the stake
+	/// and HDX in pending unstakes.
+	#[precompile::public("gigaStakeOf(address)")]
+	#[precompile::view]
+	fn giga_stake_of(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<(U256, U256, U256)> {
+		let who = Runtime::AddressMapping::into_account_id(who.into());
+
+		handle.record_db_read::<Runtime>(pallet_gigahdx::StakeRecord::max_encoded_len())?;
+		let record = Self::giga_stake_record(&who);
+
+		Ok((record.hdx.into(), record.gigahdx.into(), record.unstaking.into()))
+	}
+
+	/// Returns the amount of HDX one GIGAHDX is worth, with [`EXCHANGE_RATE_PRECISION`].
+	#[precompile::public("exchangeRate()")]
+	#[precompile::view]
+	fn exchange_rate(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		// Total locked HDX, gigapot balance and GIGAHDX issuance are read
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(3))?;
+
+		let rate = pallet_gigahdx::Pallet::<Runtime>::exchange_rate();
+
+		Ok(U256::from(rate.n) * U256::from(EXCHANGE_RATE_PRECISION) / U256::from(rate.d))
+	}
+
+	/// Dispatches the staking call with the caller as signed origin.
+	/// Returns the amount of HDX received by the caller.
+	fn dispatch_and_track_native_balance(
+		handle: &mut impl PrecompileHandle,
+		call: pallet_staking::Call<Runtime>,
+	) -> EvmResult<U256> {
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let native_asset = <Runtime as pallet_staking::Config>::NativeAssetId::get();
+
+		// Balance of the caller is read before and after the call
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+
+		let balance_before = <Runtime as pallet_staking::Config>::Currency::free_balance(native_asset, &who);
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(who.clone()).into(), call, 0)?;
+
+		let balance_after = <Runtime as pallet_staking::Config>::Currency::free_balance(native_asset, &who);
+
+		Ok(balance_after.saturating_sub(balance_before).into())
+	}
+
+	fn vote(handle: &mut impl PrecompileHandle, poll_index: u32, aye: bool, amount: U256, conviction: u8) -> EvmResult {
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let amount = Self::to_balance(amount, "amount")?;
+		let conviction: Conviction = conviction
+			.try_into()
+			.map_err(|_| RevertReason::custom("Must be an integer between 0 and 6 included").in_field("conviction"))?;
+
+		handle.record_log_costs_manual(LOG_TOPICS, LOG_DATA_LEN)?;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(who).into(),
+			pallet_conviction_voting::Call::<Runtime>::vote {
+				poll_index: poll_index.into(),
+				vote: AccountVote::Standard {
+					vote: Vote { aye, conviction },
+					balance: amount.into(),
+				},
+			},
+			0,
+		)?;
+
+		let selector = if aye {
+			SELECTOR_LOG_VOTED_YES
+		} else {
+			SELECTOR_LOG_VOTED_NO
+		};
+
+		Self::log(handle, selector, poll_index.into(), amount.into())
+	}
+
+	fn giga_stake_record(who: &Runtime::AccountId) -> pallet_gigahdx::StakeRecord {
+		pallet_gigahdx::Stakes::<Runtime>::get(who).unwrap_or_default()
+	}
+
+	fn log(handle: &mut impl PrecompileHandle, selector: [u8; 32], a: U256, b: U256) -> EvmResult {
+		log2(
+			handle.context().address,
+			selector,
+			handle.context().caller,
+			solidity::encode_event_data((a, b)),
+		)
+		.record(handle)
+	}
+
+	fn to_position_id(value: U256) -> EvmResult<PositionIdOf<Runtime>> {
+		let value: u128 = value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("position id type").in_field("positionId"))?;
+
+		value.try_into().map_err(|_| {
+			RevertReason::value_is_too_large("position id type")
+				.in_field("positionId")
+				.into()
+		})
+	}
+
+	fn to_balance(value: U256, field: &'static str) -> EvmResult<u128> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field(field).into())
+	}
+}
@@ -0,0 +1,535 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2025  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+//! Test utilities
+//!
+//! Legacy staking uses `Tokens` (asset [`HDX`]) as its currency while GIGAHDX staking locks
+//! native `Balances`, so accounts are endowed in both.
+use super::*;
+
+use frame_support::traits::{
+	AsEnsureOriginWithArg, ConstU128, ConstU32, Everything, LockIdentifier, NeverEnsureOrigin, PollStatus,
+	TotalIssuanceOf,
+};
+use frame_support::{assert_ok, construct_runtime, pallet_prelude::*, parameter_types, PalletId};
+use frame_system::{EnsureRoot, RawOrigin};
+use hydradx_traits::gigahdx::MoneyMarketOperations;
+use orml_traits::{parameter_type_with_key, GetByKey};
+use pallet_conviction_voting::Tally;
+use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, FrameSystemAccountProvider};
+use pallet_staking::traits::{Freeze, GetReferendumState, VestingDetails};
+use pallet_staking::types::{Action, Point, ReferendumIndex};
+use pallet_staking::SigmoidPercentage;
+use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::{BuildStorage, DispatchError, DispatchResult, FixedU128, Perbill, Permill};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+
+pub type AccountId = MockAccount;
+pub type AssetId = u32;
+pub type Balance = u128;
+pub type Amount = i128;
+pub type BlockNumber = u64;
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+pub const HDX: AssetId = 0;
+pub const ST_HDX: AssetId = 670;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+/// Initial balance of the staking pot, which is never paid out as rewards.
+pub const POT_RESERVE: Balance = 1_000 * ONE;
+
+/// Period length of legacy staking.
+pub const PERIOD_LENGTH: BlockNumber = 10_000;
+
+/// Ongoing poll, which can be voted on.
+pub const ONGOING_POLL: u32 = 0;
+/// Track of `ONGOING_POLL`.
+pub const TRACK_ID: u16 = 0;
+
+construct_runtime!(
+	pub enum Runtime	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		Uniques: pallet_uniques,
+		Evm: pallet_evm,
+		Timestamp: pallet_timestamp,
+		Staking: pallet_staking,
+		GigaHdx: pallet_gigahdx,
+		ConvictionVoting: pallet_conviction_voting,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+	type ExtensionsWeightInfo = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 0;
+}
+
+impl pallet_balances::Config for Runtime {
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type MaxLocks = ConstU32<20>;
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeFreezeReason = ();
+	type DoneSlashHandler = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ConstU32<10>;
+	type DustRemovalWhitelist = Everything;
+	type ReserveIdentifier = ();
+	type MaxReserves = ();
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const UniquesDeposit: Balance = 0;
+	pub const UniquesStringLimit: u32 = 72;
+	pub const UniquesKeyLimit: u32 = 256;
+	pub const UniquesValueLimit: u32 = 1024;
+}
+
+impl pallet_uniques::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u128;
+	type ItemId = u128;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	// Standard collection creation is disallowed
+	type CreateOrigin = AsEnsureOriginWithArg<NeverEnsureOrigin<AccountId>>;
+	type Locker = ();
+	type CollectionDeposit = UniquesDeposit;
+	type ItemDeposit = UniquesDeposit;
+	type MetadataDepositBase = UniquesDeposit;
+	type AttributeDepositBase = UniquesDeposit;
+	type DepositPerByte = UniquesDeposit;
+	type StringLimit = UniquesStringLimit;
+	type KeyLimit = UniquesKeyLimit;
+	type ValueLimit = UniquesValueLimit;
+	type WeightInfo = ();
+
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
+mock_account!(Precompile, |_| MockAccount::from_u64(1));
+
+pub type Precompiles<R> = PrecompileSetBuilder<R, (PrecompileAt<AddressU64<1>, StakingPrecompile<R>>,)>;
+
+pub type PCall = StakingPrecompileCall<Runtime>;
+
+parameter_types! {
+	pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
+	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+}
+
+impl pallet_evm::Config for Runtime {
+	type AccountProvider = FrameSystemAccountProvider<Runtime>;
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = AccountId;
+	type Currency = Balances;
+	type PrecompilesType = Precompiles<Runtime>;
+	type PrecompilesValue = PrecompilesValue;
+	type ChainId = ();
+	type BlockGasLimit = ();
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type OnChargeTransaction = ();
+	type OnCreate = ();
+	type FindAuthor = ();
+	type GasLimitPovSizeRatio = ();
+	type GasLimitStorageGrowthRatio = ();
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+	type CreateOriginFilter = ();
+	type CreateInnerOriginFilter = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const StakingPalletId: PalletId = PalletId(*b"test_stk");
+	pub const StakingMinStake: Balance = 10 * ONE;
+	pub const PeriodLength: BlockNumber = PERIOD_LENGTH;
+	pub const TimePointsW: Permill = Permill::from_percent(80);
+	pub const ActionPointsW: Perbill = Perbill::from_percent(20);
+	pub const TimePointsPerPeriod: u8 = 2;
+	pub const CurrentStakeWeight: u8 = 2;
+	pub const UnclaimablePeriods: u128 = 10;
+	pub const PointPercentage: FixedU128 = FixedU128::from_rational(15, 100);
+	pub const MaxVotes: u32 = 10;
+}
+
+impl pallet_staking::Config for Runtime {
+	type WeightInfo = ();
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type PeriodLength = PeriodLength;
+	type PalletId = StakingPalletId;
+	type NativeAssetId = ConstU32<HDX>;
+	type MinStake = StakingMinStake;
+	type TimePointsWeight = TimePointsW;
+	type ActionPointsWeight = ActionPointsW;
+	type TimePointsPerPeriod = TimePointsPerPeriod;
+	type UnclaimablePeriods = UnclaimablePeriods;
+	type CurrentStakeWeight = CurrentStakeWeight;
+	type BlockNumberProvider = System;
+	type PositionItemId = u128;
+	type CollectionId = u128;
+	type NFTCollectionId = ConstU128<1>;
+	type NFTHandler = Uniques;
+	type PayablePercentage = SigmoidPercentage<PointPercentage, ConstU32<40_000>>;
+	type MaxVotes = MaxVotes;
+	type MaxPointsPerAction = MaxPointsPerAction;
+	type ReferendumInfo = NoReferendums;
+	type Vesting = NoVesting;
+	type ExternalClaims = ();
+	type Collections = FreezableUniques;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type MinSlash = NoSlash;
+
+	#[cfg(feature = "runtime-benchmarks")]
+	type MaxLocks = ConstU32<20>;
+}
+
+pub struct NoSlash;
+
+impl GetByKey<FixedU128, Point> for NoSlash {
+	fn get(_k: &FixedU128) -> Point {
+		0
+	}
+}
+
+pub struct MaxPointsPerAction;
+
+impl GetByKey<Action, u32> for MaxPointsPerAction {
+	fn get(_k: &Action) -> u32 {
+		100
+	}
+}
+
+pub struct NoReferendums;
+
+impl GetReferendumState<ReferendumIndex> for NoReferendums {
+	fn is_referendum_finished(_index: ReferendumIndex) -> bool {
+		false
+	}
+}
+
+pub struct NoVesting;
+
+impl VestingDetails<AccountId, Balance> for NoVesting {
+	fn locked(_who: AccountId) -> Balance {
+		0
+	}
+}
+
+pub struct FreezableUniques;
+
+impl Freeze<AccountId, u128> for FreezableUniques {
+	fn freeze_collection(owner: AccountId, collection: u128) -> DispatchResult {
+		Uniques::freeze_collection(RuntimeOrigin::signed(owner), collection)
+	}
+}
+
+thread_local! {
+	pub static MM_BALANCES: RefCell<HashMap<AccountId, Balance>> = RefCell::new(HashMap::new());
+}
+
+/// Money market which supplies and withdraws 1:1.
+pub struct MoneyMarketMock;
+
+impl MoneyMarketOperations<AccountId, AssetId, Balance> for MoneyMarketMock {
+	fn supply(who: &AccountId, _asset: AssetId, amount: Balance) -> Result<Balance, DispatchError> {
+		MM_BALANCES.with(|m| *m.borrow_mut().entry(*who).or_default() += amount);
+		Ok(amount)
+	}
+
+	fn withdraw(who: &AccountId, _asset: AssetId, amount: Balance) -> Result<Balance, DispatchError> {
+		MM_BALANCES.with(|m| {
+			let mut balances = m.borrow_mut();
+			let balance = balances.entry(*who).or_default();
+			*balance = balance.saturating_sub(amount);
+		});
+		Ok(amount)
+	}
+
+	fn balance_of(who: &AccountId) -> Balance {
+		MM_BALANCES.with(|m| m.borrow().get(who).copied().unwrap_or_default())
+	}
+}
+
+parameter_types! {
+	pub const StHdxAssetId: AssetId = ST_HDX;
+	pub const GigaHdxPalletId: PalletId = PalletId(*b"gigahdx!");
+	pub const GigaHdxLockId: LockIdentifier = *b"ghdxlock";
+	pub const GigaHdxMinStake: Balance = ONE;
+	pub const GigaHdxCooldownPeriod: BlockNumber = 100;
+	pub const GigaHdxMaxPendingUnstakes: u32 = 10;
+}
+
+impl pallet_gigahdx::Config for Runtime {
+	type NativeCurrency = Balances;
+	type MultiCurrency = Tokens;
+	type StHdxAssetId = StHdxAssetId;
+	type MoneyMarket = MoneyMarketMock;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type PalletId = GigaHdxPalletId;
+	type LockId = GigaHdxLockId;
+	type MinStake = GigaHdxMinStake;
+	type CooldownPeriod = GigaHdxCooldownPeriod;
+	type MaxPendingUnstakes = GigaHdxMaxPendingUnstakes;
+	type ExternalClaims = ();
+	type LegacyStaking = ();
+	type VotingCommitment = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TestPollState {
+	Ongoing(pallet_conviction_voting::TallyOf<Runtime>, u16),
+	Completed(BlockNumber, bool),
+}
+
+thread_local! {
+	pub static POLLS: RefCell<BTreeMap<u32, TestPollState>> = RefCell::new(BTreeMap::new());
+}
+
+pub struct TestPolls;
+
+impl Polling<pallet_conviction_voting::TallyOf<Runtime>> for TestPolls {
+	type Index = u32;
+	type Votes = Balance;
+	type Class = u16;
+	type Moment = BlockNumber;
+
+	fn classes() -> Vec<Self::Class> {
+		vec![TRACK_ID]
+	}
+
+	fn as_ongoing(index: Self::Index) -> Option<(pallet_conviction_voting::TallyOf<Runtime>, Self::Class)> {
+		POLLS.with(|p| match p.borrow().get(&index) {
+			Some(TestPollState::Ongoing(tally, class)) => Some((tally.clone(), *class)),
+			_ => None,
+		})
+	}
+
+	fn access_poll<R>(
+		index: Self::Index,
+		f: impl FnOnce(PollStatus<&mut pallet_conviction_voting::TallyOf<Runtime>, BlockNumber, u16>) -> R,
+	) -> R {
+		// The polls are not borrowed while `f` is executed, so `f` can access them as well.
+		let mut polls = POLLS.with(|p| p.borrow().clone());
+		let result = match polls.get_mut(&index) {
+			Some(TestPollState::Ongoing(tally, class)) => f(PollStatus::Ongoing(tally, *class)),
+			Some(TestPollState::Completed(when, approved)) => f(PollStatus::Completed(*when, *approved)),
+			None => f(PollStatus::None),
+		};
+		POLLS.with(|p| *p.borrow_mut() = polls);
+		result
+	}
+
+	fn try_access_poll<R>(
+		index: Self::Index,
+		f: impl FnOnce(
+			PollStatus<&mut pallet_conviction_voting::TallyOf<Runtime>, BlockNumber, u16>,
+		) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		Self::access_poll(index, f)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_ongoing(class: Self::Class) -> Result<Self::Index, ()> {
+		POLLS.with(|p| {
+			let mut polls = p.borrow_mut();
+			let index = polls.keys().next_back().map_or(0, |i| i + 1);
+			polls.insert(index, TestPollState::Ongoing(Tally::from_parts(0, 0, 0), class));
+			Ok(index)
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn end_ongoing(index: Self::Index, approved: bool) -> Result<(), ()> {
+		POLLS.with(|p| {
+			p.borrow_mut()
+				.insert(index, TestPollState::Completed(System::block_number(), approved));
+			Ok(())
+		})
+	}
+}
+
+/// Returns the tally of `ONGOING_POLL`.
+pub fn ongoing_poll_tally() -> pallet_conviction_voting::TallyOf<Runtime> {
+	TestPolls::as_ongoing(ONGOING_POLL).expect("poll is ongoing").0
+}
+
+parameter_types! {
+	pub const VoteLockingPeriod: BlockNumber = 10;
+}
+
+impl pallet_conviction_voting::Config for Runtime {
+	type WeightInfo = ();
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type VoteLockingPeriod = VoteLockingPeriod;
+	type MaxVotes = MaxVotes;
+	type MaxTurnout = TotalIssuanceOf<Balances, AccountId>;
+	type Polls = TestPolls;
+	type VotingHooks = pallet_staking::integrations::conviction_voting::StakingConvictionVoting<Runtime>;
+	type VoteRemovalOrigin = NeverEnsureOrigin<AccountId>;
+	type BlockNumberProvider = System;
+}
+
+#[derive(Default)]
+pub(crate) struct ExtBuilder {
+	// endowed accounts with HDX, both in `Tokens` and `Balances`
+	balances: Vec<(AccountId, Balance)>,
+}
+
+impl ExtBuilder {
+	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances.extend(balances);
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.expect("Frame system builds valid default genesis config");
+
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: self.balances.clone(),
+			dev_accounts: None,
+		}
+		.assimilate_storage(&mut t)
+		.expect("Balances storage can be assimilated");
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self
+				.balances
+				.into_iter()
+				.map(|(who, amount)| (who, HDX, amount))
+				.chain(std::iter::once((Staking::pot_account_id(), HDX, POT_RESERVE)))
+				.collect(),
+		}
+		.assimilate_storage(&mut t)
+		.expect("Tokens storage can be assimilated");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| {
+			MM_BALANCES.with(|m| m.borrow_mut().clear());
+			POLLS.with(|p| {
+				*p.borrow_mut() = BTreeMap::from([(
+					ONGOING_POLL,
+					TestPollState::Ongoing(Tally::from_parts(0, 0, 0), TRACK_ID),
+				)])
+			});
+			System::set_block_number(1);
+			assert_ok!(Staking::initialize_staking(RawOrigin::Root.into()));
+		});
+		ext
+	}
+}
+
+/// Adds `amount` of HDX to the staking pot, which is distributed as rewards between stakers.
+pub fn add_staking_rewards(amount: Balance) {
+	assert_ok!(<Tokens as orml_traits::MultiCurrency<AccountId>>::deposit(
+		HDX,
+		&Staking::pot_account_id(),
+		amount
+	));
+}
@@ -0,0 +1,671 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2025  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::mock::*;
+use frame_support::assert_ok;
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use orml_traits::MultiCurrency;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::U256;
+use sp_runtime::DispatchError;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Executes `f` and reverts all storage changes made by it.
+fn dry_run<T>(f: impl FnOnce() -> T) -> T {
+	with_transaction(|| TransactionOutcome::Rollback(Ok::<_, DispatchError>(f()))).expect("Dry run succeeds")
+}
+
+fn hdx_balance(who: impl Into<AccountId>) -> Balance {
+	<Tokens as MultiCurrency<AccountId>>::free_balance(HDX, &who.into())
+}
+
+fn stake(who: impl Into<AccountId>, amount: Balance) -> u128 {
+	let who = who.into();
+	assert_ok!(Staking::stake(RuntimeOrigin::signed(who), amount));
+	Staking::get_user_position_id(&who).unwrap().unwrap()
+}
+
+fn log(selector: [u8; 32], a: impl Into<U256>, b: impl Into<U256>) -> Log {
+	log2(
+		Precompile,
+		selector,
+		Alice,
+		solidity::encode_event_data((a.into(), b.into())),
+	)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::stake_selectors().contains(&0xa694fc3a));
+	assert!(PCall::increase_stake_selectors().contains(&0xbec10cde));
+	assert!(PCall::claim_selectors().contains(&0x379607f5));
+	assert!(PCall::unstake_selectors().contains(&0x2e17de78));
+	assert!(PCall::giga_stake_selectors().contains(&0xe3493038));
+	assert!(PCall::giga_unstake_selectors().contains(&0xaab46a46));
+	assert!(PCall::position_of_selectors().contains(&0xfd2d39c5));
+	assert!(PCall::pending_rewards_selectors().contains(&0x7dcb2abf));
+	assert!(PCall::giga_stake_of_selectors().contains(&0x02e2275f));
+	assert!(PCall::exchange_rate_selectors().contains(&0x3ba0b9a9));
+	assert!(PCall::vote_yes_selectors().contains(&0xda9df518));
+	assert!(PCall::vote_no_selectors().contains(&0xcc600eba));
+	assert!(PCall::remove_vote_selectors().contains(&0x79cae220));
+	assert!(PCall::unlock_selectors().contains(&0x265a1d16));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile);
+
+		tester.test_default_modifier(PCall::stake_selectors());
+		tester.test_default_modifier(PCall::increase_stake_selectors());
+		tester.test_default_modifier(PCall::claim_selectors());
+		tester.test_default_modifier(PCall::unstake_selectors());
+		tester.test_default_modifier(PCall::giga_stake_selectors());
+		tester.test_default_modifier(PCall::giga_unstake_selectors());
+		tester.test_default_modifier(PCall::vote_yes_selectors());
+		tester.test_default_modifier(PCall::vote_no_selectors());
+		tester.test_default_modifier(PCall::remove_vote_selectors());
+		tester.test_default_modifier(PCall::unlock_selectors());
+		tester.test_view_modifier(PCall::position_of_selectors());
+		tester.test_view_modifier(PCall::pending_rewards_selectors());
+		tester.test_view_modifier(PCall::giga_stake_of_selectors());
+		tester.test_view_modifier(PCall::exchange_rate_selectors());
+	});
+}
+
+#[test]
+fn stake_should_create_position() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::stake {
+						amount: (100 * ONE).into(),
+					},
+				)
+				.expect_log(log(SELECTOR_LOG_STAKED, 0u128, 100 * ONE))
+				.execute_returns(U256::zero());
+
+			// Assert
+			let position_id = Staking::get_user_position_id(&Alice.into()).unwrap();
+			assert_eq!(position_id, Some(0));
+			assert_eq!(Staking::get_position(0).unwrap().get_stake(), 100 * ONE);
+		});
+}
+
+#[test]
+fn stake_should_revert_when_amount_is_below_min_stake() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(Alice, Precompile, PCall::stake { amount: ONE.into() })
+				.execute_reverts(|output| output.starts_with(b"Dispatched call failed"));
+		});
+}
+
+#[test]
+fn increase_stake_should_add_to_position() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = stake(Alice, 100 * ONE);
+
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::increase_stake {
+						position_id: position_id.into(),
+						amount: (50 * ONE).into(),
+					},
+				)
+				.expect_log(log(SELECTOR_LOG_STAKE_INCREASED, position_id, 50 * ONE))
+				.execute_returns(());
+
+			// Assert
+			assert_eq!(Staking::get_position(position_id).unwrap().get_stake(), 150 * ONE);
+		});
+}
+
+#[test]
+fn increase_stake_should_revert_when_position_is_not_owned_by_caller() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE), (Bob.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = stake(Bob, 100 * ONE);
+
+			// Act & Assert
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::increase_stake {
+						position_id: position_id.into(),
+						amount: (50 * ONE).into(),
+					},
+				)
+				.execute_reverts(|output| output.starts_with(b"Dispatched call failed"));
+		});
+}
+
+#[test]
+fn increase_stake_should_revert_when_position_id_is_too_large() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::increase_stake {
+					position_id: U256::MAX,
+					amount: ONE.into(),
+				},
+			)
+			.execute_reverts(|output| output == b"positionId: Value is too large for position id type");
+	});
+}
+
+#[test]
+fn claim_should_pay_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE), (Bob.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = stake(Alice, 500 * ONE);
+			stake(Bob, 500 * ONE);
+			add_staking_rewards(1_000 * ONE);
+			System::set_block_number(20 * PERIOD_LENGTH);
+
+			let balance_before = hdx_balance(Alice);
+			let expected_rewards = Staking::pending_rewards(position_id).unwrap();
+			assert!(expected_rewards > 0);
+
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::claim {
+						position_id: position_id.into(),
+					},
+				)
+				.expect_log(log(SELECTOR_LOG_REWARDS_CLAIMED, position_id, expected_rewards))
+				.execute_returns(U256::from(expected_rewards));
+
+			// Assert
+			assert_eq!(hdx_balance(Alice), balance_before + expected_rewards);
+		});
+}
+
+#[test]
+fn unstake_should_pay_rewards_and_destroy_position() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE), (Bob.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = stake(Alice, 500 * ONE);
+			stake(Bob, 500 * ONE);
+			add_staking_rewards(1_000 * ONE);
+			System::set_block_number(20 * PERIOD_LENGTH);
+
+			let expected_rewards = dry_run(|| {
+				let balance_before = hdx_balance(Alice);
+				assert_ok!(Staking::unstake(RuntimeOrigin::signed(Alice.into()), position_id));
+				hdx_balance(Alice) - balance_before
+			});
+			assert!(expected_rewards > 0);
+
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::unstake {
+						position_id: position_id.into(),
+					},
+				)
+				.expect_log(log(SELECTOR_LOG_REWARDS_CLAIMED, position_id, expected_rewards))
+				.expect_log(log(SELECTOR_LOG_UNSTAKED, position_id, 500 * ONE))
+				.execute_returns(U256::from(expected_rewards));
+
+			// Assert
+			assert_eq!(Staking::get_position(position_id), None);
+			assert_eq!(Staking::get_user_position_id(&Alice.into()).unwrap(), None);
+		});
+}
+
+#[test]
+fn position_of_should_return_position() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			System::set_block_number(42);
+			let position_id = stake(Alice, 100 * ONE);
+
+			// Act & Assert
+			precompiles()
+				.prepare_test(
+					Bob,
+					Precompile,
+					PCall::position_of {
+						who: Address(Alice.into()),
+					},
+				)
+				.execute_returns((
+					U256::from(position_id),
+					U256::from(100 * ONE),
+					U256::zero(),
+					U256::from(42),
+				));
+		});
+}
+
+#[test]
+fn position_of_should_revert_when_account_has_no_position() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::position_of {
+					who: Address(Bob.into()),
+				},
+			)
+			.execute_reverts(|output| output == b"Position not found");
+	});
+}
+
+#[test]
+fn pending_rewards_should_return_claimable_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE), (Bob.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = stake(Alice, 500 * ONE);
+			stake(Bob, 500 * ONE);
+			add_staking_rewards(1_000 * ONE);
+			System::set_block_number(20 * PERIOD_LENGTH);
+
+			let claimed_rewards = dry_run(|| {
+				let balance_before = hdx_balance(Alice);
+				assert_ok!(Staking::claim(RuntimeOrigin::signed(Alice.into()), position_id));
+				hdx_balance(Alice) - balance_before
+			});
+
+			// Act & Assert
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::pending_rewards {
+						position_id: position_id.into(),
+					},
+				)
+				.execute_returns(U256::from(claimed_rewards));
+		});
+}
+
+#[test]
+fn pending_rewards_should_revert_when_position_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::pending_rewards { position_id: 10.into() })
+			.execute_reverts(|output| output == b"Position not found");
+	});
+}
+
+#[test]
+fn giga_stake_should_mint_gigahdx() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::giga_stake {
+						amount: (100 * ONE).into(),
+					},
+				)
+				.expect_log(log(SELECTOR_LOG_GIGA_STAKED, 100 * ONE, 100 * ONE))
+				.execute_returns(U256::from(100 * ONE));
+
+			// Assert
+			assert_eq!(GigaHdx::locked_gigahdx(&Alice.into()), 100 * ONE);
+		});
+}
+
+#[test]
+fn giga_stake_should_revert_when_amount_is_below_min_stake() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(Alice, Precompile, PCall::giga_stake { amount: 1.into() })
+				.execute_reverts(|output| output.starts_with(b"Dispatched call failed"));
+		});
+}
+
+#[test]
+fn giga_unstake_should_return_payout_including_yield() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(GigaHdx::giga_stake(RuntimeOrigin::signed(Alice.into()), 100 * ONE));
+			assert_ok!(Balances::force_set_balance(
+				RuntimeOrigin::root(),
+				GigaHdx::gigapot_account_id(),
+				50 * ONE
+			));
+
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::giga_unstake {
+						gigahdx_amount: (40 * ONE).into(),
+					},
+				)
+				.expect_log(log(SELECTOR_LOG_GIGA_UNSTAKED, 40 * ONE, 60 * ONE))
+				.execute_returns(U256::from(60 * ONE));
+
+			// Assert
+			let record = pallet_gigahdx::Stakes::<Runtime>::get(AccountId::from(Alice)).unwrap();
+			assert_eq!(record.gigahdx, 60 * ONE);
+			assert_eq!(record.unstaking, 60 * ONE);
+		});
+}
+
+#[test]
+fn giga_stake_of_should_return_stake_record() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(GigaHdx::giga_stake(RuntimeOrigin::signed(Alice.into()), 100 * ONE));
+			assert_ok!(GigaHdx::giga_unstake(RuntimeOrigin::signed(Alice.into()), 30 * ONE));
+
+			// Act & Assert
+			precompiles()
+				.prepare_test(
+					Bob,
+					Precompile,
+					PCall::giga_stake_of {
+						who: Address(Alice.into()),
+					},
+				)
+				.execute_returns((U256::from(70 * ONE), U256::from(70 * ONE), U256::from(30 * ONE)));
+		});
+}
+
+#[test]
+fn giga_stake_of_should_return_zeros_when_account_has_no_stake() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::giga_stake_of {
+					who: Address(Bob.into()),
+				},
+			)
+			.execute_returns((U256::zero(), U256::zero(), U256::zero()));
+	});
+}
+
+#[test]
+fn exchange_rate_should_be_one_when_nothing_is_staked() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::exchange_rate {})
+			.execute_returns(U256::from(EXCHANGE_RATE_PRECISION));
+	});
+}
+
+#[test]
+fn exchange_rate_should_include_gigapot_yield() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(GigaHdx::giga_stake(RuntimeOrigin::signed(Alice.into()), 100 * ONE));
+			assert_ok!(Balances::force_set_balance(
+				RuntimeOrigin::root(),
+				GigaHdx::gigapot_account_id(),
+				50 * ONE
+			));
+
+			// Act & Assert
+			precompiles()
+				.prepare_test(Alice, Precompile, PCall::exchange_rate {})
+				.execute_returns(U256::from(EXCHANGE_RATE_PRECISION * 3 / 2));
+		});
+}
+
+#[test]
+fn vote_yes_should_vote_and_track_vote_of_staking_position() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = stake(Alice, 100 * ONE);
+
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::vote_yes {
+						poll_index: ONGOING_POLL,
+						amount: (50 * ONE).into(),
+						conviction: 1,
+					},
+				)
+				.expect_log(log(SELECTOR_LOG_VOTED_YES, ONGOING_POLL, 50 * ONE))
+				.execute_returns(());
+
+			// Assert
+			assert_eq!(ongoing_poll_tally().ayes, 50 * ONE);
+			assert_eq!(ongoing_poll_tally().nays, 0);
+
+			let votes = Staking::get_position_votes(position_id).votes;
+			assert_eq!(votes.len(), 1);
+			assert_eq!(votes[0].0, ONGOING_POLL);
+			assert_eq!(votes[0].1.amount, 50 * ONE);
+		});
+}
+
+#[test]
+fn vote_no_should_vote_nay() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::vote_no {
+						poll_index: ONGOING_POLL,
+						amount: (50 * ONE).into(),
+						conviction: 2,
+					},
+				)
+				.expect_log(log(SELECTOR_LOG_VOTED_NO, ONGOING_POLL, 50 * ONE))
+				.execute_returns(());
+
+			// Assert
+			assert_eq!(ongoing_poll_tally().ayes, 0);
+			assert_eq!(ongoing_poll_tally().nays, 100 * ONE);
+		});
+}
+
+#[test]
+fn vote_should_revert_when_conviction_is_invalid() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::vote_yes {
+						poll_index: ONGOING_POLL,
+						amount: (50 * ONE).into(),
+						conviction: 7,
+					},
+				)
+				.execute_reverts(|output| output.ends_with(b"Must be an integer between 0 and 6 included"));
+		});
+}
+
+#[test]
+fn vote_should_revert_when_poll_is_not_ongoing() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::vote_yes {
+						poll_index: ONGOING_POLL + 1,
+						amount: (50 * ONE).into(),
+						conviction: 1,
+					},
+				)
+				.execute_reverts(|output| output.starts_with(b"Dispatched call failed"));
+		});
+}
+
+#[test]
+fn remove_vote_should_remove_vote_from_poll_and_staking_position() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = stake(Alice, 100 * ONE);
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::vote_yes {
+						poll_index: ONGOING_POLL,
+						amount: (50 * ONE).into(),
+						conviction: 1,
+					},
+				)
+				.execute_returns(());
+
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::remove_vote {
+						poll_index: ONGOING_POLL,
+					},
+				)
+				.expect_log(log2(
+					Precompile,
+					SELECTOR_LOG_VOTE_REMOVED,
+					Alice,
+					solidity::encode_event_data(U256::from(ONGOING_POLL)),
+				))
+				.execute_returns(());
+
+			// Assert
+			assert_eq!(ongoing_poll_tally().ayes, 0);
+			assert!(Staking::get_position_votes(position_id).votes.is_empty());
+		});
+}
+
+#[test]
+fn unlock_should_remove_vote_lock_when_vote_was_removed() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::vote_yes {
+						poll_index: ONGOING_POLL,
+						amount: (50 * ONE).into(),
+						conviction: 1,
+					},
+				)
+				.execute_returns(());
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile,
+					PCall::remove_vote {
+						poll_index: ONGOING_POLL,
+					},
+				)
+				.execute_returns(());
+			assert!(!pallet_balances::Locks::<Runtime>::get(AccountId::from(Alice)).is_empty());
+
+			// Act
+			precompiles()
+				.prepare_test(Alice, Precompile, PCall::unlock { track_id: TRACK_ID })
+				.execute_returns(());
+
+			// Assert
+			assert!(pallet_balances::Locks::<Runtime>::get(AccountId::from(Alice)).is_empty());
+		});
+}
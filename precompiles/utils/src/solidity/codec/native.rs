@@ -223,6 +223,48 @@ macro_rules! impl_evmdata_for_uints {
 
 impl_evmdata_for_uints!(u8, u16, u32, u64, u128,);
 
+macro_rules! impl_evmdata_for_ints {
+	($($int:ty, )*) => {
+		$(
+			impl Codec for $int {
+				fn read(reader: &mut Reader) -> MayRevert<Self> {
+					let h256 = H256::read(reader)
+						.map_err(|_| RevertReason::read_out_of_bounds(Self::signature()))?;
+
+					// Values are two's complement encoded and sign-extended to 32 bytes.
+					let (padding, value) = h256.0.split_at(32 - core::mem::size_of::<Self>());
+					let expected_padding = if value[0] & 0x80 == 0 { 0x00 } else { 0xff };
+					if padding.iter().any(|byte| *byte != expected_padding) {
+						return Err(RevertReason::value_is_too_large(Self::signature()).into());
+					}
+
+					let mut buffer = [0u8; core::mem::size_of::<Self>()];
+					buffer.copy_from_slice(value);
+
+					Ok(Self::from_be_bytes(buffer))
+				}
+
+				fn write(writer: &mut Writer, value: Self) {
+					let mut buffer = if value < 0 { [0xffu8; 32] } else { [0u8; 32] };
+					buffer[32 - core::mem::size_of::<Self>()..].copy_from_slice(&value.to_be_bytes());
+
+					writer.data.extend_from_slice(&buffer);
+				}
+
+				fn has_static_size() -> bool {
+					true
+				}
+
+				fn signature() -> String {
+					alloc::format!("int{}", core::mem::size_of::<Self>() * 8)
+				}
+			}
+		)*
+	};
+}
+
+impl_evmdata_for_ints!(i8, i16, i32, i64, i128,);
+
 impl Codec for bool {
 	fn read(reader: &mut Reader) -> MayRevert<Self> {
 		let h256 = H256::read(reader).map_err(|_| RevertReason::read_out_of_bounds("bool"))?;
@@ -119,6 +119,53 @@ fn read_u128() {
 	assert_eq!(value, parsed);
 }
 
+#[test]
+fn write_i128() {
+	let writer_output = Writer::new().write(42i128).build();
+
+	let mut expected_output = [0u8; 32];
+	expected_output[16..].copy_from_slice(&42i128.to_be_bytes());
+
+	assert_eq!(writer_output, expected_output);
+}
+
+#[test]
+fn write_negative_i128() {
+	let writer_output = Writer::new().write(-42i128).build();
+
+	let mut expected_output = [0xffu8; 32];
+	expected_output[16..].copy_from_slice(&(-42i128).to_be_bytes());
+
+	assert_eq!(writer_output, expected_output);
+}
+
+#[test]
+fn read_i128() {
+	for value in [42i128, -42i128, i128::MAX, i128::MIN] {
+		let writer_output = Writer::new().write(value).build();
+
+		let mut reader = Reader::new(&writer_output);
+		let parsed: i128 = reader.read().expect("to correctly parse i128");
+
+		assert_eq!(value, parsed);
+	}
+}
+
+#[test]
+fn read_i128_fails_when_value_is_not_sign_extended() {
+	let mut input = [0u8; 32];
+	input[15] = 1;
+
+	let mut reader = Reader::new(&input);
+	assert!(reader.read::<i128>().is_err());
+
+	let mut input = [0xffu8; 32];
+	input[16] = 0x7f;
+
+	let mut reader = Reader::new(&input);
+	assert!(reader.read::<i128>().is_err());
+}
+
 #[test]
 fn write_u256() {
 	let value = U256::from(42);
@@ -1046,6 +1093,7 @@ fn evm_data_solidity_types() {
 	assert_eq!(u32::signature(), "uint32");
 	assert_eq!(u64::signature(), "uint64");
 	assert_eq!(u128::signature(), "uint128");
+	assert_eq!(i128::signature(), "int128");
 	assert_eq!(U256::signature(), "uint256");
 	assert_eq!(H256::signature(), "bytes32");
 	assert_eq!(Address::signature(), "address");
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: Apache-2.0
+pragma solidity >=0.8.3;
+
+/// @title XYK Pair Interface
+/// @dev Uniswap V2 pair compatible interface of XYK pools.
+/// The pair address is the ERC20 address of the pool share token, so the ERC20 functions
+/// of the share token are available at the same address.
+/// `token0` is the pool asset with the lower asset id.
+/// Tokens transferred to the pair address are used by the following `swap`, `mint` or `burn` call.
+/// Flash swaps are not supported.
+interface XykPair {
+    /// @dev Emitted when the pool reserves change.
+    event Sync(uint112 reserve0, uint112 reserve1);
+
+    /// @dev Emitted when liquidity is added.
+    event Mint(address indexed sender, uint256 amount0, uint256 amount1);
+
+    /// @dev Emitted when liquidity is removed.
+    event Burn(address indexed sender, uint256 amount0, uint256 amount1, address indexed to);
+
+    /// @dev Emitted when tokens are swapped.
+    event Swap(
+        address indexed sender,
+        uint256 amount0In,
+        uint256 amount1In,
+        uint256 amount0Out,
+        uint256 amount1Out,
+        address indexed to
+    );
+
+    /// @dev Returns the address of the pool asset with the lower asset id.
+    /// @custom:selector 0dfe1681
+    function token0() external view returns (address);
+
+    /// @dev Returns the address of the pool asset with the higher asset id.
+    /// @custom:selector d21220a7
+    function token1() external view returns (address);
+
+    /// @dev Returns the pool reserves and the current block timestamp in seconds.
+    /// @custom:selector 0902f1ac
+    function getReserves()
+        external
+        view
+        returns (uint112 reserve0, uint112 reserve1, uint32 blockTimestampLast);
+
+    /// @dev Buys `amount0Out` of token0 or `amount1Out` of token1 with the other token
+    /// transferred to the pair and sends it to `to`. Only one output amount can be non-zero.
+    /// @custom:selector 022c0d9f
+    /// @param amount0Out Amount of token0 to buy.
+    /// @param amount1Out Amount of token1 to buy.
+    /// @param to Recipient of the bought token.
+    /// @param data Must be empty.
+    function swap(uint256 amount0Out, uint256 amount1Out, address to, bytes calldata data) external;
+
+    /// @dev Adds the tokens transferred to the pair as liquidity and sends the shares to `to`.
+    /// @custom:selector 6a627842
+    /// @param to Recipient of the shares.
+    /// @return liquidity Amount of minted shares.
+    function mint(address to) external returns (uint256 liquidity);
+
+    /// @dev Removes liquidity of the shares transferred to the pair and sends the tokens to `to`.
+    /// @custom:selector 89afcb44
+    /// @param to Recipient of the tokens.
+    /// @return amount0 Received amount of token0.
+    /// @return amount1 Received amount of token1.
+    function burn(address to) external returns (uint256 amount0, uint256 amount1);
+
+    /// @dev Sends the tokens transferred to the pair and not used yet to `to`.
+    /// @custom:selector bc25cf77
+    /// @param to Recipient of the tokens.
+    function skim(address to) external;
+
+    /// @dev Emits the Sync event with the current reserves.
+    /// @custom:selector fff6cae9
+    function sync() external;
+}
@@ -0,0 +1,429 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2025  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::ExistenceRequirement;
+use hydradx_traits::evm::Erc20Mapping;
+use orml_traits::MultiCurrency;
+use pallet_evm::AddressMapping;
+use pallet_xyk::types::{AssetId, Balance};
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::traits::Dispatchable;
+use sp_runtime::{Rounding, SaturatedConversion};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the Sync log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_SYNC: [u8; 32] = keccak256!("Sync(uint112,uint112)");
+
+/// Solidity selector of the Swap log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_SWAP: [u8; 32] = keccak256!("Swap(address,uint256,uint256,uint256,uint256,address)");
+
+/// Solidity selector of the Mint log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_MINT: [u8; 32] = keccak256!("Mint(address,uint256,uint256)");
+
+/// Solidity selector of the Burn log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_BURN: [u8; 32] = keccak256!("Burn(address,uint256,uint256,address)");
+
+/// Reserves are returned as `uint112`, larger reserves are saturated.
+const MAX_RESERVE: u128 = (1 << 112) - 1;
+
+/// Selectors of the pair functions: token0, token1, getReserves, swap, mint, burn, skim and sync.
+const PAIR_SELECTORS: [u32; 8] = [
+	0x0dfe1681, 0xd21220a7, 0x0902f1ac, 0x022c0d9f, 0x6a627842, 0x89afcb44, 0xbc25cf77, 0xfff6cae9,
+];
+
+/// Returns true if `input` calls one of the pair functions.
+///
+/// Used to route calls to a share token address between the pair and the ERC20 precompile.
+pub fn is_pair_call(input: &[u8]) -> bool {
+	input
+		.get(0..4)
+		.and_then(|selector| selector.try_into().ok())
+		.is_some_and(|selector| PAIR_SELECTORS.contains(&u32::from_be_bytes(selector)))
+}
+
+/// XYK pool resolved from the address of its share token.
+struct Pair<AccountId> {
+	/// Pool account holding the reserves.
+	pool: AccountId,
+	/// Account of the pair address. Tokens transferred to the pair address are held here
+	/// until they are used by `swap`, `mint` or `burn`.
+	holding: AccountId,
+	share_token: AssetId,
+	token0: AssetId,
+	token1: AssetId,
+}
+
+/// Precompile exposing XYK pools with a Uniswap V2 pair compatible ABI.
+///
+/// The pair address is the ERC20 address of the pool share token, so ERC20 functions of the share
+/// token are served by the asset precompile and only the pair functions are served by this precompile.
+/// `token0` is the pool asset with the lower asset id.
+///
+/// Same as Uniswap V2 pairs, `swap`, `mint` and `burn` use the tokens transferred to the pair
+/// address before the call. Tokens which are not used stay at the pair address and can be
+/// recovered with `skim`. Flash swaps are not supported.
+///
+/// `AssetAddress` maps the assets to their ERC20 addresses.
+pub struct XykPairPrecompile<Runtime, AssetAddress>(PhantomData<(Runtime, AssetAddress)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, AssetAddress> XykPairPrecompile<Runtime, AssetAddress>
+where
+	Runtime: pallet_xyk::Config + pallet_evm::Config + pallet_timestamp::Config,
+	Runtime::AddressMapping: AddressMapping<Runtime::AccountId>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_xyk::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	AssetAddress: Erc20Mapping<AssetId>,
+{
+	/// Returns the ERC20 address of the pool asset with the lower asset id.
+	#[precompile::public("token0()")]
+	#[precompile::view]
+	fn token0(handle: &mut impl PrecompileHandle) -> EvmResult<Address> {
+		let pair = Self::pair(handle)?;
+
+		Ok(Address(AssetAddress::asset_address(pair.token0)))
+	}
+
+	/// Returns the ERC20 address of the pool asset with the higher asset id.
+	#[precompile::public("token1()")]
+	#[precompile::view]
+	fn token1(handle: &mut impl PrecompileHandle) -> EvmResult<Address> {
+		let pair = Self::pair(handle)?;
+
+		Ok(Address(AssetAddress::asset_address(pair.token1)))
+	}
+
+	/// Returns the pool reserves and the current block timestamp.
+	/// Reserves are updated by every pool operation, so they are always in sync.
+	#[precompile::public("getReserves()")]
+	#[precompile::view]
+	fn get_reserves(handle: &mut impl PrecompileHandle) -> EvmResult<(U256, U256, u32)> {
+		let pair = Self::pair(handle)?;
+		let (reserve0, reserve1) = Self::reserves(handle, &pair)?;
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		let now: u64 = pallet_timestamp::Pallet::<Runtime>::get().saturated_into();
+
+		Ok((reserve0, reserve1, (now / 1_000) as u32))
+	}
+
+	/// Buys `amount0Out` of token0 or `amount1Out` of token1 for the other token transferred
+	/// to the pair address and sends the bought amount to `to`.
+	#[precompile::public("swap(uint256,uint256,address,bytes)")]
+	fn swap(
+		handle: &mut impl PrecompileHandle,
+		amount0_out: U256,
+		amount1_out: U256,
+		to: Address,
+		data: UnboundedBytes,
+	) -> EvmResult {
+		let pair = Self::pair(handle)?;
+		let to = Self::recipient(handle, to)?;
+
+		if !data.as_bytes().is_empty() {
+			return Err(RevertReason::custom("Flash swaps are not supported")
+				.in_field("data")
+				.into());
+		}
+
+		let amount0_out = Self::to_balance(amount0_out, "amount0Out")?;
+		let amount1_out = Self::to_balance(amount1_out, "amount1Out")?;
+		let (asset_in, asset_out, amount_out) = match (amount0_out, amount1_out) {
+			(0, 0) => return Err(revert("Insufficient output amount")),
+			(amount, 0) => (pair.token1, pair.token0, amount),
+			(0, amount) => (pair.token0, pair.token1, amount),
+			_ => return Err(revert("Only one output amount is supported")),
+		};
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+		let available_in = Self::holding_balance(&pair, asset_in);
+		if available_in == 0 {
+			return Err(revert("Insufficient input amount"));
+		}
+
+		handle.record_log_costs_manual(1, 64)?;
+		handle.record_log_costs_manual(3, 128)?;
+
+		let call = pallet_xyk::Call::<Runtime>::buy {
+			asset_out,
+			asset_in,
+			amount: amount_out,
+			max_limit: available_in,
+			discount: false,
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(pair.holding.clone()).into(), call, 0)?;
+
+		let amount_in = available_in.saturating_sub(Self::holding_balance(&pair, asset_in));
+		Self::transfer_out(handle, &pair, asset_out, &to.1, amount_out)?;
+
+		let (amount0_in, amount1_in) = if asset_in == pair.token0 {
+			(amount_in, 0)
+		} else {
+			(0, amount_in)
+		};
+
+		Self::log_sync(handle, &pair)?;
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_SWAP,
+			handle.context().caller,
+			to.0,
+			solidity::encode_event_data((
+				U256::from(amount0_in),
+				U256::from(amount1_in),
+				U256::from(amount0_out),
+				U256::from(amount1_out),
+			)),
+		)
+		.record(handle)
+	}
+
+	/// Adds the tokens transferred to the pair address as liquidity and sends the minted shares to `to`.
+	/// The token in excess of the pool ratio is left at the pair address. Returns the amount of minted shares.
+	#[precompile::public("mint(address)")]
+	fn mint(handle: &mut impl PrecompileHandle, to: Address) -> EvmResult<U256> {
+		let pair = Self::pair(handle)?;
+		let to = Self::recipient(handle, to)?;
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(5))?;
+		let available0 = Self::holding_balance(&pair, pair.token0);
+		let available1 = Self::holding_balance(&pair, pair.token1);
+		if available0 == 0 || available1 == 0 {
+			return Err(revert("Insufficient liquidity minted"));
+		}
+
+		let reserve0 = Self::pool_balance(&pair, pair.token0);
+		let reserve1 = Self::pool_balance(&pair, pair.token1);
+		let required1 = hydra_dx_math::xyk::calculate_liquidity_in(reserve0, reserve1, available0)
+			.map_err(|_| revert("Insufficient liquidity minted"))?;
+
+		let call = if required1 <= available1 {
+			pallet_xyk::Call::<Runtime>::add_liquidity {
+				asset_a: pair.token0,
+				asset_b: pair.token1,
+				amount_a: available0,
+				amount_b_max_limit: available1,
+			}
+		} else {
+			// Required amount of the other asset is rounded up, so the amount of token1 is limited
+			// to the amount which requires at most the available amount of token0.
+			let amount1 =
+				multiply_by_rational_with_rounding(available0.saturating_sub(1), reserve1, reserve0, Rounding::Down)
+					.ok_or_else(|| revert("Insufficient liquidity minted"))?
+					.min(available1);
+
+			pallet_xyk::Call::<Runtime>::add_liquidity {
+				asset_a: pair.token1,
+				asset_b: pair.token0,
+				amount_a: amount1,
+				amount_b_max_limit: available0,
+			}
+		};
+
+		handle.record_log_costs_manual(1, 64)?;
+		handle.record_log_costs_manual(2, 64)?;
+
+		let shares_before = Self::holding_balance(&pair, pair.share_token);
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(pair.holding.clone()).into(), call, 0)?;
+		let shares = Self::holding_balance(&pair, pair.share_token).saturating_sub(shares_before);
+
+		let amount0 = available0.saturating_sub(Self::holding_balance(&pair, pair.token0));
+		let amount1 = available1.saturating_sub(Self::holding_balance(&pair, pair.token1));
+		Self::transfer_out(handle, &pair, pair.share_token, &to.1, shares)?;
+
+		Self::log_sync(handle, &pair)?;
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_MINT,
+			handle.context().caller,
+			solidity::encode_event_data((U256::from(amount0), U256::from(amount1))),
+		)
+		.record(handle)?;
+
+		Ok(shares.into())
+	}
+
+	/// Removes liquidity of the shares transferred to the pair address and sends the received tokens to `to`.
+	/// Returns the received amounts of token0 and token1.
+	#[precompile::public("burn(address)")]
+	fn burn(handle: &mut impl PrecompileHandle, to: Address) -> EvmResult<(U256, U256)> {
+		let pair = Self::pair(handle)?;
+		let to = Self::recipient(handle, to)?;
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(5))?;
+		let shares = Self::holding_balance(&pair, pair.share_token);
+		if shares == 0 {
+			return Err(revert("Insufficient liquidity burned"));
+		}
+
+		handle.record_log_costs_manual(1, 64)?;
+		handle.record_log_costs_manual(3, 64)?;
+
+		let balance0_before = Self::holding_balance(&pair, pair.token0);
+		let balance1_before = Self::holding_balance(&pair, pair.token1);
+
+		let call = pallet_xyk::Call::<Runtime>::remove_liquidity {
+			asset_a: pair.token0,
+			asset_b: pair.token1,
+			share_amount: shares,
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(pair.holding.clone()).into(), call, 0)?;
+
+		let amount0 = Self::holding_balance(&pair, pair.token0).saturating_sub(balance0_before);
+		let amount1 = Self::holding_balance(&pair, pair.token1).saturating_sub(balance1_before);
+		Self::transfer_out(handle, &pair, pair.token0, &to.1, amount0)?;
+		Self::transfer_out(handle, &pair, pair.token1, &to.1, amount1)?;
+
+		Self::log_sync(handle, &pair)?;
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_BURN,
+			handle.context().caller,
+			to.0,
+			solidity::encode_event_data((U256::from(amount0), U256::from(amount1))),
+		)
+		.record(handle)?;
+
+		Ok((amount0.into(), amount1.into()))
+	}
+
+	/// Sends the tokens transferred to the pair address and not used yet to `to`.
+	#[precompile::public("skim(address)")]
+	fn skim(handle: &mut impl PrecompileHandle, to: Address) -> EvmResult {
+		let pair = Self::pair(handle)?;
+		let to = Self::recipient(handle, to)?;
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+		let amount0 = Self::holding_balance(&pair, pair.token0);
+		let amount1 = Self::holding_balance(&pair, pair.token1);
+
+		Self::transfer_out(handle, &pair, pair.token0, &to.1, amount0)?;
+		Self::transfer_out(handle, &pair, pair.token1, &to.1, amount1)
+	}
+
+	/// Emits the Sync log with the current reserves.
+	/// Reserves are always in sync with the pool balances, so no state is changed.
+	#[precompile::public("sync()")]
+	fn sync(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let pair = Self::pair(handle)?;
+
+		handle.record_log_costs_manual(1, 64)?;
+
+		Self::log_sync(handle, &pair)
+	}
+
+	/// Returns the pool of the share token at the called address.
+	fn pair(handle: &mut impl PrecompileHandle) -> EvmResult<Pair<Runtime::AccountId>> {
+		// Asset details of the share token, share token of the pool and pool assets are read
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(3))?;
+
+		let address = handle.code_address();
+		let share_token = AssetAddress::address_to_asset(address).ok_or_else(|| revert("Pool not found"))?;
+		let (pool, (token0, token1)) =
+			pallet_xyk::Pallet::<Runtime>::pool_by_share_token(share_token).ok_or_else(|| revert("Pool not found"))?;
+
+		Ok(Pair {
+			pool,
+			holding: Runtime::AddressMapping::into_account_id(address),
+			share_token,
+			token0,
+			token1,
+		})
+	}
+
+	fn reserves(handle: &mut impl PrecompileHandle, pair: &Pair<Runtime::AccountId>) -> EvmResult<(U256, U256)> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+
+		Ok((
+			Self::pool_balance(pair, pair.token0).min(MAX_RESERVE).into(),
+			Self::pool_balance(pair, pair.token1).min(MAX_RESERVE).into(),
+		))
+	}
+
+	fn log_sync(handle: &mut impl PrecompileHandle, pair: &Pair<Runtime::AccountId>) -> EvmResult {
+		let (reserve0, reserve1) = Self::reserves(handle, pair)?;
+
+		log1(
+			handle.context().address,
+			SELECTOR_LOG_SYNC,
+			solidity::encode_event_data((reserve0, reserve1)),
+		)
+		.record(handle)
+	}
+
+	/// Returns the address and the account of `to`, which must not be the pair address.
+	fn recipient(handle: &impl PrecompileHandle, to: Address) -> EvmResult<(H160, Runtime::AccountId)> {
+		let to: H160 = to.into();
+		if to == handle.code_address() {
+			return Err(RevertReason::custom("Invalid recipient").in_field("to").into());
+		}
+
+		Ok((to, Runtime::AddressMapping::into_account_id(to)))
+	}
+
+	fn pool_balance(pair: &Pair<Runtime::AccountId>, asset: AssetId) -> Balance {
+		<Runtime as pallet_xyk::Config>::Currency::free_balance(asset, &pair.pool)
+	}
+
+	fn holding_balance(pair: &Pair<Runtime::AccountId>, asset: AssetId) -> Balance {
+		<Runtime as pallet_xyk::Config>::Currency::free_balance(asset, &pair.holding)
+	}
+
+	fn transfer_out(
+		handle: &mut impl PrecompileHandle,
+		pair: &Pair<Runtime::AccountId>,
+		asset: AssetId,
+		to: &Runtime::AccountId,
+		amount: Balance,
+	) -> EvmResult {
+		if amount == 0 {
+			return Ok(());
+		}
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost().saturating_mul(2))?;
+
+		<Runtime as pallet_xyk::Config>::Currency::transfer(
+			asset,
+			&pair.holding,
+			to,
+			amount,
+			ExistenceRequirement::AllowDeath,
+		)
+		.map_err(|_| revert("Transfer failed"))
+	}
+
+	fn to_balance(value: U256, field: &'static str) -> EvmResult<Balance> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field(field).into())
+	}
+}
@@ -0,0 +1,384 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2025  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+//! Test utilities
+use super::*;
+
+use frame_support::traits::{Everything, Nothing};
+use frame_support::{assert_ok, construct_runtime, parameter_types, weights::Weight};
+use frame_system::EnsureRoot;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{AssetPairAccountIdFor, Source};
+use orml_traits::parameter_type_with_key;
+use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, FrameSystemAccountProvider, IsPrecompileResult, Precompile};
+use pallet_xyk::types::AssetPair;
+use precompile_utils::{mock_account, testing::MockAccount};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::{BuildStorage, DispatchError};
+
+pub type AccountId = MockAccount;
+pub type Amount = i128;
+
+type Block = frame_system::mocking::MockBlockU32<Runtime>;
+
+pub const DAI: AssetId = 2;
+pub const DOT: AssetId = 5;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const DAI_LIQUIDITY: Balance = 1_000 * ONE;
+pub const DOT_LIQUIDITY: Balance = 4_000 * ONE;
+
+/// Current timestamp in milliseconds.
+pub const NOW: u64 = 1_700_000_000_000;
+
+construct_runtime!(
+	pub enum Runtime	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		Evm: pallet_evm,
+		Timestamp: pallet_timestamp,
+		Broadcast: pallet_broadcast,
+		AssetRegistry: pallet_asset_registry,
+		XYK: pallet_xyk,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+	type ExtensionsWeightInfo = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 0;
+}
+
+impl pallet_balances::Config for Runtime {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 4];
+	type MaxLocks = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeFreezeReason = ();
+	type DoneSlashHandler = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = ();
+	type MaxReserves = ();
+	type CurrencyHooks = ();
+}
+
+mock_account!(Provider, |_| MockAccount::from_u64(1));
+
+/// Asset addresses, same as the ERC20 addresses of assets in the runtime.
+pub struct AssetAddressMock;
+
+impl Erc20Mapping<AssetId> for AssetAddressMock {
+	fn asset_address(asset_id: AssetId) -> H160 {
+		let mut address = [0u8; 20];
+		address[15] = 1;
+		address[16..].copy_from_slice(&asset_id.to_be_bytes());
+		H160::from(address)
+	}
+
+	fn address_to_asset(address: H160) -> Option<AssetId> {
+		let bytes = address.to_fixed_bytes();
+		if bytes[..16] != Self::asset_address(0).to_fixed_bytes()[..16] {
+			return None;
+		}
+
+		let mut asset_id = [0u8; 4];
+		asset_id.copy_from_slice(&bytes[16..]);
+		Some(u32::from_be_bytes(asset_id))
+	}
+}
+
+/// Executes the XYK pair precompile at asset addresses, same as the runtime for the pair functions.
+pub struct Precompiles<R>(PhantomData<R>);
+
+impl<R> pallet_evm::PrecompileSet for Precompiles<R>
+where
+	XykPairPrecompile<R, AssetAddressMock>: Precompile,
+{
+	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<pallet_evm::PrecompileResult> {
+		AssetAddressMock::address_to_asset(handle.code_address())
+			.map(|_| XykPairPrecompile::<R, AssetAddressMock>::execute(handle))
+	}
+
+	fn is_precompile(&self, address: H160, _remaining_gas: u64) -> IsPrecompileResult {
+		IsPrecompileResult::Answer {
+			is_precompile: AssetAddressMock::address_to_asset(address).is_some(),
+			extra_cost: 0,
+		}
+	}
+}
+
+pub type PCall = XykPairPrecompileCall<Runtime, AssetAddressMock>;
+
+parameter_types! {
+	pub PrecompilesValue: Precompiles<Runtime> = Precompiles(PhantomData);
+	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+}
+
+impl pallet_evm::Config for Runtime {
+	type AccountProvider = FrameSystemAccountProvider<Runtime>;
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = AccountId;
+	type Currency = Balances;
+	type PrecompilesType = Precompiles<Runtime>;
+	type PrecompilesValue = PrecompilesValue;
+	type ChainId = ();
+	type BlockGasLimit = ();
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type OnChargeTransaction = ();
+	type OnCreate = ();
+	type FindAuthor = ();
+	type GasLimitPovSizeRatio = ();
+	type GasLimitStorageGrowthRatio = ();
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+	type CreateOriginFilter = ();
+	type CreateInnerOriginFilter = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+impl pallet_broadcast::Config for Runtime {}
+
+parameter_types! {
+	pub const RegistryStringLimit: u32 = 100;
+	pub const MinRegistryStringLimit: u32 = 2;
+	pub const SequentialIdOffset: u32 = 1_000_000;
+}
+
+impl pallet_asset_registry::Config for Runtime {
+	type RegistryOrigin = EnsureRoot<AccountId>;
+	type Currency = Tokens;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type AssetId = AssetId;
+	type AssetNativeLocation = u8;
+	type StringLimit = RegistryStringLimit;
+	type MinStringLimit = MinRegistryStringLimit;
+	type SequentialIdStartAt = SequentialIdOffset;
+	type RegExternalWeightMultiplier = frame_support::traits::ConstU64<1>;
+	type RegisterAssetHook = ();
+	type WeightInfo = ();
+}
+
+pub struct AssetPairAccountIdMock;
+
+impl AssetPairAccountIdFor<AssetId, AccountId> for AssetPairAccountIdMock {
+	fn from_assets(asset_a: AssetId, asset_b: AssetId, _: &str) -> AccountId {
+		let (asset_a, asset_b) = AssetPair::new(asset_a, asset_b).ordered_pair();
+		MockAccount::from_u64(1_000_000 + asset_a as u64 * 1_000 + asset_b as u64)
+	}
+}
+
+pub struct NoWhitelist;
+
+impl DustRemovalAccountWhitelist<AccountId> for NoWhitelist {
+	type Error = DispatchError;
+
+	fn add_account(_account: &AccountId) -> Result<(), Self::Error> {
+		Ok(())
+	}
+
+	fn remove_account(_account: &AccountId) -> Result<(), Self::Error> {
+		Ok(())
+	}
+}
+
+parameter_types! {
+	pub const NativeAssetId: AssetId = 0;
+	pub const ExchangeFee: (u32, u32) = (3, 1_000);
+	pub const MinTradingLimit: Balance = 1_000;
+	pub const MinPoolLiquidity: Balance = 1_000;
+	pub const MaxInRatio: u128 = 3;
+	pub const MaxOutRatio: u128 = 3;
+	pub const OracleSourceIdentifier: Source = *b"hydraxyk";
+}
+
+impl pallet_xyk::Config for Runtime {
+	type AssetRegistry = AssetRegistry;
+	type AssetPairAccountId = AssetPairAccountIdMock;
+	type Currency = Tokens;
+	type NativeAssetId = NativeAssetId;
+	type WeightInfo = ();
+	type GetExchangeFee = ExchangeFee;
+	type MinTradingLimit = MinTradingLimit;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MaxInRatio = MaxInRatio;
+	type MaxOutRatio = MaxOutRatio;
+	type CanCreatePool = pallet_xyk::AllowAllPools;
+	type AMMHandler = ();
+	type NonDustableWhitelistHandler = NoWhitelist;
+	type AccountTradeVolumeHandler = ();
+	type OracleSource = OracleSourceIdentifier;
+}
+
+/// Returns the pair address of the DAI/DOT pool, which is the address of its share token.
+pub fn pair_address() -> H160 {
+	let pool = XYK::pair_account_from_assets(DAI, DOT);
+	AssetAddressMock::asset_address(XYK::share_token(pool))
+}
+
+/// Returns the share token of the DAI/DOT pool.
+pub fn share_token() -> AssetId {
+	XYK::share_token(XYK::pair_account_from_assets(DAI, DOT))
+}
+
+pub fn balance(who: impl Into<AccountId>, asset: AssetId) -> Balance {
+	<Tokens as MultiCurrency<AccountId>>::free_balance(asset, &who.into())
+}
+
+pub fn pool_balance(asset: AssetId) -> Balance {
+	<Tokens as MultiCurrency<AccountId>>::free_balance(asset, &XYK::pair_account_from_assets(DAI, DOT))
+}
+
+/// Transfers `amount` of `asset` from `who` to the pair address.
+pub fn transfer_to_pair(who: impl Into<AccountId>, asset: AssetId, amount: Balance) {
+	assert_ok!(<Tokens as MultiCurrency<AccountId>>::transfer(
+		asset,
+		&who.into(),
+		&pair_address().into(),
+		amount
+	));
+}
+
+pub(crate) struct ExtBuilder {
+	// endowed accounts with balances
+	balances: Vec<(AccountId, AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> ExtBuilder {
+		ExtBuilder {
+			balances: vec![
+				(Provider.into(), DAI, DAI_LIQUIDITY),
+				(Provider.into(), DOT, DOT_LIQUIDITY),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.balances.extend(balances);
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.expect("Frame system builds valid default genesis config");
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.expect("Tokens storage can be assimilated");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			Timestamp::set_timestamp(NOW);
+
+			assert_ok!(XYK::create_pool(
+				RuntimeOrigin::signed(Provider.into()),
+				DOT,
+				DOT_LIQUIDITY,
+				DAI,
+				DAI_LIQUIDITY,
+			));
+		});
+		ext
+	}
+}
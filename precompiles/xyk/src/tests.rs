@@ -0,0 +1,510 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2025  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::mock::*;
+use crate::*;
+use frame_support::assert_ok;
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use precompile_utils::testing::*;
+use sp_runtime::DispatchError;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+/// Executes `f` and reverts all storage changes made by it.
+fn dry_run<T>(f: impl FnOnce() -> T) -> T {
+	with_transaction(|| TransactionOutcome::Rollback(Ok::<_, DispatchError>(f()))).expect("Dry run succeeds")
+}
+
+fn sync_log(reserve0: Balance, reserve1: Balance) -> Log {
+	log1(
+		pair_address(),
+		SELECTOR_LOG_SYNC,
+		solidity::encode_event_data((U256::from(reserve0), U256::from(reserve1))),
+	)
+}
+
+fn no_data() -> UnboundedBytes {
+	Vec::<u8>::new().into()
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::token0_selectors().contains(&0x0dfe1681));
+	assert!(PCall::token1_selectors().contains(&0xd21220a7));
+	assert!(PCall::get_reserves_selectors().contains(&0x0902f1ac));
+	assert!(PCall::swap_selectors().contains(&0x022c0d9f));
+	assert!(PCall::mint_selectors().contains(&0x6a627842));
+	assert!(PCall::burn_selectors().contains(&0x89afcb44));
+	assert!(PCall::skim_selectors().contains(&0xbc25cf77));
+	assert!(PCall::sync_selectors().contains(&0xfff6cae9));
+}
+
+#[test]
+fn is_pair_call_should_match_pair_selectors_only() {
+	let mut selectors = PCall::selectors().to_vec();
+	selectors.sort();
+	let mut pair_selectors = PAIR_SELECTORS.to_vec();
+	pair_selectors.sort();
+	assert_eq!(selectors, pair_selectors);
+
+	assert!(is_pair_call(&0x0902f1acu32.to_be_bytes()));
+	// balanceOf(address)
+	assert!(!is_pair_call(&0x70a08231u32.to_be_bytes()));
+	assert!(!is_pair_call(&[0x09, 0x02]));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, pair_address());
+
+		tester.test_view_modifier(PCall::token0_selectors());
+		tester.test_view_modifier(PCall::token1_selectors());
+		tester.test_view_modifier(PCall::get_reserves_selectors());
+		tester.test_default_modifier(PCall::swap_selectors());
+		tester.test_default_modifier(PCall::mint_selectors());
+		tester.test_default_modifier(PCall::burn_selectors());
+		tester.test_default_modifier(PCall::skim_selectors());
+		tester.test_default_modifier(PCall::sync_selectors());
+	});
+}
+
+#[test]
+fn tokens_should_be_ordered_by_asset_id() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, pair_address(), PCall::token0 {})
+			.execute_returns(Address(AssetAddressMock::asset_address(DAI)));
+
+		precompiles()
+			.prepare_test(Alice, pair_address(), PCall::token1 {})
+			.execute_returns(Address(AssetAddressMock::asset_address(DOT)));
+	});
+}
+
+#[test]
+fn get_reserves_should_return_pool_balances_and_timestamp() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, pair_address(), PCall::get_reserves {})
+			.execute_returns((
+				U256::from(DAI_LIQUIDITY),
+				U256::from(DOT_LIQUIDITY),
+				(NOW / 1_000) as u32,
+			));
+	});
+}
+
+#[test]
+fn call_should_revert_when_asset_is_not_share_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, AssetAddressMock::asset_address(DAI), PCall::token0 {})
+			.execute_reverts(|output| output == b"Pool not found");
+	});
+}
+
+#[test]
+fn swap_should_buy_token1_with_token0_transferred_to_pair() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), DAI, 100 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			transfer_to_pair(Alice, DAI, 10 * ONE);
+			let amount_out = 30 * ONE;
+			let amount_in = dry_run(|| {
+				assert_ok!(XYK::buy(
+					RuntimeOrigin::signed(pair_address().into()),
+					DOT,
+					DAI,
+					amount_out,
+					10 * ONE,
+					false,
+				));
+				10 * ONE - balance(pair_address(), DAI)
+			});
+
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					pair_address(),
+					PCall::swap {
+						amount0_out: U256::zero(),
+						amount1_out: amount_out.into(),
+						to: Address(Bob.into()),
+						data: no_data(),
+					},
+				)
+				.expect_log(sync_log(DAI_LIQUIDITY + amount_in, DOT_LIQUIDITY - amount_out))
+				.expect_log(log3(
+					pair_address(),
+					SELECTOR_LOG_SWAP,
+					Alice,
+					Bob,
+					solidity::encode_event_data((
+						U256::from(amount_in),
+						U256::zero(),
+						U256::zero(),
+						U256::from(amount_out),
+					)),
+				))
+				.execute_returns(());
+
+			// Assert
+			assert_eq!(balance(Bob, DOT), amount_out);
+			assert_eq!(balance(pair_address(), DAI), 10 * ONE - amount_in);
+			assert_eq!(pool_balance(DAI), DAI_LIQUIDITY + amount_in);
+			assert_eq!(pool_balance(DOT), DOT_LIQUIDITY - amount_out);
+		});
+}
+
+#[test]
+fn swap_should_buy_token0_with_token1_transferred_to_pair() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), DOT, 100 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			transfer_to_pair(Alice, DOT, 100 * ONE);
+
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					pair_address(),
+					PCall::swap {
+						amount0_out: (10 * ONE).into(),
+						amount1_out: U256::zero(),
+						to: Address(Bob.into()),
+						data: no_data(),
+					},
+				)
+				.execute_returns(());
+
+			// Assert
+			assert_eq!(balance(Bob, DAI), 10 * ONE);
+			assert_eq!(pool_balance(DAI), DAI_LIQUIDITY - 10 * ONE);
+			assert_eq!(
+				pool_balance(DOT) + balance(pair_address(), DOT),
+				DOT_LIQUIDITY + 100 * ONE
+			);
+		});
+}
+
+#[test]
+fn swap_should_revert_when_input_is_not_sufficient() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), DAI, 100 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			transfer_to_pair(Alice, DAI, ONE);
+
+			// Act & Assert
+			precompiles()
+				.prepare_test(
+					Alice,
+					pair_address(),
+					PCall::swap {
+						amount0_out: U256::zero(),
+						amount1_out: (100 * ONE).into(),
+						to: Address(Bob.into()),
+						data: no_data(),
+					},
+				)
+				.execute_reverts(|output| output.starts_with(b"Dispatched call failed"));
+		});
+}
+
+#[test]
+fn swap_should_revert_when_nothing_is_transferred_to_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				pair_address(),
+				PCall::swap {
+					amount0_out: U256::zero(),
+					amount1_out: ONE.into(),
+					to: Address(Bob.into()),
+					data: no_data(),
+				},
+			)
+			.execute_reverts(|output| output == b"Insufficient input amount");
+	});
+}
+
+#[test]
+fn swap_should_revert_when_both_outputs_are_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				pair_address(),
+				PCall::swap {
+					amount0_out: ONE.into(),
+					amount1_out: ONE.into(),
+					to: Address(Bob.into()),
+					data: no_data(),
+				},
+			)
+			.execute_reverts(|output| output == b"Only one output amount is supported");
+	});
+}
+
+#[test]
+fn swap_should_revert_when_data_is_not_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				pair_address(),
+				PCall::swap {
+					amount0_out: U256::zero(),
+					amount1_out: ONE.into(),
+					to: Address(Bob.into()),
+					data: vec![1u8].into(),
+				},
+			)
+			.execute_reverts(|output| output == b"data: Flash swaps are not supported");
+	});
+}
+
+#[test]
+fn swap_should_revert_when_recipient_is_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				pair_address(),
+				PCall::swap {
+					amount0_out: U256::zero(),
+					amount1_out: ONE.into(),
+					to: Address(pair_address()),
+					data: no_data(),
+				},
+			)
+			.execute_reverts(|output| output == b"to: Invalid recipient");
+	});
+}
+
+#[test]
+fn mint_should_add_liquidity_and_send_shares_to_recipient() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), DAI, 100 * ONE), (Alice.into(), DOT, 500 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			transfer_to_pair(Alice, DAI, 100 * ONE);
+			transfer_to_pair(Alice, DOT, 500 * ONE);
+
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					pair_address(),
+					PCall::mint {
+						to: Address(Bob.into()),
+					},
+				)
+				.expect_log(sync_log(DAI_LIQUIDITY + 100 * ONE, DOT_LIQUIDITY + 400 * ONE + 1))
+				.expect_log(log2(
+					pair_address(),
+					SELECTOR_LOG_MINT,
+					Alice,
+					solidity::encode_event_data((U256::from(100 * ONE), U256::from(400 * ONE + 1))),
+				))
+				.execute_returns(U256::from(100 * ONE));
+
+			// Assert
+			assert_eq!(balance(Bob, share_token()), 100 * ONE);
+			assert_eq!(balance(pair_address(), DAI), 0);
+			assert_eq!(balance(pair_address(), DOT), 100 * ONE - 1);
+		});
+}
+
+#[test]
+fn mint_should_leave_token0_in_excess_of_pool_ratio_at_pair() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), DAI, 100 * ONE), (Alice.into(), DOT, 200 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			transfer_to_pair(Alice, DAI, 100 * ONE);
+			transfer_to_pair(Alice, DOT, 200 * ONE);
+
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					pair_address(),
+					PCall::mint {
+						to: Address(Bob.into()),
+					},
+				)
+				.execute_returns(U256::from(50 * ONE));
+
+			// Assert
+			assert_eq!(balance(Bob, share_token()), 50 * ONE);
+			assert_eq!(balance(pair_address(), DAI), 50 * ONE - 1);
+			assert_eq!(balance(pair_address(), DOT), 0);
+		});
+}
+
+#[test]
+fn mint_should_add_liquidity_when_tokens_are_transferred_in_pool_ratio() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), DAI, 100 * ONE), (Alice.into(), DOT, 400 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			transfer_to_pair(Alice, DAI, 100 * ONE);
+			transfer_to_pair(Alice, DOT, 400 * ONE);
+
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					pair_address(),
+					PCall::mint {
+						to: Address(Bob.into()),
+					},
+				)
+				.execute_returns(U256::from(100 * ONE - 1));
+
+			// Assert
+			assert_eq!(balance(Bob, share_token()), 100 * ONE - 1);
+			assert_eq!(balance(pair_address(), DAI), 0);
+			assert_eq!(balance(pair_address(), DOT), 4);
+		});
+}
+
+#[test]
+fn mint_should_revert_when_one_token_is_missing() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), DAI, 100 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			transfer_to_pair(Alice, DAI, 100 * ONE);
+
+			// Act & Assert
+			precompiles()
+				.prepare_test(
+					Alice,
+					pair_address(),
+					PCall::mint {
+						to: Address(Bob.into()),
+					},
+				)
+				.execute_reverts(|output| output == b"Insufficient liquidity minted");
+		});
+}
+
+#[test]
+fn burn_should_remove_liquidity_and_send_tokens_to_recipient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		transfer_to_pair(Provider, share_token(), 100 * ONE);
+
+		// Act
+		precompiles()
+			.prepare_test(
+				Alice,
+				pair_address(),
+				PCall::burn {
+					to: Address(Bob.into()),
+				},
+			)
+			.expect_log(sync_log(DAI_LIQUIDITY - 100 * ONE, DOT_LIQUIDITY - 400 * ONE))
+			.expect_log(log3(
+				pair_address(),
+				SELECTOR_LOG_BURN,
+				Alice,
+				Bob,
+				solidity::encode_event_data((U256::from(100 * ONE), U256::from(400 * ONE))),
+			))
+			.execute_returns((U256::from(100 * ONE), U256::from(400 * ONE)));
+
+		// Assert
+		assert_eq!(balance(Bob, DAI), 100 * ONE);
+		assert_eq!(balance(Bob, DOT), 400 * ONE);
+		assert_eq!(balance(pair_address(), share_token()), 0);
+	});
+}
+
+#[test]
+fn burn_should_revert_when_no_shares_are_transferred_to_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				pair_address(),
+				PCall::burn {
+					to: Address(Bob.into()),
+				},
+			)
+			.execute_reverts(|output| output == b"Insufficient liquidity burned");
+	});
+}
+
+#[test]
+fn skim_should_send_unused_tokens_to_recipient() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), DAI, 10 * ONE), (Alice.into(), DOT, 20 * ONE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			transfer_to_pair(Alice, DAI, 10 * ONE);
+			transfer_to_pair(Alice, DOT, 20 * ONE);
+
+			// Act
+			precompiles()
+				.prepare_test(
+					Alice,
+					pair_address(),
+					PCall::skim {
+						to: Address(Bob.into()),
+					},
+				)
+				.execute_returns(());
+
+			// Assert
+			assert_eq!(balance(Bob, DAI), 10 * ONE);
+			assert_eq!(balance(Bob, DOT), 20 * ONE);
+			assert_eq!(pool_balance(DAI), DAI_LIQUIDITY);
+			assert_eq!(pool_balance(DOT), DOT_LIQUIDITY);
+		});
+}
+
+#[test]
+fn sync_should_emit_reserves() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, pair_address(), PCall::sync {})
+			.expect_log(sync_log(DAI_LIQUIDITY, DOT_LIQUIDITY))
+			.execute_returns(());
+	});
+}
@@ -83,6 +83,7 @@ pub mod chain {
 	pub const OMNIPOOL_SOURCE: [u8; 8] = *b"omnipool";
 	pub const STABLESWAP_SOURCE: [u8; 8] = *b"stablesw";
 	pub const XYK_SOURCE: [u8; 8] = *b"hydraxyk";
+	pub const CONCENTRATED_LIQUIDITY_SOURCE: [u8; 8] = *b"conc-liq";
 	pub const GIGAHDX_SOURCE: [u8; 8] = *b"gigahdxs";
 
 	pub const DEFAULT_RELAY_PARENT_OFFSET: u32 = 1;
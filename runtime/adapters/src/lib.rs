@@ -28,6 +28,7 @@ use frame_support::{
 	traits::{Contains, ExistenceRequirement, LockIdentifier, OriginTrait},
 	weights::{Weight, WeightToFee},
 };
+use frame_system::ensure_signed;
 use hydra_dx_math::{
 	ema::EmaPrice,
 	ensure,
@@ -41,14 +42,16 @@ use hydradx_traits::{
 };
 use orml_traits::{GetByKey, MultiCurrency};
 use orml_xcm_support::{OnDepositFail, UnknownAsset as UnknownAssetT};
+use pallet_circuit_breaker::types::AccountLimitKind;
 use pallet_circuit_breaker::WeightInfo;
+use pallet_concentrated_liquidity::{ConcentratedLiquidityHooks, PoolState as ConcentratedLiquidityPoolState};
 use pallet_ema_oracle::{OnActivityHandler, OracleError, Price};
 use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
 use pallet_stableswap::types::{PoolState, StableswapHooks};
 use pallet_transaction_multi_payment::DepositFee;
 use polkadot_xcm::v5::prelude::*;
 use primitive_types::{U128, U512};
-use primitives::constants::chain::{STABLESWAP_SOURCE, XYK_SOURCE};
+use primitives::constants::chain::{CONCENTRATED_LIQUIDITY_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE};
 use primitives::{constants::chain::OMNIPOOL_SOURCE, AccountId, AssetId, Balance, BlockNumber, CollectionId};
 use sp_runtime::traits::BlockNumberProvider;
 use sp_std::vec;
@@ -325,6 +328,26 @@ where
 	}
 }
 
+/// Counts the hub asset value of a trade towards the trade volume limit of the trader.
+/// Within router, the trader is the account set as swapper, not the origin of the trade.
+fn note_account_trade_volume<Runtime>(
+	origin: <Runtime as frame_system::Config>::RuntimeOrigin,
+	hub_amount: Balance,
+) -> DispatchResult
+where
+	Runtime: pallet_circuit_breaker::Config + pallet_broadcast::Config,
+{
+	let Some(who) = pallet_broadcast::Pallet::<Runtime>::get_swapper().or_else(|| ensure_signed(origin).ok()) else {
+		return Ok(());
+	};
+
+	pallet_circuit_breaker::Pallet::<Runtime>::note_account_activity(
+		&who,
+		AccountLimitKind::TradeVolume,
+		hub_amount.into(),
+	)
+}
+
 /// Passes on trade and liquidity data from the omnipool to the oracle.
 pub struct OmnipoolHookAdapter<Origin, NativeAsset, Lrna, Runtime, MC, ProtocolFeeRecipient>(
 	PhantomData<(Origin, NativeAsset, Lrna, Runtime, MC, ProtocolFeeRecipient)>,
@@ -390,7 +413,7 @@ where
 	}
 
 	fn on_trade(
-		_origin: Origin,
+		origin: Origin,
 		asset_in: AssetInfo<AssetId, Balance>,
 		asset_out: AssetInfo<AssetId, Balance>,
 	) -> Result<Weight, Self::Error> {
@@ -432,10 +455,25 @@ where
 			amount_out.into(),
 		)?;
 
+		pallet_circuit_breaker::Pallet::<Runtime>::ensure_price_deviation_limit(
+			OMNIPOOL_SOURCE,
+			asset_in.asset_id.into(),
+			Lrna::get().into(),
+			Price::new(asset_in.after.reserve, asset_in.after.hub_reserve),
+		)?;
+		pallet_circuit_breaker::Pallet::<Runtime>::ensure_price_deviation_limit(
+			OMNIPOOL_SOURCE,
+			asset_out.asset_id.into(),
+			Lrna::get().into(),
+			Price::new(asset_out.after.reserve, asset_out.after.hub_reserve),
+		)?;
+
+		note_account_trade_volume::<Runtime>(origin, *asset_in.delta_changes.delta_hub_reserve)?;
+
 		Ok(Self::on_trade_weight())
 	}
 
-	fn on_hub_asset_trade(_origin: Origin, asset: AssetInfo<AssetId, Balance>) -> Result<Weight, Self::Error> {
+	fn on_hub_asset_trade(origin: Origin, asset: AssetInfo<AssetId, Balance>) -> Result<Weight, Self::Error> {
 		OnActivityHandler::<Runtime>::on_trade(
 			OMNIPOOL_SOURCE,
 			Lrna::get(),
@@ -460,6 +498,15 @@ where
 			amount_out.into(),
 		)?;
 
+		pallet_circuit_breaker::Pallet::<Runtime>::ensure_price_deviation_limit(
+			OMNIPOOL_SOURCE,
+			asset.asset_id.into(),
+			Lrna::get().into(),
+			Price::new(asset.after.reserve, asset.after.hub_reserve),
+		)?;
+
+		note_account_trade_volume::<Runtime>(origin, *asset.delta_changes.delta_hub_reserve)?;
+
 		Ok(Self::on_trade_weight())
 	}
 
@@ -475,7 +522,13 @@ where
 		let w1 = OnActivityHandler::<Runtime>::on_trade_weight().saturating_mul(2);
 		let w2 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_pool_state_change_limit();
 		let w3 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_trade_limit_entry();
-		w1.saturating_add(w2).saturating_add(w3)
+		let w4 =
+			<Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_price_deviation_limit().saturating_mul(2);
+		let w5 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::note_account_activity();
+		w1.saturating_add(w2)
+			.saturating_add(w3)
+			.saturating_add(w4)
+			.saturating_add(w5)
 	}
 
 	fn on_trade_fee(
@@ -617,6 +670,16 @@ where
 						Err(_) => return None,
 					}
 				}
+				PoolType::ConcentratedLiquidity => {
+					let price_result =
+						AggregatedPriceGetter::get_price(asset_a, asset_b, period, CONCENTRATED_LIQUIDITY_SOURCE);
+
+					match price_result {
+						Ok(price) => price.0,
+						Err(OracleError::SameAsset) => EmaPrice::from(1),
+						Err(_) => return None,
+					}
+				}
 				PoolType::Aave => EmaPrice::from(1),
 				_ => return None,
 			};
@@ -1008,7 +1071,7 @@ pub struct StableswapHooksAdapter<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> StableswapHooks<AssetId> for StableswapHooksAdapter<Runtime>
 where
-	Runtime: pallet_ema_oracle::Config + pallet_stableswap::Config,
+	Runtime: pallet_ema_oracle::Config + pallet_stableswap::Config + pallet_circuit_breaker::Config,
 {
 	fn on_liquidity_changed(pool_id: AssetId, state: PoolState<AssetId>) -> DispatchResult {
 		let pool_size = state.assets.len();
@@ -1049,12 +1112,7 @@ where
 		Ok(())
 	}
 
-	fn on_trade(
-		pool_id: AssetId,
-		_asset_in: AssetId,
-		_asset_out: AssetId,
-		state: PoolState<AssetId>,
-	) -> DispatchResult {
+	fn on_trade(pool_id: AssetId, asset_in: AssetId, asset_out: AssetId, state: PoolState<AssetId>) -> DispatchResult {
 		let pool_size = state.assets.len();
 
 		// As we access by index, let's ensure correct vec lengths.
@@ -1090,6 +1148,17 @@ where
 			.map_err(|(_, e)| e)?;
 		}
 
+		for asset_id in [asset_in, asset_out] {
+			if let Some(idx) = state.assets.iter().position(|a| *a == asset_id) {
+				pallet_circuit_breaker::Pallet::<Runtime>::ensure_price_deviation_limit(
+					STABLESWAP_SOURCE,
+					asset_id.into(),
+					pool_id.into(),
+					Price::new(state.share_prices[idx].0, state.share_prices[idx].1),
+				)?;
+			}
+		}
+
 		Ok(())
 	}
 
@@ -1098,7 +1167,86 @@ where
 	}
 
 	fn on_trade_weight(n: usize) -> Weight {
-		OnActivityHandler::<Runtime>::on_trade_weight().saturating_mul(n as u64)
+		OnActivityHandler::<Runtime>::on_trade_weight()
+			.saturating_mul(n as u64)
+			.saturating_add(
+				<Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_price_deviation_limit()
+					.saturating_mul(2),
+			)
+	}
+}
+
+/// Passes on trade and liquidity changed data from the concentrated liquidity pools to the oracle.
+pub struct ConcentratedLiquidityHooksAdapter<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> ConcentratedLiquidityHooks for ConcentratedLiquidityHooksAdapter<Runtime>
+where
+	Runtime: pallet_ema_oracle::Config + pallet_concentrated_liquidity::Config + pallet_circuit_breaker::Config,
+{
+	fn on_liquidity_changed(
+		_pool_id: pallet_concentrated_liquidity::PoolId,
+		state: ConcentratedLiquidityPoolState,
+	) -> DispatchResult {
+		// Pool price is the price of asset A denominated in asset B, oracle price is the ratio of A to B.
+		OnActivityHandler::<Runtime>::on_liquidity_changed(
+			CONCENTRATED_LIQUIDITY_SOURCE,
+			state.asset_a,
+			state.asset_b,
+			state.delta_a,
+			state.delta_b,
+			state.reserve_a,
+			state.reserve_b,
+			Price::new(state.price.1, state.price.0),
+			None,
+		)
+		.map_err(|(_, e)| e)?;
+
+		Ok(())
+	}
+
+	fn on_trade(
+		_pool_id: pallet_concentrated_liquidity::PoolId,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		state: ConcentratedLiquidityPoolState,
+	) -> DispatchResult {
+		OnActivityHandler::<Runtime>::on_trade(
+			CONCENTRATED_LIQUIDITY_SOURCE,
+			state.asset_a,
+			state.asset_b,
+			state.delta_a,
+			state.delta_b,
+			state.reserve_a,
+			state.reserve_b,
+			Price::new(state.price.1, state.price.0),
+			None,
+		)
+		.map_err(|(_, e)| e)?;
+
+		pallet_circuit_breaker::Pallet::<Runtime>::ensure_price_deviation_limit(
+			CONCENTRATED_LIQUIDITY_SOURCE,
+			state.asset_a.into(),
+			state.asset_b.into(),
+			Price::new(state.price.1, state.price.0),
+		)?;
+		pallet_circuit_breaker::Pallet::<Runtime>::ensure_price_deviation_limit(
+			CONCENTRATED_LIQUIDITY_SOURCE,
+			state.asset_b.into(),
+			state.asset_a.into(),
+			Price::new(state.price.0, state.price.1),
+		)?;
+
+		Ok(())
+	}
+
+	fn on_liquidity_changed_weight() -> Weight {
+		OnActivityHandler::<Runtime>::on_liquidity_changed_weight()
+	}
+
+	fn on_trade_weight() -> Weight {
+		OnActivityHandler::<Runtime>::on_trade_weight().saturating_add(
+			<Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_price_deviation_limit().saturating_mul(2),
+		)
 	}
 }
 
@@ -290,6 +290,7 @@ impl pallet_xyk::Config for Test {
 	type CanCreatePool = DummyCanCreatePool;
 	type AMMHandler = ();
 	type NonDustableWhitelistHandler = DummyDuster;
+	type AccountTradeVolumeHandler = ();
 }
 
 impl pallet_broadcast::Config for Test {}
@@ -309,6 +310,7 @@ parameter_types! {
 	pub DefaultMaxAddLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub DefaultMaxRemoveLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub const OmnipoolHubAsset: AssetId = LRNA;
+	pub const PriceDeviationOraclePeriod: OraclePeriod = OraclePeriod::Short;
 }
 
 pub struct NoIssuanceIncreaseLimit<T>(PhantomData<T>);
@@ -353,6 +355,8 @@ impl pallet_circuit_breaker::Config for Test {
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type TimestampProvider = Timestamp;
+	type PriceOracle = ();
+	type PriceDeviationOraclePeriod = PriceDeviationOraclePeriod;
 }
 
 pub struct Whitelist;
@@ -430,8 +434,30 @@ impl pallet_route_executor::Config for Test {
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type ForceInsertOrigin = EnsureRoot<Self::AccountId>;
 	type OraclePeriod = RouteValidationOraclePeriod;
+	type PoolsProvider = ();
+	type MaxRouteCandidates = frame_support::traits::ConstU32<10>;
+	type MaxRouteSearchSteps = frame_support::traits::ConstU32<100>;
 	type WeightInfo = ();
 }
+
+pub(crate) type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type RuntimeCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> hydradx_traits::CreateBare<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_bare(call: Self::RuntimeCall) -> Extrinsic {
+		Extrinsic::new_bare(call)
+	}
+}
 pub struct PriceProviderMock {}
 
 impl PriceOracle<AssetId> for PriceProviderMock {
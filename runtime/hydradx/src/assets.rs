@@ -38,10 +38,10 @@ use frame_support::{
 };
 use frame_system::{EnsureRoot, RawOrigin};
 use hydradx_adapters::{
-	stableswap_peg_oracle::PegOracle, AssetFeeOraclePriceProvider, EmaOraclePriceAdapter, FreezableNFT,
-	MultiCurrencyLockedBalance, OmnipoolHookAdapter, OmnipoolRawOracleAssetVolumeProvider, OraclePriceProvider,
-	PriceAdjustmentAdapter, RelayChainBlockHashProvider, RelayChainBlockNumberProvider, StableswapHooksAdapter,
-	VestingInfo,
+	stableswap_peg_oracle::PegOracle, AssetFeeOraclePriceProvider, ConcentratedLiquidityHooksAdapter,
+	EmaOraclePriceAdapter, FreezableNFT, MultiCurrencyLockedBalance, OmnipoolHookAdapter,
+	OmnipoolRawOracleAssetVolumeProvider, OraclePriceProvider, PriceAdjustmentAdapter, RelayChainBlockHashProvider,
+	RelayChainBlockNumberProvider, StableswapHooksAdapter, VestingInfo,
 };
 #[cfg(feature = "runtime-benchmarks")]
 use hydradx_traits::evm::CallContext;
@@ -58,11 +58,12 @@ use orml_traits::{
 	currency::{MultiCurrency, MultiLockableCurrency, MutationHooks, OnDeposit, OnTransfer},
 	GetByKey, Handler, Happened, NamedMultiReservableCurrency,
 };
+use pallet_concentrated_liquidity::{weights::WeightInfo as ConcentratedLiquidityWeights, ConcentratedLiquidityHooks};
 use pallet_currencies::{AssetTotalIssuance, BasicCurrencyAdapter};
 use pallet_dynamic_fees::types::FeeParams;
 use pallet_lbp::weights::WeightInfo as LbpWeights;
 use pallet_omnipool::{
-	traits::{EnsurePriceWithin, OmnipoolHooks},
+	traits::{EnsurePriceWithin, ExternalPriceProvider, OmnipoolHooks},
 	weights::WeightInfo as OmnipoolWeights,
 };
 use pallet_otc::NamedReserveIdentifier;
@@ -77,7 +78,7 @@ use pallet_staking::{
 use pallet_transaction_multi_payment::{AddTxAssetOnAccount, AssetIdOf, RemoveTxAssetOnKilled};
 use pallet_xyk::weights::WeightInfo as XykWeights;
 use primitives::constants::{
-	chain::{CORE_ASSET_ID, OMNIPOOL_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE},
+	chain::{CONCENTRATED_LIQUIDITY_SOURCE, CORE_ASSET_ID, OMNIPOOL_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE},
 	currency::{NATIVE_EXISTENTIAL_DEPOSIT, UNITS},
 	time::DAYS,
 };
@@ -612,6 +613,7 @@ impl Contains<AccountId> for DepositLockWhitelist {
 parameter_types! {
 	pub const DefaultMaxNetTradeVolumeLimitPerBlock: (u32, u32) = (5_000, 10_000);	// 50%
 	pub const DefaultMaxLiquidityLimitPerBlock: Option<(u32, u32)> = Some((500, 10_000));	// 5%
+	pub const PriceDeviationOraclePeriod: OraclePeriod = OraclePeriod::Short;
 }
 
 impl pallet_circuit_breaker::Config for Runtime {
@@ -629,6 +631,8 @@ impl pallet_circuit_breaker::Config for Runtime {
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = CircuitBreakerBenchmarkHelper<Runtime>;
 	type TimestampProvider = Timestamp;
+	type PriceOracle = EmaOracle;
+	type PriceDeviationOraclePeriod = PriceDeviationOraclePeriod;
 }
 
 parameter_types! {
@@ -643,7 +647,10 @@ parameter_types! {
 pub struct InternalOracleSources;
 impl Contains<Source> for InternalOracleSources {
 	fn contains(s: &Source) -> bool {
-		matches!(s, &OMNIPOOL_SOURCE | &STABLESWAP_SOURCE | &XYK_SOURCE)
+		matches!(
+			s,
+			&OMNIPOOL_SOURCE | &STABLESWAP_SOURCE | &XYK_SOURCE | &CONCENTRATED_LIQUIDITY_SOURCE
+		)
 	}
 }
 
@@ -696,6 +703,7 @@ impl Get<Vec<AccountId>> for ExtendedDustRemovalWhitelist {
 			// Arb profit can be smaller than the bought asset's ED and would otherwise be dust-reaped
 			// out of the pot before `settle_otc` measures it, spuriously failing the settlement.
 			pallet_otc_settlements::Pallet::<Runtime>::account_id(),
+			pallet_ice::Pallet::<Runtime>::holding_account(),
 		];
 
 		if let Some((flash_minter, loan_receiver)) = pallet_hsm::GetFlashMinterSupport::<Runtime>::get() {
@@ -919,6 +927,7 @@ parameter_types! {
 	pub NamedReserveId: NamedReserveIdentifier = *b"dcaorder";
 	pub MaxNumberOfRetriesOnError: u8 = 3;
 	pub ShortOraclePeriod: OraclePeriod = OraclePeriod::Short;
+	pub const DCATriggerOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
 }
 
 pub type FeePriceOracle = AssetFeeOraclePriceProvider<
@@ -962,11 +971,13 @@ impl pallet_dca::Config for Runtime {
 	type OraclePriceProvider = OraclePriceProvider<AssetId, EmaOracle, LRNA>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type OraclePriceProvider = DummyOraclePriceProvider;
+	type TriggerOraclePeriod = DCATriggerOraclePeriod;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type RouteExecutor = Router;
 	#[cfg(feature = "runtime-benchmarks")]
 	type RouteExecutor = pallet_route_executor::DummyRouter<Runtime>;
 	type RouteProvider = Router;
+	type SpotPriceProvider = Router;
 	type MaxPriceDifferenceBetweenBlocks = MaxPriceDifference;
 	type MaxConfigurablePriceDifferenceBetweenBlocks = MaxConfigurablePriceDifference;
 	type MinimalPeriod = MinimalPeriod;
@@ -1090,8 +1101,23 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					);
 					hsm_weight
 				}
+				PoolType::ConcentratedLiquidity => {
+					let mut cl_weight = weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::calculate_sell(
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+					.saturating_mul(c as u64);
+					cl_weight.saturating_accrue(
+						weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::sell(
+							ConcentratedLiquidityMaxSwapSteps::get(),
+						)
+						.saturating_add(<Runtime as pallet_concentrated_liquidity::Config>::Hooks::on_trade_weight())
+						.saturating_mul(e as u64),
+					);
+					cl_weight
+				}
 			};
 			weight.saturating_accrue(amm_weight);
+			weight.saturating_accrue(circuit_breaker::account_trade_volume_weight(trade.pool));
 		}
 
 		weight
@@ -1124,8 +1150,23 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 						.saturating_accrue(weights::pallet_hsm::HydraWeight::<Runtime>::buy().saturating_mul(e as u64));
 					hsm_weight
 				}
+				PoolType::ConcentratedLiquidity => {
+					let mut cl_weight = weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::calculate_buy(
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+					.saturating_mul(c as u64);
+					cl_weight.saturating_accrue(
+						weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::buy(
+							ConcentratedLiquidityMaxSwapSteps::get(),
+						)
+						.saturating_add(<Runtime as pallet_concentrated_liquidity::Config>::Hooks::on_trade_weight())
+						.saturating_mul(e as u64),
+					);
+					cl_weight
+				}
 			};
 			weight.saturating_accrue(amm_weight);
+			weight.saturating_accrue(circuit_breaker::account_trade_volume_weight(trade.pool));
 		}
 
 		weight
@@ -1156,6 +1197,20 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 						.saturating_accrue(weights::pallet_hsm::HydraWeight::<Runtime>::buy().saturating_mul(e as u64));
 					hsm_weight
 				}
+				PoolType::ConcentratedLiquidity => {
+					let mut cl_weight = weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::calculate_buy(
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+					.saturating_mul(c as u64);
+					cl_weight.saturating_accrue(
+						weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::buy(
+							ConcentratedLiquidityMaxSwapSteps::get(),
+						)
+						.saturating_add(<Runtime as pallet_concentrated_liquidity::Config>::Hooks::on_trade_weight())
+						.saturating_mul(e as u64),
+					);
+					cl_weight
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1187,8 +1242,23 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					);
 					hsm_weight
 				}
+				PoolType::ConcentratedLiquidity => {
+					let mut cl_weight = weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::calculate_sell(
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+					.saturating_mul(c as u64);
+					cl_weight.saturating_accrue(
+						weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::sell(
+							ConcentratedLiquidityMaxSwapSteps::get(),
+						)
+						.saturating_add(<Runtime as pallet_concentrated_liquidity::Config>::Hooks::on_trade_weight())
+						.saturating_mul(e as u64),
+					);
+					cl_weight
+				}
 			};
 			weight.saturating_accrue(amm_weight);
+			weight.saturating_accrue(circuit_breaker::account_trade_volume_weight(trade.pool));
 		}
 
 		weight
@@ -1219,8 +1289,23 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 						.saturating_accrue(weights::pallet_hsm::HydraWeight::<Runtime>::buy().saturating_mul(e as u64));
 					hsm_weight
 				}
+				PoolType::ConcentratedLiquidity => {
+					let mut cl_weight = weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::calculate_buy(
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+					.saturating_mul(c as u64);
+					cl_weight.saturating_accrue(
+						weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::buy(
+							ConcentratedLiquidityMaxSwapSteps::get(),
+						)
+						.saturating_add(<Runtime as pallet_concentrated_liquidity::Config>::Hooks::on_trade_weight())
+						.saturating_mul(e as u64),
+					);
+					cl_weight
+				}
 			};
 			weight.saturating_accrue(amm_weight);
+			weight.saturating_accrue(circuit_breaker::account_trade_volume_weight(trade.pool));
 		}
 
 		weight
@@ -1251,6 +1336,11 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::Aave => Aave::trade_weight(),
 				PoolType::HSM => weights::pallet_hsm::HydraWeight::<Runtime>::calculate_sell(),
+				PoolType::ConcentratedLiquidity => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::calculate_sell(
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1264,6 +1354,11 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::Aave => Aave::trade_weight(),
 				PoolType::HSM => weights::pallet_hsm::HydraWeight::<Runtime>::calculate_sell(),
+				PoolType::ConcentratedLiquidity => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::calculate_sell(
+						ConcentratedLiquidityMaxSwapSteps::get(),
+					)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1298,6 +1393,9 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::calculate_spot_price_with_fee(),
 				PoolType::Aave => Weight::zero(),
 				PoolType::HSM => weights::pallet_hsm::HydraWeight::<Runtime>::calculate_spot_price_with_fee(),
+				PoolType::ConcentratedLiquidity => {
+					weights::pallet_concentrated_liquidity::HydraWeight::<Runtime>::calculate_spot_price_with_fee()
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1313,6 +1411,8 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 parameter_types! {
 	pub const DefaultRoutePoolType: PoolType<AssetId> = PoolType::Omnipool;
 	pub const RouteValidationOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
+	pub const MaxRouteCandidates: u32 = 50;
+	pub const MaxRouteSearchSteps: u32 = 1_000;
 }
 
 impl pallet_route_executor::Config for Runtime {
@@ -1320,12 +1420,15 @@ impl pallet_route_executor::Config for Runtime {
 	type Balance = Balance;
 	type Currency = FungibleCurrencies<Runtime>;
 	type WeightInfo = RouterWeightInfo;
-	type AMM = (Omnipool, Stableswap, XYK, LBP, Aave, HSM);
+	type AMM = (Omnipool, Stableswap, XYK, LBP, Aave, HSM, ConcentratedLiquidity);
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type NativeAssetId = NativeAssetId;
 	type ForceInsertOrigin = EitherOf<EnsureRoot<Self::AccountId>, EitherOf<TechCommitteeMajority, GeneralAdmin>>;
 	type OraclePriceProvider = hydradx_adapters::OraclePriceProvider<AssetId, EmaOracle, LRNA>;
 	type OraclePeriod = RouteValidationOraclePeriod;
+	type PoolsProvider = (Omnipool, Stableswap, XYK, LBP, Aave, HSM, ConcentratedLiquidity);
+	type MaxRouteCandidates = MaxRouteCandidates;
+	type MaxRouteSearchSteps = MaxRouteSearchSteps;
 }
 
 parameter_types! {
@@ -1361,6 +1464,55 @@ impl pallet_otc_settlements::Config for Runtime {
 	type RouterWeightInfo = RouterWeightInfo;
 }
 
+parameter_types! {
+	pub const IcePalletId: PalletId = PalletId(*b"iceintnt");
+	pub const MaxIntentDuration: BlockNumber = 7 * DAYS;
+	pub const MaxResolvedIntents: u32 = 100;
+	pub const MaxSolutionTrades: u32 = 20;
+	pub const IntentDeposit: Balance = 10 * UNITS;
+}
+
+impl pallet_ice::Config for Runtime {
+	type Currency = Currencies;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Router = Router;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Router = pallet_route_executor::DummyRouter<Runtime>;
+	type PalletId = IcePalletId;
+	type NativeAssetId = NativeAssetId;
+	type IntentDeposit = IntentDeposit;
+	type MaxIntentDuration = MaxIntentDuration;
+	type MaxResolvedIntents = MaxResolvedIntents;
+	type MaxSolutionTrades = MaxSolutionTrades;
+	type RouterWeightInfo = RouterWeightInfo;
+	type WeightInfo = weights::pallet_ice::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const ConcentratedLiquidityPalletId: PalletId = PalletId(*b"py/concl");
+	pub const ConcentratedLiquidityCollectionId: CollectionId = 4242_u128;
+	pub const ConcentratedLiquidityMaxSwapSteps: u32 = 64;
+}
+
+impl pallet_concentrated_liquidity::Config for Runtime {
+	type Currency = Currencies;
+	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, OmnipoolAdmin>;
+	type AssetRegistry = AssetRegistry;
+	type PalletId = ConcentratedLiquidityPalletId;
+	type PositionItemId = ItemId;
+	type CollectionId = CollectionId;
+	type NFTCollectionId = ConcentratedLiquidityCollectionId;
+	type NFTHandler = Uniques;
+	type Hooks = ConcentratedLiquidityHooksAdapter<Runtime>;
+	type NonDustableWhitelistHandler = Duster;
+	type AccountTradeVolumeHandler = circuit_breaker::AccountTradeVolumeLimit;
+	type MinTradingLimit = MinTradingLimit;
+	type MaxSwapSteps = ConcentratedLiquidityMaxSwapSteps;
+	type WeightInfo = weights::pallet_concentrated_liquidity::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegisterAsset<Runtime>;
+}
+
 // Dynamic fees
 parameter_types! {
 	pub AssetFeeParams: FeeParams<Permill> = FeeParams{
@@ -1534,6 +1686,32 @@ impl<T: pallet_asset_registry::Config + pallet_ema_oracle::Config> BenchmarkHelp
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_asset_registry::Config> pallet_concentrated_liquidity::BenchmarkHelper<AssetId> for RegisterAsset<T> {
+	fn register_asset(asset_id: AssetId) -> DispatchResult {
+		let result = with_transaction(|| {
+			TransactionOutcome::Commit(AssetRegistry::register_sufficient_asset(
+				Some(asset_id),
+				None,
+				AssetKind::Token,
+				1,
+				None,
+				Some(12),
+				None,
+				None,
+			))
+		});
+
+		// don't throw error if the asset is already registered
+		if result.is_err_and(|e| e == pallet_asset_registry::Error::<Runtime>::AssetAlreadyRegistered.into()) {
+			return Ok(());
+		};
+
+		let _ = result?;
+		Ok(())
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl<T: pallet_ema_oracle::Config> pallet_ema_oracle::BenchmarkHelper<AssetId> for RegisterAsset<T> {
 	fn register_asset(asset_id: AssetId) -> DispatchResult {
@@ -1570,6 +1748,7 @@ impl pallet_stableswap::Config for Runtime {
 	type UpdateTradabilityOrigin = EitherOf<EnsureRoot<Self::AccountId>, TechCommitteeMajority>;
 	type DustAccountHandler = Duster;
 	type Hooks = StableswapHooksAdapter<Runtime>;
+	type AccountTradeVolumeHandler = circuit_breaker::AccountTradeVolumeLimit;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MinTradingLimit = MinTradingLimit;
 	type AmplificationRange = StableswapAmplificationRange;
@@ -1696,6 +1875,45 @@ where
 	}
 }
 
+/// Seeds XYK pools and Omnipool positions with the liquidity of finalized LBP sales.
+pub struct LBPProceedsHandler;
+
+impl pallet_lbp::ProceedsHandler<AccountId> for LBPProceedsHandler {
+	fn seed_xyk(
+		who: &AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+	) -> DispatchResult {
+		XYK::create_pool(
+			RawOrigin::Signed(who.clone()).into(),
+			asset_a,
+			amount_a,
+			asset_b,
+			amount_b,
+		)
+	}
+
+	fn seed_omnipool(who: &AccountId, asset: AssetId, amount: Balance) -> DispatchResult {
+		Omnipool::add_liquidity(RawOrigin::Signed(who.clone()).into(), asset, amount)
+	}
+
+	fn seed_xyk_weight() -> Weight {
+		weights::pallet_xyk::HydraWeight::<Runtime>::create_pool()
+	}
+
+	fn seed_omnipool_weight() -> Weight {
+		weights::pallet_omnipool::HydraWeight::<Runtime>::add_liquidity()
+			.saturating_add(<Runtime as pallet_omnipool::Config>::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(<Runtime as pallet_omnipool::Config>::ExternalPriceOracle::get_price_weight())
+	}
+}
+
+parameter_types! {
+	pub const LBPPalletId: PalletId = PalletId(*b"py/lbpsl");
+}
+
 impl pallet_lbp::Config for Runtime {
 	type MultiCurrency = Currencies;
 	type LockedBalance = MultiCurrencyLockedBalance<Runtime, NativeAssetId>;
@@ -1708,6 +1926,9 @@ impl pallet_lbp::Config for Runtime {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type ProceedsHandler = LBPProceedsHandler;
+	type PalletId = LBPPalletId;
+	type AccountTradeVolumeHandler = circuit_breaker::AccountTradeVolumeLimit;
 }
 
 parameter_types! {
@@ -1729,6 +1950,7 @@ impl pallet_xyk::Config for Runtime {
 	type CanCreatePool = hydradx_adapters::xyk::AllowPoolCreation<Runtime, AssetRegistry>;
 	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
 	type NonDustableWhitelistHandler = Duster;
+	type AccountTradeVolumeHandler = circuit_breaker::AccountTradeVolumeLimit;
 	type OracleSource = XYKOracleSourceIdentifier;
 }
 
@@ -35,8 +35,8 @@ use hydradx_traits::router::PoolType;
 use hydradx_traits::router::MAX_NUMBER_OF_TRADES;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, NamedMultiReservableCurrency};
-use pallet_dca::types::{Order, Schedule, ScheduleId};
-use pallet_dca::{ScheduleIdsPerBlock, Schedules};
+use pallet_dca::types::{Order, Schedule, ScheduleId, ScheduleUpdate};
+use pallet_dca::{PausedSchedules, ScheduleIdsPerBlock, Schedules};
 use pallet_route_executor::Trade;
 use scale_info::prelude::vec::Vec;
 use sp_runtime::traits::ConstU32;
@@ -68,6 +68,7 @@ fn schedule_fake(
 		total_amount: 1100 * ONE,
 		max_retries: None,
 		stability_threshold: None,
+		trigger: None,
 		slippage: Some(Permill::from_percent(15)),
 		order: Order::Buy {
 			asset_in,
@@ -100,6 +101,7 @@ fn schedule_buy_fake(
 		total_amount: 2000 * ONE,
 		max_retries: None,
 		stability_threshold: None,
+		trigger: None,
 		slippage: Some(Permill::from_percent(15)),
 		order: Order::Buy {
 			asset_in,
@@ -128,6 +130,7 @@ fn schedule_sell_fake(
 		total_amount: 2000 * ONE,
 		max_retries: None,
 		stability_threshold: None,
+		trigger: None,
 		slippage: Some(Permill::from_percent(100)),
 		order: Order::Sell {
 			asset_in,
@@ -481,6 +484,7 @@ runtime_benchmarks! {
 			total_amount: 1100 * ONE,
 			max_retries: None,
 			stability_threshold: None,
+			trigger: None,
 			slippage: Some(Permill::from_percent(15)),
 			order: Order::Buy {
 				asset_in: asset_1,
@@ -540,6 +544,93 @@ runtime_benchmarks! {
 		assert_eq!((MaxSchedulesPerBlock::get() - 1) as usize, <ScheduleIdsPerBlock<Runtime>>::get::<BlockNumber>(execution_block).len());
 	}
 
+	pause {
+		let caller: AccountId = create_account_with_native_balance()?;
+		fund_treasury()?; //Fund treasury with some HDX to prevent BelowMinimum issue due to low fee
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+
+		set_period(99);
+
+		let execution_block = 105u32;
+
+		// Fill block with MaxSchedulesPerBlock schedules to test worst case for linear search
+		for _ in 0..MaxSchedulesPerBlock::get() {
+			assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1.clone(), Option::Some(execution_block)));
+		}
+
+		let schedule_id: ScheduleId = MaxSchedulesPerBlock::get() - 1;
+
+	}: _(RawOrigin::Signed(caller.clone()), schedule_id)
+	verify {
+		assert!(<PausedSchedules<Runtime>>::get::<ScheduleId>(schedule_id).is_some());
+		assert_eq!((MaxSchedulesPerBlock::get() - 1) as usize, <ScheduleIdsPerBlock<Runtime>>::get::<BlockNumber>(execution_block).len());
+	}
+
+	resume {
+		let caller: AccountId = create_account_with_native_balance()?;
+		fund_treasury()?; //Fund treasury with some HDX to prevent BelowMinimum issue due to low fee
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+
+		set_period(99);
+
+		let execution_block = 105u32;
+
+		let schedule_id: ScheduleId = 0;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1.clone(), Option::Some(execution_block)));
+		assert_ok!(DCA::pause(RawOrigin::Signed(caller.clone()).into(), schedule_id));
+
+		//We fill blocks with schedules leaving only one place
+		let number_of_all_schedules = MaxSchedulesPerBlock::get() + MaxSchedulesPerBlock::get() * RETRY_TO_SEARCH_FOR_FREE_BLOCK - 1;
+		for _ in 0..number_of_all_schedules {
+			assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1.clone(), Option::Some(execution_block)));
+		}
+
+	}: _(RawOrigin::Signed(caller.clone()), schedule_id, Option::Some(execution_block))
+	verify {
+		assert!(<PausedSchedules<Runtime>>::get::<ScheduleId>(schedule_id).is_none());
+		assert_eq!((MaxSchedulesPerBlock::get()) as usize, <ScheduleIdsPerBlock<Runtime>>::get::<BlockNumber>(execution_block + DELAY_AFTER_LAST_RADIUS).len());
+	}
+
+	update_schedule {
+		let caller: AccountId = create_account_with_native_balance()?;
+		fund_treasury()?; //Fund treasury with some HDX to prevent BelowMinimum issue due to low fee
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+
+		set_period(99);
+
+		let schedule_id: ScheduleId = 0;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::None));
+
+		let update = ScheduleUpdate {
+			period: Some(10u32),
+			slippage: Some(Some(Permill::from_percent(5))),
+			stability_threshold: Some(None),
+			budget_top_up: Some(1000 * ONE),
+			route: Some(create_bounded_vec(vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			}])),
+			routes: None,
+		};
+
+	}: _(RawOrigin::Signed(caller.clone()), schedule_id, update)
+	verify {
+		assert_eq!(<Schedules<Runtime>>::get::<ScheduleId>(schedule_id).unwrap().total_amount, 2100 * ONE);
+	}
+
 	unlock_reserves {
 		let caller: AccountId = create_account_with_native_balance()?;
 		fund_treasury()?; //Fund treasury with some HDX to prevent BelowMinimum issue due to low fee
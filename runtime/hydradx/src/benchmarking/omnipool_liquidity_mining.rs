@@ -15,7 +15,7 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::benchmarking::{register_asset, register_asset_with_decimals, update_deposit_limit};
+use crate::benchmarking::{register_asset, register_asset_with_decimals, register_asset_with_id, update_deposit_limit};
 use crate::*;
 use frame_benchmarking::{account, BenchmarkError};
 use frame_support::storage::with_transaction;
@@ -29,10 +29,11 @@ use hydradx_traits::registry::{AssetKind, Create};
 use hydradx_traits::stableswap::AssetAmount;
 use orml_benchmarking::runtime_benchmarks;
 use primitives::AssetId;
+use scale_info::prelude::string::ToString;
 use sp_runtime::traits::ConstU32;
 use sp_runtime::{traits::One, FixedU128, Permill};
 use sp_runtime::{DispatchError, DispatchResult, Perquintill, TransactionOutcome};
-use warehouse_liquidity_mining::LoyaltyCurve;
+use warehouse_liquidity_mining::{LoyaltyCurve, RewardSchedule, MAX_REWARD_STREAMS_PER_GLOBAL_FARM};
 type XykPallet<T> = pallet_xyk::Pallet<T>;
 type Router<T> = pallet_route_executor::Pallet<T>;
 use hydradx_traits::liquidity_mining::PriceAdjustment;
@@ -52,6 +53,8 @@ const DOT: AssetId = 1_000_004;
 
 const G_FARM_TOTAL_REWARDS: Balance = 10_000_000 * ONE;
 const REWARD_CURRENCY: AssetId = HDX;
+// Reward currencies of the reward streams added to global farms by `initialize_global_farm()`.
+const REWARD_STREAM_ASSETS: [AssetId; MAX_REWARD_STREAMS_PER_GLOBAL_FARM as usize] = [100_001, 100_002, 100_003];
 
 fn fund(to: AccountId, currency: AssetId, amount: Balance) -> DispatchResult {
 	Currencies::deposit(currency, &to, amount)
@@ -139,7 +142,15 @@ fn create_share_funded_account(
 	account
 }
 
+// Global farm with max. number of running reward streams, so farm's syncs and claims are
+// benchmarked in the worst case.
 fn initialize_global_farm(owner: AccountId) -> DispatchResult {
+	initialize_global_farm_without_reward_streams(owner.clone())?;
+
+	add_reward_streams(owner, OmnipoolWarehouseLM::last_farm_id())
+}
+
+fn initialize_global_farm_without_reward_streams(owner: AccountId) -> DispatchResult {
 	OmnipoolLiquidityMining::create_global_farm(
 		RawOrigin::Root.into(),
 		G_FARM_TOTAL_REWARDS,
@@ -155,6 +166,31 @@ fn initialize_global_farm(owner: AccountId) -> DispatchResult {
 	seed_lm_pot()
 }
 
+fn add_reward_streams(owner: AccountId, global_farm_id: GlobalFarmId) -> DispatchResult {
+	let start = System::block_number();
+
+	for asset in REWARD_STREAM_ASSETS {
+		if !pallet_asset_registry::Assets::<Runtime>::contains_key(asset) {
+			register_asset_with_id([b"RS", asset.to_string().as_bytes()].concat(), asset)
+				.map_err(|_| DispatchError::Other("Failed to register asset"))?;
+		}
+
+		fund(owner.clone(), asset, G_FARM_TOTAL_REWARDS)?;
+
+		OmnipoolLiquidityMining::add_reward_stream(
+			RawOrigin::Signed(owner.clone()).into(),
+			global_farm_id,
+			asset,
+			G_FARM_TOTAL_REWARDS,
+			start,
+			start + 100_000,
+			RewardSchedule::Streaming,
+		)?;
+	}
+
+	Ok(())
+}
+
 fn initialize_yield_farm(owner: AccountId, id: GlobalFarmId, asset: AssetId) -> DispatchResult {
 	OmnipoolLiquidityMining::create_yield_farm(RawOrigin::Signed(owner).into(), id, asset, FixedU128::one(), None)
 }
@@ -431,6 +467,24 @@ runtime_benchmarks! {
 	}: _(RawOrigin::Signed(owner), global_farm_id)
 
 
+	add_reward_stream {
+		let owner = funded_account("owner", 0, &[REWARD_CURRENCY, BSX, ETH, DAI]);
+		let global_farm_id = 1;
+
+		initialize_omnipool(None)?;
+
+		initialize_global_farm_without_reward_streams(owner.clone())?;
+		initialize_yield_farm(owner.clone(), global_farm_id, BTC)?;
+
+		set_period(100);
+
+		//NOTE: worst case is adding last reward stream to the global farm.
+		for asset in [BSX, ETH] {
+			OmnipoolLiquidityMining::add_reward_stream(RawOrigin::Signed(owner.clone()).into(), global_farm_id, asset, G_FARM_TOTAL_REWARDS, 200, 1_000, RewardSchedule::Streaming)?;
+		}
+	}: _(RawOrigin::Signed(owner), global_farm_id, DAI, G_FARM_TOTAL_REWARDS, 200, 1_000, RewardSchedule::Streaming)
+
+
 	create_yield_farm {
 		fund_treasury().unwrap(); //To prevent BelowMinimum error
 
@@ -33,10 +33,13 @@ use hydradx_traits::AMM;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use pallet_xyk::types::AssetPair;
+use scale_info::prelude::string::ToString;
 use sp_std::vec;
-use warehouse_liquidity_mining::{GlobalFarmData, GlobalFarmId, LoyaltyCurve};
+use warehouse_liquidity_mining::{
+	GlobalFarmData, GlobalFarmId, LoyaltyCurve, RewardSchedule, MAX_REWARD_STREAMS_PER_GLOBAL_FARM,
+};
 type Router<T> = pallet_route_executor::Pallet<T>;
-use crate::benchmarking::{register_asset, register_external_asset, update_deposit_limit};
+use crate::benchmarking::{register_asset, register_asset_with_id, register_external_asset, update_deposit_limit};
 use crate::XYKLiquidityMiningInstance;
 use hydradx_traits::liquidity_mining::PriceAdjustment;
 use hydradx_traits::router::AssetPair as RouteAssetPair;
@@ -48,11 +51,27 @@ pub const ONE: Balance = 1_000_000_000_000;
 
 pub const INITIAL_BALANCE: Balance = 10_000_000 * ONE;
 
+// Reward currencies of the reward streams added to global farms by `create_gfarm()`.
+const REWARD_STREAM_ASSETS: [AssetId; MAX_REWARD_STREAMS_PER_GLOBAL_FARM as usize] = [100_001, 100_002, 100_003];
+
+// Global farm with max. number of running reward streams, so farm's syncs and claims are
+// benchmarked in the worst case.
 fn create_gfarm(
 	owner: AccountId,
 	incentivized_asset: AssetId,
 	reward_currency: AssetId,
 	total_rewards: Balance,
+) -> DispatchResult {
+	create_gfarm_without_reward_streams(owner.clone(), incentivized_asset, reward_currency, total_rewards)?;
+
+	add_reward_streams(owner, XYKWarehouseLM::last_farm_id())
+}
+
+fn create_gfarm_without_reward_streams(
+	owner: AccountId,
+	incentivized_asset: AssetId,
+	reward_currency: AssetId,
+	total_rewards: Balance,
 ) -> DispatchResult {
 	let planned_yielding_periods = BlockNumber::from(1_000_000_u32);
 	let yield_per_period = Perquintill::from_percent(20);
@@ -73,6 +92,31 @@ fn create_gfarm(
 	)
 }
 
+fn add_reward_streams(owner: AccountId, gfarm_id: GlobalFarmId) -> DispatchResult {
+	let start = System::block_number();
+
+	for asset in REWARD_STREAM_ASSETS {
+		if !pallet_asset_registry::Assets::<Runtime>::contains_key(asset) {
+			register_asset_with_id([b"RS", asset.to_string().as_bytes()].concat(), asset)
+				.map_err(|_| DispatchError::Other("Failed to register asset"))?;
+		}
+
+		Currencies::update_balance(RawOrigin::Root.into(), owner.clone(), asset, INITIAL_BALANCE as i128)?;
+
+		XYKLiquidityMining::add_reward_stream(
+			RawOrigin::Signed(owner.clone()).into(),
+			gfarm_id,
+			asset,
+			1_000_000 * ONE,
+			start,
+			start + 1_000_000,
+			RewardSchedule::Streaming,
+		)?;
+	}
+
+	Ok(())
+}
+
 fn create_yfarm(caller: AccountId, farm_id: GlobalFarmId, assets: AssetPair, multiplier: FixedU128) -> DispatchResult {
 	XYKLiquidityMining::create_yield_farm(
 		RawOrigin::Signed(caller).into(),
@@ -179,6 +223,27 @@ runtime_benchmarks! {
 	}: _(RawOrigin::Signed(farm_owner), gfarm_id)
 	//NOTE: farm is removed from storage lazylly and prop to check is private
 
+	add_reward_stream {
+		let pair = AssetPair {
+			asset_in: register_external_asset(b"TKN1".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?,
+			asset_out: register_external_asset(b"TKN2".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?
+		};
+		let stream_asset_1 = register_external_asset(b"TKN3".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let stream_asset_2 = register_external_asset(b"TKN4".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		let farm_owner = funded_account("caller", 0, &[HDX, pair.asset_in, pair.asset_out, stream_asset_1, stream_asset_2]);
+
+		let gfarm_id = 1;
+		create_gfarm_without_reward_streams(farm_owner.clone(), pair.asset_in, pair.asset_out, 9_000_000 * ONE)?;
+
+		run_to_block(100);
+
+		//NOTE: worst case is adding last reward stream to the global farm.
+		for asset in [HDX, stream_asset_1] {
+			XYKLiquidityMining::add_reward_stream(RawOrigin::Signed(farm_owner.clone()).into(), gfarm_id, asset, 1_000_000 * ONE, 200, 1_000, RewardSchedule::Streaming)?;
+		}
+	}: _(RawOrigin::Signed(farm_owner), gfarm_id, stream_asset_2, 1_000_000 * ONE, 200, 1_000, RewardSchedule::Streaming)
+
 	create_yield_farm {
 		let pair = AssetPair {
 			asset_in: register_external_asset(b"TKN1".to_vec()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?,
@@ -2,9 +2,11 @@ use super::*;
 use crate::assets::XykPaymentAssetSupport;
 use crate::types::TenMinutesOraclePrice;
 use hydradx_adapters::price::ConvertBalance;
-use hydradx_traits::circuit_breaker::{AssetWithdrawHandler, WithdrawFuseControl};
+use hydradx_traits::circuit_breaker::{AccountTradeVolumeHandler, AssetWithdrawHandler, WithdrawFuseControl};
+use hydradx_traits::router::PoolType;
 use pallet_asset_registry::AssetType;
-use pallet_circuit_breaker::types::EgressOperationKind;
+use pallet_circuit_breaker::types::{AccountLimitKind, EgressOperationKind};
+use pallet_circuit_breaker::weights::WeightInfo as _;
 use pallet_circuit_breaker::GlobalAssetCategory;
 use primitives::Balance;
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
@@ -104,11 +106,12 @@ impl WithdrawCircuitBreaker {
 }
 
 pub struct OnWithdrawHook;
-impl orml_traits::Handler<(AssetId, Balance)> for OnWithdrawHook {
-	fn handle(t: &(AssetId, Balance)) -> DispatchResult {
-		// `who` is not used: in XCM path all withdrawals go to buffer regardless of origin;
-		// in non-XCM path Withdraw is always accounted for both Local and External assets regardless of who.
-		let (asset_id, amount) = t;
+impl orml_traits::Handler<(AssetId, Balance, AccountId)> for OnWithdrawHook {
+	fn handle(t: &(AssetId, Balance, AccountId)) -> DispatchResult {
+		// In XCM path all withdrawals go to buffer regardless of origin;
+		// in non-XCM path Withdraw is always accounted for both Local and External assets.
+		// In both paths the withdrawal is counted towards the egress limit of `who`.
+		let (asset_id, amount, who) = t;
 
 		if !WithdrawCircuitBreaker::should_account_withdraw_operation(*asset_id, EgressOperationKind::Withdraw, None) {
 			return Ok(());
@@ -116,6 +119,12 @@ impl orml_traits::Handler<(AssetId, Balance)> for OnWithdrawHook {
 
 		let amount_ref_currency = WithdrawCircuitBreaker::convert_to_hdx(*asset_id, *amount)?;
 
+		pallet_circuit_breaker::Pallet::<Runtime>::note_account_activity(
+			who,
+			AccountLimitKind::Egress,
+			amount_ref_currency,
+		)?;
+
 		if let Some(mut buffer) = pallet_circuit_breaker::XcmEgressBuffer::<Runtime>::get() {
 			buffer.0 = buffer.0.saturating_add(amount_ref_currency);
 			pallet_circuit_breaker::XcmEgressBuffer::<Runtime>::put(buffer);
@@ -143,6 +152,11 @@ impl orml_traits::currency::OnTransfer<AccountId, AssetId, Balance> for OnTransf
 		{
 			let amount_ref_currency = try_convert()?;
 			pallet_circuit_breaker::Pallet::<Runtime>::note_egress(amount_ref_currency)?;
+			pallet_circuit_breaker::Pallet::<Runtime>::note_account_activity(
+				from,
+				AccountLimitKind::Egress,
+				amount_ref_currency,
+			)?;
 		}
 
 		// Ingress: transfer FROM an egress account, only for Local assets
@@ -211,3 +225,69 @@ impl<T: pallet_circuit_breaker::Config> WithdrawFuseControl for IgnoreWithdrawFu
 		pallet_circuit_breaker::IgnoreWithdrawLimit::<T>::set(!value);
 	}
 }
+
+/// Counts trades executed in Stableswap, XYK, LBP and concentrated liquidity pools towards the trade volume limit
+/// of the swapper. Used as `AccountTradeVolumeHandler` of these pallets, so both direct trades and trades executed
+/// by the router are counted.
+/// Omnipool trades are counted by the Omnipool hooks, so they are not counted here again.
+pub struct AccountTradeVolumeLimit;
+
+fn is_trade_volume_counted(pool_type: PoolType<AssetId>) -> bool {
+	matches!(
+		pool_type,
+		PoolType::Stableswap(_) | PoolType::XYK | PoolType::LBP | PoolType::ConcentratedLiquidity
+	)
+}
+
+/// Weight of counting a trade in `pool_type` towards the trade volume limit by `AccountTradeVolumeLimit`.
+pub fn account_trade_volume_weight(pool_type: PoolType<AssetId>) -> Weight {
+	if !is_trade_volume_counted(pool_type) {
+		return Weight::zero();
+	}
+
+	<AccountTradeVolumeLimit as AccountTradeVolumeHandler<AccountId, AssetId, Balance>>::note_trade_volume_weight()
+}
+
+impl AccountTradeVolumeLimit {
+	/// Value of `amount` of `asset_id` in hub asset units, based on the Omnipool state.
+	/// Assets that are not in the Omnipool are converted to the native asset first.
+	fn convert_to_hub_asset(asset_id: AssetId, amount: Balance) -> Result<Balance, DispatchError> {
+		let (asset_id, amount) = if Omnipool::exists(asset_id) {
+			(asset_id, amount)
+		} else {
+			let native_asset = NativeAssetId::get();
+			(native_asset, WithdrawCircuitBreaker::convert_to_hdx(asset_id, amount)?)
+		};
+
+		let state = Omnipool::load_asset_state(asset_id)?;
+		multiply_by_rational_with_rounding(amount, state.hub_reserve, state.reserve, Rounding::Up)
+			.ok_or_else(|| pallet_circuit_breaker::Error::<Runtime>::FailedToConvertAsset.into())
+	}
+}
+
+impl AccountTradeVolumeHandler<AccountId, AssetId, Balance> for AccountTradeVolumeLimit {
+	fn note_trade_volume(who: &AccountId, asset_id: AssetId, amount: Balance) -> DispatchResult {
+		// Trades executed by the router are counted towards the limit of the swapper.
+		let who = Broadcast::get_swapper().unwrap_or_else(|| who.clone());
+
+		// The price of the asset is only needed when the trade is limited, so that trades of assets without a price
+		// don't fail when the limit is not configured or the account is exempt from it.
+		if !pallet_circuit_breaker::Pallet::<Runtime>::is_account_limited(&who, AccountLimitKind::TradeVolume) {
+			return Ok(());
+		}
+
+		let hub_amount = Self::convert_to_hub_asset(asset_id, amount)?;
+
+		pallet_circuit_breaker::Pallet::<Runtime>::note_account_activity(
+			&who,
+			AccountLimitKind::TradeVolume,
+			hub_amount,
+		)
+	}
+
+	fn note_trade_volume_weight() -> Weight {
+		// Broadcast::Swapper, Omnipool::Assets and the Omnipool reserve, price of the asset in the native asset
+		<Runtime as pallet_circuit_breaker::Config>::WeightInfo::note_account_activity()
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(4))
+	}
+}
@@ -19,7 +19,7 @@ use hydradx_traits::evm::EVM;
 use hydradx_traits::evm::{CallContext, CallResult, Erc20Mapping, InspectEvmAccounts, ERC20};
 use sp_runtime::traits::Convert;
 
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, Trade, TradeExecution, TradeablePoolsProvider};
 use hydradx_traits::BoundErc20;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use pallet_broadcast::types::Asset;
@@ -192,6 +192,24 @@ where
 		Ok(addresses)
 	}
 
+	/// Returns the (reserve, atoken) asset pairs of the Aave pool.
+	pub fn pairs() -> Vec<(AssetId, AssetId)> {
+		let pool = <BorrowingContract<T>>::get();
+		let Ok(reserves) = Self::get_reserves_list(pool) else {
+			return vec![];
+		};
+
+		reserves
+			.into_iter()
+			.filter_map(|reserve| {
+				let data = Self::get_reserve_data(pool, reserve).ok()?;
+				let reserve_asset = HydraErc20Mapping::address_to_asset(reserve)?;
+				let atoken_asset = HydraErc20Mapping::address_to_asset(data.atoken_address)?;
+				Some((reserve_asset, atoken_asset))
+			})
+			.collect()
+	}
+
 	pub fn get_reserve_data(pool: EvmAddress, asset: EvmAddress) -> Result<ReserveData, ExecutorError<DispatchError>> {
 		let context = CallContext::new_view(pool);
 		let data = EvmDataWriter::new_with_selector(Function::GetReserveData)
@@ -579,6 +597,43 @@ pub struct PoolData<Balance> {
 	pub liqudity_out: Balance,
 }
 
+impl<T> TradeablePoolsProvider<AssetId> for AaveTradeExecutor<T>
+where
+	T: pallet_evm::Config
+		+ pallet_asset_registry::Config<AssetId = AssetId>
+		+ pallet_liquidation::Config
+		+ pallet_evm_accounts::Config
+		+ pallet_broadcast::Config
+		+ pallet_dispatcher::Config
+		+ frame_system::Config<AccountId = sp_runtime::AccountId32>,
+	T::AssetNativeLocation: Into<Location>,
+	BalanceOf<T>: TryFrom<U256> + Into<U256>,
+	T::AddressMapping: pallet_evm::AddressMapping<T::AccountId>,
+	<T as frame_system::Config>::AccountId: AsRef<[u8; 32]> + IsType<AccountId32>,
+	pallet_evm::AccountIdOf<T>: From<T::AccountId>,
+	NonceIdOf<T>: Into<T::Nonce>,
+{
+	fn tradeable_pools() -> Vec<Trade<AssetId>> {
+		Self::pairs()
+			.into_iter()
+			.flat_map(|(reserve, atoken)| {
+				[
+					Trade {
+						pool: PoolType::Aave,
+						asset_in: reserve,
+						asset_out: atoken,
+					},
+					Trade {
+						pool: PoolType::Aave,
+						asset_in: atoken,
+						asset_out: reserve,
+					},
+				]
+			})
+			.collect()
+	}
+}
+
 pub mod runtime_api {
 	use super::AssetId;
 	use super::PoolData;
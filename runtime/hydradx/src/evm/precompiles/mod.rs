@@ -36,6 +36,7 @@ use pallet_evm_precompile_blake2::Blake2F;
 use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
 use pallet_evm_precompile_modexp::Modexp;
 use pallet_evm_precompile_simple::{ECRecover, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_stableswap::is_pool_address as is_stableswap_pool_address;
 use sp_runtime::traits::{Dispatchable, One};
 
 use codec::alloc;
@@ -103,6 +104,10 @@ pub const CALLPERMIT: H160 = H160(hex!("000000000000000000000000000000000000080a
 pub const FLASH_LOAN_RECEIVER: H160 = H160(hex!("000000000000000000000000000000000000090a"));
 /// Lock-manager precompile address consumed by `LockableAToken.sol`.
 pub const LOCK_MANAGER: H160 = H160(hex!("0000000000000000000000000000000000000806"));
+/// Router precompile address, executes trades via the route executor.
+pub const ROUTER: H160 = H160(hex!("0000000000000000000000000000000000000807"));
+/// Staking precompile address, exposes HDX staking, GIGAHDX staking and conviction voting.
+pub const STAKING: H160 = H160(hex!("0000000000000000000000000000000000000808"));
 
 pub const ETH_PRECOMPILE_END: H160 = BLAKE2F;
 
@@ -148,6 +153,10 @@ where
 	<<R as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<pallet_evm::AccountIdOf<R>>>,
 	MultiCurrencyPrecompile<R>: Precompile,
 	ChainlinkOraclePrecompile<R>: Precompile,
+	pallet_evm_precompile_router::RouterPrecompile<R>: Precompile,
+	pallet_evm_precompile_staking::StakingPrecompile<R>: Precompile,
+	pallet_evm_precompile_stableswap::StableswapPrecompile<R, erc20_mapping::HydraErc20Mapping>: Precompile,
+	pallet_evm_precompile_xyk::XykPairPrecompile<R, erc20_mapping::HydraErc20Mapping>: Precompile,
 	<R as frame_system::pallet::Config>::AccountId: AsRef<[u8; 32]> + IsType<AccountId32>,
 	<R as pallet_stableswap::pallet::Config>::AssetId: From<u32>,
 	R::AddressMapping: pallet_evm::AddressMapping<R::AccountId>,
@@ -197,6 +206,10 @@ where
 				R,
 				crate::evm::precompiles::GigaHdxATokenAddress,
 			>::execute(handle))
+		} else if address == ROUTER {
+			Some(pallet_evm_precompile_router::RouterPrecompile::<R>::execute(handle))
+		} else if address == STAKING {
+			Some(pallet_evm_precompile_staking::StakingPrecompile::<R>::execute(handle))
 		} else if address == DISPATCH_ADDR {
 			let caller_account = R::AddressMapping::into_account_id(handle.context().caller);
 			let original_nonce = frame_system::Pallet::<R>::account_nonce(caller_account.clone());
@@ -214,9 +227,22 @@ where
 
 			Some(dispatch_precompile_result)
 		} else if is_asset_address(address) {
-			Some(MultiCurrencyPrecompile::<R>::execute(handle))
+			// XYK share tokens serve the Uniswap V2 pair functions at their ERC20 address
+			if pallet_evm_precompile_xyk::is_pair_call(handle.input()) {
+				Some(pallet_evm_precompile_xyk::XykPairPrecompile::<
+					R,
+					erc20_mapping::HydraErc20Mapping,
+				>::execute(handle))
+			} else {
+				Some(MultiCurrencyPrecompile::<R>::execute(handle))
+			}
 		} else if is_oracle_address(address) {
 			Some(ChainlinkOraclePrecompile::<R>::execute(handle))
+		} else if is_stableswap_pool_address(address) {
+			Some(pallet_evm_precompile_stableswap::StableswapPrecompile::<
+				R,
+				erc20_mapping::HydraErc20Mapping,
+			>::execute(handle))
 		} else {
 			None
 		}
@@ -231,7 +257,13 @@ where
 }
 
 pub fn is_precompile(address: H160) -> bool {
-	address == DISPATCH_ADDR || address == LOCK_MANAGER || is_asset_address(address) || is_standard_precompile(address)
+	address == DISPATCH_ADDR
+		|| address == LOCK_MANAGER
+		|| address == ROUTER
+		|| address == STAKING
+		|| is_asset_address(address)
+		|| is_stableswap_pool_address(address)
+		|| is_standard_precompile(address)
 }
 
 /// emits ERC-20 `Approval(owner, spender, value)` inline at the precompile's address.
@@ -414,6 +414,7 @@ fn origin_tag(origin: &ExecutionType) -> u64 {
 		ExecutionType::Omnipool(id) => 0x0400_0000_0000 | (*id as u64),
 		ExecutionType::XcmExchange(id) => 0x0500_0000_0000 | (*id as u64),
 		ExecutionType::Xcm(_, id) => 0x0600_0000_0000 | (*id as u64),
+		ExecutionType::ICE(id) => 0x0700_0000_0000 | (*id as u64),
 	}
 }
 
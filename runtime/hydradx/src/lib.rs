@@ -38,6 +38,7 @@ pub mod evm;
 pub mod gigahdx;
 pub mod governance;
 mod helpers;
+pub mod router;
 mod system;
 pub mod types;
 pub mod xcm;
@@ -129,7 +130,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: Cow::Borrowed("hydradx"),
 	impl_name: Cow::Borrowed("hydradx"),
 	authoring_version: 1,
-	spec_version: 436,
+	spec_version: 459,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -204,6 +205,8 @@ construct_runtime!(
 		EthDispenser: pallet_dispenser = 85,
 		GigaHdx: pallet_gigahdx = 86,
 		GigaHdxRewards: pallet_gigahdx_rewards = 87,
+		ICE: pallet_ice = 88,
+		ConcentratedLiquidity: pallet_concentrated_liquidity = 89,
 
 		// ORML related modules
 		Tokens: orml_tokens = 77,
@@ -235,6 +238,7 @@ construct_runtime!(
 		PolkadotXcm: pallet_xcm = 107,
 		CumulusXcm: cumulus_pallet_xcm = 109,
 		XcmpQueue: cumulus_pallet_xcmp_queue exclude_parts { Call } = 111,
+		XcmOutboundLimiter: pallet_xcm_outbound_limiter = 112,
 		// 113 was used by DmpQueue which is now replaced by MessageQueue
 		MessageQueue: pallet_message_queue = 114,
 		WeightReclaim: cumulus_pallet_weight_reclaim = 115,
@@ -336,6 +340,8 @@ mod benches {
 		[pallet_referrals, Referrals]
 		[pallet_otc, OTC]
 		[pallet_otc_settlements, OtcSettlements]
+		[pallet_ice, ICE]
+		[pallet_concentrated_liquidity, ConcentratedLiquidity]
 		[pallet_liquidation, Liquidation]
 		[pallet_state_trie_migration, StateTrieMigration]
 		[frame_system, SystemBench::<Runtime>]
@@ -510,9 +516,25 @@ use frame_support::{
 	},
 	weights::WeightToFee as _,
 };
+pub use hydra_dx_math::ema::EmaPrice;
 use hydradx_traits::evm::Erc20Mapping;
-use pallet_liquidation::BorrowingContract;
+use hydradx_traits::fee::GetDynamicFee;
+use hydradx_traits::router::{AssetPair, Route, RouteProvider, Trade};
+use hydradx_traits::{AggregatedEntry, AggregatedOracle, PriceOracle};
+use pallet_circuit_breaker::types::AccountLimitKind;
+use pallet_circuit_breaker_rpc_runtime_api::AccountLimitStatus;
+use pallet_dca_rpc_runtime_api::{ExecutionForecast, ScheduleInfo, ScheduleStatus};
+use pallet_liquidity_mining_rpc_runtime_api::{
+	DepositId, FarmKind, YieldFarmApr as LMYieldFarmApr, YieldFarmEntryRewards,
+};
+use pallet_omnipool_rpc_runtime_api::{AssetState as OmnipoolAssetState, OmnipoolState, PositionValuation};
+use pallet_otc_rpc_runtime_api::OrderBookEntry;
 use pallet_route_executor::TradeExecution;
+use pallet_route_executor_rpc_runtime_api::RouteQuote;
+use pallet_stableswap_rpc_runtime_api::{
+	AmplificationRamp, AssetReserve as StableswapAssetReserve, PegState, PoolSnapshot as StableswapPoolSnapshot,
+	PoolState as StableswapPoolState, RemoveLiquidityOneAssetQuote,
+};
 pub use polkadot_xcm::latest::Junction;
 use polkadot_xcm::{IntoVersion, VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm};
 use primitives::constants::chain::CORE_ASSET_ID;
@@ -523,6 +545,195 @@ use xcm_runtime_apis::{
 	fees::Error as XcmPaymentApiError,
 };
 
+fn otc_order_book_entry(
+	order_id: pallet_otc::OrderId,
+	order: pallet_otc::OrderOf<Runtime>,
+) -> OrderBookEntry<AccountId, AssetId, BlockNumber, Balance> {
+	OrderBookEntry {
+		order_id,
+		fee: OTC::calculate_fee(order.amount_out),
+		price: OTC::order_price(&order).unwrap_or_default(),
+		owner: order.owner,
+		asset_in: order.asset_in,
+		asset_out: order.asset_out,
+		amount_in: order.amount_in,
+		amount_out: order.amount_out,
+		partially_fillable: order.partially_fillable,
+		expires_at: order.expires_at,
+		min_fill_amount: order.min_fill_amount,
+	}
+}
+
+fn circuit_breaker_account_limit_status(
+	who: &AccountId,
+	kind: AccountLimitKind,
+) -> Option<AccountLimitStatus<Balance>> {
+	CircuitBreaker::account_limit_usage(who, kind).map(|(parameters, used)| AccountLimitStatus {
+		limit: parameters.limit,
+		window: parameters.window,
+		used,
+		remaining: parameters.limit.saturating_sub(used),
+	})
+}
+
+fn dca_schedule_info(
+	schedule_id: pallet_dca::types::ScheduleId,
+	schedule: pallet_dca::types::Schedule<AccountId, AssetId, BlockNumber>,
+) -> ScheduleInfo<AccountId, AssetId, BlockNumber, Balance> {
+	let status = if DCA::paused_schedules(schedule_id).is_some() {
+		ScheduleStatus::Paused
+	} else if !DCA::is_trigger_met(&schedule) {
+		ScheduleStatus::WaitingForTrigger
+	} else {
+		ScheduleStatus::Planned
+	};
+	let forecast = DCA::forecast_next_execution(schedule_id, &schedule)
+		.ok()
+		.map(|(fee, amounts)| ExecutionForecast {
+			fee,
+			amount_in: amounts.amount_in,
+			amount_out: amounts.amount_out,
+		});
+
+	ScheduleInfo {
+		schedule_id,
+		asset_in: schedule.order.get_asset_in(),
+		asset_out: schedule.order.get_asset_out(),
+		owner: schedule.owner,
+		period: schedule.period,
+		total_amount: schedule.total_amount,
+		remaining_amount: DCA::remaining_amounts(schedule_id).unwrap_or_default(),
+		retries: DCA::retries_on_error(schedule_id),
+		max_retries: schedule
+			.max_retries
+			.unwrap_or_else(<Runtime as pallet_dca::Config>::MaxNumberOfRetriesOnError::get),
+		next_execution_block: DCA::schedule_execution_block(schedule_id),
+		status,
+		forecast,
+	}
+}
+
+fn omnipool_asset_state(asset_id: AssetId) -> Option<OmnipoolAssetState<AssetId, Balance>> {
+	let state = Omnipool::load_asset_state(asset_id).ok()?;
+	let (asset_fee, protocol_fee) =
+		<<Runtime as pallet_omnipool::Config>::Fee as GetDynamicFee<_>>::get((asset_id, state.reserve));
+
+	Some(OmnipoolAssetState {
+		asset_id,
+		reserve: state.reserve,
+		hub_reserve: state.hub_reserve,
+		shares: state.shares,
+		protocol_shares: state.protocol_shares,
+		cap: FixedU128::from_inner(state.cap),
+		tradable: state.tradable.bits(),
+		asset_fee,
+		protocol_fee,
+	})
+}
+
+fn omnipool_position_valuation(position_id: ItemId) -> Result<PositionValuation<AssetId, Balance>, DispatchError> {
+	let position = Omnipool::positions(position_id).ok_or(pallet_omnipool::Error::<Runtime>::PositionNotFound)?;
+	let value = Omnipool::position_value(position_id)?;
+
+	Ok(PositionValuation {
+		asset_id: position.asset_id,
+		amount: position.amount,
+		shares: position.shares,
+		asset_amount: value.asset_amount,
+		hub_amount: value.hub_amount,
+		value: value.value,
+		withdrawal_fee: value.withdrawal_fee,
+		withdrawal_fee_amount: value.withdrawal_fee_amount,
+		impermanent_loss: value.impermanent_loss,
+	})
+}
+
+fn stableswap_pool_state(pool_id: AssetId) -> Option<StableswapPoolState<AssetId, Balance, BlockNumber>> {
+	let pool = Stableswap::pools(pool_id)?;
+	let snapshot = Stableswap::create_snapshot(pool_id)?;
+	let current_block = System::block_number();
+
+	let pegs = pallet_stableswap::PoolPegs::<Runtime>::get(pool_id).map(|peg_info| PegState {
+		stored: peg_info.current.to_vec(),
+		updated_at: peg_info.updated_at,
+		target: Stableswap::target_pegs(pool_id).ok(),
+		max_peg_update: peg_info.max_peg_update,
+	});
+
+	Some(StableswapPoolState {
+		pool_id,
+		virtual_price: Stableswap::virtual_price(&snapshot),
+		snapshot: StableswapPoolSnapshot {
+			assets: snapshot
+				.assets
+				.iter()
+				.zip(snapshot.reserves.iter())
+				.map(|(asset_id, reserve)| StableswapAssetReserve {
+					asset_id: *asset_id,
+					reserve: reserve.amount,
+					decimals: reserve.decimals,
+				})
+				.collect(),
+			amplification: snapshot.amplification,
+			fee: snapshot.fee,
+			block_fee: snapshot.block_fee,
+			pegs: snapshot.pegs.to_vec(),
+			share_issuance: snapshot.share_issuance,
+		},
+		amplification: AmplificationRamp {
+			initial_amplification: pool.initial_amplification.get(),
+			final_amplification: pool.final_amplification.get(),
+			initial_block: pool.initial_block,
+			final_block: pool.final_block,
+			in_progress: pool.initial_block < current_block && current_block < pool.final_block,
+		},
+		pegs,
+	})
+}
+
+/// Number of blocks in a year used to estimate APR of the liquidity mining yield farms.
+const LM_BLOCKS_PER_YEAR: BlockNumber = primitives::constants::time::DAYS * 365;
+
+fn lm_deposit_rewards(
+	kind: FarmKind,
+	deposit_id: DepositId,
+) -> Result<Vec<YieldFarmEntryRewards<AssetId, Balance>>, DispatchError> {
+	let rewards = match kind {
+		FarmKind::Omnipool => OmnipoolWarehouseLM::deposit_rewards(deposit_id, LM_BLOCKS_PER_YEAR)?,
+		FarmKind::Xyk => XYKWarehouseLM::deposit_rewards(deposit_id, LM_BLOCKS_PER_YEAR)?,
+	};
+
+	Ok(rewards
+		.into_iter()
+		.map(|entry| YieldFarmEntryRewards {
+			global_farm_id: entry.global_farm_id,
+			yield_farm_id: entry.yield_farm_id,
+			reward_currency: entry.reward_currency,
+			claimable_rewards: entry.claimable_rewards,
+			unclaimable_rewards: entry.unclaimable_rewards,
+			loyalty_multiplier: entry.loyalty_multiplier,
+			apr: entry.apr,
+			reward_streams: entry.reward_streams,
+		})
+		.collect())
+}
+
+fn lm_yield_farms_apr(kind: FarmKind) -> Vec<LMYieldFarmApr<AssetId>> {
+	let aprs = match kind {
+		FarmKind::Omnipool => OmnipoolWarehouseLM::yield_farms_apr(LM_BLOCKS_PER_YEAR),
+		FarmKind::Xyk => XYKWarehouseLM::yield_farms_apr(LM_BLOCKS_PER_YEAR),
+	};
+
+	aprs.into_iter()
+		.map(|farm| LMYieldFarmApr {
+			global_farm_id: farm.global_farm_id,
+			yield_farm_id: farm.yield_farm_id,
+			reward_currency: farm.reward_currency,
+			apr: farm.apr,
+		})
+		.collect()
+}
+
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
@@ -1045,6 +1256,182 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_route_executor_rpc_runtime_api::RouterApi<Block, AssetId, Balance> for Runtime {
+		fn quote_sell(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			route: Route<AssetId>,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError> {
+			router::quote_sell(asset_in, asset_out, amount_in, route)
+		}
+
+		fn quote_buy(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+			route: Route<AssetId>,
+		) -> Result<RouteQuote<AssetId, Balance>, DispatchError> {
+			router::quote_buy(asset_in, asset_out, amount_out, route)
+		}
+
+		fn get_route(asset_in: AssetId, asset_out: AssetId) -> Route<AssetId> {
+			<Router as RouteProvider<AssetId>>::get_route(AssetPair::new(asset_in, asset_out))
+		}
+
+		fn find_best_sell_route(asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> Option<Route<AssetId>> {
+			Router::find_best_sell_route(asset_in, asset_out, amount_in).map(|(route, _)| route)
+		}
+
+		fn find_best_buy_route(asset_in: AssetId, asset_out: AssetId, amount_out: Balance) -> Option<Route<AssetId>> {
+			Router::find_best_buy_route(asset_in, asset_out, amount_out).map(|(route, _)| route)
+		}
+	}
+
+	impl pallet_otc_rpc_runtime_api::OtcApi<Block, AccountId, AssetId, BlockNumber, Balance> for Runtime {
+		fn orders(
+			asset_in: Option<AssetId>,
+			asset_out: Option<AssetId>,
+			owner: Option<AccountId>,
+		) -> Vec<OrderBookEntry<AccountId, AssetId, BlockNumber, Balance>> {
+			OTC::open_orders(asset_in, asset_out, owner)
+				.into_iter()
+				.map(|(order_id, order)| otc_order_book_entry(order_id, order))
+				.collect()
+		}
+
+		fn order(order_id: pallet_otc::OrderId) -> Option<OrderBookEntry<AccountId, AssetId, BlockNumber, Balance>> {
+			OTC::orders(order_id).map(|order| otc_order_book_entry(order_id, order))
+		}
+	}
+
+	impl pallet_circuit_breaker_rpc_runtime_api::CircuitBreakerApi<Block, AccountId, Balance> for Runtime {
+		fn account_trade_volume_limit(who: AccountId) -> Option<AccountLimitStatus<Balance>> {
+			circuit_breaker_account_limit_status(&who, AccountLimitKind::TradeVolume)
+		}
+
+		fn account_egress_limit(who: AccountId) -> Option<AccountLimitStatus<Balance>> {
+			circuit_breaker_account_limit_status(&who, AccountLimitKind::Egress)
+		}
+	}
+
+	impl pallet_dca_rpc_runtime_api::DcaApi<Block, AccountId, AssetId, BlockNumber, Balance> for Runtime {
+		fn schedules_of(owner: AccountId) -> Vec<ScheduleInfo<AccountId, AssetId, BlockNumber, Balance>> {
+			DCA::schedule_ids_of(&owner)
+				.into_iter()
+				.filter_map(|schedule_id| {
+					DCA::schedules(schedule_id).map(|schedule| dca_schedule_info(schedule_id, schedule))
+				})
+				.collect()
+		}
+
+		fn schedule(
+			schedule_id: pallet_dca::types::ScheduleId,
+		) -> Option<ScheduleInfo<AccountId, AssetId, BlockNumber, Balance>> {
+			DCA::schedules(schedule_id).map(|schedule| dca_schedule_info(schedule_id, schedule))
+		}
+	}
+
+	impl pallet_omnipool_rpc_runtime_api::OmnipoolApi<Block, AssetId, ItemId, Balance> for Runtime {
+		fn state() -> OmnipoolState<AssetId, Balance> {
+			OmnipoolState {
+				hub_asset_tradability: Omnipool::hub_asset_tradability().bits(),
+				max_slip_fee: pallet_omnipool::SlipFee::<Runtime>::get().map(|config| config.max_slip_fee),
+				assets: pallet_omnipool::Assets::<Runtime>::iter_keys()
+					.filter_map(omnipool_asset_state)
+					.collect(),
+			}
+		}
+
+		fn asset_state(asset_id: AssetId) -> Option<OmnipoolAssetState<AssetId, Balance>> {
+			omnipool_asset_state(asset_id)
+		}
+
+		fn position_valuation(position_id: ItemId) -> Result<PositionValuation<AssetId, Balance>, DispatchError> {
+			omnipool_position_valuation(position_id)
+		}
+	}
+
+	impl pallet_stableswap_rpc_runtime_api::StableswapApi<Block, AssetId, Balance, BlockNumber> for Runtime {
+		fn pools() -> Vec<StableswapPoolState<AssetId, Balance, BlockNumber>> {
+			pallet_stableswap::Pools::<Runtime>::iter_keys()
+				.filter_map(stableswap_pool_state)
+				.collect()
+		}
+
+		fn pool(pool_id: AssetId) -> Option<StableswapPoolState<AssetId, Balance, BlockNumber>> {
+			stableswap_pool_state(pool_id)
+		}
+
+		fn quote_add_liquidity(pool_id: AssetId, assets: Vec<(AssetId, Balance)>) -> Result<Balance, DispatchError> {
+			let assets: Vec<_> = assets
+				.into_iter()
+				.map(|(asset_id, amount)| hydradx_traits::stableswap::AssetAmount::new(asset_id, amount))
+				.collect();
+			Stableswap::simulate_add_liquidity(pool_id, &assets)
+		}
+
+		fn quote_remove_liquidity(pool_id: AssetId, share_amount: Balance) -> Result<Vec<(AssetId, Balance)>, DispatchError> {
+			Ok(Stableswap::simulate_remove_liquidity(pool_id, share_amount)?
+				.into_iter()
+				.map(|asset| (asset.asset_id, asset.amount))
+				.collect())
+		}
+
+		fn quote_remove_liquidity_one_asset(
+			pool_id: AssetId,
+			asset_id: AssetId,
+			share_amount: Balance,
+		) -> Result<RemoveLiquidityOneAssetQuote<Balance>, DispatchError> {
+			let (amount, fee) = Stableswap::simulate_remove_liquidity_one_asset(pool_id, asset_id, share_amount)?;
+			Ok(RemoveLiquidityOneAssetQuote { amount, fee })
+		}
+	}
+
+	impl pallet_liquidity_mining_rpc_runtime_api::LiquidityMiningApi<Block, AssetId, Balance> for Runtime {
+		fn deposit_rewards(kind: FarmKind, deposit_id: DepositId) -> Result<Vec<YieldFarmEntryRewards<AssetId, Balance>>, DispatchError> {
+			lm_deposit_rewards(kind, deposit_id)
+		}
+
+		fn yield_farms_apr(kind: FarmKind) -> Vec<LMYieldFarmApr<AssetId>> {
+			lm_yield_farms_apr(kind)
+		}
+	}
+
+	impl pallet_ema_oracle_rpc_runtime_api::EmaOracleApi<Block, AssetId, Balance, BlockNumber, EmaPrice> for Runtime {
+		fn get_entry(
+			asset_a: AssetId,
+			asset_b: AssetId,
+			period: OraclePeriod,
+			source: Source,
+		) -> Option<AggregatedEntry<Balance, BlockNumber, EmaPrice>> {
+			EmaOracle::get_entry(asset_a, asset_b, period, source).ok()
+		}
+
+		fn get_entries(
+			asset_a: AssetId,
+			asset_b: AssetId,
+			source: Source,
+		) -> Vec<(OraclePeriod, AggregatedEntry<Balance, BlockNumber, EmaPrice>)> {
+			OraclePeriod::all_periods()
+				.iter()
+				.filter_map(|period| {
+					EmaOracle::get_entry(asset_a, asset_b, *period, source)
+						.ok()
+						.map(|entry| (*period, entry))
+				})
+				.collect()
+		}
+
+		fn tracked_pairs(source: Source) -> Vec<(AssetId, AssetId)> {
+			EmaOracle::tracked_pairs(source)
+		}
+
+		fn route_price(route: Vec<Trade<AssetId>>, period: OraclePeriod) -> Option<EmaPrice> {
+			hydradx_adapters::OraclePriceProvider::<AssetId, EmaOracle, LRNA>::price(&route, period)
+		}
+	}
+
 	impl evm::precompiles::erc20_mapping::Erc20MappingApi<Block> for Runtime {
 		fn asset_address(asset_id: AssetId) -> EvmAddress {
 			HydraErc20Mapping::asset_address(asset_id)
@@ -1173,19 +1560,7 @@ impl_runtime_apis! {
 
 	impl evm::aave_trade_executor::runtime_api::AaveTradeExecutor<Block, Balance> for Runtime {
 		fn pairs() -> Vec<(AssetId, AssetId)> {
-			let pool = <BorrowingContract<Runtime>>::get();
-			let reserves = match AaveTradeExecutor::<Runtime>::get_reserves_list(pool) {
-				Ok(reserves) => reserves,
-				Err(_) => return vec![]
-			};
-			reserves.into_iter()
-				.filter_map(|reserve| {
-					let data = AaveTradeExecutor::<Runtime>::get_reserve_data(pool, reserve).ok()?;
-					let reserve_asset = HydraErc20Mapping::address_to_asset(reserve)?;
-					let atoken_asset = HydraErc20Mapping::address_to_asset(data.atoken_address)?;
-					Some((reserve_asset, atoken_asset))
-				})
-				.collect()
+			AaveTradeExecutor::<Runtime>::pairs()
 		}
 
 		fn liquidity_depth(asset_in: AssetId, asset_out: AssetId) -> Option<Balance> {
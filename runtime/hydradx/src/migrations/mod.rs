@@ -16,7 +16,12 @@
 use crate::Runtime;
 
 // New migrations which need to be cleaned up after every Runtime upgrade
-pub type UnreleasedSingleBlockMigrations = pallet_stableswap::migrations::v2::MigrateV1ToV2<Runtime>;
+pub type UnreleasedSingleBlockMigrations = (
+	pallet_stableswap::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_otc::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_dca::migrations::v3::MigrateV2ToV3<Runtime>,
+	pallet_xyk::migrations::v1::MigrateV0ToV1<Runtime>,
+);
 
 // These migrations can run on every runtime upgrade
 pub type PermanentSingleBlockMigrations = pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>;
@@ -0,0 +1,171 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Router quotes served by the `RouterApi` runtime API.
+//!
+//! Trade amounts are calculated by the router itself, so the quotes follow the on-chain AMM math exactly.
+//! Fees of every trade are collected by executing the route in a storage transaction which is always rolled back,
+//! and reading the fees reported in the `Swapped3` events of `pallet_broadcast`.
+
+use super::*;
+use frame_support::traits::fungibles::Mutate;
+use hydradx_traits::router::{AmountInAndOut, ExecutorError, Route, Trade, TradeExecution};
+use pallet_route_executor_rpc_runtime_api::{RouteQuote, TradeFee, TradeQuote};
+
+type RouterAmm = <Runtime as pallet_route_executor::Config>::AMM;
+type RouterCurrency = <Runtime as pallet_route_executor::Config>::Currency;
+
+pub fn quote_sell(
+	asset_in: AssetId,
+	asset_out: AssetId,
+	amount_in: Balance,
+	route: Route<AssetId>,
+) -> Result<RouteQuote<AssetId, Balance>, DispatchError> {
+	let (route, trade_amounts) = Router::quote_sell(asset_in, asset_out, amount_in, &route)?;
+
+	build_quote(asset_in, asset_out, route, trade_amounts, TradeKind::Sell)
+}
+
+pub fn quote_buy(
+	asset_in: AssetId,
+	asset_out: AssetId,
+	amount_out: Balance,
+	route: Route<AssetId>,
+) -> Result<RouteQuote<AssetId, Balance>, DispatchError> {
+	let (route, trade_amounts) = Router::quote_buy(asset_in, asset_out, amount_out, &route)?;
+
+	build_quote(asset_in, asset_out, route, trade_amounts, TradeKind::Buy)
+}
+
+#[derive(Clone, Copy)]
+enum TradeKind {
+	Sell,
+	Buy,
+}
+
+fn build_quote(
+	asset_in: AssetId,
+	asset_out: AssetId,
+	route: Route<AssetId>,
+	trade_amounts: Vec<AmountInAndOut<Balance>>,
+	kind: TradeKind,
+) -> Result<RouteQuote<AssetId, Balance>, DispatchError> {
+	let amount_in = trade_amounts
+		.first()
+		.ok_or(pallet_route_executor::Error::<Runtime>::RouteCalculationFailed)?
+		.amount_in;
+	let amount_out = trade_amounts
+		.last()
+		.ok_or(pallet_route_executor::Error::<Runtime>::RouteCalculationFailed)?
+		.amount_out;
+
+	let mut fees = dry_run_fees(&route, &trade_amounts, kind).into_iter();
+
+	let trades = route
+		.into_iter()
+		.zip(trade_amounts)
+		.map(|(trade, amounts)| TradeQuote {
+			trade,
+			amount_in: amounts.amount_in,
+			amount_out: amounts.amount_out,
+			spot_price_with_fee: RouterAmm::calculate_spot_price_with_fee(trade.pool, trade.asset_in, trade.asset_out)
+				.ok(),
+			fees: fees.next().unwrap_or_default(),
+		})
+		.collect();
+
+	Ok(RouteQuote {
+		asset_in,
+		asset_out,
+		amount_in,
+		amount_out,
+		trades,
+	})
+}
+
+/// Executes the trades and returns the fees charged by every trade.
+/// If the dry-run fails, e.g. because the asset in can't be minted, no fees are returned.
+fn dry_run_fees(
+	route: &[Trade<AssetId>],
+	trade_amounts: &[AmountInAndOut<Balance>],
+	kind: TradeKind,
+) -> Vec<Vec<TradeFee<AssetId, Balance>>> {
+	with_transaction(|| TransactionOutcome::Rollback(execute_trades(route, trade_amounts, kind))).unwrap_or_default()
+}
+
+fn execute_trades(
+	route: &[Trade<AssetId>],
+	trade_amounts: &[AmountInAndOut<Balance>],
+	kind: TradeKind,
+) -> Result<Vec<Vec<TradeFee<AssetId, Balance>>>, DispatchError> {
+	let trader = Router::router_account();
+
+	let (first_trade, first_amounts) = route
+		.first()
+		.zip(trade_amounts.first())
+		.ok_or(pallet_route_executor::Error::<Runtime>::RouteCalculationFailed)?;
+	RouterCurrency::mint_into(first_trade.asset_in, &trader, first_amounts.amount_in)?;
+
+	let mut fees = Vec::with_capacity(route.len());
+	for (trade, amounts) in route.iter().zip(trade_amounts) {
+		let events_before = System::event_count();
+		let origin = RuntimeOrigin::signed(trader.clone());
+
+		let result = match kind {
+			TradeKind::Sell => RouterAmm::execute_sell(
+				origin,
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				amounts.amount_in,
+				0,
+			),
+			TradeKind::Buy => RouterAmm::execute_buy(
+				origin,
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				amounts.amount_out,
+				amounts.amount_in,
+			),
+		};
+
+		result.map_err(|error| match error {
+			ExecutorError::NotSupported => pallet_route_executor::Error::<Runtime>::PoolNotSupported.into(),
+			ExecutorError::Error(dispatch_error) => dispatch_error,
+		})?;
+
+		fees.push(reported_fees(events_before));
+	}
+
+	Ok(fees)
+}
+
+fn reported_fees(events_before: u32) -> Vec<TradeFee<AssetId, Balance>> {
+	System::read_events_no_consensus()
+		.skip(events_before as usize)
+		.filter_map(|record| match record.event {
+			RuntimeEvent::Broadcast(pallet_broadcast::Event::Swapped3 { fees, .. }) => Some(fees),
+			_ => None,
+		})
+		.flatten()
+		.map(|fee| TradeFee {
+			asset: fee.asset,
+			amount: fee.amount,
+		})
+		.collect()
+}
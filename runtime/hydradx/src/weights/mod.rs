@@ -13,6 +13,7 @@ pub mod pallet_claims;
 pub mod pallet_collator_selection;
 pub mod pallet_collective_council;
 pub mod pallet_collective_technical_committee;
+pub mod pallet_concentrated_liquidity;
 pub mod pallet_conviction_voting;
 pub mod pallet_currencies;
 pub mod pallet_dca;
@@ -28,6 +29,7 @@ pub mod pallet_fee_processor;
 pub mod pallet_gigahdx;
 pub mod pallet_gigahdx_rewards;
 pub mod pallet_hsm;
+pub mod pallet_ice;
 pub mod pallet_identity;
 pub mod pallet_lbp;
 pub mod pallet_liquidation;
@@ -16,15 +16,14 @@
 // limitations under the License.
 
 
-//! Autogenerated weights for `pallet_circuit_breaker`
+//! Weights for `pallet_circuit_breaker`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 49.1.0
-//! DATE: 2026-03-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! The weights of `set_price_deviation_limit`, `lift_price_deviation_lockdown`, `ensure_price_deviation_limit`,
+//! `set_account_limit_params`, `add_account_limit_whitelist`, `remove_account_limit_whitelist` and `note_account_activity`
+//! are estimated from the storage accesses of the extrinsics, they were not produced by the benchmark CLI. Regenerate the
+//! weights with the command below before relying on them.
 
-// Executed Command:
+// Command to generate the weights:
 // ./bin/hydradx
 // benchmark
 // pallet
@@ -307,4 +306,63 @@ impl<T: frame_system::Config> pallet_circuit_breaker::WeightInfo for HydraWeight
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-}
\ No newline at end of file
+	/// Storage: `CircuitBreaker::PriceDeviationLimitPerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::PriceDeviationLimitPerAsset` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn set_price_deviation_limit() -> Weight {
+		Weight::from_parts(11_518_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::PriceDeviationLockdown` (r:1 w:1)
+	/// Proof: `CircuitBreaker::PriceDeviationLockdown` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn lift_price_deviation_lockdown() -> Weight {
+		Weight::from_parts(15_260_000, 3489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::PriceDeviationLockdown` (r:1 w:1)
+	/// Proof: `CircuitBreaker::PriceDeviationLockdown` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::PriceDeviationLimitPerAsset` (r:1 w:0)
+	/// Proof: `CircuitBreaker::PriceDeviationLimitPerAsset` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	fn ensure_price_deviation_limit() -> Weight {
+		Weight::from_parts(28_102_000, 6328)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::AccountLimitConfig` (r:0 w:1)
+	/// Proof: `CircuitBreaker::AccountLimitConfig` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	fn set_account_limit_params() -> Weight {
+		Weight::from_parts(11_362_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CircuitBreaker::AccountLimitWhitelist` (r:0 w:100)
+	/// Proof: `CircuitBreaker::AccountLimitWhitelist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn add_account_limit_whitelist(n: u32, ) -> Weight {
+		Weight::from_parts(10_847_255, 0)
+			.saturating_add(Weight::from_parts(1_468_402, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `CircuitBreaker::AccountLimitWhitelist` (r:0 w:100)
+	/// Proof: `CircuitBreaker::AccountLimitWhitelist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn remove_account_limit_whitelist(n: u32, ) -> Weight {
+		Weight::from_parts(11_012_930, 0)
+			.saturating_add(Weight::from_parts(1_481_209, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `CircuitBreaker::AccountLimitConfig` (r:1 w:0)
+	/// Proof: `CircuitBreaker::AccountLimitConfig` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::AccountLimitWhitelist` (r:1 w:0)
+	/// Proof: `CircuitBreaker::AccountLimitWhitelist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::AccountLimitAccumulator` (r:1 w:1)
+	/// Proof: `CircuitBreaker::AccountLimitAccumulator` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	fn note_account_activity() -> Weight {
+		Weight::from_parts(25_318_000, 3554)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
@@ -16,15 +16,12 @@
 // limitations under the License.
 
 
-//! Autogenerated weights for `pallet_dca`
+//! Weights for `pallet_dca`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 49.1.0
-//! DATE: 2026-03-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! The weights of `pause`, `resume` and `update_schedule` are estimated from the storage accesses of the extrinsics,
+//! they were not produced by the benchmark CLI. Regenerate the weights with the command below before relying on them.
 
-// Executed Command:
+// Command to generate the weights:
 // ./bin/hydradx
 // benchmark
 // pallet
@@ -63,7 +60,7 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
 	/// Storage: `DCA::ScheduleIdsPerBlock` (r:12 w:2)
 	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::Schedules` (r:1 w:0)
-	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(243), added: 2718, mode: `MaxEncodedLen`)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::ScheduleExtraGas` (r:1 w:0)
 	/// Proof: `DCA::ScheduleExtraGas` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::RemainingAmounts` (r:1 w:1)
@@ -96,7 +93,7 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
 	/// Storage: `DCA::ScheduleIdsPerBlock` (r:12 w:2)
 	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::Schedules` (r:1 w:0)
-	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(243), added: 2718, mode: `MaxEncodedLen`)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
 	/// Storage: `MultiTransactionPayment::AcceptedCurrencies` (r:2 w:0)
 	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::ScheduleExtraGas` (r:1 w:0)
@@ -155,7 +152,7 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
 	/// Storage: `DCA::ScheduleIdsPerBlock` (r:12 w:2)
 	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::Schedules` (r:1 w:0)
-	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(243), added: 2718, mode: `MaxEncodedLen`)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::ScheduleExtraGas` (r:1 w:0)
 	/// Proof: `DCA::ScheduleExtraGas` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::RemainingAmounts` (r:1 w:1)
@@ -188,7 +185,7 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
 	/// Storage: `DCA::ScheduleIdsPerBlock` (r:12 w:2)
 	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::Schedules` (r:1 w:0)
-	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(243), added: 2718, mode: `MaxEncodedLen`)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
 	/// Storage: `MultiTransactionPayment::AcceptedCurrencies` (r:2 w:0)
 	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::ScheduleExtraGas` (r:1 w:0)
@@ -277,7 +274,7 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
 	/// Storage: `DCA::RetriesOnError` (r:0 w:1)
 	/// Proof: `DCA::RetriesOnError` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::Schedules` (r:0 w:1)
-	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(243), added: 2718, mode: `MaxEncodedLen`)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::ScheduleOwnership` (r:0 w:1)
 	/// Proof: `DCA::ScheduleOwnership` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::ScheduleExecutionBlock` (r:0 w:1)
@@ -294,7 +291,7 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `DCA::Schedules` (r:1 w:1)
-	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(243), added: 2718, mode: `MaxEncodedLen`)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::RemainingAmounts` (r:1 w:1)
 	/// Proof: `DCA::RemainingAmounts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Reserves` (r:1 w:1)
@@ -311,14 +308,16 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
 	/// Proof: `DCA::ScheduleExtraGas` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `DCA::ScheduleOwnership` (r:0 w:1)
 	/// Proof: `DCA::ScheduleOwnership` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::PausedSchedules` (r:1 w:1)
+	/// Proof: `DCA::PausedSchedules` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	fn terminate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4072`
 		//  Estimated: `4714`
 		// Minimum execution time: 98_858_000 picoseconds.
 		Weight::from_parts(99_809_000, 4714)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `DCA::ScheduleOwnership` (r:1 w:0)
 	/// Proof: `DCA::ScheduleOwnership` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
@@ -335,4 +334,43 @@ impl<T: frame_system::Config> pallet_dca::WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `DCA::Schedules` (r:1 w:0)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::PausedSchedules` (r:1 w:1)
+	/// Proof: `DCA::PausedSchedules` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::ScheduleExecutionBlock` (r:1 w:1)
+	/// Proof: `DCA::ScheduleExecutionBlock` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::ScheduleIdsPerBlock` (r:1 w:1)
+	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		Weight::from_parts(42_114_000, 4097)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DCA::Schedules` (r:1 w:0)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::PausedSchedules` (r:1 w:1)
+	/// Proof: `DCA::PausedSchedules` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::ScheduleIdsPerBlock` (r:11 w:1)
+	/// Proof: `DCA::ScheduleIdsPerBlock` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::ScheduleExecutionBlock` (r:0 w:1)
+	/// Proof: `DCA::ScheduleExecutionBlock` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn resume() -> Weight {
+		Weight::from_parts(80_912_000, 28710)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DCA::Schedules` (r:1 w:1)
+	/// Proof: `DCA::Schedules` (`max_values`: None, `max_size`: Some(632), added: 3107, mode: `MaxEncodedLen`)
+	/// Storage: `DCA::RemainingAmounts` (r:1 w:1)
+	/// Proof: `DCA::RemainingAmounts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Reserves` (r:1 w:1)
+	/// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_schedule() -> Weight {
+		Weight::from_parts(59_638_000, 4714)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
\ No newline at end of file
@@ -16,15 +16,11 @@
 // limitations under the License.
 
 
-//! Autogenerated weights for `pallet_lbp`
+//! Weights for `pallet_lbp`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 49.1.0
-//! DATE: 2026-03-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! The weights of `finalize_sale`, `claim_proceeds` and `set_sale_finalization` are estimated from the storage accesses of the extrinsics, they were not produced by the benchmark CLI. Regenerate the weights with the command below before relying on them.
 
-// Executed Command:
+// Command to generate the weights:
 // ./bin/hydradx
 // benchmark
 // pallet
@@ -342,4 +338,45 @@ impl<T: frame_system::Config> pallet_lbp::WeightInfo for HydraWeight<T> {
 		Weight::from_parts(43_221_000, 6190)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 	}
+	/// Storage: `LBP::PoolFinalization` (r:1 w:1)
+	/// Proof: `LBP::PoolFinalization` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolData` (r:1 w:1)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:1 w:0)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::VestedProceeds` (r:0 w:1)
+	/// Proof: `LBP::VestedProceeds` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::FeeCollectorWithAsset` (r:0 w:1)
+	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn finalize_sale() -> Weight {
+		Weight::from_parts(194_310_000, 11322)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `LBP::VestedProceeds` (r:1 w:1)
+	/// Proof: `LBP::VestedProceeds` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_proceeds() -> Weight {
+		Weight::from_parts(97_102_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::VestedProceeds` (r:1 w:0)
+	/// Proof: `LBP::VestedProceeds` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolFinalization` (r:0 w:1)
+	/// Proof: `LBP::PoolFinalization` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn set_sale_finalization() -> Weight {
+		Weight::from_parts(22_000_000, 3628)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
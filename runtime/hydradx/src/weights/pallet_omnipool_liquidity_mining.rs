@@ -16,15 +16,12 @@
 // limitations under the License.
 
 
-//! Autogenerated weights for `pallet_omnipool_liquidity_mining`
+//! Weights for `pallet_omnipool_liquidity_mining`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 49.1.0
-//! DATE: 2026-07-13, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! These weights are estimated from the storage accesses of the extrinsics, they were not produced by the benchmark
+//! CLI. Regenerate them with the command below before relying on them.
 
-// Executed Command:
+// Command to generate the weights:
 // ./bin/hydradx
 // benchmark
 // pallet
@@ -125,6 +122,29 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
+	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:4 w:1)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::EgressAccounts` (r:2 w:0)
+	/// Proof: `CircuitBreaker::EgressAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::GlobalAssetOverrides` (r:1 w:0)
+	/// Proof: `CircuitBreaker::GlobalAssetOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn add_reward_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8214`
+		//  Estimated: `11410`
+		// Minimum execution time: 118_402_000 picoseconds.
+		Weight::from_parts(119_356_000, 11410)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 	/// Storage: `Omnipool::Assets` (r:1 w:0)
 	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
@@ -290,14 +310,22 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `OmnipoolLiquidityMining::OmniPositionId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `OmnipoolWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:6 w:6)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:0 w:3)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn deposit_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `12607`
-		//  Estimated: `11666`
-		// Minimum execution time: 280_223_000 picoseconds.
-		Weight::from_parts(282_139_000, 11666)
-			.saturating_add(T::DbWeight::get().reads(20_u64))
-			.saturating_add(T::DbWeight::get().writes(14_u64))
+		//  Estimated: `50381`
+		// Minimum execution time: 391_223_000 picoseconds.
+		Weight::from_parts(393_139_000, 50381)
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(29_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:2 w:0)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -323,14 +351,22 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `CircuitBreaker::EgressAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `CircuitBreaker::GlobalAssetOverrides` (r:1 w:0)
 	/// Proof: `CircuitBreaker::GlobalAssetOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:6 w:6)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:0 w:3)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn redeposit_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `15543`
-		//  Estimated: `11666`
-		// Minimum execution time: 240_302_000 picoseconds.
-		Weight::from_parts(242_508_000, 11666)
-			.saturating_add(T::DbWeight::get().reads(18_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+		//  Estimated: `50381`
+		// Minimum execution time: 351_302_000 picoseconds.
+		Weight::from_parts(353_508_000, 50381)
+			.saturating_add(T::DbWeight::get().reads(30_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:1 w:0)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -340,7 +376,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `OmnipoolWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
 	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:4 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:3)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
@@ -350,14 +386,22 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `CircuitBreaker::EgressAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `CircuitBreaker::GlobalAssetOverrides` (r:1 w:0)
 	/// Proof: `CircuitBreaker::GlobalAssetOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:9 w:9)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `12822`
-		//  Estimated: `8799`
-		// Minimum execution time: 243_130_000 picoseconds.
-		Weight::from_parts(244_920_000, 8799)
-			.saturating_add(T::DbWeight::get().reads(14_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+		//  Estimated: `63063`
+		// Minimum execution time: 432_130_000 picoseconds.
+		Weight::from_parts(433_920_000, 63063)
+			.saturating_add(T::DbWeight::get().reads(35_u64))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:2 w:2)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -371,7 +415,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `OmnipoolWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:1 w:1)
 	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:4 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:3)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
@@ -387,14 +431,22 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:2)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:9 w:9)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:3 w:6)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn withdraw_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `11245`
-		//  Estimated: `8799`
-		// Minimum execution time: 334_976_000 picoseconds.
-		Weight::from_parts(336_401_000, 8799)
-			.saturating_add(T::DbWeight::get().reads(19_u64))
-			.saturating_add(T::DbWeight::get().writes(15_u64))
+		//  Estimated: `63063`
+		// Minimum execution time: 538_976_000 picoseconds.
+		Weight::from_parts(540_401_000, 63063)
+			.saturating_add(T::DbWeight::get().reads(40_u64))
+			.saturating_add(T::DbWeight::get().writes(36_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:2 w:2)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -430,24 +482,32 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `OmnipoolLiquidityMining::OmniPositionId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `OmnipoolWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:30 w:30)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:0 w:15)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn join_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `20799 + c * (530 ±0)`
-		//  Estimated: `11666 + c * (2680 ±0)`
-		// Minimum execution time: 286_041_000 picoseconds.
+		//  Estimated: `11666 + c * (41395 ±0)`
+		// Minimum execution time: 397_041_000 picoseconds.
 		Weight::from_parts(172_413_412, 11666)
 			// Standard Error: 77_838
-			.saturating_add(Weight::from_parts(118_563_650, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(229_563_650, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(16_u64))
-			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((16_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(11_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2680).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes((18_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 41395).saturating_mul(c.into()))
 	}
 	/// Storage: `AssetRegistry::Assets` (r:4 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:4 w:3)
+	/// Storage: `Tokens::Accounts` (r:34 w:33)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
 	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
@@ -513,20 +573,26 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `OmnipoolWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:0 w:15)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn add_liquidity_and_join_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `22400 + c * (530 ±0)`
-		//  Estimated: `14335 + c * (2680 ±0)`
-		// Minimum execution time: 634_082_000 picoseconds.
+		//  Estimated: `14335 + c * (41395 ±0)`
+		// Minimum execution time: 745_082_000 picoseconds.
 		Weight::from_parts(517_897_325, 14335)
 			// Standard Error: 77_150
-			.saturating_add(Weight::from_parts(122_276_989, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(233_276_989, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(46_u64))
-			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((16_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(25_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2680).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes((18_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 41395).saturating_mul(c.into()))
 	}
 	/// Storage: `Uniques::Asset` (r:2 w:2)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -540,7 +606,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `OmnipoolWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:5 w:5)
 	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:16 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:7 w:7)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
@@ -556,20 +622,28 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:2)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:45 w:45)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:15 w:30)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn exit_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `13266 + c * (521 ±0)`
-		//  Estimated: `6328 + c * (2680 ±0)`
-		// Minimum execution time: 288_690_000 picoseconds.
+		//  Estimated: `6328 + c * (56944 ±0)`
+		// Minimum execution time: 492_690_000 picoseconds.
 		Weight::from_parts(107_728_007, 6328)
 			// Standard Error: 269_196
-			.saturating_add(Weight::from_parts(182_523_860, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(386_523_860, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(13_u64))
-			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((25_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2680).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes((24_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 56944).saturating_mul(c.into()))
 	}
 	/// Storage: `Stableswap::Pools` (r:1 w:0)
 	/// Proof: `Stableswap::Pools` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
@@ -577,7 +651,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `Stableswap::AssetTradability` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:8 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:13 w:13)
+	/// Storage: `Tokens::Accounts` (r:43 w:43)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Stableswap::ShareIssuance` (r:1 w:1)
 	/// Proof: `Stableswap::ShareIssuance` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
@@ -645,20 +719,26 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `Omnipool::Positions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `OmnipoolWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:0 w:15)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn add_liquidity_stableswap_omnipool_and_join_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `25161 + c * (530 ±0)`
-		//  Estimated: `34569 + c * (2680 ±0)`
-		// Minimum execution time: 2_800_527_000 picoseconds.
+		//  Estimated: `34569 + c * (41395 ±0)`
+		// Minimum execution time: 2_911_527_000 picoseconds.
 		Weight::from_parts(2_704_641_304, 34569)
 			// Standard Error: 640_868
-			.saturating_add(Weight::from_parts(122_657_388, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(233_657_388, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(80_u64))
-			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((16_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(37_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2680).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes((18_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 41395).saturating_mul(c.into()))
 	}
 	/// Storage: `Uniques::Asset` (r:2 w:2)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -672,7 +752,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `OmnipoolWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(198), added: 2673, mode: `MaxEncodedLen`)
 	/// Storage: `OmnipoolWarehouseLM::GlobalFarm` (r:5 w:5)
 	/// Proof: `OmnipoolWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:8 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:11 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:9 w:7)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
@@ -686,7 +766,7 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::Assets` (r:1 w:1)
 	/// Proof: `Omnipool::Assets` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:13 w:13)
+	/// Storage: `Tokens::Accounts` (r:22 w:22)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Omnipool::SlipFee` (r:1 w:0)
 	/// Proof: `Omnipool::SlipFee` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
@@ -722,20 +802,26 @@ impl<T: frame_system::Config> pallet_omnipool_liquidity_mining::WeightInfo for H
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:2)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `OmnipoolWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `OmnipoolWarehouseLM::EntryRewardStream` (r:3 w:6)
+	/// Proof: `OmnipoolWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[0, 1]`.
 	fn remove_liquidity_stableswap_omnipool_and_exit_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `27165 + c * (7239 ±0)`
-		//  Estimated: `34569 + c * (18221 ±0)`
-		// Minimum execution time: 1_695_593_000 picoseconds.
+		//  Estimated: `34569 + c * (72485 ±0)`
+		// Minimum execution time: 1_899_593_000 picoseconds.
 		Weight::from_parts(1_714_757_642, 34569)
 			// Standard Error: 2_539_598
-			.saturating_add(Weight::from_parts(1_016_348_957, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(1_220_348_957, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(64_u64))
-			.saturating_add(T::DbWeight::get().reads((31_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((52_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(23_u64))
-			.saturating_add(T::DbWeight::get().writes((24_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 18221).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes((45_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 72485).saturating_mul(c.into()))
 	}
 	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
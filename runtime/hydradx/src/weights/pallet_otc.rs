@@ -16,15 +16,13 @@
 // limitations under the License.
 
 
-//! Autogenerated weights for `pallet_otc`
+//! Weights for `pallet_otc`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 49.1.0
-//! DATE: 2026-03-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! The weights of `place_order_with_constraints` and `expire_order` are estimated from the storage accesses of the
+//! extrinsics, they were not produced by the benchmark CLI. Regenerate the weights with the command below before relying
+//! on them.
 
-// Executed Command:
+// Command to generate the weights:
 // ./bin/hydradx
 // benchmark
 // pallet
@@ -69,7 +67,7 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 	/// Storage: `Tokens::Accounts` (r:1 w:1)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `OTC::Orders` (r:0 w:1)
-	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
 	fn place_order() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1029`
@@ -79,8 +77,27 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::NextOrderId` (r:1 w:1)
+	/// Proof: `OTC::NextOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:1 w:1)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::Orders` (r:0 w:1)
+	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::OrderExpiries` (r:0 w:1)
+	/// Proof: `OTC::OrderExpiries` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::NextExpiryBlock` (r:1 w:1)
+	/// Proof: `OTC::NextExpiryBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn place_order_with_constraints() -> Weight {
+		Weight::from_parts(60_040_000, 6190)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 	/// Storage: `OTC::Orders` (r:1 w:1)
-	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `EVMAccounts::AccountExtension` (r:1 w:0)
@@ -115,7 +132,7 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `OTC::Orders` (r:1 w:1)
-	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `EVMAccounts::AccountExtension` (r:1 w:0)
@@ -140,6 +157,8 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `MultiTransactionPayment::AcceptedCurrencies` (r:1 w:0)
 	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::OrderExpiries` (r:0 w:1)
+	/// Proof: `OTC::OrderExpiries` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	fn fill_order() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2270`
@@ -147,16 +166,18 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 		// Minimum execution time: 240_330_000 picoseconds.
 		Weight::from_parts(241_837_000, 13905)
 			.saturating_add(T::DbWeight::get().reads(24_u64))
-			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `OTC::Orders` (r:1 w:1)
-	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Reserves` (r:1 w:1)
 	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:1 w:1)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::OrderExpiries` (r:0 w:1)
+	/// Proof: `OTC::OrderExpiries` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	fn cancel_order() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1263`
@@ -164,6 +185,19 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 		// Minimum execution time: 51_096_000 picoseconds.
 		Weight::from_parts(51_622_000, 4726)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `OTC::Orders` (r:1 w:1)
+	/// Proof: `OTC::Orders` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `OTC::OrderExpiries` (r:0 w:1)
+	/// Proof: `OTC::OrderExpiries` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:1 w:1)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn expire_order() -> Weight {
+		Weight::from_parts(48_105_000, 4726)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
-}
\ No newline at end of file
+}
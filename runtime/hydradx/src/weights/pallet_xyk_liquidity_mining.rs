@@ -16,15 +16,12 @@
 // limitations under the License.
 
 
-//! Autogenerated weights for `pallet_xyk_liquidity_mining`
+//! Weights for `pallet_xyk_liquidity_mining`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 49.1.0
-//! DATE: 2026-03-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `bench-bot`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! These weights are estimated from the storage accesses of the extrinsics, they were not produced by the benchmark
+//! CLI. Regenerate them with the command below before relying on them.
 
-// Executed Command:
+// Command to generate the weights:
 // ./bin/hydradx
 // benchmark
 // pallet
@@ -169,6 +166,25 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 			.saturating_add(T::DbWeight::get().reads(20_u64))
 			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:1 w:1)
+	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:4 w:1)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn add_reward_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2967`
+		//  Estimated: `11410`
+		// Minimum execution time: 124_611_000 picoseconds.
+		Weight::from_parts(125_703_000, 11410)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 	/// Storage: `XYK::ShareToken` (r:1 w:0)
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:1 w:1)
@@ -297,7 +313,7 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Storage: `Tokens::Accounts` (r:10 w:10)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::YieldFarm` (r:1 w:1)
 	/// Proof: `XYKWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
@@ -333,14 +349,20 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `XYKWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:0 w:3)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn deposit_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6362`
-		//  Estimated: `11322`
-		// Minimum execution time: 304_795_000 picoseconds.
-		Weight::from_parts(307_046_000, 11322)
-			.saturating_add(T::DbWeight::get().reads(29_u64))
-			.saturating_add(T::DbWeight::get().writes(11_u64))
+		//  Estimated: `50037`
+		// Minimum execution time: 415_795_000 picoseconds.
+		Weight::from_parts(418_046_000, 50037)
+			.saturating_add(T::DbWeight::get().reads(41_u64))
+			.saturating_add(T::DbWeight::get().writes(26_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:1 w:0)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -354,7 +376,7 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Storage: `Tokens::Accounts` (r:8 w:8)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
@@ -374,14 +396,20 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `CircuitBreaker::EgressAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `XYK::PoolAssets` (r:1 w:0)
 	/// Proof: `XYK::PoolAssets` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:0 w:3)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn redeposit_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7220`
-		//  Estimated: `6328`
-		// Minimum execution time: 227_990_000 picoseconds.
-		Weight::from_parts(230_453_000, 6328)
-			.saturating_add(T::DbWeight::get().reads(19_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+		//  Estimated: `45043`
+		// Minimum execution time: 338_990_000 picoseconds.
+		Weight::from_parts(341_453_000, 45043)
+			.saturating_add(T::DbWeight::get().reads(31_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:1 w:0)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -391,9 +419,9 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `XYKWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:1 w:1)
 	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:4 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:3 w:3)
+	/// Storage: `Tokens::Accounts` (r:12 w:12)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
@@ -411,14 +439,20 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `CircuitBreaker::GlobalAssetOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	/// Storage: `CircuitBreaker::EgressAccounts` (r:3 w:0)
 	/// Proof: `CircuitBreaker::EgressAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn claim_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7339`
-		//  Estimated: `8739`
-		// Minimum execution time: 253_268_000 picoseconds.
-		Weight::from_parts(256_151_000, 8739)
-			.saturating_add(T::DbWeight::get().reads(21_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+		//  Estimated: `63003`
+		// Minimum execution time: 442_268_000 picoseconds.
+		Weight::from_parts(445_151_000, 63003)
+			.saturating_add(T::DbWeight::get().reads(42_u64))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
 	}
 	/// Storage: `Uniques::Asset` (r:1 w:1)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -430,9 +464,9 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `XYKWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:1 w:1)
 	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:3 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:6 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:5 w:5)
+	/// Storage: `Tokens::Accounts` (r:14 w:14)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
@@ -466,14 +500,20 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:1)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:3 w:3)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:3 w:6)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn withdraw_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7101`
-		//  Estimated: `13905`
-		// Minimum execution time: 508_797_000 picoseconds.
-		Weight::from_parts(512_375_000, 13905)
-			.saturating_add(T::DbWeight::get().reads(40_u64))
-			.saturating_add(T::DbWeight::get().writes(16_u64))
+		//  Estimated: `68169`
+		// Minimum execution time: 712_797_000 picoseconds.
+		Weight::from_parts(716_375_000, 68169)
+			.saturating_add(T::DbWeight::get().reads(61_u64))
+			.saturating_add(T::DbWeight::get().writes(37_u64))
 	}
 	/// Storage: `XYK::ShareToken` (r:1 w:0)
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
@@ -516,7 +556,7 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:8 w:8)
+	/// Storage: `Tokens::Accounts` (r:38 w:38)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::YieldFarm` (r:5 w:5)
 	/// Proof: `XYKWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
@@ -552,26 +592,32 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `XYKWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:0 w:15)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn join_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6799 + c * (690 ±0)`
-		//  Estimated: `8739 + c * (2701 ±0)`
-		// Minimum execution time: 311_005_000 picoseconds.
+		//  Estimated: `8739 + c * (41416 ±0)`
+		// Minimum execution time: 422_005_000 picoseconds.
 		Weight::from_parts(198_724_237, 8739)
 			// Standard Error: 76_688
-			.saturating_add(Weight::from_parts(115_820_750, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(226_820_750, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(23_u64))
-			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((18_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(8_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2701).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes((18_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 41416).saturating_mul(c.into()))
 	}
 	/// Storage: `XYK::ShareToken` (r:1 w:0)
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:3 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:12 w:12)
+	/// Storage: `Tokens::Accounts` (r:42 w:42)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `XYK::TotalLiquidity` (r:1 w:1)
 	/// Proof: `XYK::TotalLiquidity` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
@@ -619,20 +665,26 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::Deposit` (r:0 w:1)
 	/// Proof: `XYKWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:0 w:15)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn add_liquidity_and_join_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7392 + c * (672 ±0)`
-		//  Estimated: `19071 + c * (2701 ±0)`
-		// Minimum execution time: 524_720_000 picoseconds.
+		//  Estimated: `19071 + c * (41416 ±0)`
+		// Minimum execution time: 635_720_000 picoseconds.
 		Weight::from_parts(408_645_203, 19071)
 			// Standard Error: 74_419
-			.saturating_add(Weight::from_parts(120_089_949, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(231_089_949, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(37_u64))
-			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((18_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(17_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2701).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes((18_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 41416).saturating_mul(c.into()))
 	}
 	/// Storage: `Uniques::Asset` (r:1 w:1)
 	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
@@ -644,9 +696,9 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `XYKWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:5 w:5)
 	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
-	/// Storage: `AssetRegistry::Assets` (r:3 w:0)
+	/// Storage: `AssetRegistry::Assets` (r:18 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:9 w:9)
+	/// Storage: `Tokens::Accounts` (r:54 w:54)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
@@ -680,20 +732,26 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for HydraW
 	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::ItemPriceOf` (r:0 w:1)
 	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::RewardStream` (r:15 w:15)
+	/// Proof: `XYKWarehouseLM::RewardStream` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarmRewardStream` (r:15 w:15)
+	/// Proof: `XYKWarehouseLM::YieldFarmRewardStream` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::EntryRewardStream` (r:15 w:30)
+	/// Proof: `XYKWarehouseLM::EntryRewardStream` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 5]`.
 	fn exit_farms(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `6708 + c * (692 ±0)`
-		//  Estimated: `6328 + c * (2792 ±0)`
-		// Minimum execution time: 342_263_000 picoseconds.
+		//  Estimated: `6328 + c * (57056 ±0)`
+		// Minimum execution time: 546_263_000 picoseconds.
 		Weight::from_parts(108_830_970, 6328)
 			// Standard Error: 1_012_487
-			.saturating_add(Weight::from_parts(231_505_237, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(435_505_237, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(16_u64))
-			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((28_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2792).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes((24_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 57056).saturating_mul(c.into()))
 	}
 	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
@@ -4,7 +4,9 @@ use crate::origins::GeneralAdmin;
 use sp_std::{fmt::Debug, marker::PhantomData};
 
 use codec::{DecodeWithMemTracking, FullCodec, MaxEncodedLen};
-use hydradx_adapters::{MultiCurrencyTrader, ReroutingMultiCurrencyAdapter, ToFeeReceiver};
+use hydradx_adapters::{
+	MultiCurrencyTrader, RelayChainBlockNumberProvider, ReroutingMultiCurrencyAdapter, ToFeeReceiver,
+};
 use pallet_transaction_multi_payment::DepositAll;
 use primitives::{AssetId, Price};
 
@@ -24,7 +26,7 @@ use pallet_evm::AddressMapping;
 pub use pallet_xcm::GenesisConfig as XcmGenesisConfig;
 use pallet_xcm::XcmPassthrough;
 use parachains_common::message_queue::{NarrowOriginToSibling, ParaIdToSibling};
-use polkadot_parachain::primitives::Sibling;
+use polkadot_parachain::primitives::{RelayChainBlockNumber, Sibling};
 use polkadot_xcm::v5::{prelude::*, InstructionError, InteriorLocation, Location, Weight as XcmWeight};
 use scale_info::TypeInfo;
 use sp_runtime::{
@@ -435,6 +437,20 @@ impl pallet_xcm::Config for Runtime {
 	type AuthorizedAliasConsideration = Disabled;
 }
 
+parameter_types! {
+	// One day of relay chain blocks
+	pub const XcmOutboundLimitDuration: RelayChainBlockNumber = 14_400;
+}
+
+impl pallet_xcm_outbound_limiter::Config for Runtime {
+	type AssetId = AssetId;
+	type LimitDuration = XcmOutboundLimitDuration;
+	type RelayBlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type CurrencyIdConvert = CurrencyIdConvert;
+	type UniversalLocation = UniversalLocation;
+	type RateLimitFor = pallet_asset_registry::XcmRateLimitsInRegistry<Runtime>;
+}
+
 parameter_types! {
 	pub MessageQueueServiceWeight: Weight = Perbill::from_percent(20) * BlockWeights::get().max_block;
 	pub const MessageQueueMaxStale: u32 = 8;
@@ -583,12 +599,16 @@ impl Convert<AccountId, Location> for AccountIdToLocation {
 
 /// The means for routing XCM messages which are not for local execution into the right message
 /// queues.
-pub type XcmRouter = WithUniqueTopic<(
-	// Two routers - use UMP to communicate with the relay chain:
-	cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, ()>,
-	// ..and XCMP to communicate with the sibling chains.
-	XcmpQueue,
-)>;
+/// Tokens leaving the chain are subject to the XCM rate limits of the asset registry.
+pub type XcmRouter = pallet_xcm_outbound_limiter::OutboundRateLimiter<
+	Runtime,
+	WithUniqueTopic<(
+		// Two routers - use UMP to communicate with the relay chain:
+		cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, ()>,
+		// ..and XCMP to communicate with the sibling chains.
+		XcmpQueue,
+	)>,
+>;
 
 /// Type for specifying how a `Location` can be converted into an `AccountId`. This is used
 /// when determining ownership of accounts for asset transacting and when attempting to use XCM
@@ -1,3 +1,5 @@
+use frame_support::dispatch::DispatchResult;
+use frame_support::weights::Weight;
 use orml_traits::{currency::OnTransfer, Handler};
 
 pub trait WithdrawFuseControl {
@@ -9,7 +11,27 @@ impl WithdrawFuseControl for () {
 }
 
 pub trait AssetWithdrawHandler<AccountId, AssetId, Balance> {
-	type OnWithdraw: Handler<(AssetId, Balance)>;
+	type OnWithdraw: Handler<(AssetId, Balance, AccountId)>;
 	type OnDeposit: Handler<(AssetId, Balance, Option<AccountId>)>;
 	type OnTransfer: OnTransfer<AccountId, AssetId, Balance>;
 }
+
+/// Handler used by AMM pools to count trades towards the per-account trade volume limit of the trader.
+pub trait AccountTradeVolumeHandler<AccountId, AssetId, Balance> {
+	/// Count `amount` of `asset_id` traded by `who`.
+	/// Fails if the trade volume limit of the trader is exceeded.
+	fn note_trade_volume(who: &AccountId, asset_id: AssetId, amount: Balance) -> DispatchResult;
+
+	/// Weight of `note_trade_volume`. Add this weight to an extrinsic from which you call `note_trade_volume`.
+	fn note_trade_volume_weight() -> Weight;
+}
+
+impl<AccountId, AssetId, Balance> AccountTradeVolumeHandler<AccountId, AssetId, Balance> for () {
+	fn note_trade_volume(_who: &AccountId, _asset_id: AssetId, _amount: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn note_trade_volume_weight() -> Weight {
+		Weight::zero()
+	}
+}
@@ -13,6 +13,7 @@ pub trait Mutate<AccountId, AssetId, BlockNumber> {
 	type Balance;
 	type Period;
 	type LoyaltyCurve;
+	type RewardSchedule;
 
 	/// Create new global farm.
 	///
@@ -68,6 +69,18 @@ pub trait Mutate<AccountId, AssetId, BlockNumber> {
 		global_farm_id: GlobalFarmId,
 	) -> Result<(AssetId, Self::Balance, AccountId), Self::Error>;
 
+	/// Add additional reward currency(reward stream) to the existing global farm.
+	#[allow(clippy::too_many_arguments)]
+	fn add_reward_stream(
+		who: AccountId,
+		global_farm_id: GlobalFarmId,
+		reward_currency: AssetId,
+		total_rewards: Self::Balance,
+		start: BlockNumber,
+		end: BlockNumber,
+		schedule: Self::RewardSchedule,
+	) -> Result<(), Self::Error>;
+
 	/// Crate new yield farm in the global farm.
 	///
 	/// Returns: `(YieldFarmId)`
@@ -149,8 +162,20 @@ pub trait Mutate<AccountId, AssetId, BlockNumber> {
 		yield_farm_id: YieldFarmId,
 	) -> Result<(GlobalFarmId, AssetId, Self::Balance, Self::Balance), Self::Error>;
 
+	/// Claim rewards from global farm's reward streams for given deposit.
+	///
+	/// Returns: `Vec<(reward currency, claimed amount)>`
+	fn claim_reward_streams(
+		who: AccountId,
+		deposit_id: DepositId,
+		yield_farm_id: YieldFarmId,
+	) -> Result<Vec<(AssetId, Self::Balance)>, Self::Error>;
+
 	/// Withdraw LP shares from yield farm. Function attempts to claim rewards for `who` if farm is
 	/// claimable.
+	/// Rewards from global farm's reward streams are not claimed by this function and unclaimed
+	/// rewards are returned back to the global farm. Use `claim_reward_streams()` first if
+	/// claiming is desirable.
 	///
 	/// Returns: `(withdrawn amount, Option<(reward currency, claimed amount, unclaimable amount>, true if deposit was destroyed)`
 	#[allow(clippy::type_complexity)]
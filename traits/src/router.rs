@@ -5,7 +5,9 @@ use frame_support::traits::ConstU32;
 use frame_support::weights::Weight;
 use frame_support::BoundedVec;
 use scale_info::TypeInfo;
-use sp_arithmetic::FixedU128;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::{FixedU128, Permill};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -13,6 +15,11 @@ pub const MAX_NUMBER_OF_TRADES: u32 = 9;
 
 pub type Route<AssetId> = BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>;
 
+pub const MAX_NUMBER_OF_SPLIT_ROUTES: u32 = 4;
+
+/// Parallel routes of the same asset pair, executed together as a single trade
+pub type SplitRoute<AssetId> = BoundedVec<WeightedRoute<AssetId>, ConstU32<MAX_NUMBER_OF_SPLIT_ROUTES>>;
+
 pub trait RouteSpotPriceProvider<AssetId> {
 	fn spot_price_with_fee(route: &[Trade<AssetId>]) -> Option<FixedU128>;
 }
@@ -79,6 +86,7 @@ pub trait RouteProvider<AssetId> {
 	}
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
 pub enum PoolType<AssetId> {
 	XYK,
@@ -87,6 +95,7 @@ pub enum PoolType<AssetId> {
 	Omnipool,
 	Aave,
 	HSM,
+	ConcentratedLiquidity,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -96,6 +105,7 @@ pub enum ExecutorError<E> {
 }
 
 ///A single trade for buy/sell, describing the asset pair and the pool type in which the trade is executed
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, Copy, Clone, TypeInfo, MaxEncodedLen)]
 pub struct Trade<AssetId> {
 	pub pool: PoolType<AssetId>,
@@ -103,6 +113,14 @@ pub struct Trade<AssetId> {
 	pub asset_out: AssetId,
 }
 
+///A route of a split trade, together with the share of the traded amount executed via the route
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct WeightedRoute<AssetId> {
+	pub weight: Permill,
+	pub route: Route<AssetId>,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct AmountInAndOut<Balance> {
 	pub amount_in: Balance,
@@ -126,6 +144,45 @@ pub fn inverse_route<AssetId>(trades: Route<AssetId>) -> Route<AssetId> {
 	BoundedVec::truncate_from(inversed_route)
 }
 
+/// Checks that the split route is not empty, and the weights are non-zero and sum up to 100%.
+pub fn is_valid_split_route<AssetId>(routes: &[WeightedRoute<AssetId>]) -> bool {
+	let mut total_weight: u32 = 0;
+
+	for weighted_route in routes {
+		if weighted_route.weight.is_zero() {
+			return false;
+		}
+		total_weight = total_weight.saturating_add(weighted_route.weight.deconstruct());
+	}
+
+	!routes.is_empty() && total_weight == Permill::one().deconstruct()
+}
+
+/// Splits `amount` between the routes based on their weights.
+///
+/// Shares are rounded down, the last route gets the remainder, so the parts always sum up to `amount`.
+pub fn split_amount<AssetId, Balance>(amount: Balance, routes: &[WeightedRoute<AssetId>]) -> Vec<Balance>
+where
+	Balance: Copy + Into<u128> + From<u128>,
+{
+	let total: u128 = amount.into();
+	let mut remaining = total;
+
+	routes
+		.iter()
+		.enumerate()
+		.map(|(i, weighted_route)| {
+			let part = if i == routes.len().saturating_sub(1) {
+				remaining
+			} else {
+				weighted_route.weight.mul_floor(total).min(remaining)
+			};
+			remaining = remaining.saturating_sub(part);
+			part.into()
+		})
+		.collect()
+}
+
 pub trait RouterT<Origin, AssetId, Balance, Trade, AmountInAndOut> {
 	fn sell(
 		origin: Origin,
@@ -153,6 +210,24 @@ pub trait RouterT<Origin, AssetId, Balance, Trade, AmountInAndOut> {
 		route: BoundedVec<Trade, ConstU32<MAX_NUMBER_OF_TRADES>>,
 	) -> DispatchResult;
 
+	fn split_sell(
+		origin: Origin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		routes: SplitRoute<AssetId>,
+	) -> Result<AmountInAndOut, DispatchError>;
+
+	fn split_buy(
+		origin: Origin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_amount_in: Balance,
+		routes: SplitRoute<AssetId>,
+	) -> Result<AmountInAndOut, DispatchError>;
+
 	fn calculate_sell_trade_amounts(route: &[Trade], amount_in: Balance) -> Result<Vec<AmountInAndOut>, DispatchError>;
 
 	fn calculate_buy_trade_amounts(route: &[Trade], amount_out: Balance) -> Result<Vec<AmountInAndOut>, DispatchError>;
@@ -220,7 +295,7 @@ pub trait TradeExecution<Origin, AccountId, AssetId, Balance> {
 }
 
 #[allow(clippy::redundant_clone)] //Needed as it complains about redundant clone, but clone is needed as Origin is moved and it is not copy type.
-#[impl_trait_for_tuples::impl_for_tuples(1, 6)]
+#[impl_trait_for_tuples::impl_for_tuples(1, 8)]
 impl<E: PartialEq, Origin: Clone, AccountId, AssetId: Copy, Balance: Copy>
 	TradeExecution<Origin, AccountId, AssetId, Balance> for Tuple
 {
@@ -338,6 +413,27 @@ impl<E: PartialEq, Origin: Clone, AccountId, AssetId: Copy, Balance: Copy>
 	}
 }
 
+/// Lists the trades an AMM is able to execute. Used by the router to discover routes.
+pub trait TradeablePoolsProvider<AssetId> {
+	/// Returns every trade supported by the AMM, the asset pair of a pool is listed in both directions.
+	fn tradeable_pools() -> Vec<Trade<AssetId>>;
+}
+
+impl<AssetId> TradeablePoolsProvider<AssetId> for () {
+	fn tradeable_pools() -> Vec<Trade<AssetId>> {
+		Vec::new()
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(1, 8)]
+impl<AssetId> TradeablePoolsProvider<AssetId> for Tuple {
+	fn tradeable_pools() -> Vec<Trade<AssetId>> {
+		let mut trades = Vec::new();
+		for_tuples!( #( trades.extend(Tuple::tradeable_pools()); )* );
+		trades
+	}
+}
+
 /// Provides weight info for the router. Calculates the weight of a route based on the AMMs.
 pub trait AmmTradeWeights<Trade> {
 	fn sell_weight(route: &[Trade]) -> Weight;